@@ -0,0 +1,34 @@
+use charcoal::{emitter::Emitter, project::Project, source_provider::EmptySourceProvider, sway};
+
+/// The name of the virtual file that fuzzed sources are translated from.
+const SOURCE_NAME: &str = "Fuzz.sol";
+
+/// Translates the source from a virtual file, returning the rendered Sway of each translated definition or the error message.
+fn translate(source: &str, parse_eagerly: bool) -> Result<Vec<String>, String> {
+    let mut project = Project::with_source_provider(EmptySourceProvider);
+    project.emitter = Emitter::Silent;
+
+    if parse_eagerly {
+        project.add_source_text(SOURCE_NAME, source).map_err(|e| e.to_string())?;
+    } else {
+        project.add_source(SOURCE_NAME, source);
+    }
+
+    let translated_definitions = project.translate().map_err(|e| e.to_string())?;
+
+    Ok(translated_definitions.into_iter()
+        .map(|translated_definition| {
+            let module: sway::Module = translated_definition.into();
+            sway::TabbedDisplayer(&module).to_string()
+        })
+        .collect())
+}
+
+/// Translates the source, which must either succeed or fail with an error instead of panicking,
+/// and checks that parsing it eagerly and lazily produces the same result.
+pub fn check_translation(source: &str) {
+    let lazy_result = translate(source, false);
+    let eager_result = translate(source, true);
+
+    assert_eq!(lazy_result, eager_result, "Translating the source lazily and eagerly produced different results");
+}
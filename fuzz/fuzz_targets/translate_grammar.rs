@@ -0,0 +1,451 @@
+//! Translates Solidity source files generated from a subset of the Solidity grammar, which reach further into the translator than arbitrary text.
+//!
+//! `cargo +nightly fuzz run translate_grammar`
+
+#![no_main]
+
+mod common;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use std::fmt::{Display, Formatter, Result};
+
+/// The names that identifiers are picked from, so that generated code references its own declarations.
+const NAMES: &[&str] = &["a", "b", "c", "value", "owner", "total", "balances", "Token", "Vault", "Math"];
+
+#[derive(Arbitrary, Debug)]
+struct Identifier(u8);
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", NAMES[self.0 as usize % NAMES.len()])
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum TypeName {
+    Bool,
+    Uint(u8),
+    Int(u8),
+    Address,
+    Bytes(u8),
+    DynamicBytes,
+    String,
+    Mapping(Box<TypeName>, Box<TypeName>),
+    Array(Box<TypeName>, Option<u8>),
+    Named(Identifier),
+}
+
+impl Display for TypeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            TypeName::Bool => write!(f, "bool"),
+            TypeName::Uint(bits) => write!(f, "uint{}", (*bits as usize % 32 + 1) * 8),
+            TypeName::Int(bits) => write!(f, "int{}", (*bits as usize % 32 + 1) * 8),
+            TypeName::Address => write!(f, "address"),
+            TypeName::Bytes(size) => write!(f, "bytes{}", *size % 32 + 1),
+            TypeName::DynamicBytes => write!(f, "bytes"),
+            TypeName::String => write!(f, "string"),
+            TypeName::Mapping(key, value) => write!(f, "mapping({key} => {value})"),
+            TypeName::Array(element, Some(size)) => write!(f, "{element}[{size}]"),
+            TypeName::Array(element, None) => write!(f, "{element}[]"),
+            TypeName::Named(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum Expression {
+    Bool(bool),
+    Number(u64),
+    String(Identifier),
+    Variable(Identifier),
+    MsgSender,
+    MsgValue,
+    BlockTimestamp,
+    Unary(UnaryOperator, Box<Expression>),
+    Binary(Box<Expression>, BinaryOperator, Box<Expression>),
+    Ternary(Box<Expression>, Box<Expression>, Box<Expression>),
+    Index(Box<Expression>, Box<Expression>),
+    Member(Box<Expression>, Identifier),
+    Call(Box<Expression>, Vec<Expression>),
+    Cast(TypeName, Box<Expression>),
+    New(TypeName, Vec<Expression>),
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Expression::Bool(value) => write!(f, "{value}"),
+            Expression::Number(value) => write!(f, "{value}"),
+            Expression::String(value) => write!(f, "\"{value}\""),
+            Expression::Variable(name) => write!(f, "{name}"),
+            Expression::MsgSender => write!(f, "msg.sender"),
+            Expression::MsgValue => write!(f, "msg.value"),
+            Expression::BlockTimestamp => write!(f, "block.timestamp"),
+            Expression::Unary(operator, x) => write!(f, "{operator}({x})"),
+            Expression::Binary(lhs, operator, rhs) => write!(f, "({lhs} {operator} {rhs})"),
+            Expression::Ternary(condition, then, otherwise) => write!(f, "({condition} ? {then} : {otherwise})"),
+            Expression::Index(x, index) => write!(f, "{x}[{index}]"),
+            Expression::Member(x, member) => write!(f, "{x}.{member}"),
+            Expression::Call(function, arguments) => write!(f, "{function}({})", Separated(arguments, ", ")),
+            Expression::Cast(type_name, x) => write!(f, "{type_name}({x})"),
+            Expression::New(type_name, arguments) => write!(f, "new {type_name}({})", Separated(arguments, ", ")),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum UnaryOperator {
+    Not,
+    Negate,
+    BitwiseNot,
+    Delete,
+}
+
+impl Display for UnaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            UnaryOperator::Not => write!(f, "!"),
+            UnaryOperator::Negate => write!(f, "-"),
+            UnaryOperator::BitwiseNot => write!(f, "~"),
+            UnaryOperator::Delete => write!(f, "delete "),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Power,
+    ShiftLeft,
+    ShiftRight,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    More,
+    MoreEqual,
+    And,
+    Or,
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::Power => "**",
+            BinaryOperator::ShiftLeft => "<<",
+            BinaryOperator::ShiftRight => ">>",
+            BinaryOperator::BitwiseAnd => "&",
+            BinaryOperator::BitwiseOr => "|",
+            BinaryOperator::BitwiseXor => "^",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::Less => "<",
+            BinaryOperator::LessEqual => "<=",
+            BinaryOperator::More => ">",
+            BinaryOperator::MoreEqual => ">=",
+            BinaryOperator::And => "&&",
+            BinaryOperator::Or => "||",
+        })
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum Statement {
+    VariableDefinition(TypeName, Identifier, Option<Expression>),
+    Assignment(Expression, Option<BinaryOperator>, Expression),
+    Expression(Expression),
+    If(Expression, Vec<Statement>, Option<Vec<Statement>>),
+    While(Expression, Vec<Statement>),
+    For(Identifier, Expression, Vec<Statement>),
+    Unchecked(Vec<Statement>),
+    Require(Expression, Option<Identifier>),
+    Revert,
+    Emit(Identifier, Vec<Expression>),
+    Return(Option<Expression>),
+    Break,
+    Continue,
+}
+
+impl Display for Statement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Statement::VariableDefinition(type_name, name, Some(value)) => write!(f, "{type_name} {name} = {value};"),
+            Statement::VariableDefinition(type_name, name, None) => write!(f, "{type_name} {name};"),
+            Statement::Assignment(lhs, Some(operator), rhs) => write!(f, "{lhs} {operator}= {rhs};"),
+            Statement::Assignment(lhs, None, rhs) => write!(f, "{lhs} = {rhs};"),
+            Statement::Expression(x) => write!(f, "{x};"),
+            Statement::If(condition, body, Some(otherwise)) => write!(f, "if ({condition}) {{ {} }} else {{ {} }}", Separated(body, " "), Separated(otherwise, " ")),
+            Statement::If(condition, body, None) => write!(f, "if ({condition}) {{ {} }}", Separated(body, " ")),
+            Statement::While(condition, body) => write!(f, "while ({condition}) {{ {} }}", Separated(body, " ")),
+            Statement::For(name, condition, body) => write!(f, "for (uint256 {name} = 0; {condition}; {name}++) {{ {} }}", Separated(body, " ")),
+            Statement::Unchecked(body) => write!(f, "unchecked {{ {} }}", Separated(body, " ")),
+            Statement::Require(condition, Some(message)) => write!(f, "require({condition}, \"{message}\");"),
+            Statement::Require(condition, None) => write!(f, "require({condition});"),
+            Statement::Revert => write!(f, "revert();"),
+            Statement::Emit(event, arguments) => write!(f, "emit {event}({});", Separated(arguments, ", ")),
+            Statement::Return(Some(x)) => write!(f, "return {x};"),
+            Statement::Return(None) => write!(f, "return;"),
+            Statement::Break => write!(f, "break;"),
+            Statement::Continue => write!(f, "continue;"),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct Parameter(TypeName, Option<StorageLocation>, Identifier);
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.1.as_ref() {
+            Some(storage_location) => write!(f, "{} {storage_location} {}", self.0, self.2),
+            None => write!(f, "{} {}", self.0, self.2),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum StorageLocation {
+    Memory,
+    Storage,
+    Calldata,
+}
+
+impl Display for StorageLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            StorageLocation::Memory => write!(f, "memory"),
+            StorageLocation::Storage => write!(f, "storage"),
+            StorageLocation::Calldata => write!(f, "calldata"),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum Visibility {
+    Public,
+    External,
+    Internal,
+    Private,
+}
+
+impl Display for Visibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Visibility::Public => write!(f, "public"),
+            Visibility::External => write!(f, "external"),
+            Visibility::Internal => write!(f, "internal"),
+            Visibility::Private => write!(f, "private"),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum Mutability {
+    Pure,
+    View,
+    Payable,
+}
+
+impl Display for Mutability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Mutability::Pure => write!(f, "pure"),
+            Mutability::View => write!(f, "view"),
+            Mutability::Payable => write!(f, "payable"),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum ContractPart {
+    StateVariable(TypeName, Option<Visibility>, bool, Identifier, Option<Expression>),
+    Struct(Identifier, Vec<(TypeName, Identifier)>),
+    Event(Identifier, Vec<Parameter>),
+    Modifier(Identifier, Vec<Parameter>, Vec<Statement>),
+    Constructor(Vec<Parameter>, Vec<Statement>),
+    Function {
+        name: Identifier,
+        parameters: Vec<Parameter>,
+        visibility: Visibility,
+        mutability: Option<Mutability>,
+        modifiers: Vec<Identifier>,
+        is_virtual: bool,
+        is_override: bool,
+        returns: Vec<Parameter>,
+        body: Option<Vec<Statement>>,
+    },
+}
+
+impl Display for ContractPart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ContractPart::StateVariable(type_name, visibility, is_constant, name, value) => {
+                write!(f, "{type_name}")?;
+
+                if let Some(visibility) = visibility {
+                    write!(f, " {visibility}")?;
+                }
+
+                if *is_constant {
+                    write!(f, " constant")?;
+                }
+
+                write!(f, " {name}")?;
+
+                if let Some(value) = value {
+                    write!(f, " = {value}")?;
+                }
+
+                write!(f, ";")
+            }
+
+            ContractPart::Struct(name, fields) => {
+                write!(f, "struct {name} {{ ")?;
+
+                for (type_name, field_name) in fields {
+                    write!(f, "{type_name} {field_name}; ")?;
+                }
+
+                write!(f, "}}")
+            }
+
+            ContractPart::Event(name, parameters) => write!(f, "event {name}({});", Separated(parameters, ", ")),
+
+            ContractPart::Modifier(name, parameters, body) => {
+                write!(f, "modifier {name}({}) {{ {} _; }}", Separated(parameters, ", "), Separated(body, " "))
+            }
+
+            ContractPart::Constructor(parameters, body) => {
+                write!(f, "constructor({}) {{ {} }}", Separated(parameters, ", "), Separated(body, " "))
+            }
+
+            ContractPart::Function { name, parameters, visibility, mutability, modifiers, is_virtual, is_override, returns, body } => {
+                write!(f, "function {name}({}) {visibility}", Separated(parameters, ", "))?;
+
+                if let Some(mutability) = mutability {
+                    write!(f, " {mutability}")?;
+                }
+
+                for modifier in modifiers {
+                    write!(f, " {modifier}")?;
+                }
+
+                if *is_virtual {
+                    write!(f, " virtual")?;
+                }
+
+                if *is_override {
+                    write!(f, " override")?;
+                }
+
+                if !returns.is_empty() {
+                    write!(f, " returns ({})", Separated(returns, ", "))?;
+                }
+
+                match body {
+                    Some(body) => write!(f, " {{ {} }}", Separated(body, " ")),
+                    None => write!(f, ";"),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum ContractKind {
+    Contract,
+    AbstractContract,
+    Interface,
+    Library,
+}
+
+impl Display for ContractKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ContractKind::Contract => write!(f, "contract"),
+            ContractKind::AbstractContract => write!(f, "abstract contract"),
+            ContractKind::Interface => write!(f, "interface"),
+            ContractKind::Library => write!(f, "library"),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct ContractDefinition {
+    kind: ContractKind,
+    name: Identifier,
+    base_contracts: Vec<Identifier>,
+    parts: Vec<ContractPart>,
+}
+
+impl Display for ContractDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} {}", self.kind, self.name)?;
+
+        if !self.base_contracts.is_empty() {
+            write!(f, " is {}", Separated(&self.base_contracts, ", "))?;
+        }
+
+        writeln!(f, " {{")?;
+
+        for part in self.parts.iter() {
+            writeln!(f, "    {part}")?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct SourceUnit {
+    contracts: Vec<ContractDefinition>,
+}
+
+impl Display for SourceUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "// SPDX-License-Identifier: MIT")?;
+        writeln!(f, "pragma solidity ^0.8.0;")?;
+
+        for contract in self.contracts.iter() {
+            writeln!(f)?;
+            write!(f, "{contract}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays the items separated by the separator.
+struct Separated<'a, T>(&'a [T], &'a str);
+
+impl<T: Display> Display for Separated<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.1)?;
+            }
+
+            write!(f, "{item}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fuzz_target!(|source_unit: SourceUnit| {
+    common::check_translation(source_unit.to_string().as_str());
+});
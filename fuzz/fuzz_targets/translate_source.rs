@@ -0,0 +1,15 @@
+//! Translates arbitrary text as a Solidity source file.
+//!
+//! `cargo +nightly fuzz run translate_source fuzz/corpus/translate_source test/solidity-by-example/contracts`
+
+#![no_main]
+
+mod common;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(source) = std::str::from_utf8(data) {
+        common::check_translation(source);
+    }
+});
@@ -0,0 +1,131 @@
+use crate::errors::Error;
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// The formats that a generated workspace can be archived to, detected from the extension of the archive path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let file_name = path.as_ref().file_name()?.to_string_lossy().to_lowercase();
+
+        if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else if file_name.ends_with(".tar") {
+            Some(ArchiveFormat::Tar)
+        } else if file_name.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+/// Gets the format of the archive path, or an error if its extension is unsupported.
+pub fn check_archive_path<P: AsRef<Path>>(archive_path: P) -> Result<ArchiveFormat, Error> {
+    let archive_path = archive_path.as_ref();
+
+    ArchiveFormat::from_path(archive_path).ok_or_else(|| Error::Wrapped(Box::new(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!("error: Unsupported archive format: \"{}\" (supported extensions: .tar, .tar.gz, .tgz, .zip)", archive_path.to_string_lossy()),
+    ))))
+}
+
+/// Writes every file in the source directory to an archive, with paths relative to the source directory.
+pub fn write_archive<P1: AsRef<Path>, P2: AsRef<Path>>(source_directory: P1, archive_path: P2) -> Result<(), Error> {
+    let source_directory = source_directory.as_ref();
+    let archive_path = archive_path.as_ref();
+
+    let format = check_archive_path(archive_path)?;
+
+    let mut file_paths = vec![];
+    collect_file_paths(source_directory, &mut file_paths).map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    // Sort the file paths so that archives of the same workspace are identical
+    file_paths.sort();
+
+    if let Some(parent) = archive_path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+    }
+
+    let file = File::create(archive_path).map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    let relative_paths = file_paths.iter()
+        .map(|p| (p, p.strip_prefix(source_directory).unwrap_or(p)))
+        .collect::<Vec<_>>();
+
+    match format {
+        ArchiveFormat::Tar => {
+            write_tar_archive(file, relative_paths.as_slice()).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        ArchiveFormat::TarGz => {
+            let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            write_tar_archive(encoder, relative_paths.as_slice())
+                .and_then(|encoder| encoder.finish())
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        ArchiveFormat::Zip => {
+            let mut writer = zip::ZipWriter::new(file);
+            let options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+            for (path, relative_path) in relative_paths {
+                let mut contents = vec![];
+                File::open(path).and_then(|mut f| f.read_to_end(&mut contents)).map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+                writer.start_file(get_archive_entry_name(relative_path), options).map_err(|e| Error::Wrapped(Box::new(e)))?;
+                writer.write_all(&contents).map_err(|e| Error::Wrapped(Box::new(e)))?;
+            }
+
+            writer.finish().map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_tar_archive<W: Write>(writer: W, relative_paths: &[(&PathBuf, &Path)]) -> std::io::Result<W> {
+    let mut builder = tar::Builder::new(writer);
+
+    // Use fixed modification times and owners, so that archives of the same workspace are identical
+    builder.mode(tar::HeaderMode::Deterministic);
+
+    for (path, relative_path) in relative_paths {
+        builder.append_path_with_name(path, get_archive_entry_name(relative_path))?;
+    }
+
+    builder.into_inner()
+}
+
+/// Gets the name of an archive entry, which always uses `/` as its separator.
+fn get_archive_entry_name(relative_path: &Path) -> String {
+    relative_path.components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn collect_file_paths(directory: &Path, file_paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_file_paths(&path, file_paths)?;
+        } else {
+            file_paths.push(path);
+        }
+    }
+
+    Ok(())
+}
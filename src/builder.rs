@@ -0,0 +1,727 @@
+use crate::{
+    archive,
+    cache::TranslationCache,
+    changelog::{self, Manifest},
+    emitter::{emitln, Emitter},
+    errors::Error,
+    forc,
+    printer::PrinterOptions,
+    project::{self, Lint, Project, ProjectType},
+    report,
+    sway,
+    translate::{self, RenameReason, TranslatedDefinition},
+};
+#[cfg(feature = "syntax-check")]
+use crate::syntax_check;
+use convert_case::Case;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+/// Configures and runs a translation without going through the command line interface.
+///
+/// ```ignore
+/// let translated_definitions = ProjectBuilder::new()
+///     .source("contracts/Token.sol")
+///     .remapping("@openzeppelin/", "lib/openzeppelin-contracts/")
+///     .type_override("uint256", sway::TypeName::Identifier { name: "u64".into(), generic_parameters: None })
+///     .preset(ProjectType::Foundry { remappings: HashMap::new() })
+///     .output("out")
+///     .build()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ProjectBuilder {
+    sources: Vec<PathBuf>,
+    definition_name: Option<String>,
+    remappings: HashMap<String, String>,
+    type_overrides: HashMap<String, sway::TypeName>,
+    config_path: Option<PathBuf>,
+    preset: Option<ProjectType>,
+    output_directory: Option<PathBuf>,
+    sway_version: Option<String>,
+    emit_all_types: bool,
+    strict_overflow: bool,
+    invariant_helpers: bool,
+    debug_events: bool,
+    source_locations: bool,
+    source_map: bool,
+    strict: bool,
+    allowed_lints: HashSet<Lint>,
+    archive_path: Option<PathBuf>,
+    report_path: Option<PathBuf>,
+    cache_directory: Option<PathBuf>,
+    verify: bool,
+    format: bool,
+    emitter: Emitter,
+    printer_options: PrinterOptions,
+    limit: Option<usize>,
+    section_libraries: Vec<(String, String)>,
+    native_assets: Vec<String>,
+    erc20_as_src20: bool,
+    erc721_as_nft: bool,
+    erc1155_as_multi_asset: bool,
+}
+
+/// The name of the file in the output directory that records which source files have been translated by budgeted runs.
+pub const PROGRESS_FILE: &str = ".charcoal_progress";
+
+impl ProjectBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a Solidity file or folder to translate.
+    pub fn source<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.sources.push(path.into());
+        self
+    }
+
+    /// Only translates the definition with the specified name.
+    pub fn definition_name<S: Into<String>>(mut self, definition_name: S) -> Self {
+        self.definition_name = Some(definition_name.into());
+        self
+    }
+
+    /// Remaps import paths starting with `prefix` to `path`, relative to the project root folder when one is found.
+    ///
+    /// Remappings supplied here take priority over the remappings of the project type.
+    pub fn remapping<S1: Into<String>, S2: Into<String>>(mut self, prefix: S1, path: S2) -> Self {
+        self.remappings.insert(prefix.into(), path.into());
+        self
+    }
+
+    /// Translates every occurrence of the Solidity type name to the specified Sway type name.
+    pub fn type_override<S: Into<String>>(mut self, solidity_type_name: S, sway_type_name: sway::TypeName) -> Self {
+        self.type_overrides.insert(solidity_type_name.into(), sway_type_name);
+        self
+    }
+
+    /// Reads type and identifier mappings from the specified `charcoal.toml` file instead of the closest one to each source.
+    ///
+    /// Type overrides supplied to the builder take priority over the type mappings of the file.
+    pub fn config<P: Into<PathBuf>>(mut self, config_path: P) -> Self {
+        self.config_path = Some(config_path.into());
+        self
+    }
+
+    /// Uses the specified project type instead of detecting it from the project root folder.
+    pub fn preset(mut self, project_type: ProjectType) -> Self {
+        self.preset = Some(project_type);
+        self
+    }
+
+    /// Writes a Forc project for each translated definition to the specified directory.
+    pub fn output<P: Into<PathBuf>>(mut self, output_directory: P) -> Self {
+        self.output_directory = Some(output_directory.into());
+        self
+    }
+
+    /// Pins the dependencies of generated Forc projects to the specified Sway version.
+    pub fn sway_version<S: Into<String>>(mut self, sway_version: S) -> Self {
+        self.sway_version = Some(sway_version.into());
+        self
+    }
+
+    /// Emits events and errors enums even when they are not referenced by an `emit` or `revert` statement.
+    pub fn emit_all_types(mut self, emit_all_types: bool) -> Self {
+        self.emit_all_types = emit_all_types;
+        self
+    }
+
+    /// Keeps checked arithmetic in `unchecked` blocks instead of disabling panics on overflow, warning about the semantic difference.
+    pub fn strict_overflow(mut self, strict_overflow: bool) -> Self {
+        self.strict_overflow = strict_overflow;
+        self
+    }
+
+    /// Generates a `check_invariants` function in each contract that asserts the `require` conditions on contract state shared by multiple functions.
+    pub fn invariant_helpers(mut self, invariant_helpers: bool) -> Self {
+        self.invariant_helpers = invariant_helpers;
+        self
+    }
+
+    /// Instruments each contract function with events logged on entry and exit, carrying the function name and the caller.
+    pub fn debug_events(mut self, debug_events: bool) -> Self {
+        self.debug_events = debug_events;
+        self
+    }
+
+    /// Annotates each translated function and storage field with a comment referring to the Solidity line it was translated from, i.e: `// from Token.sol:143`.
+    pub fn source_locations(mut self, source_locations: bool) -> Self {
+        self.source_locations = source_locations;
+        self
+    }
+
+    /// Writes a `source_map.json` file to each generated project, mapping the items of its `src/main.sw` file to the Solidity code they were translated from.
+    pub fn source_map(mut self, source_map: bool) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    /// Rejects each definition whose translation has semantic differences from the original code or stubbed code, instead of emitting it.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Suppresses the audit warnings of the specified lint, for translation decisions that have already been reviewed.
+    pub fn allow(mut self, lint: Lint) -> Self {
+        self.allowed_lints.insert(lint);
+        self
+    }
+
+    /// Writes the generated workspace to a single `.tar`, `.tar.gz`, `.tgz` or `.zip` archive.
+    ///
+    /// If no output directory was supplied, the workspace is generated in a temporary directory that is removed once archived.
+    pub fn archive<P: Into<PathBuf>>(mut self, archive_path: P) -> Self {
+        self.archive_path = Some(archive_path.into());
+        self
+    }
+
+    /// Writes a markdown migration report to the specified path, describing for each translated definition which functions were translated cleanly,
+    /// which items were stubbed, which semantic differences were introduced and what has to be followed up manually.
+    pub fn report<P: Into<PathBuf>>(mut self, report_path: P) -> Self {
+        self.report_path = Some(report_path.into());
+        self
+    }
+
+    /// Caches the translation of each source file in the specified directory, so that subsequent runs with the same options
+    /// only translate the source files that changed or that import changed files.
+    pub fn cache<P: Into<PathBuf>>(mut self, cache_directory: P) -> Self {
+        self.cache_directory = Some(cache_directory.into());
+        self
+    }
+
+    /// Writes the warnings, audit warnings and notes of the translation to the specified emitter instead of stderr.
+    pub fn emitter(mut self, emitter: Emitter) -> Self {
+        self.emitter = emitter;
+        self
+    }
+
+    /// Formats the generated Sway code with the specified options, i.e: to match the `forc fmt` settings of a project.
+    ///
+    /// Options supplied here take priority over the printer options of the `charcoal.toml` file.
+    pub fn printer_options(mut self, printer_options: PrinterOptions) -> Self {
+        self.printer_options = printer_options;
+        self
+    }
+
+    /// Runs `forc fmt` on each generated project after it is written, so the generated code follows the canonical Sway formatting.
+    ///
+    /// Formatting is skipped if `forc` is not installed.
+    pub fn format(mut self, format: bool) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Runs `forc build` on each generated project, failing with the compiler errors mapped back to the Solidity code they originate from.
+    ///
+    /// Verification is skipped if `forc` is not installed.
+    pub fn verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Only translates up to the specified number of source files per run.
+    ///
+    /// If an output directory was supplied, the translated source files are recorded in it and skipped by subsequent runs,
+    /// allowing large codebases to be translated in chunks. Imported definitions are still resolved from their source files.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Extracts the helper functions of the contract whose names start with `prefix` into a separate library that the contract depends on. (Experimental)
+    ///
+    /// The contract is not split into multiple contracts: functions that access storage, are exposed by the contract abi
+    /// or reference other items of the contract are left in the contract, and a note explains why for each of them.
+    pub fn section_library<S1: Into<String>, S2: Into<String>>(mut self, definition_name: S1, prefix: S2) -> Self {
+        self.section_libraries.push((definition_name.into(), prefix.into()));
+        self
+    }
+
+    /// Ports the token contract to a native asset, rewriting the reads of its balance mapping to native balance queries,
+    /// replacing its writes with reverting stubs and dropping the mapping. (Experimental)
+    pub fn native_asset<S: Into<String>>(mut self, definition_name: S) -> Self {
+        self.native_assets.push(definition_name.into());
+        self
+    }
+
+    /// Ports each recognized ERC-20 token contract to a native asset implementing the SRC-20 standard,
+    /// minting and burning the asset in `_mint` and `_burn` and making `transfer` and `transferFrom` revert. (Experimental)
+    pub fn erc20_as_src20(mut self, erc20_as_src20: bool) -> Self {
+        self.erc20_as_src20 = erc20_as_src20;
+        self
+    }
+
+    /// Ports each recognized ERC-721 token contract to native NFTs, minting a separate asset for each token
+    /// and implementing the SRC-20 and SRC-7 standards for their metadata. (Experimental)
+    pub fn erc721_as_nft(mut self, erc721_as_nft: bool) -> Self {
+        self.erc721_as_nft = erc721_as_nft;
+        self
+    }
+
+    /// Ports each recognized ERC-1155 multi-token contract to native assets, minting a separate asset for each token id
+    /// and implementing the SRC-20 and SRC-7 standards for their metadata. (Experimental)
+    pub fn erc1155_as_multi_asset(mut self, erc1155_as_multi_asset: bool) -> Self {
+        self.erc1155_as_multi_asset = erc1155_as_multi_asset;
+        self
+    }
+
+    /// Translates each source unit of the configured sources, returning the path of each source unit along with its translated project.
+    pub fn translate(&self) -> Result<Vec<(PathBuf, Project)>, Error> {
+        if self.sources.is_empty() {
+            return Err(Error::Wrapped(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "No source files or folders were supplied",
+            ))));
+        }
+
+        let mut result = vec![];
+        let completed_source_unit_paths = self.read_progress()?;
+        let mut remaining_count = 0;
+        let mut section_library_definition_names = HashSet::new();
+        let mut native_asset_definition_names = HashSet::new();
+        let translation_cache = self.cache_directory.as_ref().map(TranslationCache::new);
+        let mut cached_count = 0;
+
+        for source in self.sources.iter() {
+            let source_unit_paths = project::collect_source_unit_paths(source)
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+            for source_unit_path in source_unit_paths {
+                // Skip source files that were translated by a previous budgeted run
+                if completed_source_unit_paths.contains(&source_unit_path) {
+                    continue;
+                }
+
+                if let Some(limit) = self.limit {
+                    if result.len() >= limit {
+                        remaining_count += 1;
+                        continue;
+                    }
+                }
+
+                let mut project = Project {
+                    remappings: self.remappings.clone(),
+                    type_overrides: self.type_overrides.clone(),
+                    emit_all_types: self.emit_all_types,
+                    strict_overflow: self.strict_overflow,
+                    invariant_helpers: self.invariant_helpers,
+                    debug_events: self.debug_events,
+                    source_locations: self.source_locations,
+                    source_map: self.source_map,
+                    strict: self.strict,
+                    allowed_lints: self.allowed_lints.clone(),
+                    emitter: self.emitter.clone(),
+                    printer_options: self.printer_options.clone(),
+                    ..Default::default()
+                };
+
+                if let Some(preset) = self.preset.as_ref() {
+                    project.project_type = preset.clone();
+                } else if source.is_dir() {
+                    project.detect_project_type(source)?;
+                } else if let Some(root_path) = project.find_project_root_folder(source) {
+                    project.detect_project_type(root_path)?;
+                } else {
+                    project.project_type = ProjectType::Unknown;
+                }
+
+                // Load the project-specific mappings of the supplied config file, or the closest one to the source
+                if let Some(config_path) = self.config_path.as_ref() {
+                    project.load_config(config_path)?;
+                } else if let Some(config_path) = project.find_config_file(source) {
+                    project.load_config(config_path)?;
+                }
+
+                let cache_key = match translation_cache.as_ref() {
+                    Some(translation_cache) => Some(translation_cache.get_key(&source_unit_path, self.describe_translation_options(&project).as_str())?),
+                    None => None,
+                };
+
+                // Use the cached translation of the source file if neither it nor its imports have changed
+                let is_cached = match (translation_cache.as_ref(), cache_key.as_ref()) {
+                    (Some(translation_cache), Some(cache_key)) => translation_cache.load(cache_key, &mut project),
+                    _ => false,
+                };
+
+                if is_cached {
+                    cached_count += 1;
+                } else {
+                    project.translate_source_unit(self.definition_name.as_ref(), &source_unit_path)?;
+
+                    // Cache the translation before the passes below, so that they run again and report their diagnostics on later runs
+                    if let (Some(translation_cache), Some(cache_key)) = (translation_cache.as_ref(), cache_key.as_ref()) {
+                        translation_cache.store(cache_key, &project)?;
+                    }
+                }
+
+                for (definition_name, prefix) in self.section_libraries.iter() {
+                    if translate::extract_section_library(&mut project, definition_name, prefix) {
+                        section_library_definition_names.insert(definition_name.clone());
+                    }
+                }
+
+                for definition_name in self.native_assets.iter() {
+                    if translate::rewrite_native_asset_balances(&mut project, definition_name)? {
+                        native_asset_definition_names.insert(definition_name.clone());
+                    }
+                }
+
+                if self.erc20_as_src20 {
+                    translate::port_erc20_contracts_to_src20(&mut project, &self.native_assets)?;
+                }
+
+                if self.erc721_as_nft {
+                    translate::port_erc721_contracts_to_nfts(&mut project)?;
+                }
+
+                if self.erc1155_as_multi_asset {
+                    translate::port_erc1155_contracts_to_multi_assets(&mut project)?;
+                }
+
+                // Infer the storage attributes again, since the passes above may have changed which functions access storage
+                for translated_definition in project.translated_definitions.iter_mut() {
+                    translate::refresh_storage_attributes(translated_definition);
+                }
+
+                result.push((source_unit_path, project));
+            }
+        }
+
+        for (definition_name, _) in self.section_libraries.iter() {
+            if !section_library_definition_names.contains(definition_name) && !result.is_empty() {
+                emitln!(self.emitter, "WARNING: Failed to find contract to extract a section library from: `{definition_name}`");
+            }
+        }
+
+        for definition_name in self.native_assets.iter() {
+            if !native_asset_definition_names.contains(definition_name) && !result.is_empty() {
+                emitln!(self.emitter, "WARNING: Failed to find contract to port to a native asset: `{definition_name}`");
+            }
+        }
+
+        if cached_count > 0 {
+            emitln!(self.emitter, "NOTE: Loaded the translations of {cached_count} unchanged source file(s) from the cache, their translation warnings are not repeated");
+        }
+
+        if self.limit.is_some() {
+            if remaining_count > 0 {
+                emitln!(self.emitter, "NOTE: {remaining_count} source file(s) remain to be translated, run again with the same options to continue");
+            } else if result.is_empty() && !completed_source_unit_paths.is_empty() {
+                emitln!(self.emitter, "NOTE: All source files have already been translated, remove `{PROGRESS_FILE}` from the output directory to start over");
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Describes the options that affect the translation of the project, so that translations cached with different options are not used.
+    ///
+    /// The options of the passes that run after translation are not included, since translations are cached before they run.
+    fn describe_translation_options(&self, project: &Project) -> String {
+        let (project_type, project_remappings) = match &project.project_type {
+            ProjectType::Foundry { remappings } => ("foundry", remappings.iter().collect::<BTreeMap<_, _>>()),
+            ProjectType::Hardhat => ("hardhat", BTreeMap::new()),
+            ProjectType::Brownie { remappings } => ("brownie", remappings.iter().collect::<BTreeMap<_, _>>()),
+            ProjectType::Truffle => ("truffle", BTreeMap::new()),
+            ProjectType::Unknown => ("unknown", BTreeMap::new()),
+        };
+
+        [
+            format!("definition_name: {:?}", self.definition_name),
+            format!("project_type: {project_type} {project_remappings:?}"),
+            format!("remappings: {:?}", project.remappings.iter().collect::<BTreeMap<_, _>>()),
+            format!("type_overrides: {:?}", project.type_overrides.iter().collect::<BTreeMap<_, _>>()),
+            format!("identifier_mappings: {:?}", project.identifier_mappings.iter().collect::<BTreeMap<_, _>>()),
+            format!("emit_all_types: {}", self.emit_all_types),
+            format!("strict_overflow: {}", self.strict_overflow),
+            format!("invariant_helpers: {}", self.invariant_helpers),
+            format!("debug_events: {}", self.debug_events),
+            format!("source_locations: {}", self.source_locations),
+            format!("strict: {}", self.strict),
+            format!("allowed_lints: {:?}", self.allowed_lints.iter().collect::<BTreeSet<_>>()),
+        ].join("\n")
+    }
+
+    /// Reads the source files translated by previous budgeted runs from the progress file of the output directory.
+    fn read_progress(&self) -> Result<HashSet<PathBuf>, Error> {
+        let (Some(_), Some(output_directory)) = (self.limit, self.output_directory.as_ref()) else {
+            return Ok(HashSet::new());
+        };
+
+        let progress_path = output_directory.join(PROGRESS_FILE);
+
+        if !progress_path.exists() {
+            return Ok(HashSet::new());
+        }
+
+        let progress = std::fs::read_to_string(progress_path)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        Ok(progress.lines().filter(|l| !l.is_empty()).map(PathBuf::from).collect())
+    }
+
+    /// Appends the supplied source files to the progress file of the output directory.
+    fn write_progress(&self, source_unit_paths: &[PathBuf]) -> Result<(), Error> {
+        let (Some(_), Some(output_directory)) = (self.limit, self.output_directory.as_ref()) else {
+            return Ok(());
+        };
+
+        let mut progress = source_unit_paths.iter()
+            .map(|p| format!("{}\n", p.to_string_lossy()))
+            .collect::<String>();
+
+        let progress_path = output_directory.join(PROGRESS_FILE);
+
+        if progress_path.exists() {
+            let previous_progress = std::fs::read_to_string(&progress_path)
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+            progress = format!("{previous_progress}{progress}");
+        }
+
+        std::fs::write(progress_path, progress)
+            .map_err(|e| Error::Wrapped(Box::new(e)))
+    }
+
+    /// Writes the migration report of the translated definitions, including the definitions of the translated source units that were rejected by strict mode.
+    fn write_report(&self, report_path: &Path, translated_source_units: &[(PathBuf, Project)], translated_definitions: &[TranslatedDefinition]) -> Result<(), Error> {
+        let mut rejected_definitions = translated_source_units.iter()
+            .flat_map(|(source_unit_path, project)| {
+                project.rejected_definitions.iter()
+                    .filter(move |(path, name)| path == source_unit_path && self.definition_name.as_ref().is_none_or(|n| n == name))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Keep the report identical between runs
+        rejected_definitions.sort();
+
+        std::fs::write(report_path, report::generate_report(translated_definitions, rejected_definitions.as_slice()))
+            .map_err(|e| Error::Wrapped(Box::new(e)))
+    }
+
+    /// Writes the manifest of the generated items to the output directory.
+    ///
+    /// Budgeted runs merge their definitions into the manifest of the previous runs, since they only translate some of the source files.
+    fn write_manifest(&self, output_directory: &Path, translated_definitions: &[TranslatedDefinition], output_names: &HashMap<(PathBuf, String), String>) -> Result<(), Error> {
+        let manifest_path = output_directory.join(changelog::MANIFEST_FILE);
+        let mut manifest = Manifest::new(translated_definitions, output_names);
+
+        if self.limit.is_some() && manifest_path.exists() {
+            let mut previous_manifest = Manifest::read(&manifest_path)?;
+            previous_manifest.merge(manifest);
+            manifest = previous_manifest;
+        }
+
+        manifest.write(manifest_path)
+    }
+
+    /// Translates the configured sources and returns the translated definitions.
+    ///
+    /// If an output directory was supplied, a Forc project is also generated for each translated definition.
+    /// Definitions that share a name with a definition from another file are written to a project with a name qualified by their path.
+    pub fn build(&self) -> Result<Vec<TranslatedDefinition>, Error> {
+        if let Some(archive_path) = self.archive_path.as_ref() {
+            archive::check_archive_path(archive_path)?;
+        }
+
+        let mut translated_source_units = self.translate()?;
+        let mut translated_definitions = vec![];
+
+        for (source_unit_path, project) in translated_source_units.iter() {
+            translated_definitions.extend(project.collect_translated_definitions(self.definition_name.as_ref(), source_unit_path));
+        }
+
+        // Record the qualified output names of the definitions as renames, so they are listed in the report and the renames of each project
+        let output_names = resolve_output_names(&self.emitter, translated_definitions.as_slice());
+
+        record_output_name_renames(translated_definitions.as_mut_slice(), &output_names);
+
+        for (_, project) in translated_source_units.iter_mut() {
+            record_output_name_renames(project.translated_definitions.as_mut_slice(), &output_names);
+        }
+
+        // Fail loudly when the printed code of a translated definition is not syntactically valid Sway
+        #[cfg(feature = "syntax-check")]
+        for translated_definition in translated_definitions.iter() {
+            let module: sway::Module = translated_definition.clone().into();
+            syntax_check::check_sway_syntax(&translated_definition.name, &self.printer_options.print(&module))?;
+
+            for (submodule_name, submodule) in translated_definition.submodules.iter() {
+                syntax_check::check_sway_syntax(submodule_name, &self.printer_options.print(submodule))?;
+            }
+        }
+
+        if let Some(report_path) = self.report_path.as_ref() {
+            self.write_report(report_path, translated_source_units.as_slice(), translated_definitions.as_slice())?;
+        }
+
+        // Generate the workspace in a temporary directory if it is only being archived
+        let temporary_directory = match (self.output_directory.as_ref(), self.archive_path.as_ref()) {
+            (None, Some(_)) => Some(TemporaryDirectory(std::env::temp_dir().join(format!("charcoal-{}", std::process::id())))),
+            _ => None,
+        };
+
+        if let Some(output_directory) = self.output_directory.as_ref().or(temporary_directory.as_ref().map(|x| &x.0)) {
+            let source_unit_paths = translated_source_units.iter().map(|(p, _)| p.clone()).collect::<Vec<_>>();
+
+            // Declare the ABIs used by multiple projects in a shared library project
+            let shared_abis = forc::collect_shared_abis(translated_definitions.as_slice());
+            forc::generate_shared_abis_project(output_directory, self.sway_version.as_ref(), shared_abis.as_slice(), &self.printer_options)?;
+
+            let is_forc_available = (self.format || self.verify) && forc::is_forc_available();
+            let format = self.format && is_forc_available;
+            let verify = self.verify && is_forc_available;
+
+            if self.format && !format {
+                emitln!(self.emitter, "NOTE: `forc` was not found, skipping the formatting of the generated projects");
+            }
+
+            if self.verify && !verify {
+                emitln!(self.emitter, "NOTE: `forc` was not found, skipping the verification of the generated projects");
+            }
+
+            if format {
+                forc::format_shared_abis_project(&self.emitter, output_directory)?;
+            }
+
+            let mut forc_diagnostics = vec![];
+
+            for (source_unit_path, mut project) in translated_source_units {
+                forc::generate_forc_project(
+                    &mut project,
+                    output_directory,
+                    self.definition_name.as_ref(),
+                    self.sway_version.as_ref(),
+                    &source_unit_path,
+                    &output_names,
+                    shared_abis.as_slice(),
+                )?;
+
+                if format {
+                    forc::format_forc_project(
+                        &project,
+                        output_directory,
+                        self.definition_name.as_ref(),
+                        &source_unit_path,
+                        &output_names,
+                    )?;
+                }
+
+                if verify {
+                    forc_diagnostics.extend(forc::verify_forc_project(
+                        &project,
+                        output_directory,
+                        self.definition_name.as_ref(),
+                        &source_unit_path,
+                        &output_names,
+                    )?);
+                }
+            }
+
+            forc::generate_third_party_notices(output_directory, translated_definitions.as_slice(), &output_names)?;
+
+            // Record the items generated by this run so they can be compared against the items of other runs
+            self.write_manifest(output_directory, translated_definitions.as_slice(), &output_names)?;
+
+            // Record the source files translated by this run once their projects have been written
+            self.write_progress(source_unit_paths.as_slice())?;
+
+            if let Some(archive_path) = self.archive_path.as_ref() {
+                archive::write_archive(output_directory, archive_path)?;
+            }
+
+            if !forc_diagnostics.is_empty() {
+                return Err(Error::ForcDiagnostics(forc_diagnostics));
+            }
+        } else {
+            if self.format {
+                emitln!(self.emitter, "WARNING: Skipping the formatting of the generated projects because no output directory or archive was supplied");
+            }
+
+            if self.verify {
+                emitln!(self.emitter, "WARNING: Skipping the verification of the generated projects because no output directory or archive was supplied");
+            }
+        }
+
+        self.emitter.flush().map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        Ok(translated_definitions)
+    }
+}
+
+/// A temporary directory that is removed when dropped, so that it is also removed when generating its contents fails.
+struct TemporaryDirectory(PathBuf);
+
+impl Drop for TemporaryDirectory {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Resolves a unique output name for each translated definition, keyed by its source unit path and name.
+///
+/// Definitions whose names are unique keep their names, while definitions that share a name with a definition from another file
+/// are qualified with their path relative to the common ancestor of the conflicting files (i.e: `a/Token.sol` => `ATokenToken`).
+pub fn resolve_output_names(emitter: &Emitter, translated_definitions: &[TranslatedDefinition]) -> HashMap<(PathBuf, String), String> {
+    let mut output_names = HashMap::new();
+    let mut paths_by_name: BTreeMap<&String, Vec<&PathBuf>> = BTreeMap::new();
+
+    for translated_definition in translated_definitions.iter() {
+        let paths = paths_by_name.entry(&translated_definition.name).or_default();
+
+        if !paths.contains(&&translated_definition.path) {
+            paths.push(&translated_definition.path);
+        }
+    }
+
+    for (name, mut paths) in paths_by_name {
+        paths.sort();
+
+        if paths.len() == 1 {
+            output_names.insert((paths[0].clone(), name.clone()), name.clone());
+            continue;
+        }
+
+        // Find the common ancestor of the conflicting files
+        let mut common_ancestor = paths[0].parent().map(Path::to_path_buf).unwrap_or_default();
+
+        while !paths.iter().all(|p| p.starts_with(&common_ancestor)) {
+            if !common_ancestor.pop() {
+                break;
+            }
+        }
+
+        for path in paths {
+            let relative_path = path.strip_prefix(&common_ancestor).unwrap_or(path).with_extension("");
+
+            let prefix = relative_path.components()
+                .map(|c| crate::translate_naming_convention(c.as_os_str().to_string_lossy().as_ref(), Case::Pascal))
+                .collect::<String>();
+
+            let output_name = format!("{prefix}{name}");
+
+            emitln!(emitter, "WARNING: {} - `{name}` is defined in multiple files, its output will be named `{output_name}`", path.to_string_lossy());
+
+            output_names.insert((path.clone(), name.clone()), output_name);
+        }
+    }
+
+    output_names
+}
+
+/// Records the output names that differ from the names of their definitions as renamed identifiers of the definitions.
+fn record_output_name_renames(translated_definitions: &mut [TranslatedDefinition], output_names: &HashMap<(PathBuf, String), String>) {
+    for translated_definition in translated_definitions.iter_mut() {
+        let Some(output_name) = output_names.get(&(translated_definition.path.clone(), translated_definition.name.clone())) else { continue };
+
+        if *output_name != translated_definition.name {
+            let name = translated_definition.name.clone();
+            translated_definition.add_renamed_identifier(&name, output_name, RenameReason::QualifiedName);
+        }
+    }
+}
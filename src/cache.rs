@@ -0,0 +1,125 @@
+use crate::{errors::Error, line_ranges::LineRanges, project::Project, translate::TranslatedDefinition};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use solang_parser::pt as solidity;
+use std::path::{Path, PathBuf};
+
+/// The version of the contents of cache entries, which is part of their keys so that entries written with different contents are not used.
+const CACHE_VERSION: u32 = 2;
+
+/// The translation of a source unit, along with the source units it was translated from.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// The path and content digest of each source unit that the translation depends on, including the translated source unit.
+    dependencies: Vec<(PathBuf, String)>,
+    source_units: Vec<(PathBuf, solidity::SourceUnit)>,
+    translated_definitions: Vec<TranslatedDefinition>,
+    rejected_definitions: Vec<(PathBuf, String)>,
+}
+
+/// An on-disk cache of translated source units, which allows a run to skip the source units that have not changed since a previous run.
+///
+/// Each source unit is cached by the digest of its content, the charcoal version and the translation options.
+/// A cached translation is only used if none of the source units it depends on have changed.
+///
+/// Translations are cached before the passes that rewrite translated contracts (i.e: porting tokens to native assets),
+/// which run again on cached translations.
+#[derive(Clone, Debug)]
+pub struct TranslationCache {
+    directory: PathBuf,
+}
+
+impl TranslationCache {
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Gets the key that the translation of the source unit is cached by, which changes with its content, the charcoal version and the supplied translation options.
+    pub fn get_key<P: AsRef<Path>>(&self, source_unit_path: P, translation_options: &str) -> Result<String, Error> {
+        let source = std::fs::read(source_unit_path.as_ref())
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        let mut hasher = Keccak256::new();
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.update(CACHE_VERSION.to_le_bytes());
+        hasher.update(translation_options.as_bytes());
+        hasher.update(source_unit_path.as_ref().to_string_lossy().as_bytes());
+        hasher.update(source);
+
+        Ok(hasher.finalize().iter().map(|b| format!("{b:02x}")).collect())
+    }
+
+    fn get_entry_path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{key}.yaml"))
+    }
+
+    /// Restores the cached translation of the source unit into the project, returning `false` if it has not been cached
+    /// or if any of the source units it depends on have changed.
+    pub fn load(&self, key: &str, project: &mut Project) -> bool {
+        let Ok(entry) = std::fs::read_to_string(self.get_entry_path(key)) else { return false };
+
+        // Treat unreadable entries (i.e: from an incompatible version) as missing
+        let Ok(entry) = serde_yaml::with::singleton_map_recursive::deserialize::<CacheEntry, _>(serde_yaml::Deserializer::from_str(entry.as_str())) else { return false };
+
+        let mut line_ranges = vec![];
+
+        for (path, digest) in entry.dependencies.iter() {
+            let Ok(source) = std::fs::read_to_string(path) else { return false };
+
+            if get_digest(source.as_str()) != *digest {
+                return false;
+            }
+
+            line_ranges.push((path.clone(), LineRanges::new(source.as_str())));
+        }
+
+        project.line_ranges.extend(line_ranges);
+
+        for (path, source_unit) in entry.source_units {
+            project.solidity_source_units.insert(path, source_unit);
+        }
+
+        project.translated_definitions.extend(entry.translated_definitions);
+        project.rejected_definitions.extend(entry.rejected_definitions);
+
+        true
+    }
+
+    /// Writes the translation of the source unit in the project to the cache.
+    pub fn store(&self, key: &str, project: &Project) -> Result<(), Error> {
+        let mut entry = CacheEntry {
+            dependencies: vec![],
+            source_units: vec![],
+            translated_definitions: project.translated_definitions.clone(),
+            rejected_definitions: project.rejected_definitions.iter().cloned().collect(),
+        };
+
+        // Keep the entry identical between runs
+        entry.rejected_definitions.sort();
+
+        for (path, source_unit) in project.solidity_source_units.iter() {
+            let source = std::fs::read_to_string(path)
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+            entry.dependencies.push((path.to_path_buf(), get_digest(source.as_str())));
+            entry.source_units.push((path.to_path_buf(), source_unit.clone()));
+        }
+
+        std::fs::create_dir_all(&self.directory)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        let mut buffer = vec![];
+
+        serde_yaml::with::singleton_map_recursive::serialize(&entry, &mut serde_yaml::Serializer::new(&mut buffer))
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        std::fs::write(self.get_entry_path(key), buffer)
+            .map_err(|e| Error::Wrapped(Box::new(e)))
+    }
+}
+
+fn get_digest(source: &str) -> String {
+    Keccak256::digest(source.as_bytes()).iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,228 @@
+use crate::{
+    errors::Error,
+    sway,
+    translate::TranslatedDefinition,
+    upgrade_diff::{self, ItemChange, ItemChangeKind},
+};
+use sha3::{Digest, Keccak256};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// The name of the file in the output directory that records the generated items of the latest run.
+pub const MANIFEST_FILE: &str = "charcoal_manifest.toml";
+
+/// A generated Sway item, recorded by the digest of its generated code.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManifestItem {
+    pub kind: String,
+    pub name: String,
+    pub digest: String,
+}
+
+/// The generated items of a translated definition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManifestDefinition {
+    pub name: String,
+    pub path: PathBuf,
+    pub todo_count: usize,
+    pub items: Vec<ManifestItem>,
+}
+
+/// A record of the items generated by a run, which can be compared against the manifest of another run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Manifest {
+    pub definitions: Vec<ManifestDefinition>,
+}
+
+impl Manifest {
+    /// Creates a manifest of the supplied translated definitions, keyed by their output names.
+    pub fn new(translated_definitions: &[TranslatedDefinition], output_names: &HashMap<(PathBuf, String), String>) -> Self {
+        let mut definitions = vec![];
+
+        for translated_definition in translated_definitions.iter() {
+            let name = output_names.get(&(translated_definition.path.clone(), translated_definition.name.clone()))
+                .cloned()
+                .unwrap_or_else(|| translated_definition.name.clone());
+
+            if definitions.iter().any(|d: &ManifestDefinition| d.name == name) {
+                continue;
+            }
+
+            let module: sway::Module = translated_definition.clone().into();
+
+            definitions.push(ManifestDefinition {
+                name,
+                path: translated_definition.path.clone(),
+                todo_count: sway::TabbedDisplayer(&module).to_string().matches("todo!").count(),
+                items: upgrade_diff::collect_module_items(&module).into_iter()
+                    .map(|(kind, name, code)| ManifestItem {
+                        kind: kind.into(),
+                        name,
+                        digest: Keccak256::digest(code.as_bytes()).iter().map(|b| format!("{b:02x}")).collect(),
+                    })
+                    .collect(),
+            });
+        }
+
+        Self { definitions }
+    }
+
+    /// Reads a manifest from a file, or from the manifest file of an output directory.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let mut path = path.as_ref().to_path_buf();
+
+        if path.is_dir() {
+            path = path.join(MANIFEST_FILE);
+        }
+
+        let invalid_manifest = |message: &str| Error::Wrapped(Box::new(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("error: Invalid manifest \"{}\": {message}", path.to_string_lossy()),
+        )));
+
+        let table: toml::Table = std::fs::read_to_string(&path)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?
+            .parse()
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        let get_str = |table: &toml::Table, key: &str| table.get(key).and_then(toml::Value::as_str).map(String::from);
+
+        let mut definitions = vec![];
+
+        for definition in table.get("definitions").and_then(toml::Value::as_array).cloned().unwrap_or_default() {
+            let Some(definition) = definition.as_table() else {
+                return Err(invalid_manifest("expected a table of definitions"));
+            };
+
+            let (Some(name), Some(definition_path), Some(todo_count)) = (
+                get_str(definition, "name"),
+                get_str(definition, "path"),
+                definition.get("todo_count").and_then(toml::Value::as_integer),
+            ) else {
+                return Err(invalid_manifest("expected `name`, `path` and `todo_count` in each definition"));
+            };
+
+            let mut items = vec![];
+
+            for item in definition.get("items").and_then(toml::Value::as_array).cloned().unwrap_or_default() {
+                let (Some(kind), Some(name), Some(digest)) = item.as_table()
+                    .map(|item| (get_str(item, "kind"), get_str(item, "name"), get_str(item, "digest")))
+                    .unwrap_or_default()
+                else {
+                    return Err(invalid_manifest("expected `kind`, `name` and `digest` in each item"));
+                };
+
+                items.push(ManifestItem { kind, name, digest });
+            }
+
+            definitions.push(ManifestDefinition {
+                name,
+                path: PathBuf::from(definition_path),
+                todo_count: todo_count as usize,
+                items,
+            });
+        }
+
+        Ok(Self { definitions })
+    }
+
+    /// Writes the manifest to a file.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let definitions = self.definitions.iter()
+            .map(|definition| {
+                let mut table = toml::Table::new();
+                table.insert("name".into(), toml::Value::String(definition.name.clone()));
+                table.insert("path".into(), toml::Value::String(definition.path.to_string_lossy().to_string()));
+                table.insert("todo_count".into(), toml::Value::Integer(definition.todo_count as i64));
+
+                table.insert("items".into(), toml::Value::Array(definition.items.iter().map(|item| {
+                    let mut table = toml::Table::new();
+                    table.insert("kind".into(), toml::Value::String(item.kind.clone()));
+                    table.insert("name".into(), toml::Value::String(item.name.clone()));
+                    table.insert("digest".into(), toml::Value::String(item.digest.clone()));
+                    toml::Value::Table(table)
+                }).collect()));
+
+                toml::Value::Table(table)
+            })
+            .collect();
+
+        let mut table = toml::Table::new();
+        table.insert("definitions".into(), toml::Value::Array(definitions));
+
+        std::fs::write(path, table.to_string())
+            .map_err(|e| Error::Wrapped(Box::new(e)))
+    }
+
+    /// Merges the definitions of the supplied manifest into this one, replacing the definitions that share a name.
+    pub fn merge(&mut self, manifest: Manifest) {
+        for definition in manifest.definitions {
+            match self.definitions.iter_mut().find(|d| d.name == definition.name) {
+                Some(existing_definition) => *existing_definition = definition,
+                None => self.definitions.push(definition),
+            }
+        }
+    }
+
+    /// Gets the total number of `todo!()` expressions generated for all definitions.
+    pub fn todo_count(&self) -> usize {
+        self.definitions.iter().map(|d| d.todo_count).sum()
+    }
+}
+
+/// The changes to a translated definition between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefinitionChangelog {
+    pub name: String,
+    pub kind: ItemChangeKind,
+    pub old_todo_count: usize,
+    pub new_todo_count: usize,
+    pub changes: Vec<ItemChange>,
+}
+
+/// Compares the manifests of two runs, returning the changes of each definition that was added, removed or changed.
+pub fn diff_manifests(old_manifest: &Manifest, new_manifest: &Manifest) -> Vec<DefinitionChangelog> {
+    let mut definition_names = old_manifest.definitions.iter().map(|d| d.name.clone()).collect::<Vec<_>>();
+
+    for definition in new_manifest.definitions.iter() {
+        if !definition_names.contains(&definition.name) {
+            definition_names.push(definition.name.clone());
+        }
+    }
+
+    let collect_items = |definition: Option<&ManifestDefinition>| {
+        definition.map(|d| d.items.iter().map(|i| (i.kind.clone(), i.name.clone(), i.digest.clone())).collect::<Vec<_>>()).unwrap_or_default()
+    };
+
+    let mut result = vec![];
+
+    for name in definition_names {
+        let old_definition = old_manifest.definitions.iter().find(|d| d.name == name);
+        let new_definition = new_manifest.definitions.iter().find(|d| d.name == name);
+
+        let changes = upgrade_diff::diff_items(collect_items(old_definition).as_slice(), collect_items(new_definition).as_slice());
+
+        let old_todo_count = old_definition.map(|d| d.todo_count).unwrap_or(0);
+        let new_todo_count = new_definition.map(|d| d.todo_count).unwrap_or(0);
+
+        if changes.is_empty() && old_todo_count == new_todo_count {
+            continue;
+        }
+
+        result.push(DefinitionChangelog {
+            name,
+            kind: match (old_definition, new_definition) {
+                (None, Some(_)) => ItemChangeKind::Added,
+                (Some(_), None) => ItemChangeKind::Removed,
+                _ => ItemChangeKind::Changed,
+            },
+            old_todo_count,
+            new_todo_count,
+            changes,
+        });
+    }
+
+    result
+}
@@ -0,0 +1,60 @@
+use crate::{errors::Error, printer::PrinterOptions, project::Lint};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The name of the file containing the project-specific mappings of a translated project.
+pub const CONFIG_FILE: &str = "charcoal.toml";
+
+/// Project-specific mappings read from a `charcoal.toml` file.
+///
+/// ```toml
+/// allow = ["tx-origin", "narrow-int"]
+///
+/// [type_mappings]
+/// IERC20 = "src20::SRC20"
+///
+/// [identifier_mappings]
+/// totalSupply = "supply"
+///
+/// [printer]
+/// indent_style = "tabs"
+/// max_line_width = 100
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Maps Solidity type names to the Sway type names they are translated to. Type names with a path are imported, i.e: `src20::SRC20`.
+    pub type_mappings: HashMap<String, String>,
+
+    /// Maps Solidity identifiers to the Sway identifiers they are translated to, instead of converting them to the Sway naming convention.
+    pub identifier_mappings: HashMap<String, String>,
+
+    /// The formatting of the generated Sway code.
+    pub printer: PrinterOptions,
+
+    /// The lints to suppress the audit warnings of, in addition to the lints allowed with `--allow`.
+    pub allow: Vec<Lint>,
+}
+
+impl std::str::FromStr for Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s).map_err(|e| Error::Wrapped(Box::new(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_lints() {
+        let config: Config = "allow = [\"tx-origin\", \"eip712-domain\"]\n\n[identifier_mappings]\ntotalSupply = \"supply\"\n".parse().unwrap();
+
+        assert_eq!(config.allow, vec![Lint::TxOrigin, Lint::Eip712Domain]);
+        assert_eq!(config.identifier_mappings.get("totalSupply").map(String::as_str), Some("supply"));
+
+        assert!("allow = [\"unknown-lint\"]".parse::<Config>().is_err());
+    }
+}
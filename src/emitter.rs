@@ -0,0 +1,83 @@
+use std::{cell::RefCell, io::Write, rc::Rc};
+
+/// Where the warnings, audit warnings and notes of a translation are written, which is stderr by default.
+///
+/// Emitters are cheap to clone and clones write to the same destination, so a buffer can be read back after it was given to a project.
+#[derive(Clone, Default)]
+pub enum Emitter {
+    #[default]
+    Stderr,
+    Stdout,
+    Writer(Rc<RefCell<dyn Write>>),
+    Buffer(Rc<RefCell<Vec<u8>>>),
+    /// Discards everything that is written.
+    Silent,
+}
+
+impl std::fmt::Debug for Emitter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Emitter::Stderr => write!(f, "Stderr"),
+            Emitter::Stdout => write!(f, "Stdout"),
+            Emitter::Writer(_) => write!(f, "Writer(..)"),
+            Emitter::Buffer(buffer) => f.debug_tuple("Buffer").field(&buffer.borrow().len()).finish(),
+            Emitter::Silent => write!(f, "Silent"),
+        }
+    }
+}
+
+impl Emitter {
+    /// Creates an emitter that writes to the supplied writer.
+    pub fn writer<W: Write + 'static>(writer: W) -> Self {
+        Emitter::Writer(Rc::new(RefCell::new(writer)))
+    }
+
+    /// Creates an emitter that writes to the file at `path`, replacing its contents.
+    #[cfg(feature = "fs")]
+    pub fn file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        Ok(Self::writer(std::io::BufWriter::new(std::fs::File::create(path)?)))
+    }
+
+    /// Creates an emitter that writes to an in-memory buffer, which can be read with [Emitter::buffer_contents].
+    pub fn buffer() -> Self {
+        Emitter::Buffer(Rc::new(RefCell::new(vec![])))
+    }
+
+    /// Gets the text written to the emitter if it writes to an in-memory buffer.
+    pub fn buffer_contents(&self) -> Option<String> {
+        match self {
+            Emitter::Buffer(buffer) => Some(String::from_utf8_lossy(buffer.borrow().as_slice()).into_owned()),
+            _ => None,
+        }
+    }
+
+    /// Writes the formatted arguments followed by a newline. Write errors are ignored, since they can not be reported anywhere else.
+    pub fn emit_line(&self, arguments: std::fmt::Arguments) {
+        let _ = match self {
+            Emitter::Stderr => writeln!(std::io::stderr(), "{arguments}"),
+            Emitter::Stdout => writeln!(std::io::stdout(), "{arguments}"),
+            Emitter::Writer(writer) => writeln!(writer.borrow_mut(), "{arguments}"),
+            Emitter::Buffer(buffer) => writeln!(buffer.borrow_mut(), "{arguments}"),
+            Emitter::Silent => Ok(()),
+        };
+    }
+
+    /// Flushes the destination of the emitter, i.e: to make sure everything has been written to a file.
+    pub fn flush(&self) -> std::io::Result<()> {
+        match self {
+            Emitter::Stderr => std::io::stderr().flush(),
+            Emitter::Stdout => std::io::stdout().flush(),
+            Emitter::Writer(writer) => writer.borrow_mut().flush(),
+            Emitter::Buffer(_) | Emitter::Silent => Ok(()),
+        }
+    }
+}
+
+/// Writes a formatted line to the supplied emitter, i.e: `emitln!(project.emitter, "WARNING: ...")`.
+macro_rules! emitln {
+    ($emitter:expr, $($arg:tt)*) => {
+        $emitter.emit_line(format_args!($($arg)*))
+    };
+}
+
+pub(crate) use emitln;
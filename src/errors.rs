@@ -1,4 +1,7 @@
-use solang_parser::diagnostics::Diagnostic;
+use crate::{forc::ForcDiagnostic, line_ranges::LineRanges};
+#[cfg(feature = "syntax-check")]
+use crate::syntax_check::SwaySyntaxError;
+use solang_parser::{diagnostics::Diagnostic, pt as solidity};
 use std::path::PathBuf;
 
 #[derive(Debug)]
@@ -6,7 +9,11 @@ pub enum Error {
     Wrapped(Box<dyn std::error::Error>),
     MissingContractFile,
     LineNotFound(PathBuf, usize),
-    SolangDiagnostics(PathBuf, Vec<(usize, usize)>, Vec<Diagnostic>),
+    SolangDiagnostics(PathBuf, LineRanges, Vec<Diagnostic>),
+    UnsupportedSwayVersion(String, Vec<String>),
+    ForcDiagnostics(Vec<ForcDiagnostic>),
+    #[cfg(feature = "syntax-check")]
+    SwaySyntaxErrors(String, LineRanges, Vec<SwaySyntaxError>),
 }
 
 impl std::fmt::Display for Error {
@@ -23,28 +30,71 @@ impl std::fmt::Display for Error {
             Error::LineNotFound(path, offset) => {
                 write!(f, "error: Offset {offset} not found in file: \"{}\"", path.to_string_lossy())
             }
+
+            Error::UnsupportedSwayVersion(version, supported_versions) => {
+                write!(f, "error: Unsupported Sway version \"{version}\" (supported versions: {})", supported_versions.join(", "))
+            }
             
             Error::SolangDiagnostics(path, line_ranges, diagnostics) => {
-                let loc_offset_to_line = |offset: usize| -> usize {
-                    for (i, line_range) in line_ranges.iter().enumerate() {
-                        if offset >= line_range.0 && offset < line_range.1 {
-                            return i + 1;
+                let path = path.to_string_lossy();
+
+                for (i, diagnostic) in diagnostics.iter().enumerate() {
+                    writeln!(f, "{}: {}", diagnostic.level, diagnostic.message)?;
+
+                    let (start, end) = match diagnostic.loc {
+                        solidity::Loc::File(_, start, end) => (start, end),
+                        _ => (0, 0),
+                    };
+
+                    // Render a code frame of the offending line when the location of the diagnostic is known
+                    match line_ranges.loc_to_line_and_column(&diagnostic.loc).zip(line_ranges.code_frame(start, end)) {
+                        Some(((line, col), code_frame)) => {
+                            let gutter = " ".repeat(line.to_string().len());
+                            writeln!(f, "{gutter}--> {path}:{line}:{col}")?;
+                            write!(f, "{code_frame}")?;
+
+                            for note in diagnostic.notes.iter() {
+                                write!(f, "\n{gutter} = note: {}", note.message)?;
+                            }
+                        }
+
+                        None => {
+                            write!(f, " --> {path}")?;
+
+                            for note in diagnostic.notes.iter() {
+                                write!(f, "\n  = note: {}", note.message)?;
+                            }
                         }
                     }
 
-                    0
-                };
+                    if i < diagnostics.len() - 1 {
+                        writeln!(f)?;
+                        writeln!(f)?;
+                    }
+                }
+
+                Ok(())
+            }
 
+            Error::ForcDiagnostics(diagnostics) => {
                 for (i, diagnostic) in diagnostics.iter().enumerate() {
-                    writeln!(
-                        f,
-                        "{} at {}:{}:",
-                        diagnostic.level,
-                        path.to_string_lossy(),
-                        loc_offset_to_line(diagnostic.loc.start()),
-                    )?;
-                    
-                    write!(f, "\t{}", diagnostic.message)?;
+                    let sway_location = match diagnostic.line_and_column {
+                        Some((line, col)) => format!("{}:{}:{}", diagnostic.path.to_string_lossy(), line, col),
+                        None => format!("{}", diagnostic.path.to_string_lossy()),
+                    };
+
+                    match diagnostic.solidity_location.as_ref() {
+                        Some(solidity_location) => {
+                            writeln!(f, "error at {solidity_location}:")?;
+                            writeln!(f, "\t{}", diagnostic.message)?;
+                            write!(f, "\t(generated at {sway_location})")?;
+                        }
+
+                        None => {
+                            writeln!(f, "error at {sway_location}:")?;
+                            write!(f, "\t{}", diagnostic.message)?;
+                        }
+                    }
 
                     if i < diagnostics.len() - 1 {
                         writeln!(f)?;
@@ -53,6 +103,30 @@ impl std::fmt::Display for Error {
 
                 Ok(())
             }
+
+            #[cfg(feature = "syntax-check")]
+            Error::SwaySyntaxErrors(name, line_ranges, errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    writeln!(f, "error: {}", error.message)?;
+
+                    match line_ranges.offset_to_line_and_column(error.start).zip(line_ranges.code_frame(error.start, error.end)) {
+                        Some(((line, col), code_frame)) => {
+                            let gutter = " ".repeat(line.to_string().len());
+                            writeln!(f, "{gutter}--> generated code of `{name}`:{line}:{col}")?;
+                            write!(f, "{code_frame}")?;
+                        }
+
+                        None => write!(f, " --> generated code of `{name}`")?,
+                    }
+
+                    if i < errors.len() - 1 {
+                        writeln!(f)?;
+                        writeln!(f)?;
+                    }
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -0,0 +1,1032 @@
+use crate::{
+    errors::Error,
+    sway,
+    translate::{Attribution, TranslatedDefinition},
+};
+#[cfg(feature = "fs")]
+use crate::{
+    emitter::{emitln, Emitter},
+    line_ranges::LineRanges,
+    printer::PrinterOptions,
+    project::Project,
+    source_map::{render_module_with_source_map, source_map_to_json},
+    translate::get_function_name_signature,
+};
+#[cfg(feature = "fs")]
+use convert_case::Case;
+#[cfg(feature = "fs")]
+use solang_parser::pt as solidity;
+use sha3::{Digest, Keccak256};
+use std::path::{Path, PathBuf};
+#[cfg(feature = "fs")]
+use std::{
+    collections::{BTreeMap, HashMap},
+    process::Command,
+};
+
+/// The Sway version that generated Forc projects target by default.
+pub const DEFAULT_SWAY_VERSION: &str = "0.51.1";
+
+pub const SWAY_GIT_URL: &str = "https://github.com/fuellabs/sway";
+pub const SWAY_LIBS_GIT_URL: &str = "https://github.com/fuellabs/sway-libs";
+pub const SWAY_STANDARDS_GIT_URL: &str = "https://github.com/fuellabs/sway-standards";
+
+/// The name of the file listing the third-party sources of the generated projects.
+pub const THIRD_PARTY_NOTICES_FILE: &str = "THIRD_PARTY_NOTICES.md";
+
+/// The names and licenses of the known git repositories that generated projects depend on.
+const KNOWN_DEPENDENCY_SOURCES: &[(&str, &str, &str)] = &[
+    // (git url, name, license)
+    (SWAY_LIBS_GIT_URL, "sway-libs", "Apache-2.0"),
+    (SWAY_STANDARDS_GIT_URL, "sway-standards", "Apache-2.0"),
+];
+
+/// Known-compatible release tags of `std`, `sway-libs` and `sway-standards` for each supported Sway version.
+const COMPATIBLE_RELEASE_TAGS: &[(&str, &str, &str, &str)] = &[
+    // (sway version, std, sway-libs, sway-standards)
+    ("0.49.3", "v0.49.3", "v0.18.0", "v0.3.3"),
+    ("0.50.0", "v0.50.0", "v0.19.2", "v0.4.1"),
+    ("0.51.1", "v0.51.1", "v0.20.0", "v0.4.3"),
+];
+
+/// The exact dependency revisions a generated Forc project is pinned to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PinnedDependencies {
+    pub sway_version: String,
+    pub std_tag: String,
+    pub sway_libs_tag: String,
+    pub sway_standards_tag: String,
+}
+
+impl PinnedDependencies {
+    /// Resolves the dependency revisions that are compatible with the specified Sway version.
+    pub fn resolve(sway_version: &str) -> Result<Self, Error> {
+        let sway_version = sway_version.trim_start_matches('v');
+
+        let Some((_, std_tag, sway_libs_tag, sway_standards_tag)) = COMPATIBLE_RELEASE_TAGS.iter().find(|x| x.0 == sway_version) else {
+            return Err(Error::UnsupportedSwayVersion(
+                sway_version.into(),
+                COMPATIBLE_RELEASE_TAGS.iter().map(|x| x.0.to_string()).collect(),
+            ));
+        };
+
+        Ok(Self {
+            sway_version: sway_version.into(),
+            std_tag: std_tag.to_string(),
+            sway_libs_tag: sway_libs_tag.to_string(),
+            sway_standards_tag: sway_standards_tag.to_string(),
+        })
+    }
+
+    /// Reads the pinned dependency revisions from the lock section of a previously generated `Forc.toml`, if any.
+    #[cfg(feature = "fs")]
+    pub fn from_manifest<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let manifest: toml::Table = std::fs::read_to_string(path).ok()?.parse().ok()?;
+
+        let charcoal = manifest.get("project")?.get("metadata")?.get("charcoal")?;
+        let lock = charcoal.get("lock")?;
+
+        let get_tag = |name: &str| -> Option<String> {
+            Some(lock.get(name)?.get("tag")?.as_str()?.to_string())
+        };
+
+        Some(Self {
+            sway_version: charcoal.get("sway-version")?.as_str()?.to_string(),
+            std_tag: get_tag("std")?,
+            sway_libs_tag: get_tag("sway-libs")?,
+            sway_standards_tag: get_tag("sway-standards")?,
+        })
+    }
+
+    /// Gets the pinned `std` dependency declaration.
+    pub fn std_dependency(&self) -> String {
+        format!("std = {{ git = \"{SWAY_GIT_URL}\", tag = \"{}\" }}", self.std_tag)
+    }
+
+    /// Pins a dependency declaration from one of the known git repositories to its compatible release tag.
+    ///
+    /// Dependency declarations from unknown sources are returned unchanged.
+    pub fn pin_dependency(&self, dependency: &str) -> String {
+        let Ok(mut table) = dependency.parse::<toml::Table>() else {
+            return dependency.into();
+        };
+
+        let Some((name, toml::Value::Table(fields))) = table.iter_mut().next() else {
+            return dependency.into();
+        };
+
+        let Some(git) = fields.get("git").and_then(|x| x.as_str()).map(|x| x.trim_end_matches('/').to_lowercase()) else {
+            return dependency.into();
+        };
+
+        let tag = match git.as_str() {
+            SWAY_GIT_URL => &self.std_tag,
+            SWAY_LIBS_GIT_URL => &self.sway_libs_tag,
+            SWAY_STANDARDS_GIT_URL => &self.sway_standards_tag,
+            _ => return dependency.into(),
+        };
+
+        fields.remove("branch");
+        fields.remove("rev");
+        fields.insert("tag".into(), toml::Value::String(tag.clone()));
+
+        format!("{name} = {}", toml::Value::Table(fields.clone()))
+    }
+
+    /// Gets the lock section to record in the project metadata of a generated `Forc.toml`.
+    pub fn lock_section(&self) -> String {
+        format!(
+            "[project.metadata.charcoal]\n\
+            sway-version = \"{}\"\n\
+            \n\
+            [project.metadata.charcoal.lock]\n\
+            std = {{ git = \"{SWAY_GIT_URL}\", tag = \"{}\" }}\n\
+            sway-libs = {{ git = \"{SWAY_LIBS_GIT_URL}\", tag = \"{}\" }}\n\
+            sway-standards = {{ git = \"{SWAY_STANDARDS_GIT_URL}\", tag = \"{}\" }}\n",
+            self.sway_version,
+            self.std_tag,
+            self.sway_libs_tag,
+            self.sway_standards_tag,
+        )
+    }
+}
+
+/// The name of the library project that the ABIs shared by multiple generated projects are written to.
+pub const SHARED_ABIS_PROJECT_NAME: &str = "shared_abis";
+
+/// The Sway type names that a shared ABI can use without declaring any types of its own.
+const SHARED_ABI_TYPE_NAMES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u256", "b256", "bool",
+    "Address", "ContractId", "Identity", "AssetId",
+    "String", "Bytes", "Vec", "Option", "Result",
+];
+
+/// Collects the external ABIs that are declared by more than one translated definition, which are written to a shared library project
+/// instead of being duplicated in the module of each definition.
+///
+/// An ABI is only shared when all of its declarations are identical, its functions only use types of the standard library,
+/// and the ABIs it inherits are shared as well.
+pub fn collect_shared_abis(translated_definitions: &[TranslatedDefinition]) -> Vec<sway::Abi> {
+    let mut declarations: Vec<(sway::Abi, usize, bool)> = vec![];
+
+    for translated_definition in translated_definitions.iter() {
+        for abi in translated_definition.abis.iter() {
+            match declarations.iter_mut().find(|(x, _, _)| x.name == abi.name) {
+                Some((x, count, is_identical)) => {
+                    *count += 1;
+                    *is_identical &= x == abi;
+                }
+
+                None => declarations.push((abi.clone(), 1, true)),
+            }
+        }
+    }
+
+    let mut shared_abis = declarations.into_iter()
+        .filter(|(abi, count, is_identical)| *count > 1 && *is_identical && abi.functions.iter().all(|f| {
+            f.parameters.entries.iter().all(|p| p.type_name.as_ref().is_none_or(is_shared_abi_type_name))
+                && f.return_type.as_ref().is_none_or(is_shared_abi_type_name)
+        }))
+        .map(|(abi, _, _)| abi)
+        .collect::<Vec<_>>();
+
+    // Keep removing ABIs that inherit an ABI that is not shared until none are left
+    while let Some(index) = shared_abis.iter().position(|abi| abi.inherits.iter().any(|i| !shared_abis.iter().any(|x| x.name == *i))) {
+        shared_abis.remove(index);
+    }
+
+    shared_abis.sort_by(|a, b| a.name.cmp(&b.name));
+    shared_abis
+}
+
+/// Checks if a type name only consists of types of the standard library, which a shared ABI can use.
+fn is_shared_abi_type_name(type_name: &sway::TypeName) -> bool {
+    match type_name {
+        sway::TypeName::Undefined => false,
+
+        sway::TypeName::Identifier { name, generic_parameters } => {
+            SHARED_ABI_TYPE_NAMES.contains(&name.as_str())
+                && generic_parameters.as_ref().is_none_or(|g| g.entries.iter().all(|p| is_shared_abi_type_name(&p.type_name)))
+        }
+
+        sway::TypeName::Array { type_name, .. } => is_shared_abi_type_name(type_name),
+        sway::TypeName::Tuple { type_names } => type_names.iter().all(is_shared_abi_type_name),
+        sway::TypeName::StringSlice | sway::TypeName::StringArray { .. } => true,
+    }
+}
+
+/// Writes the library project containing the ABIs shared by multiple generated projects to the output directory.
+///
+/// The project is only written if at least one ABI is shared.
+#[cfg(feature = "fs")]
+pub fn generate_shared_abis_project<P: AsRef<Path>>(
+    output_directory: P,
+    sway_version: Option<&String>,
+    shared_abis: &[sway::Abi],
+    printer_options: &PrinterOptions,
+) -> Result<(), Error> {
+    if shared_abis.is_empty() {
+        return Ok(());
+    }
+
+    let output_directory = crate::get_canonical_path(output_directory, true, true)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    let project_path = crate::get_canonical_path(output_directory.join(SHARED_ABIS_PROJECT_NAME), true, true)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    let pinned_dependencies = resolve_pinned_dependencies(sway_version, &project_path)?;
+
+    let mut module = sway::Module {
+        kind: sway::ModuleKind::Library,
+        items: vec![],
+    };
+
+    // Only the standard library types outside of the prelude need to be imported
+    let uses_type_name = |name: &str| shared_abis.iter().flat_map(|abi| abi.functions.iter()).any(|f| {
+        f.parameters.entries.iter().filter_map(|p| p.type_name.as_ref()).chain(f.return_type.as_ref())
+            .any(|t| t.to_string().contains(name))
+    });
+
+    for (type_name, path) in [("String", "string"), ("Bytes", "bytes")] {
+        if uses_type_name(type_name) {
+            module.items.push(sway::ModuleItem::Use(sway::Use {
+                is_public: false,
+                tree: sway::UseTree::Path {
+                    prefix: "std".into(),
+                    suffix: Box::new(sway::UseTree::Path {
+                        prefix: path.into(),
+                        suffix: Box::new(sway::UseTree::Name { name: type_name.into() }),
+                    }),
+                },
+            }));
+        }
+    }
+
+    for abi in shared_abis.iter() {
+        module.items.push(sway::ModuleItem::Abi(abi.clone()));
+    }
+
+    let src_dir_path = crate::get_canonical_path(project_path.join("src"), true, true)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    std::fs::write(src_dir_path.join("lib.sw"), format!("{}{}", get_provenance_header(None, None), printer_options.print(&module)))
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    write_project_files(&project_path, SHARED_ABIS_PROJECT_NAME, "lib.sw", &pinned_dependencies, &[pinned_dependencies.std_dependency()])
+}
+
+/// Removes the shared ABIs declared by a translated definition, importing them from the shared library project instead.
+#[cfg(feature = "fs")]
+fn use_shared_abis(translated_definition: &mut TranslatedDefinition, shared_abis: &[sway::Abi]) {
+    let abi_names = translated_definition.abis.iter()
+        .filter(|abi| shared_abis.iter().any(|x| x.name == abi.name))
+        .map(|abi| abi.name.clone())
+        .collect::<Vec<_>>();
+
+    if abi_names.is_empty() {
+        return;
+    }
+
+    translated_definition.abis.retain(|abi| !abi_names.contains(&abi.name));
+
+    for abi_name in abi_names {
+        translated_definition.ensure_use_declared(format!("{SHARED_ABIS_PROJECT_NAME}::{abi_name}").as_str());
+    }
+
+    translated_definition.ensure_dependency_declared(format!("{SHARED_ABIS_PROJECT_NAME} = {{ path = \"../{SHARED_ABIS_PROJECT_NAME}\" }}").as_str());
+}
+
+/// Pins dependencies to the requested Sway version, or the version recorded when the project was last generated.
+#[cfg(feature = "fs")]
+fn resolve_pinned_dependencies(sway_version: Option<&String>, project_path: &Path) -> Result<PinnedDependencies, Error> {
+    match sway_version {
+        Some(sway_version) => PinnedDependencies::resolve(sway_version),
+        None => match PinnedDependencies::from_manifest(project_path.join("Forc.toml")) {
+            Some(pinned_dependencies) => Ok(pinned_dependencies),
+            None => PinnedDependencies::resolve(DEFAULT_SWAY_VERSION),
+        },
+    }
+}
+
+/// Writes the `.gitignore` and `Forc.toml` files of a generated project.
+#[cfg(feature = "fs")]
+fn write_project_files(
+    project_path: &Path,
+    project_name: &str,
+    entry: &str,
+    pinned_dependencies: &PinnedDependencies,
+    dependencies: &[String],
+) -> Result<(), Error> {
+    std::fs::write(
+        project_path.join(".gitignore"),
+        "out\ntarget\nForc.lock\n",
+    )
+    .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    std::fs::write(
+        project_path.join("Forc.toml"),
+        format!(
+            "[project]\n\
+            authors = [\"\"]\n\
+            entry = \"{entry}\"\n\
+            license = \"Apache-2.0\"\n\
+            name = \"{project_name}\"\n\
+            \n\
+            {}\
+            \n\
+            [dependencies]\n\
+            {}\
+            \n\
+            ",
+            pinned_dependencies.lock_section(),
+            dependencies.join("\n"),
+        ),
+    )
+    .map_err(|e| Error::Wrapped(Box::new(e)))
+}
+
+/// Writes a Forc project for each translated definition of the source unit to the output directory
+///
+/// The project of each definition is named after its entry in `output_names` (keyed by source unit path and definition name), or the definition name if it has none.
+/// The ABIs in `shared_abis` are imported from the shared library project instead of being declared in each module.
+#[cfg(feature = "fs")]
+pub fn generate_forc_project<P1: AsRef<Path>, P2: AsRef<Path>>(
+    project: &mut Project,
+    output_directory: P1,
+    definition_name: Option<&String>,
+    sway_version: Option<&String>,
+    source_unit_path: P2,
+    output_names: &HashMap<(PathBuf, String), String>,
+    shared_abis: &[sway::Abi],
+) -> Result<(), Error> {
+    let output_directory = crate::get_canonical_path(output_directory, true, true)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    for mut translated_definition in project.collect_translated_definitions(definition_name, source_unit_path) {
+        use_shared_abis(&mut translated_definition, shared_abis);
+
+        let output_name = output_names.get(&(translated_definition.path.clone(), translated_definition.name.clone())).unwrap_or(&translated_definition.name);
+        let definition_snake_name = crate::translate_naming_convention(output_name.as_str(), Case::Snake);
+        let dependencies = translated_definition.dependencies.clone();
+        let provenance_header = get_provenance_header(
+            Some(translated_definition.path.as_path()),
+            project.line_ranges.get(&translated_definition.path).map(LineRanges::source),
+        );
+        let attribution_header = get_attribution_header(translated_definition.attributions.as_slice());
+        let submodules = translated_definition.submodules.clone();
+        
+        let module: sway::Module = translated_definition.clone().into();
+
+        let (code, source_map) = if project.source_map {
+            let (code, mut mappings) = render_module_with_source_map(project, &translated_definition, &module, &project.printer_options);
+
+            // Account for the headers preceding the code
+            let header_line_count = format!("{provenance_header}{attribution_header}").lines().count();
+
+            for mapping in mappings.iter_mut() {
+                mapping.output_lines.0 += header_line_count;
+                mapping.output_lines.1 += header_line_count;
+            }
+
+            (code, Some(source_map_to_json("src/main.sw", mappings.as_slice())))
+        } else {
+            (project.printer_options.print(&module), None)
+        };
+
+        let project_path = crate::get_canonical_path(output_directory.join(definition_snake_name.as_str()), true, true)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        
+        let pinned_dependencies = resolve_pinned_dependencies(sway_version, &project_path)?;
+
+        let mut dependencies = dependencies.iter()
+            .map(|d| pinned_dependencies.pin_dependency(d))
+            .collect::<Vec<_>>();
+
+        dependencies.insert(0, pinned_dependencies.std_dependency());
+
+        let src_dir_path = crate::get_canonical_path(project_path.join("src"), true, true)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        
+        std::fs::write(
+            src_dir_path.join("main.sw"),
+            format!("{provenance_header}{attribution_header}{code}"),
+        )
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        if !translated_definition.renamed_identifiers.is_empty() {
+            std::fs::write(project_path.join("renames.json"), get_renames_json(&translated_definition))
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+            std::fs::write(project_path.join("RENAMES.md"), get_renames_table(&translated_definition))
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        if !translated_definition.warnings.is_empty() {
+            std::fs::write(project_path.join("warnings.json"), get_warnings_json(&translated_definition))
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        if let Some(source_map) = source_map {
+            std::fs::write(project_path.join("source_map.json"), source_map)
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        for (submodule_name, submodule) in submodules.iter() {
+            std::fs::write(
+                src_dir_path.join(format!("{submodule_name}.sw")),
+                format!("{provenance_header}{}", project.printer_options.print(submodule)),
+            )
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        write_project_files(&project_path, &definition_snake_name, "main.sw", &pinned_dependencies, &dependencies)?;
+    }
+
+    Ok(())
+}
+
+/// An error reported by `forc build` for a generated project.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForcDiagnostic {
+    /// The path of the generated Sway file, or of the generated project if the error has no location.
+    pub path: PathBuf,
+    pub line_and_column: Option<(usize, usize)>,
+    pub message: String,
+    /// The location of the Solidity function or contract that the erroneous Sway code was translated from, if known.
+    pub solidity_location: Option<String>,
+}
+
+/// Checks if `forc` can be invoked to verify generated projects.
+#[cfg(feature = "fs")]
+pub fn is_forc_available() -> bool {
+    Command::new("forc").arg("--version").output().is_ok()
+}
+
+/// Runs `forc fmt` on the Forc project of each translated definition of the source unit that was written to the output directory
+///
+/// Projects that can not be formatted are left as they were written, reporting a warning.
+#[cfg(feature = "fs")]
+pub fn format_forc_project<P1: AsRef<Path>, P2: AsRef<Path>>(
+    project: &Project,
+    output_directory: P1,
+    definition_name: Option<&String>,
+    source_unit_path: P2,
+    output_names: &HashMap<(PathBuf, String), String>,
+) -> Result<(), Error> {
+    for translated_definition in project.collect_translated_definitions(definition_name, source_unit_path) {
+        let output_name = output_names.get(&(translated_definition.path.clone(), translated_definition.name.clone())).unwrap_or(&translated_definition.name);
+        let definition_snake_name = crate::translate_naming_convention(output_name.as_str(), Case::Snake);
+
+        run_forc_fmt(&project.emitter, output_directory.as_ref().join(definition_snake_name.as_str()))?;
+    }
+
+    Ok(())
+}
+
+/// Runs `forc fmt` on the library project containing the shared ABIs, if it was written to the output directory.
+#[cfg(feature = "fs")]
+pub fn format_shared_abis_project<P: AsRef<Path>>(emitter: &Emitter, output_directory: P) -> Result<(), Error> {
+    let project_path = output_directory.as_ref().join(SHARED_ABIS_PROJECT_NAME);
+
+    if !project_path.exists() {
+        return Ok(());
+    }
+
+    run_forc_fmt(emitter, project_path)
+}
+
+#[cfg(feature = "fs")]
+fn run_forc_fmt<P: AsRef<Path>>(emitter: &Emitter, project_path: P) -> Result<(), Error> {
+    let project_path = crate::get_canonical_path(project_path, true, false)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    let output = Command::new("forc")
+        .arg("fmt")
+        .arg("--path")
+        .arg(&project_path)
+        .output()
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    if !output.status.success() {
+        let output = format!("{}\n{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+
+        emitln!(
+            emitter,
+            "WARNING: Failed to format \"{}\": {}",
+            project_path.to_string_lossy(),
+            output.lines().map(str::trim).rfind(|l| !l.is_empty()).unwrap_or("`forc fmt` failed"),
+        );
+    }
+
+    Ok(())
+}
+
+/// Runs `forc build` on the Forc project of each translated definition of the source unit that was written to the output directory
+///
+/// Returns the errors reported by the compiler, along with the location of the Solidity code that each error originates from.
+#[cfg(feature = "fs")]
+pub fn verify_forc_project<P1: AsRef<Path>, P2: AsRef<Path>>(
+    project: &Project,
+    output_directory: P1,
+    definition_name: Option<&String>,
+    source_unit_path: P2,
+    output_names: &HashMap<(PathBuf, String), String>,
+) -> Result<Vec<ForcDiagnostic>, Error> {
+    let mut diagnostics = vec![];
+
+    for translated_definition in project.collect_translated_definitions(definition_name, source_unit_path) {
+        let output_name = output_names.get(&(translated_definition.path.clone(), translated_definition.name.clone())).unwrap_or(&translated_definition.name);
+        let definition_snake_name = crate::translate_naming_convention(output_name.as_str(), Case::Snake);
+        let project_path = crate::get_canonical_path(output_directory.as_ref().join(definition_snake_name.as_str()), true, false)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        let output = Command::new("forc")
+            .arg("build")
+            .arg("--path")
+            .arg(&project_path)
+            .output()
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        if output.status.success() {
+            continue;
+        }
+
+        let output = format!("{}\n{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+        let mut project_diagnostics = parse_forc_diagnostics(output.as_str());
+
+        // Report the last line of output if the errors could not be parsed, i.e: when a dependency could not be fetched
+        if project_diagnostics.is_empty() {
+            project_diagnostics.push(ForcDiagnostic {
+                path: project_path.clone(),
+                line_and_column: None,
+                message: output.lines().map(str::trim).rfind(|l| !l.is_empty()).unwrap_or("`forc build` failed").to_string(),
+                solidity_location: None,
+            });
+        }
+
+        for diagnostic in project_diagnostics.iter_mut() {
+            // Only errors in the generated project can be traced back to its Solidity source
+            if !diagnostic.path.starts_with(&project_path) {
+                continue;
+            }
+
+            let function_name = diagnostic.line_and_column.and_then(|(line, _)| {
+                let source = std::fs::read_to_string(&diagnostic.path).ok()?;
+                find_enclosing_function_name(source.as_str(), line)
+            });
+
+            diagnostic.solidity_location = function_name
+                .and_then(|function_name| find_solidity_function_location(project, &translated_definition, function_name.as_str()))
+                .or_else(|| find_solidity_contract_location(project, &translated_definition));
+        }
+
+        diagnostics.extend(project_diagnostics);
+    }
+
+    Ok(diagnostics)
+}
+
+/// Parses the errors from the output of `forc build`, i.e:
+///
+/// ```text
+/// error
+///   --> /path/to/project/src/main.sw:12:5
+///    |
+/// 12 |     let x: u8 = true;
+///    |                 ^^^^ Mismatched types.
+/// ```
+#[cfg(feature = "fs")]
+fn parse_forc_diagnostics(output: &str) -> Vec<ForcDiagnostic> {
+    let mut diagnostics: Vec<ForcDiagnostic> = vec![];
+    let mut in_message = false;
+
+    for line in output.lines() {
+        let trimmed_line = line.trim();
+
+        // Start a new diagnostic at each error header
+        if trimmed_line == "error" || trimmed_line.starts_with("error:") {
+            diagnostics.push(ForcDiagnostic {
+                path: PathBuf::new(),
+                line_and_column: None,
+                message: trimmed_line.trim_start_matches("error").trim_start_matches(':').trim().to_string(),
+                solidity_location: None,
+            });
+
+            in_message = false;
+            continue;
+        }
+
+        let Some(diagnostic) = diagnostics.last_mut() else { continue };
+
+        if let Some(location) = trimmed_line.strip_prefix("--> ") {
+            let mut parts = location.rsplitn(3, ':');
+            let column = parts.next().and_then(|x| x.parse::<usize>().ok());
+            let line = parts.next().and_then(|x| x.parse::<usize>().ok());
+
+            match (parts.next(), line, column) {
+                (Some(path), Some(line), Some(column)) => {
+                    diagnostic.path = PathBuf::from(path);
+                    diagnostic.line_and_column = Some((line, column));
+                }
+
+                _ => diagnostic.path = PathBuf::from(location),
+            }
+
+            continue;
+        }
+
+        // The message of a labelled error follows the carets underlining its code
+        if let Some((_, label)) = trimmed_line.split_once('|') {
+            let label = label.trim();
+
+            if label.starts_with('^') {
+                let label = label.trim_start_matches('^').trim();
+
+                if !label.is_empty() {
+                    diagnostic.message = label.to_string();
+                    in_message = true;
+                }
+            } else {
+                in_message = false;
+            }
+
+            continue;
+        }
+
+        if trimmed_line.starts_with("____") || trimmed_line.is_empty() {
+            in_message = false;
+            continue;
+        }
+
+        // Append the continuation lines of the message
+        if in_message {
+            diagnostic.message = format!("{} {}", diagnostic.message, trimmed_line.split_whitespace().collect::<Vec<_>>().join(" "));
+        }
+    }
+
+    diagnostics.retain(|d| !d.message.is_empty() || d.line_and_column.is_some());
+    diagnostics
+}
+
+/// Finds the name of the function that encloses the 1-based `line` of the Sway source.
+#[cfg(feature = "fs")]
+fn find_enclosing_function_name(source: &str, line: usize) -> Option<String> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let get_indent = |line: &str| line.len() - line.trim_start().len();
+
+    let get_function_name = |line: &str| {
+        let line = line.trim();
+        let declaration = line.strip_prefix("pub ").unwrap_or(line).strip_prefix("fn ")?;
+        Some(declaration.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect::<String>())
+    };
+
+    let error_line = lines.get(line.checked_sub(1)?)?;
+
+    // Check if the error is in the declaration of a function
+    if let Some(function_name) = get_function_name(error_line) {
+        return Some(function_name);
+    }
+
+    let mut indent = get_indent(error_line);
+
+    // Walk back through the enclosing blocks of the line until a function is found
+    for line in lines[..line - 1].iter().rev() {
+        if line.trim().is_empty() || get_indent(line) >= indent {
+            continue;
+        }
+
+        if let Some(function_name) = get_function_name(line) {
+            return Some(function_name);
+        }
+
+        indent = get_indent(line);
+
+        if indent == 0 {
+            break;
+        }
+    }
+
+    None
+}
+
+/// Finds the location of the Solidity function that was translated to the function of the translated definition with the supplied name.
+///
+/// Functions inherited as toplevel functions are prefixed with the name of the contract they were declared in, i.e: `erc_20_transfer`.
+#[cfg(feature = "fs")]
+fn find_solidity_function_location(project: &Project, translated_definition: &TranslatedDefinition, function_name: &str) -> Option<String> {
+    let origin = translated_definition.function_origins.get(function_name).unwrap_or(&translated_definition.name);
+
+    for (path, source_unit) in project.solidity_source_units.iter() {
+        for source_unit_part in source_unit.0.iter() {
+            let solidity::SourceUnitPart::ContractDefinition(contract_definition) = source_unit_part else { continue };
+            let Some(contract_name) = contract_definition.name.as_ref().map(|i| &i.name) else { continue };
+
+            let prefix = format!("{}_", crate::translate_naming_convention(contract_name, Case::Snake));
+
+            let mut names = vec![];
+
+            if contract_name == origin {
+                names.push(function_name);
+            }
+
+            if let Some(name) = function_name.strip_prefix(prefix.as_str()) {
+                names.push(name);
+            }
+
+            if names.is_empty() {
+                continue;
+            }
+
+            for part in contract_definition.parts.iter() {
+                let solidity::ContractPart::FunctionDefinition(function_definition) = part else { continue };
+
+                let is_match = names.iter().any(|name| match function_definition.ty {
+                    solidity::FunctionTy::Constructor => *name == "constructor",
+                    _ => translated_definition.function_names.get(&get_function_name_signature(function_definition)).map(String::as_str) == Some(*name),
+                });
+
+                if is_match {
+                    return Some(project.loc_to_file_location_string(path, &function_definition.loc));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the location of the Solidity contract that the translated definition was translated from.
+#[cfg(feature = "fs")]
+fn find_solidity_contract_location(project: &Project, translated_definition: &TranslatedDefinition) -> Option<String> {
+    let source_unit = project.solidity_source_units.get_by_path(&translated_definition.path)?;
+
+    source_unit.0.iter().find_map(|source_unit_part| match source_unit_part {
+        solidity::SourceUnitPart::ContractDefinition(contract_definition) if contract_definition.name.as_ref().map(|i| &i.name) == Some(&translated_definition.name) => {
+            Some(project.loc_to_file_location_string(&translated_definition.path, &contract_definition.loc))
+        }
+
+        _ => None,
+    })
+}
+
+/// Gets the attribution of the known third-party source with the supplied git url, i.e: [SWAY_LIBS_GIT_URL]
+pub fn get_known_attribution(git_url: &str) -> Option<Attribution> {
+    let (url, name, license) = KNOWN_DEPENDENCY_SOURCES.iter().find(|(url, _, _)| *url == git_url)?;
+
+    Some(Attribution {
+        name: name.to_string(),
+        url: url.to_string(),
+        license: license.to_string(),
+    })
+}
+
+/// Gets the attributions of a translated definition, including the known third-party sources of the dependencies it declares.
+pub fn collect_attributions(translated_definition: &TranslatedDefinition) -> Vec<Attribution> {
+    let mut attributions = translated_definition.attributions.clone();
+
+    for dependency in translated_definition.dependencies.iter() {
+        let Ok(table) = dependency.parse::<toml::Table>() else { continue };
+        let Some((_, toml::Value::Table(fields))) = table.iter().next() else { continue };
+        let Some(git) = fields.get("git").and_then(|x| x.as_str()).map(|x| x.trim_end_matches('/').to_lowercase()) else { continue };
+        let Some(attribution) = get_known_attribution(&git) else { continue };
+
+        if !attributions.contains(&attribution) {
+            attributions.push(attribution);
+        }
+    }
+
+    attributions.sort();
+    attributions
+}
+
+/// Gets the comment header for a generated source file, which carries over the license identifier of the Solidity source it was
+/// translated from (if any) and records the charcoal version and the path and content digest of the Solidity source.
+///
+/// The time the file was generated is only recorded when it is supplied through the `SOURCE_DATE_EPOCH` environment variable,
+/// so that translating the same sources twice generates identical files. The environment is only read when the `fs` feature is enabled.
+pub fn get_provenance_header(source_path: Option<&Path>, source: Option<&str>) -> String {
+    let mut header = String::new();
+
+    if let Some(license) = source.and_then(find_license_identifier) {
+        header.push_str(&format!("// SPDX-License-Identifier: {license}\n"));
+    }
+
+    match source_path {
+        Some(source_path) => {
+            let source_path = crate::get_display_path(source_path);
+            header.push_str(&format!("// Generated by charcoal {} from {}\n", env!("CARGO_PKG_VERSION"), source_path.to_string_lossy()));
+        }
+
+        None => header.push_str(&format!("// Generated by charcoal {}\n", env!("CARGO_PKG_VERSION"))),
+    }
+
+    if let Some(source) = source {
+        let digest = Keccak256::digest(source.as_bytes()).iter().map(|b| format!("{b:02x}")).collect::<String>();
+        header.push_str(&format!("// Source keccak256: 0x{digest}\n"));
+    }
+
+    #[cfg(feature = "fs")]
+    if let Some(seconds) = std::env::var("SOURCE_DATE_EPOCH").ok().and_then(|x| x.trim().parse::<u64>().ok()) {
+        header.push_str(&format!("// Generated at {}\n", format_timestamp(seconds)));
+    }
+
+    header.push('\n');
+    header
+}
+
+/// Finds the license identifier of a Solidity source, i.e: `MIT` in `// SPDX-License-Identifier: MIT`
+fn find_license_identifier(source: &str) -> Option<&str> {
+    let (_, rest) = source.split_once("SPDX-License-Identifier:")?;
+    let line = rest.lines().next().unwrap_or_default();
+    let license = line.split("*/").next().unwrap_or_default().trim();
+
+    if license.is_empty() {
+        None
+    } else {
+        Some(license)
+    }
+}
+
+/// Formats the seconds since the Unix epoch as an RFC 3339 UTC timestamp, i.e: `2024-03-01T12:30:00Z`
+#[cfg(feature = "fs")]
+fn format_timestamp(seconds: u64) -> String {
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+
+    // Convert the days since the epoch to a date in the proleptic Gregorian calendar
+    // (see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days)
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days % 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z", seconds / 3600, seconds % 3600 / 60, seconds % 60)
+}
+
+/// Gets the identifiers of a translated definition that were renamed in the generated code as JSON, i.e:
+///
+/// ```json
+/// {
+///   "definition": "Token",
+///   "source": "contracts/Token.sol",
+///   "renames": [
+///     { "old": "totalSupply", "new": "total_supply", "reason": "naming convention" },
+///     { "old": "transfer(address,uint256,bytes)", "new": "transfer_2", "reason": "overload" }
+///   ]
+/// }
+/// ```
+pub fn get_renames_json(translated_definition: &TranslatedDefinition) -> String {
+    let renames = translated_definition.renamed_identifiers.iter()
+        .map(|i| format!(
+            "    {{ \"old\": {}, \"new\": {}, \"reason\": {} }}",
+            crate::to_json_string(&i.old),
+            crate::to_json_string(&i.new),
+            crate::to_json_string(&i.reason.to_string()),
+        ))
+        .collect::<Vec<_>>();
+
+    format!(
+        "{{\n  \"definition\": {},\n  \"source\": {},\n  \"renames\": [{}]\n}}\n",
+        crate::to_json_string(&translated_definition.name),
+        crate::to_json_string(&crate::get_display_path(&translated_definition.path).to_string_lossy()),
+        if renames.is_empty() { String::new() } else { format!("\n{}\n  ", renames.join(",\n")) },
+    )
+}
+
+/// Gets the identifiers of a translated definition that were renamed in the generated code as a markdown table.
+pub fn get_renames_table(translated_definition: &TranslatedDefinition) -> String {
+    let mut table = format!(
+        "# Renamed identifiers of `{}`\n\nTranslated from `{}`.\n\n| Solidity | Sway | Reason |\n| --- | --- | --- |\n",
+        translated_definition.name,
+        crate::get_display_path(&translated_definition.path).to_string_lossy(),
+    );
+
+    for renamed_identifier in translated_definition.renamed_identifiers.iter() {
+        table.push_str(&format!("| `{}` | `{}` | {} |\n", renamed_identifier.old, renamed_identifier.new, renamed_identifier.reason));
+    }
+
+    table
+}
+
+/// Gets the warnings reported while translating a translated definition as JSON, i.e:
+///
+/// ```json
+/// {
+///   "definition": "Token",
+///   "source": "contracts/Token.sol",
+///   "warnings": [
+///     { "lint": "tx-origin", "severity": "high", "path": "contracts/Token.sol", "line": 12, "column": 5, "message": "..." }
+///   ]
+/// }
+/// ```
+pub fn get_warnings_json(translated_definition: &TranslatedDefinition) -> String {
+    let warnings = translated_definition.warnings.iter()
+        .map(|w| format!(
+            "    {{ \"lint\": {}, \"severity\": {}, \"path\": {}{}, \"message\": {} }}",
+            crate::to_json_string(w.lint.name()),
+            crate::to_json_string(&w.severity.to_string()),
+            crate::to_json_string(&crate::get_display_path(&w.path).to_string_lossy()),
+            match w.line_and_column {
+                Some((line, column)) => format!(", \"line\": {line}, \"column\": {column}"),
+                None => String::new(),
+            },
+            crate::to_json_string(&w.message),
+        ))
+        .collect::<Vec<_>>();
+
+    format!(
+        "{{\n  \"definition\": {},\n  \"source\": {},\n  \"warnings\": [{}]\n}}\n",
+        crate::to_json_string(&translated_definition.name),
+        crate::to_json_string(&crate::get_display_path(&translated_definition.path).to_string_lossy()),
+        if warnings.is_empty() { String::new() } else { format!("\n{}\n  ", warnings.join(",\n")) },
+    )
+}
+
+/// Gets the comment header for a generated source file containing code derived from the supplied third-party sources, if any.
+pub fn get_attribution_header(attributions: &[Attribution]) -> String {
+    if attributions.is_empty() {
+        return String::new();
+    }
+
+    let mut header = "// This file contains code derived from the following third-party sources:\n".to_string();
+
+    for attribution in attributions.iter() {
+        header.push_str(&format!("// - {} ({}), licensed under {}\n", attribution.name, attribution.url, attribution.license));
+    }
+
+    header.push_str(&format!("// See {THIRD_PARTY_NOTICES_FILE} in the output directory for details.\n\n"));
+    header
+}
+
+/// Writes a notices file to the output directory listing the third-party sources of all generated projects, along with the projects using each source.
+///
+/// The file is only written if at least one project has a third-party source.
+#[cfg(feature = "fs")]
+pub fn generate_third_party_notices<P: AsRef<Path>>(
+    output_directory: P,
+    translated_definitions: &[TranslatedDefinition],
+    output_names: &HashMap<(PathBuf, String), String>,
+) -> Result<(), Error> {
+    let mut projects_by_attribution: BTreeMap<Attribution, Vec<String>> = BTreeMap::new();
+
+    for translated_definition in translated_definitions.iter() {
+        let output_name = output_names.get(&(translated_definition.path.clone(), translated_definition.name.clone())).unwrap_or(&translated_definition.name);
+        let project_name = crate::translate_naming_convention(output_name.as_str(), Case::Snake);
+
+        for attribution in collect_attributions(translated_definition) {
+            let projects = projects_by_attribution.entry(attribution).or_default();
+
+            if !projects.contains(&project_name) {
+                projects.push(project_name.clone());
+            }
+        }
+    }
+
+    if projects_by_attribution.is_empty() {
+        return Ok(());
+    }
+
+    let mut notices = "# Third-Party Notices\n\nThe generated projects in this directory include code derived from, or depend on, the following third-party sources.\n".to_string();
+
+    for (attribution, mut projects) in projects_by_attribution {
+        projects.sort();
+
+        notices.push_str(&format!(
+            "\n## {}\n\n* Source: {}\n* License: {}\n* Used by: {}\n",
+            attribution.name,
+            attribution.url,
+            attribution.license,
+            projects.iter().map(|p| format!("`{p}`")).collect::<Vec<_>>().join(", "),
+        ));
+    }
+
+    let output_directory = crate::get_canonical_path(output_directory, true, true)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    std::fs::write(output_directory.join(THIRD_PARTY_NOTICES_FILE), notices)
+        .map_err(|e| Error::Wrapped(Box::new(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provenance_header() {
+        #[cfg(feature = "fs")]
+        {
+            assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+            assert_eq!(format_timestamp(951782400), "2000-02-29T00:00:00Z");
+            assert_eq!(format_timestamp(1709296200), "2024-03-01T12:30:00Z");
+        }
+
+        assert_eq!(find_license_identifier("// SPDX-License-Identifier: MIT\npragma solidity ^0.8.0;"), Some("MIT"));
+        assert_eq!(find_license_identifier("/* SPDX-License-Identifier: GPL-3.0-or-later */"), Some("GPL-3.0-or-later"));
+        assert_eq!(find_license_identifier("pragma solidity ^0.8.0;"), None);
+    }
+}
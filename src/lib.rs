@@ -0,0 +1,134 @@
+//! A Solidity to Sway translator.
+//!
+//! ```ignore
+//! let mut project = Project::new();
+//! project.add_source_file("contracts/Token.sol")?;
+//! project.add_source_text("contracts/Vault.sol", "pragma solidity ^0.8.0; import \"./Token.sol\"; contract Vault { ... }")?;
+//!
+//! for translated_definition in project.translate()? {
+//!     let module: sway::Module = translated_definition.into();
+//!     println!("{}", sway::TabbedDisplayer(&module));
+//! }
+//! ```
+
+#[cfg(feature = "fs")]
+pub mod archive;
+#[cfg(feature = "fs")]
+pub mod builder;
+#[cfg(feature = "fs")]
+pub mod cache;
+#[cfg(feature = "fs")]
+pub mod changelog;
+pub mod config;
+pub mod emitter;
+pub mod errors;
+pub mod forc;
+pub mod line_ranges;
+pub mod printer;
+pub mod project;
+pub mod report;
+pub mod source_map;
+pub mod source_provider;
+pub mod source_units;
+pub mod sway;
+#[cfg(feature = "syntax-check")]
+pub mod syntax_check;
+pub mod translate;
+pub mod upgrade_diff;
+
+pub use errors::Error;
+
+use convert_case::{Case, Casing};
+use std::path::Path;
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
+
+/// The keywords of Sway, which can not be used as identifiers.
+pub const SWAY_KEYWORDS: &[&str] = &[
+    "abi", "as", "asm", "break", "configurable", "const", "continue", "contract", "deref", "else", "enum", "false",
+    "fn", "for", "if", "impl", "in", "let", "library", "match", "mod", "mut", "predicate", "ptr", "pub", "ref",
+    "return", "script", "self", "Self", "slice", "storage", "str", "struct", "trait", "true", "type", "use",
+    "where", "while",
+];
+
+#[inline]
+pub fn translate_naming_convention(name: &str, case: Case) -> String {
+    if name == "_" {
+        return "_".into();
+    }
+
+    let prefix = name.chars().take_while(|c| *c == '_').collect::<String>();
+    let postfix = name.chars().rev().take_while(|c| *c == '_').collect::<String>();
+    let result = format!("{prefix}{}{postfix}", name.to_case(case));
+
+    // Escape identifiers that are Sway keywords, i.e: `impl` => `impl_`
+    if SWAY_KEYWORDS.contains(&result.as_str()) {
+        return format!("{result}_");
+    }
+
+    result
+}
+
+#[cfg(feature = "fs")]
+#[inline]
+pub fn get_canonical_path<P: AsRef<Path>>(path: P, is_dir: bool, create_if_necessary: bool) -> std::io::Result<PathBuf> {
+    let mut path_string = path.as_ref().to_string_lossy().to_string();
+
+    while path_string.contains("\\\\") {
+        path_string = path_string.replace("\\\\", "\\");
+    }
+
+    path_string = path_string.replace('\\', "/");
+
+    while path_string.contains("//") {
+        path_string = path_string.replace("//", "/");
+    }
+
+    if is_dir && !path_string.ends_with('/') {
+        path_string = format!("{path_string}/");
+    }
+
+    let path = PathBuf::from(path_string);
+
+    if create_if_necessary && !path.exists() {
+        if is_dir {
+            std::fs::create_dir_all(path.clone())?;
+        } else {
+            std::fs::File::create(path.clone())?;
+        }
+    }
+    
+    path.canonicalize()
+}
+
+/// Gets the path relative to the working directory if it is inside of it, so that it doesn't depend on where the sources are checked out.
+///
+/// Paths are displayed unchanged when the `fs` feature is disabled, since there is no working directory to resolve them against.
+pub fn get_display_path(path: &Path) -> &Path {
+    #[cfg(feature = "fs")]
+    if let Some(relative_path) = std::env::current_dir().ok().and_then(|current_dir| path.strip_prefix(current_dir).ok()) {
+        return relative_path;
+    }
+
+    path
+}
+
+/// Quotes and escapes the text as a JSON string.
+pub fn to_json_string(text: &str) -> String {
+    let mut result = String::from("\"");
+
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
@@ -0,0 +1,205 @@
+use solang_parser::pt as solidity;
+
+/// The byte order mark some editors write at the start of UTF-8 files.
+pub const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Maps byte offsets within a source file to 1-based line and column positions and back.
+///
+/// Offsets are byte offsets (as found in `solidity::Loc`), while columns are counted in characters
+/// so that positions stay accurate on lines containing multi-byte UTF-8 sequences.
+///
+/// Lines can be terminated by either `\n` or `\r\n`, and a leading byte order mark is not part of the first line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineRanges {
+    source: String,
+
+    /// The byte range of each line, excluding its line terminator
+    ranges: Vec<(usize, usize)>,
+}
+
+impl LineRanges {
+    pub fn new(source: &str) -> Self {
+        let mut ranges = vec![];
+        let mut line_start = source.strip_prefix(BYTE_ORDER_MARK).map(|_| BYTE_ORDER_MARK.len_utf8()).unwrap_or(0);
+
+        for (i, _) in source.match_indices('\n') {
+            let line_end = if source[..i].ends_with('\r') { i - 1 } else { i };
+            ranges.push((line_start, line_end));
+            line_start = i + 1;
+        }
+
+        // The final line is always present, even when it is empty
+        ranges.push((line_start, source.len()));
+
+        Self {
+            source: source.into(),
+            ranges,
+        }
+    }
+
+    /// Gets the number of lines in the source.
+    pub fn line_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Gets the byte range of the 1-based `line`, excluding its line terminator.
+    pub fn line_range(&self, line: usize) -> Option<(usize, usize)> {
+        self.ranges.get(line.checked_sub(1)?).cloned()
+    }
+
+    /// Gets the text of the 1-based `line`, excluding its line terminator.
+    pub fn line_text(&self, line: usize) -> Option<&str> {
+        let (start, end) = self.line_range(line)?;
+        self.source.get(start..end)
+    }
+
+    /// Gets the text of the source.
+    pub fn source(&self) -> &str {
+        self.source.as_str()
+    }
+
+    /// Gets the text between the byte offsets `start` and `end`.
+    pub fn text(&self, start: usize, end: usize) -> Option<&str> {
+        self.source.get(start..end)
+    }
+
+    /// Gets the 1-based line and column of the byte `offset`.
+    ///
+    /// The offset of a line terminator belongs to the line it terminates, and the offset one past the end of the source belongs to the final line.
+    pub fn offset_to_line_and_column(&self, offset: usize) -> Option<(usize, usize)> {
+        if offset > self.source.len() {
+            return None;
+        }
+
+        // Find the last line starting at or before the offset, treating offsets within the byte order mark as the start of the first line
+        let index = self.ranges.partition_point(|(start, _)| *start <= offset).saturating_sub(1);
+        let (start, _) = self.ranges[index];
+        let offset = offset.max(start);
+
+        // Count the characters between the start of the line and the offset
+        let column = self.source.get(start..offset)?.chars().count() + 1;
+
+        Some((index + 1, column))
+    }
+
+    /// Gets the 1-based line and column of the start of `loc`, or `None` if `loc` is not a file location.
+    pub fn loc_to_line_and_column(&self, loc: &solidity::Loc) -> Option<(usize, usize)> {
+        match loc {
+            solidity::Loc::File(_, start, _) => self.offset_to_line_and_column(*start),
+            _ => None,
+        }
+    }
+
+    /// Gets the 1-based lines and columns of the start and end of `loc`, or `None` if `loc` is not a file location.
+    pub fn loc_to_line_and_column_range(&self, loc: &solidity::Loc) -> Option<((usize, usize), (usize, usize))> {
+        match loc {
+            solidity::Loc::File(_, start, end) => Some((
+                self.offset_to_line_and_column(*start)?,
+                self.offset_to_line_and_column(*end)?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Gets the byte offset of the 1-based `line` and `column`.
+    pub fn line_and_column_to_offset(&self, line: usize, column: usize) -> Option<usize> {
+        let (start, end) = self.line_range(line)?;
+        let column = column.checked_sub(1)?;
+
+        self.source[start..end].char_indices()
+            .map(|(i, _)| start + i)
+            .chain(std::iter::once(end))
+            .nth(column)
+    }
+
+    /// Renders a code frame of the line containing the byte `start` offset, underlining the text up to the byte `end` offset with carets:
+    ///
+    /// ```text
+    ///   |
+    /// 4 |     uint x = ;
+    ///   |              ^
+    /// ```
+    ///
+    /// Spans continuing past the end of the line are underlined up to the end of the line, and empty spans are marked with a single caret.
+    pub fn code_frame(&self, start: usize, end: usize) -> Option<String> {
+        let (line, _) = self.offset_to_line_and_column(start)?;
+        let (line_start, line_end) = self.line_range(line)?;
+        let start = start.max(line_start);
+        let line_text = self.line_text(line)?;
+
+        // Keep tabs in the padding so the carets line up with the text above them
+        let padding = self.source.get(line_start..start)?.chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        let underline_len = self.source.get(start..end.clamp(start, line_end))
+            .map(|text| text.chars().count())
+            .unwrap_or(0)
+            .max(1);
+
+        let gutter = " ".repeat(line.to_string().len());
+
+        Some(format!(
+            "{gutter} |\n{line} | {line_text}\n{gutter} | {padding}{}",
+            "^".repeat(underline_len),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_ranges() {
+        let line_ranges = LineRanges::new("contract A {\n    string s = \"héllo\"; uint x;\n}");
+
+        assert_eq!(line_ranges.line_count(), 3);
+        assert_eq!(line_ranges.line_text(2), Some("    string s = \"héllo\"; uint x;"));
+        assert_eq!(line_ranges.offset_to_line_and_column(0), Some((1, 1)));
+        assert_eq!(line_ranges.offset_to_line_and_column(12), Some((1, 13)));
+        assert_eq!(line_ranges.offset_to_line_and_column(13), Some((2, 1)));
+
+        // `uint` follows a 2-byte character, so its column is one less than its byte distance from the start of the line
+        let offset = line_ranges.source.find("uint").unwrap();
+        assert_eq!(line_ranges.offset_to_line_and_column(offset), Some((2, 25)));
+        assert_eq!(line_ranges.line_and_column_to_offset(2, 25), Some(offset));
+
+        // The final line has no line terminator
+        assert_eq!(line_ranges.offset_to_line_and_column(line_ranges.source.len() - 1), Some((3, 1)));
+        assert_eq!(line_ranges.offset_to_line_and_column(line_ranges.source.len()), Some((3, 2)));
+        assert_eq!(line_ranges.offset_to_line_and_column(line_ranges.source.len() + 1), None);
+    }
+
+    #[test]
+    fn test_line_ranges_crlf_and_byte_order_mark() {
+        let line_ranges = LineRanges::new("\u{feff}contract A {\r\n    uint x;\r\n}\r\n");
+
+        assert_eq!(line_ranges.line_count(), 4);
+        assert_eq!(line_ranges.line_text(1), Some("contract A {"));
+        assert_eq!(line_ranges.line_text(2), Some("    uint x;"));
+        assert_eq!(line_ranges.line_text(4), Some(""));
+
+        // The byte order mark is not counted as a column of the first line
+        assert_eq!(line_ranges.offset_to_line_and_column(0), Some((1, 1)));
+        assert_eq!(line_ranges.offset_to_line_and_column(3), Some((1, 1)));
+        assert_eq!(line_ranges.offset_to_line_and_column(12), Some((1, 10)));
+
+        let offset = line_ranges.source.find("uint").unwrap();
+        assert_eq!(line_ranges.loc_to_line_and_column(&solidity::Loc::File(0, offset, offset + 4)), Some((2, 5)));
+        assert_eq!(line_ranges.loc_to_line_and_column_range(&solidity::Loc::File(0, offset, offset + 4)), Some(((2, 5), (2, 9))));
+        assert_eq!(line_ranges.loc_to_line_and_column(&solidity::Loc::Implicit), None);
+    }
+
+    #[test]
+    fn test_code_frame() {
+        let line_ranges = LineRanges::new("contract A {\n\tuint x = ;\n}");
+
+        let offset = line_ranges.source.find(';').unwrap();
+        assert_eq!(line_ranges.code_frame(offset, offset).unwrap(), "  |\n2 | \tuint x = ;\n  | \t         ^");
+
+        // Spans past the end of the line are cut off at the end of the line
+        let offset = line_ranges.source.find("uint").unwrap();
+        assert_eq!(line_ranges.code_frame(offset, line_ranges.source.len()).unwrap(), "  |\n2 | \tuint x = ;\n  | \t^^^^^^^^^^");
+    }
+}
@@ -1,56 +1,17 @@
-pub mod errors;
-pub mod project;
-pub mod sway;
-pub mod translate;
-
-use convert_case::{Case, Casing};
-use errors::Error;
-use project::Project;
+use charcoal::{
+    builder::ProjectBuilder,
+    changelog,
+    emitter::Emitter,
+    errors::Error,
+    forc,
+    printer::{BraceStyle, IndentStyle, PrinterOptions},
+    project::{Lint, ProjectType},
+    sway,
+    upgrade_diff,
+};
 use std::path::{Path, PathBuf};
 use structopt::{clap::AppSettings, StructOpt};
 
-#[inline]
-pub fn translate_naming_convention(name: &str, case: Case) -> String {
-    if name == "_" {
-        return "_".into();
-    }
-
-    let prefix = name.chars().take_while(|c| *c == '_').collect::<String>();
-    let postfix = name.chars().rev().take_while(|c| *c == '_').collect::<String>();
-    format!("{prefix}{}{postfix}", name.to_case(case))
-}
-
-#[inline]
-pub fn get_canonical_path<P: AsRef<Path>>(path: P, is_dir: bool, create_if_necessary: bool) -> std::io::Result<PathBuf> {
-    let mut path_string = path.as_ref().to_string_lossy().to_string();
-
-    while path_string.contains("\\\\") {
-        path_string = path_string.replace("\\\\", "\\");
-    }
-
-    path_string = path_string.replace('\\', "/");
-
-    while path_string.contains("//") {
-        path_string = path_string.replace("//", "/");
-    }
-
-    if is_dir && !path_string.ends_with('/') {
-        path_string = format!("{path_string}/");
-    }
-
-    let path = PathBuf::from(path_string);
-
-    if create_if_necessary && !path.exists() {
-        if is_dir {
-            std::fs::create_dir_all(path.clone())?;
-        } else {
-            std::fs::File::create(path.clone())?;
-        }
-    }
-    
-    path.canonicalize()
-}
-
 #[derive(Default, StructOpt)]
 #[structopt(global_settings = &[AppSettings::ColoredHelp, AppSettings::ArgRequiredElseHelp])]
 struct Options {
@@ -58,13 +19,153 @@ struct Options {
     #[structopt(long, short)]
     definition_name: Option<String>,
 
-    /// The Solidity target file or folder to translate.
+    /// The Solidity target file or folder to translate. (Required unless using a subcommand)
     #[structopt(long, short)]
-    target: PathBuf,
+    target: Option<PathBuf>,
 
     /// The path to save the translated Forc project to. (Optional; Must be a directory)
     #[structopt(long, short)]
     output_directory: Option<PathBuf>,
+
+    /// The path to write the generated workspace to as a single archive. (Optional; One of: .tar, .tar.gz, .tgz, .zip)
+    #[structopt(long)]
+    archive: Option<PathBuf>,
+
+    /// The path to write a markdown migration report to, describing the clean translations, stubs, semantic differences and manual follow-up items of each translated definition. (Optional)
+    #[structopt(long)]
+    report: Option<PathBuf>,
+
+    /// The maximum number of source files to translate per run. When used with an output directory, subsequent runs continue where the previous run stopped. (Optional)
+    #[structopt(long)]
+    limit: Option<usize>,
+
+    /// The path of a file to write warnings, audit warnings and notes to instead of stderr. (Optional)
+    #[structopt(long)]
+    log_file: Option<PathBuf>,
+
+    /// The path of a directory to cache translations in, so that subsequent runs only translate the source files that changed or that import changed files. (Optional)
+    #[structopt(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// The Sway version to pin the dependencies of translated Forc projects to. (Optional; Defaults to the previously locked version or 0.51.1)
+    #[structopt(long)]
+    sway_version: Option<String>,
+
+    /// The path of a `charcoal.toml` file containing type and identifier mappings. (Optional; Defaults to the closest `charcoal.toml` to the target)
+    #[structopt(long)]
+    config: Option<PathBuf>,
+
+    /// Whether to indent the generated code with spaces or tabs. (Optional; One of: spaces, tabs; Defaults to spaces)
+    #[structopt(long)]
+    indent_style: Option<IndentStyle>,
+
+    /// The number of spaces to indent the generated code by, or the width of a tab when wrapping lines. (Optional; Defaults to 4)
+    #[structopt(long)]
+    indent_width: Option<usize>,
+
+    /// The width to wrap long parameter lists, argument lists and method call chains of the generated code at. (Optional; Lines are not wrapped by default)
+    #[structopt(long)]
+    max_line_width: Option<usize>,
+
+    /// Where to place the opening brace of items in the generated code. (Optional; One of: same-line, next-line; Defaults to same-line)
+    #[structopt(long)]
+    brace_style: Option<BraceStyle>,
+
+    /// The project type to use instead of detecting it. (Optional; One of: foundry, hardhat, brownie, truffle, unknown)
+    #[structopt(long)]
+    preset: Option<ProjectType>,
+
+    /// An import remapping in the form `prefix=path`. (Optional; Can be supplied multiple times)
+    #[structopt(long)]
+    remapping: Vec<String>,
+
+    /// A type override in the form `solidity_type=sway_type`. (Optional; Can be supplied multiple times)
+    #[structopt(long)]
+    type_override: Vec<String>,
+
+    /// Emit events and errors enums even when they are never emitted or reverted with.
+    #[structopt(long)]
+    emit_all_types: bool,
+
+    /// Keep checked arithmetic in `unchecked` blocks instead of disabling panics on overflow, warning about the semantic difference.
+    #[structopt(long)]
+    strict_overflow: bool,
+
+    /// Generate a `check_invariants` function in each contract that asserts the `require` conditions on contract state shared by multiple functions.
+    #[structopt(long)]
+    invariant_helpers: bool,
+
+    /// Instrument each contract function with events logged on entry and exit, carrying the function name and the caller.
+    #[structopt(long)]
+    debug_events: bool,
+
+    /// Annotate each translated function and storage field with a comment referring to the Solidity line it was translated from, i.e: `// from Token.sol:143`.
+    #[structopt(long)]
+    source_locations: bool,
+
+    /// Write a `source_map.json` file to each generated project, mapping the items of its `src/main.sw` file to the Solidity code they were translated from.
+    #[structopt(long)]
+    source_map: bool,
+
+    /// Reject each contract whose translation has semantic differences from the original code or stubbed code instead of emitting it, not counting allowed lints.
+    #[structopt(long)]
+    strict: bool,
+
+    /// Run `forc fmt` on each generated project after it is written. (Skipped if `forc` is not installed)
+    #[structopt(long)]
+    fmt: bool,
+
+    /// Run `forc build` on each generated project and report its compiler errors at the Solidity code they originate from. (Skipped if `forc` is not installed)
+    #[structopt(long)]
+    verify: bool,
+
+    /// Extracts the helper functions of a contract whose names start with a prefix into a separate library, in the form `contract=prefix`. Functions that access storage, are exposed by the contract abi or reference other items of the contract are left in the contract, with a note explaining why. The contract is not split into multiple contracts. (Optional; Experimental; Can be supplied multiple times)
+    #[structopt(long)]
+    section_library: Vec<String>,
+
+    /// The name of a token contract to port to a native asset, rewriting its balance mapping reads to native balance queries and its writes to reverting stubs. (Optional; Experimental; Can be supplied multiple times)
+    #[structopt(long)]
+    native_asset: Vec<String>,
+
+    /// Port each recognized ERC-20 token contract to a native asset implementing the SRC-20 standard, making its transfer functions revert. (Experimental)
+    #[structopt(long)]
+    erc20_as_src20: bool,
+
+    /// Port each recognized ERC-721 token contract to native NFTs implementing the SRC-20 and SRC-7 standards. (Experimental)
+    #[structopt(long)]
+    erc721_as_nft: bool,
+
+    /// Port each recognized ERC-1155 multi-token contract to native assets implementing the SRC-20 and SRC-7 standards. (Experimental)
+    #[structopt(long)]
+    erc1155_as_multi_asset: bool,
+
+    /// A lint to suppress the audit warnings of. (Optional; Can be supplied multiple times; One of: tx-origin, narrow-int, checked-conversion, non-payable-fallback, modifier-early-return, missing-override, mutability, user-defined-operator, unchecked-arithmetic, invariant, unsupported-global, address-member, reentrancy, contract-creation, storage-aliasing, msg-value, native-asset, import-cycle, reentrancy-guard, access-control, eip712-domain, fallback)
+    #[structopt(long)]
+    allow: Vec<Lint>,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(StructOpt)]
+enum Command {
+    /// Translates two versions of a Solidity target and reports which generated Sway items changed.
+    UpgradeDiff {
+        /// The previous version of the Solidity target file or folder.
+        old_target: PathBuf,
+
+        /// The new version of the Solidity target file or folder.
+        new_target: PathBuf,
+    },
+
+    /// Compares the manifests of two runs and reports which generated Sway items changed and how the TODO count changed.
+    Changelog {
+        /// The manifest of the previous run, or the output directory containing it.
+        old_manifest: PathBuf,
+
+        /// The manifest of the new run, or the output directory containing it.
+        new_manifest: PathBuf,
+    },
 }
 
 fn main() {
@@ -77,37 +178,131 @@ fn translate_project() -> Result<(), Error> {
     let mut options = Options::from_args_safe()
         .map_err(|e| Error::Wrapped(Box::new(e)))?;
 
-    // If an output directory was supplied, canonicalize it
-    if let Some(output_directory) = options.output_directory.as_mut() {
-        *output_directory = get_canonical_path(output_directory.clone(), true, true)
-            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+    if let Some(Command::UpgradeDiff { old_target, new_target }) = options.command.as_ref() {
+        return upgrade_diff(old_target, new_target, options.definition_name.as_ref());
     }
 
-    let source_unit_paths = collect_source_unit_paths(&options.target)
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
-    
-    for source_unit_path in &source_unit_paths {
-        let mut project = Project::default();
-    
-        if options.target.is_dir() {
-            project.detect_project_type(options.target.as_path())?;
-        } else if let Some(root_path) = project.find_project_root_folder(options.target.as_path()) {
-            project.detect_project_type(root_path)?;
-        } else {
-            project.project_type = crate::project::ProjectType::Unknown;
-        }
+    if let Some(Command::Changelog { old_manifest, new_manifest }) = options.command.as_ref() {
+        return print_changelog(old_manifest, new_manifest);
+    }
+
+    let Some(target) = options.target.clone() else {
+        return Err(Error::Wrapped(Box::new(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "error: The following required argument was not provided: --target <target>",
+        ))));
+    };
+
+    let mut builder = ProjectBuilder::new().source(target);
+
+    if let Some(definition_name) = options.definition_name.as_ref() {
+        builder = builder.definition_name(definition_name);
+    }
+
+    if let Some(config_path) = options.config.as_ref() {
+        builder = builder.config(config_path);
+    }
+
+    if let Some(preset) = options.preset.take() {
+        builder = builder.preset(preset);
+    }
+
+    for remapping in options.remapping.iter() {
+        let (prefix, path) = split_key_value_argument("remapping", remapping)?;
+        builder = builder.remapping(prefix, path);
+    }
+
+    for type_override in options.type_override.iter() {
+        let (solidity_type_name, sway_type_name) = split_key_value_argument("type-override", type_override)?;
         
-        project.translate(options.definition_name.as_ref(), source_unit_path)?;
+        builder = builder.type_override(solidity_type_name, sway::TypeName::Identifier {
+            name: sway_type_name.into(),
+            generic_parameters: None,
+        });
+    }
+
+    for section_library in options.section_library.iter() {
+        let (definition_name, prefix) = split_key_value_argument("section-library", section_library)?;
+        builder = builder.section_library(definition_name, prefix);
+    }
 
-        match options.output_directory.as_ref() {
-            Some(output_directory) => generate_forc_project(&mut project, output_directory, options.definition_name.as_ref(), source_unit_path)?,
+    for definition_name in options.native_asset.iter() {
+        builder = builder.native_asset(definition_name);
+    }
+
+    builder = builder.erc20_as_src20(options.erc20_as_src20);
+    builder = builder.erc721_as_nft(options.erc721_as_nft);
+    builder = builder.erc1155_as_multi_asset(options.erc1155_as_multi_asset);
+
+    if let Some(sway_version) = options.sway_version.as_ref() {
+        builder = builder.sway_version(sway_version);
+    }
+
+    builder = builder.emit_all_types(options.emit_all_types);
+    builder = builder.strict_overflow(options.strict_overflow);
+    builder = builder.invariant_helpers(options.invariant_helpers);
+    builder = builder.debug_events(options.debug_events);
+    builder = builder.source_locations(options.source_locations);
+    builder = builder.source_map(options.source_map);
+    builder = builder.strict(options.strict);
+    builder = builder.format(options.fmt);
+    builder = builder.verify(options.verify);
+
+    for lint in options.allow.iter() {
+        builder = builder.allow(*lint);
+    }
+
+    if let Some(limit) = options.limit {
+        builder = builder.limit(limit);
+    }
 
-            None => {
-                for translated_definition in project.collect_translated_definitions(options.definition_name.as_ref(), source_unit_path) {
-                    println!("// Translated from {}", translated_definition.path.to_string_lossy());
-                    
-                    let module: sway::Module = translated_definition.into();
-                    println!("{}", sway::TabbedDisplayer(&module));
+    if let Some(archive_path) = options.archive.as_ref() {
+        builder = builder.archive(archive_path);
+    }
+
+    if let Some(report_path) = options.report.as_ref() {
+        builder = builder.report(report_path);
+    }
+
+    let printer_options = PrinterOptions {
+        indent_style: options.indent_style,
+        indent_width: options.indent_width,
+        max_line_width: options.max_line_width,
+        brace_style: options.brace_style,
+    };
+
+    builder = builder.printer_options(printer_options.clone());
+
+    if let Some(log_file_path) = options.log_file.as_ref() {
+        builder = builder.emitter(Emitter::file(log_file_path).map_err(|e| Error::Wrapped(Box::new(e)))?);
+    }
+
+    if let Some(cache_directory) = options.cache_dir.as_ref() {
+        builder = builder.cache(cache_directory);
+    }
+
+    match options.output_directory.as_ref() {
+        Some(output_directory) => {
+            builder.output(output_directory).build()?;
+        }
+
+        None if options.archive.is_some() => {
+            builder.build()?;
+        }
+
+        None => {
+            for translated_definition in builder.build()? {
+                let source = std::fs::read_to_string(&translated_definition.path).ok();
+                print!("{}", forc::get_provenance_header(Some(translated_definition.path.as_path()), source.as_deref()));
+
+                let submodules = translated_definition.submodules.clone();
+                
+                let module: sway::Module = translated_definition.into();
+                println!("{}", printer_options.print(&module));
+
+                for (submodule_name, submodule) in submodules.iter() {
+                    println!("// {submodule_name}.sw");
+                    println!("{}", printer_options.print(submodule));
                 }
             }
         }
@@ -116,97 +311,120 @@ fn translate_project() -> Result<(), Error> {
     Ok(())
 }
 
-fn generate_forc_project<P1: AsRef<Path>, P2: AsRef<Path>>(
-    project: &mut Project,
-    output_directory: P1,
-    definition_name: Option<&String>,
-    source_unit_path: P2,
-) -> Result<(), Error> {
-    let output_directory = get_canonical_path(output_directory, true, true)
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+/// Splits a `key=value` command line argument
+fn split_key_value_argument<'a>(name: &str, argument: &'a str) -> Result<(&'a str, &'a str), Error> {
+    argument.split_once('=').ok_or_else(|| Error::Wrapped(Box::new(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!("error: Invalid value for '--{name}': expected `key=value`, found `{argument}`"),
+    ))))
+}
 
-    for translated_definition in project.collect_translated_definitions(definition_name, source_unit_path) {
-        let definition_snake_name = translate_naming_convention(translated_definition.name.as_str(), Case::Snake);
-        let dependencies = translated_definition.dependencies.clone();
-        
-        let module: sway::Module = translated_definition.into();
+/// Translates every definition in the target, keyed by definition name
+fn translate_modules(target: &Path, definition_name: Option<&String>) -> Result<Vec<(String, sway::Module)>, Error> {
+    let mut builder = ProjectBuilder::new().source(target);
 
-        let project_path = get_canonical_path(output_directory.join(definition_snake_name.as_str()), true, true)
-            .map_err(|e| Error::Wrapped(Box::new(e)))?;
-        
-        let src_dir_path = get_canonical_path(project_path.join("src"), true, true)
-            .map_err(|e| Error::Wrapped(Box::new(e)))?;
-        
-        std::fs::write(
-            src_dir_path.join("main.sw"),
-            sway::TabbedDisplayer(&module).to_string(),
-        )
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
-    
-        std::fs::write(
-            project_path.join(".gitignore"),
-            "out\ntarget\nForc.lock\n",
-        )
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+    if let Some(definition_name) = definition_name {
+        builder = builder.definition_name(definition_name);
+    }
 
-        std::fs::write(
-            project_path.join("Forc.toml"),
-            format!(
-                "[project]\n\
-                authors = [\"\"]\n\
-                entry = \"main.sw\"\n\
-                license = \"Apache-2.0\"\n\
-                name = \"{definition_snake_name}\"\n\
-                \n\
-                [dependencies]\n\
-                {}\
-                \n\
-                ",
-                dependencies.join("\n"),
-            ),
-        )
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+    let mut modules: Vec<(String, sway::Module)> = vec![];
+
+    for (source_unit_path, project) in builder.translate()? {
+        for translated_definition in project.collect_translated_definitions(definition_name, &source_unit_path) {
+            if modules.iter().any(|(name, _)| *name == translated_definition.name) {
+                continue;
+            }
+
+            modules.push((translated_definition.name.clone(), translated_definition.into()));
+        }
     }
 
-    Ok(())
+    Ok(modules)
 }
 
-/// Recursively search for .sol files in the given directory
-fn collect_source_unit_paths(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
-    if !dir.is_dir() {
-        if dir.extension().unwrap() != "sol" {
-            panic!("Only solidity files are supported.");
+fn upgrade_diff(old_target: &Path, new_target: &Path, definition_name: Option<&String>) -> Result<(), Error> {
+    let old_modules = translate_modules(old_target, definition_name)?;
+    let new_modules = translate_modules(new_target, definition_name)?;
+
+    let mut definition_names = old_modules.iter().map(|(name, _)| name.clone()).collect::<Vec<_>>();
+
+    for (name, _) in new_modules.iter() {
+        if !definition_names.contains(name) {
+            definition_names.push(name.clone());
         }
-        
-        if !dir.exists() {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                format!("File not found: {}", dir.to_string_lossy()),
-            ));
+    }
+
+    let mut has_changes = false;
+
+    for definition_name in definition_names {
+        let old_module = old_modules.iter().find(|(name, _)| *name == definition_name).map(|(_, m)| m);
+        let new_module = new_modules.iter().find(|(name, _)| *name == definition_name).map(|(_, m)| m);
+
+        let changes = upgrade_diff::diff_modules(old_module, new_module);
+
+        if changes.is_empty() {
+            continue;
+        }
+
+        has_changes = true;
+
+        match (old_module, new_module) {
+            (None, Some(_)) => println!("{definition_name} (added):"),
+            (Some(_), None) => println!("{definition_name} (removed):"),
+            _ => println!("{definition_name}:"),
+        }
+
+        for change in changes {
+            println!("    {change}");
         }
 
-        return Ok(vec![
-            get_canonical_path(dir, false, false)?,
-        ]);
+        println!();
     }
 
-    let mut source_unit_paths = vec![];
+    if !has_changes {
+        println!("No generated Sway items changed.");
+    }
 
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
+    Ok(())
+}
 
-        if path.is_dir() {
-            source_unit_paths.extend(collect_source_unit_paths(&path)?);
-            continue;
+fn print_changelog(old_manifest_path: &Path, new_manifest_path: &Path) -> Result<(), Error> {
+    let old_manifest = changelog::Manifest::read(old_manifest_path)?;
+    let new_manifest = changelog::Manifest::read(new_manifest_path)?;
+
+    let definition_changelogs = changelog::diff_manifests(&old_manifest, &new_manifest);
+
+    for definition_changelog in definition_changelogs.iter() {
+        match definition_changelog.kind {
+            upgrade_diff::ItemChangeKind::Changed => println!("{}:", definition_changelog.name),
+            kind => println!("{} ({kind}):", definition_changelog.name),
         }
-        
-        if let Some(extension) = path.extension() {
-            if extension == "sol" {
-                source_unit_paths.push(get_canonical_path(path, false, false)?);
-            }
+
+        for change in definition_changelog.changes.iter() {
+            println!("    {change}");
+        }
+
+        if definition_changelog.old_todo_count != definition_changelog.new_todo_count {
+            println!("    TODO count: {} => {}", definition_changelog.old_todo_count, definition_changelog.new_todo_count);
         }
+
+        println!();
     }
 
-    Ok(source_unit_paths)
+    let count_changes = |kind: upgrade_diff::ItemChangeKind| {
+        definition_changelogs.iter().flat_map(|d| d.changes.iter()).filter(|c| c.kind == kind).count()
+    };
+
+    let old_todo_count = old_manifest.todo_count() as isize;
+    let new_todo_count = new_manifest.todo_count() as isize;
+
+    println!(
+        "{} item(s) added, {} removed, {} changed; TODO count: {old_todo_count} => {new_todo_count} ({:+})",
+        count_changes(upgrade_diff::ItemChangeKind::Added),
+        count_changes(upgrade_diff::ItemChangeKind::Removed),
+        count_changes(upgrade_diff::ItemChangeKind::Changed),
+        new_todo_count - old_todo_count,
+    );
+
+    Ok(())
 }
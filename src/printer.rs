@@ -0,0 +1,347 @@
+use crate::sway::{TabbedDisplay, TabbedDisplayer};
+use serde::Deserialize;
+
+/// The number of spaces that [TabbedDisplayer] indents each level by.
+const DEFAULT_INDENT_WIDTH: usize = 4;
+
+/// The keywords that start the item declarations whose opening brace is placed according to the [BraceStyle].
+const ITEM_KEYWORDS: &[&str] = &["abi", "configurable", "enum", "fn", "impl", "storage", "struct", "trait"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IndentStyle {
+    #[default]
+    Spaces,
+    Tabs,
+}
+
+impl std::str::FromStr for IndentStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "spaces" => Ok(IndentStyle::Spaces),
+            "tabs" => Ok(IndentStyle::Tabs),
+            _ => Err(format!("Unsupported indent style: {s} (Expected one of: spaces, tabs)")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BraceStyle {
+    /// The opening brace of an item is placed at the end of its declaration, i.e: `fn f() {`
+    #[default]
+    SameLine,
+    /// The opening brace of an item is placed on the line following its declaration
+    NextLine,
+}
+
+impl std::str::FromStr for BraceStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "same-line" => Ok(BraceStyle::SameLine),
+            "next-line" => Ok(BraceStyle::NextLine),
+            _ => Err(format!("Unsupported brace style: {s} (Expected one of: same-line, next-line)")),
+        }
+    }
+}
+
+/// Options for the formatting of generated Sway code, i.e: to match the `forc fmt` settings of a project.
+///
+/// Options that are not set keep the formatting of [TabbedDisplayer], which indents with 4 spaces and does not wrap lines.
+///
+/// ```toml
+/// [printer]
+/// indent_style = "tabs"
+/// indent_width = 4
+/// max_line_width = 100
+/// brace_style = "next-line"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PrinterOptions {
+    pub indent_style: Option<IndentStyle>,
+    /// The number of spaces to indent each level by, or the width of a tab when computing the width of a line.
+    pub indent_width: Option<usize>,
+    /// The width that lines are wrapped at, by breaking long parameter and argument lists and method call chains over multiple lines.
+    pub max_line_width: Option<usize>,
+    pub brace_style: Option<BraceStyle>,
+}
+
+impl PrinterOptions {
+    /// Sets the options that are not set to the options of `other`.
+    pub fn merge(&mut self, other: &PrinterOptions) {
+        self.indent_style = self.indent_style.or(other.indent_style);
+        self.indent_width = self.indent_width.or(other.indent_width);
+        self.max_line_width = self.max_line_width.or(other.max_line_width);
+        self.brace_style = self.brace_style.or(other.brace_style);
+    }
+
+    /// Renders the supplied Sway item with the formatting of the options.
+    pub fn print<T: TabbedDisplay>(&self, item: &T) -> String {
+        let text = TabbedDisplayer(item).to_string();
+
+        if *self == PrinterOptions::default() {
+            return text;
+        }
+
+        let mut lines = vec![];
+
+        for line in text.split('\n') {
+            let indentation = line.len() - line.trim_start_matches(' ').len();
+            let content = &line[indentation..];
+
+            if content.is_empty() {
+                lines.push((0, String::new()));
+                continue;
+            }
+
+            let depth = indentation / DEFAULT_INDENT_WIDTH;
+            let alignment = " ".repeat(indentation % DEFAULT_INDENT_WIDTH);
+
+            match content.strip_suffix(" {") {
+                Some(declaration) if self.brace_style == Some(BraceStyle::NextLine) && is_item_declaration(declaration) => {
+                    self.wrap_line(depth, format!("{alignment}{declaration}"), &mut lines);
+                    lines.push((depth, format!("{alignment}{{")));
+                }
+
+                _ => self.wrap_line(depth, format!("{alignment}{content}"), &mut lines),
+            }
+        }
+
+        lines.into_iter()
+            .map(|(depth, content)| if content.is_empty() { content } else { format!("{}{content}", self.get_indentation(depth)) })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn get_indentation(&self, depth: usize) -> String {
+        match self.indent_style.unwrap_or_default() {
+            IndentStyle::Spaces => " ".repeat(depth * self.indent_width.unwrap_or(DEFAULT_INDENT_WIDTH)),
+            IndentStyle::Tabs => "\t".repeat(depth),
+        }
+    }
+
+    /// Breaks the line into lines that fit in the maximum line width where possible, preferring to break method call chains
+    /// over parameter and argument lists.
+    fn wrap_line(&self, depth: usize, content: String, lines: &mut Vec<(usize, String)>) {
+        let Some(max_line_width) = self.max_line_width else {
+            lines.push((depth, content));
+            return;
+        };
+
+        let width = depth * self.indent_width.unwrap_or(DEFAULT_INDENT_WIDTH) + content.chars().count();
+
+        if width <= max_line_width || content.trim_start().starts_with("//") {
+            lines.push((depth, content));
+            return;
+        }
+
+        let tokens = get_structural_tokens(content.as_str());
+
+        // Break a chain of at least two method calls before each call, i.e: `x.a().b()` => `x\n.a()\n.b()`
+        let call_offsets = tokens.iter()
+            .filter(|(offset, c, token_depth)| *c == '.' && *token_depth == 0 && *offset > 0 && is_method_call(&content[offset + 1..]))
+            .map(|(offset, _, _)| *offset)
+            .collect::<Vec<_>>();
+
+        // Only break chains that are not part of a larger expression, i.e: `a.b() + c.d()`
+        let is_chain = call_offsets.len() >= 2 && !tokens.iter().any(|(offset, c, token_depth)| {
+            *c == ' ' && *token_depth == 0 && *offset > call_offsets[0] && *offset < *call_offsets.last().unwrap()
+        });
+
+        if is_chain {
+            self.wrap_line(depth, content[..call_offsets[0]].to_string(), lines);
+
+            for (i, offset) in call_offsets.iter().enumerate() {
+                let end = call_offsets.get(i + 1).cloned().unwrap_or(content.len());
+                self.wrap_line(depth + 1, content[*offset..end].to_string(), lines);
+            }
+
+            return;
+        }
+
+        // Break the longest of the outermost lists containing a comma, placing each of its elements on a separate line
+        let mut list: Option<(usize, usize, usize)> = None;
+
+        for (i, (open_offset, c, token_depth)) in tokens.iter().enumerate() {
+            if !matches!(c, '(' | '[') {
+                continue;
+            }
+
+            let Some((close_offset, _, _)) = tokens[i + 1..].iter().find(|(_, c, d)| matches!(c, ')' | ']' | '}') && d == token_depth) else { continue };
+
+            let has_comma = tokens[i + 1..].iter()
+                .take_while(|(offset, _, _)| offset < close_offset)
+                .any(|(_, c, d)| *c == ',' && *d == token_depth + 1);
+
+            let is_preferred = match list {
+                Some((list_open_offset, list_close_offset, list_depth)) => {
+                    *token_depth < list_depth || (*token_depth == list_depth && close_offset - open_offset > list_close_offset - list_open_offset)
+                }
+
+                None => true,
+            };
+
+            if has_comma && is_preferred {
+                list = Some((*open_offset, *close_offset, *token_depth));
+            }
+        }
+
+        let Some((open_offset, close_offset, list_depth)) = list else {
+            lines.push((depth, content));
+            return;
+        };
+
+        self.wrap_line(depth, content[..=open_offset].to_string(), lines);
+
+        let mut element_start = open_offset + 1;
+
+        for (offset, _, _) in tokens.iter().filter(|(offset, c, d)| *c == ',' && *d == list_depth + 1 && *offset > open_offset && *offset < close_offset) {
+            self.wrap_line(depth + 1, format!("{},", content[element_start..*offset].trim()), lines);
+            element_start = offset + 1;
+        }
+
+        // The final element is empty if the list already has a trailing comma
+        let final_element = content[element_start..close_offset].trim();
+
+        if !final_element.is_empty() {
+            self.wrap_line(depth + 1, format!("{final_element},"), lines);
+        }
+
+        self.wrap_line(depth, content[close_offset..].to_string(), lines);
+    }
+}
+
+/// Checks if the line without its opening brace declares an item, i.e: `pub fn transfer(to: Identity)`
+fn is_item_declaration(declaration: &str) -> bool {
+    let declaration = declaration.strip_prefix("pub ").unwrap_or(declaration);
+
+    ITEM_KEYWORDS.iter().any(|keyword| {
+        declaration == *keyword
+            || declaration.strip_prefix(keyword).is_some_and(|rest| rest.starts_with([' ', '<']))
+    })
+}
+
+/// Checks if the text following a `.` is a method call, i.e: `read()`
+fn is_method_call(text: &str) -> bool {
+    let name_length = text.chars().take_while(|c| c.is_alphanumeric() || *c == '_').count();
+    name_length > 0 && text[name_length..].starts_with(['(', ':'])
+}
+
+/// Gets the byte offset, character and bracket depth of each bracket, comma, `.` and space of the line that is not in a string literal or comment.
+///
+/// Closing brackets have the depth of their opening bracket.
+pub(crate) fn get_structural_tokens(content: &str) -> Vec<(usize, char, usize)> {
+    let mut tokens = vec![];
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut previous = None;
+
+    for (offset, c) in content.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else {
+            match c {
+                '"' => in_string = true,
+                '/' if previous == Some('/') => break,
+
+                '(' | '[' | '{' => {
+                    tokens.push((offset, c, depth));
+                    depth += 1;
+                }
+
+                ')' | ']' | '}' => {
+                    depth = depth.saturating_sub(1);
+                    tokens.push((offset, c, depth));
+                }
+
+                ',' | '.' | ' ' => tokens.push((offset, c, depth)),
+                _ => {}
+            }
+        }
+
+        previous = Some(c);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sway;
+
+    fn method_call(container: sway::Expression, member: &str, parameters: Vec<sway::Expression>) -> sway::Expression {
+        sway::Expression::FunctionCall(Box::new(sway::FunctionCall {
+            function: sway::Expression::MemberAccess(Box::new(sway::MemberAccess {
+                expression: container,
+                member: member.into(),
+            })),
+            generic_parameters: None,
+            parameters,
+        }))
+    }
+
+    #[test]
+    fn test_printer_options() {
+        let function = sway::Function {
+            comment: None,
+            attributes: None,
+            is_public: false,
+            name: "transfer".into(),
+            generic_parameters: None,
+            parameters: sway::ParameterList {
+                entries: ["recipient", "amount", "memo"].iter()
+                    .map(|name| sway::Parameter {
+                        name: name.to_string(),
+                        type_name: Some(sway::TypeName::Identifier { name: "u64".into(), generic_parameters: None }),
+                        ..Default::default()
+                    })
+                    .collect(),
+            },
+            return_type: None,
+            body: Some(sway::Block {
+                statements: vec![],
+                final_expr: Some(method_call(
+                    method_call(
+                        sway::Expression::MemberAccess(Box::new(sway::MemberAccess {
+                            expression: sway::Expression::Identifier("storage".into()),
+                            member: "balances".into(),
+                        })),
+                        "get",
+                        vec![sway::Expression::Identifier("recipient".into())],
+                    ),
+                    "read",
+                    vec![],
+                )),
+            }),
+        };
+
+        assert_eq!(
+            PrinterOptions::default().print(&function),
+            TabbedDisplayer(&function).to_string(),
+        );
+
+        let options = PrinterOptions {
+            indent_style: Some(IndentStyle::Tabs),
+            indent_width: Some(4),
+            max_line_width: Some(30),
+            brace_style: Some(BraceStyle::NextLine),
+        };
+
+        assert_eq!(
+            options.print(&function),
+            "fn transfer(\n\trecipient: u64,\n\tamount: u64,\n\tmemo: u64,\n)\n{\n\tstorage.balances\n\t\t.get(recipient)\n\t\t.read()\n}",
+        );
+    }
+}
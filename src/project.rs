@@ -1,11 +1,24 @@
 use crate::{
+    config::{self, Config},
+    emitter::{emitln, Emitter},
     errors::Error,
-    translate::{translate_contract_definition, TranslatedDefinition},
+    line_ranges::{LineRanges, BYTE_ORDER_MARK},
+    printer::PrinterOptions,
+    source_provider::{self, SourceProvider},
+    source_units::SourceUnitArena,
+    sway,
+    translate::{translate_contract_definition, RenameReason, TranslatedDefinition, TranslationHook},
+};
+use convert_case::Case;
+use serde::{Deserialize, Serialize};
+use solang_parser::{
+    doccomment::{parse_doccomments, DocComment, DocCommentTag},
+    helpers::CodeLocation,
+    pt as solidity,
 };
-use solang_parser::pt as solidity;
 use std::{
-    cell::RefCell,
-    collections::HashMap,
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
     path::{Path, PathBuf},
     rc::Rc,
 };
@@ -32,20 +45,548 @@ impl ProjectType {
     pub const TRUFFLE_CONFIG_FILE: &'static str = "truffle-config.js";
 }
 
+impl std::str::FromStr for ProjectType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "foundry" => Ok(ProjectType::Foundry { remappings: HashMap::new() }),
+            "hardhat" => Ok(ProjectType::Hardhat),
+            "brownie" => Ok(ProjectType::Brownie { remappings: HashMap::new() }),
+            "truffle" => Ok(ProjectType::Truffle),
+            "unknown" => Ok(ProjectType::Unknown),
+            _ => Err(format!("Unsupported project type: {s} (Expected one of: foundry, hardhat, brownie, truffle, unknown)")),
+        }
+    }
+}
+
+/// A category of translation decisions that may change the behavior of the translated code.
+///
+/// Each lint reports an audit warning when triggered, unless it has been allowed (i.e: `--allow tx-origin`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Lint {
+    /// `tx.origin` has no Sway equivalent and is translated to a zero address
+    TxOrigin,
+    /// An integer type has no Sway equivalent of the same size and is translated to the next largest size
+    NarrowInt,
+    /// A conversion between signed and unsigned integers is translated to a checked conversion
+    CheckedConversion,
+    /// A non-payable fallback function cannot reject forwarded coins
+    NonPayableFallback,
+    /// A function returns early, skipping the code following the underscore statement of its modifiers
+    ModifierEarlyReturn,
+    /// A function overrides an inherited function without being marked `override`
+    MissingOverride,
+    /// A function's translation accesses storage in a way its declared mutability does not allow
+    Mutability,
+    /// A user-defined operator has no Sway operator trait equivalent
+    UserDefinedOperator,
+    /// An `unchecked` block's arithmetic does not wrap the same way it does in Solidity
+    UncheckedArithmetic,
+    /// A `require` condition on contract state is checked by multiple functions
+    Invariant,
+    /// A block, transaction or message global has no Fuel equivalent and is translated to a placeholder or an approximation
+    UnsupportedGlobal,
+    /// An address member has different semantics on Fuel, i.e: the balance of a non-contract address, the code of a contract or a low-level call
+    AddressMember,
+    /// A function writes to storage after transferring value or calling another contract, leaving it open to reentrancy
+    Reentrancy,
+    /// A `new` expression cannot deploy a contract on Fuel and is translated to the initialization of a contract deployed ahead of time
+    ContractCreation,
+    /// A `storage` pointer cannot alias the value it points to and is translated to a copy of that value
+    StorageAliasing,
+    /// `msg.value` is translated to the amount of coins forwarded with the call, which can be coins of any asset instead of only the base asset
+    MsgValue,
+    /// A token contract is ported to native assets, whose balances and transfers are tracked by the blockchain instead of the contract
+    NativeAsset,
+    /// A contract referenced through an import cycle is translated to `Identity`, since its ABI is not available yet
+    ImportCycle,
+    /// A `nonReentrant` modifier is mapped to the `sway_libs::reentrancy` guard, which detects reentrancy from the call stack instead of a storage flag, so internal calls between guarded functions no longer revert
+    ReentrancyGuard,
+    /// Inherited ownership, pausing or role-based access control is mapped to `sway_libs` or flattened into storage maps, whose behavior and events differ from the inherited implementation
+    AccessControl,
+    /// An EIP-712 hash is computed with the Fuel chain id and contract id in its domain, so signatures made for the Ethereum domain are rejected
+    Eip712Domain,
+    /// A `fallback` or `receive` function is translated to a `#[fallback]` function or an abi function, which is not called for plain coin transfers or calldata the same way
+    Fallback,
+}
+
+impl Lint {
+    pub const ALL: &'static [Lint] = &[
+        Lint::TxOrigin,
+        Lint::NarrowInt,
+        Lint::CheckedConversion,
+        Lint::NonPayableFallback,
+        Lint::ModifierEarlyReturn,
+        Lint::MissingOverride,
+        Lint::Mutability,
+        Lint::UserDefinedOperator,
+        Lint::UncheckedArithmetic,
+        Lint::Invariant,
+        Lint::UnsupportedGlobal,
+        Lint::AddressMember,
+        Lint::Reentrancy,
+        Lint::ContractCreation,
+        Lint::StorageAliasing,
+        Lint::MsgValue,
+        Lint::NativeAsset,
+        Lint::ImportCycle,
+        Lint::ReentrancyGuard,
+        Lint::AccessControl,
+        Lint::Eip712Domain,
+        Lint::Fallback,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::TxOrigin => "tx-origin",
+            Lint::NarrowInt => "narrow-int",
+            Lint::CheckedConversion => "checked-conversion",
+            Lint::NonPayableFallback => "non-payable-fallback",
+            Lint::ModifierEarlyReturn => "modifier-early-return",
+            Lint::MissingOverride => "missing-override",
+            Lint::Mutability => "mutability",
+            Lint::UserDefinedOperator => "user-defined-operator",
+            Lint::UncheckedArithmetic => "unchecked-arithmetic",
+            Lint::Invariant => "invariant",
+            Lint::UnsupportedGlobal => "unsupported-global",
+            Lint::AddressMember => "address-member",
+            Lint::Reentrancy => "reentrancy",
+            Lint::ContractCreation => "contract-creation",
+            Lint::StorageAliasing => "storage-aliasing",
+            Lint::MsgValue => "msg-value",
+            Lint::NativeAsset => "native-asset",
+            Lint::ImportCycle => "import-cycle",
+            Lint::ReentrancyGuard => "reentrancy-guard",
+            Lint::AccessControl => "access-control",
+            Lint::Eip712Domain => "eip712-domain",
+            Lint::Fallback => "fallback",
+        }
+    }
+
+    /// Gets a description of the translation decision that the lint reports.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Lint::TxOrigin => "`tx.origin` has no Sway equivalent and is translated to a zero address",
+            Lint::NarrowInt => "An integer type has no Sway equivalent of the same size and is translated to the next largest size",
+            Lint::CheckedConversion => "A conversion between signed and unsigned integers is translated to a checked conversion",
+            Lint::NonPayableFallback => "A non-payable fallback function cannot reject forwarded coins",
+            Lint::ModifierEarlyReturn => "A function returns early, skipping the code following the underscore statement of its modifiers",
+            Lint::MissingOverride => "A function overrides an inherited function without being marked `override`",
+            Lint::Mutability => "A function's translation accesses storage in a way its declared mutability does not allow",
+            Lint::UserDefinedOperator => "A user-defined operator has no Sway operator trait equivalent",
+            Lint::UncheckedArithmetic => "An `unchecked` block's arithmetic does not wrap the same way it does in Solidity",
+            Lint::Invariant => "A `require` condition on contract state is checked by multiple functions",
+            Lint::UnsupportedGlobal => "A block, transaction or message global has no Fuel equivalent and is translated to a placeholder or an approximation",
+            Lint::AddressMember => "An address member has different semantics on Fuel, i.e: the balance of a non-contract address, the code of a contract or a low-level call",
+            Lint::Reentrancy => "A function writes to storage after transferring value or calling another contract, leaving it open to reentrancy",
+            Lint::ContractCreation => "A `new` expression cannot deploy a contract on Fuel and is translated to the initialization of a contract deployed ahead of time",
+            Lint::StorageAliasing => "A `storage` pointer cannot alias the value it points to and is translated to a copy of that value",
+            Lint::MsgValue => "`msg.value` is translated to the amount of coins forwarded with the call, which can be coins of any asset instead of only the base asset",
+            Lint::NativeAsset => "A token contract is ported to native assets, whose balances and transfers are tracked by the blockchain instead of the contract",
+            Lint::ImportCycle => "A contract referenced through an import cycle is translated to `Identity`, since its ABI is not available yet",
+            Lint::ReentrancyGuard => "A `nonReentrant` modifier is mapped to the `sway_libs::reentrancy` guard, which detects reentrancy from the call stack instead of a storage flag, so internal calls between guarded functions no longer revert",
+            Lint::AccessControl => "Inherited ownership, pausing or role-based access control is mapped to `sway_libs` or flattened into storage maps, whose behavior and events differ from the inherited implementation",
+            Lint::Eip712Domain => "An EIP-712 hash is computed with the Fuel chain id and contract id in its domain, so signatures made for the Ethereum domain are rejected",
+            Lint::Fallback => "A `fallback` or `receive` function is translated to a `#[fallback]` function or an abi function, which is not called for plain coin transfers or calldata the same way",
+        }
+    }
+
+    /// Checks if the lint reports a translation that behaves differently than the original code,
+    /// rather than a pattern of the original code that is worth reviewing.
+    pub fn is_semantic_drift(&self) -> bool {
+        !matches!(self, Lint::MissingOverride | Lint::Invariant | Lint::Reentrancy)
+    }
+
+    /// Gets how likely the translation decision that the lint reports is to break the translated code.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Lint::TxOrigin
+            | Lint::NonPayableFallback
+            | Lint::UncheckedArithmetic
+            | Lint::AddressMember
+            | Lint::Reentrancy
+            | Lint::ContractCreation
+            | Lint::StorageAliasing
+            | Lint::NativeAsset
+            | Lint::Eip712Domain => Severity::High,
+
+            Lint::NarrowInt
+            | Lint::CheckedConversion
+            | Lint::ModifierEarlyReturn
+            | Lint::Mutability
+            | Lint::UserDefinedOperator
+            | Lint::UnsupportedGlobal
+            | Lint::MsgValue
+            | Lint::ImportCycle
+            | Lint::ReentrancyGuard
+            | Lint::AccessControl
+            | Lint::Fallback => Severity::Medium,
+
+            Lint::MissingOverride | Lint::Invariant => Severity::Low,
+        }
+    }
+}
+
+impl std::fmt::Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// How likely a [Warning] is to break the translated code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    /// The translation is equivalent, but the original code is worth reviewing
+    Low,
+    /// The translation behaves differently in edge cases, i.e: on overflow
+    Medium,
+    /// The translation behaves differently or is incomplete, i.e: authorization or value transfers
+    High,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Low => write!(f, "low"),
+            Severity::Medium => write!(f, "medium"),
+            Severity::High => write!(f, "high"),
+        }
+    }
+}
+
+/// A semantic difference between the original code and its translation, or a pattern of the original code that is worth reviewing, reported by a [Lint].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Warning {
+    pub lint: Lint,
+    pub severity: Severity,
+    pub path: PathBuf,
+    /// The 1-based line and column of the Solidity code that the warning was reported at, if known.
+    pub line_and_column: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl Warning {
+    /// Gets the location of the warning, i.e: `contracts/Token.sol:12:5`
+    pub fn location(&self) -> String {
+        match self.line_and_column {
+            Some((line, column)) => format!("{}:{line}:{column}", self.path.to_string_lossy()),
+            None => self.path.to_string_lossy().to_string(),
+        }
+    }
+}
+
+/// Semantic differences are emitted as `WARNING` and patterns worth reviewing as `AUDIT`, i.e:
+/// `WARNING: contracts/Token.sol:12:5 - `tx.origin` has no Sway equivalent and was translated to the zero address (tx-origin, high severity)`
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} - {} ({}, {} severity)",
+            if self.lint.is_semantic_drift() { "WARNING" } else { "AUDIT" },
+            self.location(),
+            self.message,
+            self.lint,
+            self.severity,
+        )
+    }
+}
+
+/// The kind of a user-defined type declared at the top level of a source unit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserTypeKind {
+    Struct,
+    Enum,
+    TypeDefinition,
+    /// A contract, abstract contract, interface or library.
+    Contract,
+}
+
+/// The symbols imported into a source unit under a name other than the one they were declared with.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportSymbols {
+    /// Maps the alias of each symbol imported with `import {X as Y} from "...";` to the name it was declared with.
+    pub aliases: HashMap<String, String>,
+
+    /// Maps the name of each source unit imported with `import * as N from "...";` or `import "..." as N;` to its path.
+    pub namespaces: HashMap<String, PathBuf>,
+}
+
+impl std::str::FromStr for Lint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Lint::ALL.iter()
+            .find(|lint| lint.name() == s.to_lowercase())
+            .cloned()
+            .ok_or_else(|| format!(
+                "Unsupported lint: {s} (Expected one of: {})",
+                Lint::ALL.iter().map(Lint::name).collect::<Vec<_>>().join(", "),
+            ))
+    }
+}
+
 #[derive(Default)]
 pub struct Project {
-    pub line_ranges: HashMap<PathBuf, Vec<(usize, usize)>>,
-    pub solidity_source_units: Rc<RefCell<HashMap<PathBuf, solidity::SourceUnit>>>,
+    pub line_ranges: HashMap<PathBuf, LineRanges>,
+    pub comments: HashMap<PathBuf, Vec<solidity::Comment>>,
+    pub solidity_source_units: SourceUnitArena,
     pub translated_definitions: Vec<TranslatedDefinition>,
-    pub import_directives: HashMap<PathBuf, HashMap<PathBuf, Option<Vec<String>>>>,
+    pub import_directives: HashMap<PathBuf, BTreeMap<PathBuf, Option<Vec<String>>>>,
+    pub import_symbols: HashMap<PathBuf, ImportSymbols>,
+    /// The `(path, name)` of each contract definition being translated, innermost last.
+    pub translation_stack: Vec<(PathBuf, String)>,
+    /// The user-defined types declared at the top level of each parsed source unit, keyed by name.
+    pub user_types: HashMap<PathBuf, BTreeMap<String, UserTypeKind>>,
+    /// The paths of the source units imported by each source unit.
+    pub import_graph: HashMap<PathBuf, Vec<PathBuf>>,
+    /// The import cycles encountered during translation, each listing the paths of the source units in import order.
+    pub import_cycles: Vec<Vec<PathBuf>>,
     pub project_type: ProjectType,
+    pub remappings: HashMap<String, String>,
+    pub type_overrides: HashMap<String, sway::TypeName>,
+    pub identifier_mappings: HashMap<String, String>,
+    pub emit_all_types: bool,
+    pub strict_overflow: bool,
+    pub invariant_helpers: bool,
+    pub debug_events: bool,
+    pub source_locations: bool,
+    pub source_map: bool,
+    pub strict: bool,
+    pub allowed_lints: HashSet<Lint>,
+    /// The warnings reported for the definition being translated, not counting the warnings of allowed lints.
+    pub warnings: Vec<Warning>,
+    pub rejected_definitions: HashSet<(PathBuf, String)>,
+    pub source_unit_paths: Vec<PathBuf>,
+    pub virtual_files: HashMap<PathBuf, String>,
+    pub source_provider: Option<Box<dyn SourceProvider>>,
+    pub translation_hooks: Vec<Rc<dyn TranslationHook>>,
+    pub emitter: Emitter,
+    pub printer_options: PrinterOptions,
 }
 
 impl Project {
-    /// Attempts to parse the file from the supplied `path`.
+    /// Checks if the audit warnings of the supplied lint have been allowed.
+    #[inline]
+    pub fn is_lint_allowed(&self, lint: Lint) -> bool {
+        self.allowed_lints.contains(&lint)
+    }
+
+    /// Emits a warning for the Solidity code at `loc` in the file at `path` and records it for the definition being translated,
+    /// unless its lint has been allowed.
+    pub fn report_warning<P: AsRef<Path>, S: Into<String>>(&mut self, lint: Lint, path: P, loc: Option<&solidity::Loc>, message: S) {
+        if self.is_lint_allowed(lint) {
+            return;
+        }
+
+        let warning = Warning {
+            lint,
+            severity: lint.severity(),
+            path: path.as_ref().to_path_buf(),
+            line_and_column: loc.and_then(|loc| self.loc_to_line_and_column(path.as_ref(), loc)),
+            message: message.into(),
+        };
+
+        emitln!(self.emitter, "{warning}");
+
+        self.warnings.push(warning);
+    }
+
+    /// Emits a warning for a definition that has already been translated, i.e: by the passes that port token contracts to native assets,
+    /// and records it with the other warnings of the definition, unless its lint has been allowed.
+    pub fn report_definition_warning<S: Into<String>>(&mut self, lint: Lint, definition_name: &str, loc: Option<&solidity::Loc>, message: S) {
+        let Some(index) = self.translated_definitions.iter().position(|d| d.name == definition_name) else { return };
+        let path = self.translated_definitions[index].path.clone();
+        let warning_count = self.warnings.len();
+
+        self.report_warning(lint, path, loc, message);
+
+        let warnings = self.warnings.split_off(warning_count);
+        self.translated_definitions[index].warnings.extend(warnings);
+    }
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a project that reads its source files and project configuration files from the supplied source provider.
+    pub fn with_source_provider<S: SourceProvider + 'static>(source_provider: S) -> Self {
+        Self {
+            source_provider: Some(Box::new(source_provider)),
+            ..Default::default()
+        }
+    }
+
+    /// Registers a hook with custom translation rules, which is consulted after the hooks registered before it.
+    pub fn add_translation_hook<H: TranslationHook + 'static>(&mut self, translation_hook: H) {
+        self.translation_hooks.push(Rc::new(translation_hook));
+    }
+
+    /// Gets the source provider of the project, or the default source provider if it was not given one.
+    #[inline]
+    pub fn source_provider(&self) -> &dyn SourceProvider {
+        match self.source_provider.as_ref() {
+            Some(source_provider) => source_provider.as_ref(),
+            None => source_provider::default_source_provider(),
+        }
+    }
+
+    /// Adds a Solidity file to be translated by [Project::translate].
+    ///
+    /// If no project type has been set, it is detected from the root folder of the project containing the file.
+    /// The mappings of the closest `charcoal.toml` file to the file are also loaded, if any.
+    pub fn add_source_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+        let path = self.parse_solidity_source_unit(path)?;
+
+        if matches!(self.project_type, ProjectType::Unknown) {
+            if let Some(root_path) = self.find_project_root_folder(&path) {
+                self.detect_project_type(root_path)?;
+            }
+        }
+
+        if let Some(config_path) = self.find_config_file(&path) {
+            self.load_config(config_path)?;
+        }
+
+        if !self.source_unit_paths.contains(&path) {
+            self.source_unit_paths.push(path);
+        }
+
+        Ok(())
+    }
+
+    /// Adds Solidity source text to be translated by [Project::translate] as if it was read from the file named `name`, which does not need to exist.
+    ///
+    /// The source text is added to the virtual files of the project, which imports are resolved against before the file system.
+    /// It is parsed when it is translated, so syntax errors are reported by [Project::translate].
+    pub fn add_source(&mut self, name: &str, source: &str) {
+        let path = normalize_path(Path::new(name));
+
+        // Parse the source text again if it replaces previously added source text
+        self.solidity_source_units.remove(&path);
+        self.virtual_files.insert(path.clone(), source.into());
+
+        if !self.source_unit_paths.contains(&path) {
+            self.source_unit_paths.push(path);
+        }
+    }
+
+    /// Adds Solidity source text to be translated by [Project::translate] as if it was read from the file at `path`, parsing it ahead of time.
+    ///
+    /// Imports are resolved against `path`, so source text can import other added source text as well as files.
+    pub fn add_source_text<P: AsRef<Path>, S: AsRef<str>>(&mut self, path: P, source: S) -> Result<(), Error> {
+        let path = normalize_path(path.as_ref());
+
+        self.add_source(path.to_string_lossy().as_ref(), source.as_ref());
+        self.parse_solidity_source_unit(path)?;
+
+        Ok(())
+    }
+
+    /// Finds the closest `charcoal.toml` file to the supplied `path`, searching its folder and each of its parent folders.
+    pub fn find_config_file<P: AsRef<Path>>(&self, path: P) -> Option<PathBuf> {
+        let mut folder_path = Some(path.as_ref());
+
+        while let Some(path) = folder_path {
+            let config_path = path.join(config::CONFIG_FILE);
+
+            if self.source_provider().exists(&config_path) {
+                return Some(config_path);
+            }
+
+            folder_path = path.parent();
+        }
+
+        None
+    }
+
+    /// Reads the `charcoal.toml` file at the supplied `path` and applies its mappings to the project.
+    pub fn load_config<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+        if !self.source_provider().exists(path.as_ref()) {
+            return Err(Error::Wrapped(Box::new(
+                std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("File not found: {}", path.as_ref().to_string_lossy()),
+                )
+            )));
+        }
+
+        let config: Config = self.source_provider().read_to_string(path.as_ref())
+            .map_err(|e| Error::Wrapped(Box::new(e)))?
+            .parse()?;
+
+        self.apply_config(&config);
+
+        Ok(())
+    }
+
+    /// Applies the mappings, printer options and allowed lints of a config to the project. Type overrides, identifier mappings and printer options that were already supplied take priority.
+    pub fn apply_config(&mut self, config: &Config) {
+        for (solidity_type_name, sway_type_name) in config.type_mappings.iter() {
+            self.type_overrides.entry(solidity_type_name.clone()).or_insert_with(|| sway::TypeName::Identifier {
+                name: sway_type_name.clone(),
+                generic_parameters: None,
+            });
+        }
+
+        for (old_name, new_name) in config.identifier_mappings.iter() {
+            self.identifier_mappings.entry(old_name.clone()).or_insert_with(|| new_name.clone());
+        }
+
+        self.printer_options.merge(&config.printer);
+
+        self.allowed_lints.extend(config.allow.iter().copied());
+    }
+
+    /// Translates a Solidity identifier to the supplied naming convention, unless it has an identifier mapping.
+    pub fn translate_naming_convention(&self, name: &str, case: Case) -> String {
+        if let Some(new_name) = self.identifier_mappings.get(name) {
+            return new_name.clone();
+        }
+
+        crate::translate_naming_convention(name, case)
+    }
+
+    /// Translates each added source unit, returning the translated definitions of each in the order they were added.
+    pub fn translate(&mut self) -> Result<Vec<TranslatedDefinition>, Error> {
+        let source_unit_paths = self.source_unit_paths.clone();
+
+        for source_unit_path in source_unit_paths.iter() {
+            self.translate_source_unit(None, source_unit_path)?;
+        }
+
+        Ok(source_unit_paths.iter().flat_map(|p| self.collect_translated_definitions(None, p)).collect())
+    }
+
+    /// Gets the path that the source unit at `path` is keyed by, which is its canonical path unless it is a virtual file.
+    pub fn get_source_unit_path<P: AsRef<Path>>(&self, path: P) -> std::io::Result<PathBuf> {
+        let normalized_path = normalize_path(path.as_ref());
+
+        if self.virtual_files.contains_key(&normalized_path) {
+            return Ok(normalized_path);
+        }
+
+        self.source_provider().canonicalize(path.as_ref())
+    }
+
+    /// Checks if the source unit at `path` is a virtual file or is provided by the source provider.
+    pub fn source_unit_exists<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.virtual_files.contains_key(&normalize_path(path.as_ref())) || self.source_provider().exists(path.as_ref())
+    }
+
+    /// Attempts to parse the virtual file or provided file from the supplied `path`, returning the path it is keyed by.
     #[inline]
-    fn parse_solidity_source_unit<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
-        if !path.as_ref().exists() {
+    fn parse_solidity_source_unit<P: AsRef<Path>>(&mut self, path: P) -> Result<PathBuf, Error> {
+        let normalized_path = normalize_path(path.as_ref());
+
+        if let Some(source) = self.virtual_files.get(&normalized_path).cloned() {
+            self.parse_solidity_source_text(normalized_path.clone(), source.as_str())?;
+            return Ok(normalized_path);
+        }
+
+        if !self.source_provider().exists(path.as_ref()) {
             return Err(Error::Wrapped(Box::new(
                 std::io::Error::new(
                     std::io::ErrorKind::NotFound,
@@ -54,64 +595,258 @@ impl Project {
             )));
         }
 
-        let path = crate::get_canonical_path(path, false, false)
+        let path = self.source_provider().canonicalize(path.as_ref())
             .map_err(|e| Error::Wrapped(Box::new(e)))?;
         
-        let source = std::fs::read_to_string(path.clone())
+        let source = self.source_provider().read_to_string(&path)
             .map_err(|e| Error::Wrapped(Box::new(e)))?;
         
-        self.load_line_ranges(path.clone(), source.as_str());
+        self.parse_solidity_source_text(path.clone(), source.as_str())?;
+
+        Ok(path)
+    }
 
-        let line_ranges = self.line_ranges.get(&path).unwrap();
+    /// Attempts to parse the supplied `source` as the file at `path`.
+    fn parse_solidity_source_text(&mut self, path: PathBuf, source: &str) -> Result<(), Error> {
+        let line_ranges = LineRanges::new(source);
+        self.line_ranges.insert(path.clone(), line_ranges.clone());
 
-        let (source_unit, _comments) = solang_parser::parse(source.as_str(), 0)
-            .map_err(|e| Error::SolangDiagnostics(path.clone(), line_ranges.clone(), e))?;
+        // NOTE: solang doesn't accept a byte order mark, so it is replaced with whitespace of the same length to keep the offsets of the file
+        let source = match source.strip_prefix(BYTE_ORDER_MARK) {
+            Some(source) => Cow::Owned(format!("{}{source}", " ".repeat(BYTE_ORDER_MARK.len_utf8()))),
+            None => Cow::Borrowed(source),
+        };
+
+        let (source_unit, comments) = solang_parser::parse(&source, 0)
+            .map_err(|e| Error::SolangDiagnostics(path.clone(), line_ranges, e))?;
 
-        // TODO: do we need the comments for anything?
+        // Keep track of the user-defined types of the source unit so they can be resolved from other source units
+        let mut user_types = BTreeMap::new();
 
-        self.solidity_source_units.borrow_mut().insert(path, source_unit);
+        for (index, source_unit_part) in source_unit.0.iter().enumerate() {
+            let (name, kind) = match source_unit_part {
+                solidity::SourceUnitPart::StructDefinition(struct_definition) => match struct_definition.name.as_ref() {
+                    Some(name) => (name.name.clone(), UserTypeKind::Struct),
+                    None => continue,
+                },
+
+                solidity::SourceUnitPart::EnumDefinition(enum_definition) => match enum_definition.name.as_ref() {
+                    Some(name) => (name.name.clone(), UserTypeKind::Enum),
+                    None => continue,
+                },
+
+                solidity::SourceUnitPart::TypeDefinition(type_definition) => (type_definition.name.name.clone(), UserTypeKind::TypeDefinition),
+
+                solidity::SourceUnitPart::ContractDefinition(contract_definition) => {
+                    (get_contract_definition_name(contract_definition, index), UserTypeKind::Contract)
+                }
+
+                _ => continue,
+            };
+
+            user_types.insert(name, kind);
+        }
+
+        self.user_types.insert(path.clone(), user_types);
+
+        // Keep the comments so they can be attached to the items and statements they are next to
+        self.comments.insert(path.clone(), comments);
+        self.solidity_source_units.insert(path, source_unit);
 
         Ok(())
     }
 
-    /// Loads line ranges in a specific file `path` from the provided `source` text.
-    #[inline]
-    fn load_line_ranges(&mut self, path: PathBuf, source: &str) {
-        let mut line_range = (0usize, 0usize);
+    /// Gets the comments that immediately precede `loc` in the file at `path` in source order, separated from it by whitespace only.
+    /// Comments that follow code on the same line are not included.
+    fn get_preceding_comments(&self, path: &Path, loc: &solidity::Loc) -> Vec<&solidity::Comment> {
+        let solidity::Loc::File(_, start, _) = loc else { return vec![] };
+        let (Some(comments), Some(line_ranges)) = (self.comments.get(path), self.line_ranges.get(path)) else { return vec![] };
+
+        let mut offset = *start;
+        let mut result = vec![];
+
+        for comment in comments.iter().rev() {
+            let solidity::Loc::File(_, comment_start, comment_end) = comment.loc() else { continue };
+
+            if comment_end > offset {
+                continue;
+            }
+
+            if !line_ranges.text(comment_end, offset).is_some_and(|text| text.trim().is_empty()) {
+                break;
+            }
+
+            // Comments following code on the same line belong to that code
+            let Some((line, _)) = line_ranges.offset_to_line_and_column(comment_start) else { break };
+            let Some((line_start, _)) = line_ranges.line_range(line) else { break };
+
+            if !line_ranges.text(line_start, comment_start).is_some_and(|text| text.trim().is_empty()) {
+                break;
+            }
+
+            offset = comment_start;
+            result.push(comment);
+        }
+
+        result.reverse();
+        result
+    }
 
-        for (i, c) in source.chars().enumerate() {
-            if c == '\n' {
-                line_range.1 = i;
-                self.line_ranges.entry(path.clone()).or_default().push(line_range);
-                line_range = (i + 1, 0);
+    /// Gets the comments that follow the code of `loc` on the line it ends on in the file at `path`, separated from it by whitespace
+    /// or a terminating `;` or `,` only, i.e: `uint256 public count; // trailing`
+    fn get_following_comments(&self, path: &Path, loc: &solidity::Loc) -> Vec<&solidity::Comment> {
+        let solidity::Loc::File(_, _, end) = loc else { return vec![] };
+        let (Some(comments), Some(line_ranges)) = (self.comments.get(path), self.line_ranges.get(path)) else { return vec![] };
+
+        let mut offset = *end;
+        let mut result = vec![];
+
+        for comment in comments.iter() {
+            let solidity::Loc::File(_, comment_start, comment_end) = comment.loc() else { continue };
+
+            if comment_start < offset {
+                continue;
             }
+
+            let is_same_line = line_ranges.text(offset, comment_start)
+                .is_some_and(|text| text.chars().all(|c| c == ';' || c == ',' || (c.is_whitespace() && c != '\n')));
+
+            if !is_same_line {
+                break;
+            }
+
+            offset = comment_end;
+            result.push(comment);
+        }
+
+        result
+    }
+
+    /// Gets the comments that immediately precede `loc` in the file at `path`, separated from it by whitespace only, one comment per line,
+    /// followed by the comments that follow the code of `loc` on the line it ends on, i.e: `uint256 public count; // trailing`.
+    /// Comments that follow other code on the same line are not included.
+    ///
+    /// Doc comments and license identifiers are skipped, since they are not explanatory comments on the code.
+    pub fn get_leading_comment<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Option<String> {
+        let comments = self.get_preceding_comments(path.as_ref(), loc).into_iter()
+            .chain(self.get_following_comments(path.as_ref(), loc))
+            .filter(|comment| is_explanatory_comment(comment))
+            .map(normalize_comment)
+            .collect::<Vec<_>>();
+
+        if comments.is_empty() {
+            None
+        } else {
+            Some(comments.join("\n"))
         }
+    }
 
-        if line_range.1 > line_range.0 {
-            self.line_ranges.entry(path.clone()).or_default().push(line_range);
+    /// Gets the comments that follow the code of `loc` on the line it ends on in the file at `path`, i.e: `x += 1; // trailing`,
+    /// along with the byte offset where the last of them ends, or where `loc` ends if there are none.
+    ///
+    /// Doc comments and license identifiers are skipped, since they are not explanatory comments on the code.
+    pub fn get_trailing_comments<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> (Vec<String>, Option<usize>) {
+        let comments = self.get_following_comments(path.as_ref(), loc);
+
+        let end = match comments.last().map(|comment| comment.loc()).unwrap_or(*loc) {
+            solidity::Loc::File(_, _, end) => Some(end),
+            _ => None,
+        };
+
+        let comments = comments.into_iter()
+            .filter(|comment| is_explanatory_comment(comment))
+            .map(normalize_comment)
+            .collect();
+
+        (comments, end)
+    }
+
+    /// Gets the NatSpec tags of the doc comments that immediately precede `loc` in the file at `path`.
+    /// Untagged lines are treated as `@notice` tags.
+    pub fn get_natspec_tags<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Vec<DocCommentTag> {
+        let doc_comments = self.get_preceding_comments(path.as_ref(), loc).into_iter()
+            .filter(|comment| comment.is_doc())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        parse_doccomments(doc_comments.as_slice(), 0, usize::MAX).into_iter()
+            .flat_map(DocComment::into_comments)
+            .collect()
+    }
+
+    /// Gets a comment referring to the line of `loc` in the file at `path` when source locations are enabled, i.e: `// from Token.sol:143`
+    pub fn get_source_location_comment<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Option<String> {
+        if !self.source_locations {
+            return None;
         }
+
+        let (line, _) = self.loc_to_line_and_column(path.as_ref(), loc)?;
+        Some(format!("// from {}:{line}", path.as_ref().file_name()?.to_string_lossy()))
+    }
+
+    /// Gets the comments located between the byte offsets `start` and `end` of the file at `path`.
+    ///
+    /// Doc comments and license identifiers are skipped, since they are not explanatory comments on the code.
+    pub fn get_comments_between<P: AsRef<Path>>(&self, path: P, start: usize, end: usize) -> Vec<String> {
+        let Some(comments) = self.comments.get(path.as_ref()) else { return vec![] };
+
+        comments.iter()
+            .filter(|comment| matches!(comment.loc(), solidity::Loc::File(_, comment_start, comment_end) if comment_start >= start && comment_end <= end))
+            .filter(|comment| is_explanatory_comment(comment))
+            .map(normalize_comment)
+            .collect()
     }
 
+    /// Gets the 1-based line and column of the byte `offset` in the file at `path`.
+    #[inline]
+    pub fn offset_to_line_and_column<P: AsRef<Path>>(&self, path: P, offset: usize) -> Option<(usize, usize)> {
+        self.line_ranges.get(path.as_ref())?.offset_to_line_and_column(offset)
+    }
+
+    /// Gets the 1-based line and column of the start of `loc` in the file at `path`.
     #[inline]
     pub fn loc_to_line_and_column<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Option<(usize, usize)> {
-        let Some(line_ranges) = self.line_ranges.get(path.as_ref()) else { return None };
+        self.line_ranges.get(path.as_ref())?.loc_to_line_and_column(loc)
+    }
+
+    /// Gets the 1-based lines and columns of the start and end of `loc` in the file at `path`.
+    #[inline]
+    pub fn loc_to_line_and_column_range<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Option<((usize, usize), (usize, usize))> {
+        self.line_ranges.get(path.as_ref())?.loc_to_line_and_column_range(loc)
+    }
 
-        let start = match loc {
-            solidity::Loc::Builtin
-            | solidity::Loc::CommandLine
-            | solidity::Loc::Implicit
-            | solidity::Loc::Codegen => return None,
+    /// Resolves the symbol referred to by `identifiers` in the file at `path` to the name it was declared with, following the aliases
+    /// and namespaces of the import directives of the file, i.e: `BaseToken` of `import {ERC20 as BaseToken} from "...";` or `Base.ERC20`
+    /// of `import * as Base from "...";` both resolve to `ERC20`.
+    ///
+    /// Returns `None` if `identifiers` doesn't refer to an aliased or namespaced symbol.
+    pub fn resolve_import_symbol<P: AsRef<Path>, S: AsRef<str>>(&self, path: P, identifiers: &[S]) -> Option<String> {
+        let import_symbols = self.import_symbols.get(path.as_ref())?;
 
-            solidity::Loc::File(_, start, _) => start,
-        };
+        match identifiers {
+            [name] => import_symbols.aliases.get(name.as_ref()).cloned(),
 
-        for (i, (line_start, line_end)) in line_ranges.iter().enumerate() {
-            if start >= line_start && start < line_end {
-                return Some((i + 1, (start - line_start) + 1));
+            [namespace, name] => {
+                let namespace_path = import_symbols.namespaces.get(namespace.as_ref())?;
+
+                // The symbol may have been imported into the namespaced source unit under an alias itself
+                Some(
+                    self.resolve_import_symbol(namespace_path, &[name.as_ref()])
+                        .unwrap_or_else(|| name.as_ref().to_string())
+                )
             }
+
+            _ => None,
+        }
+    }
+
+    /// Formats the location of `loc` in the file at `path` as `path:line:column`, or just `path` if the location is unknown.
+    #[inline]
+    pub fn loc_to_file_location_string<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> String {
+        match self.loc_to_line_and_column(path.as_ref(), loc) {
+            Some((line, col)) => format!("{}:{}:{}", path.as_ref().to_string_lossy(), line, col),
+            None => format!("{}", path.as_ref().to_string_lossy()),
         }
-        
-        None
     }
 
     pub fn collect_translated_definitions<P: AsRef<Path>>(&self, definition_name: Option<&String>, source_unit_path: P) -> Vec<TranslatedDefinition> {
@@ -124,8 +859,19 @@ impl Project {
                 }
             }
 
+            // Skip definitions that were rejected by strict mode
+            if self.rejected_definitions.contains(&(translated_definition.path.clone(), translated_definition.name.clone())) {
+                continue;
+            }
+
             if translated_definition.path == source_unit_path.as_ref() {
-                result.push(translated_definition.clone());
+                let mut translated_definition = translated_definition.clone();
+
+                if !self.emit_all_types {
+                    translated_definition.remove_unreferenced_events_and_errors_enums();
+                }
+
+                result.push(translated_definition);
             }
         }
 
@@ -140,17 +886,150 @@ impl Project {
         None
     }
 
-    pub fn translate(&mut self, definition_name: Option<&String>, source_unit_path: &Path) -> Result<(), Error> {
+    /// Gets the paths of the source units imported by the source unit at `source_unit_path`, parsing it first if necessary.
+    pub fn get_imported_source_unit_paths(&mut self, source_unit_path: &Path) -> Result<Vec<PathBuf>, Error> {
+        if let Some(import_paths) = self.import_graph.get(source_unit_path) {
+            return Ok(import_paths.clone());
+        }
+
+        if !self.solidity_source_units.contains(source_unit_path) {
+            self.parse_solidity_source_unit(source_unit_path)?;
+        }
+
+        let source_unit_directory = source_unit_path.parent().map(PathBuf::from).unwrap();
+        let source_unit_id = self.solidity_source_units.get_id(source_unit_path).unwrap();
+        let source_unit = self.solidity_source_units.get_shared(source_unit_id);
+
+        let mut import_paths = vec![];
+
+        for source_unit_part in source_unit.0.iter() {
+            let solidity::SourceUnitPart::ImportDirective(
+                solidity::Import::Plain(solidity::ImportPath::Filename(filename), _)
+                | solidity::Import::GlobalSymbol(solidity::ImportPath::Filename(filename), _, _)
+                | solidity::Import::Rename(solidity::ImportPath::Filename(filename), _, _)
+            ) = source_unit_part else { continue };
+
+            let import_path = if filename.string.starts_with('.') {
+                source_unit_directory.join(filename.string.as_str())
+            } else {
+                self.get_project_type_path(source_unit_directory.as_path(), filename.string.clone())?
+            };
+
+            let import_path = self.get_source_unit_path(import_path)
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+            if self.source_unit_exists(&import_path) && !import_paths.contains(&import_path) {
+                import_paths.push(import_path);
+            }
+        }
+
+        self.import_graph.insert(source_unit_path.into(), import_paths.clone());
+
+        Ok(import_paths)
+    }
+
+    /// Finds the source unit declaring the user-defined type `name` at its top level, as seen from the source unit at `source_unit_path`.
+    ///
+    /// The source unit itself is searched first, then the source units it imports directly or indirectly in import order,
+    /// and finally any other source unit that has been parsed, as long as only one of them declares `name`.
+    pub fn find_user_type(&mut self, source_unit_path: &Path, name: &str) -> Result<Option<(PathBuf, UserTypeKind)>, Error> {
+        let mut visited = HashSet::new();
+        let mut pending = VecDeque::from([source_unit_path.to_path_buf()]);
+
+        while let Some(path) = pending.pop_front() {
+            if !visited.insert(path.clone()) {
+                continue;
+            }
+
+            for import_path in self.get_imported_source_unit_paths(&path)? {
+                pending.push_back(import_path);
+            }
+
+            if let Some(kind) = self.user_types.get(&path).and_then(|user_types| user_types.get(name)) {
+                return Ok(Some((path, *kind)));
+            }
+        }
+
+        let mut user_types = self.user_types.iter()
+            .filter_map(|(path, user_types)| user_types.get(name).map(|kind| (path.clone(), *kind)));
+
+        match (user_types.next(), user_types.next()) {
+            (Some(user_type), None) => Ok(Some(user_type)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Finds the import cycle formed by the source unit at `source_unit_path` importing the source unit at `import_path`,
+    /// listing the paths of the source units in import order starting with `source_unit_path`.
+    ///
+    /// Returns `None` if the source unit at `import_path` doesn't import the source unit at `source_unit_path`, directly or indirectly.
+    pub fn find_import_cycle(&mut self, source_unit_path: &Path, import_path: &Path) -> Result<Option<Vec<PathBuf>>, Error> {
+        let mut visited = HashSet::new();
+
+        // Walk the import graph depth-first, keeping the chain of imports leading to each source unit
+        let mut pending = vec![vec![source_unit_path.to_path_buf(), import_path.to_path_buf()]];
+
+        while let Some(mut import_chain) = pending.pop() {
+            let path = import_chain.last().unwrap().clone();
+
+            if path == source_unit_path {
+                import_chain.pop();
+                return Ok(Some(import_chain));
+            }
+
+            if !visited.insert(path.clone()) {
+                continue;
+            }
+
+            // Push the imports in reverse so they are walked in source order
+            for import_path in self.get_imported_source_unit_paths(&path)?.into_iter().rev() {
+                if !visited.contains(&import_path) {
+                    let mut import_chain = import_chain.clone();
+                    import_chain.push(import_path);
+                    pending.push(import_chain);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Records the supplied import cycle and reports it the first time it is encountered, i.e: `NOTE: A.sol - circular import: A.sol -> B.sol -> A.sol`
+    pub fn report_import_cycle(&mut self, mut import_cycle: Vec<PathBuf>) {
+        // Start the cycle at its first path in sorting order, so the same cycle is only reported once regardless of where it was entered
+        let Some(start_index) = import_cycle.iter().enumerate().min_by_key(|(_, path)| *path).map(|(i, _)| i) else { return };
+        import_cycle.rotate_left(start_index);
+
+        if self.import_cycles.contains(&import_cycle) {
+            return;
+        }
+
+        emitln!(
+            self.emitter,
+            "NOTE: {} - circular import: {}",
+            import_cycle[0].to_string_lossy(),
+            import_cycle.iter()
+                .chain(std::iter::once(&import_cycle[0]))
+                .map(|path| crate::get_display_path(path).to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" -> "),
+        );
+
+        self.import_cycles.push(import_cycle);
+    }
+
+    /// Translates the definitions of the source unit at `source_unit_path`, parsing it first if necessary.
+    pub fn translate_source_unit(&mut self, definition_name: Option<&String>, source_unit_path: &Path) -> Result<(), Error> {
         let source_unit_directory = source_unit_path.parent().map(PathBuf::from).unwrap();
-        let solidity_source_units = self.solidity_source_units.clone();
 
         // Ensure the source unit has been parsed
-        if !solidity_source_units.borrow().contains_key(source_unit_path) {
+        if !self.solidity_source_units.contains(source_unit_path) {
             self.parse_solidity_source_unit(source_unit_path)?;
         }
         
-        // Get the parsed source unit
-        let source_unit = solidity_source_units.borrow().get(source_unit_path).unwrap().clone();
+        // Get a handle to the parsed source unit, which can be borrowed while the project is modified
+        let source_unit_id = self.solidity_source_units.get_id(source_unit_path).unwrap();
+        let source_unit = self.solidity_source_units.get_shared(source_unit_id);
 
         // Collect toplevel items ahead of time for contextual reasons
         let mut import_directives = vec![];
@@ -174,7 +1053,7 @@ impl Project {
                 }
 
                 solidity::SourceUnitPart::ContractDefinition(contract_definition) => {
-                    contract_names.push(contract_definition.name.as_ref().unwrap().name.clone());
+                    contract_names.push(get_contract_definition_name(contract_definition, contract_names.len()));
                 }
 
                 solidity::SourceUnitPart::EnumDefinition(enum_definition) => {
@@ -216,9 +1095,11 @@ impl Project {
             }
         }
 
-        // Extend the import directive tree
+        // Extend the import directive tree, keeping track of the symbols imported under another name
+        let mut import_symbols = ImportSymbols::default();
+
         for import_directive in import_directives.iter() {
-            let mut translate_import_directive = |definition_name: Option<&String>, filename: &solidity::StringLiteral| -> Result<(), Error> {
+            let mut translate_import_directive = |definition_name: Option<&String>, filename: &solidity::StringLiteral| -> Result<PathBuf, Error> {
                 let mut import_path = PathBuf::from(filename.string.clone());
 
                 if !import_path.to_string_lossy().starts_with('.') {
@@ -227,11 +1108,11 @@ impl Project {
                     import_path = source_unit_directory.join(import_path);
                 }
 
-                import_path = crate::get_canonical_path(import_path, false, false)
-                    .map_err(|e| Error::Wrapped(Box::new(e))).unwrap();
+                import_path = self.get_source_unit_path(import_path)
+                    .map_err(|e| Error::Wrapped(Box::new(e)))?;
                 
                 let import_directives = self.import_directives.entry(source_unit_path.into()).or_default();
-                let definition_names = import_directives.entry(import_path).or_default();
+                let definition_names = import_directives.entry(import_path.clone()).or_default();
 
                 if let Some(definition_name) = definition_name {
                     if definition_names.is_none() {
@@ -245,7 +1126,7 @@ impl Project {
                     }
                 }
 
-                Ok(())
+                Ok(import_path)
             };
 
             match import_directive {
@@ -253,9 +1134,19 @@ impl Project {
                     translate_import_directive(None, filename)?;
                 }
 
+                solidity::Import::GlobalSymbol(solidity::ImportPath::Filename(filename), namespace, _) => {
+                    let import_path = translate_import_directive(None, filename)?;
+
+                    import_symbols.namespaces.insert(namespace.name.clone(), import_path);
+                }
+
                 solidity::Import::Rename(solidity::ImportPath::Filename(filename), identifiers, _) => {
-                    for (identifier, _) in identifiers.iter() {
+                    for (identifier, alias) in identifiers.iter() {
                         translate_import_directive(Some(&identifier.name), filename)?;
+
+                        if let Some(alias) = alias.as_ref().filter(|alias| alias.name != identifier.name) {
+                            import_symbols.aliases.insert(alias.name.clone(), identifier.name.clone());
+                        }
                     }
                 }
 
@@ -263,17 +1154,57 @@ impl Project {
             }
         }
 
+        if import_symbols != ImportSymbols::default() {
+            self.import_symbols.insert(source_unit_path.into(), import_symbols);
+        }
+
         // Translate any contract definitions in the file
-        for source_unit_part in source_unit.0.iter() {
-            let solidity::SourceUnitPart::ContractDefinition(contract_definition) = source_unit_part else { continue };
+        let contract_definitions = source_unit.0.iter()
+            .filter_map(|source_unit_part| match source_unit_part {
+                solidity::SourceUnitPart::ContractDefinition(contract_definition) => Some(contract_definition),
+                _ => None,
+            })
+            .enumerate();
+
+        for (index, contract_definition) in contract_definitions {
+            let contract_name = get_contract_definition_name(contract_definition, index);
 
             if let Some(definition_name) = definition_name {
-                if contract_definition.name.as_ref().unwrap().name != *definition_name {
+                if contract_name != *definition_name {
                     continue;
                 }
             }
 
-            translate_contract_definition(
+            // Don't translate a contract that is already being translated (i.e: when definitions refer to each other)
+            if self.translation_stack.iter().any(|(path, name)| *name == contract_name && path == source_unit_path) {
+                continue;
+            }
+
+            // Don't translate the same contract twice (i.e: when it was already translated as an import)
+            if self.translated_definitions.iter().any(|d| d.name == contract_name && d.path == source_unit_path) {
+                continue;
+            }
+
+            let mut contract_definition = contract_definition.clone();
+
+            // Give unnamed contract definitions their generated name
+            let is_unnamed = contract_definition.name.as_ref().map(|n| n.name != contract_name).unwrap_or(true);
+
+            if is_unnamed {
+                emitln!(self.emitter, 
+                    "WARNING: {} - unnamed contract definition will be translated as `{contract_name}`",
+                    self.loc_to_file_location_string(source_unit_path, &contract_definition.loc),
+                );
+
+                contract_definition.name = Some(solidity::Identifier {
+                    loc: contract_definition.loc,
+                    name: contract_name.clone(),
+                });
+            }
+
+            self.translation_stack.push((source_unit_path.into(), contract_name.clone()));
+
+            let result = translate_contract_definition(
                 self,
                 source_unit_path,
                 import_directives.as_slice(),
@@ -285,8 +1216,18 @@ impl Project {
                 toplevel_errors.as_slice(),
                 toplevel_functions.as_slice(),
                 contract_names.as_slice(),
-                contract_definition,
-            )?;
+                &contract_definition,
+            );
+
+            self.translation_stack.pop();
+            result?;
+
+            // Record the generated name of an unnamed contract definition as a rename
+            if is_unnamed {
+                if let Some(translated_definition) = self.translated_definitions.iter_mut().find(|d| d.name == contract_name && d.path == source_unit_path) {
+                    translated_definition.add_renamed_identifier("", &contract_name, RenameReason::Unnamed);
+                }
+            }
         }
 
         Ok(())
@@ -296,8 +1237,10 @@ impl Project {
     pub fn find_project_root_folder<P: AsRef<Path>>(&self, path: P) -> Option<PathBuf> {
         let path = path.as_ref();
 
-        if path.join(ProjectType::FOUNDRY_CONFIG_FILE).exists() || path.join(ProjectType::HARDHAT_CONFIG_FILE).exists() 
-        || path.join(ProjectType::BROWNIE_CONFIG_FILE).exists() || path.join(ProjectType::TRUFFLE_CONFIG_FILE).exists() {
+        let source_provider = self.source_provider();
+
+        if source_provider.exists(&path.join(ProjectType::FOUNDRY_CONFIG_FILE)) || source_provider.exists(&path.join(ProjectType::HARDHAT_CONFIG_FILE))
+        || source_provider.exists(&path.join(ProjectType::BROWNIE_CONFIG_FILE)) || source_provider.exists(&path.join(ProjectType::TRUFFLE_CONFIG_FILE)) {
             return Some(path.to_path_buf());
         }
 
@@ -312,7 +1255,7 @@ impl Project {
     pub fn detect_project_type<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
         let path = path.as_ref();
 
-        if path.join(ProjectType::FOUNDRY_CONFIG_FILE).exists() {
+        if self.source_provider().exists(&path.join(ProjectType::FOUNDRY_CONFIG_FILE)) {
             self.project_type = ProjectType::Foundry {
                 remappings: HashMap::new(),
             };
@@ -324,11 +1267,11 @@ impl Project {
             self.project_type = ProjectType::Foundry { remappings };
         }
 
-        if path.join(ProjectType::HARDHAT_CONFIG_FILE).exists() {
+        if self.source_provider().exists(&path.join(ProjectType::HARDHAT_CONFIG_FILE)) {
             self.project_type = ProjectType::Hardhat;
         }
 
-        if path.join(ProjectType::BROWNIE_CONFIG_FILE).exists() {
+        if self.source_provider().exists(&path.join(ProjectType::BROWNIE_CONFIG_FILE)) {
             self.project_type = ProjectType::Brownie { remappings: HashMap::new() };
 
             self.project_type = ProjectType::Brownie { remappings: self.get_remappings(path).map_err(|e| Error::Wrapped(
@@ -336,7 +1279,7 @@ impl Project {
             ))? };
         }
 
-        if path.join(ProjectType::TRUFFLE_CONFIG_FILE).exists() {
+        if self.source_provider().exists(&path.join(ProjectType::TRUFFLE_CONFIG_FILE)) {
             self.project_type = ProjectType::Truffle;
         }
         
@@ -347,6 +1290,18 @@ impl Project {
     pub fn get_project_type_path(&self, source_unit_directory: &Path, filename: String) -> Result<PathBuf, Error> {
         let project_root_folder = self.find_project_root_folder(source_unit_directory);
 
+        // User-supplied remappings take priority over the remappings of the project type
+        for (k, v) in get_sorted_remappings(&self.remappings) {
+            if filename.starts_with(k) {
+                let remapped_path = match project_root_folder.as_ref() {
+                    Some(project_root_folder) => project_root_folder.join(v),
+                    None => PathBuf::from(v),
+                };
+
+                return Ok(PathBuf::from(filename.replacen(k, remapped_path.to_string_lossy().as_ref(), 1)));
+            }
+        }
+
         let Some(project_root_folder) = project_root_folder else {
             // If we cant find a project root folder we return the filename as is
             return Ok(PathBuf::from(filename.clone()));
@@ -355,7 +1310,7 @@ impl Project {
         match &self.project_type {
             // Remappings in foundry and brownie are handled using the same pattern
             ProjectType::Foundry { remappings } | ProjectType::Brownie { remappings } => {
-                for (k, v) in remappings {
+                for (k, v) in get_sorted_remappings(remappings) {
                     if filename.starts_with(k) {
                         let project_full_path = project_root_folder.join(v);
                         return Ok(PathBuf::from(filename.replace(k, project_full_path.to_string_lossy().as_ref())))
@@ -385,15 +1340,15 @@ impl Project {
             ProjectType::Foundry { .. } => {
                 let remappings_filename = "remappings.txt";
                 
-                let lines: Vec<String> = if root_folder_path.join(remappings_filename).exists() {
+                let lines: Vec<String> = if self.source_provider().exists(&root_folder_path.join(remappings_filename)) {
                     // Get the remappings.txt file from the root of the project folder
-                    let remappings_content = std::fs::read_to_string(root_folder_path.join(remappings_filename))
+                    let remappings_content = self.source_provider().read_to_string(&root_folder_path.join(remappings_filename))
                         .map_err(|e| Error::Wrapped(e.into()))?;
 
                     remappings_content.lines().map(str::to_string).collect()
                 } else {
                     // Get foundry toml file from the root of the project folder
-                    let remappings_from_toml_str = std::fs::read_to_string(root_folder_path.join(ProjectType::FOUNDRY_CONFIG_FILE))
+                    let remappings_from_toml_str = self.source_provider().read_to_string(&root_folder_path.join(ProjectType::FOUNDRY_CONFIG_FILE))
                         .map_err(|e| Error::Wrapped(e.into()))?;
         
                     let remappings_from_toml: toml::Value = toml::from_str(&remappings_from_toml_str)
@@ -423,7 +1378,7 @@ impl Project {
             }
 
             ProjectType::Brownie { .. } => {
-                let remappings_from_yaml_str = std::fs::read_to_string(root_folder_path.join(ProjectType::BROWNIE_CONFIG_FILE))
+                let remappings_from_yaml_str = self.source_provider().read_to_string(&root_folder_path.join(ProjectType::BROWNIE_CONFIG_FILE))
                     .map_err(|e| Error::Wrapped(e.into()))?;
 
                 let remappings_from_yaml: serde_yaml::Value = serde_yaml::from_str(&remappings_from_yaml_str)
@@ -479,3 +1434,115 @@ fn find_in_toml_value(value: &toml::Value, key: &str) -> Option<toml::Value> {
         _ => None,
     }
 }
+
+/// Gets the name of the contract definition, or a generated name based on its index within the source unit if it has no name
+pub fn get_contract_definition_name(contract_definition: &solidity::ContractDefinition, index: usize) -> String {
+    match contract_definition.name.as_ref() {
+        Some(identifier) if !identifier.name.is_empty() => identifier.name.clone(),
+        _ => format!("Unnamed{}", index + 1),
+    }
+}
+
+/// Gets the remappings ordered by descending prefix length, so the most specific remapping of an import is tried first regardless of the hash order
+fn get_sorted_remappings(remappings: &HashMap<String, String>) -> Vec<(&String, &String)> {
+    let mut remappings = remappings.iter().collect::<Vec<_>>();
+    remappings.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    remappings
+}
+
+/// Checks if the comment explains the code, as opposed to documenting it or identifying its license
+fn is_explanatory_comment(comment: &solidity::Comment) -> bool {
+    !comment.is_doc() && !comment.value().contains("SPDX-License-Identifier")
+}
+
+/// Gets the text of the comment with the common indentation of the lines after its first one removed, keeping the lines
+/// of block comments that start with `*` aligned with its opening line.
+fn normalize_comment(comment: &solidity::Comment) -> String {
+    let mut lines = comment.value().lines();
+    let mut result = vec![lines.next().unwrap_or_default().trim().to_string()];
+    let lines = lines.collect::<Vec<_>>();
+
+    let indentation = lines.iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    for line in lines {
+        let line = line.get(indentation..).unwrap_or_default().trim_end();
+
+        result.push(if line.starts_with('*') {
+            format!(" {line}")
+        } else {
+            line.to_string()
+        });
+    }
+
+    result.join("\n")
+}
+
+/// Lexically removes the `.` and `..` components of the supplied `path`, without accessing the file system
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir if result.file_name().is_some() => {
+                result.pop();
+            }
+            component => result.push(component),
+        }
+    }
+
+    result
+}
+
+/// Recursively search for .sol files in the given directory
+#[cfg(feature = "fs")]
+pub fn collect_source_unit_paths(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        if dir.extension().is_none_or(|extension| extension != "sol") {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Only solidity files are supported: {}", dir.to_string_lossy()),
+            ));
+        }
+
+        if !dir.exists() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("File not found: {}", dir.to_string_lossy()),
+            ));
+        }
+
+        return Ok(vec![
+            crate::get_canonical_path(dir, false, false)?,
+        ]);
+    }
+
+    let mut source_unit_paths = vec![];
+
+    // Visit the entries in a stable order, since the order of `read_dir` depends on the file system
+    let mut entry_paths = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+
+    entry_paths.sort();
+
+    for path in entry_paths {
+
+        if path.is_dir() {
+            source_unit_paths.extend(collect_source_unit_paths(&path)?);
+            continue;
+        }
+        
+        if let Some(extension) = path.extension() {
+            if extension == "sol" {
+                source_unit_paths.push(crate::get_canonical_path(path, false, false)?);
+            }
+        }
+    }
+
+    Ok(source_unit_paths)
+}
@@ -0,0 +1,211 @@
+use crate::{
+    project::{Lint, Warning},
+    sway,
+    translate::{RenameReason, TranslatedDefinition},
+    upgrade_diff,
+};
+use solang_parser::pt as solidity;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// Generates a markdown migration report for the supplied translated definitions, describing for each definition which functions
+/// were translated cleanly, which items were stubbed, which semantic differences were introduced and what has to be followed up manually.
+///
+/// Definitions that were rejected by strict mode are listed at the end of the report.
+pub fn generate_report(translated_definitions: &[TranslatedDefinition], rejected_definitions: &[(PathBuf, String)]) -> String {
+    let mut report = format!("# Migration Report\n\nGenerated by charcoal {}.\n\n", env!("CARGO_PKG_VERSION"));
+
+    let summaries = translated_definitions.iter().map(DefinitionSummary::new).collect::<Vec<_>>();
+
+    if summaries.is_empty() {
+        report.push_str("No definitions were translated.\n");
+    } else {
+        report.push_str("| Definition | Source | Clean Functions | Stubs | Semantic Differences |\n");
+        report.push_str("| --- | --- | --- | --- | --- |\n");
+
+        for summary in summaries.iter() {
+            report.push_str(&format!(
+                "| `{}` | `{}` | {}/{} | {} | {} |\n",
+                summary.translated_definition.name,
+                get_source_path_string(&summary.translated_definition.path),
+                summary.clean_functions.len(),
+                summary.function_count,
+                summary.stubbed_items.iter().map(|(_, count)| count).sum::<usize>(),
+                summary.semantic_differences.len(),
+            ));
+        }
+    }
+
+    for summary in summaries.iter() {
+        report.push('\n');
+        summary.write(&mut report);
+    }
+
+    if !rejected_definitions.is_empty() {
+        report.push_str("\n## Rejected Definitions\n\n");
+        report.push_str("The following definitions were rejected by strict mode and have to be translated manually:\n\n");
+
+        for (path, name) in rejected_definitions.iter() {
+            report.push_str(&format!("- [ ] `{name}` from `{}`\n", get_source_path_string(path)));
+        }
+    }
+
+    report
+}
+
+/// The reviewable state of a translated definition.
+struct DefinitionSummary<'a> {
+    translated_definition: &'a TranslatedDefinition,
+    function_count: usize,
+    clean_functions: Vec<String>,
+    /// The `(item, todo!() count)` of each generated item containing `todo!()` stubs.
+    stubbed_items: Vec<(String, usize)>,
+    /// The warnings of the lints that report semantic differences, sorted by severity.
+    semantic_differences: Vec<&'a Warning>,
+    review_notes: BTreeMap<Lint, usize>,
+}
+
+impl<'a> DefinitionSummary<'a> {
+    fn new(translated_definition: &'a TranslatedDefinition) -> Self {
+        let module: sway::Module = translated_definition.clone().into();
+
+        let mut function_count = 0;
+        let mut clean_functions = vec![];
+        let mut stubbed_items = vec![];
+
+        for (item_kind, item_name, code) in upgrade_diff::collect_module_items(&module) {
+            let item = format!("{item_kind} `{item_name}`");
+            let stub_count = code.matches("todo!").count();
+            let is_function = matches!(item_kind, "function" | "impl function");
+
+            if is_function {
+                function_count += 1;
+            }
+
+            if stub_count > 0 {
+                stubbed_items.push((item, stub_count));
+            } else if is_function {
+                clean_functions.push(item);
+            }
+        }
+
+        let mut semantic_differences = vec![];
+        let mut review_notes = BTreeMap::new();
+
+        for warning in translated_definition.warnings.iter() {
+            if warning.lint.is_semantic_drift() {
+                semantic_differences.push(warning);
+            } else {
+                *review_notes.entry(warning.lint).or_insert(0) += 1;
+            }
+        }
+
+        // List the most severe differences first, keeping the order of the code otherwise
+        semantic_differences.sort_by_key(|warning| std::cmp::Reverse(warning.severity));
+
+        Self {
+            translated_definition,
+            function_count,
+            clean_functions,
+            stubbed_items,
+            semantic_differences,
+            review_notes,
+        }
+    }
+
+    fn write(&self, report: &mut String) {
+        let kind = match self.translated_definition.kind.as_ref() {
+            Some(solidity::ContractTy::Abstract(_)) => "Abstract contract",
+            Some(solidity::ContractTy::Contract(_)) => "Contract",
+            Some(solidity::ContractTy::Interface(_)) => "Interface",
+            Some(solidity::ContractTy::Library(_)) => "Library",
+            None => "Definition",
+        };
+
+        report.push_str(&format!(
+            "## `{}`\n\n{kind} translated from `{}`.\n",
+            self.translated_definition.name,
+            get_source_path_string(&self.translated_definition.path),
+        ));
+
+        report.push_str("\n### Translated Cleanly\n\n");
+        write_list(report, self.clean_functions.iter().map(|item| format!("- {item}")));
+
+        report.push_str("\n### Stubbed\n\n");
+        write_list(report, self.stubbed_items.iter().map(|(item, count)| format!("- {item}: {count} `todo!()` stub(s)")));
+
+        report.push_str("\n### Semantic Differences\n\n");
+        write_list(report, self.semantic_differences.iter().map(|warning| {
+            format!("- **{}** `{}` at `{}`: {}", warning.severity, warning.lint, get_warning_location_string(warning), warning.message)
+        }));
+
+        report.push_str("\n### Manual Follow-Up\n\n");
+
+        let mut follow_ups = vec![];
+
+        for (item, count) in self.stubbed_items.iter() {
+            follow_ups.push(format!("- [ ] Implement the {count} `todo!()` stub(s) of the {item}"));
+        }
+
+        let mut semantic_difference_counts: BTreeMap<Lint, usize> = BTreeMap::new();
+
+        for warning in self.semantic_differences.iter() {
+            *semantic_difference_counts.entry(warning.lint).or_insert(0) += 1;
+        }
+
+        for (lint, count) in semantic_difference_counts.iter().chain(self.review_notes.iter()) {
+            follow_ups.push(format!("- [ ] Review the {count} `{lint}` finding(s): {}", lint.description()));
+        }
+
+        for renamed_identifier in self.translated_definition.renamed_identifiers.iter() {
+            match renamed_identifier.reason {
+                RenameReason::QualifiedName => {
+                    follow_ups.push(format!("- [ ] Check the references to the output of `{}`, which was named `{}` since another file defines a contract with the same name", renamed_identifier.old, renamed_identifier.new));
+                }
+
+                RenameReason::Unnamed => {
+                    follow_ups.push(format!("- [ ] Check the unnamed contract definition, which was translated as `{}`", renamed_identifier.new));
+                }
+
+                _ => {}
+            }
+        }
+
+        let renamed_count = self.translated_definition.renamed_identifiers.iter()
+            .filter(|i| matches!(i.reason, RenameReason::Keyword | RenameReason::Collision | RenameReason::Overload | RenameReason::IdentifierMapping))
+            .count();
+
+        if renamed_count > 0 {
+            follow_ups.push(format!("- [ ] Check the callers of the {renamed_count} identifier(s) renamed to avoid Sway keywords and name collisions"));
+        }
+
+        write_list(report, follow_ups.into_iter());
+    }
+}
+
+fn write_list<I: Iterator<Item = String>>(report: &mut String, lines: I) {
+    let mut is_empty = true;
+
+    for line in lines {
+        report.push_str(&line);
+        report.push('\n');
+        is_empty = false;
+    }
+
+    if is_empty {
+        report.push_str("None.\n");
+    }
+}
+
+fn get_source_path_string(path: &Path) -> String {
+    crate::get_display_path(path).to_string_lossy().to_string()
+}
+
+fn get_warning_location_string(warning: &Warning) -> String {
+    match warning.line_and_column {
+        Some((line, column)) => format!("{}:{line}:{column}", get_source_path_string(&warning.path)),
+        None => get_source_path_string(&warning.path),
+    }
+}
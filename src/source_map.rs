@@ -0,0 +1,231 @@
+use crate::{
+    printer::{get_structural_tokens, PrinterOptions},
+    project::Project,
+    sway,
+    translate::{ItemOrigin, ItemOriginKind, TranslatedDefinition},
+};
+use std::path::PathBuf;
+
+/// The comment that is placed before each item with a known origin while rendering a module, followed by the index of the item.
+const ITEM_MARKER: &str = "// charcoal-source-map:";
+
+/// A generated Sway item, along with the Solidity code it was translated from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceMapping {
+    pub item_kind: &'static str,
+    pub item_name: String,
+    pub source_path: PathBuf,
+    /// The byte offsets of the Solidity code in its source file.
+    pub source_offsets: (usize, usize),
+    /// The 1-based first and last lines of the Solidity code in its source file, if known.
+    pub source_lines: Option<(usize, usize)>,
+    /// The 1-based first and last lines of the generated item, including its attributes.
+    pub output_lines: (usize, usize),
+}
+
+/// Renders the module of the translated definition with the printer options, along with the Solidity code that each of its items was
+/// translated from. Items whose origin is unknown (i.e: generated helper functions) are not mapped.
+pub fn render_module_with_source_map(
+    project: &Project,
+    translated_definition: &TranslatedDefinition,
+    module: &sway::Module,
+    printer_options: &PrinterOptions,
+) -> (String, Vec<SourceMapping>) {
+    let mut module = module.clone();
+    let mut items = vec![];
+
+    // Mark each item with a known origin so it can be found in the rendered code
+    let mut mark_item = |comment: &mut Option<String>, item_kind: &'static str, item_name: String, origin: Option<&ItemOrigin>| {
+        let Some(origin) = origin else { return };
+        let marker = format!("{ITEM_MARKER}{}", items.len());
+
+        *comment = Some(match comment.take() {
+            Some(comment) => format!("{comment}\n{marker}"),
+            None => marker,
+        });
+
+        items.push((item_kind, item_name, origin.clone()));
+    };
+
+    for item in module.items.iter_mut() {
+        match item {
+            sway::ModuleItem::Function(x) => {
+                let origin = translated_definition.get_item_origin(ItemOriginKind::Function, &x.name);
+                mark_item(&mut x.comment, "function", x.name.clone(), origin);
+            }
+
+            sway::ModuleItem::Struct(x) => {
+                let origin = translated_definition.get_item_origin(ItemOriginKind::Struct, &x.name);
+                mark_item(&mut x.comment, "struct", x.name.clone(), origin);
+            }
+
+            sway::ModuleItem::Constant(x) => {
+                let origin = translated_definition.get_item_origin(ItemOriginKind::Constant, &x.name);
+                mark_item(&mut x.comment, "constant", x.name.clone(), origin);
+            }
+
+            sway::ModuleItem::Abi(x) => {
+                for function in x.functions.iter_mut() {
+                    let origin = translated_definition.get_item_origin(ItemOriginKind::AbiFunction, &function.name);
+                    mark_item(&mut function.comment, "abi function", format!("{}::{}", x.name, function.name), origin);
+                }
+            }
+
+            sway::ModuleItem::Storage(x) => {
+                for field in x.fields.iter_mut() {
+                    let origin = translated_definition.get_item_origin(ItemOriginKind::StorageField, &field.name);
+                    mark_item(&mut field.comment, "storage field", field.name.clone(), origin);
+                }
+            }
+
+            // Only the functions of the contract impl are translated from Solidity functions
+            sway::ModuleItem::Impl(x) if matches!(&x.for_type_name, Some(sway::TypeName::Identifier { name, .. }) if name == "Contract") => {
+                for impl_item in x.items.iter_mut() {
+                    let sway::ImplItem::Function(function) = impl_item else { continue };
+                    let origin = translated_definition.get_item_origin(ItemOriginKind::Function, &function.name);
+                    mark_item(&mut function.comment, "impl function", format!("{}::{}", x.type_name, function.name), origin);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    // Remove the markers from the rendered code, keeping track of the line that each marked item starts at
+    let text = printer_options.print(&module);
+    let mut lines = vec![];
+    let mut item_start_lines = vec![None; items.len()];
+
+    for line in text.split('\n') {
+        match line.trim_start().strip_prefix(ITEM_MARKER).and_then(|index| index.parse::<usize>().ok()) {
+            Some(index) => item_start_lines[index] = Some(lines.len()),
+            None => lines.push(line),
+        }
+    }
+
+    let mut mappings = vec![];
+
+    for ((item_kind, item_name, origin), start_line) in items.into_iter().zip(item_start_lines) {
+        let Some(start_line) = start_line else { continue };
+        let solang_parser::pt::Loc::File(_, start, end) = origin.loc else { continue };
+
+        let source_lines = project.line_ranges.get(&origin.path)
+            .and_then(|line_ranges| Some((line_ranges.offset_to_line_and_column(start)?.0, line_ranges.offset_to_line_and_column(end)?.0)));
+
+        mappings.push(SourceMapping {
+            item_kind,
+            item_name,
+            source_path: origin.path,
+            source_offsets: (start, end),
+            source_lines,
+            output_lines: (start_line + 1, get_item_end_line(lines.as_slice(), start_line) + 1),
+        });
+    }
+
+    mappings.sort_by_key(|mapping| mapping.output_lines);
+
+    (lines.join("\n"), mappings)
+}
+
+/// Gets the 0-based index of the last line of the item starting at the 0-based `start_line`, which is the first line
+/// outside of any brackets that ends the item with a `}`, `;` or `,`.
+fn get_item_end_line(lines: &[&str], start_line: usize) -> usize {
+    let mut depth = 0isize;
+
+    for (i, line) in lines.iter().enumerate().skip(start_line) {
+        for (_, c, _) in get_structural_tokens(line) {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        if depth <= 0 && line.trim_end().ends_with(['}', ';', ',']) {
+            return i;
+        }
+    }
+
+    lines.len().saturating_sub(1)
+}
+
+/// Serializes the source mappings of the generated file at `output_path` as JSON, i.e:
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "file": "src/main.sw",
+///   "mappings": [
+///     {
+///       "kind": "impl function",
+///       "name": "Token::transfer",
+///       "source": { "path": "contracts/Token.sol", "start": 1520, "end": 1790, "start_line": 48, "end_line": 55 },
+///       "output": { "start_line": 61, "end_line": 70 }
+///     }
+///   ]
+/// }
+/// ```
+///
+/// Source offsets are byte offsets and lines are 1-based and inclusive.
+pub fn source_map_to_json(output_path: &str, mappings: &[SourceMapping]) -> String {
+    let mut result = format!("{{\n  \"version\": 1,\n  \"file\": {},\n  \"mappings\": [", crate::to_json_string(output_path));
+
+    for (i, mapping) in mappings.iter().enumerate() {
+        if i > 0 {
+            result.push(',');
+        }
+
+        let source_lines = match mapping.source_lines {
+            Some((start_line, end_line)) => format!(", \"start_line\": {start_line}, \"end_line\": {end_line}"),
+            None => String::new(),
+        };
+
+        result.push_str(&format!(
+            "\n    {{\n      \"kind\": {},\n      \"name\": {},\n      \"source\": {{ \"path\": {}, \"start\": {}, \"end\": {}{source_lines} }},\n      \"output\": {{ \"start_line\": {}, \"end_line\": {} }}\n    }}",
+            crate::to_json_string(mapping.item_kind),
+            crate::to_json_string(&mapping.item_name),
+            crate::to_json_string(&crate::get_display_path(&mapping.source_path).to_string_lossy()),
+            mapping.source_offsets.0,
+            mapping.source_offsets.1,
+            mapping.output_lines.0,
+            mapping.output_lines.1,
+        ));
+    }
+
+    if !mappings.is_empty() {
+        result.push_str("\n  ");
+    }
+
+    result.push_str("]\n}\n");
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_map() {
+        let lines = ["struct S {", "    a: u64,", "}", "", "fn f(", "    a: u64,", ") -> u64 {", "    a", "}"];
+
+        assert_eq!(get_item_end_line(&lines, 0), 2);
+        assert_eq!(get_item_end_line(&lines, 1), 1);
+        assert_eq!(get_item_end_line(&lines, 4), 8);
+
+        let mappings = [SourceMapping {
+            item_kind: "function",
+            item_name: "f".into(),
+            source_path: PathBuf::from("F\"oo.sol"),
+            source_offsets: (10, 20),
+            source_lines: None,
+            output_lines: (5, 9),
+        }];
+
+        assert_eq!(source_map_to_json("src/main.sw", &[]), "{\n  \"version\": 1,\n  \"file\": \"src/main.sw\",\n  \"mappings\": []\n}\n");
+
+        assert_eq!(
+            source_map_to_json("src/main.sw", &mappings),
+            "{\n  \"version\": 1,\n  \"file\": \"src/main.sw\",\n  \"mappings\": [\n    {\n      \"kind\": \"function\",\n      \"name\": \"f\",\n      \"source\": { \"path\": \"F\\\"oo.sol\", \"start\": 10, \"end\": 20 },\n      \"output\": { \"start_line\": 5, \"end_line\": 9 }\n    }\n  ]\n}\n",
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+/// Provides the Solidity source files and project configuration files that a [Project](crate::project::Project) reads.
+pub trait SourceProvider {
+    /// Checks if a file exists at the supplied `path`.
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Gets the canonical path of the file at the supplied `path`, which its source unit is keyed by.
+    fn canonicalize(&self, path: &Path) -> std::io::Result<PathBuf>;
+
+    /// Reads the contents of the file at the supplied `path`.
+    fn read_to_string(&self, path: &Path) -> std::io::Result<String>;
+}
+
+/// Reads files from the file system.
+#[cfg(feature = "fs")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FileSystemSourceProvider;
+
+#[cfg(feature = "fs")]
+impl SourceProvider for FileSystemSourceProvider {
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn canonicalize(&self, path: &Path) -> std::io::Result<PathBuf> {
+        crate::get_canonical_path(path, false, false)
+    }
+
+    fn read_to_string(&self, path: &Path) -> std::io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+}
+
+/// Provides no files, so only the virtual files of a project can be translated.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmptySourceProvider;
+
+impl SourceProvider for EmptySourceProvider {
+    fn exists(&self, _path: &Path) -> bool {
+        false
+    }
+
+    fn canonicalize(&self, path: &Path) -> std::io::Result<PathBuf> {
+        Err(not_found_error(path))
+    }
+
+    fn read_to_string(&self, path: &Path) -> std::io::Result<String> {
+        Err(not_found_error(path))
+    }
+}
+
+fn not_found_error(path: &Path) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        format!("File not found: {}", path.to_string_lossy()),
+    )
+}
+
+/// Gets the source provider used by projects that were not given one, which reads from the file system when the `fs` feature is enabled.
+pub fn default_source_provider() -> &'static dyn SourceProvider {
+    #[cfg(feature = "fs")]
+    return &FileSystemSourceProvider;
+
+    #[cfg(not(feature = "fs"))]
+    return &EmptySourceProvider;
+}
@@ -0,0 +1,84 @@
+use solang_parser::pt as solidity;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+/// The identifier of a parsed source unit in a [SourceUnitArena], which stays valid for the lifetime of the arena.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceUnitId(usize);
+
+/// Stores the parsed source units of a project, which are looked up by identifier or by the path they are keyed by.
+///
+/// Source units are never removed from the arena, so a shared handle from [SourceUnitArena::get_shared] can be held while the project is modified.
+/// Parsing a file again adds a new source unit that its path resolves to from then on.
+#[derive(Clone, Debug, Default)]
+pub struct SourceUnitArena {
+    source_units: Vec<(PathBuf, Rc<solidity::SourceUnit>)>,
+    ids: HashMap<PathBuf, SourceUnitId>,
+}
+
+impl SourceUnitArena {
+    /// Adds the source unit parsed from the file at `path`, replacing the source unit that the path previously resolved to.
+    pub fn insert(&mut self, path: PathBuf, source_unit: solidity::SourceUnit) -> SourceUnitId {
+        let id = SourceUnitId(self.source_units.len());
+
+        self.source_units.push((path.clone(), Rc::new(source_unit)));
+        self.ids.insert(path, id);
+
+        id
+    }
+
+    /// Stops resolving `path` to a source unit, i.e: when the file needs to be parsed again.
+    pub fn remove<P: AsRef<Path>>(&mut self, path: P) -> Option<SourceUnitId> {
+        self.ids.remove(path.as_ref())
+    }
+
+    /// Gets the identifier of the source unit that `path` resolves to.
+    #[inline]
+    pub fn get_id<P: AsRef<Path>>(&self, path: P) -> Option<SourceUnitId> {
+        self.ids.get(path.as_ref()).copied()
+    }
+
+    /// Checks if `path` resolves to a source unit.
+    #[inline]
+    pub fn contains<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.ids.contains_key(path.as_ref())
+    }
+
+    #[inline]
+    pub fn get(&self, id: SourceUnitId) -> &solidity::SourceUnit {
+        self.source_units[id.0].1.as_ref()
+    }
+
+    /// Gets the path of the file that the source unit was parsed from.
+    #[inline]
+    pub fn get_path(&self, id: SourceUnitId) -> &Path {
+        self.source_units[id.0].0.as_path()
+    }
+
+    /// Gets a handle to the source unit that can be held without borrowing the arena.
+    #[inline]
+    pub fn get_shared(&self, id: SourceUnitId) -> Rc<solidity::SourceUnit> {
+        self.source_units[id.0].1.clone()
+    }
+
+    /// Gets the source unit that `path` resolves to.
+    #[inline]
+    pub fn get_by_path<P: AsRef<Path>>(&self, path: P) -> Option<&solidity::SourceUnit> {
+        self.get_id(path).map(|id| self.get(id))
+    }
+
+    /// Gets the identifiers of the source units that the paths resolve to, in the order they were parsed.
+    pub fn ids(&self) -> impl Iterator<Item = SourceUnitId> + '_ {
+        (0..self.source_units.len())
+            .map(SourceUnitId)
+            .filter(|id| self.ids.get(&self.source_units[id.0].0) == Some(id))
+    }
+
+    /// Iterates over the source units that the paths resolve to along with their paths, in the order they were parsed.
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &solidity::SourceUnit)> + '_ {
+        self.ids().map(|id| (self.get_path(id), self.get(id)))
+    }
+}
@@ -1,4 +1,5 @@
 use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
@@ -24,9 +25,37 @@ impl<T: TabbedDisplay> Display for TabbedDisplayer<'_, T> {
     }
 }
 
+/// Writes the lines of a comment, indenting each line after the first one to the supplied depth.
+fn write_comment(comment: &str, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (i, line) in comment.lines().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+
+            if !line.is_empty() {
+                "".tabbed_fmt(depth, f)?;
+            }
+        }
+
+        write!(f, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Writes the comment preceding an item followed by the indentation of the item, if any.
+fn write_leading_comment(comment: Option<&String>, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some(comment) = comment {
+        write_comment(comment, depth, f)?;
+        writeln!(f)?;
+        "".tabbed_fmt(depth, f)?;
+    }
+
+    Ok(())
+}
+
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ModuleKind {
     Contract,
     Library,
@@ -47,7 +76,7 @@ impl Display for ModuleKind {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Module {
     pub kind: ModuleKind,
     pub items: Vec<ModuleItem>,
@@ -158,7 +187,8 @@ impl TabbedDisplay for Module {
 
         for (i, item) in self.items.iter().enumerate() {
             if let Some(prev_item) = prev_item {
-                if !(matches!(prev_item, ModuleItem::Use(_)) && matches!(item, ModuleItem::Use(_)) 
+                if !(matches!(prev_item, ModuleItem::Submodule(_)) && matches!(item, ModuleItem::Submodule(_))
+                || matches!(prev_item, ModuleItem::Use(_)) && matches!(item, ModuleItem::Use(_)) 
                 || matches!(prev_item, ModuleItem::Constant(_)) && matches!(item, ModuleItem::Constant(_))
                 || matches!(prev_item, ModuleItem::TypeDefinition(_)) && matches!(item, ModuleItem::TypeDefinition(_))) {
                     writeln!(f)?;
@@ -179,8 +209,10 @@ impl TabbedDisplay for Module {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ModuleItem {
+    Comment(String),
+    Submodule(String),
     Use(Use),
     TypeDefinition(TypeDefinition),
     Constant(Constant),
@@ -197,6 +229,8 @@ pub enum ModuleItem {
 impl TabbedDisplay for ModuleItem {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            ModuleItem::Comment(x) => write_comment(x, depth, f),
+            ModuleItem::Submodule(x) => write!(f, "mod {x};"),
             ModuleItem::Use(x) => x.tabbed_fmt(depth, f),
             ModuleItem::TypeDefinition(x) => x.tabbed_fmt(depth, f),
             ModuleItem::Constant(x) => x.tabbed_fmt(depth, f),
@@ -214,7 +248,7 @@ impl TabbedDisplay for ModuleItem {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Use {
     pub is_public: bool,
     pub tree: UseTree,
@@ -232,7 +266,7 @@ impl Display for Use {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum UseTree {
     Path {
         prefix: String,
@@ -265,7 +299,7 @@ impl Display for UseTree {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GenericParameter {
     pub type_name: TypeName,
     pub implements: Option<Vec<TypeName>>,
@@ -285,7 +319,7 @@ impl Display for GenericParameter {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct GenericParameterList {
     pub entries: Vec<GenericParameter>,
 }
@@ -298,7 +332,7 @@ impl Display for GenericParameterList {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Attribute {
     pub name: String,
     pub parameters: Option<Vec<String>>,
@@ -318,7 +352,7 @@ impl Display for Attribute {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct AttributeList {
     pub attributes: Vec<Attribute>,
 }
@@ -331,7 +365,7 @@ impl Display for AttributeList {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum TypeName {
     #[default]
     Undefined,
@@ -367,6 +401,14 @@ impl Display for TypeName {
 }
 
 impl TypeName {
+    /// Creates a type name without generic parameters, i.e: `Identity`
+    pub fn create_identifier(name: &str) -> TypeName {
+        TypeName::Identifier {
+            name: name.into(),
+            generic_parameters: None,
+        }
+    }
+
     /// Checks if the type name is an unsigned integer type
     pub fn is_uint(&self) -> bool {
         match self {
@@ -378,6 +420,21 @@ impl TypeName {
         }
     }
 
+    /// Gets the bit width of the type name if it is a native unsigned integer type
+    pub fn uint_bits(&self) -> Option<usize> {
+        match self {
+            TypeName::Identifier { name, generic_parameters: None } => match name.as_str() {
+                "u8" => Some(8),
+                "u16" => Some(16),
+                "u32" => Some(32),
+                "u64" => Some(64),
+                "u256" => Some(256),
+                _ => None,
+            }
+            _ => None,
+        }
+    }
+
     /// Checks if the type name is a signed integer type
     pub fn is_int(&self) -> bool {
         match self {
@@ -487,7 +544,7 @@ impl TypeName {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TypeDefinition {
     pub is_public: bool,
     pub name: TypeName,
@@ -512,8 +569,9 @@ impl Display for TypeDefinition {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Constant {
+    pub comment: Option<String>,
     pub is_public: bool,
     pub name: String,
     pub type_name: TypeName,
@@ -522,6 +580,8 @@ pub struct Constant {
 
 impl TabbedDisplay for Constant {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_leading_comment(self.comment.as_ref(), depth, f)?;
+
         if self.is_public {
             write!(f, "pub ")?;
         }
@@ -539,11 +599,12 @@ impl TabbedDisplay for Constant {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Literal {
     Bool(bool),
     DecInt(BigUint),
     HexInt(BigUint),
+    B256(BigUint),
     String(String),
 }
 
@@ -551,8 +612,12 @@ impl TabbedDisplay for Literal {
     fn tabbed_fmt(&self, _depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Literal::Bool(x) => write!(f, "{x}"),
+            // Integers that don't fit in a u64 need to be suffixed
+            Literal::DecInt(x) if x.bits() > 64 => write!(f, "{x}u256"),
             Literal::DecInt(x) => write!(f, "{x}"),
+            Literal::HexInt(x) if x.bits() > 64 => write!(f, "0x{x:X}u256"),
             Literal::HexInt(x) => write!(f, "0x{x:X}"),
+            Literal::B256(x) => write!(f, "0x{x:064X}"),
             Literal::String(x) => write!(f, "\"{x}\""),
         }
     }
@@ -560,8 +625,9 @@ impl TabbedDisplay for Literal {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Struct {
+    pub comment: Option<String>,
     pub attributes: Option<AttributeList>,
     pub is_public: bool,
     pub name: String,
@@ -571,6 +637,8 @@ pub struct Struct {
 
 impl TabbedDisplay for Struct {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_leading_comment(self.comment.as_ref(), depth, f)?;
+
         if let Some(attributes) = self.attributes.as_ref() {
             writeln!(f, "{attributes}")?;
             "".tabbed_fmt(depth, f)?;
@@ -602,7 +670,7 @@ impl TabbedDisplay for Struct {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StructField {
     pub is_public: bool,
     pub name: String,
@@ -621,7 +689,7 @@ impl Display for StructField {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Enum {
     pub attributes: Option<AttributeList>,
     pub is_public: bool,
@@ -663,7 +731,7 @@ impl TabbedDisplay for Enum {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EnumVariant {
     pub name: String,
     pub type_name: TypeName,
@@ -677,7 +745,7 @@ impl Display for EnumVariant {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Abi {
     pub name: String,
     pub inherits: Vec<String>,
@@ -710,7 +778,7 @@ impl TabbedDisplay for Abi {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Trait {
     pub attributes: Option<AttributeList>,
     pub is_public: bool,
@@ -752,7 +820,7 @@ impl TabbedDisplay for Trait {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum TraitItem {
     Constant(Constant),
     TypeName(GenericParameter),
@@ -771,7 +839,7 @@ impl TabbedDisplay for TraitItem {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Storage {
     pub fields: Vec<StorageField>,
 }
@@ -792,8 +860,9 @@ impl TabbedDisplay for Storage {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StorageField {
+    pub comment: Option<String>,
     pub name: String,
     pub type_name: TypeName,
     pub value: Expression,
@@ -801,6 +870,7 @@ pub struct StorageField {
 
 impl TabbedDisplay for StorageField {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_leading_comment(self.comment.as_ref(), depth, f)?;
         write!(f, "{}: {} = ", self.name, self.type_name)?;
         self.value.tabbed_fmt(depth, f)
     }
@@ -808,7 +878,7 @@ impl TabbedDisplay for StorageField {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Configurable {
     pub fields: Vec<ConfigurableField>,
 }
@@ -829,7 +899,7 @@ impl TabbedDisplay for Configurable {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurableField {
     pub name: String,
     pub type_name: TypeName,
@@ -845,8 +915,9 @@ impl TabbedDisplay for ConfigurableField {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Function {
+    pub comment: Option<String>,
     pub attributes: Option<AttributeList>,
     pub is_public: bool,
     pub name: String,
@@ -856,8 +927,23 @@ pub struct Function {
     pub body: Option<Block>,
 }
 
+impl Function {
+    /// Checks if the function is the same as `other`, ignoring their comments.
+    pub fn is_equivalent(&self, other: &Function) -> bool {
+        self.attributes == other.attributes
+            && self.is_public == other.is_public
+            && self.name == other.name
+            && self.generic_parameters == other.generic_parameters
+            && self.parameters == other.parameters
+            && self.return_type == other.return_type
+            && self.body == other.body
+    }
+}
+
 impl TabbedDisplay for Function {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_leading_comment(self.comment.as_ref(), depth, f)?;
+
         if let Some(attributes) = self.attributes.as_ref() {
             writeln!(f, "{attributes}")?;
             "".tabbed_fmt(depth, f)?;
@@ -896,7 +982,7 @@ impl TabbedDisplay for Function {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Parameter {
     pub is_ref: bool,
     pub is_mut: bool,
@@ -926,7 +1012,7 @@ impl Display for Parameter {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ParameterList {
     pub entries: Vec<Parameter>,
 }
@@ -939,7 +1025,7 @@ impl Display for ParameterList {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Impl {
     pub generic_parameters: Option<GenericParameterList>,
     pub type_name: TypeName,
@@ -986,7 +1072,7 @@ impl TabbedDisplay for Impl {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ImplItem {
     Constant(Constant),
     TypeDefinition(TypeDefinition),
@@ -1005,12 +1091,93 @@ impl TabbedDisplay for ImplItem {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Block {
     pub statements: Vec<Statement>,
     pub final_expr: Option<Expression>,
 }
 
+impl Block {
+    /// Calls `f` on every expression within the block, including nested expressions
+    pub fn visit_expressions<F: FnMut(&Expression)>(&self, f: &mut F) {
+        for statement in self.statements.iter() {
+            match statement {
+                Statement::Let(x) => x.value.visit(f),
+                Statement::Expression(x) => x.visit(f),
+                Statement::Comment(_) => {}
+            }
+        }
+
+        if let Some(final_expr) = self.final_expr.as_ref() {
+            final_expr.visit(f);
+        }
+    }
+
+    /// Calls `f` on every expression within the block, including nested expressions, allowing each expression to be modified
+    pub fn visit_expressions_mut<F: FnMut(&mut Expression)>(&mut self, f: &mut F) {
+        for statement in self.statements.iter_mut() {
+            match statement {
+                Statement::Let(x) => x.value.visit_mut(f),
+                Statement::Expression(x) => x.visit_mut(f),
+                Statement::Comment(_) => {}
+            }
+        }
+
+        if let Some(final_expr) = self.final_expr.as_mut() {
+            final_expr.visit_mut(f);
+        }
+    }
+
+    /// Calls `f` on the block and every block nested within it, i.e: the bodies of blocks, `if`, `while` and `match` expressions
+    pub fn visit_blocks<F: FnMut(&Block)>(&self, f: &mut F) {
+        f(self);
+
+        self.visit_expressions(&mut |expression| match expression {
+            // NOTE: The bodies of `match` branches are visited as block expressions
+            Expression::Block(block) => f(block),
+            Expression::While(x) => f(&x.body),
+
+            Expression::If(x) => {
+                let mut next = Some(x.as_ref());
+
+                while let Some(x) = next {
+                    f(&x.then_body);
+                    next = x.else_if.as_deref();
+                }
+            }
+
+            _ => {}
+        });
+    }
+
+    /// Calls `f` on the block and every block nested within it, allowing each block to be modified
+    pub fn visit_blocks_mut<F: FnMut(&mut Block)>(&mut self, f: &mut F) {
+        f(self);
+
+        self.visit_expressions_mut(&mut |expression| match expression {
+            // NOTE: The bodies of `match` branches are visited as block expressions
+            Expression::Block(block) => f(block),
+            Expression::While(x) => f(&mut x.body),
+
+            Expression::If(x) => {
+                let mut next = Some(x.as_mut());
+
+                while let Some(x) = next {
+                    f(&mut x.then_body);
+                    next = x.else_if.as_deref_mut();
+                }
+            }
+
+            _ => {}
+        });
+    }
+
+    /// Retains the statements of the block and its nested blocks that satisfy `f`
+    pub fn retain_statements<F: FnMut(&Statement) -> bool>(&mut self, f: &mut F) {
+        self.visit_blocks_mut(&mut |block| block.statements.retain(|s| f(s)));
+    }
+}
+
 impl TabbedDisplay for Block {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{{")?;
@@ -1033,10 +1200,11 @@ impl TabbedDisplay for Block {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Statement {
     Let(Let),
     Expression(Expression),
+    Comment(String),
     // TODO: finish
 }
 
@@ -1057,6 +1225,8 @@ impl TabbedDisplay for Statement {
 
                 Ok(())
             }
+
+            Statement::Comment(x) => write_comment(x, depth, f),
         }
     }
 }
@@ -1076,7 +1246,7 @@ impl_stmt_from!(Expression);
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Let {
     pub pattern: LetPattern,
     pub type_name: Option<TypeName>,
@@ -1098,7 +1268,7 @@ impl TabbedDisplay for Let {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum LetPattern {
     Identifier(LetIdentifier),
     Tuple(Vec<LetIdentifier>),
@@ -1127,7 +1297,7 @@ impl From<Vec<LetIdentifier>> for LetPattern {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LetIdentifier {
     pub is_mutable: bool,
     pub name: String,
@@ -1145,7 +1315,7 @@ impl Display for LetIdentifier {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Expression {
     Literal(Literal),
     Identifier(String),
@@ -1266,6 +1436,27 @@ impl Expression {
         }))
     }
 
+    /// Creates a call to a function by its path, i.e: `sway_libs::ownership::only_owner()`
+    pub fn create_function_call(function: &str, parameters: Vec<Expression>) -> Expression {
+        Expression::FunctionCall(Box::new(FunctionCall {
+            function: Expression::Identifier(function.into()),
+            generic_parameters: None,
+            parameters,
+        }))
+    }
+
+    /// Creates a call to a method of an expression, i.e: `expression.method(parameters)`
+    pub fn create_method_call(expression: Expression, method: &str, parameters: Vec<Expression>) -> Expression {
+        Expression::FunctionCall(Box::new(FunctionCall {
+            function: Expression::MemberAccess(Box::new(MemberAccess {
+                expression,
+                member: method.into(),
+            })),
+            generic_parameters: None,
+            parameters,
+        }))
+    }
+
     pub fn create_unimplemented(msg: Option<String>) -> Expression {
         Expression::FunctionCall(Box::new(FunctionCall {
             function: Expression::Identifier("unimplemented!".into()),
@@ -1279,11 +1470,167 @@ impl Expression {
             },
         }))
     }
+
+    /// Calls `f` on the expression and every expression nested within it
+    pub fn visit<F: FnMut(&Expression)>(&self, f: &mut F) {
+        f(self);
+
+        match self {
+            Expression::Literal(_) | Expression::Identifier(_) | Expression::Continue | Expression::Break => {}
+
+            Expression::FunctionCall(x) => {
+                x.function.visit(f);
+                x.parameters.iter().for_each(|p| p.visit(f));
+            }
+
+            Expression::FunctionCallBlock(x) => {
+                x.function.visit(f);
+                x.fields.iter().for_each(|field| field.value.visit(f));
+                x.parameters.iter().for_each(|p| p.visit(f));
+            }
+
+            Expression::Block(x) => x.visit_expressions(f),
+            Expression::Return(x) => if let Some(x) = x.as_ref() { x.visit(f) },
+            Expression::Array(x) => x.elements.iter().for_each(|e| e.visit(f)),
+
+            Expression::ArrayAccess(x) => {
+                x.expression.visit(f);
+                x.index.visit(f);
+            }
+
+            Expression::MemberAccess(x) => x.expression.visit(f),
+            Expression::Tuple(x) => x.iter().for_each(|e| e.visit(f)),
+
+            Expression::If(x) => {
+                let mut x = Some(x.as_ref());
+
+                while let Some(if_expression) = x {
+                    if let Some(condition) = if_expression.condition.as_ref() {
+                        condition.visit(f);
+                    }
+
+                    if_expression.then_body.visit_expressions(f);
+                    x = if_expression.else_if.as_deref();
+                }
+            }
+
+            Expression::Match(x) => {
+                x.expression.visit(f);
+
+                for branch in x.branches.iter() {
+                    branch.pattern.visit(f);
+                    branch.value.visit(f);
+                }
+            }
+
+            Expression::While(x) => {
+                x.condition.visit(f);
+                x.body.visit_expressions(f);
+            }
+
+            Expression::UnaryExpression(x) => x.expression.visit(f),
+
+            Expression::BinaryExpression(x) => {
+                x.lhs.visit(f);
+                x.rhs.visit(f);
+            }
+
+            Expression::Constructor(x) => x.fields.iter().for_each(|field| field.value.visit(f)),
+
+            Expression::AsmBlock(x) => {
+                for register in x.registers.iter() {
+                    if let Some(value) = register.value.as_ref() {
+                        value.visit(f);
+                    }
+                }
+            }
+
+            Expression::Commented(_, x) => x.visit(f),
+        }
+    }
+
+    /// Calls `f` on the expression and every expression nested within it, allowing each expression to be modified
+    pub fn visit_mut<F: FnMut(&mut Expression)>(&mut self, f: &mut F) {
+        f(self);
+
+        match self {
+            Expression::Literal(_) | Expression::Identifier(_) | Expression::Continue | Expression::Break => {}
+
+            Expression::FunctionCall(x) => {
+                x.function.visit_mut(f);
+                x.parameters.iter_mut().for_each(|p| p.visit_mut(f));
+            }
+
+            Expression::FunctionCallBlock(x) => {
+                x.function.visit_mut(f);
+                x.fields.iter_mut().for_each(|field| field.value.visit_mut(f));
+                x.parameters.iter_mut().for_each(|p| p.visit_mut(f));
+            }
+
+            Expression::Block(x) => x.visit_expressions_mut(f),
+            Expression::Return(x) => if let Some(x) = x.as_mut() { x.visit_mut(f) },
+            Expression::Array(x) => x.elements.iter_mut().for_each(|e| e.visit_mut(f)),
+
+            Expression::ArrayAccess(x) => {
+                x.expression.visit_mut(f);
+                x.index.visit_mut(f);
+            }
+
+            Expression::MemberAccess(x) => x.expression.visit_mut(f),
+            Expression::Tuple(x) => x.iter_mut().for_each(|e| e.visit_mut(f)),
+
+            Expression::If(x) => {
+                let mut x = Some(x.as_mut());
+
+                while let Some(if_expression) = x {
+                    if let Some(condition) = if_expression.condition.as_mut() {
+                        condition.visit_mut(f);
+                    }
+
+                    if_expression.then_body.visit_expressions_mut(f);
+                    x = if_expression.else_if.as_deref_mut();
+                }
+            }
+
+            Expression::Match(x) => {
+                x.expression.visit_mut(f);
+
+                for branch in x.branches.iter_mut() {
+                    branch.pattern.visit_mut(f);
+                    branch.value.visit_mut(f);
+                }
+            }
+
+            Expression::While(x) => {
+                x.condition.visit_mut(f);
+                x.body.visit_expressions_mut(f);
+            }
+
+            Expression::UnaryExpression(x) => x.expression.visit_mut(f),
+
+            Expression::BinaryExpression(x) => {
+                x.lhs.visit_mut(f);
+                x.rhs.visit_mut(f);
+            }
+
+            Expression::Constructor(x) => x.fields.iter_mut().for_each(|field| field.value.visit_mut(f)),
+
+            Expression::AsmBlock(x) => {
+                for register in x.registers.iter_mut() {
+                    if let Some(value) = register.value.as_mut() {
+                        value.visit_mut(f);
+                    }
+                }
+            }
+
+            Expression::Commented(_, x) => x.visit_mut(f),
+        }
+    }
 }
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FunctionCall {
     pub function: Expression,
     pub generic_parameters: Option<GenericParameterList>,
@@ -1314,7 +1661,7 @@ impl TabbedDisplay for FunctionCall {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FunctionCallBlock {
     pub function: Expression,
     pub generic_parameters: Option<GenericParameterList>,
@@ -1357,7 +1704,7 @@ impl TabbedDisplay for FunctionCallBlock {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Array {
     pub elements: Vec<Expression>,
 }
@@ -1380,7 +1727,7 @@ impl TabbedDisplay for Array {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ArrayAccess {
     pub expression: Expression,
     pub index: Expression,
@@ -1397,7 +1744,7 @@ impl TabbedDisplay for ArrayAccess {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MemberAccess {
     pub expression: Expression,
     pub member: String,
@@ -1412,7 +1759,7 @@ impl TabbedDisplay for MemberAccess {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct If {
     pub condition: Option<Expression>,
     pub then_body: Block,
@@ -1440,7 +1787,7 @@ impl TabbedDisplay for If {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Match {
     pub expression: Expression,
     pub branches: Vec<MatchBranch>,
@@ -1462,7 +1809,7 @@ impl TabbedDisplay for Match {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MatchBranch {
     pub pattern: Expression,
     pub value: Expression,
@@ -1479,7 +1826,7 @@ impl TabbedDisplay for MatchBranch {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct While {
     pub condition: Expression,
     pub body: Block,
@@ -1496,7 +1843,7 @@ impl TabbedDisplay for While {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UnaryExpression {
     pub operator: String,
     pub expression: Expression,
@@ -1511,7 +1858,7 @@ impl TabbedDisplay for UnaryExpression {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BinaryExpression {
     pub operator: String,
     pub lhs: Expression,
@@ -1528,7 +1875,7 @@ impl TabbedDisplay for BinaryExpression {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Constructor {
     pub type_name: TypeName,
     pub fields: Vec<ConstructorField>,
@@ -1557,7 +1904,7 @@ impl TabbedDisplay for Constructor {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConstructorField {
     pub name: String,
     pub value: Expression,
@@ -1573,7 +1920,7 @@ impl TabbedDisplay for ConstructorField {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct AsmBlock {
     pub registers: Vec<AsmRegister>,
     pub instructions: Vec<AsmInstruction>,
@@ -1610,7 +1957,7 @@ impl TabbedDisplay for AsmBlock {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AsmRegister {
     pub name: String,
     pub value: Option<Expression>,
@@ -1631,7 +1978,7 @@ impl Display for AsmRegister {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AsmInstruction {
     pub op_code: String,
     pub args: Vec<String>,
@@ -1651,7 +1998,7 @@ impl Display for AsmInstruction {
 
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AsmFinalExpression {
     pub register: String,
     pub type_name: Option<TypeName>,
@@ -1704,6 +2051,7 @@ mod tests {
         //     return;
         // }
         module.items.push(ModuleItem::Function(Function {
+            comment: None,
             attributes: None,
             is_public: true,
             name: "test".into(),
@@ -0,0 +1,70 @@
+//! Parses the generated Sway code with the parser of the Sway compiler, so that syntactically invalid output is caught
+//! without building the generated projects with `forc`.
+
+use crate::{errors::Error, line_ranges::LineRanges};
+use sway_types::Spanned;
+
+/// A syntax error reported by the Sway parser, along with the byte range it was reported at in the generated code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwaySyntaxError {
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+/// Parses the code generated for the module named `name`, returning an error containing each syntax error reported by the Sway parser.
+///
+/// Errors reported at `todo!` stubs are skipped, since they mark code that could not be translated rather than a bug in the printer.
+pub fn check_sway_syntax(name: &str, code: &str) -> Result<(), Error> {
+    let handler = sway_error::handler::Handler::default();
+
+    let result = sway_parse::parse_file(
+        &handler,
+        sway_types::span::Source::new(code),
+        None,
+        sway_features::ExperimentalFeatures::default(),
+    );
+
+    let (errors, _, _) = handler.consume();
+
+    let mut syntax_errors = errors.iter()
+        .filter(|e| !code[..e.span().start()].ends_with("todo"))
+        .map(|e| SwaySyntaxError {
+            start: e.span().start(),
+            end: e.span().end(),
+            message: e.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    // The parser can fail without emitting an error when it can not recover
+    if result.is_err() && errors.is_empty() {
+        syntax_errors.push(SwaySyntaxError {
+            start: 0,
+            end: 0,
+            message: "failed to parse the generated code".into(),
+        });
+    }
+
+    if syntax_errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(Error::SwaySyntaxErrors(name.into(), LineRanges::new(code), syntax_errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_sway_syntax() {
+        assert!(check_sway_syntax("A", "contract;\n\nfn f() -> u64 {\n    let x = todo!(\"x\");\n    x\n}\n").is_ok());
+
+        let Err(Error::SwaySyntaxErrors(name, _, errors)) = check_sway_syntax("B", "contract;\n\nfn f() -> u64 {\n    let x = 1\n    x\n}\n") else {
+            panic!("Expected a syntax error");
+        };
+
+        assert_eq!(name, "B");
+        assert!(!errors.is_empty());
+    }
+}
@@ -0,0 +1,768 @@
+use super::{translate_storage_name, ItemOriginKind, TranslatedDefinition};
+use crate::{
+    forc::{SWAY_LIBS_GIT_URL, SWAY_STANDARDS_GIT_URL},
+    project::{Lint, Project},
+    sway,
+};
+
+/// Checks if the definition has the shape of the `Ownable` contract, whose ownership can be managed by the `sway_libs::ownership` library.
+pub fn is_ownable_contract(definition: &TranslatedDefinition) -> bool {
+    [
+        // function owner() public view returns (address)
+        ("owner", &[][..], Some("Identity")),
+        // function renounceOwnership() public
+        ("renounceOwnership", &[][..], None),
+        // function transferOwnership(address newOwner) public
+        ("transferOwnership", &["Identity"][..], None),
+        // function _transferOwnership(address newOwner) internal
+        ("_transferOwnership", &["Identity"][..], None),
+    ]
+    .iter()
+    .all(|(old_name, parameter_types, return_type)| find_function_name(definition, old_name, parameter_types, *return_type).is_some())
+}
+
+/// Checks if the definition has the shape of the `Ownable2Step` contract, whose ownership is transferred in two steps.
+pub fn is_ownable_2step_contract(definition: &TranslatedDefinition) -> bool {
+    is_ownable_contract(definition)
+        // function pendingOwner() public view returns (address)
+        && find_function_name(definition, "pendingOwner", &[], Some("Identity")).is_some()
+        // function acceptOwnership() public
+        && find_function_name(definition, "acceptOwnership", &[], None).is_some()
+}
+
+/// Checks if the definition has the shape of the `Pausable` contract, whose pausing can be managed by the `sway_libs::pausable` library.
+pub fn is_pausable_contract(definition: &TranslatedDefinition) -> bool {
+    [
+        // function paused() public view returns (bool)
+        ("paused", Some("bool")),
+        // function _pause() internal
+        ("_pause", None),
+        // function _unpause() internal
+        ("_unpause", None),
+    ]
+    .iter()
+    .all(|(old_name, return_type)| find_function_name(definition, old_name, &[], *return_type).is_some())
+}
+
+/// Checks if the definition has the shape of the `AccessControl` contract, which grants roles to accounts.
+pub fn is_access_control_contract(definition: &TranslatedDefinition) -> bool {
+    [
+        // function hasRole(bytes32 role, address account) public view returns (bool)
+        ("hasRole", &["b256", "Identity"][..], Some("bool")),
+        // function getRoleAdmin(bytes32 role) public view returns (bytes32)
+        ("getRoleAdmin", &["b256"][..], Some("b256")),
+        // function grantRole(bytes32 role, address account) public
+        ("grantRole", &["b256", "Identity"][..], None),
+        // function revokeRole(bytes32 role, address account) public
+        ("revokeRole", &["b256", "Identity"][..], None),
+        // function _setRoleAdmin(bytes32 role, bytes32 adminRole) internal
+        ("_setRoleAdmin", &["b256", "b256"][..], None),
+    ]
+    .iter()
+    .all(|(old_name, parameter_types, return_type)| find_function_name(definition, old_name, parameter_types, *return_type).is_some())
+}
+
+/// Finds the translated name of a function in the toplevel scope of the definition by its Solidity name and signature.
+///
+/// The return type is only checked if one is supplied.
+fn find_function_name(definition: &TranslatedDefinition, old_name: &str, parameter_types: &[&str], return_type: Option<&str>) -> Option<String> {
+    definition.toplevel_scope.borrow().functions.iter().find_map(|f| {
+        let f = f.borrow();
+
+        let is_match = f.old_name == old_name
+            && f.parameters.entries.len() == parameter_types.len()
+            && f.parameters.entries.iter().zip(parameter_types.iter()).all(|(p, t)| {
+                matches!(&p.type_name, Some(sway::TypeName::Identifier { name, generic_parameters: None }) if name == t)
+            })
+            && return_type.is_none_or(|t| {
+                matches!(&f.return_type, Some(sway::TypeName::Identifier { name, generic_parameters: None }) if name == t)
+            });
+
+        is_match.then(|| f.new_name.clone())
+    })
+}
+
+/// Finds the definition that the function was inherited from if it has the expected shape, returning its name.
+fn find_function_origin(
+    project: &Project,
+    translated_definition: &TranslatedDefinition,
+    function_name: &str,
+    is_expected_shape: fn(&TranslatedDefinition) -> bool,
+) -> Option<String> {
+    let origin = translated_definition.function_origins.get(function_name)?;
+
+    if *origin == translated_definition.name {
+        return None;
+    }
+
+    project.translated_definitions.iter()
+        .find(|d| d.name == *origin && translated_definition.linearization.contains(&d.name))
+        .filter(|d| is_expected_shape(d))
+        .map(|d| d.name.clone())
+}
+
+/// Maps the inherited ownership and role-based access control of a contract to Sway equivalents instead of porting their internals literally:
+/// * The functions inherited from `Ownable` are rewritten to use the `sway_libs::ownership` library.
+/// * The role data inherited from `AccessControl` is flattened into storage maps, since Sway structs cannot contain storage maps.
+///
+/// Functions overridden by the contract are left unchanged.
+pub fn map_inherited_access_control(project: &mut Project, translated_definition: &mut TranslatedDefinition) {
+    map_inherited_ownership(project, translated_definition);
+    map_inherited_roles(project, translated_definition);
+}
+
+fn map_inherited_ownership(project: &mut Project, translated_definition: &mut TranslatedDefinition) {
+    if !is_ownable_contract(translated_definition) {
+        return;
+    }
+
+    let owner_function_name = find_function_name(translated_definition, "owner", &[], Some("Identity")).unwrap();
+
+    let Some(origin) = find_function_origin(project, translated_definition, &owner_function_name, is_ownable_contract) else { return };
+    let loc = translated_definition.get_item_origin(ItemOriginKind::Function, &owner_function_name).map(|origin| origin.loc);
+
+    // `sway_libs::ownership` can only transfer ownership from the current owner, so pending owners can't accept it
+    if is_ownable_2step_contract(translated_definition) {
+        project.report_warning(
+            Lint::AccessControl,
+            &translated_definition.path,
+            loc.as_ref(),
+            format!(
+                "`{}` inherits the two-step ownership transfer of `{origin}`, which `sway_libs::ownership` does not provide, keeping its ported implementation",
+                translated_definition.name,
+            ),
+        );
+        return;
+    }
+
+    let zero_identity = create_zero_identity_expression(translated_definition);
+
+    let create_call = |function_name: &str, parameters: Vec<sway::Expression>| {
+        sway::Expression::create_function_call(format!("sway_libs::ownership::{function_name}").as_str(), parameters)
+    };
+
+    let mut replaced_bodies = vec![];
+
+    for (old_name, parameter_types) in [
+        ("owner", &[][..]),
+        ("_checkOwner", &[][..]),
+        ("renounceOwnership", &[][..]),
+        ("transferOwnership", &["Identity"][..]),
+        ("_transferOwnership", &["Identity"][..]),
+    ] {
+        replaced_bodies.extend(replace_inherited_function_body(translated_definition, &origin, old_name, parameter_types, |parameters| match old_name {
+            // match sway_libs::ownership::_owner() {
+            //     standards::src5::State::Initialized(owner) => owner,
+            //     _ => Identity::Address(Address::from(ZERO_B256)),
+            // }
+            "owner" => create_body(vec![], Some(sway::Expression::from(sway::Match {
+                expression: create_call("_owner", vec![]),
+                branches: vec![
+                    sway::MatchBranch {
+                        pattern: sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("standards::src5::State::Initialized".into()),
+                            generic_parameters: None,
+                            parameters: vec![sway::Expression::Identifier("owner".into())],
+                        }),
+                        value: sway::Expression::Identifier("owner".into()),
+                    },
+                    sway::MatchBranch {
+                        pattern: sway::Expression::Identifier("_".into()),
+                        value: zero_identity.clone(),
+                    },
+                ],
+            }))),
+
+            // sway_libs::ownership::only_owner();
+            "_checkOwner" => create_body(vec![create_call("only_owner", vec![])], None),
+
+            // sway_libs::ownership::renounce_ownership();
+            "renounceOwnership" => create_body(vec![create_call("renounce_ownership", vec![])], None),
+
+            // sway_libs::ownership::transfer_ownership(new_owner);
+            "transferOwnership" => create_body(vec![create_call("transfer_ownership", parameters)], None),
+
+            // if new_owner == Identity::Address(Address::from(ZERO_B256)) {
+            //     sway_libs::ownership::renounce_ownership();
+            // } else if sway_libs::ownership::_owner() == standards::src5::State::Uninitialized {
+            //     sway_libs::ownership::initialize_ownership(new_owner);
+            // } else {
+            //     sway_libs::ownership::transfer_ownership(new_owner);
+            // }
+            "_transferOwnership" => create_body(vec![sway::Expression::from(sway::If {
+                condition: Some(sway::Expression::from(sway::BinaryExpression {
+                    operator: "==".into(),
+                    lhs: parameters[0].clone(),
+                    rhs: zero_identity.clone(),
+                })),
+                then_body: create_body(vec![create_call("renounce_ownership", vec![])], None),
+                else_if: Some(Box::new(sway::If {
+                    condition: Some(sway::Expression::from(sway::BinaryExpression {
+                        operator: "==".into(),
+                        lhs: create_call("_owner", vec![]),
+                        rhs: sway::Expression::Identifier("standards::src5::State::Uninitialized".into()),
+                    })),
+                    then_body: create_body(vec![create_call("initialize_ownership", parameters.clone())], None),
+                    else_if: Some(Box::new(sway::If {
+                        condition: None,
+                        then_body: create_body(vec![create_call("transfer_ownership", parameters)], None),
+                        else_if: None,
+                    })),
+                })),
+            })], None),
+
+            _ => unreachable!(),
+        }));
+    }
+
+    if replaced_bodies.is_empty() {
+        return;
+    }
+
+    // Drop the storage fields that were only used by the replaced implementations, i.e: `_owner: Identity`
+    drop_replaced_storage_fields(translated_definition, replaced_bodies.as_slice());
+
+    translated_definition.ensure_dependency_declared(format!("sway_libs = {{ git = \"{SWAY_LIBS_GIT_URL}\", branch = \"master\" }}").as_str());
+    translated_definition.ensure_known_attribution_declared(SWAY_LIBS_GIT_URL);
+    translated_definition.ensure_dependency_declared(format!("standards = {{ git = \"{SWAY_STANDARDS_GIT_URL}\", branch = \"master\" }}").as_str());
+    translated_definition.ensure_known_attribution_declared(SWAY_STANDARDS_GIT_URL);
+
+    project.report_warning(
+        Lint::AccessControl,
+        &translated_definition.path,
+        loc.as_ref(),
+        format!(
+            "mapped the ownership `{}` inherits from `{origin}` to the `sway_libs::ownership` library",
+            translated_definition.name,
+        ),
+    );
+
+    if find_function_name(translated_definition, "_transferOwnership", &["Identity"], None).is_some() {
+        project.report_warning(
+            Lint::AccessControl,
+            &translated_definition.path,
+            loc.as_ref(),
+            format!(
+                "internal ownership transfers of `{}` can only initialize the ownership or transfer it from the current owner with `sway_libs::ownership`",
+                translated_definition.name,
+            ),
+        );
+    }
+}
+
+/// Maps the pausing functionality a contract inherits from `Pausable` to the `sway_libs::pausable` library, which is shared by every contract that uses it.
+pub fn map_inherited_pausable(project: &mut Project, translated_definition: &mut TranslatedDefinition) {
+    if !is_pausable_contract(translated_definition) {
+        return;
+    }
+
+    let paused_function_name = find_function_name(translated_definition, "paused", &[], Some("bool")).unwrap();
+
+    let Some(origin) = find_function_origin(project, translated_definition, &paused_function_name, is_pausable_contract) else { return };
+    let loc = translated_definition.get_item_origin(ItemOriginKind::Function, &paused_function_name).map(|origin| origin.loc);
+
+    let create_call = |function_name: &str| {
+        sway::Expression::create_function_call(format!("sway_libs::pausable::{function_name}").as_str(), vec![])
+    };
+
+    let mut replaced_bodies = vec![];
+
+    for old_name in ["paused", "_requireNotPaused", "_requirePaused", "_pause", "_unpause"] {
+        replaced_bodies.extend(replace_inherited_function_body(translated_definition, &origin, old_name, &[], |_| match old_name {
+            // sway_libs::pausable::_is_paused()
+            "paused" => create_body(vec![], Some(create_call("_is_paused"))),
+
+            // sway_libs::pausable::require_not_paused();
+            "_requireNotPaused" => create_body(vec![create_call("require_not_paused")], None),
+
+            // sway_libs::pausable::require_paused();
+            "_requirePaused" => create_body(vec![create_call("require_paused")], None),
+
+            // sway_libs::pausable::require_not_paused();
+            // sway_libs::pausable::_pause();
+            "_pause" => create_body(vec![create_call("require_not_paused"), create_call("_pause")], None),
+
+            // sway_libs::pausable::require_paused();
+            // sway_libs::pausable::_unpause();
+            "_unpause" => create_body(vec![create_call("require_paused"), create_call("_unpause")], None),
+
+            _ => unreachable!(),
+        }));
+    }
+
+    if replaced_bodies.is_empty() {
+        return;
+    }
+
+    // Drop the storage fields that were only used by the replaced implementations, i.e: `_paused: bool`
+    drop_replaced_storage_fields(translated_definition, replaced_bodies.as_slice());
+
+    translated_definition.ensure_dependency_declared(format!("sway_libs = {{ git = \"{SWAY_LIBS_GIT_URL}\", branch = \"master\" }}").as_str());
+    translated_definition.ensure_known_attribution_declared(SWAY_LIBS_GIT_URL);
+
+    project.report_warning(
+        Lint::AccessControl,
+        &translated_definition.path,
+        loc.as_ref(),
+        format!(
+            "mapped the pausing `{}` inherits from `{origin}` to the `sway_libs::pausable` library, which logs `PauseEvent` and `UnpauseEvent` instead of `Paused` and `Unpaused`",
+            translated_definition.name,
+        ),
+    );
+}
+
+/// Replaces the body of a function the contract inherits from the origin definition, unless the contract overrides it.
+///
+/// The new body is created from the parameters of the function. Returns the replaced body, if any.
+fn replace_inherited_function_body(
+    translated_definition: &mut TranslatedDefinition,
+    origin: &str,
+    old_name: &str,
+    parameter_types: &[&str],
+    create_body: impl FnOnce(Vec<sway::Expression>) -> sway::Block,
+) -> Option<sway::Block> {
+    let function_name = find_function_name(translated_definition, old_name, parameter_types, None)?;
+
+    if translated_definition.function_origins.get(&function_name).map(|x| x.as_str()) != Some(origin) {
+        return None;
+    }
+
+    let function = translated_definition.functions.iter_mut().find(|f| f.name == function_name && f.body.is_some())?;
+
+    let parameters = function.parameters.entries.iter()
+        .map(|p| sway::Expression::Identifier(p.name.clone()))
+        .collect::<Vec<_>>();
+
+    Some(std::mem::replace(function.body.as_mut().unwrap(), create_body(parameters)))
+}
+
+/// Drops the storage fields that were only used by the replaced function bodies, along with the writes that initialize them in constructors.
+///
+/// Fields that are still read elsewhere are left unchanged.
+fn drop_replaced_storage_fields(translated_definition: &mut TranslatedDefinition, replaced_bodies: &[sway::Block]) {
+    let mut field_names = vec![];
+
+    for body in replaced_bodies.iter() {
+        body.visit_expressions(&mut |expression| {
+            if let Some(field_name) = get_storage_field_name(expression) {
+                if !field_names.contains(&field_name) {
+                    field_names.push(field_name);
+                }
+            }
+        });
+    }
+
+    for field_name in field_names {
+        // Remove the writes to the field from copies of the functions, i.e: `storage._paused.write(false);`
+        let mut functions = translated_definition.functions.clone();
+        let mut impls = translated_definition.impls.clone();
+
+        let bodies = functions.iter_mut()
+            .filter_map(|f| f.body.as_mut())
+            .chain(impls.iter_mut().flat_map(|i| i.items.iter_mut()).filter_map(|item| match item {
+                sway::ImplItem::Function(f) => f.body.as_mut(),
+                _ => None,
+            }));
+
+        for body in bodies {
+            body.retain_statements(&mut |statement| {
+                let sway::Statement::Expression(sway::Expression::FunctionCall(function_call)) = statement else { return true };
+                let sway::Expression::MemberAccess(member_access) = &function_call.function else { return true };
+                member_access.member != "write" || get_storage_field_name(&member_access.expression).as_ref() != Some(&field_name)
+            });
+        }
+
+        if is_storage_field_used(functions.as_slice(), impls.as_slice(), &field_name) {
+            continue;
+        }
+
+        translated_definition.functions = functions;
+        translated_definition.impls = impls;
+
+        remove_storage_field(translated_definition, &field_name);
+    }
+}
+
+fn map_inherited_roles(project: &mut Project, translated_definition: &mut TranslatedDefinition) {
+    if !is_access_control_contract(translated_definition) {
+        return;
+    }
+
+    let has_role_function_name = find_function_name(translated_definition, "hasRole", &["b256", "Identity"], Some("bool")).unwrap();
+
+    let Some(origin) = find_function_origin(project, translated_definition, &has_role_function_name, is_access_control_contract) else { return };
+
+    // Find the storage field of the role data and the names of its fields, i.e: `_roles: StorageMap<b256, RoleData>`
+    let Some((field_name, struct_name, members_name, admin_name)) = translated_definition.storage.as_ref()
+        .into_iter()
+        .flat_map(|s| s.fields.iter())
+        .find_map(|field| {
+            let sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) } = &field.type_name else { return None };
+
+            if name != "StorageMap" || generic_parameters.entries.len() != 2 || generic_parameters.entries[0].type_name.to_string() != "b256" {
+                return None;
+            }
+
+            let sway::TypeName::Identifier { name: struct_name, generic_parameters: None } = &generic_parameters.entries[1].type_name else { return None };
+            let struct_definition = translated_definition.structs.iter().find(|s| s.name == *struct_name)?;
+
+            // struct RoleData { has_role: StorageMap<Identity, bool>, admin_role: b256 }
+            let members_name = struct_definition.fields.iter().find(|f| f.type_name.to_string() == "StorageMap<Identity, bool>")?.name.clone();
+            let admin_name = struct_definition.fields.iter().find(|f| f.type_name.to_string() == "b256")?.name.clone();
+
+            Some((field.name.clone(), struct_name.clone(), members_name, admin_name))
+        })
+    else {
+        return;
+    };
+
+    let loc = translated_definition.get_item_origin(ItemOriginKind::StorageField, &field_name).map(|origin| origin.loc);
+
+    let members_field_name = translate_storage_name(project, translated_definition, "roleMembers");
+    let admins_field_name = translate_storage_name(project, translated_definition, "roleAdmins");
+
+    let zero_b256 = {
+        translated_definition.ensure_use_declared("std::constants::ZERO_B256");
+        sway::Expression::Identifier("ZERO_B256".into())
+    };
+
+    // Rewrite copies of the functions, so the contract is left unchanged if the role data can't be flattened
+    let mut functions = translated_definition.functions.clone();
+    let mut impls = translated_definition.impls.clone();
+
+    let mut bodies = functions.iter_mut()
+        .filter_map(|f| f.body.as_mut())
+        .chain(impls.iter_mut().flat_map(|i| i.items.iter_mut()).filter_map(|item| match item {
+            sway::ImplItem::Function(f) => f.body.as_mut(),
+            _ => None,
+        }))
+        .collect::<Vec<_>>();
+
+    for body in bodies.iter_mut() {
+        rewrite_role_admin_writes(body, &field_name, &admin_name, &admins_field_name);
+
+        body.visit_expressions_mut(&mut |expression| {
+            // storage._roles.get(role).has_role.get(account).read() => storage.role_members.get((role, account)).try_read().unwrap_or(false)
+            if let Some(("read", container)) = get_method_call(expression) {
+                if let Some((role, account)) = get_role_member_key(container, &field_name, &members_name) {
+                    *expression = create_try_read_expression(
+                        create_storage_get_expression(&members_field_name, sway::Expression::Tuple(vec![role.clone(), account.clone()])),
+                        sway::Expression::from(sway::Literal::Bool(false)),
+                    );
+                    return;
+                }
+            }
+
+            // storage._roles.get(role).has_role.get(account) => storage.role_members.get((role, account))
+            if let Some((role, account)) = get_role_member_key(expression, &field_name, &members_name) {
+                *expression = create_storage_get_expression(&members_field_name, sway::Expression::Tuple(vec![role.clone(), account.clone()]));
+                return;
+            }
+
+            // storage._roles.get(role).read().admin_role => storage.role_admins.get(role).try_read().unwrap_or(ZERO_B256)
+            if let sway::Expression::MemberAccess(member_access) = expression {
+                if member_access.member != admin_name {
+                    return;
+                }
+
+                let Some(("read", container)) = get_method_call(&member_access.expression) else { return };
+                let Some(role) = get_role_data_key(container, &field_name) else { return };
+
+                *expression = create_try_read_expression(
+                    create_storage_get_expression(&admins_field_name, role.clone()),
+                    zero_b256.clone(),
+                );
+            }
+        });
+    }
+
+    let mut is_still_used = false;
+
+    for body in bodies.iter() {
+        body.visit_expressions(&mut |expression| {
+            if get_storage_field_name(expression).as_ref() == Some(&field_name) {
+                is_still_used = true;
+            }
+        });
+    }
+
+    if is_still_used {
+        project.report_warning(
+            Lint::AccessControl,
+            &translated_definition.path,
+            loc.as_ref(),
+            format!(
+                "the `{field_name}` role data `{}` inherits from `{origin}` is used in ways that can't be flattened into storage maps, leaving it unchanged",
+                translated_definition.name,
+            ),
+        );
+        return;
+    }
+
+    translated_definition.functions = functions;
+    translated_definition.impls = impls;
+
+    remove_storage_field(translated_definition, &field_name);
+
+    translated_definition.structs.retain(|s| s.name != struct_name);
+    translated_definition.struct_names.retain(|s| *s != struct_name);
+
+    let storage = translated_definition.get_storage();
+
+    // role_members: StorageMap<(b256, Identity), bool> = StorageMap {}
+    // role_admins: StorageMap<b256, b256> = StorageMap {}
+    for (name, key_type_name, value_type_name) in [
+        (
+            members_field_name,
+            sway::TypeName::Tuple {
+                type_names: vec![sway::TypeName::create_identifier("b256"), sway::TypeName::create_identifier("Identity")],
+            },
+            sway::TypeName::create_identifier("bool"),
+        ),
+        (admins_field_name, sway::TypeName::create_identifier("b256"), sway::TypeName::create_identifier("b256")),
+    ] {
+        storage.fields.push(sway::StorageField {
+            comment: None,
+            name,
+            type_name: sway::TypeName::Identifier {
+                name: "StorageMap".into(),
+                generic_parameters: Some(sway::GenericParameterList {
+                    entries: vec![
+                        sway::GenericParameter { type_name: key_type_name, implements: None },
+                        sway::GenericParameter { type_name: value_type_name, implements: None },
+                    ],
+                }),
+            },
+            value: sway::Expression::from(sway::Constructor {
+                type_name: sway::TypeName::Identifier {
+                    name: "StorageMap".into(),
+                    generic_parameters: None,
+                },
+                fields: vec![],
+            }),
+        });
+    }
+
+    project.report_warning(
+        Lint::AccessControl,
+        &translated_definition.path,
+        loc.as_ref(),
+        format!(
+            "flattened the `{field_name}` role data `{}` inherits from `{origin}` into storage maps of role members and role admins",
+            translated_definition.name,
+        ),
+    );
+}
+
+/// Replaces the copies of role data that only change the admin role of a role:
+///
+/// ```ignore
+/// let mut __roles = storage._roles.get(role).read();
+/// __roles.admin_role = admin_role;
+/// storage._roles.get(role).write(__roles);
+/// ```
+///
+/// with a single write to the storage map of role admins:
+///
+/// ```ignore
+/// storage.role_admins.insert(role, admin_role);
+/// ```
+fn rewrite_role_admin_writes(block: &mut sway::Block, field_name: &str, admin_name: &str, admins_field_name: &str) {
+    let mut rewrite = |block: &mut sway::Block| {
+        let mut i = 0;
+
+        while i + 2 < block.statements.len() {
+            if let Some(statement) = get_role_admin_write(&block.statements[i..i + 3], field_name, admin_name, admins_field_name) {
+                block.statements.splice(i..i + 3, [statement]);
+            }
+
+            i += 1;
+        }
+    };
+
+    block.visit_blocks_mut(&mut rewrite);
+}
+
+fn get_role_admin_write(statements: &[sway::Statement], field_name: &str, admin_name: &str, admins_field_name: &str) -> Option<sway::Statement> {
+    // let mut __roles = storage._roles.get(role).read();
+    let sway::Statement::Let(sway::Let { pattern: sway::LetPattern::Identifier(sway::LetIdentifier { name, .. }), value, .. }) = &statements[0] else { return None };
+    let ("read", container) = get_method_call(value)? else { return None };
+    let role = get_role_data_key(container, field_name)?;
+
+    // __roles.admin_role = admin_role;
+    let sway::Statement::Expression(sway::Expression::BinaryExpression(assignment)) = &statements[1] else { return None };
+    let sway::Expression::MemberAccess(member_access) = &assignment.lhs else { return None };
+
+    if assignment.operator != "=" || member_access.member != admin_name || !matches!(&member_access.expression, sway::Expression::Identifier(x) if x == name) {
+        return None;
+    }
+
+    // storage._roles.get(role).write(__roles);
+    let sway::Statement::Expression(sway::Expression::FunctionCall(function_call)) = &statements[2] else { return None };
+    let sway::Expression::MemberAccess(write_access) = &function_call.function else { return None };
+
+    if write_access.member != "write"
+        || get_role_data_key(&write_access.expression, field_name) != Some(role)
+        || !matches!(function_call.parameters.as_slice(), [sway::Expression::Identifier(x)] if x == name)
+    {
+        return None;
+    }
+
+    // storage.role_admins.insert(role, admin_role);
+    Some(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression: create_storage_field_expression(admins_field_name),
+            member: "insert".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![role.clone(), assignment.rhs.clone()],
+    })))
+}
+
+/// Gets the method name and the expression it was called on from a call without arguments, i.e: `x.read()` => `("read", x)`
+fn get_method_call(expression: &sway::Expression) -> Option<(&str, &sway::Expression)> {
+    let sway::Expression::FunctionCall(function_call) = expression else { return None };
+    let sway::Expression::MemberAccess(member_access) = &function_call.function else { return None };
+
+    if !function_call.parameters.is_empty() {
+        return None;
+    }
+
+    Some((member_access.member.as_str(), &member_access.expression))
+}
+
+/// Gets `k` from `x.get(k)`.
+fn get_map_key(expression: &sway::Expression, container: impl Fn(&sway::Expression) -> bool) -> Option<&sway::Expression> {
+    let sway::Expression::FunctionCall(function_call) = expression else { return None };
+    let sway::Expression::MemberAccess(member_access) = &function_call.function else { return None };
+
+    if member_access.member != "get" || function_call.parameters.len() != 1 || !container(&member_access.expression) {
+        return None;
+    }
+
+    Some(&function_call.parameters[0])
+}
+
+/// Gets `role` from `storage._roles.get(role)`.
+fn get_role_data_key<'a>(expression: &'a sway::Expression, field_name: &str) -> Option<&'a sway::Expression> {
+    get_map_key(expression, |x| get_storage_field_name(x).as_deref() == Some(field_name))
+}
+
+/// Gets `(role, account)` from `storage._roles.get(role).has_role.get(account)`.
+fn get_role_member_key<'a>(expression: &'a sway::Expression, field_name: &str, members_name: &str) -> Option<(&'a sway::Expression, &'a sway::Expression)> {
+    let account = get_map_key(expression, |x| {
+        matches!(x, sway::Expression::MemberAccess(member_access) if member_access.member == members_name && get_role_data_key(&member_access.expression, field_name).is_some())
+    })?;
+
+    let sway::Expression::FunctionCall(function_call) = expression else { unreachable!() };
+    let sway::Expression::MemberAccess(get_access) = &function_call.function else { unreachable!() };
+    let sway::Expression::MemberAccess(members_access) = &get_access.expression else { unreachable!() };
+
+    Some((get_role_data_key(&members_access.expression, field_name)?, account))
+}
+
+/// Gets `x` from `storage.x`.
+fn get_storage_field_name(expression: &sway::Expression) -> Option<String> {
+    let sway::Expression::MemberAccess(member_access) = expression else { return None };
+    let sway::Expression::Identifier(name) = &member_access.expression else { return None };
+
+    if name != "storage" {
+        return None;
+    }
+
+    Some(member_access.member.clone())
+}
+
+fn is_storage_field_used(functions: &[sway::Function], impls: &[sway::Impl], field_name: &str) -> bool {
+    let mut is_used = false;
+
+    let bodies = functions.iter()
+        .filter_map(|f| f.body.as_ref())
+        .chain(impls.iter().flat_map(|i| i.items.iter()).filter_map(|item| match item {
+            sway::ImplItem::Function(f) => f.body.as_ref(),
+            _ => None,
+        }));
+
+    for body in bodies {
+        body.visit_expressions(&mut |expression| {
+            if get_storage_field_name(expression).as_deref() == Some(field_name) {
+                is_used = true;
+            }
+        });
+    }
+
+    is_used
+}
+
+fn remove_storage_field(translated_definition: &mut TranslatedDefinition, field_name: &str) {
+    if let Some(storage) = translated_definition.storage.as_mut() {
+        storage.fields.retain(|f| f.name != field_name);
+
+        if storage.fields.is_empty() {
+            translated_definition.storage = None;
+        }
+    }
+}
+
+fn create_storage_field_expression(field_name: &str) -> sway::Expression {
+    sway::Expression::from(sway::MemberAccess {
+        expression: sway::Expression::Identifier("storage".into()),
+        member: field_name.into(),
+    })
+}
+
+/// Creates `storage.x.get(k)`.
+fn create_storage_get_expression(field_name: &str, key: sway::Expression) -> sway::Expression {
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression: create_storage_field_expression(field_name),
+            member: "get".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![key],
+    })
+}
+
+/// Creates `x.try_read().unwrap_or(default)`, since reading an unset storage key reverts instead of returning a default value like Solidity does.
+fn create_try_read_expression(storage_key: sway::Expression, default_value: sway::Expression) -> sway::Expression {
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::from(sway::MemberAccess {
+                    expression: storage_key,
+                    member: "try_read".into(),
+                }),
+                generic_parameters: None,
+                parameters: vec![],
+            }),
+            member: "unwrap_or".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![default_value],
+    })
+}
+
+/// Creates `Identity::Address(Address::from(ZERO_B256))`.
+fn create_zero_identity_expression(translated_definition: &mut TranslatedDefinition) -> sway::Expression {
+    translated_definition.ensure_use_declared("std::constants::ZERO_B256");
+
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("Identity::Address".into()),
+        generic_parameters: None,
+        parameters: vec![
+            sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier("Address::from".into()),
+                generic_parameters: None,
+                parameters: vec![sway::Expression::Identifier("ZERO_B256".into())],
+            }),
+        ],
+    })
+}
+
+fn create_body(statements: Vec<sway::Expression>, final_expr: Option<sway::Expression>) -> sway::Block {
+    sway::Block {
+        statements: statements.into_iter().map(sway::Statement::from).collect(),
+        final_expr,
+    }
+}
+
@@ -1,8 +1,8 @@
 use super::{
-    create_value_expression, finalize_block_translation, TranslatedDefinition, TranslatedVariable,
-    TranslationScope,
+    create_code_size_expression, create_integer_width_conversion_expression, create_to_identity_call, create_value_expression, finalize_block_translation,
+    is_untyped_integer_expression, TranslatedDefinition, TranslatedVariable, TranslationScope,
 };
-use crate::{errors::Error, project::Project, sway};
+use crate::{emitter::emitln, errors::Error, project::{Lint, Project}, sway};
 use convert_case::Case;
 use num_bigint::BigUint;
 use num_traits::{Num, Zero};
@@ -40,8 +40,19 @@ pub fn translate_yul_block(
 ) -> Result<sway::Block, Error> {
     let mut block = sway::Block::default();
 
+    let mut statements = yul_block.statements.iter().peekable();
+
     // Translate each of the statements in the block
-    for statement in yul_block.statements.iter() {
+    while let Some(statement) = statements.next() {
+        // mstore(p, x) return(p, 32) => return x
+        if let Some(value) = statements.peek().and_then(|next| get_yul_memory_return_value(statement, next)) {
+            statements.next();
+
+            let value = translate_yul_expression(project, translated_definition, scope.clone(), value)?;
+            block.statements.push(sway::Statement::from(sway::Expression::Return(Some(Box::new(value)))));
+            continue;
+        }
+
         // Translate the statement
         let sway_statement = translate_yul_statement(project, translated_definition, scope.clone(), statement)?;
 
@@ -78,6 +89,33 @@ pub fn translate_yul_block(
     Ok(block)
 }
 
+/// Gets the value returned by a `mstore(p, x)` statement followed by a `return(p, 32)` statement, i.e: `x`
+fn get_yul_memory_return_value<'a>(statement: &'a solidity::YulStatement, next_statement: &solidity::YulStatement) -> Option<&'a solidity::YulExpression> {
+    let solidity::YulStatement::FunctionCall(mstore) = statement else { return None };
+    let solidity::YulStatement::FunctionCall(r#return) = next_statement else { return None };
+
+    if mstore.id.name != "mstore" || mstore.arguments.len() != 2 || r#return.id.name != "return" || r#return.arguments.len() != 2 {
+        return None;
+    }
+
+    let offset = evaluate_yul_number_literal(&mstore.arguments[0])?;
+
+    if evaluate_yul_number_literal(&r#return.arguments[0])? != offset || evaluate_yul_number_literal(&r#return.arguments[1])? != BigUint::from(32u8) {
+        return None;
+    }
+
+    Some(&mstore.arguments[1])
+}
+
+/// Evaluates a yul number literal, i.e: `0x20` => `32`
+fn evaluate_yul_number_literal(expression: &solidity::YulExpression) -> Option<BigUint> {
+    match expression {
+        solidity::YulExpression::NumberLiteral(_, value, _, _) => value.parse().ok(),
+        solidity::YulExpression::HexNumberLiteral(_, value, _) => BigUint::from_str_radix(value.trim_start_matches("0x"), 16).ok(),
+        _ => None,
+    }
+}
+
 #[inline]
 pub fn translate_yul_statement(
     project: &mut Project,
@@ -91,11 +129,21 @@ pub fn translate_yul_statement(
         solidity::YulStatement::If(_, condition, then_block) => translate_yul_if_statement(project, translated_definition, scope.clone(), condition, then_block),
         solidity::YulStatement::For(yul_for) => translate_yul_for_statement(project, translated_definition, scope.clone(), yul_for),
         solidity::YulStatement::Switch(yul_switch) => translate_yul_switch_statement(project, translated_definition, scope.clone(), yul_switch),
-        solidity::YulStatement::Leave(_) => todo!("yul leave statement: {yul_statement} - {yul_statement:#?}"),
+        solidity::YulStatement::Leave(_) => Ok(sway::Statement::from(sway::Expression::create_todo(Some(yul_statement.to_string())))),
         solidity::YulStatement::Break(_) => Ok(sway::Statement::from(sway::Expression::Break)),
         solidity::YulStatement::Continue(_) => Ok(sway::Statement::from(sway::Expression::Continue)),
         solidity::YulStatement::Block(block) => Ok(sway::Statement::from(sway::Expression::from(translate_yul_block(project, translated_definition, scope.clone(), block)?))),
-        solidity::YulStatement::FunctionDefinition(_) => todo!("yul function definition statement: {yul_statement} - {yul_statement:#?}"),
+        solidity::YulStatement::FunctionDefinition(function_definition) => {
+            // TODO: function f(a) -> b { ... } => todo!("function f(a) -> b")
+            let parameters = function_definition.params.iter().map(|p| p.id.name.clone()).collect::<Vec<_>>().join(", ");
+            let returns = function_definition.returns.iter().map(|p| p.id.name.clone()).collect::<Vec<_>>().join(", ");
+
+            Ok(sway::Statement::from(sway::Expression::create_todo(Some(if returns.is_empty() {
+                format!("function {}({parameters})", function_definition.id.name)
+            } else {
+                format!("function {}({parameters}) -> {returns}", function_definition.id.name)
+            }))))
+        }
         solidity::YulStatement::FunctionCall(yul_function_call) => translate_yul_function_call_statement(project, translated_definition, scope.clone(), yul_function_call),
         solidity::YulStatement::Error(_) => todo!("yul error statement: {yul_statement} - {yul_statement:#?}"),
     }
@@ -118,18 +166,46 @@ pub fn translate_yul_assign_statement(
 
         let Some(variable) = scope.borrow().get_variable_from_new_name(name) else {
             panic!(
-                "{}error: Variable not found in scope: \"{name}\"",
-                match project.loc_to_line_and_column(&translated_definition.path, &identifiers[i].loc()) {
-                    Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                    None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                }
+                "{} - error: Variable not found in scope: \"{name}\"",
+                project.loc_to_file_location_string(&translated_definition.path, &identifiers[i].loc())
             );
         };
 
         variable.borrow_mut().mutation_count += 1;
     }
 
-    let value = translate_yul_expression(project, translated_definition, scope.clone(), value)?;
+    let mut value = translate_yul_expression(project, translated_definition, scope.clone(), value)?;
+
+    // Convert 256-bit words assigned to variables of other types
+    if let [sway::Expression::Identifier(name)] = translated_identifiers.as_slice() {
+        let variable_type_name = scope.borrow().get_variable_from_new_name(name).unwrap().borrow().type_name.clone();
+        let value_type_name = translated_definition.get_expression_type(scope.clone(), &value)?;
+
+        if value_type_name.is_uint() && !is_untyped_integer_expression(&value) {
+            value = match variable_type_name.to_string().as_str() {
+                // x := v => x = b256::from(v)
+                "b256" => create_b256_from_yul_word_expression(value, &value_type_name),
+
+                // x := v => x = Identity::Address(Address::from(b256::from(v)))
+                "Identity" => sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Identity::Address".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("Address::from".into()),
+                            generic_parameters: None,
+                            parameters: vec![create_b256_from_yul_word_expression(value, &value_type_name)],
+                        }),
+                    ],
+                }),
+
+                _ => match variable_type_name.uint_bits() {
+                    Some(bits) => create_integer_width_conversion_expression(value, &value_type_name, bits),
+                    None => value,
+                },
+            };
+        }
+    }
     
     Ok(sway::Statement::from(sway::Expression::from(sway::BinaryExpression {
         operator: "=".into(),
@@ -363,8 +439,161 @@ pub fn translate_yul_expression(
         solidity::YulExpression::StringLiteral(string_literal, _) => Ok(sway::Expression::from(sway::Literal::String(string_literal.string.clone()))),
         solidity::YulExpression::Variable(solidity::Identifier { name, .. }) => translate_yul_variable_expression(project, translated_definition, scope.clone(), expression, name.as_str()),
         solidity::YulExpression::FunctionCall(function_call) => translate_yul_function_call_expression(project, translated_definition, scope.clone(), function_call),
-        solidity::YulExpression::SuffixAccess(_, _, _) => Ok(sway::Expression::create_todo(Some(expression.to_string()))),
+        solidity::YulExpression::SuffixAccess(_, container, suffix) => {
+            // Each storage field is stored separately instead of being packed into a slot, so its offset is always 0
+            // x.offset => 0
+            if suffix.name == "offset" && get_yul_storage_variable(scope.clone(), container).is_some() {
+                return Ok(sway::Expression::from(sway::Literal::DecInt(BigUint::zero())));
+            }
+
+            Ok(sway::Expression::create_todo(Some(expression.to_string())))
+        }
+    }
+}
+
+/// Ensures the storage field that stands in for an unstructured storage slot constant is declared, returning its name,
+/// i.e: `sload(_IMPLEMENTATION_SLOT)` => `storage.implementation.read()`
+///
+/// Proxies and upgradeable contracts keep their state in constant keccak-derived slots (i.e: EIP-1967),
+/// which don't map onto Sway storage, so each slot constant is given a named storage field of its own.
+fn ensure_yul_unstructured_storage_field(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    slot: &solidity::YulExpression,
+) -> Option<String> {
+    let solidity::YulExpression::Variable(solidity::Identifier { loc, name }) = slot else { return None };
+    let variable = scope.borrow().get_variable_from_old_name(name)?;
+
+    if !variable.borrow().is_constant || variable.borrow().type_name.to_string() != "b256" {
+        return None;
+    }
+
+    let old_name = format!("{name}.slot");
+
+    if let Some(field) = translated_definition.toplevel_scope.borrow().variables.iter().find(|v| v.borrow().old_name == old_name) {
+        return Some(field.borrow().new_name.clone());
+    }
+
+    // _IMPLEMENTATION_SLOT => implementation
+    let mut field_name = crate::translate_naming_convention(name.trim_start_matches('_').trim_end_matches("_SLOT"), Case::Snake);
+
+    // Keep the `_slot` suffix if the field name collides with a state variable
+    if translated_definition.toplevel_scope.borrow().variables.iter().any(|v| v.borrow().is_storage && v.borrow().new_name == field_name) {
+        field_name = format!("{field_name}_slot");
+    }
+
+    let type_name = sway::TypeName::Identifier {
+        name: "u256".into(),
+        generic_parameters: None,
+    };
+
+    translated_definition.get_storage().fields.push(sway::StorageField {
+        comment: None,
+        name: field_name.clone(),
+        type_name: type_name.clone(),
+        value: sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+    });
+
+    // Add the storage variable for function scopes, named after the slot it stands in for so it can't be referred to directly
+    translated_definition.toplevel_scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+        old_name,
+        new_name: field_name.clone(),
+        type_name,
+        is_storage: true,
+        ..Default::default()
+    })));
+
+    emitln!(project.emitter, 
+        "NOTE: {} - unstructured storage slot `{name}` was translated to the `{field_name}` storage field",
+        project.loc_to_file_location_string(&translated_definition.path, loc),
+    );
+
+    Some(field_name)
+}
+
+/// Creates a `b256` from a yul word, i.e: `b256::from(x.as_u256())`
+fn create_b256_from_yul_word_expression(value: sway::Expression, value_type_name: &sway::TypeName) -> sway::Expression {
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("b256::from".into()),
+        generic_parameters: None,
+        parameters: vec![create_integer_width_conversion_expression(value, value_type_name, 256)],
+    })
+}
+
+/// Creates the `b256` storage key for a yul storage slot that doesn't refer to a state variable, i.e: a constant slot.
+fn create_yul_storage_key_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    slot: &sway::Expression,
+) -> Result<Option<sway::Expression>, Error> {
+    let slot_type_name = translated_definition.get_expression_type(scope.clone(), slot)?;
+
+    if slot_type_name.to_string() == "b256" {
+        return Ok(Some(slot.clone()));
+    }
+
+    if !slot_type_name.is_uint() {
+        return Ok(None);
+    }
+
+    // x => b256::from(x.as_u256())
+    if is_untyped_integer_expression(slot) {
+        return Ok(Some(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier("b256::from".into()),
+            generic_parameters: None,
+            parameters: vec![slot.clone()],
+        })));
+    }
+
+    Ok(Some(create_b256_from_yul_word_expression(slot.clone(), &slot_type_name)))
+}
+
+/// Gets the name of the `bytes` variable whose data is referred to by the `add(x, 32), mload(x)` yul arguments, i.e: `x`
+fn get_yul_bytes_data_variable_name(arguments: &[solidity::YulExpression]) -> Option<&str> {
+    let [solidity::YulExpression::FunctionCall(add), solidity::YulExpression::FunctionCall(mload)] = arguments else { return None };
+
+    if add.id.name != "add" || add.arguments.len() != 2 || mload.id.name != "mload" || mload.arguments.len() != 1 {
+        return None;
     }
+
+    let solidity::YulExpression::Variable(data) = &add.arguments[0] else { return None };
+    let solidity::YulExpression::Variable(length) = &mload.arguments[0] else { return None };
+
+    if data.name != length.name || evaluate_yul_number_literal(&add.arguments[1])? != BigUint::from(32u8) {
+        return None;
+    }
+
+    Some(data.name.as_str())
+}
+
+/// Gets the state variable of a `x.slot` yul expression, if it refers to a storage field holding a single value.
+fn get_yul_storage_slot_variable(scope: Rc<RefCell<TranslationScope>>, expression: &solidity::YulExpression) -> Option<Rc<RefCell<TranslatedVariable>>> {
+    let solidity::YulExpression::SuffixAccess(_, container, suffix) = expression else { return None };
+
+    if suffix.name != "slot" {
+        return None;
+    }
+
+    get_yul_storage_variable(scope, container)
+}
+
+/// Gets the state variable referred to by a yul expression, if it is a storage field holding a single value.
+fn get_yul_storage_variable(scope: Rc<RefCell<TranslationScope>>, expression: &solidity::YulExpression) -> Option<Rc<RefCell<TranslatedVariable>>> {
+    let solidity::YulExpression::Variable(solidity::Identifier { name, .. }) = expression else { return None };
+    let variable = scope.borrow().get_variable_from_old_name(name)?;
+
+    // Mappings, arrays, strings and bytes are stored in multiple slots
+    let is_single_value = match &variable.borrow().type_name {
+        sway::TypeName::Identifier { name, .. } => !name.starts_with("Storage"),
+        _ => true,
+    };
+
+    if !variable.borrow().is_storage || !is_single_value {
+        return None;
+    }
+
+    Some(variable)
 }
 
 #[inline]
@@ -377,11 +606,8 @@ pub fn translate_yul_variable_expression(
 ) -> Result<sway::Expression, Error> {
     let Some(variable) = scope.borrow().get_variable_from_old_name(name) else {
         panic!(
-            "{}error: Variable not found in scope: \"{name}\"",
-            match project.loc_to_line_and_column(&translated_definition.path, &expression.loc()) {
-                Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                None => format!("{} - ", translated_definition.path.to_string_lossy()),
-            }
+            "{} - error: Variable not found in scope: \"{name}\"",
+            project.loc_to_file_location_string(&translated_definition.path, &expression.loc())
         );
     };
 
@@ -783,6 +1009,19 @@ pub fn translate_yul_function_call_expression(
         }
 
         "keccak256" => {
+            // keccak256(add(x, 32), mload(x)) => std::hash::keccak256(x)
+            if let Some(name) = get_yul_bytes_data_variable_name(&function_call.arguments) {
+                let value = translate_yul_variable_expression(project, translated_definition, scope.clone(), &function_call.arguments[1], name)?;
+
+                if translated_definition.get_expression_type(scope.clone(), &value)?.to_string() == "Bytes" {
+                    return Ok(sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("std::hash::keccak256".into()),
+                        generic_parameters: None,
+                        parameters: vec![value],
+                    }));
+                }
+            }
+
             // TODO: keccak256(offset, length) => ???
             Ok(sway::Expression::create_todo(Some(function_call.to_string())))
         }
@@ -813,13 +1052,174 @@ pub fn translate_yul_function_call_expression(
         }
 
         "sload" => {
-            // TODO: sload(key) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            // sload(x.slot) => storage.x.read()
+
+            if parameters.len() != 1 {
+                panic!("Invalid yul sload function call, expected 1 parameter, found {}", parameters.len());
+            }
+
+            // sload(_IMPLEMENTATION_SLOT) => storage.implementation.read()
+            if let Some(field_name) = ensure_yul_unstructured_storage_field(project, translated_definition, scope.clone(), &function_call.arguments[0]) {
+                return Ok(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::from(sway::MemberAccess {
+                        expression: sway::Expression::from(sway::MemberAccess {
+                            expression: sway::Expression::Identifier("storage".into()),
+                            member: field_name,
+                        }),
+                        member: "read".into(),
+                    }),
+                    generic_parameters: None,
+                    parameters: vec![],
+                }));
+            }
+
+            // sload(key) => std::storage::storage_api::read::<u256>(key, 0).unwrap_or(0)
+            let Some(variable) = get_yul_storage_slot_variable(scope.clone(), &function_call.arguments[0]) else {
+                let Some(key) = create_yul_storage_key_expression(translated_definition, scope.clone(), &parameters[0])? else {
+                    return Ok(sway::Expression::create_todo(Some(function_call.to_string())));
+                };
+
+                return Ok(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::from(sway::MemberAccess {
+                        expression: sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("std::storage::storage_api::read".into()),
+                            generic_parameters: Some(sway::GenericParameterList {
+                                entries: vec![
+                                    sway::GenericParameter {
+                                        type_name: sway::TypeName::Identifier {
+                                            name: "u256".into(),
+                                            generic_parameters: None,
+                                        },
+                                        implements: None,
+                                    },
+                                ],
+                            }),
+                            parameters: vec![
+                                key,
+                                sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+                            ],
+                        }),
+                        member: "unwrap_or".into(),
+                    }),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+                    ],
+                }));
+            };
+
+            let mut variable = variable.borrow_mut();
+            variable.read_count += 1;
+
+            let value = sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::from(sway::MemberAccess {
+                    expression: sway::Expression::from(sway::MemberAccess {
+                        expression: sway::Expression::Identifier("storage".into()),
+                        member: variable.new_name.clone(),
+                    }),
+                    member: "read".into(),
+                }),
+                generic_parameters: None,
+                parameters: vec![],
+            });
+
+            // Storage slots hold 256-bit words
+            Ok(create_integer_width_conversion_expression(value, &variable.type_name, 256))
         }
 
         "sstore" => {
-            // TODO: sstore(key, value) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            // sstore(x.slot, value) => storage.x.write(value)
+
+            if parameters.len() != 2 {
+                panic!("Invalid yul sstore function call, expected 2 parameters, found {}", parameters.len());
+            }
+
+            // sstore(_IMPLEMENTATION_SLOT, value) => storage.implementation.write(value)
+            if let Some(field_name) = ensure_yul_unstructured_storage_field(project, translated_definition, scope.clone(), &function_call.arguments[0]) {
+                let mut value = parameters[1].clone();
+
+                // Storage slots hold 256-bit words
+                if !is_untyped_integer_expression(&value) {
+                    let value_type_name = translated_definition.get_expression_type(scope.clone(), &value)?;
+                    value = create_integer_width_conversion_expression(value, &value_type_name, 256);
+                }
+
+                return Ok(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::from(sway::MemberAccess {
+                        expression: sway::Expression::from(sway::MemberAccess {
+                            expression: sway::Expression::Identifier("storage".into()),
+                            member: field_name,
+                        }),
+                        member: "write".into(),
+                    }),
+                    generic_parameters: None,
+                    parameters: vec![value],
+                }));
+            }
+
+            // sstore(key, value) => std::storage::storage_api::write::<u256>(key, 0, value)
+            let Some(variable) = get_yul_storage_slot_variable(scope.clone(), &function_call.arguments[0]) else {
+                let Some(key) = create_yul_storage_key_expression(translated_definition, scope.clone(), &parameters[0])? else {
+                    return Ok(sway::Expression::create_todo(Some(function_call.to_string())));
+                };
+
+                let mut value = parameters[1].clone();
+                let mut value_type_name = translated_definition.get_expression_type(scope.clone(), &value)?;
+
+                // Storage slots hold 256-bit words
+                if value_type_name.is_uint() {
+                    if !is_untyped_integer_expression(&value) {
+                        value = create_integer_width_conversion_expression(value, &value_type_name, 256);
+                    }
+
+                    value_type_name = sway::TypeName::Identifier {
+                        name: "u256".into(),
+                        generic_parameters: None,
+                    };
+                }
+
+                return Ok(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("std::storage::storage_api::write".into()),
+                    generic_parameters: Some(sway::GenericParameterList {
+                        entries: vec![
+                            sway::GenericParameter {
+                                type_name: value_type_name,
+                                implements: None,
+                            },
+                        ],
+                    }),
+                    parameters: vec![
+                        key,
+                        sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+                        value,
+                    ],
+                }));
+            };
+
+            let mut value = parameters[1].clone();
+
+            if !is_untyped_integer_expression(&value) {
+                let value_type_name = translated_definition.get_expression_type(scope.clone(), &value)?;
+
+                if let Some(bits) = variable.borrow().type_name.uint_bits() {
+                    value = create_integer_width_conversion_expression(value, &value_type_name, bits);
+                }
+            }
+
+            let mut variable = variable.borrow_mut();
+            variable.mutation_count += 1;
+
+            Ok(sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::from(sway::MemberAccess {
+                    expression: sway::Expression::from(sway::MemberAccess {
+                        expression: sway::Expression::Identifier("storage".into()),
+                        member: variable.new_name.clone(),
+                    }),
+                    member: "write".into(),
+                }),
+                generic_parameters: None,
+                parameters: vec![value],
+            }))
         }
 
         "tload" => {
@@ -843,23 +1243,17 @@ pub fn translate_yul_function_call_expression(
         }
 
         "address" => {
-            // address() => Identity::from(ContractId::this())
+            // address() => to_identity(ContractId::this())
 
             if parameters.len() != 0 {
                 panic!("Invalid yul address function call, expected 0 parameters, found {}", parameters.len());
             }
 
-            Ok(sway::Expression::from(sway::FunctionCall {
-                function: sway::Expression::Identifier("Identity::ContractId".into()),
+            Ok(create_to_identity_call(translated_definition, sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier("ContractId::this".into()),
                 generic_parameters: None,
-                parameters: vec![
-                    sway::Expression::from(sway::FunctionCall {
-                        function: sway::Expression::Identifier("ContractId::this".into()),
-                        generic_parameters: None,
-                        parameters: vec![],
-                    }),
-                ],
-            }))
+                parameters: vec![],
+            })))
         }
 
         "balance" => {
@@ -868,7 +1262,7 @@ pub fn translate_yul_function_call_expression(
         }
 
         "selfbalance" => {
-            // selfbalance() => std::context::this_balance(AssetId::default()).as_u256()
+            // selfbalance() => std::context::this_balance(AssetId::base()).as_u256()
 
             if parameters.len() != 0 {
                 panic!("Invalid yul balance function call, expected 0 parameters, found {}", parameters.len());
@@ -881,7 +1275,7 @@ pub fn translate_yul_function_call_expression(
                         generic_parameters: None,
                         parameters: vec![
                             sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::Identifier("AssetId::default".into()),
+                                function: sway::Expression::Identifier("AssetId::base".into()),
                                 generic_parameters: None,
                                 parameters: vec![],
                             }),
@@ -901,6 +1295,8 @@ pub fn translate_yul_function_call_expression(
                 panic!("Invalid yul caller function call, expected 0 parameters, found {}", parameters.len());
             }
 
+            translated_definition.ensure_use_declared("std::auth::msg_sender");
+
             Ok(sway::Expression::from(sway::FunctionCall {
                 function: sway::Expression::from(sway::MemberAccess {
                     expression: sway::Expression::from(sway::FunctionCall {
@@ -916,14 +1312,18 @@ pub fn translate_yul_function_call_expression(
         }
 
         "callvalue" => {
-            // callvalue() => std::context::msg_amount()
+            // callvalue() => msg_amount()
             
             if parameters.len() != 0 {
                 panic!("Invalid yul callvalue function call, expected 0 parameters, found {}", parameters.len());
             }
 
+            project.report_warning(Lint::MsgValue, &translated_definition.path, Some(&function_call.loc), "`callvalue()` was translated to `msg_amount()`, which does not check that the forwarded coins are of the base asset");
+
+            translated_definition.ensure_use_declared("std::context::msg_amount");
+
             Ok(sway::Expression::from(sway::FunctionCall {
-                function: sway::Expression::Identifier("std::context::msg_amount".into()),
+                function: sway::Expression::Identifier("msg_amount".into()),
                 generic_parameters: None,
                 parameters: vec![],
             }))
@@ -966,6 +1366,16 @@ pub fn translate_yul_function_call_expression(
         }
 
         "extcodesize" => {
+            // extcodesize(x) => match x { Identity::ContractId(x) => asm(r1, r2: x) { csiz r1 r2; r1: u64 }.as_u256(), _ => 0 }
+
+            if parameters.len() != 1 {
+                panic!("Invalid yul extcodesize function call, expected 1 parameter, found {}", parameters.len());
+            }
+
+            if translated_definition.get_expression_type(scope.clone(), &parameters[0])?.to_string() == "Identity" {
+                return Ok(create_code_size_expression(parameters[0].clone()));
+            }
+
             // TODO: extcodesize(addr) => ???
             Ok(sway::Expression::create_todo(Some(function_call.to_string())))
         }
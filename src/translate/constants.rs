@@ -0,0 +1,376 @@
+use super::TranslatedDefinition;
+use crate::sway;
+use num_bigint::BigUint;
+use num_traits::{Num, One, ToPrimitive, Zero};
+use sha3::{Digest, Keccak256};
+use solang_parser::pt as solidity;
+
+/// A value produced by evaluating a constant expression at translation time.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstantValue {
+    Bool(bool),
+    Int(BigUint),
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+impl ConstantValue {
+    /// Attempts to create a value expression of the specified type from the constant value.
+    pub fn to_expression(&self, type_name: &sway::TypeName) -> Option<sway::Expression> {
+        match (self, type_name) {
+            (ConstantValue::Bool(value), sway::TypeName::Identifier { name, generic_parameters: None }) if name == "bool" => {
+                Some(sway::Expression::from(sway::Literal::Bool(*value)))
+            }
+
+            (ConstantValue::Int(value), sway::TypeName::Identifier { name, generic_parameters: None }) => {
+                let bits = match name.as_str() {
+                    "u8" => 8,
+                    "u16" => 16,
+                    "u32" => 32,
+                    "u64" => 64,
+                    "u256" | "b256" => 256,
+                    _ => return None,
+                };
+
+                // Don't emit values that can't fit in the destination type
+                if value.bits() > bits {
+                    return None;
+                }
+
+                if name == "b256" {
+                    Some(sway::Expression::from(sway::Literal::B256(value.clone())))
+                } else {
+                    Some(sway::Expression::from(sway::Literal::DecInt(value.clone())))
+                }
+            }
+
+            (ConstantValue::Bytes(value), sway::TypeName::Identifier { name, generic_parameters: None }) if name == "b256" && value.len() <= 32 => {
+                // Fixed-size byte arrays are left-aligned
+                let mut bytes = value.clone();
+                bytes.resize(32, 0);
+
+                ConstantValue::Int(BigUint::from_bytes_be(bytes.as_slice())).to_expression(type_name)
+            }
+
+            (ConstantValue::String(value), sway::TypeName::StringSlice) => {
+                Some(sway::Expression::from(sway::Literal::String(value.clone())))
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Gets the packed byte representation of the constant value, if it has one.
+    fn to_packed_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            ConstantValue::String(value) => Some(value.as_bytes().to_vec()),
+            ConstantValue::Bytes(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Gets the multiplier of a Solidity number unit (i.e: `ether`, `days`, etc).
+pub fn get_number_unit_multiplier(unit: &str) -> Option<BigUint> {
+    match unit {
+        "wei" | "seconds" => Some(BigUint::one()),
+        "gwei" => Some(BigUint::from(10u64.pow(9))),
+        "szabo" => Some(BigUint::from(10u64.pow(12))),
+        "finney" => Some(BigUint::from(10u64.pow(15))),
+        "ether" => Some(BigUint::from(10u64.pow(18))),
+        "minutes" => Some(BigUint::from(60u64)),
+        "hours" => Some(BigUint::from(60u64 * 60)),
+        "days" => Some(BigUint::from(60u64 * 60 * 24)),
+        "weeks" => Some(BigUint::from(60u64 * 60 * 24 * 7)),
+        _ => None,
+    }
+}
+
+/// Evaluates the integer value of a Solidity number literal, including its exponent and unit.
+pub fn evaluate_number_literal(
+    integer: &str,
+    fraction: &str,
+    exponent: &str,
+    unit: Option<&solidity::Identifier>,
+) -> Option<BigUint> {
+    let integer = integer.replace('_', "");
+    let fraction = fraction.replace('_', "");
+    let exponent = exponent.replace('_', "");
+
+    let mut numerator: BigUint = format!("{integer}{fraction}").parse().ok()?;
+    let mut denominator = BigUint::from(10u64).pow(fraction.len() as u32);
+
+    if !exponent.is_empty() {
+        let exponent: i32 = exponent.parse().ok()?;
+        let scale = BigUint::from(10u64).pow(exponent.unsigned_abs());
+
+        if exponent < 0 {
+            denominator *= scale;
+        } else {
+            numerator *= scale;
+        }
+    }
+
+    if let Some(unit) = unit {
+        numerator *= get_number_unit_multiplier(unit.name.as_str())?;
+    }
+
+    // Only integral values can be represented
+    if !(&numerator % &denominator).is_zero() {
+        return None;
+    }
+
+    Some(numerator / denominator)
+}
+
+/// Attempts to evaluate a Solidity constant expression at translation time.
+///
+/// Returns `None` if the expression contains anything that can't be evaluated ahead of time.
+pub fn evaluate_constant_expression(
+    translated_definition: &TranslatedDefinition,
+    expression: &solidity::Expression,
+) -> Option<ConstantValue> {
+    let evaluate = |x: &solidity::Expression| evaluate_constant_expression(translated_definition, x);
+
+    let evaluate_int = |x: &solidity::Expression| match evaluate(x)? {
+        ConstantValue::Int(value) => Some(value),
+        _ => None,
+    };
+
+    let evaluate_u32 = |x: &solidity::Expression| evaluate_int(x)?.to_u32();
+
+    match expression {
+        solidity::Expression::BoolLiteral(_, value) => Some(ConstantValue::Bool(*value)),
+
+        solidity::Expression::NumberLiteral(_, value, exponent, unit) => {
+            evaluate_number_literal(value, "", exponent, unit.as_ref()).map(ConstantValue::Int)
+        }
+
+        solidity::Expression::RationalNumberLiteral(_, integer, fraction, exponent, unit) => {
+            evaluate_number_literal(integer, fraction, exponent, unit.as_ref()).map(ConstantValue::Int)
+        }
+
+        solidity::Expression::HexNumberLiteral(_, value, None) => {
+            BigUint::from_str_radix(value.trim_start_matches("0x").replace('_', "").as_str(), 16).ok().map(ConstantValue::Int)
+        }
+
+        solidity::Expression::HexLiteral(hex_literals) => {
+            let mut bytes = vec![];
+
+            for hex_literal in hex_literals.iter() {
+                let hex = hex_literal.hex.trim_start_matches("hex").trim_matches(|c| c == '"' || c == '\'').replace('_', "");
+
+                if hex.len() % 2 != 0 {
+                    return None;
+                }
+
+                for i in (0..hex.len()).step_by(2) {
+                    bytes.push(u8::from_str_radix(&hex[i..i + 2], 16).ok()?);
+                }
+            }
+
+            Some(ConstantValue::Bytes(bytes))
+        }
+
+        solidity::Expression::StringLiteral(value) => {
+            Some(ConstantValue::String(value.iter().map(|s| s.string.clone()).collect::<Vec<_>>().join("")))
+        }
+
+        solidity::Expression::Parenthesis(_, x) => evaluate(x),
+
+        // Only references to other constants can be evaluated
+        solidity::Expression::Variable(solidity::Identifier { name, .. }) => {
+            let scope = translated_definition.toplevel_scope.borrow();
+            let variable = scope.get_variable_from_old_name(name.as_str())?;
+            let variable = variable.borrow();
+
+            if !variable.is_constant {
+                return None;
+            }
+
+            let constant = translated_definition.constants.iter().find(|c| c.name == variable.new_name)?;
+
+            match constant.value.as_ref()? {
+                sway::Expression::Literal(sway::Literal::Bool(value)) => Some(ConstantValue::Bool(*value)),
+                sway::Expression::Literal(sway::Literal::DecInt(value) | sway::Literal::HexInt(value) | sway::Literal::B256(value)) => Some(ConstantValue::Int(value.clone())),
+                sway::Expression::Literal(sway::Literal::String(value)) => Some(ConstantValue::String(value.clone())),
+                _ => None,
+            }
+        }
+
+        solidity::Expression::Not(_, x) => match evaluate(x)? {
+            ConstantValue::Bool(value) => Some(ConstantValue::Bool(!value)),
+            _ => None,
+        },
+
+        solidity::Expression::And(_, lhs, rhs) | solidity::Expression::Or(_, lhs, rhs) => {
+            let (ConstantValue::Bool(lhs), ConstantValue::Bool(rhs)) = (evaluate(lhs)?, evaluate(rhs)?) else {
+                return None;
+            };
+
+            Some(ConstantValue::Bool(if matches!(expression, solidity::Expression::And(_, _, _)) { lhs && rhs } else { lhs || rhs }))
+        }
+
+        solidity::Expression::Add(_, lhs, rhs) => Some(ConstantValue::Int(evaluate_int(lhs)? + evaluate_int(rhs)?)),
+
+        solidity::Expression::Subtract(_, lhs, rhs) => {
+            let (lhs, rhs) = (evaluate_int(lhs)?, evaluate_int(rhs)?);
+
+            // Negative values can't be represented
+            if rhs > lhs {
+                return None;
+            }
+
+            Some(ConstantValue::Int(lhs - rhs))
+        }
+
+        solidity::Expression::Multiply(_, lhs, rhs) => Some(ConstantValue::Int(evaluate_int(lhs)? * evaluate_int(rhs)?)),
+
+        solidity::Expression::Divide(_, lhs, rhs) | solidity::Expression::Modulo(_, lhs, rhs) => {
+            let (lhs, rhs) = (evaluate_int(lhs)?, evaluate_int(rhs)?);
+
+            if rhs.is_zero() {
+                return None;
+            }
+
+            Some(ConstantValue::Int(if matches!(expression, solidity::Expression::Divide(_, _, _)) { lhs / rhs } else { lhs % rhs }))
+        }
+
+        solidity::Expression::Power(_, lhs, rhs) => Some(ConstantValue::Int(evaluate_int(lhs)?.pow(evaluate_u32(rhs)?))),
+        solidity::Expression::ShiftLeft(_, lhs, rhs) => Some(ConstantValue::Int(evaluate_int(lhs)? << evaluate_u32(rhs)?)),
+        solidity::Expression::ShiftRight(_, lhs, rhs) => Some(ConstantValue::Int(evaluate_int(lhs)? >> evaluate_u32(rhs)?)),
+        solidity::Expression::BitwiseAnd(_, lhs, rhs) => Some(ConstantValue::Int(evaluate_int(lhs)? & evaluate_int(rhs)?)),
+        solidity::Expression::BitwiseOr(_, lhs, rhs) => Some(ConstantValue::Int(evaluate_int(lhs)? | evaluate_int(rhs)?)),
+        solidity::Expression::BitwiseXor(_, lhs, rhs) => Some(ConstantValue::Int(evaluate_int(lhs)? ^ evaluate_int(rhs)?)),
+
+        solidity::Expression::Less(_, lhs, rhs) => Some(ConstantValue::Bool(evaluate_int(lhs)? < evaluate_int(rhs)?)),
+        solidity::Expression::More(_, lhs, rhs) => Some(ConstantValue::Bool(evaluate_int(lhs)? > evaluate_int(rhs)?)),
+        solidity::Expression::LessEqual(_, lhs, rhs) => Some(ConstantValue::Bool(evaluate_int(lhs)? <= evaluate_int(rhs)?)),
+        solidity::Expression::MoreEqual(_, lhs, rhs) => Some(ConstantValue::Bool(evaluate_int(lhs)? >= evaluate_int(rhs)?)),
+        solidity::Expression::Equal(_, lhs, rhs) => Some(ConstantValue::Bool(evaluate(lhs)? == evaluate(rhs)?)),
+        solidity::Expression::NotEqual(_, lhs, rhs) => Some(ConstantValue::Bool(evaluate(lhs)? != evaluate(rhs)?)),
+
+        solidity::Expression::ConditionalOperator(_, condition, then_value, else_value) => match evaluate(condition)? {
+            ConstantValue::Bool(true) => evaluate(then_value),
+            ConstantValue::Bool(false) => evaluate(else_value),
+            _ => None,
+        },
+
+        solidity::Expression::FunctionCall(_, function, arguments) => match function.as_ref() {
+            // keccak256("...") => 0x...
+            solidity::Expression::Variable(solidity::Identifier { name, .. }) if name == "keccak256" && arguments.len() == 1 => {
+                let bytes = evaluate(&arguments[0])?.to_packed_bytes()?;
+                Some(ConstantValue::Int(BigUint::from_bytes_be(&Keccak256::digest(bytes))))
+            }
+
+            // abi.encodePacked("...", ...), string.concat("...", ...), bytes.concat("...", ...)
+            solidity::Expression::MemberAccess(_, container, member) => {
+                let is_string = match (container.as_ref(), member.name.as_str()) {
+                    (solidity::Expression::Variable(solidity::Identifier { name, .. }), "encodePacked") if name == "abi" => false,
+                    (solidity::Expression::Type(_, solidity::Type::String), "concat") => true,
+                    (solidity::Expression::Type(_, solidity::Type::DynamicBytes), "concat") => false,
+                    _ => return None,
+                };
+
+                let mut bytes = vec![];
+
+                for argument in arguments.iter() {
+                    bytes.extend(evaluate(argument)?.to_packed_bytes()?);
+                }
+
+                if is_string {
+                    String::from_utf8(bytes).ok().map(ConstantValue::String)
+                } else {
+                    Some(ConstantValue::Bytes(bytes))
+                }
+            }
+
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
+
+/// Checks if a translated value expression can be evaluated at compile time (i.e: in a storage field initializer).
+pub fn is_constant_value_expression(
+    translated_definition: &TranslatedDefinition,
+    expression: &sway::Expression,
+) -> bool {
+    match expression {
+        sway::Expression::Literal(_) => true,
+
+        sway::Expression::Identifier(name) => {
+            // Enum variants and std constants
+            if name.contains("::") || name == "ZERO_B256" {
+                return true;
+            }
+
+            translated_definition.toplevel_scope.borrow()
+                .get_variable_from_new_name(name)
+                .map(|v| v.borrow().is_constant)
+                .unwrap_or(false)
+        }
+
+        sway::Expression::FunctionCall(function_call) => {
+            let sway::Expression::Identifier(function_name) = &function_call.function else {
+                return false;
+            };
+
+            let is_constant_function = matches!(
+                function_name.split_once("::"),
+                Some(("Identity", "Address" | "ContractId"))
+                | Some(("Address" | "ContractId", "from"))
+                | Some(("I8" | "I16" | "I32" | "I64" | "I128" | "I256", "new" | "min" | "max"))
+                | Some(("u8" | "u16" | "u32" | "u64" | "u256", "min" | "max"))
+            );
+
+            is_constant_function && function_call.parameters.iter().all(|x| is_constant_value_expression(translated_definition, x))
+        }
+
+        sway::Expression::Array(array) => array.elements.iter().all(|x| is_constant_value_expression(translated_definition, x)),
+        sway::Expression::Tuple(elements) => elements.iter().all(|x| is_constant_value_expression(translated_definition, x)),
+        sway::Expression::Constructor(constructor) => constructor.fields.iter().all(|x| is_constant_value_expression(translated_definition, &x.value)),
+        sway::Expression::UnaryExpression(unary_expression) => is_constant_value_expression(translated_definition, &unary_expression.expression),
+
+        sway::Expression::BinaryExpression(binary_expression) => {
+            is_constant_value_expression(translated_definition, &binary_expression.lhs)
+                && is_constant_value_expression(translated_definition, &binary_expression.rhs)
+        }
+
+        sway::Expression::Commented(_, x) => is_constant_value_expression(translated_definition, x),
+
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(source: &str) -> Option<ConstantValue> {
+        let (source_unit, _) = solang_parser::parse(format!("uint256 constant X = {source};").as_str(), 0).unwrap();
+
+        let Some(solidity::SourceUnitPart::VariableDefinition(variable_definition)) = source_unit.0.first() else {
+            panic!("Expected variable definition");
+        };
+
+        evaluate_constant_expression(&TranslatedDefinition::default(), variable_definition.initializer.as_ref().unwrap())
+    }
+
+    #[test]
+    fn test_evaluate_constant_expressions() {
+        assert_eq!(evaluate("3 * 10**15"), Some(ConstantValue::Int(BigUint::from(3_000_000_000_000_000u64))));
+        assert_eq!(evaluate("(1 << 8) - 1"), Some(ConstantValue::Int(BigUint::from(255u64))));
+        assert_eq!(evaluate("1.5 ether"), Some(ConstantValue::Int(BigUint::from(1_500_000_000_000_000_000u64))));
+        assert_eq!(evaluate("2e3 + 7 days"), Some(ConstantValue::Int(BigUint::from(2_000u64 + 604_800))));
+        assert_eq!(evaluate("string.concat(\"ab\", \"cd\")"), Some(ConstantValue::String("abcd".into())));
+        assert_eq!(evaluate("1 - 2"), None);
+        assert_eq!(evaluate("0.5"), None);
+
+        assert_eq!(
+            evaluate("keccak256(\"hello\")"),
+            Some(ConstantValue::Int(BigUint::from_str_radix("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8", 16).unwrap())),
+        );
+    }
+}
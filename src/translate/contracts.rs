@@ -1,12 +1,13 @@
 use super::{
-    create_assignment_expression, generate_enum_abi_encode_function, resolve_import,
-    translate_enum_definition, translate_error_definition, translate_event_definition,
+    create_assignment_expression, extract_invariants, generate_enum_abi_encode_function, infer_storage_attributes, instrument_debug_events, is_public_function,
+    map_inherited_access_control, map_inherited_pausable, map_inherited_reentrancy_guard, report_reentrancy_patterns, resolve_import,
+    translate_enum_definition, translate_error_definition, translate_event_definition, translate_fallback_functions,
     translate_function_declaration, translate_function_definition, translate_import_directives,
     translate_modifier_definition, translate_state_variable, translate_storage_name,
-    translate_struct_definition, translate_type_definition, translate_type_name,
-    TranslatedDefinition, TranslatedUsingDirective,
+    translate_struct_definition, translate_type_definition, translate_type_name, translate_user_defined_operators,
+    translate_expression, RenameReason, TranslatedDefinition, TranslatedFunction, TranslatedUsingDirective, TranslatedVariable, TranslationScope,
 };
-use crate::{project::Project, sway, Error};
+use crate::{emitter::emitln, project::Project, sway, Error};
 use convert_case::Case;
 use solang_parser::pt as solidity;
 use std::{
@@ -23,18 +24,18 @@ pub fn translate_using_directive(
 ) -> Result<(), Error> {
     let for_type = using_directive.ty.as_ref()
         .map(|t| translate_type_name(project, translated_definition, t, false, false))
-        .map_or(Ok(None), |t| Ok(Some(t)))?;
+        .transpose()?;
 
     match &using_directive.list {
         solidity::UsingList::Library(using_library) => {
             let library_name = using_library.identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".");
 
-            // Find the translated library definition
-            let Some(library_definition) = project.translated_definitions.iter().find(|d| {
-                d.name == library_name && matches!(d.kind.as_ref().unwrap(), solidity::ContractTy::Library(_))
-            }) else {
-                panic!("Failed to find translated library: \"{library_name}\"");
-            };
+            // A library doesn't need to use its own functions through a toplevel using directive
+            if library_name == translated_definition.name {
+                return Ok(());
+            }
+
+            let library_definition = find_library_definition(project, translated_definition, library_name.as_str())?;
 
             let mut translated_using_directive = TranslatedUsingDirective {
                 library_name,
@@ -42,54 +43,97 @@ pub fn translate_using_directive(
                 functions: vec![],
             };
 
+            // SafeMath functions are translated to natively checked arithmetic, so the library doesn't need to be imported
+            if is_safe_math_library(&library_definition) {
+                translated_definition.using_directives.push(translated_using_directive);
+                return Ok(());
+            }
+
             // Collect all functions that support the `for_type`
             for function in library_definition.functions.iter() {
                 // If we're using the library for a specific type, ensure the first function parameter matches that type
-                if translated_using_directive.for_type != function.parameters.entries.first().and_then(|p| p.type_name.clone()) {
-                    continue;
-                }
+                if let Some(for_type) = translated_using_directive.for_type.as_ref() {
+                    let Some(parameter_type) = function.parameters.entries.first().and_then(|p| p.type_name.as_ref()) else { continue };
 
-                // Get the scope entry for the library function
-                let Some(scope_entry) = library_definition.toplevel_scope.borrow().find_function(|f| f.borrow().new_name == function.name) else {
-                    panic!("Failed to find function in scope: \"{}\"", function.name);
-                };
+                    // using SafeERC20 for IERC20 => parameters with an ABI type are translated as identities
+                    let is_abi_parameter = matches!(parameter_type, sway::TypeName::Identifier { name, generic_parameters: None } if name == "Identity")
+                        && matches!(for_type, sway::TypeName::Identifier { name, generic_parameters: None } if project.find_definition_with_abi(name).is_some());
 
-                // Add the function to the current definition's toplevel scope
-                if !translated_definition.toplevel_scope.borrow().functions.iter().any(|f| {
-                    f.borrow().old_name == scope_entry.borrow().old_name
-                    && f.borrow().parameters == scope_entry.borrow().parameters
-                    && f.borrow().return_type == scope_entry.borrow().return_type
-                }) {
-                    translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(scope_entry.borrow().clone())));
+                    if for_type != parameter_type && !is_abi_parameter {
+                        continue;
+                    }
                 }
 
                 // Add the function to the translated using directive so we know where it came from
-                translated_using_directive.functions.push(scope_entry.borrow().clone());
+                let scope_entry = import_library_function(translated_definition, &library_definition, function);
+                translated_using_directive.functions.push(scope_entry);
+            }
 
-                // Add the function name to the current definition's function name list
-                *translated_definition.function_name_counts.entry(function.name.clone()).or_insert(0) += 1;
+            // Add the using directive to the current definition
+            translated_definition.using_directives.push(translated_using_directive);
+        }
 
-                // Add the function definition to the current definition
-                if !translated_definition.functions.contains(function) {
-                    translated_definition.functions.push(function.clone());
+        solidity::UsingList::Functions(using_functions) => {
+            for using_function in using_functions.iter() {
+                // User-defined operators are translated by `translate_user_defined_operators`
+                if using_function.oper.is_some() {
+                    continue;
                 }
 
-                // Add the function call count from the library definition to the current definition
-                translated_definition.function_call_counts.insert(
-                    function.name.clone(),
-                    if let Some(function_call_count) = library_definition.function_call_counts.get(&function.name) {
-                        *function_call_count
-                    } else {
-                        0
+                let identifiers = &using_function.path.identifiers;
+                let function_name = identifiers.last().unwrap().name.clone();
+                let library_name = identifiers[..identifiers.len() - 1].iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".");
+
+                // A library doesn't need to use its own functions through a toplevel using directive
+                if library_name == translated_definition.name {
+                    continue;
+                }
+
+                let mut translated_using_directive = TranslatedUsingDirective {
+                    library_name: library_name.clone(),
+                    for_type: for_type.clone(),
+                    functions: vec![],
+                };
+
+                if library_name.is_empty() {
+                    // using {f} for T => f is a function in the toplevel scope
+                    translated_using_directive.library_name = translated_definition.name.clone();
+
+                    for f in translated_definition.toplevel_scope.borrow().functions.iter() {
+                        if f.borrow().old_name == function_name {
+                            translated_using_directive.functions.push(f.borrow().clone());
+                        }
+                    }
+                } else {
+                    // using {L.f} for T => f is a function of the library L
+                    let library_definition = find_library_definition(project, translated_definition, library_name.as_str())?;
+
+                    for function in library_definition.functions.iter() {
+                        let is_named_function = library_definition.toplevel_scope.borrow()
+                            .find_function(|f| f.borrow().new_name == function.name && f.borrow().old_name == function_name)
+                            .is_some();
+
+                        if !is_named_function {
+                            continue;
+                        }
+
+                        let scope_entry = import_library_function(translated_definition, &library_definition, function);
+                        translated_using_directive.functions.push(scope_entry);
                     }
-                );
-            }
+                }
 
-            // Add the using directive to the current definition
-            translated_definition.using_directives.push(translated_using_directive);
-        }
+                if translated_using_directive.functions.is_empty() {
+                    panic!(
+                        "{} - Failed to find function for using directive: \"{}\"",
+                        project.loc_to_file_location_string(&translated_definition.path, &using_function.loc),
+                        identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join("."),
+                    );
+                }
 
-        solidity::UsingList::Functions(_) => todo!("using directive function list: {}", using_directive.to_string()),
+                // Add the using directive to the current definition
+                translated_definition.using_directives.push(translated_using_directive);
+            }
+        }
 
         solidity::UsingList::Error => panic!("Failed to parse using directive"),
     }
@@ -97,6 +141,132 @@ pub fn translate_using_directive(
     Ok(())
 }
 
+/// Checks if the library has the shape of the `SafeMath` library, whose functions are equivalent to the natively checked arithmetic of Sway.
+pub fn is_safe_math_library(library_definition: &TranslatedDefinition) -> bool {
+    if !matches!(library_definition.kind, Some(solidity::ContractTy::Library(_))) {
+        return false;
+    }
+
+    let toplevel_scope = library_definition.toplevel_scope.borrow();
+
+    ["add", "sub", "mul", "div"].iter().all(|function_name| {
+        toplevel_scope.functions.iter().any(|f| {
+            let f = f.borrow();
+
+            // function add(uint256 a, uint256 b) internal pure returns (uint256)
+            f.old_name == *function_name
+                && f.return_type.is_some()
+                && f.parameters.entries.len() == 2
+                && f.parameters.entries.iter().all(|p| p.type_name == f.return_type)
+        })
+    })
+}
+
+/// Finds the translated definition of a library, translating it ahead of time if it is declared later in the same file.
+fn find_library_definition(
+    project: &mut Project,
+    translated_definition: &TranslatedDefinition,
+    library_name: &str,
+) -> Result<TranslatedDefinition, Error> {
+    if !project.translated_definitions.iter().any(|d| d.name == library_name) {
+        let source_unit_path = translated_definition.path.clone();
+        project.translate_source_unit(Some(&library_name.to_string()), &source_unit_path)?;
+    }
+
+    let Some(library_definition) = project.translated_definitions.iter().find(|d| {
+        d.name == library_name && matches!(d.kind.as_ref().unwrap(), solidity::ContractTy::Library(_))
+    }) else {
+        panic!("Failed to find translated library: \"{library_name}\"");
+    };
+
+    Ok(library_definition.clone())
+}
+
+/// Imports a library function into the current definition, returning its scope entry.
+fn import_library_function(
+    translated_definition: &mut TranslatedDefinition,
+    library_definition: &TranslatedDefinition,
+    function: &sway::Function,
+) -> TranslatedFunction {
+    // Get the scope entry for the library function
+    let Some(scope_entry) = library_definition.toplevel_scope.borrow().find_function(|f| f.borrow().new_name == function.name) else {
+        panic!("Failed to find function in scope: \"{}\"", function.name);
+    };
+
+    // Add the function to the current definition's toplevel scope
+    if !translated_definition.toplevel_scope.borrow().functions.iter().any(|f| {
+        f.borrow().old_name == scope_entry.borrow().old_name
+        && f.borrow().parameters == scope_entry.borrow().parameters
+        && f.borrow().return_type == scope_entry.borrow().return_type
+    }) {
+        translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(scope_entry.borrow().clone())));
+    }
+
+    // Add the function name to the current definition's function name list
+    *translated_definition.function_name_counts.entry(function.name.clone()).or_insert(0) += 1;
+
+    // Add the function definition to the current definition
+    if !translated_definition.functions.contains(function) {
+        translated_definition.functions.push(function.clone());
+        import_library_function_dependencies(translated_definition, library_definition, function);
+    }
+
+    // Add the function call count from the library definition to the current definition
+    translated_definition.function_call_counts.insert(
+        function.name.clone(),
+        if let Some(function_call_count) = library_definition.function_call_counts.get(&function.name) {
+            *function_call_count
+        } else {
+            0
+        }
+    );
+
+    // Add the declared mutability from the library definition to the current definition
+    if let Some(mutability) = library_definition.function_mutabilities.get(&function.name) {
+        translated_definition.function_mutabilities.insert(function.name.clone(), mutability.clone());
+    }
+
+    let scope_entry = scope_entry.borrow().clone();
+    scope_entry
+}
+
+/// Imports the library functions called by an imported library function, since library functions are copied into the definitions that use them.
+pub fn import_library_function_dependencies(
+    translated_definition: &mut TranslatedDefinition,
+    library_definition: &TranslatedDefinition,
+    function: &sway::Function,
+) {
+    let Some(body) = function.body.as_ref() else { return };
+
+    let mut function_names = vec![];
+
+    body.visit_expressions(&mut |expression| {
+        let sway::Expression::FunctionCall(function_call) = expression else { return };
+        let sway::Expression::Identifier(name) = &function_call.function else { return };
+
+        if !function_names.contains(name) {
+            function_names.push(name.clone());
+        }
+    });
+
+    for function_name in function_names {
+        if translated_definition.functions.iter().any(|f| f.name == function_name) {
+            continue;
+        }
+
+        let Some(dependency) = library_definition.functions.iter().find(|f| f.name == function_name) else { continue };
+
+        translated_definition.functions.push(dependency.clone());
+        *translated_definition.function_call_counts.entry(function_name.clone()).or_insert(0) += 1;
+
+        if let Some(mutability) = library_definition.function_mutabilities.get(&function_name) {
+            translated_definition.function_mutabilities.insert(function_name.clone(), mutability.clone());
+        }
+
+        import_library_function_dependencies(translated_definition, library_definition, dependency);
+    }
+}
+
 #[inline]
 pub fn translate_contract_definition(
     project: &mut Project,
@@ -112,12 +282,32 @@ pub fn translate_contract_definition(
     contract_names: &[String],
     contract_definition: &solidity::ContractDefinition,
 ) -> Result<(), Error> {
+    // Use the definition supplied by a translation hook if it recognizes the contract
+    for translation_hook in project.translation_hooks.clone() {
+        if let Some(translated_definition) = translation_hook.recognize_contract(source_unit_path, contract_definition) {
+            project.translated_definitions.push(translated_definition);
+            return Ok(());
+        }
+    }
+
     let definition_name = contract_definition.name.as_ref().unwrap().name.clone();
-    let inherits: Vec<String> = contract_definition.base.iter().map(|b| b.name.identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".")).collect();
+    let inherits: Vec<String> = contract_definition.base.iter()
+        .map(|b| {
+            let identifiers = b.name.identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>();
+
+            // Refer to inherited definitions imported under an alias or through a namespace by their declared names
+            project.resolve_import_symbol(source_unit_path, identifiers.as_slice())
+                .unwrap_or_else(|| identifiers.join("."))
+        })
+        .collect();
+
+    // Record the warnings of the definition separately from the definitions translated while resolving its imports
+    let outer_warnings = std::mem::take(&mut project.warnings);
 
     // Create a new translation container
     let mut translated_definition = TranslatedDefinition {
         contract_names: contract_names.into(),
+        comment: project.get_leading_comment(source_unit_path, &contract_definition.loc),
         ..TranslatedDefinition::new(
             source_unit_path,
             contract_definition.ty.clone(),
@@ -129,8 +319,12 @@ pub fn translate_contract_definition(
     // Translate import directives
     translate_import_directives(project, &mut translated_definition, import_directives)?;
 
-    // Translate toplevel using directives
+    // Translate toplevel using directives (function lists are translated once the toplevel functions have been declared)
     for using_directive in toplevel_using_directives {
+        if let solidity::UsingList::Functions(_) = &using_directive.list {
+            continue;
+        }
+
         translate_using_directive(project, &mut translated_definition, using_directive)?;
     }
 
@@ -199,6 +393,14 @@ pub fn translate_contract_definition(
         }
     }
 
+    // Translate toplevel using directive function lists and user-defined operators
+    for using_directive in toplevel_using_directives {
+        let solidity::UsingList::Functions(_) = &using_directive.list else { continue };
+
+        translate_using_directive(project, &mut translated_definition, using_directive)?;
+        translate_user_defined_operators(project, &mut translated_definition, using_directive)?;
+    }
+
     // Translate toplevel function definitions
     for function_definition in toplevel_functions {
         translate_function_definition(project, &mut translated_definition, function_definition)?;
@@ -274,9 +476,28 @@ pub fn translate_contract_definition(
     }
     
     // Collect each toplevel function ahead of time for contextual reasons
-    for part in contract_definition.parts.iter() {
-        let solidity::ContractPart::FunctionDefinition(function_definition) = part else { continue };
+    let function_definitions = contract_definition.parts.iter()
+        .filter_map(|part| match part {
+            solidity::ContractPart::FunctionDefinition(function_definition) => Some(function_definition),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Collect the names of public functions first so they are preserved in the abi when internal functions declared before them collide with them
+    for function_definition in function_definitions.iter() {
+        if !is_public_function(function_definition) || matches!(function_definition.ty, solidity::FunctionTy::Modifier) {
+            continue;
+        }
+
+        let Some(name) = function_definition.name.as_ref() else { continue };
+        let new_name = project.translate_naming_convention(name.name.as_str(), Case::Snake);
 
+        if !translated_definition.public_function_names.contains(&new_name) {
+            translated_definition.public_function_names.push(new_name);
+        }
+    }
+
+    for function_definition in function_definitions {
         let is_modifier = matches!(function_definition.ty, solidity::FunctionTy::Modifier);
 
         if is_modifier {
@@ -375,122 +596,21 @@ pub fn translate_contract_definition(
             }
         }
         
-        let mut constructor_function = translated_definition.functions.iter_mut().find(|f| f.name == "constructor");
-    
-        // Create the constructor if it doesn't exist
-        if constructor_function.is_none() {
-            let mut function = sway::Function {
-                attributes: None,
-                is_public: false,
-                name: "constructor".into(),
-                generic_parameters: None,
-                parameters: sway::ParameterList::default(),
-                return_type: None,
-                body: None,
-            };
-    
-            translated_definition.get_abi().functions.insert(0, function.clone());
-    
-            function.body = Some(sway::Block::default());
-            let function_body = function.body.as_mut().unwrap();
-    
-            let prefix = crate::translate_naming_convention(translated_definition.name.as_str(), Case::Snake);
-            let constructor_called_variable_name =  translate_storage_name(project, &mut translated_definition, format!("{prefix}_constructor_called").as_str());
-            
-            // Add the `constructor_called` field to the storage block
-            translated_definition.get_storage().fields.push(sway::StorageField {
-                name: constructor_called_variable_name.clone(),
-                type_name: sway::TypeName::Identifier {
-                    name: "bool".into(),
-                    generic_parameters: None,
-                },
-                value: sway::Expression::from(sway::Literal::Bool(false)),
-            });
-    
-            // Add the `constructor_called` requirement to the beginning of the function
-            // require(!storage.initialized.read(), "The Contract constructor has already been called");
-            function_body.statements.insert(0, sway::Statement::from(sway::Expression::from(sway::FunctionCall {
-                function: sway::Expression::Identifier("require".into()),
-                generic_parameters: None,
-                parameters: vec![
-                    sway::Expression::from(sway::UnaryExpression {
-                        operator: "!".into(),
-                        expression: sway::Expression::from(sway::FunctionCall {
-                            function: sway::Expression::from(sway::MemberAccess {
-                                expression: sway::Expression::from(sway::MemberAccess {
-                                    expression: sway::Expression::Identifier("storage".into()),
-                                    member: constructor_called_variable_name.clone(),
-                                }),
-                                member: "read".into(),
-                            }),
-                            generic_parameters: None,
-                            parameters: vec![],
-                        })
-                    }),
-                    sway::Expression::from(sway::Literal::String(format!("The {} constructor has already been called", translated_definition.name))),
-                ],
-            })));
-    
-            // Set the `constructor_called` storage field to `true` at the end of the function
-            // storage.initialized.write(true);
-            function_body.statements.push(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
-                function: sway::Expression::from(sway::MemberAccess {
-                    expression: sway::Expression::from(sway::MemberAccess {
-                        expression: sway::Expression::Identifier("storage".into()),
-                        member: constructor_called_variable_name.clone(),
-                    }),
-                    member: "write".into(),
-                }),
-                generic_parameters: None,
-                parameters: vec![
-                    sway::Expression::from(sway::Literal::Bool(true)),
-                ],
-            })));
-
-            translated_definition.get_contract_impl().items.insert(0, sway::ImplItem::Function(function));
-            constructor_function = translated_definition.get_contract_impl().items.iter_mut()
-                .find(|i| {
-                    let sway::ImplItem::Function(f) = i else { return false };
-                    f.name == "constructor"
-                })
-                .map(|i| {
-                    let sway::ImplItem::Function(f) = i else { unreachable!() };
-                    f
-                });
-        }
-
-        let constructor_function = constructor_function.unwrap();
-
-        if constructor_function.body.is_none() {
-            constructor_function.body = Some(sway::Block::default());
-        }
-
-        let constructor_body = constructor_function.body.as_mut().unwrap();
-
-        let mut statement_index = 0;
-
-        // Skip past the initial constructor requirements
-        for (i, statement) in constructor_body.statements.iter().enumerate() {
-            let sway::Statement::Expression(sway::Expression::FunctionCall(function_call)) = statement else {
-                statement_index = i;
-                break;
-            };
-
-            let sway::Expression::Identifier(function_name) = &function_call.function else {
-                statement_index = i;
-                break;
-            };
+        // Add the deferred initializations to the constructor body
+        let constructor_function = ensure_constructor_function(project, &mut translated_definition);
+        insert_constructor_statements(constructor_function, assignment_statements);
+    }
 
-            if function_name != "require" {
-                statement_index = i;
-                break;
-            }
-        }
+    // Call the constructors of the base contracts in the order of their linearization
+    if matches!(translated_definition.kind.as_ref(), Some(solidity::ContractTy::Contract(_))) {
+        translate_base_constructor_calls(project, &mut translated_definition, contract_definition)?;
+    }
 
-        // Add the deferred initializations to the constructor body
-        for statement in assignment_statements.into_iter().rev() {
-            constructor_body.statements.insert(statement_index, statement);
-        }
+    // Map inherited ownership, access control, pausing and reentrancy guards to Sway equivalents instead of porting their internals
+    if matches!(translated_definition.kind.as_ref(), Some(solidity::ContractTy::Contract(_))) {
+        map_inherited_access_control(project, &mut translated_definition);
+        map_inherited_pausable(project, &mut translated_definition);
+        map_inherited_reentrancy_guard(project, &mut translated_definition);
     }
 
     // Look for toplevel functions that are never called, move their implementation to the abi wrapper function if it exists
@@ -513,12 +633,399 @@ pub fn translate_contract_definition(
             translated_definition.functions.remove(toplevel_function_index);
         }
     }
+
+    // Collect the invariants of the contract from the requirements shared by its functions
+    if matches!(translated_definition.kind.as_ref(), Some(solidity::ContractTy::Contract(_))) {
+        // Move the fallback function out of the abi, since Sway calls it when no abi function matches
+        translate_fallback_functions(project, &mut translated_definition);
+
+        extract_invariants(project, &mut translated_definition);
+
+        // Report the functions that write to storage after interacting with other contracts
+        report_reentrancy_patterns(project, &translated_definition);
+
+        // Log debug events on entry and exit of each contract function
+        if project.debug_events {
+            instrument_debug_events(project, &mut translated_definition)?;
+        }
+    }
+
+    // Infer the storage attributes of all functions from their translated bodies
+    infer_storage_attributes(project, &mut translated_definition);
+
+    // Report the identifiers that were renamed to avoid Sway keywords and name collisions
+    let renamed_identifiers = translated_definition.renamed_identifiers.iter()
+        .filter(|i| i.reason != RenameReason::NamingConvention)
+        .collect::<Vec<_>>();
+
+    if !renamed_identifiers.is_empty() {
+        emitln!(project.emitter, 
+            "NOTE: {} - `{}` has {} renamed identifier(s):",
+            translated_definition.path.to_string_lossy(),
+            translated_definition.name,
+            renamed_identifiers.len(),
+        );
+
+        for renamed_identifier in renamed_identifiers {
+            emitln!(project.emitter, "    `{}` => `{}`", renamed_identifier.old, renamed_identifier.new);
+        }
+    }
+
+    translated_definition.warnings = std::mem::replace(&mut project.warnings, outer_warnings);
+
+    let semantic_drift_count = translated_definition.warnings.iter().filter(|warning| warning.lint.is_semantic_drift()).count();
+
+    // Reject the definition in strict mode if its translation is not faithful to the original code
+    if project.strict {
+        let module: sway::Module = translated_definition.clone().into();
+        let stub_count = sway::TabbedDisplayer(&module).to_string().matches("todo!").count();
+
+        if semantic_drift_count > 0 || stub_count > 0 {
+            emitln!(project.emitter, 
+                "ERROR: {} - `{}` was rejected by strict mode, its translation has {semantic_drift_count} semantic difference(s) and {stub_count} stub(s)",
+                translated_definition.path.to_string_lossy(),
+                translated_definition.name,
+            );
+
+            project.rejected_definitions.insert((translated_definition.path.clone(), translated_definition.name.clone()));
+        }
+    }
     
     project.translated_definitions.push(translated_definition);
     
     Ok(())
 }
 
+/// Gets the constructor of the translated definition, creating it if it doesn't exist
+fn ensure_constructor_function<'a>(project: &mut Project, translated_definition: &'a mut TranslatedDefinition) -> &'a mut sway::Function {
+    let has_constructor = translated_definition.functions.iter().any(|f| f.name == "constructor")
+        || translated_definition.find_contract_impl().is_some_and(|i| i.items.iter().any(|item| matches!(item, sway::ImplItem::Function(f) if f.name == "constructor")));
+
+    // Create the constructor if it doesn't exist
+    if !has_constructor {
+        let mut function = sway::Function {
+            comment: None,
+            attributes: None,
+            is_public: false,
+            name: "constructor".into(),
+            generic_parameters: None,
+            parameters: sway::ParameterList::default(),
+            return_type: None,
+            body: None,
+        };
+
+        translated_definition.get_abi().functions.insert(0, function.clone());
+
+        function.body = Some(sway::Block::default());
+        let function_body = function.body.as_mut().unwrap();
+
+        let prefix = crate::translate_naming_convention(translated_definition.name.as_str(), Case::Snake);
+        let constructor_called_variable_name = translate_storage_name(project, translated_definition, format!("{prefix}_constructor_called").as_str());
+        
+        // Add the `constructor_called` field to the storage block
+        translated_definition.get_storage().fields.push(sway::StorageField {
+            comment: None,
+            name: constructor_called_variable_name.clone(),
+            type_name: sway::TypeName::Identifier {
+                name: "bool".into(),
+                generic_parameters: None,
+            },
+            value: sway::Expression::from(sway::Literal::Bool(false)),
+        });
+
+        // Add the `constructor_called` requirement to the beginning of the function
+        // require(!storage.initialized.read(), "The Contract constructor has already been called");
+        function_body.statements.insert(0, sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier("require".into()),
+            generic_parameters: None,
+            parameters: vec![
+                sway::Expression::from(sway::UnaryExpression {
+                    operator: "!".into(),
+                    expression: sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::from(sway::MemberAccess {
+                            expression: sway::Expression::from(sway::MemberAccess {
+                                expression: sway::Expression::Identifier("storage".into()),
+                                member: constructor_called_variable_name.clone(),
+                            }),
+                            member: "read".into(),
+                        }),
+                        generic_parameters: None,
+                        parameters: vec![],
+                    })
+                }),
+                sway::Expression::from(sway::Literal::String(format!("The {} constructor has already been called", translated_definition.name))),
+            ],
+        })));
+
+        // Set the `constructor_called` storage field to `true` at the end of the function
+        // storage.initialized.write(true);
+        function_body.statements.push(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::from(sway::MemberAccess {
+                expression: sway::Expression::from(sway::MemberAccess {
+                    expression: sway::Expression::Identifier("storage".into()),
+                    member: constructor_called_variable_name.clone(),
+                }),
+                member: "write".into(),
+            }),
+            generic_parameters: None,
+            parameters: vec![
+                sway::Expression::from(sway::Literal::Bool(true)),
+            ],
+        })));
+
+        translated_definition.get_contract_impl().items.insert(0, sway::ImplItem::Function(function));
+    }
+
+    if let Some(index) = translated_definition.functions.iter().position(|f| f.name == "constructor") {
+        return &mut translated_definition.functions[index];
+    }
+
+    translated_definition.get_contract_impl().items.iter_mut()
+        .find_map(|i| match i {
+            sway::ImplItem::Function(f) if f.name == "constructor" => Some(f),
+            _ => None,
+        })
+        .unwrap()
+}
+
+/// Inserts statements into the body of a constructor, following its initial requirements
+fn insert_constructor_statements(constructor_function: &mut sway::Function, statements: Vec<sway::Statement>) {
+    if constructor_function.body.is_none() {
+        constructor_function.body = Some(sway::Block::default());
+    }
+
+    let constructor_body = constructor_function.body.as_mut().unwrap();
+
+    let mut statement_index = 0;
+
+    // Skip past the initial constructor requirements
+    for (i, statement) in constructor_body.statements.iter().enumerate() {
+        let sway::Statement::Expression(sway::Expression::FunctionCall(function_call)) = statement else {
+            statement_index = i;
+            break;
+        };
+
+        let sway::Expression::Identifier(function_name) = &function_call.function else {
+            statement_index = i;
+            break;
+        };
+
+        if function_name != "require" {
+            statement_index = i;
+            break;
+        }
+    }
+
+    for statement in statements.into_iter().rev() {
+        constructor_body.statements.insert(statement_index, statement);
+    }
+}
+
+/// Translates the calls to the constructors of the base contracts of a contract, inserting them into its constructor in the order of its linearization.
+///
+/// The arguments of a base constructor are supplied by the most derived contract invoking it, either in its inheritance list (i.e: `is Named("Token")`)
+/// or in its constructor (i.e: `constructor(string memory name_) Named(name_)`). When the constructor of a base contract supplies arguments to its own
+/// base contracts, its arguments are bound to variables first so they can be used by both calls.
+fn translate_base_constructor_calls(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    contract_definition: &solidity::ContractDefinition,
+) -> Result<(), Error> {
+    let linearization = translated_definition.linearization.clone();
+
+    // Get handles to the parsed source units, so their definitions can be borrowed while the project is modified
+    let source_units = project.solidity_source_units.ids()
+        .map(|id| project.solidity_source_units.get_shared(id))
+        .collect::<Vec<_>>();
+
+    // Collect the Solidity definitions of the contract and its base contracts, from the most derived to the most base
+    let mut contract_definitions = vec![(translated_definition.name.clone(), contract_definition)];
+
+    for contract_name in linearization.iter().skip(1) {
+        if let Some(contract_definition) = find_solidity_contract_definition(source_units.as_slice(), contract_name) {
+            contract_definitions.push((contract_name.clone(), contract_definition));
+        }
+    }
+
+    // Find the arguments supplied to the constructor of each base contract by the most derived contract invoking it
+    let mut invocations = vec![];
+
+    for base_name in linearization.iter().skip(1) {
+        let function_name = format!("{}_constructor", crate::translate_naming_convention(base_name, Case::Snake));
+        let Some(function) = translated_definition.functions.iter().find(|f| f.name == function_name) else { continue };
+
+        let arguments = contract_definitions.iter()
+            .take_while(|(contract_name, _)| contract_name != base_name)
+            .find_map(|(contract_name, contract_definition)| {
+                find_base_constructor_arguments(contract_definition, base_name)
+                    .map(|(is_constructor_invocation, arguments)| (contract_name.clone(), is_constructor_invocation, arguments))
+            });
+
+        invocations.push((base_name.clone(), function_name, function.parameters.clone(), arguments));
+    }
+
+    if invocations.is_empty() {
+        return Ok(());
+    }
+
+    // The arguments of the base constructors that supply arguments to other base constructors are bound to variables
+    let bound_contract_names = invocations.iter()
+        .filter_map(|(_, _, _, arguments)| arguments.as_ref())
+        .filter(|(contract_name, is_constructor_invocation, _)| *is_constructor_invocation && *contract_name != translated_definition.name)
+        .map(|(contract_name, _, _)| contract_name.clone())
+        .collect::<Vec<_>>();
+
+    let mut let_statements = vec![];
+    let mut call_statements = vec![];
+
+    for (base_name, function_name, parameters, arguments) in invocations {
+        let mut call_parameters = vec![];
+
+        if !parameters.entries.is_empty() {
+            // A base constructor without supplied arguments can only be called by a contract deriving from this one
+            let Some((contract_name, is_constructor_invocation, arguments)) = arguments else { continue };
+
+            if arguments.len() != parameters.entries.len() {
+                continue;
+            }
+
+            let scope = create_base_constructor_arguments_scope(translated_definition, &contract_definitions, &contract_name, is_constructor_invocation);
+
+            for argument in arguments.iter() {
+                call_parameters.push(translate_expression(project, translated_definition, scope.clone(), argument)?);
+            }
+
+            if bound_contract_names.contains(&base_name) {
+                let prefix = crate::translate_naming_convention(&base_name, Case::Snake);
+
+                for (parameter, value) in parameters.entries.iter().zip(call_parameters.iter_mut()) {
+                    let name = format!("{prefix}_{}", parameter.name);
+
+                    let_statements.push(sway::Statement::from(sway::Let {
+                        pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+                            is_mutable: false,
+                            name: name.clone(),
+                        }),
+                        type_name: None,
+                        value: std::mem::replace(value, sway::Expression::Identifier(name)),
+                    }));
+                }
+            }
+        }
+
+        *translated_definition.function_call_counts.entry(function_name.clone()).or_insert(0) += 1;
+
+        // Base constructors are called from the most base contract to the most derived contract
+        call_statements.insert(0, sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier(function_name),
+            generic_parameters: None,
+            parameters: call_parameters,
+        })));
+    }
+
+    if call_statements.is_empty() {
+        return Ok(());
+    }
+
+    let statements = let_statements.into_iter().chain(call_statements).collect::<Vec<_>>();
+
+    // Keep track of the calls so they can be removed from the constructor when it is inherited
+    translated_definition.base_constructor_calls = statements.clone();
+
+    let constructor_function = ensure_constructor_function(project, translated_definition);
+    insert_constructor_statements(constructor_function, statements);
+
+    Ok(())
+}
+
+/// Creates the scope that the arguments supplied to a base constructor are translated in.
+///
+/// Arguments supplied by a constructor can refer to its parameters, which are the parameters of the translated constructor
+/// when supplied by the contract itself, or the variables bound by `translate_base_constructor_calls` when supplied by a base contract.
+fn create_base_constructor_arguments_scope(
+    translated_definition: &TranslatedDefinition,
+    contract_definitions: &[(String, &solidity::ContractDefinition)],
+    contract_name: &str,
+    is_constructor_invocation: bool,
+) -> Rc<RefCell<TranslationScope>> {
+    let scope = Rc::new(RefCell::new(TranslationScope {
+        parent: Some(translated_definition.toplevel_scope.clone()),
+        ..Default::default()
+    }));
+
+    if !is_constructor_invocation {
+        return scope;
+    }
+
+    let (function_name, prefix) = if contract_name == translated_definition.name {
+        ("constructor".to_string(), None)
+    } else {
+        let prefix = crate::translate_naming_convention(contract_name, Case::Snake);
+        (format!("{prefix}_constructor"), Some(prefix))
+    };
+
+    let Some(function) = translated_definition.functions.iter().find(|f| f.name == function_name) else { return scope };
+    let Some((_, contract_definition)) = contract_definitions.iter().find(|(name, _)| name == contract_name) else { return scope };
+    let Some(constructor_definition) = find_constructor_definition(contract_definition) else { return scope };
+
+    for ((_, parameter), translated_parameter) in constructor_definition.params.iter().zip(function.parameters.entries.iter()) {
+        let Some(old_name) = parameter.as_ref().and_then(|p| p.name.as_ref()).map(|n| n.name.clone()) else { continue };
+
+        scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+            old_name,
+            new_name: match prefix.as_ref() {
+                Some(prefix) => format!("{prefix}_{}", translated_parameter.name),
+                None => translated_parameter.name.clone(),
+            },
+            type_name: translated_parameter.type_name.clone().unwrap_or_default(),
+            ..Default::default()
+        })));
+    }
+
+    scope
+}
+
+/// Finds the arguments a contract supplies to the constructor of one of its base contracts, along with whether they are supplied by its constructor
+/// (i.e: `constructor() Named("Token")`) instead of its inheritance list (i.e: `is Named("Token")`)
+fn find_base_constructor_arguments(contract_definition: &solidity::ContractDefinition, base_name: &str) -> Option<(bool, Vec<solidity::Expression>)> {
+    let get_base_name = |base: &solidity::Base| base.name.identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".");
+
+    if let Some(constructor_definition) = find_constructor_definition(contract_definition) {
+        for attribute in constructor_definition.attributes.iter() {
+            let solidity::FunctionAttribute::BaseOrModifier(_, base) = attribute else { continue };
+
+            if get_base_name(base) == base_name {
+                if let Some(arguments) = base.args.as_ref() {
+                    return Some((true, arguments.clone()));
+                }
+            }
+        }
+    }
+
+    contract_definition.base.iter()
+        .find(|base| get_base_name(base) == base_name)
+        .and_then(|base| base.args.clone())
+        .map(|arguments| (false, arguments))
+}
+
+/// Finds the constructor of a Solidity contract definition, if it has one
+fn find_constructor_definition(contract_definition: &solidity::ContractDefinition) -> Option<&solidity::FunctionDefinition> {
+    contract_definition.parts.iter().find_map(|part| match part {
+        solidity::ContractPart::FunctionDefinition(function_definition) if matches!(function_definition.ty, solidity::FunctionTy::Constructor) => Some(function_definition.as_ref()),
+        _ => None,
+    })
+}
+
+/// Finds the Solidity definition of a contract by name in the source units of the project
+fn find_solidity_contract_definition<'a>(source_units: &'a [Rc<solidity::SourceUnit>], contract_name: &str) -> Option<&'a solidity::ContractDefinition> {
+    source_units.iter()
+        .flat_map(|source_unit| source_unit.0.iter())
+        .find_map(|source_unit_part| match source_unit_part {
+            solidity::SourceUnitPart::ContractDefinition(contract_definition) if contract_definition.name.as_ref().is_some_and(|n| n.name == contract_name) => Some(contract_definition.as_ref()),
+            _ => None,
+        })
+}
+
 #[inline]
 pub fn propagate_inherited_definitions(
     project: &mut Project,
@@ -527,6 +1034,7 @@ pub fn propagate_inherited_definitions(
     translated_definition: &mut TranslatedDefinition,
 ) -> Result<(), Error> {
     let source_unit_directory = translated_definition.path.parent().map(PathBuf::from).unwrap();
+    let mut inherited_definitions = vec![];
 
     for inherit in inherits.iter() {
         let mut inherited_definition = None;
@@ -534,8 +1042,10 @@ pub fn propagate_inherited_definitions(
         // Find inherited import directive
         for import_directive in import_directives.iter() {
             let filename = match import_directive {
-                solidity::Import::Plain(solidity::ImportPath::Filename(filename), _) => filename,
-                
+                solidity::Import::Plain(solidity::ImportPath::Filename(filename), _)
+                | solidity::Import::GlobalSymbol(solidity::ImportPath::Filename(filename), _, _) => filename,
+
+
                 solidity::Import::Rename(solidity::ImportPath::Filename(filename), identifiers, _) => {
                     if !identifiers.iter().any(|i| i.0.name == *inherit) {
                         continue;
@@ -553,13 +1063,13 @@ pub fn propagate_inherited_definitions(
 
             let import_path = source_unit_directory.join(filename.string.clone());
 
-            if !import_path.exists() {
+            if !project.source_unit_exists(&import_path) {
                 return Err(
                     Error::Wrapped(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, format!("File not found: {}", import_path.to_string_lossy()))))
                 );
             }
 
-            let import_path = crate::get_canonical_path(import_path, false, false)
+            let import_path = project.get_source_unit_path(import_path)
                 .map_err(|e| Error::Wrapped(Box::new(e)))?;
 
             if let Some(t) = resolve_import(project, inherit, &import_path)? {
@@ -579,9 +1089,32 @@ pub fn propagate_inherited_definitions(
             panic!("Failed to find inherited definition \"{inherit}\" for \"{}\"", translated_definition.name);
         };
 
-        // Extend the toplevel scope
-        translated_definition.toplevel_scope.borrow_mut().variables.extend(inherited_definition.toplevel_scope.borrow().variables.clone());
-        translated_definition.toplevel_scope.borrow_mut().functions.extend(inherited_definition.toplevel_scope.borrow().functions.clone());
+        inherited_definitions.push(inherited_definition);
+    }
+
+    translated_definition.linearization = linearize_inheritance(translated_definition.name.as_str(), inherited_definitions.as_slice())?;
+
+    for inherited_definition in inherited_definitions.iter() {
+        // Keep track of the origins of the functions before they are extended, so overridden implementations can be resolved
+        let function_origins = translated_definition.function_origins.clone();
+
+        // Extend the toplevel scope, skipping entries that were already inherited through another base contract
+        for inherited_variable in inherited_definition.toplevel_scope.borrow().variables.iter() {
+            if !translated_definition.toplevel_scope.borrow().variables.iter().any(|v| Rc::ptr_eq(v, inherited_variable)) {
+                translated_definition.toplevel_scope.borrow_mut().variables.push(inherited_variable.clone());
+            }
+        }
+
+        for inherited_function in inherited_definition.toplevel_scope.borrow().functions.iter() {
+            if !translated_definition.toplevel_scope.borrow().functions.iter().any(|f| Rc::ptr_eq(f, inherited_function)) {
+                translated_definition.toplevel_scope.borrow_mut().functions.push(inherited_function.clone());
+            }
+        }
+
+        // Extend the attributions
+        for inherited_attribution in inherited_definition.attributions.iter() {
+            translated_definition.ensure_attribution_declared(inherited_attribution.clone());
+        }
 
         // Extend the use statements
         for inherited_use in inherited_definition.uses.iter() {
@@ -590,6 +1123,20 @@ pub fn propagate_inherited_definitions(
             }
         }
 
+        // Extend the using directives
+        for inherited_using_directive in inherited_definition.using_directives.iter() {
+            if !translated_definition.using_directives.contains(inherited_using_directive) {
+                translated_definition.using_directives.push(inherited_using_directive.clone());
+            }
+        }
+
+        // Extend the constants
+        for inherited_constant in inherited_definition.constants.iter() {
+            if !translated_definition.constants.contains(inherited_constant) {
+                translated_definition.constants.push(inherited_constant.clone());
+            }
+        }
+
         // Extend the type definitions
         for inherited_type_definition in inherited_definition.type_definitions.iter() {
             if !translated_definition.type_definitions.contains(inherited_type_definition) {
@@ -597,6 +1144,20 @@ pub fn propagate_inherited_definitions(
             }
         }
 
+        // Extend the user-defined value types that were translated to structs
+        for inherited_value_type_struct in inherited_definition.value_type_structs.iter() {
+            if !translated_definition.value_type_structs.contains(inherited_value_type_struct) {
+                translated_definition.value_type_structs.push(inherited_value_type_struct.clone());
+            }
+        }
+
+        // Extend the enums
+        for inherited_enum in inherited_definition.enums.iter() {
+            if !translated_definition.enums.contains(inherited_enum) {
+                translated_definition.enums.push(inherited_enum.clone());
+            }
+        }
+
         // Extend the structs
         for inherited_struct in inherited_definition.structs.iter() {
             if !translated_definition.structs.contains(inherited_struct) {
@@ -625,6 +1186,13 @@ pub fn propagate_inherited_definitions(
             }
         }
 
+        // Extend the referenced enum names
+        for inherited_enum_name in inherited_definition.referenced_enum_names.iter() {
+            if !translated_definition.referenced_enum_names.contains(inherited_enum_name) {
+                translated_definition.referenced_enum_names.push(inherited_enum_name.clone());
+            }
+        }
+
         // Extend the abi
         if let Some(inherited_abi) = inherited_definition.abi.as_ref() {
             for inherited_function in inherited_abi.functions.iter() {
@@ -634,7 +1202,7 @@ pub fn propagate_inherited_definitions(
 
                 let abi = translated_definition.get_abi();
 
-                if !abi.functions.contains(inherited_function) {
+                if !abi.functions.iter().any(|f| f.is_equivalent(inherited_function)) {
                     abi.functions.push(inherited_function.clone());
                 }
             }
@@ -681,43 +1249,261 @@ pub fn propagate_inherited_definitions(
             *translated_definition.function_name_counts.entry(function_name.clone()).or_insert(0) += *count;
         }
 
-        // Extend the functions
+        // Extend function mutability mapping
+        for (function_name, mutability) in inherited_definition.function_mutabilities.iter() {
+            if !translated_definition.function_mutabilities.contains_key(function_name) {
+                translated_definition.function_mutabilities.insert(function_name.clone(), mutability.clone());
+            }
+        }
+
+        // Extend the functions, keeping the implementation of the most derived contract when a function is overridden
         for inherited_function in inherited_definition.functions.iter() {
-            if !translated_definition.functions.contains(inherited_function) {
+            let existing_index = translated_definition.functions.iter().position(|f| {
+                f.name == inherited_function.name && f.parameters == inherited_function.parameters
+            });
+
+            let Some(existing_index) = existing_index else {
                 translated_definition.functions.push(inherited_function.clone());
 
                 if let Some(function_call_count) = inherited_definition.function_call_counts.get(&inherited_function.name) {
                     *translated_definition.function_call_counts.entry(inherited_function.name.clone()).or_insert(0) += *function_call_count;
                 }
+
+                continue;
+            };
+
+            if translated_definition.is_more_derived_origin(
+                inherited_definition.function_origins.get(&inherited_function.name),
+                function_origins.get(&inherited_function.name),
+            ) {
+                translated_definition.functions[existing_index] = inherited_function.clone();
             }
         }
 
-        // Extend the contract impl block
+        // Extend the impls of other types (i.e: user-defined operators)
+        for inherited_impl in inherited_definition.impls.iter() {
+            if inherited_definition.find_contract_impl() == Some(inherited_impl) {
+                continue;
+            }
+
+            if !translated_definition.impls.contains(inherited_impl) {
+                translated_definition.impls.push(inherited_impl.clone());
+            }
+        }
+
+        // Extend the contract impl block, keeping the implementation of the most derived contract when a function is overridden
         if let Some(inherited_impl) = inherited_definition.find_contract_impl() {
             for inherited_impl_item in inherited_impl.items.iter() {
                 if let sway::ImplItem::Function(inherited_function) = inherited_impl_item {
                     if inherited_function.name == "constructor" {
                         let mut inherited_function = inherited_function.clone();
+
+                        // The base constructors called by the inherited constructor are called by the constructor of the current definition instead
+                        if let Some(body) = inherited_function.body.as_mut() {
+                            body.statements.retain(|s| !inherited_definition.base_constructor_calls.contains(s));
+                        }
                         
                         let prefix = crate::translate_naming_convention(inherited_definition.name.as_str(), Case::Snake);
                         inherited_function.name = format!("{prefix}_constructor");
 
-                        if !translated_definition.functions.contains(&inherited_function) {
+                        // Abstract contracts already provide their constructor as a toplevel function, which the contract impl's function wraps
+                        if !translated_definition.functions.iter().any(|f| f.name == inherited_function.name) {
                             translated_definition.functions.push(inherited_function);
                         }
 
                         continue;
-                    }    
+                    }
+
+                    let is_more_derived = translated_definition.is_more_derived_origin(
+                        inherited_definition.function_origins.get(&inherited_function.name),
+                        function_origins.get(&inherited_function.name),
+                    );
+
+                    let contract_impl = translated_definition.get_contract_impl();
+
+                    let existing_function = contract_impl.items.iter_mut().find_map(|item| match item {
+                        sway::ImplItem::Function(f) if f.name == inherited_function.name && f.parameters == inherited_function.parameters => Some(f),
+                        _ => None,
+                    });
+
+                    match existing_function {
+                        Some(existing_function) => {
+                            if is_more_derived {
+                                *existing_function = inherited_function.clone();
+                            }
+                        }
+
+                        None => contract_impl.items.push(inherited_impl_item.clone()),
+                    }
+
+                    continue;
                 }
 
                 let contract_impl = translated_definition.get_contract_impl();
 
-                if !contract_impl.items.contains(&inherited_impl_item) {
+                if !contract_impl.items.contains(inherited_impl_item) {
                     contract_impl.items.push(inherited_impl_item.clone());
                 }
             }
         }
+
+        // Extend the function origins, keeping the origin of the most derived implementation
+        for (function_name, origin) in inherited_definition.function_origins.iter() {
+            if translated_definition.is_more_derived_origin(Some(origin), function_origins.get(function_name)) || !function_origins.contains_key(function_name) {
+                translated_definition.function_origins.insert(function_name.clone(), origin.clone());
+            }
+        }
+
+        // Extend the item origins, skipping items that were already inherited through another base contract
+        for inherited_item_origin in inherited_definition.item_origins.iter() {
+            if translated_definition.get_item_origin(inherited_item_origin.kind, &inherited_item_origin.name).is_none() {
+                translated_definition.item_origins.push(inherited_item_origin.clone());
+            }
+        }
     }
 
     Ok(())
 }
+
+/// Computes the C3 linearization of a contract from its base contracts, which are supplied in the order they were declared.
+///
+/// Solidity considers the last declared base contract to be the most derived, so the base contracts are merged from right to left.
+fn linearize_inheritance(name: &str, inherited_definitions: &[TranslatedDefinition]) -> Result<Vec<String>, Error> {
+    let mut sequences: Vec<Vec<String>> = inherited_definitions.iter().rev()
+        .map(|d| if d.linearization.is_empty() { vec![d.name.clone()] } else { d.linearization.clone() })
+        .collect();
+
+    sequences.push(inherited_definitions.iter().rev().map(|d| d.name.clone()).collect());
+
+    let mut result = vec![name.to_string()];
+
+    loop {
+        sequences.retain(|s| !s.is_empty());
+
+        if sequences.is_empty() {
+            break;
+        }
+
+        // Take the first head which does not appear in the tail of any sequence
+        let Some(head) = sequences.iter()
+            .map(|s| &s[0])
+            .find(|head| !sequences.iter().any(|s| s[1..].contains(head)))
+            .cloned()
+        else {
+            return Err(Error::Wrapped(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Linearization of inheritance graph impossible for \"{name}\""),
+            ))));
+        };
+
+        for sequence in sequences.iter_mut() {
+            if sequence[0] == head {
+                sequence.remove(0);
+            }
+        }
+
+        result.push(head);
+    }
+
+    Ok(result)
+}
+
+/// Resolves the implementation of `function_name` that a `super` call refers to, searching the translated definition's linearization
+/// starting at `contract_index`. The implementation is added as a toplevel function named after the contract defining it (i.e: `erc20_transfer`).
+///
+/// Any `super` calls made by the implementation are resolved again against the translated definition's linearization,
+/// so chained `super` calls walk the linearization of the most derived contract instead of the linearization of each base contract.
+pub fn resolve_super_function(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    contract_index: usize,
+    function_name: &str,
+    parameters: &sway::ParameterList,
+) -> Option<String> {
+    let parameter_types = |parameters: &sway::ParameterList| {
+        parameters.entries.iter().map(|p| p.type_name.clone()).collect::<Vec<_>>()
+    };
+
+    let linearization = translated_definition.linearization.clone();
+
+    for (index, contract_name) in linearization.iter().enumerate().skip(contract_index) {
+        let Some(contract_definition) = project.translated_definitions.iter().find(|d| d.name == *contract_name) else { continue };
+
+        // Only consider contracts that provide their own implementation of the function
+        if contract_definition.function_origins.get(function_name) != Some(contract_name) {
+            continue;
+        }
+
+        let super_function_name = format!("{}_{function_name}", crate::translate_naming_convention(contract_name, Case::Snake));
+
+        let is_implementation = |f: &sway::Function| {
+            (f.name == function_name || f.name == super_function_name) && parameter_types(&f.parameters) == parameter_types(parameters)
+        };
+
+        // The implementation is either a toplevel function, or the body of the contract impl's function if it was never called
+        let implementation = contract_definition.functions.iter().find(|f| is_implementation(f)).or_else(|| {
+            contract_definition.find_contract_impl()?.items.iter().find_map(|item| match item {
+                sway::ImplItem::Function(f) if is_implementation(f) => Some(f),
+                _ => None,
+            })
+        });
+
+        let Some(mut implementation) = implementation.cloned() else { continue };
+
+        implementation.name = super_function_name.clone();
+
+        // Resolve the super calls of the implementation using the linearization of the translated definition,
+        // leaving explicit calls to the functions of base contracts (i.e: `A.foo()`) untouched
+        let super_function_names = contract_definition.super_function_calls.iter()
+            .filter(|n| linearization.iter().any(|c| **n == format!("{}_{function_name}", crate::translate_naming_convention(c, Case::Snake))))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut has_super_calls = false;
+
+        if let Some(body) = implementation.body.as_ref() {
+            body.visit_expressions(&mut |expression| {
+                let sway::Expression::FunctionCall(function_call) = expression else { return };
+                let sway::Expression::Identifier(name) = &function_call.function else { return };
+                has_super_calls |= super_function_names.contains(name);
+            });
+        }
+
+        if has_super_calls {
+            if let Some(next_function_name) = resolve_super_function(project, translated_definition, index + 1, function_name, parameters) {
+                implementation.body.as_mut().unwrap().visit_expressions_mut(&mut |expression| {
+                    let sway::Expression::FunctionCall(function_call) = expression else { return };
+                    let sway::Expression::Identifier(name) = &mut function_call.function else { return };
+
+                    if super_function_names.contains(name) {
+                        *name = next_function_name.clone();
+                    }
+                });
+            }
+        }
+
+        // Add or replace the toplevel function for the implementation
+        if let Some(function) = translated_definition.functions.iter_mut().find(|f| f.name == super_function_name && f.parameters == implementation.parameters) {
+            *function = implementation;
+        } else {
+            translated_definition.functions.push(implementation);
+        }
+
+        *translated_definition.function_call_counts.entry(super_function_name.clone()).or_insert(0) += 1;
+
+        return Some(super_function_name);
+    }
+
+    None
+}
+
+/// Gets the name of a function without the prefix given to the functions of abstract contracts and libraries.
+pub fn get_unprefixed_function_name<'a>(translated_definition: &TranslatedDefinition, function_name: &'a str) -> &'a str {
+    let Some(solidity::ContractTy::Abstract(_) | solidity::ContractTy::Library(_)) = &translated_definition.kind else {
+        return function_name;
+    };
+
+    let prefix = format!("{}_", crate::translate_naming_convention(&translated_definition.name, Case::Snake));
+
+    function_name.strip_prefix(prefix.as_str()).unwrap_or(function_name)
+}
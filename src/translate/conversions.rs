@@ -0,0 +1,200 @@
+use super::{TranslatedDefinition, TranslatedFunction};
+use crate::sway;
+use num_bigint::BigUint;
+use std::{cell::RefCell, rc::Rc};
+
+/// Creates a call to the `b256_to_bytesN` conversion helper, which keeps the first `N` bytes of a `b256`, i.e: `bytes4(x)` => `b256_to_bytes4(x)`
+pub fn create_b256_to_fixed_bytes_call(translated_definition: &mut TranslatedDefinition, value: sway::Expression, byte_count: usize) -> sway::Expression {
+    let function_name = format!("b256_to_bytes{byte_count}");
+    ensure_conversion_helper_declared(translated_definition, &function_name, byte_count);
+    create_conversion_helper_call(translated_definition, &function_name, value)
+}
+
+/// Creates a call to the `bytesN_to_b256` conversion helper, which pads a `[u8; N]` with zeros on the right, i.e: `bytes32(x)` => `bytes4_to_b256(x)`
+pub fn create_fixed_bytes_to_b256_call(translated_definition: &mut TranslatedDefinition, value: sway::Expression, byte_count: usize) -> sway::Expression {
+    let function_name = format!("bytes{byte_count}_to_b256");
+    ensure_conversion_helper_declared(translated_definition, &function_name, byte_count);
+    create_conversion_helper_call(translated_definition, &function_name, value)
+}
+
+fn create_conversion_helper_call(translated_definition: &mut TranslatedDefinition, function_name: &str, argument: sway::Expression) -> sway::Expression {
+    *translated_definition.function_call_counts.entry(function_name.into()).or_insert(0) += 1;
+
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier(function_name.into()),
+        generic_parameters: None,
+        parameters: vec![argument],
+    })
+}
+
+/// Declares a fixed-size bytes conversion helper function in the translated definition if it hasn't been declared yet:
+///
+/// ```ignore
+/// fn b256_to_bytes4(value: b256) -> [u8; 4] {
+///     let bytes = Bytes::from(value);
+///     [bytes.get(0).unwrap(), bytes.get(1).unwrap(), bytes.get(2).unwrap(), bytes.get(3).unwrap()]
+/// }
+///
+/// fn bytes4_to_b256(value: [u8; 4]) -> b256 {
+///     let mut bytes = Bytes::with_capacity(32);
+///     let mut i = 0;
+///     while i < 4 {
+///         bytes.push(value[i]);
+///         i += 1;
+///     }
+///     while i < 32 {
+///         bytes.push(0);
+///         i += 1;
+///     }
+///     b256::from(bytes)
+/// }
+/// ```
+fn ensure_conversion_helper_declared(translated_definition: &mut TranslatedDefinition, function_name: &str, byte_count: usize) {
+    if translated_definition.functions.iter().any(|f| f.name == function_name) {
+        return;
+    }
+
+    // Ensure `std::bytes::Bytes` is imported
+    translated_definition.ensure_use_declared("std::bytes::Bytes");
+
+    let b256_type_name = sway::TypeName::Identifier {
+        name: "b256".into(),
+        generic_parameters: None,
+    };
+
+    let bytes_type_name = sway::TypeName::Array {
+        type_name: Box::new(sway::TypeName::Identifier {
+            name: "u8".into(),
+            generic_parameters: None,
+        }),
+        length: byte_count,
+    };
+
+    let is_b256_to_bytes = function_name.starts_with("b256_to_");
+
+    let (parameter_type_name, return_type) = if is_b256_to_bytes {
+        (b256_type_name, bytes_type_name)
+    } else {
+        (bytes_type_name, b256_type_name)
+    };
+
+    let bytes = sway::Expression::Identifier("bytes".into());
+    let i = sway::Expression::Identifier("i".into());
+
+    let create_let = |is_mutable: bool, name: &str, value: sway::Expression| sway::Statement::from(sway::Let {
+        pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+            is_mutable,
+            name: name.into(),
+        }),
+        type_name: None,
+        value,
+    });
+
+    let create_push_loop = |length: usize, value: sway::Expression| sway::Statement::from(sway::Expression::from(sway::While {
+        condition: sway::Expression::from(sway::BinaryExpression {
+            operator: "<".into(),
+            lhs: i.clone(),
+            rhs: sway::Expression::from(sway::Literal::DecInt(BigUint::from(length))),
+        }),
+        body: sway::Block {
+            statements: vec![
+                sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::from(sway::MemberAccess {
+                        expression: bytes.clone(),
+                        member: "push".into(),
+                    }),
+                    generic_parameters: None,
+                    parameters: vec![value],
+                })),
+                sway::Statement::from(sway::Expression::from(sway::BinaryExpression {
+                    operator: "+=".into(),
+                    lhs: i.clone(),
+                    rhs: sway::Expression::from(sway::Literal::DecInt(BigUint::from(1u8))),
+                })),
+            ],
+            final_expr: None,
+        },
+    }));
+
+    let body = if is_b256_to_bytes {
+        sway::Block {
+            statements: vec![
+                create_let(false, "bytes", sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Bytes::from".into()),
+                    generic_parameters: None,
+                    parameters: vec![sway::Expression::Identifier("value".into())],
+                })),
+            ],
+            final_expr: Some(sway::Expression::from(sway::Array {
+                elements: (0..byte_count)
+                    .map(|index| sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::from(sway::MemberAccess {
+                            expression: sway::Expression::from(sway::FunctionCall {
+                                function: sway::Expression::from(sway::MemberAccess {
+                                    expression: bytes.clone(),
+                                    member: "get".into(),
+                                }),
+                                generic_parameters: None,
+                                parameters: vec![sway::Expression::from(sway::Literal::DecInt(BigUint::from(index)))],
+                            }),
+                            member: "unwrap".into(),
+                        }),
+                        generic_parameters: None,
+                        parameters: vec![],
+                    }))
+                    .collect(),
+            })),
+        }
+    } else {
+        sway::Block {
+            statements: vec![
+                create_let(true, "bytes", sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Bytes::with_capacity".into()),
+                    generic_parameters: None,
+                    parameters: vec![sway::Expression::from(sway::Literal::DecInt(BigUint::from(32u8)))],
+                })),
+                create_let(true, "i", sway::Expression::from(sway::Literal::DecInt(BigUint::from(0u8)))),
+                create_push_loop(byte_count, sway::Expression::from(sway::ArrayAccess {
+                    expression: sway::Expression::Identifier("value".into()),
+                    index: i.clone(),
+                })),
+                create_push_loop(32, sway::Expression::from(sway::Literal::DecInt(BigUint::from(0u8)))),
+            ],
+            final_expr: Some(sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier("b256::from".into()),
+                generic_parameters: None,
+                parameters: vec![bytes.clone()],
+            })),
+        }
+    };
+
+    let parameters = sway::ParameterList {
+        entries: vec![
+            sway::Parameter {
+                is_ref: false,
+                is_mut: false,
+                name: "value".into(),
+                type_name: Some(parameter_type_name),
+            },
+        ],
+    };
+
+    translated_definition.functions.push(sway::Function {
+        comment: None,
+        attributes: None,
+        is_public: false,
+        name: function_name.into(),
+        generic_parameters: None,
+        parameters: parameters.clone(),
+        return_type: Some(return_type.clone()),
+        body: Some(body),
+    });
+
+    translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(TranslatedFunction {
+        old_name: String::new(),
+        new_name: function_name.into(),
+        parameters,
+        modifiers: vec![],
+        return_type: Some(return_type),
+    })));
+}
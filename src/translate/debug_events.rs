@@ -0,0 +1,210 @@
+use super::{evaluate_before_statements, generate_enum_abi_encode_function, TranslatedDefinition};
+use crate::{project::Project, sway, Error};
+
+/// Instruments every function of the contract impl with events logged on entry and exit, carrying the name of the function and its caller.
+///
+/// The events are only meant to be used while validating the behavior of a translated contract against the original contract,
+/// so they are generated into a separate `{Contract}DebugEvent` enum which is left out when translating without `--debug-events`.
+pub fn instrument_debug_events(project: &mut Project, translated_definition: &mut TranslatedDefinition) -> Result<(), Error> {
+    let debug_events_enum_name = format!("{}DebugEvent", translated_definition.name);
+
+    let Some(contract_impl) = translated_definition.find_contract_impl_mut() else { return Ok(()) };
+
+    let mut instrumented_count = 0;
+
+    for item in contract_impl.items.iter_mut() {
+        let sway::ImplItem::Function(function) = item else { continue };
+        let Some(body) = function.body.as_mut() else { continue };
+
+        let exit_statement = create_debug_event_statement(&debug_events_enum_name, "FunctionExited", &function.name);
+        let result_name = generate_unique_result_name(&function.parameters, body);
+
+        instrument_returns(body, function.return_type.as_ref(), &exit_statement, &result_name);
+
+        match body.final_expr.take() {
+            Some(final_expr) if function.return_type.is_some() => {
+                let (statements, result) = evaluate_before_statements(&result_name, function.return_type.clone(), final_expr, [exit_statement]);
+
+                body.statements.extend(statements);
+                body.final_expr = Some(result);
+            }
+
+            Some(final_expr) => {
+                body.statements.push(sway::Statement::from(final_expr));
+                body.statements.push(exit_statement);
+            }
+
+            None => {
+                // Don't log after a trailing return statement, since it has already been instrumented
+                if !matches!(body.statements.last(), Some(sway::Statement::Expression(sway::Expression::Return(_)))) {
+                    body.statements.push(exit_statement);
+                }
+            }
+        }
+
+        body.statements.insert(0, create_debug_event_statement(&debug_events_enum_name, "FunctionEntered", &function.name));
+
+        instrumented_count += 1;
+    }
+
+    if instrumented_count == 0 {
+        return Ok(());
+    }
+
+    // enum ContractDebugEvent {
+    //     FunctionEntered: (str, Identity),
+    //     FunctionExited: (str, Identity),
+    // }
+    let debug_event_type_name = sway::TypeName::Tuple {
+        type_names: vec![
+            sway::TypeName::StringSlice,
+            sway::TypeName::Identifier {
+                name: "Identity".into(),
+                generic_parameters: None,
+            },
+        ],
+    };
+
+    let debug_events_enum = sway::Enum {
+        name: debug_events_enum_name.clone(),
+        variants: vec![
+            sway::EnumVariant {
+                name: "FunctionEntered".into(),
+                type_name: debug_event_type_name.clone(),
+            },
+            sway::EnumVariant {
+                name: "FunctionExited".into(),
+                type_name: debug_event_type_name,
+            },
+        ],
+        ..Default::default()
+    };
+
+    let mut abi_encode_impl = sway::Impl {
+        type_name: sway::TypeName::Identifier {
+            name: "AbiEncode".into(),
+            generic_parameters: None,
+        },
+        for_type_name: Some(sway::TypeName::Identifier {
+            name: debug_events_enum_name.clone(),
+            generic_parameters: None,
+        }),
+        ..Default::default()
+    };
+
+    generate_enum_abi_encode_function(project, &debug_events_enum, &mut abi_encode_impl)?;
+
+    translated_definition.ensure_use_declared("core::codec::AbiEncode");
+    translated_definition.ensure_use_declared("std::auth::msg_sender");
+    translated_definition.events_enums.push((debug_events_enum, abi_encode_impl));
+
+    if !translated_definition.referenced_enum_names.contains(&debug_events_enum_name) {
+        translated_definition.referenced_enum_names.push(debug_events_enum_name);
+    }
+
+    Ok(())
+}
+
+/// Creates `log(ContractDebugEvent::Variant(("function_name", msg_sender().unwrap())));`.
+fn create_debug_event_statement(debug_events_enum_name: &str, variant_name: &str, function_name: &str) -> sway::Statement {
+    sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("log".into()),
+        generic_parameters: None,
+        parameters: vec![
+            sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier(format!("{debug_events_enum_name}::{variant_name}")),
+                generic_parameters: None,
+                parameters: vec![
+                    sway::Expression::Tuple(vec![
+                        sway::Expression::from(sway::Literal::String(function_name.into())),
+                        // msg_sender().unwrap()
+                        sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::from(sway::MemberAccess {
+                                expression: sway::Expression::from(sway::FunctionCall {
+                                    function: sway::Expression::Identifier("msg_sender".into()),
+                                    generic_parameters: None,
+                                    parameters: vec![],
+                                }),
+                                member: "unwrap".into(),
+                            }),
+                            generic_parameters: None,
+                            parameters: vec![],
+                        }),
+                    ]),
+                ],
+            }),
+        ],
+    }))
+}
+
+/// Logs the exit event before every return statement of the block and its nested blocks.
+///
+/// The returned values are bound to a variable of the return type before logging, so untyped literals are still coerced to it.
+fn instrument_returns(block: &mut sway::Block, return_type: Option<&sway::TypeName>, exit_statement: &sway::Statement, result_name: &str) {
+    let mut instrument = |block: &mut sway::Block| {
+        // Treat a trailing `return x` expression as a statement
+        if let Some(sway::Expression::Return(_)) = block.final_expr.as_ref() {
+            let final_expr = block.final_expr.take().unwrap();
+            block.statements.push(sway::Statement::from(final_expr));
+        }
+
+        let mut statements = vec![];
+
+        for statement in block.statements.drain(..) {
+            let sway::Statement::Expression(sway::Expression::Return(value)) = statement else {
+                statements.push(statement);
+                continue;
+            };
+
+            match value {
+                // return x; => let result = x; log(...); return result;
+                Some(value) => {
+                    let (value_statements, result) = evaluate_before_statements(result_name, return_type.cloned(), *value, [exit_statement.clone()]);
+
+                    statements.extend(value_statements);
+                    statements.push(sway::Statement::from(sway::Expression::Return(Some(Box::new(result)))));
+                }
+
+                // return; => log(...); return;
+                None => {
+                    statements.push(exit_statement.clone());
+                    statements.push(sway::Statement::from(sway::Expression::Return(None)));
+                }
+            }
+        }
+
+        block.statements = statements;
+    };
+
+    block.visit_blocks_mut(&mut instrument);
+}
+
+/// Generates a name for the variable holding the returned value which doesn't shadow any of the names used in the function
+fn generate_unique_result_name(parameters: &sway::ParameterList, body: &sway::Block) -> String {
+    let mut names = parameters.entries.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+
+    body.visit_blocks(&mut |block| {
+        for statement in block.statements.iter() {
+            let sway::Statement::Let(sway::Let { pattern, .. }) = statement else { continue };
+
+            match pattern {
+                sway::LetPattern::Identifier(id) => names.push(id.name.clone()),
+                sway::LetPattern::Tuple(ids) => names.extend(ids.iter().map(|id| id.name.clone())),
+            }
+        }
+    });
+
+    body.visit_expressions(&mut |expression| {
+        if let sway::Expression::Identifier(name) = expression {
+            names.push(name.clone());
+        }
+    });
+
+    let mut result = "result".to_string();
+
+    while names.contains(&result) {
+        result = format!("_{result}");
+    }
+
+    result
+}
@@ -0,0 +1,373 @@
+use super::{
+    create_abi_encode_expression, create_abi_encode_packed_expression, create_integer_width_conversion_expression, translate_expression,
+    TranslatedDefinition, TranslationScope,
+};
+use crate::{project::{Lint, Project}, sway, Error};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use solang_parser::pt as solidity;
+use std::{cell::RefCell, rc::Rc};
+
+/// The name of the helper module generated in the project of each contract that computes EIP-712 hashes.
+pub const EIP712_MODULE_NAME: &str = "eip712";
+
+/// Translates the EIP-712 hashes computed by `DOMAIN_SEPARATOR`, `_hashTypedDataV4` and `permit` implementations:
+///
+/// * `keccak256(abi.encode(TYPEHASH, a, b, ...))` hashes the Ethereum ABI encoding of the values, where each value is a 32-byte word,
+///   instead of their Sway encoding.
+/// * `keccak256(abi.encodePacked("\x19\x01", domainSeparator, structHash))` => `eip712::hash_typed_data(domain_separator, struct_hash)`
+///
+/// Returns `None` if the arguments of the `keccak256` call are not an EIP-712 hash.
+pub fn translate_eip712_hash_expression(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    arguments: &[solidity::Expression],
+) -> Result<Option<sway::Expression>, Error> {
+    let [solidity::Expression::FunctionCall(loc, function, encode_arguments)] = arguments else { return Ok(None) };
+    let solidity::Expression::MemberAccess(_, container, member) = function.as_ref() else { return Ok(None) };
+    let solidity::Expression::Variable(solidity::Identifier { name: container_name, .. }) = container.as_ref() else { return Ok(None) };
+
+    if container_name != "abi" {
+        return Ok(None);
+    }
+
+    match member.name.as_str() {
+        "encode" if encode_arguments.first().is_some_and(is_type_hash_expression) => {
+            let parameters = encode_arguments.iter()
+                .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut words = vec![];
+
+            for parameter in parameters.iter() {
+                let type_name = translated_definition.get_expression_type(scope.clone(), parameter)?;
+
+                match create_word_expression(project, translated_definition, loc, parameter.clone(), &type_name) {
+                    Some(word) => words.push(word),
+
+                    // Dynamic values are not encoded in place, so fall back to the Sway encoding
+                    None => {
+                        return Ok(Some(create_keccak256_call(create_abi_encode_expression(translated_definition, parameters))));
+                    }
+                }
+            }
+
+            // Ensure `std::bytes::Bytes` is imported
+            translated_definition.ensure_use_declared("std::bytes::Bytes");
+
+            let variable_name = scope.borrow_mut().generate_unique_variable_name("bytes");
+
+            // {
+            //     let mut bytes = Bytes::new();
+            //     bytes.append(Bytes::from(a));
+            //     ...
+            //     bytes
+            // }
+            let mut block = sway::Block {
+                statements: vec![
+                    sway::Statement::from(sway::Let {
+                        pattern: sway::LetPattern::from(sway::LetIdentifier {
+                            is_mutable: true,
+                            name: variable_name.clone(),
+                        }),
+                        type_name: None,
+                        value: sway::Expression::create_function_call("Bytes::new", vec![]),
+                    }),
+                ],
+                final_expr: Some(sway::Expression::Identifier(variable_name.clone())),
+            };
+
+            for word in words {
+                block.statements.push(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::from(sway::MemberAccess {
+                        expression: sway::Expression::Identifier(variable_name.clone()),
+                        member: "append".into(),
+                    }),
+                    generic_parameters: None,
+                    parameters: vec![sway::Expression::create_function_call("Bytes::from", vec![word])],
+                })));
+            }
+
+            Ok(Some(create_keccak256_call(sway::Expression::from(block))))
+        }
+
+        "encodePacked" if encode_arguments.len() == 3 && is_typed_data_prefix_expression(&encode_arguments[0]) => {
+            let domain_separator = translate_expression(project, translated_definition, scope.clone(), &encode_arguments[1])?;
+            let struct_hash = translate_expression(project, translated_definition, scope.clone(), &encode_arguments[2])?;
+
+            let is_b256 = |type_name: sway::TypeName| matches!(type_name, sway::TypeName::Identifier { name, generic_parameters: None } if name == "b256");
+
+            if !is_b256(translated_definition.get_expression_type(scope.clone(), &domain_separator)?)
+                || !is_b256(translated_definition.get_expression_type(scope.clone(), &struct_hash)?)
+            {
+                let prefix = translate_expression(project, translated_definition, scope.clone(), &encode_arguments[0])?;
+                let packed_bytes = create_abi_encode_packed_expression(translated_definition, scope.clone(), vec![prefix, domain_separator, struct_hash])?;
+                return Ok(Some(create_keccak256_call(packed_bytes)));
+            }
+
+            ensure_eip712_module_declared(project, translated_definition, loc);
+
+            Ok(Some(sway::Expression::create_function_call(
+                format!("{EIP712_MODULE_NAME}::hash_typed_data").as_str(),
+                vec![domain_separator, struct_hash],
+            )))
+        }
+
+        _ => Ok(None),
+    }
+}
+
+/// Checks if the expression is the type hash of an EIP-712 struct, i.e: `keccak256("Permit(address owner,...)")` or `PERMIT_TYPEHASH`.
+fn is_type_hash_expression(expression: &solidity::Expression) -> bool {
+    match expression {
+        solidity::Expression::FunctionCall(_, function, arguments) => {
+            let solidity::Expression::Variable(solidity::Identifier { name, .. }) = function.as_ref() else { return false };
+            let [solidity::Expression::StringLiteral(literals)] = arguments.as_slice() else { return false };
+
+            let type_string = literals.iter().map(|l| l.string.as_str()).collect::<String>();
+
+            // Name(type name,...)
+            name == "keccak256"
+                && type_string.ends_with(')')
+                && type_string.split_once('(').is_some_and(|(name, _)| !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_'))
+        }
+
+        solidity::Expression::Variable(solidity::Identifier { name, .. })
+        | solidity::Expression::MemberAccess(_, _, solidity::Identifier { name, .. }) => {
+            name.replace('_', "").to_lowercase().ends_with("typehash")
+        }
+
+        _ => false,
+    }
+}
+
+/// Checks if the expression is the `"\x19\x01"` prefix of EIP-712 typed data.
+fn is_typed_data_prefix_expression(expression: &solidity::Expression) -> bool {
+    match expression {
+        solidity::Expression::StringLiteral(literals) => {
+            literals.iter().map(|l| l.string.as_str()).collect::<String>() == "\\x19\\x01"
+        }
+
+        solidity::Expression::HexLiteral(literals) => {
+            literals.iter().map(|l| l.hex.as_str()).collect::<String>() == "1901"
+        }
+
+        _ => false,
+    }
+}
+
+/// Creates the 32-byte word of the Ethereum ABI encoding of a value, or `None` if the value is not encoded in place.
+fn create_word_expression(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    loc: &solidity::Loc,
+    value: sway::Expression,
+    type_name: &sway::TypeName,
+) -> Option<sway::Expression> {
+    let sway::TypeName::Identifier { name, generic_parameters: None } = type_name else { return None };
+
+    match name.as_str() {
+        // x => x
+        "b256" => Some(value),
+
+        // x => b256::from(x.as_u256())
+        _ if type_name.is_uint() => {
+            Some(sway::Expression::create_function_call("b256::from", vec![create_integer_width_conversion_expression(value, type_name, 256)]))
+        }
+
+        // x => x.bits()
+        "Address" | "ContractId" => Some(create_bits_call(value)),
+
+        "Identity" => {
+            // to_identity(x) => x.bits()
+            if let sway::Expression::FunctionCall(function_call) = &value {
+                if matches!(&function_call.function, sway::Expression::Identifier(name) if name == "to_identity") {
+                    if let Some(function_call_count) = translated_definition.function_call_counts.get_mut("to_identity") {
+                        *function_call_count = function_call_count.saturating_sub(1);
+                    }
+
+                    return Some(create_bits_call(function_call.parameters[0].clone()));
+                }
+            }
+
+            // x => eip712::identity_to_b256(x)
+            ensure_eip712_module_declared(project, translated_definition, loc);
+            Some(sway::Expression::create_function_call(format!("{EIP712_MODULE_NAME}::identity_to_b256").as_str(), vec![value]))
+        }
+
+        "bool" => {
+            // x => eip712::bool_to_b256(x)
+            ensure_eip712_module_declared(project, translated_definition, loc);
+            Some(sway::Expression::create_function_call(format!("{EIP712_MODULE_NAME}::bool_to_b256").as_str(), vec![value]))
+        }
+
+        _ => None,
+    }
+}
+
+/// Declares the `eip712` helper module in the project of the translated definition:
+///
+/// ```ignore
+/// library;
+///
+/// use std::bytes::Bytes;
+///
+/// pub fn identity_to_b256(value: Identity) -> b256 {
+///     match value {
+///         Identity::Address(x) => x.bits(),
+///         Identity::ContractId(x) => x.bits(),
+///     }
+/// }
+///
+/// pub fn bool_to_b256(value: bool) -> b256 {
+///     if value {
+///         0x0000000000000000000000000000000000000000000000000000000000000001
+///     } else {
+///         0x0000000000000000000000000000000000000000000000000000000000000000
+///     }
+/// }
+///
+/// pub fn hash_typed_data(domain_separator: b256, struct_hash: b256) -> b256 {
+///     let mut bytes = Bytes::new();
+///     bytes.push(0x19);
+///     bytes.push(0x01);
+///     bytes.append(Bytes::from(domain_separator));
+///     bytes.append(Bytes::from(struct_hash));
+///     std::hash::keccak256(bytes)
+/// }
+/// ```
+fn ensure_eip712_module_declared(project: &mut Project, translated_definition: &mut TranslatedDefinition, loc: &solidity::Loc) {
+    if translated_definition.submodules.iter().any(|(name, _)| name == EIP712_MODULE_NAME) {
+        return;
+    }
+
+    project.report_warning(
+        Lint::Eip712Domain,
+        &translated_definition.path,
+        Some(loc),
+        format!(
+            "`{}` computes EIP-712 hashes with the generated `{EIP712_MODULE_NAME}` module, but `block.chainid` is the Fuel chain id, so signatures must be made for the Fuel domain",
+            translated_definition.name,
+        ),
+    );
+
+    translated_definition.ensure_submodule_declared(EIP712_MODULE_NAME, || {
+        let create_parameter = |name: &str, type_name: &str| sway::Parameter {
+            is_ref: false,
+            is_mut: false,
+            name: name.into(),
+            type_name: Some(sway::TypeName::create_identifier(type_name)),
+        };
+
+        let create_function = |name: &str, parameters: Vec<sway::Parameter>, statements: Vec<sway::Statement>, final_expr: sway::Expression| {
+            sway::ModuleItem::Function(sway::Function {
+                comment: None,
+                attributes: None,
+                is_public: true,
+                name: name.into(),
+                generic_parameters: None,
+                parameters: sway::ParameterList { entries: parameters },
+                return_type: Some(sway::TypeName::create_identifier("b256")),
+                body: Some(sway::Block {
+                    statements,
+                    final_expr: Some(final_expr),
+                }),
+            })
+        };
+
+        let create_bytes_call = |method: &str, parameter: sway::Expression| {
+            sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::from(sway::MemberAccess {
+                    expression: sway::Expression::Identifier("bytes".into()),
+                    member: method.into(),
+                }),
+                generic_parameters: None,
+                parameters: vec![parameter],
+            }))
+        };
+
+        let mut module = sway::Module::new(sway::ModuleKind::Library);
+
+        module.items.push(sway::ModuleItem::Use(sway::Use {
+            is_public: false,
+            tree: sway::UseTree::Path {
+                prefix: "std".into(),
+                suffix: Box::new(sway::UseTree::Path {
+                    prefix: "bytes".into(),
+                    suffix: Box::new(sway::UseTree::Name { name: "Bytes".into() }),
+                }),
+            },
+        }));
+
+        // identity_to_b256(value: Identity) -> b256
+        module.items.push(create_function(
+            "identity_to_b256",
+            vec![create_parameter("value", "Identity")],
+            vec![],
+            sway::Expression::from(sway::Match {
+                expression: sway::Expression::Identifier("value".into()),
+                branches: ["Identity::Address", "Identity::ContractId"].into_iter()
+                    .map(|variant| sway::MatchBranch {
+                        pattern: sway::Expression::create_function_call(variant, vec![sway::Expression::Identifier("x".into())]),
+                        value: create_bits_call(sway::Expression::Identifier("x".into())),
+                    })
+                    .collect(),
+            }),
+        ));
+
+        // bool_to_b256(value: bool) -> b256
+        module.items.push(create_function(
+            "bool_to_b256",
+            vec![create_parameter("value", "bool")],
+            vec![],
+            sway::Expression::from(sway::If {
+                condition: Some(sway::Expression::Identifier("value".into())),
+                then_body: sway::Block {
+                    statements: vec![],
+                    final_expr: Some(sway::Expression::from(sway::Literal::B256(BigUint::one()))),
+                },
+                else_if: Some(Box::new(sway::If {
+                    condition: None,
+                    then_body: sway::Block {
+                        statements: vec![],
+                        final_expr: Some(sway::Expression::from(sway::Literal::B256(BigUint::zero()))),
+                    },
+                    else_if: None,
+                })),
+            }),
+        ));
+
+        // hash_typed_data(domain_separator: b256, struct_hash: b256) -> b256
+        module.items.push(create_function(
+            "hash_typed_data",
+            vec![create_parameter("domain_separator", "b256"), create_parameter("struct_hash", "b256")],
+            vec![
+                sway::Statement::from(sway::Let {
+                    pattern: sway::LetPattern::from(sway::LetIdentifier {
+                        is_mutable: true,
+                        name: "bytes".into(),
+                    }),
+                    type_name: None,
+                    value: sway::Expression::create_function_call("Bytes::new", vec![]),
+                }),
+                create_bytes_call("push", sway::Expression::from(sway::Literal::HexInt(0x19u8.into()))),
+                create_bytes_call("push", sway::Expression::from(sway::Literal::HexInt(0x01u8.into()))),
+                create_bytes_call("append", sway::Expression::create_function_call("Bytes::from", vec![sway::Expression::Identifier("domain_separator".into())])),
+                create_bytes_call("append", sway::Expression::create_function_call("Bytes::from", vec![sway::Expression::Identifier("struct_hash".into())])),
+            ],
+            create_keccak256_call(sway::Expression::Identifier("bytes".into())),
+        ));
+
+        module
+    });
+}
+
+fn create_keccak256_call(value: sway::Expression) -> sway::Expression {
+    sway::Expression::create_function_call("std::hash::keccak256", vec![value])
+}
+
+fn create_bits_call(value: sway::Expression) -> sway::Expression {
+    sway::Expression::create_method_call(value, "bits", vec![])
+}
+
@@ -1,4 +1,4 @@
-use super::{translate_type_name, TranslatedDefinition, TranslatedEnum};
+use super::{get_rename_reason, translate_type_name, TranslatedDefinition, TranslatedEnum};
 use crate::{project::Project, sway, Error};
 use convert_case::Case;
 use num_bigint::BigUint;
@@ -6,7 +6,7 @@ use solang_parser::pt as solidity;
 
 #[inline]
 pub fn translate_enum_definition(
-    _project: &mut Project,
+    project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
     enum_definition: &solidity::EnumDefinition,
 ) -> Result<(), Error> {
@@ -33,9 +33,17 @@ pub fn translate_enum_definition(
 
     // Add each variant to the variants impl block
     for (i, value) in enum_definition.values.iter().enumerate() {
+        let old_name = value.as_ref().unwrap().name.as_str();
+        let new_name = project.translate_naming_convention(old_name, Case::ScreamingSnake);
+
+        if let Some(reason) = get_rename_reason(project, old_name, new_name.as_str(), Case::ScreamingSnake) {
+            translated_definition.add_renamed_identifier(format!("{}.{old_name}", type_definition.name).as_str(), format!("{}::{new_name}", type_definition.name).as_str(), reason);
+        }
+
         variants_impl.items.push(sway::ImplItem::Constant(sway::Constant {
+            comment: None,
             is_public: false,
-            name: crate::translate_naming_convention(value.as_ref().unwrap().name.as_str(), Case::ScreamingSnake),
+            name: new_name,
             type_name: type_definition.name.clone(),
             value: Some(sway::Expression::from(sway::Literal::DecInt(BigUint::from(i)))),
         }));
@@ -59,7 +67,7 @@ pub fn translate_event_definition(
     let events_enum_name = format!("{}Event", translated_definition.name);
 
     let type_name = if event_definition.fields.len() == 1 {
-        match translate_type_name(project, translated_definition, &event_definition.fields[0].ty, false, false) {
+        match translate_type_name(project, translated_definition, &event_definition.fields[0].ty, false, false)? {
             sway::TypeName::Identifier { name, .. } if project.find_definition_with_abi(name.as_str()).is_some() => {
                 sway::TypeName::Identifier {
                     name: "Identity".into(),
@@ -72,7 +80,7 @@ pub fn translate_event_definition(
     } else {
         sway::TypeName::Tuple {
             type_names: event_definition.fields.iter().map(|f| {
-                match translate_type_name(project, translated_definition, &f.ty, false, false) {
+                Ok(match translate_type_name(project, translated_definition, &f.ty, false, false)? {
                     sway::TypeName::Identifier { name, .. } if project.find_definition_with_abi(name.as_str()).is_some() => {
                         sway::TypeName::Identifier {
                             name: "Identity".into(),
@@ -81,8 +89,8 @@ pub fn translate_event_definition(
                     }
 
                     type_name => type_name,
-                }
-            }).collect(),
+                })
+            }).collect::<Result<Vec<_>, Error>>()?,
         }
     };
 
@@ -133,12 +141,12 @@ pub fn translate_error_definition(
     let errors_enum_name = format!("{}Error", translated_definition.name);
 
     let type_name = if error_definition.fields.len() == 1 {
-        translate_type_name(project, translated_definition, &error_definition.fields[0].ty, false, false)
+        translate_type_name(project, translated_definition, &error_definition.fields[0].ty, false, false)?
     } else {
         sway::TypeName::Tuple {
             type_names: error_definition.fields.iter().map(|f| {
                 translate_type_name(project, translated_definition, &f.ty, false, false)
-            }).collect(),
+            }).collect::<Result<Vec<_>, Error>>()?,
         }
     };
 
@@ -322,6 +330,7 @@ pub fn generate_enum_abi_encode_function(
 
     // Add the `abi_encode` function to the `core::codec::AbiEncode` impl
     abi_encode_impl.items.push(sway::ImplItem::Function(sway::Function {
+        comment: None,
         attributes: None,
         is_public: false,
         name: "abi_encode".into(),
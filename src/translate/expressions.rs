@@ -1,5 +1,10 @@
-use super::{translate_type_name, TranslatedDefinition, TranslatedVariable, TranslationScope};
-use crate::{project::Project, sway, translate::resolve_import, Error};
+use super::{
+    compute_function_selector, compute_interface_id, create_b256_to_fixed_bytes_call, create_expect_address_call, create_expect_contract_call,
+    create_fixed_bytes_to_b256_call, create_function_selector_expression, create_interface_id_expression, create_to_identity_call, evaluate_constant_expression,
+    evaluate_number_literal, get_function_signature, import_user_type, get_unprefixed_function_name, import_library_function_dependencies, is_safe_math_library,
+    resolve_import_symbols, resolve_super_function, translate_eip712_hash_expression, translate_type_name, ConstantValue, TranslatedDefinition, TranslatedFunction, TranslatedVariable, TranslationScope,
+};
+use crate::{emitter::emitln, project::{Lint, Project}, sway, translate::resolve_import, Error};
 use convert_case::Case;
 use num_bigint::BigUint;
 use num_traits::{Num, One, Zero};
@@ -62,7 +67,13 @@ pub fn create_value_expression(
             "I8" | "I16" | "I32" | "I64" | "I128" | "I256" => {
                 let value = match value.as_ref() {
                     Some(value) => *value,
-                    None => return sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+
+                    // I256::new()
+                    None => return sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier(format!("{name}::new")),
+                        generic_parameters: None,
+                        parameters: vec![],
+                    }),
                 };
 
                 match value {
@@ -382,6 +393,20 @@ pub fn translate_expression(
     scope: Rc<RefCell<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
+    // Refer to symbols imported under an alias or through a namespace by their declared names
+    let resolved_expression = resolve_import_symbols(project, translated_definition, Some(&scope.borrow()), expression);
+
+    if let Some(expression) = resolved_expression {
+        return translate_expression(project, translated_definition, scope, &expression);
+    }
+
+    // Check for expressions translated by translation hooks
+    for translation_hook in project.translation_hooks.clone() {
+        if let Some(expression) = translation_hook.translate_expression(translated_definition, &scope.borrow(), expression) {
+            return Ok(expression);
+        }
+    }
+
     match expression {
         solidity::Expression::BoolLiteral(_, _)
         | solidity::Expression::NumberLiteral(_, _, _, _)
@@ -465,17 +490,25 @@ pub fn translate_literal_expression(
             Ok(sway::Expression::from(sway::Literal::Bool(*value)))
         }
         
-        solidity::Expression::NumberLiteral(_, value, _, _) => {
-            Ok(sway::Expression::from(sway::Literal::DecInt(value.parse().unwrap())))
+        // 1_000, 1e18, 1 ether, 3 days => 1000, 1000000000000000000, 1000000000000000000, 259200
+        solidity::Expression::NumberLiteral(_, value, exponent, unit) => {
+            match evaluate_number_literal(value, "", exponent, unit.as_ref()) {
+                Some(value) => Ok(sway::Expression::from(sway::Literal::DecInt(value))),
+                None => Ok(sway::Expression::create_todo(Some(format!("number: {}", expression)))),
+            }
         }
 
-        solidity::Expression::RationalNumberLiteral(_, _, _, _, _) => {
-            Ok(sway::Expression::create_todo(Some(format!("rational number: {}", expression))))
+        // 1.5 ether, 2.5e3 => 1500000000000000000, 2500
+        solidity::Expression::RationalNumberLiteral(_, integer, fraction, exponent, unit) => {
+            match evaluate_number_literal(integer, fraction, exponent, unit.as_ref()) {
+                Some(value) => Ok(sway::Expression::from(sway::Literal::DecInt(value))),
+                None => Ok(sway::Expression::create_todo(Some(format!("rational number: {}", expression)))),
+            }
         }
 
         solidity::Expression::HexNumberLiteral(_, value, _) | solidity::Expression::AddressLiteral(_, value) => {
             Ok(sway::Expression::from(sway::Literal::HexInt(
-                BigUint::from_str_radix(value.trim_start_matches("0x"), 16)
+                BigUint::from_str_radix(value.trim_start_matches("0x").replace('_', "").as_str(), 16)
                     .map_err(|e| Error::Wrapped(Box::new(e)))?
             )))
         }
@@ -615,10 +648,9 @@ pub fn translate_array_subscript_expression(
         }))
     } else {
         match &variable.type_name {
-            sway::TypeName::Identifier { name, .. } if name == "Vec" => {
-                let sway::Expression::ArrayAccess(array_access) = expression else {
-                    panic!("Expected array access expression, found {expression:#?}");
-                };
+            // v[i] = x => v.set(i, x)
+            sway::TypeName::Identifier { name, .. } if name == "Vec" && matches!(expression, sway::Expression::ArrayAccess(_)) => {
+                let sway::Expression::ArrayAccess(array_access) = expression else { unreachable!() };
 
                 Ok(sway::Expression::from(sway::FunctionCall {
                     function: sway::Expression::from(sway::MemberAccess {
@@ -709,17 +741,41 @@ pub fn translate_member_access_expression(
                         panic!("Invalid type name expression, expected 1 parameter, found {}: {}", args.len(), expression);
                     }
 
-                    let type_name = translate_type_name(project, translated_definition, &args[0], false, false);
+                    if let solidity::Expression::Variable(contract_name) = &args[0] {
+                        match member.name.as_str() {
+                            // type(I).interfaceId => I_INTERFACE_ID
+                            "interfaceId" => if let Some(interface_id) = compute_interface_id(project, &contract_name.name) {
+                                return Ok(create_interface_id_expression(translated_definition, &contract_name.name, interface_id));
+                            }
+
+                            // type(C).creationCode => todo!("type(C).creationCode")
+                            "creationCode" | "runtimeCode" => {
+                                project.report_warning(Lint::ContractCreation, &translated_definition.path, Some(&expression.loc()), format!("`{expression}` has no Fuel equivalent, since contracts cannot deploy other contracts and are identified by the merkle root of their bytecode"));
+
+                                return Ok(sway::Expression::create_todo(Some(expression.to_string())));
+                            }
+
+                            _ => {}
+                        }
+                    }
+
+                    let type_name = translate_type_name(project, translated_definition, &args[0], false, false)?;
 
                     match &type_name {
                         sway::TypeName::Identifier { name, .. } => match (name.as_str(), member.name.as_str()) {
+                            // Only fold the maximum values that keep their type as a literal, i.e: `type(uint256).max` => `115792...639935u256`
+                            ("u64" | "u256", "max") => {
+                                let bits = type_name.uint_bits().unwrap();
+                                return Ok(sway::Expression::from(sway::Literal::DecInt((BigUint::one() << bits) - BigUint::one())));
+                            }
+
                             ("I8" | "I16" | "I32" | "I64" | "I128" | "I256" | "u8" | "u16" | "u32" | "u64" | "u256", "min") => return Ok(sway::Expression::from(sway::FunctionCall {
                                 function: sway::Expression::Identifier(format!("{name}::min")),
                                 generic_parameters: None,
                                 parameters: vec![],
                             })),
 
-                            ("I8" | "I16" | "I32" | "I64" | "I128" | "I256" | "u8" | "u16" | "u32" | "u64" | "u256", "max") => return Ok(sway::Expression::from(sway::FunctionCall {
+                            ("I8" | "I16" | "I32" | "I64" | "I128" | "I256" | "u8" | "u16" | "u32", "max") => return Ok(sway::Expression::from(sway::FunctionCall {
                                 function: sway::Expression::Identifier(format!("{name}::max")),
                                 generic_parameters: None,
                                 parameters: vec![],
@@ -738,9 +794,20 @@ pub fn translate_member_access_expression(
             _ => {}
         }
 
+        // IERC20.transfer.selector => [0xa9, 0x05, 0x9c, 0xbb]
+        solidity::Expression::MemberAccess(_, contract, function) if member.name == "selector" => {
+            if let solidity::Expression::Variable(contract) = contract.as_ref() {
+                if let Some(signature) = get_function_signature(project, &contract.name, &function.name, None) {
+                    return Ok(create_function_selector_expression(compute_function_selector(&signature)));
+                }
+            }
+        }
+
         solidity::Expression::Variable(solidity::Identifier { name, .. }) => match (name.as_str(), member.name.as_str()) {
             ("block", "basefee") => {
                 // block.basefee => /*unsupported: block.basefee; using:*/ 0
+                report_unsupported_global(project, translated_definition, &expression.loc(), "block.basefee", "was translated to `0`");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: block.basefee; using:".into(),
                     Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -749,6 +816,8 @@ pub fn translate_member_access_expression(
 
             ("block", "blobbasefee") => {
                 // block.blobbasefee => /*unsupported: block.blobbasefee; using:*/ 0
+                report_unsupported_global(project, translated_definition, &expression.loc(), "block.blobbasefee", "was translated to `0`");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: block.blobbasefee; using:".into(),
                     Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -886,6 +955,8 @@ pub fn translate_member_access_expression(
 
             ("block", "difficulty") => {
                 // block.difficulty => /*unsupported: block.difficulty; using:*/ 0
+                report_unsupported_global(project, translated_definition, &expression.loc(), "block.difficulty", "was translated to `0`");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: block.difficulty; using:".into(),
                     Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -895,20 +966,31 @@ pub fn translate_member_access_expression(
             // TODO: find out the appropriate sway version of `block.gaslimit`
             ("block", "gaslimit") => {
                 // block.gaslimit => ???
+                report_unsupported_global(project, translated_definition, &expression.loc(), "block.gaslimit", "was left as a TODO");
+
                 return Ok(sway::Expression::create_todo(Some("block.gaslimit".into())))
             }
 
             ("block", "number") => {
-                // block.number => std::block::height()
-                return Ok(sway::Expression::from(sway::FunctionCall {
-                    function: sway::Expression::Identifier("std::block::height".into()),
-                    generic_parameters: None,
-                    parameters: vec![],
-                }))
+                // block.number => std::block::height().as_u256()
+                return Ok(create_integer_width_conversion_expression(
+                    sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("std::block::height".into()),
+                        generic_parameters: None,
+                        parameters: vec![],
+                    }),
+                    &sway::TypeName::Identifier {
+                        name: "u32".into(),
+                        generic_parameters: None,
+                    },
+                    256,
+                ))
             }
 
             ("block", "prevrandao") => {
                 // block.prevrandao => /*unsupported: block.prevrandao; using:*/ 0
+                report_unsupported_global(project, translated_definition, &expression.loc(), "block.prevrandao", "was translated to `0`");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: block.prevrandao; using:".into(),
                     Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -932,9 +1014,14 @@ pub fn translate_member_access_expression(
             }
 
             ("msg", "data") => {
-                // msg.data => std::inputs::input_message_data(0, 0)
+                // msg.data => input_message_data(0, 0)
+
+                report_unsupported_global(project, translated_definition, &expression.loc(), "msg.data", "was translated to the data of the first input message");
+
+                translated_definition.ensure_use_declared("std::inputs::input_message_data");
+
                 return Ok(sway::Expression::from(sway::FunctionCall {
-                    function: sway::Expression::Identifier("std::inputs::input_message_data".into()),
+                    function: sway::Expression::Identifier("input_message_data".into()),
                     generic_parameters: None,
                     parameters: vec![
                         sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
@@ -945,6 +1032,8 @@ pub fn translate_member_access_expression(
 
             ("msg", "sender") => {
                 // msg.sender => msg_sender().unwrap()
+                translated_definition.ensure_use_declared("std::auth::msg_sender");
+
                 return Ok(sway::Expression::from(sway::FunctionCall {
                     function: sway::Expression::from(sway::MemberAccess {
                         expression: sway::Expression::from(sway::FunctionCall {
@@ -961,6 +1050,8 @@ pub fn translate_member_access_expression(
 
             ("msg", "sig") => {
                 // msg.sig => /*unsupported: msg.sig; using:*/ [0, 0, 0, 0]
+                report_unsupported_global(project, translated_definition, &expression.loc(), "msg.sig", "was translated to `[0, 0, 0, 0]`");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: msg.sig; using:".into(),
                     Box::new(sway::Expression::from(sway::Array {
@@ -975,9 +1066,18 @@ pub fn translate_member_access_expression(
             }
 
             ("msg", "value") => {
-                // msg.value => std::context::msg_amount()
+                // msg.value => msg_amount()
+                project.report_warning(
+                    Lint::MsgValue,
+                    &translated_definition.path,
+                    Some(&expression.loc()),
+                    "`msg.value` was translated to `msg_amount()`, which does not check that the forwarded coins are of the base asset",
+                );
+
+                translated_definition.ensure_use_declared("std::context::msg_amount");
+
                 return Ok(sway::Expression::from(sway::FunctionCall {
-                    function: sway::Expression::Identifier("std::context::msg_amount".into()),
+                    function: sway::Expression::Identifier("msg_amount".into()),
                     generic_parameters: None,
                     parameters: vec![],
                 }))
@@ -1004,6 +1104,8 @@ pub fn translate_member_access_expression(
             ("tx", "origin") => {
                 // tx.origin => Identity::from(Address::from(/*unsupported: tx.origin; using:*/ ZERO_B256))
 
+                project.report_warning(Lint::TxOrigin, &translated_definition.path, Some(&expression.loc()), "`tx.origin` has no Sway equivalent and was translated to the zero address");
+
                 // Ensure `std::constants::ZERO_B256` is imported
                 translated_definition.ensure_use_declared("std::constants::ZERO_B256");
 
@@ -1026,12 +1128,17 @@ pub fn translate_member_access_expression(
             }
 
             (name, member) => {
+                // Check to see if the variable is an enum declared in another source unit
+                if scope.borrow().get_variable_from_old_name(name).is_none() {
+                    import_user_type(project, translated_definition, name)?;
+                }
+
                 // Check to see if the variable is an enum
                 if let Some(translated_enum) = translated_definition.enums.iter().find(|e| match &e.type_definition.name {
                     sway::TypeName::Identifier { name: enum_name, .. } => enum_name == name,
                     _ => false
                 }) {
-                    let new_name = crate::translate_naming_convention(member, Case::ScreamingSnake);
+                    let new_name = project.translate_naming_convention(member, Case::ScreamingSnake);
 
                     // Check to see if member is part of translated enum
                     if let Some(sway::ImplItem::Constant(c)) = translated_enum.variants_impl.items.iter().find(|i| match i {
@@ -1046,11 +1153,8 @@ pub fn translate_member_access_expression(
                 if let Some(external_definition) = resolve_import(project, &name.to_string(), &translated_definition.path)? {
                     let Some(variable) = external_definition.toplevel_scope.borrow().get_variable_from_old_name(member) else {
                         panic!(
-                            "{}error: Variable not found in scope: \"{member}\"",
-                            match project.loc_to_line_and_column(&translated_definition.path, &container.loc()) {
-                                Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                                None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                            }
+                            "{} - error: Variable not found in scope: \"{member}\"",
+                            project.loc_to_file_location_string(&translated_definition.path, &container.loc())
                         );
                     };
 
@@ -1090,7 +1194,7 @@ pub fn translate_member_access_expression(
                             panic!("Expected Identifier type name, found {:#?}", external_enum.type_definition.name);
                         };
     
-                        let variant_name = crate::translate_naming_convention(member.name.as_str(), Case::ScreamingSnake);
+                        let variant_name = project.translate_naming_convention(member.name.as_str(), Case::ScreamingSnake);
     
                         // Ensure the variant exists
                         if external_enum.variants_impl.items.iter().any(|i| {
@@ -1114,13 +1218,27 @@ pub fn translate_member_access_expression(
         _ => {}
     }
 
+    // x.code.length => match x { Identity::ContractId(x) => asm(r1, r2: x) { csiz r1 r2; r1: u64 }.as_u256(), _ => 0 }
+    if let solidity::Expression::MemberAccess(_, address, code_member) = container {
+        if code_member.name == "code" && member.name == "length" {
+            let address = translate_expression(project, translated_definition, scope.clone(), address)?;
+
+            if let sway::TypeName::Identifier { name, generic_parameters: None } = translated_definition.get_expression_type(scope.clone(), &address)? {
+                if name == "Identity" {
+                    return Ok(create_code_size_expression(address));
+                }
+            }
+        }
+    }
+
+    let container_loc = container.loc();
     let container = translate_expression(project, translated_definition, scope.clone(), container)?;
     let container_type_name = translated_definition.get_expression_type(scope.clone(), &container)?;
     let container_type_name_string = container_type_name.to_string();
     
     // Check if container is a struct
     if let Some(struct_definition) = translated_definition.structs.iter().find(|s| s.name == container_type_name_string) {
-        let field_name = crate::translate_naming_convention(member.name.as_str(), Case::Snake);
+        let field_name = project.translate_naming_convention(member.name.as_str(), Case::Snake);
 
         if struct_definition.fields.iter().any(|f| f.name == field_name) {
             return Ok(sway::Expression::from(sway::MemberAccess {
@@ -1133,6 +1251,9 @@ pub fn translate_member_access_expression(
     // Check for fields of built-in solidity value types
     match container_type_name {
         sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
+            // todo!("x").y => todo!("x")
+            ("todo!", None) => return Ok(container),
+
             ("Bytes", None) => match member.name.as_str() {
                 "length" => return Ok(sway::Expression::from(sway::FunctionCall {
                     function: sway::Expression::from(sway::MemberAccess {
@@ -1147,40 +1268,54 @@ pub fn translate_member_access_expression(
             }
 
             ("Identity", None) => match member.name.as_str() {
-                "balance" => return Ok(sway::Expression::from(sway::FunctionCall {
-                    function: sway::Expression::Identifier("std::context::balance_of".into()),
-                    generic_parameters: None,
-                    parameters: vec![
+                "balance" => {
+                    let contract_id = create_expect_contract_call(translated_definition, container);
+
+                    // address(this).balance => std::context::this_balance(AssetId::base()).as_u256()
+                    let is_this = matches!(
+                        &contract_id,
+                        sway::Expression::FunctionCall(f) if matches!(&f.function, sway::Expression::Identifier(name) if name == "ContractId::this")
+                    );
+
+                    let balance = if is_this {
                         sway::Expression::from(sway::FunctionCall {
-                            function: sway::Expression::from(sway::MemberAccess {
-                                expression: sway::Expression::from(sway::FunctionCall {
-                                    function: sway::Expression::from(sway::MemberAccess {
-                                        expression: container,
-                                        member: "as_contract_id".into(),
-                                    }),
-                                    generic_parameters: None,
-                                    parameters: vec![],
-                                }),
-                                member: "unwrap".into(),
-                            }),
+                            function: sway::Expression::Identifier("std::context::this_balance".into()),
                             generic_parameters: None,
-                            parameters: vec![],
-                        }),
+                            parameters: vec![create_base_asset_id_expression()],
+                        })
+                    } else {
+                        // x.balance => std::context::balance_of(expect_contract(x), AssetId::base()).as_u256()
+                        project.report_warning(Lint::AddressMember, &translated_definition.path, Some(&container_loc), format!("`{expression}` can only be queried for contracts on Fuel, the translation reverts for non-contract addresses"));
+
                         sway::Expression::from(sway::FunctionCall {
-                            function: sway::Expression::Identifier("AssetId::default".into()),
+                            function: sway::Expression::Identifier("std::context::balance_of".into()),
                             generic_parameters: None,
-                            parameters: vec![],
-                        }),
-                    ],
-                })),
+                            parameters: vec![contract_id, create_base_asset_id_expression()],
+                        })
+                    };
+
+                    // Native balances are always u64
+                    return Ok(create_integer_width_conversion_expression(balance, &sway::TypeName::Identifier {
+                        name: "u64".into(),
+                        generic_parameters: None,
+                    }, 256));
+                }
+
+                "code" | "codehash" => {
+                    // x.code => todo!("x.code")
+                    project.report_warning(Lint::AddressMember, &translated_definition.path, Some(&container_loc), format!("`{expression}` has no Fuel equivalent, since contract bytecode is identified by its merkle root instead of its hash"));
+
+                    return Ok(sway::Expression::create_todo(Some(expression.to_string())));
+                }
 
                 _ => {}
             }
 
             ("StorageVec", Some(_)) => match member.name.as_str() {
+                // x.read().length => x.len()
                 "length" => return Ok(sway::Expression::from(sway::FunctionCall {
                     function: sway::Expression::from(sway::MemberAccess {
-                        expression: container,
+                        expression: get_storage_read_key(&container).cloned().unwrap_or(container),
                         member: "len".into(),
                     }),
                     generic_parameters: None,
@@ -1206,6 +1341,13 @@ pub fn translate_member_access_expression(
             _ => {}
         }
 
+        // Fixed-size arrays have a constant length, i.e: `x.length` => `3`
+        sway::TypeName::Array { length, .. } => match member.name.as_str() {
+            "length" => return Ok(sway::Expression::from(sway::Literal::DecInt(BigUint::from(length)))),
+
+            _ => {}
+        }
+
         _ => {}
     }
 
@@ -1258,18 +1400,12 @@ pub fn translate_function_call_expression(
                     }
 
                     solidity::Expression::Variable(solidity::Identifier { name, .. }) if name == "this" => {
-                        // address(this) => Identity::from(ContractId::this())
-                        Ok(sway::Expression::from(sway::FunctionCall {
-                            function: sway::Expression::Identifier("Identity::ContractId".into()),
+                        // address(this) => to_identity(ContractId::this())
+                        Ok(create_to_identity_call(translated_definition, sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("ContractId::this".into()),
                             generic_parameters: None,
-                            parameters: vec![
-                                sway::Expression::from(sway::FunctionCall {
-                                    function: sway::Expression::Identifier("ContractId::this".into()),
-                                    generic_parameters: None,
-                                    parameters: vec![],
-                                }),
-                            ],
-                        }))
+                            parameters: vec![],
+                        })))
                     }
 
                     value => {
@@ -1281,6 +1417,35 @@ pub fn translate_function_call_expression(
                             sway::TypeName::Identifier { name, generic_parameters: None } if name == "Identity" => {
                                 Ok(value)
                             }
+
+                            // address(x) => Identity::Address(Address::from(x))
+                            sway::TypeName::Identifier { name, generic_parameters: None } if name == "b256" => {
+                                Ok(create_address_identity_expression(value))
+                            }
+
+                            // address(x) => Identity::Address(Address::from(b256::from(x.as_u256())))
+                            type_name if type_name.is_uint() => {
+                                let value = create_integer_width_conversion_expression(value, &type_name, 256);
+
+                                Ok(create_address_identity_expression(sway::Expression::from(sway::FunctionCall {
+                                    function: sway::Expression::Identifier("b256::from".into()),
+                                    generic_parameters: None,
+                                    parameters: vec![value],
+                                })))
+                            }
+
+                            // address(abi(I, expect_contract(x).into())) => x
+                            sway::TypeName::Identifier { name, generic_parameters: None } if translated_definition.abis.iter().any(|a| a.name == name) => {
+                                if let Some(contract_id) = get_abi_cast_contract_id(&value) {
+                                    return Ok(create_to_identity_call(translated_definition, contract_id.clone()));
+                                }
+
+                                // The contract id of an abi value can't be retrieved after the cast, i.e: `address(x)` => `todo!("address(x)")`
+                                project.report_warning(Lint::AddressMember, &translated_definition.path, Some(&expression.loc()), format!("`{expression}` has no Fuel equivalent, since the `ContractId` of a `{name}` value can't be retrieved"));
+
+                                Ok(sway::Expression::create_todo(Some(expression.to_string())))
+                            }
+
                             _ => todo!("translate address cast: {expression:#?}"),
                         }
                     }
@@ -1333,37 +1498,37 @@ pub fn translate_function_call_expression(
                     let bits = match bits {
                         0..=8 => {
                             if *bits != 8 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i8`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported signed integer type `int{bits}`, using `i8`"));
                             }
                             8
                         }
                         9..=16 => {
                             if *bits != 16 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i16`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported signed integer type `int{bits}`, using `i16`"));
                             }
                             16
                         }
                         17..=32 => {
                             if *bits != 32 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i32`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported signed integer type `int{bits}`, using `i32`"));
                             }
                             32
                         }
                         33..=64 => {
                             if *bits != 64 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i64`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported signed integer type `int{bits}`, using `i64`"));
                             }
                             64
                         }
                         65..=128 => {
                             if *bits != 128 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i128`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported signed integer type `int{bits}`, using `i128`"));
                             }
                             128
                         }
                         129..=256 => {
                             if *bits != 256 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i256`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported signed integer type `int{bits}`, using `i256`"));
                             }
                             256
                         }
@@ -1405,8 +1570,11 @@ pub fn translate_function_call_expression(
                         match &arguments[0] {
                             solidity::Expression::Negate(_, expr) => {
                                 match expr.as_ref() {
-                                    solidity::Expression::NumberLiteral(_, value, _, _) => {
-                                        let value = value.parse::<BigUint>().map_err(|e| Error::Wrapped(Box::new(e)))?;
+                                    solidity::Expression::NumberLiteral(_, value, exponent, unit) => {
+                                        let Some(value) = evaluate_number_literal(value, "", exponent, unit.as_ref()) else {
+                                            panic!("Invalid number literal: {expr}");
+                                        };
+
                                         let max = if *bits == 256 {
                                             BigUint::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 16).map_err(|e| Error::Wrapped(Box::new(e)))?
                                         } else {
@@ -1427,6 +1595,14 @@ pub fn translate_function_call_expression(
                             return Ok(value);
                         }
 
+                        // Widening conversions can not fail, i.e: `uint256(x)` => `x.as_u256()`
+                        if from_bits < to_bits {
+                            return Ok(create_integer_width_conversion_expression(value, &sway::TypeName::Identifier {
+                                name: format!("u{from_bits}"),
+                                generic_parameters: None,
+                            }, to_bits));
+                        }
+
                         Ok(sway::Expression::from(sway::FunctionCall {
                             function: sway::Expression::from(sway::MemberAccess {
                                 expression: sway::Expression::from(sway::FunctionCall {
@@ -1444,31 +1620,31 @@ pub fn translate_function_call_expression(
                     let bits = match bits {
                         0..=8 => {
                             if *bits != 8 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u8`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u8`"));
                             }
                             8
                         }
                         9..=16 => {
                             if *bits != 16 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u16`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u16`"));
                             }
                             16
                         }
                         17..=32 => {
                             if *bits != 32 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u32`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u32`"));
                             }
                             32
                         }
                         33..=64 => {
                             if *bits != 64 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u64`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u64`"));
                             }
                             64
                         }
                         65..=256 => {
                             if *bits != 256 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u256`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&expression.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u256`"));
                             }
                             translated_definition.ensure_use_declared("std::u256::*");
                             256
@@ -1527,21 +1703,7 @@ pub fn translate_function_call_expression(
                                                     function: sway::Expression::Identifier("b256::from".into()),
                                                     generic_parameters: None,
                                                     parameters: vec![
-                                                        sway::Expression::from(sway::FunctionCall {
-                                                            function: sway::Expression::from(sway::MemberAccess {
-                                                                expression: sway::Expression::from(sway::FunctionCall {
-                                                                    function: sway::Expression::from(sway::MemberAccess {
-                                                                        expression: value_expression.clone(),
-                                                                        member: "as_address".into(),
-                                                                    }),
-                                                                    generic_parameters: None,
-                                                                    parameters: vec![],
-                                                                }),
-                                                                member: "unwrap".into(),
-                                                            }),
-                                                            generic_parameters: None,
-                                                            parameters: vec![],
-                                                        }),
+                                                        create_expect_address_call(translated_definition, value_expression.clone()),
                                                     ],
                                                 }),
                                                 member: "as_u256".into(),
@@ -1560,21 +1722,7 @@ pub fn translate_function_call_expression(
                                                         function: sway::Expression::Identifier("b256::from".into()),
                                                         generic_parameters: None,
                                                         parameters: vec![
-                                                            sway::Expression::from(sway::FunctionCall {
-                                                                function: sway::Expression::from(sway::MemberAccess {
-                                                                    expression: sway::Expression::from(sway::FunctionCall {
-                                                                        function: sway::Expression::from(sway::MemberAccess {
-                                                                            expression: value_expression.clone(),
-                                                                            member: "as_contract_id".into(),
-                                                                        }),
-                                                                        generic_parameters: None,
-                                                                        parameters: vec![],
-                                                                    }),
-                                                                    member: "unwrap".into(),
-                                                                }),
-                                                                generic_parameters: None,
-                                                                parameters: vec![],
-                                                            }),
+                                                            create_expect_contract_call(translated_definition, value_expression.clone()),
                                                         ],
                                                     }),
                                                     member: "as_u256".into(),
@@ -1607,60 +1755,33 @@ pub fn translate_function_call_expression(
                         sway::TypeName::Undefined => panic!("Undefined type name"),
 
                         sway::TypeName::Identifier { name, generic_parameters } => match name.as_str() {
+                            // bytes32(x) => x
+                            "b256" if generic_parameters.is_none() && *byte_count == 32 => Ok(value_expression),
+
+                            // bytes4(x) => b256_to_bytes4(x)
                             "b256" if generic_parameters.is_none() => {
-                                // Ensure `std::bytes::Bytes` is imported
-                                translated_definition.ensure_use_declared("std::bytes::Bytes");
-        
-                                // Generate a unique name for our variable
-                                let variable_name = scope.borrow_mut().generate_unique_variable_name("bytes");
-        
-                                Ok(sway::Expression::from(sway::Block {
-                                    statements: vec![
-                                        sway::Statement::from(sway::Let {
-                                            pattern: sway::LetPattern::from(sway::LetIdentifier {
-                                                is_mutable: false,
-                                                name: variable_name.clone(),
-                                            }),
-                                            type_name: None,
-                                            value: sway::Expression::from(sway::FunctionCall {
-                                                function: sway::Expression::Identifier("Bytes::from".into()),
-                                                generic_parameters: None,
-                                                parameters: vec![
-                                                    value_expression.clone(),
-                                                ],
-                                            }),
-                                        }),
-                                        sway::Statement::from(sway::Let {
-                                            pattern: sway::LetPattern::from(vec![
-                                                sway::LetIdentifier {
-                                                    is_mutable: false,
-                                                    name: variable_name.clone(),
-                                                },
-                                                sway::LetIdentifier {
-                                                    is_mutable: false,
-                                                    name: "_".into(),
-                                                },
-                                            ]),
-                                            type_name: None,
-                                            value: sway::Expression::from(sway::FunctionCall {
-                                                function: sway::Expression::from(sway::MemberAccess {
-                                                    expression: sway::Expression::Identifier(variable_name.clone()),
-                                                    member: "split_at".into(),
-                                                }),
-                                                generic_parameters: None,
-                                                parameters: vec![
-                                                    sway::Expression::from(sway::Literal::DecInt(BigUint::from(*byte_count))),
-                                                ],
-                                            }),
-                                        }),
-                                    ],
-                                    final_expr: Some(sway::Expression::Identifier(variable_name)),
+                                Ok(create_b256_to_fixed_bytes_call(translated_definition, value_expression, *byte_count as usize))
+                            }
+
+                            // bytes32(x) => b256::from(x)
+                            _ if value_type_name.is_uint() && *byte_count == 32 => {
+                                let value_expression = create_integer_width_conversion_expression(value_expression, &value_type_name, 256);
+
+                                Ok(sway::Expression::from(sway::FunctionCall {
+                                    function: sway::Expression::Identifier("b256::from".into()),
+                                    generic_parameters: None,
+                                    parameters: vec![value_expression],
                                 }))
                             }
 
                             _ => Ok(sway::Expression::create_todo(Some(format!("translate from {value_type_name} to bytes{byte_count}: {expression}")))),
                         }
 
+                        // bytes32(x) => bytes4_to_b256(x)
+                        sway::TypeName::Array { type_name, length } if *byte_count == 32 && type_name.to_string() == "u8" => {
+                            Ok(create_fixed_bytes_to_b256_call(translated_definition, value_expression, *length))
+                        }
+
                         sway::TypeName::Array { .. } => todo!("translate from {value_type_name} to bytes{byte_count}"),
                         sway::TypeName::Tuple { .. } => todo!("translate from {value_type_name} to bytes{byte_count}"),
                         sway::TypeName::StringSlice => todo!("translate from {value_type_name} to bytes{byte_count}"),
@@ -1677,10 +1798,25 @@ pub fn translate_function_call_expression(
                     match &value_type_name {
                         sway::TypeName::Undefined => panic!("Undefined type name"),
 
-                        sway::TypeName::Identifier { name, .. } => match name.as_str() {
+                        sway::TypeName::Identifier { name, generic_parameters: None } => match name.as_str() {
+                            // bytes(x) => x
+                            "Bytes" => Ok(value_expression),
+
+                            // bytes(x) => x.as_bytes()
+                            "String" => Ok(sway::Expression::from(sway::FunctionCall {
+                                function: sway::Expression::from(sway::MemberAccess {
+                                    expression: value_expression,
+                                    member: "as_bytes".into(),
+                                }),
+                                generic_parameters: None,
+                                parameters: vec![],
+                            })),
+
                             _ => todo!("translate from {value_type_name} to bytes"),
                         }
 
+                        sway::TypeName::Identifier { .. } => todo!("translate from {value_type_name} to bytes"),
+
                         sway::TypeName::Array { .. } => todo!("translate from {value_type_name} to bytes"),
                         sway::TypeName::Tuple { .. } => todo!("translate from {value_type_name} to bytes"),
                         
@@ -1821,46 +1957,68 @@ pub fn translate_function_call_expression(
         }
 
         solidity::Expression::Variable(solidity::Identifier { name, .. }) => {
+            // keccak256(abi.encode(TYPEHASH, ...)) and keccak256(abi.encodePacked("\x19\x01", ...)) => EIP-712 hashes
+            if name == "keccak256" {
+                if let Some(result) = translate_eip712_hash_expression(project, translated_definition, scope.clone(), arguments)? {
+                    return Ok(result);
+                }
+            }
+
             let mut parameters = arguments.iter()
                 .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
                 .collect::<Result<Vec<_>, _>>()?;
 
             match name.as_str() {
                 "blockhash" => {
-                    // blockhash(block_number) => std::block::block_header_hash(block_height).unwrap_or(0)
+                    // blockhash(block_number) => std::block::block_header_hash(u32::try_from(block_number).unwrap()).unwrap_or(ZERO_B256)
 
                     if parameters.len() != 1 {
                         panic!("Invalid blockhash call: {expression:#?}");
                     }
 
+                    report_unsupported_global(project, translated_definition, &expression.loc(), "blockhash", "was translated to the hash of the Fuel block header, which is zero for unavailable blocks");
+
+                    let block_number_type_name = translated_definition.get_expression_type(scope.clone(), &parameters[0])?;
+                    let block_number = create_integer_width_conversion_expression(parameters.pop().unwrap(), &block_number_type_name, 32);
+
+                    // Ensure `std::constants::ZERO_B256` is imported
+                    translated_definition.ensure_use_declared("std::constants::ZERO_B256");
+
                     Ok(sway::Expression::from(sway::FunctionCall {
                         function: sway::Expression::from(sway::MemberAccess {
                             expression: sway::Expression::from(sway::FunctionCall {
                                 function: sway::Expression::Identifier("std::block::block_header_hash".into()),
                                 generic_parameters: None,
-                                parameters,
+                                parameters: vec![block_number],
                             }),
                             member: "unwrap_or".into(),
                         }),
                         generic_parameters: None,
                         parameters: vec![
-                            sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+                            sway::Expression::Identifier("ZERO_B256".into()),
                         ],
                     }))
                 }
 
                 "gasleft" => {
-                    // gasleft() => std::registers::global_gas()
+                    // gasleft() => std::registers::global_gas().as_u256()
 
                     if !parameters.is_empty() {
                         panic!("Invalid gasleft call: {expression:#?}");
                     }
 
-                    Ok(sway::Expression::from(sway::FunctionCall {
-                        function: sway::Expression::Identifier("std::registers::global_gas".into()),
-                        generic_parameters: None,
-                        parameters,
-                    }))
+                    Ok(create_integer_width_conversion_expression(
+                        sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("std::registers::global_gas".into()),
+                            generic_parameters: None,
+                            parameters,
+                        }),
+                        &sway::TypeName::Identifier {
+                            name: "u64".into(),
+                            generic_parameters: None,
+                        },
+                        256,
+                    ))
                 }
 
                 "addmod" => {
@@ -1941,24 +2099,20 @@ pub fn translate_function_call_expression(
                 }
 
                 "ecrecover" => {
-                    // ecrecover(hash, v, r, s) => std::ecr::ec_recover(sig, msg_hash)
-
-                    //
-                    // TODO: how should we generate the sig value from v,r,s?
-                    //
+                    // ecrecover(hash, v, r, s) => std::vm::evm::ecr::ec_recover_evm_address(B512::from((r, y_parity_and_s)), hash)
 
                     if parameters.len() != 4 {
-                        panic!("Invalid ecrecover call: {expression:#?}");
+                        return Err(Error::Wrapped(Box::new(std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            format!(
+                                "{} - error: `ecrecover` expects 4 arguments, found {}",
+                                project.loc_to_file_location_string(&translated_definition.path, &expression.loc()),
+                                parameters.len(),
+                            ),
+                        ))));
                     }
 
-                    Ok(sway::Expression::from(sway::FunctionCall {
-                        function: sway::Expression::Identifier("std::ecr::ec_recover".into()),
-                        generic_parameters: None,
-                        parameters: vec![
-                            sway::Expression::create_todo(Some("ecrecover: how should we generate the sig value from v,r,s?".into())),
-                            parameters[0].clone(),
-                        ],
-                    }))
+                    create_ec_recover_expression(translated_definition, scope.clone(), &parameters)
                 }
 
                 "selfdestruct" => {
@@ -2052,6 +2206,11 @@ pub fn translate_function_call_expression(
                     let mut parameter_types = parameters.iter()
                         .map(|p| translated_definition.get_expression_type(scope.clone(), p))
                         .collect::<Result<Vec<_>, _>>()?;
+
+                    // Check to see if the expression constructs a struct declared in another source unit
+                    if scope.borrow().find_function(|f| f.borrow().old_name == old_name).is_none() {
+                        import_user_type(project, translated_definition, old_name)?;
+                    }
         
                     // Check to see if the expression is a by-value struct constructor
                     if let Some(struct_definition) = translated_definition.structs.iter().find(|s| s.name == old_name).cloned() {
@@ -2067,7 +2226,7 @@ pub fn translate_function_call_expression(
 
                                     for field in struct_definition.fields.iter() {
                                         let arg = named_arguments.iter().find(|a| {
-                                            let new_name = crate::translate_naming_convention(&a.name.name, Case::Snake);
+                                            let new_name = project.translate_naming_convention(&a.name.name, Case::Snake);
                                             new_name == field.name
                                         }).unwrap();
 
@@ -2105,6 +2264,18 @@ pub fn translate_function_call_expression(
                         }
                     }
 
+                    // Check to see if the expression is an enum cast, i.e: `E(x)` => `u8::try_from(x).unwrap()`
+                    if parameters.len() == 1 && translated_definition.enums.iter().any(|e| {
+                        matches!(&e.type_definition.name, sway::TypeName::Identifier { name, generic_parameters: None } if name == old_name)
+                    }) {
+                        if is_untyped_integer_expression(&parameters[0]) {
+                            return Ok(parameters[0].clone());
+                        }
+
+                        let value_type_name = translated_definition.get_underlying_type(&parameter_types[0]);
+                        return Ok(create_integer_width_conversion_expression(parameters[0].clone(), &value_type_name, 8));
+                    }
+
                     // Check to see if the expression is an ABI type
                     if let Some(external_definition) = project.find_definition_with_abi(old_name) {
                         if parameters.len() == 1 {
@@ -2121,24 +2292,10 @@ pub fn translate_function_call_expression(
                                         parameters: vec![
                                             sway::Expression::Identifier(old_name.into()),
     
-                                            // x.as_contract_id().unwrap().into()
+                                            // expect_contract(x).into()
                                             sway::Expression::from(sway::FunctionCall {
                                                 function: sway::Expression::from(sway::MemberAccess {
-                                                    expression: sway::Expression::from(sway::FunctionCall {
-                                                        function: sway::Expression::from(sway::MemberAccess {
-                                                            expression: sway::Expression::from(sway::FunctionCall {
-                                                                function: sway::Expression::from(sway::MemberAccess {
-                                                                    expression: parameters[0].clone(),
-                                                                    member: "as_contract_id".into(),
-                                                                }),
-                                                                generic_parameters: None,
-                                                                parameters: vec![],
-                                                            }),
-                                                            member: "unwrap".into(),
-                                                        }),
-                                                        generic_parameters: None,
-                                                        parameters: vec![],
-                                                    }),
+                                                    expression: create_expect_contract_call(translated_definition, parameters[0].clone()),
                                                     member: "into".into(),
                                                 }),
                                                 generic_parameters: None,
@@ -2158,7 +2315,7 @@ pub fn translate_function_call_expression(
 
                         for arg in named_arguments {
                             named_parameters.push((
-                                crate::translate_naming_convention(&arg.name.name, Case::Snake),
+                                project.translate_naming_convention(&arg.name.name, Case::Snake),
                                 translate_expression(project, translated_definition, scope.clone(), &arg.expr)?
                             ));
                         }
@@ -2183,7 +2340,7 @@ pub fn translate_function_call_expression(
 
                             for parameter in function.parameters.entries.iter() {
                                 let arg = named_arguments.iter().find(|a| {
-                                    let new_name = crate::translate_naming_convention(&a.name.name, Case::Snake);
+                                    let new_name = project.translate_naming_convention(&a.name.name, Case::Snake);
                                     new_name == parameter.name
                                 }).unwrap();
 
@@ -2196,14 +2353,33 @@ pub fn translate_function_call_expression(
                         }
                     }
 
+                    // Pass storage keys to the storage pointer parameters of the function, i.e: `f(storage.x.read())` => `f(storage.x)`
+                    if scope.borrow().find_function_matching_types(old_name, parameters.as_slice(), parameter_types.as_slice()).is_none() {
+                        let parameter_count = parameters.len();
+
+                        for i in 0..parameter_count {
+                            let Some(storage_key) = get_storage_read_key(&parameters[i]).cloned() else { continue };
+
+                            let has_storage_parameter = scope.borrow().find_function(|f| {
+                                let f = f.borrow();
+
+                                f.old_name == old_name
+                                    && f.parameters.entries.len() == parameter_count
+                                    && matches!(f.parameters.entries[i].type_name.as_ref(), Some(sway::TypeName::Identifier { name, .. }) if name == "StorageKey")
+                            }).is_some();
+
+                            if has_storage_parameter {
+                                parameter_types[i] = translated_definition.get_expression_type(scope.clone(), &storage_key)?;
+                                parameters[i] = storage_key;
+                            }
+                        }
+                    }
+
                     // Ensure the function exists in scope
                     let Some(function) = scope.borrow().find_function_matching_types(old_name, parameters.as_slice(), parameter_types.as_slice()) else {
                         panic!(
-                            "{}error: Failed to find function `{old_name}({})` in scope",
-                            match project.loc_to_line_and_column(&translated_definition.path, &function.loc()) {
-                                Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                                None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                            },
+                            "{} - error: Failed to find function `{old_name}({})` in scope",
+                            project.loc_to_file_location_string(&translated_definition.path, &function.loc()),
                             parameter_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "),
                         );
                     };
@@ -2260,16 +2436,7 @@ pub fn translate_function_call_expression(
                 solidity::Expression::Variable(solidity::Identifier { name, .. }) => match name.as_str() {
                     "abi" => match member.name.as_str() {
                         "decode" => {
-                            // abi.decode(encodedData, (uint256, bool)) =>
-                            // let (a, b): (u256, bool) = {
-                            //     let slice = encoded_data.as_raw_slice();
-                            //     let mut ptr = slice.ptr();
-                            //     let a = ptr.read::<u256>();
-                            //     ptr = ptr.add::<u256>(1);
-                            //     let b = ptr.read::<bool>();
-                            //     ptr = ptr.add::<bool>(1);
-                            //     (a, b)
-                            // };
+                            // abi.decode(encoded_data, (uint256, bool)) => core::codec::abi_decode::<(u256, bool)>(encoded_data.as_raw_slice())
 
                             if arguments.len() != 2 {
                                 panic!("Invalid `abi.decode` call: expected 2 arguments, found {}: {} - {expression:#?}", arguments.len(), expression);
@@ -2277,16 +2444,16 @@ pub fn translate_function_call_expression(
 
                             let encoded_data = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
                             
-                            let parameter_types = match &arguments[1] {
+                            let mut parameter_types = match &arguments[1] {
                                 solidity::Expression::List(_, parameter_types) => {
                                     parameter_types.iter()
                                         .map(|(_, p)| translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, false))
-                                        .collect::<Vec<_>>()
+                                        .collect::<Result<Vec<_>, _>>()?
                                 }
 
-                                solidity::Expression::Parenthesis(_, expression) if matches!(expression.as_ref(), solidity::Expression::Type(_, _)) => {
+                                solidity::Expression::Parenthesis(_, expression) => {
                                     vec![
-                                        translate_type_name(project, translated_definition, expression, false, false),
+                                        translate_type_name(project, translated_definition, expression, false, false)?,
                                     ]
                                 }
 
@@ -2295,235 +2462,123 @@ pub fn translate_function_call_expression(
                                 }
                             };
 
-                            let parameter_names = ('a'..='z').enumerate()
-                                .take_while(|(i, _)| *i < parameter_types.len())
-                                .map(|(_, c)| c.to_string())
-                                .collect::<Vec<_>>();
-
-                            if parameter_types.len() != parameter_names.len() {
-                                panic!("Failed to generate parameter names for `{}`", expression);
-                            }
+                            // (T) => T
+                            let type_name = if parameter_types.len() == 1 {
+                                parameter_types.pop().unwrap()
+                            } else {
+                                sway::TypeName::Tuple { type_names: parameter_types }
+                            };
 
-                            // If we only have 1 parameter to decode, just decode it directly
-                            if parameter_types.len() == 1 {
-                                // encoded_data.as_raw_slice().ptr().read::<u256>()
-                                return Ok(
+                            return Ok(sway::Expression::from(sway::FunctionCall {
+                                function: sway::Expression::Identifier("core::codec::abi_decode".into()),
+                                generic_parameters: Some(sway::GenericParameterList {
+                                    entries: vec![
+                                        sway::GenericParameter {
+                                            type_name,
+                                            implements: None,
+                                        },
+                                    ],
+                                }),
+                                parameters: vec![
                                     sway::Expression::from(sway::FunctionCall {
                                         function: sway::Expression::from(sway::MemberAccess {
-                                            expression: sway::Expression::from(sway::FunctionCall {
-                                                function: sway::Expression::from(sway::MemberAccess {
-                                                    expression: sway::Expression::from(sway::FunctionCall {
-                                                        function: sway::Expression::from(sway::MemberAccess {
-                                                            expression: encoded_data.clone(),
-                                                            member: "as_raw_slice".into(),
-                                                        }),
-                                                        generic_parameters: None,
-                                                        parameters: vec![],
-                                                    }),
-                                                    member: "ptr".into(),
-                                                }),
-                                                generic_parameters: None,
-                                                parameters: vec![],
-                                            }),
-                                            member: "read".into(),
-                                        }),
-                                        generic_parameters: Some(sway::GenericParameterList {
-                                            entries: vec![
-                                                sway::GenericParameter {
-                                                    type_name: parameter_types[0].clone(),
-                                                    implements: None,
-                                                },
-                                            ],
+                                            expression: encoded_data,
+                                            member: "as_raw_slice".into(),
                                         }),
+                                        generic_parameters: None,
                                         parameters: vec![],
-                                    })
-                                )
-                            }
-
-                            let mut block = sway::Block {
-                                statements: vec![
-                                    // let mut ptr = encoded_data.as_raw_slice().ptr();
-                                    sway::Statement::from(sway::Let {
-                                        pattern: sway::LetPattern::from(sway::LetIdentifier {
-                                            // This only needs to be mutable if there's multiple parameters to decode
-                                            is_mutable: parameter_names.len() > 1,
-                                            name: "ptr".into(),
-                                        }),
-                                        type_name: None,
-                                        value: sway::Expression::from(sway::FunctionCall {
-                                            function: sway::Expression::from(sway::MemberAccess {
-                                                expression: sway::Expression::from(sway::FunctionCall {
-                                                    function: sway::Expression::from(sway::MemberAccess {
-                                                        expression: encoded_data.clone(),
-                                                        member: "as_raw_slice".into(),
-                                                    }),
-                                                    generic_parameters: None,
-                                                    parameters: vec![],
-                                                }),
-                                                member: "ptr".into(),
-                                            }),
-                                            generic_parameters: None,
-                                            parameters: vec![],
-                                        }),
                                     }),
                                 ],
-                                final_expr: Some(sway::Expression::Tuple(
-                                    parameter_names.iter()
-                                        .map(|p| sway::Expression::Identifier(p.clone()))
-                                        .collect()
-                                )),
-                            };
-
-                            for (i, (parameter_name, parameter_type)) in parameter_names.iter().zip(parameter_types.iter()).enumerate() {
-                                // let a = ptr.read::<u256>();
-                                block.statements.push(sway::Statement::from(sway::Let {
-                                    pattern: sway::LetPattern::from(sway::LetIdentifier {
-                                        is_mutable: false,
-                                        name: parameter_name.clone(),
-                                    }),
-                                    type_name: None,
-                                    value: sway::Expression::from(sway::FunctionCall {
-                                        function: sway::Expression::from(sway::MemberAccess {
-                                            expression: sway::Expression::Identifier("ptr".into()),
-                                            member: "read".into(),
-                                        }),
-                                        generic_parameters: Some(sway::GenericParameterList {
-                                            entries: vec![
-                                                sway::GenericParameter {
-                                                    type_name: parameter_type.clone(),
-                                                    implements: None,
-                                                },
-                                            ],
-                                        }),
-                                        parameters: vec![],
-                                    }),
-                                }));
+                            }));
+                        }
 
-                                // If we have more parameters to decode, increase the ptr
-                                if i < parameter_names.len() - 1 {
-                                    // ptr = ptr.add::<u256>(1);
-                                    block.statements.push(sway::Statement::from(sway::Expression::from(sway::BinaryExpression {
-                                        operator: "=".into(),
-                                        lhs: sway::Expression::Identifier("ptr".into()),
-                                        rhs: sway::Expression::from(sway::FunctionCall {
-                                            function: sway::Expression::from(sway::MemberAccess {
-                                                expression: sway::Expression::Identifier("ptr".into()),
-                                                member: "add".into(),
-                                            }),
-                                            generic_parameters: Some(sway::GenericParameterList {
-                                                entries: vec![
-                                                    sway::GenericParameter {
-                                                        type_name: parameter_type.clone(),
-                                                        implements: None,
-                                                    },
-                                                ],
-                                            }),
-                                            parameters: vec![
-                                                sway::Expression::from(sway::Literal::DecInt(BigUint::one())),
-                                            ],
-                                        }),
-                                    })));
-                                }
-                            }
+                        "encode" => {
+                            // abi.encode(a, b, ...) => Bytes::from(core::codec::encode((a, b, ...)))
+
+                            let parameters = arguments.iter()
+                                .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
+                                .collect::<Result<Vec<_>, _>>()?;
 
-                            return Ok(sway::Expression::from(block));
+                            return Ok(create_abi_encode_expression(translated_definition, parameters));
                         }
 
-                        "encode" | "encodePacked" => {
-                            // abi.encode(a, b, ...) | abi.encodePacked(a, b, ...) => {
+                        "encodePacked" => {
+                            // abi.encodePacked(a, b, ...) => {
                             //     let mut bytes = Bytes::new();
                             //     bytes.append(Bytes::from(core::codec::encode(a)));
-                            //     bytes.append(Bytes::from(core::codec::encode(b)));
+                            //     bytes.append(b);
                             //     // ...
                             //     bytes
                             // }
 
-                            // Ensure `std::bytes::Bytes` is imported
-                            translated_definition.ensure_use_declared("std::bytes::Bytes");
-
-                            // Generate a unique variable name
-                            let variable_name = scope.borrow_mut().generate_unique_variable_name("bytes");
-
                             let parameters = arguments.iter()
                                 .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
                                 .collect::<Result<Vec<_>, _>>()?;
-                            
-                            // Create the abi encoding block
-                            let mut block = sway::Block {
-                                statements: vec![
-                                    sway::Statement::from(sway::Let {
-                                        pattern: sway::LetPattern::from(sway::LetIdentifier {
-                                            is_mutable: true,
-                                            name: variable_name.clone(),
-                                        }),
-                                        type_name: None,
-                                        value: sway::Expression::from(sway::FunctionCall {
-                                            function: sway::Expression::Identifier("Bytes::new".into()),
-                                            generic_parameters: None,
-                                            parameters: vec![],
-                                        }),
-                                    }),
-                                ],
-                                final_expr: Some(sway::Expression::Identifier(variable_name.clone())),
-                            };
-                            
-                            // Add the encoding statements to the block
-                            for parameter in parameters {
-                                block.statements.push(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
-                                    function: sway::Expression::from(sway::MemberAccess {
-                                        expression: sway::Expression::Identifier(variable_name.clone()),
-                                        member: "append".into(),
-                                    }),
-                                    generic_parameters: None,
-                                    parameters: vec![
-                                        sway::Expression::from(sway::FunctionCall {
-                                            function: sway::Expression::Identifier("Bytes::from".into()),
-                                            generic_parameters: None,
-                                            parameters: vec![
-                                                sway::Expression::from(sway::FunctionCall {
-                                                    function: sway::Expression::Identifier("core::codec::encode".into()),
-                                                    generic_parameters: None,
-                                                    parameters: vec![
-                                                        parameter.clone(),
-                                                    ],
-                                                }),
-                                            ],
-                                        }),
-                                    ],
-                                })));
-                            }
 
-                            return Ok(sway::Expression::from(block))
+                            return create_abi_encode_packed_expression(translated_definition, scope.clone(), parameters);
                         }
 
                         "encodeWithSelector" => {
-                            // abi.encodeWithSelector(selector, ...) => ???
+                            // abi.encodeWithSelector(selector, a, b, ...) => Bytes::from(core::codec::encode((selector, a, b, ...)))
 
-                            //
-                            // TODO: how should this be handled?
-                            //
+                            let parameters = arguments.iter()
+                                .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
+                                .collect::<Result<Vec<_>, _>>()?;
 
-                            return Ok(sway::Expression::create_todo(Some(expression.to_string())))
+                            return Ok(create_abi_encode_expression(translated_definition, parameters));
                         }
                         
                         "encodeWithSignature" => {
-                            // abi.encodeWithSignature(signature, ...) => ???
+                            // abi.encodeWithSignature("f(uint256)", a, ...) => Bytes::from(core::codec::encode(([0x.., 0x.., 0x.., 0x..], a, ...)))
 
-                            //
-                            // TODO: how should this be handled?
-                            //
+                            let Some(solidity::Expression::StringLiteral(signature)) = arguments.first() else {
+                                return Ok(sway::Expression::create_todo(Some(expression.to_string())));
+                            };
+
+                            let signature = signature.iter().map(|s| s.string.as_str()).collect::<String>();
 
-                            return Ok(sway::Expression::create_todo(Some(expression.to_string())))
+                            let mut parameters = arguments.iter().skip(1)
+                                .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
+                                .collect::<Result<Vec<_>, _>>()?;
+
+                            parameters.insert(0, create_function_selector_expression(compute_function_selector(&signature)));
+
+                            return Ok(create_abi_encode_expression(translated_definition, parameters));
                         }
                         
                         "encodeCall" => {
-                            // abi.encodeCall(functionPointer, (...)) => ???
+                            // abi.encodeCall(I.f, (a, ...)) => Bytes::from(core::codec::encode(([0x.., 0x.., 0x.., 0x..], a, ...)))
 
-                            //
-                            // TODO: how should this be handled?
-                            //
+                            let call_arguments = match arguments.get(1) {
+                                Some(solidity::Expression::List(_, parameters)) => parameters.iter()
+                                    .map(|(_, p)| p.as_ref().map(|p| p.ty.clone()))
+                                    .collect::<Option<Vec<_>>>(),
+
+                                Some(solidity::Expression::Parenthesis(_, x)) => Some(vec![x.as_ref().clone()]),
+                                Some(x) => Some(vec![x.clone()]),
+                                None => Some(vec![]),
+                            };
+
+                            let signature = match (arguments.first(), call_arguments.as_ref()) {
+                                (Some(solidity::Expression::MemberAccess(_, contract, function)), Some(call_arguments)) => match contract.as_ref() {
+                                    solidity::Expression::Variable(contract) => get_function_signature(project, &contract.name, &function.name, Some(call_arguments.len())),
+                                    _ => None,
+                                },
+
+                                _ => None,
+                            };
+
+                            let (Some(signature), Some(call_arguments)) = (signature, call_arguments) else {
+                                return Ok(sway::Expression::create_todo(Some(expression.to_string())));
+                            };
+
+                            let mut parameters = call_arguments.iter()
+                                .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
+                                .collect::<Result<Vec<_>, _>>()?;
 
-                            return Ok(sway::Expression::create_todo(Some(expression.to_string())))
+                            parameters.insert(0, create_function_selector_expression(compute_function_selector(&signature)));
+
+                            return Ok(create_abi_encode_expression(translated_definition, parameters));
                         }
                         
                         member => todo!("handle `abi.{member}` translation"),
@@ -2538,7 +2593,8 @@ pub fn translate_function_call_expression(
                             .map(|p| translated_definition.get_expression_type(scope.clone(), p))    
                             .collect::<Result<Vec<_>, _>>()?;
 
-                        for inherit in translated_definition.inherits.clone() {
+                        // Search the base contracts in the order of the linearization
+                        for inherit in translated_definition.linearization.clone().into_iter().skip(1) {
                             let Some(inherited_definition) = project.find_definition_with_abi(&inherit).cloned() else { continue };
 
                             if let Some(named_arguments) = named_arguments {
@@ -2546,7 +2602,7 @@ pub fn translate_function_call_expression(
         
                                 for arg in named_arguments {
                                     named_parameters.push((
-                                        crate::translate_naming_convention(&arg.name.name, Case::Snake),
+                                        project.translate_naming_convention(&arg.name.name, Case::Snake),
                                         translate_expression(project, translated_definition, scope.clone(), &arg.expr)?
                                     ));
                                 }
@@ -2571,7 +2627,7 @@ pub fn translate_function_call_expression(
         
                                     for parameter in function.parameters.entries.iter() {
                                         let arg = named_arguments.iter().find(|a| {
-                                            let new_name = crate::translate_naming_convention(&a.name.name, Case::Snake);
+                                            let new_name = project.translate_naming_convention(&a.name.name, Case::Snake);
                                             new_name == parameter.name
                                         }).unwrap();
         
@@ -2590,10 +2646,21 @@ pub fn translate_function_call_expression(
                                 parameter_types.as_slice(),
                             ) else { continue };
 
-                            let inherited_function = inherited_function.borrow();
+                            let inherited_function = inherited_function.borrow().clone();
+
+                            // Call the implementation that follows the current contract in the linearization
+                            let function_name = get_unprefixed_function_name(&inherited_definition, &inherited_function.new_name);
+                            
+                            let function_name = resolve_super_function(project, translated_definition, 1, function_name, &inherited_function.parameters)
+                                .unwrap_or(inherited_function.new_name);
+
+                            // Keep track of the super call so it can be resolved again when the function is inherited
+                            if !translated_definition.super_function_calls.contains(&function_name) {
+                                translated_definition.super_function_calls.push(function_name.clone());
+                            }
 
                             return Ok(sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::Identifier(inherited_function.new_name.clone()),
+                                function: sway::Expression::Identifier(function_name),
                                 generic_parameters: None,
                                 parameters,
                             }));
@@ -2616,7 +2683,7 @@ pub fn translate_function_call_expression(
 
                             for arg in named_arguments {
                                 named_parameters.push((
-                                    crate::translate_naming_convention(&arg.name.name, Case::Snake),
+                                    project.translate_naming_convention(&arg.name.name, Case::Snake),
                                     translate_expression(project, translated_definition, scope.clone(), &arg.expr)?
                                 ));
                             }
@@ -2641,7 +2708,7 @@ pub fn translate_function_call_expression(
 
                                 for parameter in function.parameters.entries.iter() {
                                     let arg = named_arguments.iter().find(|a| {
-                                        let new_name = crate::translate_naming_convention(&a.name.name, Case::Snake);
+                                        let new_name = project.translate_naming_convention(&a.name.name, Case::Snake);
                                         new_name == parameter.name
                                     }).unwrap();
 
@@ -2672,16 +2739,15 @@ pub fn translate_function_call_expression(
                             .map(|p| translated_definition.get_expression_type(scope.clone(), p))
                             .collect::<Result<Vec<_>, _>>()?;
 
-                        // TODO: check full inheritance heirarchy
                         // Check for explicit super function calls
-                        if translated_definition.inherits.iter().any(|i| i == name) {
+                        if let Some(contract_index) = translated_definition.linearization.iter().skip(1).position(|i| i == name).map(|i| i + 1) {
                             if let Some(inherited_definition) = project.find_definition_with_abi(name).cloned() {
                                 if let Some(named_arguments) = named_arguments {
                                     let mut named_parameters = vec![];
             
                                     for arg in named_arguments {
                                         named_parameters.push((
-                                            crate::translate_naming_convention(&arg.name.name, Case::Snake),
+                                            project.translate_naming_convention(&arg.name.name, Case::Snake),
                                             translate_expression(project, translated_definition, scope.clone(), &arg.expr)?
                                         ));
                                     }
@@ -2706,7 +2772,7 @@ pub fn translate_function_call_expression(
             
                                         for parameter in function.parameters.entries.iter() {
                                             let arg = named_arguments.iter().find(|a| {
-                                                let new_name = crate::translate_naming_convention(&a.name.name, Case::Snake);
+                                                let new_name = project.translate_naming_convention(&a.name.name, Case::Snake);
                                                 new_name == parameter.name
                                             }).unwrap();
             
@@ -2724,10 +2790,16 @@ pub fn translate_function_call_expression(
                                     parameters.as_slice(),
                                     parameter_types.as_slice(),
                                 ) {
-                                    let inherited_function = inherited_function.borrow();
+                                    let inherited_function = inherited_function.borrow().clone();
+
+                                    // Call the implementation of the named contract, or the base contract it inherits the function from
+                                    let function_name = get_unprefixed_function_name(&inherited_definition, &inherited_function.new_name);
+
+                                    let function_name = resolve_super_function(project, translated_definition, contract_index, function_name, &inherited_function.parameters)
+                                        .unwrap_or(inherited_function.new_name);
         
                                     return Ok(sway::Expression::from(sway::FunctionCall {
-                                        function: sway::Expression::Identifier(inherited_function.new_name.clone()),
+                                        function: sway::Expression::Identifier(function_name),
                                         generic_parameters: None,
                                         parameters,
                                     }));
@@ -2745,8 +2817,45 @@ pub fn translate_function_call_expression(
                             }
                         }
 
+                        // Check to see if container is a user-defined type name that was translated to a struct
+                        if translated_definition.value_type_structs.iter().any(|n| n == name) {
+                            match member.name.as_str() {
+                                // Fixed.wrap(x) => Fixed { value: x }
+                                "wrap" => return Ok(sway::Expression::from(sway::Constructor {
+                                    type_name: sway::TypeName::Identifier {
+                                        name: name.into(),
+                                        generic_parameters: None,
+                                    },
+                                    fields: vec![
+                                        sway::ConstructorField {
+                                            name: "value".into(),
+                                            value: parameters[0].clone(),
+                                        },
+                                    ],
+                                })),
+
+                                // Fixed.unwrap(x) => x.value
+                                "unwrap" => return Ok(sway::Expression::from(sway::MemberAccess {
+                                    expression: parameters[0].clone(),
+                                    member: "value".into(),
+                                })),
+
+                                _ => {}
+                            }
+                        }
+
                         // Check if function is contained in an external definition
                         if let Some(external_definition) = project.translated_definitions.iter().find(|x| x.name == name).cloned() {
+                            // SafeMath.add(a, b) => a + b
+                            if is_safe_math_library(&external_definition) && (2..=3).contains(&arguments.len()) {
+                                let lhs = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
+                                let rhs = translate_expression(project, translated_definition, scope.clone(), &arguments[1])?;
+
+                                if let Some(expression) = create_safe_math_expression(member.name.as_str(), lhs, rhs) {
+                                    return Ok(expression);
+                                }
+                            }
+
                             let old_name = member.name.clone();
                             let new_name = crate::translate_naming_convention(format!("{}_{}", container, member.name).as_str(), Case::Snake);
     
@@ -2755,7 +2864,7 @@ pub fn translate_function_call_expression(
         
                                 for arg in named_arguments {
                                     named_parameters.push((
-                                        crate::translate_naming_convention(&arg.name.name, Case::Snake),
+                                        project.translate_naming_convention(&arg.name.name, Case::Snake),
                                         translate_expression(project, translated_definition, scope.clone(), &arg.expr)?
                                     ));
                                 }
@@ -2780,7 +2889,7 @@ pub fn translate_function_call_expression(
         
                                     for parameter in function.parameters.entries.iter() {
                                         let arg = named_arguments.iter().find(|a| {
-                                            let new_name = crate::translate_naming_convention(&a.name.name, Case::Snake);
+                                            let new_name = project.translate_naming_convention(&a.name.name, Case::Snake);
                                             new_name == parameter.name
                                         }).unwrap();
         
@@ -2813,11 +2922,8 @@ pub fn translate_function_call_expression(
                                 && f.parameters.entries.len() == external_function_declaration.parameters.entries.len()
                             }) else {
                                 panic!(
-                                    "{}error: Failed to find function in scope: {name}.{old_name}({})",
-                                    match project.loc_to_line_and_column(&translated_definition.path, &container.loc()) {
-                                        Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                                        None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                                    },
+                                    "{} - error: Failed to find function in scope: {name}.{old_name}({})",
+                                    project.loc_to_file_location_string(&translated_definition.path, &container.loc()),
                                     parameter_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "),
                                 );
                             };
@@ -2834,6 +2940,9 @@ pub fn translate_function_call_expression(
                                 
                                 // Add the local function definition to the beginning of the list
                                 translated_definition.functions.insert(0, local_function_definition);
+
+                                // Import the functions the local function definition depends on
+                                import_library_function_dependencies(translated_definition, &external_definition, external_function_definition);
     
                                 // Create the local function declaration for the toplevel scope
                                 let mut local_function_declaration = external_function_declaration.clone();
@@ -2868,6 +2977,9 @@ pub fn translate_function_call_expression(
                 Err(_) => (None, None),
             };
 
+            // Variables with an ABI type are stored as identities, but their members are functions of the ABI instead of address members
+            let is_abi_variable = variable.as_ref().is_some_and(|v| v.borrow().abi_type_name.is_some());
+
             let mut container = translate_expression(project, translated_definition, scope.clone(), container)?;
             let type_name = translated_definition.get_expression_type(scope.clone(), &container)?;
 
@@ -2876,31 +2988,20 @@ pub fn translate_function_call_expression(
                 
                 sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
                     ("Identity", None) => match member.name.as_str() {
-                        "transfer" => {
-                            // to.transfer(amount) => std::asset::transfer(to, asset_id, amount)
+                        "transfer" if !is_abi_variable => {
+                            // to.transfer(amount) => std::asset::transfer(to, AssetId::base(), amount)
 
                             if arguments.len() != 1 {
                                 panic!("Malformed `address.transfer` call, expected 1 argument, found {}", arguments.len());
                             }
 
-                            Ok(sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::Identifier("std::asset::transfer".into()),
-                                generic_parameters: None,
-                                parameters: vec![
-                                    container,
-                                    sway::Expression::from(sway::FunctionCall {
-                                        function: sway::Expression::Identifier("AssetId::default".into()),
-                                        generic_parameters: None,
-                                        parameters: vec![],
-                                    }),
-                                    translate_expression(project, translated_definition, scope.clone(), &arguments[0])?,
-                                ],
-                            }))
+                            let amount = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
+                            create_native_transfer_expression(translated_definition, scope.clone(), container, amount)
                         }
 
-                        "send" => {
+                        "send" if !is_abi_variable => {
                             // to.send(amount) => {
-                            //     std::asset::transfer(to, asset_id, amount);
+                            //     std::asset::transfer(to, AssetId::base(), amount);
                             //     true
                             // }
 
@@ -2908,54 +3009,27 @@ pub fn translate_function_call_expression(
                                 panic!("Malformed `address.send` call, expected 1 argument, found {}", arguments.len());
                             }
 
+                            let amount = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
+
                             Ok(sway::Expression::from(sway::Block {
                                 statements: vec![
-                                    sway::Statement::from(sway::Expression::from(sway::FunctionCall {
-                                        function: sway::Expression::Identifier("std::asset::transfer".into()),
-                                        generic_parameters: None,
-                                        parameters: vec![
-                                            container,
-                                            sway::Expression::from(sway::FunctionCall {
-                                                function: sway::Expression::Identifier("AssetId::default".into()),
-                                                generic_parameters: None,
-                                                parameters: vec![],
-                                            }),
-                                            translate_expression(project, translated_definition, scope.clone(), &arguments[0])?,
-                                        ],
-                                    })),
+                                    sway::Statement::from(create_native_transfer_expression(translated_definition, scope.clone(), container, amount)?),
                                 ],
                                 final_expr: Some(sway::Expression::from(sway::Literal::Bool(true))),
                             }))
                         }
 
-                        "call" => {
+                        "call" | "delegatecall" | "staticcall" if !is_abi_variable => {
                             if arguments.len() != 1 {
-                                panic!("Malformed `address.call` call, expected 1 argument, found {}", arguments.len());
+                                panic!("Malformed `address.{member}` call, expected 1 argument, found {}", arguments.len());
                             }
 
-                            let payload = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
-                            translate_address_call_expression(project, translated_definition, scope.clone(), payload, None, None, None)
-                        }
-
-                        "delegatecall" => {
-                            //
-                            // TODO: is delegatecall possible?
-                            //
-
-                            Ok(sway::Expression::create_todo(Some(expression.to_string())))
-                        }
-
-                        "staticcall" => {
-                            //
-                            // TODO: is staticcall possible?
-                            //
-
-                            Ok(sway::Expression::create_todo(Some(expression.to_string())))
+                            translate_low_level_call_expression(project, translated_definition, scope.clone(), expression, container, None, None)
                         }
 
                         _ => {
                             let mut name = name.clone();
-                            let external_function_new_name = crate::translate_naming_convention(member.name.as_str(), Case::Snake);
+                            let external_function_new_name = project.translate_naming_convention(member.name.as_str(), Case::Snake);
 
                             // Check using directives for Identity-specific function
                             for using_directive in translated_definition.using_directives.iter() {
@@ -2964,7 +3038,10 @@ pub fn translate_function_call_expression(
                                 }).cloned() else { continue };
 
                                 if let Some(for_type_name) = &using_directive.for_type {
-                                    if *for_type_name != type_name {
+                                    // using SafeERC20 for IERC20 => the receiver is a variable with the `IERC20` ABI type
+                                    let abi_type_name = variable.as_ref().and_then(|v| v.borrow().abi_type_name.clone());
+
+                                    if *for_type_name != type_name && Some(for_type_name) != abi_type_name.as_ref() {
                                         continue;
                                     }
                                 }
@@ -3024,24 +3101,10 @@ pub fn translate_function_call_expression(
                                         parameters: vec![
                                             sway::Expression::Identifier(abi_type_name.clone()),
 
-                                            // x.as_contract_id().unwrap().into()
+                                            // expect_contract(x).into()
                                             sway::Expression::from(sway::FunctionCall {
                                                 function: sway::Expression::from(sway::MemberAccess {
-                                                    expression: sway::Expression::from(sway::FunctionCall {
-                                                        function: sway::Expression::from(sway::MemberAccess {
-                                                            expression: sway::Expression::from(sway::FunctionCall {
-                                                                function: sway::Expression::from(sway::MemberAccess {
-                                                                    expression: container,
-                                                                    member: "as_contract_id".into(),
-                                                                }),
-                                                                generic_parameters: None,
-                                                                parameters: vec![],
-                                                            }),
-                                                            member: "unwrap".into(),
-                                                        }),
-                                                        generic_parameters: None,
-                                                        parameters: vec![],
-                                                    }),
+                                                    expression: create_expect_contract_call(translated_definition, container),
                                                     member: "into".into(),
                                                 }),
                                                 generic_parameters: None,
@@ -3232,12 +3295,23 @@ pub fn translate_function_call_expression(
                                 }
                             }
 
+                            // a.add(b) => a + b
+                            let is_safe_math = project.translated_definitions.iter()
+                                .find(|d| d.name == using_directive.library_name)
+                                .is_some_and(is_safe_math_library);
+
+                            if is_safe_math && (1..=2).contains(&parameters.len()) {
+                                if let Some(expression) = create_safe_math_expression(member.name.as_str(), container.clone(), parameters[0].clone()) {
+                                    return Ok(expression);
+                                }
+                            }
+
                             if let Some(named_arguments) = named_arguments {
                                 let mut named_parameters = vec![];
         
                                 for arg in named_arguments {
                                     named_parameters.push((
-                                        crate::translate_naming_convention(&arg.name.name, Case::Snake),
+                                        project.translate_naming_convention(&arg.name.name, Case::Snake),
                                         translate_expression(project, translated_definition, scope.clone(), &arg.expr)?
                                     ));
                                 }
@@ -3261,7 +3335,7 @@ pub fn translate_function_call_expression(
         
                                     for parameter in function.parameters.entries.iter() {
                                         let arg = named_arguments.iter().find(|a| {
-                                            let new_name = crate::translate_naming_convention(&a.name.name, Case::Snake);
+                                            let new_name = project.translate_naming_convention(&a.name.name, Case::Snake);
                                             new_name == parameter.name
                                         }).unwrap();
         
@@ -3312,7 +3386,7 @@ pub fn translate_function_call_expression(
         
                                 for arg in named_arguments {
                                     named_parameters.push((
-                                        crate::translate_naming_convention(&arg.name.name, Case::Snake),
+                                        project.translate_naming_convention(&arg.name.name, Case::Snake),
                                         translate_expression(project, translated_definition, scope.clone(), &arg.expr)?
                                     ));
                                 }
@@ -3337,7 +3411,7 @@ pub fn translate_function_call_expression(
         
                                     for parameter in function.parameters.entries.iter() {
                                         let arg = named_arguments.iter().find(|a| {
-                                            let new_name = crate::translate_naming_convention(&a.name.name, Case::Snake);
+                                            let new_name = project.translate_naming_convention(&a.name.name, Case::Snake);
                                             new_name == parameter.name
                                         }).unwrap();
         
@@ -3378,6 +3452,11 @@ pub fn translate_function_call_expression(
         }
 
         solidity::Expression::FunctionCallBlock(_, function, block) => match function.as_ref() {
+            // (new Contract){value: x}(...)
+            solidity::Expression::Parenthesis(_, x) if matches!(x.as_ref(), solidity::Expression::New(_, _)) => {
+                translate_new_expression(project, translated_definition, scope.clone(), expression)
+            }
+
             solidity::Expression::MemberAccess(_, container, member) => {
                 let variable = translate_variable_access_expression(project, translated_definition, scope.clone(), container).ok().map(|(v, _)| v);
                 let mut container = translate_expression(project, translated_definition, scope.clone(), container)?;
@@ -3403,18 +3482,41 @@ pub fn translate_function_call_expression(
                     
                     sway::TypeName::Identifier { name, .. } => match name.as_str() {
                         "Identity" => match member.name.as_str() {
-                            "call" => {
+                            "call" | "delegatecall" | "staticcall" => {
                                 if arguments.len() != 1 {
-                                    panic!("Malformed `address.call` call, expected 1 argument, found {}", arguments.len());
+                                    panic!("Malformed `address.{member}` call, expected 1 argument, found {}", arguments.len());
+                                }
+
+                                // to.call{value: amount}("") => {
+                                //     std::asset::transfer(to, AssetId::base(), amount);
+                                //     (true, Bytes::new())
+                                // }
+                                if let ("call", Some(amount), solidity::Expression::StringLiteral(parts)) = (member.name.as_str(), coins.clone(), &arguments[0]) {
+                                    if parts.iter().all(|p| p.string.is_empty()) {
+                                        translated_definition.ensure_use_declared("std::bytes::Bytes");
+
+                                        return Ok(sway::Expression::from(sway::Block {
+                                            statements: vec![
+                                                sway::Statement::from(create_native_transfer_expression(translated_definition, scope.clone(), container, amount)?),
+                                            ],
+                                            final_expr: Some(sway::Expression::Tuple(vec![
+                                                sway::Expression::from(sway::Literal::Bool(true)),
+                                                sway::Expression::from(sway::FunctionCall {
+                                                    function: sway::Expression::Identifier("Bytes::new".into()),
+                                                    generic_parameters: None,
+                                                    parameters: vec![],
+                                                }),
+                                            ])),
+                                        }));
+                                    }
                                 }
 
-                                let payload = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
-                                translate_address_call_expression(project, translated_definition, scope.clone(), payload, coins, None, gas)
+                                translate_low_level_call_expression(project, translated_definition, scope.clone(), expression, container, coins, gas)
                             }
 
                             _ => {
                                 let mut name = name.clone();
-                                let external_function_new_name = crate::translate_naming_convention(member.name.as_str(), Case::Snake);
+                                let external_function_new_name = project.translate_naming_convention(member.name.as_str(), Case::Snake);
     
                                 // Check if expression is a variable that had an ABI type
                                 if let Some(variable) = variable.as_ref() {
@@ -3441,24 +3543,10 @@ pub fn translate_function_call_expression(
                                             parameters: vec![
                                                 sway::Expression::Identifier(abi_type_name.clone()),
     
-                                                // x.as_contract_id().unwrap().into()
+                                                // expect_contract(x).into()
                                                 sway::Expression::from(sway::FunctionCall {
                                                     function: sway::Expression::from(sway::MemberAccess {
-                                                        expression: sway::Expression::from(sway::FunctionCall {
-                                                            function: sway::Expression::from(sway::MemberAccess {
-                                                                expression: sway::Expression::from(sway::FunctionCall {
-                                                                    function: sway::Expression::from(sway::MemberAccess {
-                                                                        expression: container,
-                                                                        member: "as_contract_id".into(),
-                                                                    }),
-                                                                    generic_parameters: None,
-                                                                    parameters: vec![],
-                                                                }),
-                                                                member: "unwrap".into(),
-                                                            }),
-                                                            generic_parameters: None,
-                                                            parameters: vec![],
-                                                        }),
+                                                        expression: create_expect_contract_call(translated_definition, container),
                                                         member: "into".into(),
                                                     }),
                                                     generic_parameters: None,
@@ -3532,223 +3620,261 @@ pub fn translate_function_call_expression(
     }
 }
 
+/// Translates a low-level `call`, `delegatecall` or `staticcall` to an address.
+///
+/// Fuel contracts can't be called with raw call data, so the call is translated to a typed ABI call when its data encodes a call
+/// to a function of a known ABI, i.e: `abi.encodeWithSelector(IERC20.transfer.selector, to, amount)`.
+/// Any other call is translated to a TODO stub containing the original code.
 #[inline]
-pub fn translate_address_call_expression(
-    _project: &mut Project,
+pub fn translate_low_level_call_expression(
+    project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
     scope: Rc<RefCell<TranslationScope>>,
-    payload: sway::Expression,
+    expression: &solidity::Expression,
+    container: sway::Expression,
     coins: Option<sway::Expression>,
-    asset_id: Option<sway::Expression>,
     gas: Option<sway::Expression>,
 ) -> Result<sway::Expression, Error> {
-    // to.call(memory) => {
-    //     let return_ptr = asm(
-    //         r1: payload.buf.ptr,
-    //         r2: coins,
-    //         r3: asset_id,
-    //         r4: gas,
-    //     ) {
-    //         call r1 r2 r3 r4;
-    //         ret: raw_ptr
-    //     };
-    //     let return_length = asm() {
-    //         retl: u64
-    //     };
-    //     let result_ptr = std::alloc::alloc_bytes(return_length);
-    //     return_ptr.copy_to::<u8>(result_ptr, return_length);
-    //     (true, Bytes::from(raw_slice::from_parts::<u8>(result_ptr, return_length)))
-    // }
+    // to.call(data) | to.call{value: x}(data)
+    let solidity::Expression::FunctionCall(_, function, arguments) = expression else {
+        panic!("Malformed low-level call: {expression:#?}");
+    };
+
+    let function = match function.as_ref() {
+        solidity::Expression::FunctionCallBlock(_, function, _) => function.as_ref(),
+        function => function,
+    };
+
+    let solidity::Expression::MemberAccess(_, _, member) = function else {
+        panic!("Malformed low-level call: {expression:#?}");
+    };
+
+    let member_name = member.name.as_str();
+    let payload = &arguments[0];
+
+    let create_todo_stub = |comment: &str| sway::Expression::Commented(
+        format!(" {comment} "),
+        Box::new(sway::Expression::create_todo(Some(expression.to_string()))),
+    );
+
+    if member_name == "delegatecall" {
+        report_low_level_call(project, translated_definition, expression, "`delegatecall` has no Fuel equivalent, since contracts can't run the code of another contract on their own storage");
+        return Ok(create_todo_stub("delegatecall has no Fuel equivalent"));
+    }
+
+    let Some((abi, function, arguments)) = resolve_call_data_function(project, payload) else {
+        report_low_level_call(project, translated_definition, expression, &format!("the data of `{member_name}` does not encode a call to a function of a known ABI and was translated to a TODO stub"));
+        return Ok(create_todo_stub("raw call data has no Fuel equivalent"));
+    };
+
+    report_low_level_call(project, translated_definition, expression, &format!("`{member_name}` was translated to a call to `{}::{}`, which reverts instead of returning `false` when the call fails", abi.name, function.name));
+
+    // Ensure the ABI is added to the current definition
+    if !translated_definition.abis.iter().any(|a| a.name == abi.name) {
+        translated_definition.abis.push(abi.clone());
+    }
 
     // Ensure `std::bytes::Bytes` is imported
     translated_definition.ensure_use_declared("std::bytes::Bytes");
 
-    // Create unique variable names
-    let return_ptr_name = scope.borrow_mut().generate_unique_variable_name("return_ptr");
-    let return_length_name = scope.borrow_mut().generate_unique_variable_name("return_length");
-    let result_ptr_name = scope.borrow_mut().generate_unique_variable_name("result_ptr");
+    let parameters = arguments.iter()
+        .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    Ok(sway::Expression::from(sway::Block {
-        statements: vec![
-            // let return_ptr = asm(
-            //     r1: payload.buf.ptr,
-            //     r2: coins,
-            //     r3: asset_id,
-            //     r4: gas,
-            // ) {
-            //     call r1 r2 r3 r4;
-            //     ret: raw_ptr
-            // };
-            sway::Statement::from(sway::Let {
-                pattern: sway::LetPattern::from(sway::LetIdentifier {
-                    is_mutable: false,
-                    name: return_ptr_name.clone(),
+    // abi(I, expect_contract(to).into()).f
+    let function_access = sway::Expression::from(sway::MemberAccess {
+        expression: sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier("abi".into()),
+            generic_parameters: None,
+            parameters: vec![
+                sway::Expression::Identifier(abi.name.clone()),
+                sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::from(sway::MemberAccess {
+                        expression: create_expect_contract_call(translated_definition, container),
+                        member: "into".into(),
+                    }),
+                    generic_parameters: None,
+                    parameters: vec![],
                 }),
-                type_name: None,
-                value: sway::Expression::from(sway::AsmBlock {
-                    registers: vec![
-                        sway::AsmRegister {
-                            name: "r1".into(),
-                            value: Some(sway::Expression::from(sway::MemberAccess {
-                                expression: sway::Expression::from(sway::MemberAccess {
-                                    expression: payload.clone(),
-                                    member: "buf".into(),
-                                }),
-                                member: "ptr".into(),
-                            })),
-                        },
-                        sway::AsmRegister {
-                            name: "r2".into(),
-                            value: Some(coins.unwrap_or_else(|| sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::Identifier("std::inputs::input_amount".into()),
-                                generic_parameters: None,
-                                parameters: vec![
-                                    sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
-                                ],
-                            }))),
-                        },
-                        sway::AsmRegister {
-                            name: "r3".into(),
-                            value: Some(asset_id.unwrap_or_else(|| sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::from(sway::MemberAccess {
-                                    expression: sway::Expression::from(sway::FunctionCall {
-                                        function: sway::Expression::Identifier("std::inputs::input_asset_id".into()),
-                                        generic_parameters: None,
-                                        parameters: vec![
-                                            sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
-                                        ],
-                                    }),
-                                    member: "unwrap".into(),
-                                }),
-                                generic_parameters: None,
-                                parameters: vec![],
-                            }))),
-                        },
-                        sway::AsmRegister {
-                            name: "r4".into(),
-                            value: Some(gas.unwrap_or_else(|| sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::Identifier("std::registers::global_gas".into()),
-                                generic_parameters: None,
-                                parameters: vec![],
-                            }))),
-                        },
-                    ],
+            ],
+        }),
+        member: function.name.clone(),
+    });
 
-                    instructions: vec![
-                        sway::AsmInstruction {
-                            op_code: "call".into(),
-                            args: vec![
-                                "r1".into(),
-                                "r2".into(),
-                                "r3".into(),
-                                "r4".into(),
-                            ],
-                        },
-                    ],
+    let mut fields = vec![];
 
-                    final_expression: Some(sway::AsmFinalExpression {
-                        register: "ret".into(),
-                        type_name: Some(sway::TypeName::Identifier {
-                            name: "raw_ptr".into(),
-                            generic_parameters: None,
-                        }),
-                    }),
-                }),
-            }),
+    if let Some(coins) = coins {
+        fields.push(sway::ConstructorField {
+            name: "coins".into(),
+            value: coins,
+        });
+    }
 
-            // let return_length = asm() {
-            //     retl: u64
-            // };
-            sway::Statement::from(sway::Let {
-                pattern: sway::LetPattern::from(sway::LetIdentifier {
-                    is_mutable: false,
-                    name: return_length_name.clone(),
-                }),
-                type_name: None,
-                value: sway::Expression::from(sway::AsmBlock {
-                    registers: vec![],
-                    instructions: vec![],
-                    final_expression: Some(sway::AsmFinalExpression {
-                        register: "retl".into(),
-                        type_name: Some(sway::TypeName::Identifier {
-                            name: "u64".into(),
-                            generic_parameters: None,
-                        }),
-                    }),
-                }),
-            }),
+    if let Some(gas) = gas {
+        fields.push(sway::ConstructorField {
+            name: "gas".into(),
+            value: gas,
+        });
+    }
 
-            // let result_ptr = std::alloc::alloc_bytes(return_length);
-            sway::Statement::from(sway::Let {
-                pattern: sway::LetPattern::from(sway::LetIdentifier {
-                    is_mutable: false,
-                    name: result_ptr_name.clone(),
+    let function_call = if fields.is_empty() {
+        sway::Expression::from(sway::FunctionCall {
+            function: function_access,
+            generic_parameters: None,
+            parameters,
+        })
+    } else {
+        sway::Expression::from(sway::FunctionCallBlock {
+            function: function_access,
+            generic_parameters: None,
+            fields,
+            parameters,
+        })
+    };
+
+    // to.call(data) => {
+    //     let result = abi(I, expect_contract(to).into()).f(...);
+    //     (true, Bytes::from(core::codec::encode(result)))
+    // }
+    if function.return_type.is_some() {
+        let result_name = scope.borrow_mut().generate_unique_variable_name("result");
+
+        return Ok(sway::Expression::from(sway::Block {
+            statements: vec![
+                sway::Statement::from(sway::Let {
+                    pattern: sway::LetPattern::from(sway::LetIdentifier {
+                        is_mutable: false,
+                        name: result_name.clone(),
+                    }),
+                    type_name: None,
+                    value: function_call,
                 }),
-                type_name: None,
-                value: sway::Expression::from(sway::FunctionCall {
-                    function: sway::Expression::Identifier("std::alloc::alloc_bytes".into()),
+            ],
+            final_expr: Some(sway::Expression::Tuple(vec![
+                sway::Expression::from(sway::Literal::Bool(true)),
+                sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Bytes::from".into()),
                     generic_parameters: None,
                     parameters: vec![
-                        sway::Expression::Identifier(return_length_name.clone()),
+                        sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("core::codec::encode".into()),
+                            generic_parameters: None,
+                            parameters: vec![
+                                sway::Expression::Identifier(result_name),
+                            ],
+                        }),
                     ],
                 }),
-            }),
+            ])),
+        }));
+    }
 
-            // return_ptr.copy_to::<u8>(result_ptr, return_length);
-            sway::Statement::from(sway::Expression::from(sway::FunctionCall {
-                function: sway::Expression::from(sway::MemberAccess {
-                    expression: sway::Expression::Identifier(return_ptr_name.clone()),
-                    member: "copy_to".into(),
-                }),
-                generic_parameters: Some(sway::GenericParameterList {
-                    entries: vec![
-                        sway::GenericParameter {
-                            type_name: sway::TypeName::Identifier {
-                                name: "u8".into(),
-                                generic_parameters: None,
-                            },
-                            implements: None,
-                        }
-                    ],
-                }),
-                parameters: vec![
-                    sway::Expression::Identifier(result_ptr_name.clone()),
-                    sway::Expression::Identifier(return_length_name.clone()),
-                ],
-            })),
+    // to.call(data) => {
+    //     abi(I, expect_contract(to).into()).f(...);
+    //     (true, Bytes::new())
+    // }
+    Ok(sway::Expression::from(sway::Block {
+        statements: vec![
+            sway::Statement::from(function_call),
         ],
-
-        // (true, Bytes::from(raw_slice::from_parts::<u8>(result_ptr, return_length)))
         final_expr: Some(sway::Expression::Tuple(vec![
             sway::Expression::from(sway::Literal::Bool(true)),
             sway::Expression::from(sway::FunctionCall {
-                function: sway::Expression::Identifier("Bytes::from".into()),
+                function: sway::Expression::Identifier("Bytes::new".into()),
                 generic_parameters: None,
-                parameters: vec![
-                    sway::Expression::from(sway::FunctionCall {
-                        function: sway::Expression::Identifier("raw_slice::from_parts".into()),
-                        generic_parameters: Some(sway::GenericParameterList {
-                            entries: vec![
-                                sway::GenericParameter {
-                                    type_name: sway::TypeName::Identifier {
-                                        name: "u8".into(),
-                                        generic_parameters: None,
-                                    },
-                                    implements: None,
-                                }
-                            ],
-                        }),
-                        parameters: vec![
-                            sway::Expression::Identifier(result_ptr_name.clone()),
-                            sway::Expression::Identifier(return_length_name.clone()),
-                        ],
-                    }),
-                ],
-            })
+                parameters: vec![],
+            }),
         ])),
     }))
 }
 
+/// Reports a warning for a low-level call, unless the `address-member` lint is allowed.
+fn report_low_level_call(project: &mut Project, translated_definition: &TranslatedDefinition, expression: &solidity::Expression, message: &str) {
+    project.report_warning(Lint::AddressMember, &translated_definition.path, Some(&expression.loc()), message);
+}
+
+/// Resolves the ABI function that the data of a low-level call encodes a call to, along with the arguments of the call.
+///
+/// Functions referenced by a signature instead of an interface are only resolved when a single ABI has a matching function.
+fn resolve_call_data_function(project: &Project, payload: &solidity::Expression) -> Option<(sway::Abi, sway::Function, Vec<solidity::Expression>)> {
+    let solidity::Expression::FunctionCall(_, function, arguments) = payload else { return None };
+    let solidity::Expression::MemberAccess(_, container, member) = function.as_ref() else { return None };
+
+    if !matches!(container.as_ref(), solidity::Expression::Variable(solidity::Identifier { name, .. }) if name == "abi") || arguments.is_empty() {
+        return None;
+    }
+
+    // "transfer(address,uint256)" => "transfer"
+    let get_signature_function_name = |expression: &solidity::Expression| {
+        let solidity::Expression::StringLiteral(parts) = expression else { return None };
+        let signature = parts.iter().map(|p| p.string.as_str()).collect::<String>();
+        signature.split_once('(').map(|(name, _)| name.to_string())
+    };
+
+    let (abi_name, function_name, arguments) = match member.name.as_str() {
+        "encodeWithSelector" => match &arguments[0] {
+            // abi.encodeWithSelector(I.f.selector, ...)
+            solidity::Expression::MemberAccess(_, function, selector) if selector.name == "selector" => {
+                let solidity::Expression::MemberAccess(_, abi_name, function_name) = function.as_ref() else { return None };
+                let solidity::Expression::Variable(abi_name) = abi_name.as_ref() else { return None };
+                (Some(abi_name.name.clone()), function_name.name.clone(), arguments[1..].to_vec())
+            }
+
+            // abi.encodeWithSelector(bytes4(keccak256("f(...)")), ...)
+            solidity::Expression::FunctionCall(_, ty, hash_arguments) if matches!(ty.as_ref(), solidity::Expression::Type(_, solidity::Type::Bytes(4))) && hash_arguments.len() == 1 => {
+                let solidity::Expression::FunctionCall(_, hash_function, signature) = &hash_arguments[0] else { return None };
+
+                if !matches!(hash_function.as_ref(), solidity::Expression::Variable(solidity::Identifier { name, .. }) if name == "keccak256") || signature.len() != 1 {
+                    return None;
+                }
+
+                (None, get_signature_function_name(&signature[0])?, arguments[1..].to_vec())
+            }
+
+            _ => return None,
+        },
+
+        // abi.encodeWithSignature("f(...)", ...)
+        "encodeWithSignature" => (None, get_signature_function_name(&arguments[0])?, arguments[1..].to_vec()),
+
+        // abi.encodeCall(I.f, (...))
+        "encodeCall" if arguments.len() == 2 => {
+            let solidity::Expression::MemberAccess(_, abi_name, function_name) = &arguments[0] else { return None };
+            let solidity::Expression::Variable(abi_name) = abi_name.as_ref() else { return None };
+
+            let call_arguments = match &arguments[1] {
+                solidity::Expression::List(_, parameters) => parameters.iter()
+                    .map(|(_, p)| p.as_ref().map(|p| p.ty.clone()))
+                    .collect::<Option<Vec<_>>>()?,
+
+                solidity::Expression::Parenthesis(_, x) => vec![x.as_ref().clone()],
+                x => vec![x.clone()],
+            };
+
+            (Some(abi_name.name.clone()), function_name.name.clone(), call_arguments)
+        }
+
+        _ => return None,
+    };
+
+    let function_name = project.translate_naming_convention(function_name.as_str(), Case::Snake);
+    let mut candidates: Vec<(&sway::Abi, &sway::Function)> = vec![];
+
+    for abi in project.translated_definitions.iter().filter_map(|d| d.abi.as_ref()) {
+        if abi_name.as_ref().is_some_and(|abi_name| *abi_name != abi.name) || candidates.iter().any(|(a, _)| a.name == abi.name) {
+            continue;
+        }
+
+        if let Some(function) = abi.functions.iter().find(|f| f.name == function_name && f.parameters.entries.len() == arguments.len()) {
+            candidates.push((abi, function));
+        }
+    }
+
+    let [(abi, function)] = candidates.as_slice() else { return None };
+
+    Some(((*abi).clone(), (*function).clone(), arguments))
+}
+
 #[inline]
 pub fn translate_function_call_block_expression(
     _project: &mut Project,
@@ -3820,13 +3946,31 @@ pub fn translate_power_expression(
     lhs: &solidity::Expression,
     rhs: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
+    // 2 ** 255 => 57896044618658097711785492504343953926634992332820282019728792003956564819968u256
+    if let (
+        solidity::Expression::NumberLiteral(..) | solidity::Expression::HexNumberLiteral(..),
+        solidity::Expression::NumberLiteral(..) | solidity::Expression::HexNumberLiteral(..),
+    ) = (lhs, rhs) {
+        let expression = solidity::Expression::Power(solidity::Loc::Codegen, Box::new(lhs.clone()), Box::new(rhs.clone()));
+
+        if let Some(ConstantValue::Int(value)) = evaluate_constant_expression(translated_definition, &expression) {
+            return Ok(sway::Expression::from(sway::Literal::DecInt(value)));
+        }
+    }
+
     // lhs ** rhs => lhs.pow(rhs)
 
     // Ensure std::math::Power is imported for the pow function
     translated_definition.ensure_use_declared("std::math::Power");
 
     let lhs = translate_expression(project, translated_definition, scope.clone(), lhs)?;
-    let rhs = translate_expression(project, translated_definition, scope.clone(), rhs)?;
+    let mut rhs = translate_expression(project, translated_definition, scope.clone(), rhs)?;
+
+    // The exponent of the pow function is always a u32
+    if !is_untyped_integer_expression(&rhs) {
+        let rhs_type_name = translated_definition.get_expression_type(scope.clone(), &rhs)?;
+        rhs = create_integer_width_conversion_expression(rhs, &rhs_type_name, 32);
+    }
 
     Ok(sway::Expression::from(sway::FunctionCall {
         function: sway::Expression::from(sway::MemberAccess {
@@ -3885,10 +4029,678 @@ pub fn translate_binary_expression(
         }
     }
 
+    let lhs_loc = lhs.loc();
+    let rhs_loc = rhs.loc();
+
+    let mut lhs = translate_expression(project, translated_definition, scope.clone(), lhs)?;
+    let mut rhs = translate_expression(project, translated_definition, scope.clone(), rhs)?;
+
+    // Convert operands of comparisons between signed and unsigned integers
+    if let "==" | "!=" | "<" | ">" | "<=" | ">=" = operator {
+        let lhs_type_name = get_simple_expression_type(translated_definition, scope.clone(), &lhs)?;
+        let rhs_type_name = get_simple_expression_type(translated_definition, scope.clone(), &rhs)?;
+
+        if let (Some(lhs_type_name), Some(rhs_type_name)) = (lhs_type_name, rhs_type_name) {
+            if lhs_type_name.is_int() {
+                rhs = create_integer_sign_conversion_expression(project, translated_definition, &rhs_loc, rhs, &rhs_type_name, &lhs_type_name);
+            } else if rhs_type_name.is_int() {
+                lhs = create_integer_sign_conversion_expression(project, translated_definition, &lhs_loc, lhs, &lhs_type_name, &rhs_type_name);
+            }
+        }
+    }
+
+    match operator {
+        // The shift amount is always a u64
+        "<<" | ">>" if !is_untyped_integer_expression(&rhs) => {
+            let rhs_type_name = translated_definition.get_expression_type(scope.clone(), &rhs)?;
+            rhs = create_integer_width_conversion_expression(rhs, &rhs_type_name, 64);
+        }
+
+        // Widen the narrower operand of unsigned integers with different widths
+        "+" | "-" | "*" | "/" | "%" | "&" | "|" | "^" | "==" | "!=" | "<" | ">" | "<=" | ">="
+            if !is_untyped_integer_expression(&lhs) && !is_untyped_integer_expression(&rhs) =>
+        {
+            let lhs_type_name = translated_definition.get_expression_type(scope.clone(), &lhs)?;
+            let rhs_type_name = translated_definition.get_expression_type(scope.clone(), &rhs)?;
+
+            if let (Some(lhs_bits), Some(rhs_bits)) = (lhs_type_name.uint_bits(), rhs_type_name.uint_bits()) {
+                if lhs_bits < rhs_bits {
+                    lhs = create_integer_width_conversion_expression(lhs, &lhs_type_name, rhs_bits);
+                } else if rhs_bits < lhs_bits {
+                    rhs = create_integer_width_conversion_expression(rhs, &rhs_type_name, lhs_bits);
+                }
+            }
+        }
+
+        _ => {}
+    }
+
     Ok(sway::Expression::from(sway::BinaryExpression {
         operator: operator.into(),
-        lhs: translate_expression(project, translated_definition, scope.clone(), lhs)?,
-        rhs: translate_expression(project, translated_definition, scope.clone(), rhs)?,
+        lhs,
+        rhs,
+    }))
+}
+
+/// Gets the type of literals, variables and storage reads, which can always be resolved.
+///
+/// Returns `None` for any other kind of expression.
+#[inline]
+pub fn get_simple_expression_type(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    expression: &sway::Expression,
+) -> Result<Option<sway::TypeName>, Error> {
+    let is_simple = match expression {
+        sway::Expression::Literal(_) | sway::Expression::Identifier(_) => true,
+
+        // storage.x.read()
+        sway::Expression::FunctionCall(function_call) => match &function_call.function {
+            sway::Expression::MemberAccess(member_access) if member_access.member == "read" => matches!(
+                &member_access.expression,
+                sway::Expression::MemberAccess(x) if matches!(&x.expression, sway::Expression::Identifier(x) if x == "storage")
+            ),
+            _ => false,
+        },
+
+        _ => false,
+    };
+
+    if !is_simple {
+        return Ok(None);
+    }
+
+    translated_definition.get_expression_type(scope, expression).map(Some)
+}
+
+/// Creates a checked conversion of a value between signed and unsigned integer types.
+///
+/// Returns the value unchanged if it does not need to be converted.
+#[inline]
+pub fn create_integer_sign_conversion_expression(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    loc: &solidity::Loc,
+    value: sway::Expression,
+    value_type_name: &sway::TypeName,
+    type_name: &sway::TypeName,
+) -> sway::Expression {
+    if !((value_type_name.is_int() && type_name.is_uint()) || (value_type_name.is_uint() && type_name.is_int())) {
+        return value;
+    }
+
+    let sway::TypeName::Identifier { name, generic_parameters: None } = type_name else {
+        return value;
+    };
+
+    project.report_warning(Lint::CheckedConversion, &translated_definition.path, Some(loc), format!("inserted checked conversion from `{value_type_name}` to `{type_name}`"));
+
+    // x => T::try_from(x).unwrap()
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier(format!("{name}::try_from")),
+                generic_parameters: None,
+                parameters: vec![value],
+            }),
+            member: "unwrap".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![],
+    })
+}
+
+/// Creates `AssetId::base()`, the asset that Solidity's native balances and values are translated to.
+fn create_base_asset_id_expression() -> sway::Expression {
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("AssetId::base".into()),
+        generic_parameters: None,
+        parameters: vec![],
+    })
+}
+
+/// Creates the recovery of the address that signed a message hash from the `(v, r, s)` parameters of `ecrecover`.
+///
+/// Fuel expects the 64-byte compact signature of EIP-2098, where the recovery id of `v` is stored in the highest bit of `s`.
+/// The EVM address of the signer is recovered (rather than its Fuel address), so it matches the addresses signed for on Ethereum.
+/// A failed recovery results in the zero address, as it does in Solidity.
+fn create_ec_recover_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    parameters: &[sway::Expression],
+) -> Result<sway::Expression, Error> {
+    // Ensure `std::b512::B512` is imported
+    translated_definition.ensure_use_declared("std::b512::B512");
+
+    // Ensure `std::constants::ZERO_B256` is imported
+    translated_definition.ensure_use_declared("std::constants::ZERO_B256");
+
+    let mut b256_parameters = vec![];
+
+    for parameter in [&parameters[0], &parameters[2], &parameters[3]] {
+        let type_name = translated_definition.get_expression_type(scope.clone(), parameter)?;
+
+        // x => b256::from(x)
+        b256_parameters.push(match &type_name {
+            sway::TypeName::Identifier { name, generic_parameters: None } if name == "b256" => parameter.clone(),
+
+            _ => sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier("b256::from".into()),
+                generic_parameters: None,
+                parameters: vec![parameter.clone()],
+            }),
+        });
+    }
+
+    let [hash, r, s] = b256_parameters.try_into().unwrap();
+    let v = parameters[1].clone();
+
+    // if v == 28 { s | 0x8000000000000000000000000000000000000000000000000000000000000000 } else { s }
+    let y_parity_and_s = sway::Expression::from(sway::If {
+        condition: Some(sway::Expression::from(sway::BinaryExpression {
+            operator: "==".into(),
+            lhs: v,
+            rhs: sway::Expression::from(sway::Literal::DecInt(BigUint::from(28u8))),
+        })),
+        then_body: sway::Block {
+            statements: vec![],
+            final_expr: Some(sway::Expression::from(sway::BinaryExpression {
+                operator: "|".into(),
+                lhs: s.clone(),
+                rhs: sway::Expression::from(sway::Literal::B256(BigUint::one() << 255)),
+            })),
+        },
+        else_if: Some(Box::new(sway::If {
+            condition: None,
+            then_body: sway::Block {
+                statements: vec![],
+                final_expr: Some(s),
+            },
+            else_if: None,
+        })),
+    });
+
+    let create_identity = |value: sway::Expression| sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("Identity::Address".into()),
+        generic_parameters: None,
+        parameters: vec![value],
+    });
+
+    let y_parity_and_s_name = scope.borrow_mut().generate_unique_variable_name("y_parity_and_s");
+
+    let recovery = sway::Expression::from(sway::Match {
+        expression: sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier("std::vm::evm::ecr::ec_recover_evm_address".into()),
+            generic_parameters: None,
+            parameters: vec![
+                sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("B512::from".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::Tuple(vec![r, sway::Expression::Identifier(y_parity_and_s_name.clone())]),
+                    ],
+                }),
+                hash,
+            ],
+        }),
+        branches: vec![
+            sway::MatchBranch {
+                pattern: sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Ok".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::Identifier("address".into()),
+                    ],
+                }),
+                // Identity::Address(Address::from(b256::from(address)))
+                value: create_identity(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Address::from".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("b256::from".into()),
+                            generic_parameters: None,
+                            parameters: vec![sway::Expression::Identifier("address".into())],
+                        }),
+                    ],
+                })),
+            },
+            sway::MatchBranch {
+                pattern: sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Err".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::Identifier("_".into()),
+                    ],
+                }),
+                value: create_identity(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Address::from".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::Identifier("ZERO_B256".into()),
+                    ],
+                })),
+            },
+        ],
+    });
+
+    Ok(sway::Expression::from(sway::Block {
+        statements: vec![
+            sway::Statement::from(sway::Let {
+                pattern: sway::LetPattern::from(sway::LetIdentifier {
+                    is_mutable: false,
+                    name: y_parity_and_s_name,
+                }),
+                type_name: None,
+                value: y_parity_and_s,
+            }),
+        ],
+        final_expr: Some(recovery),
+    }))
+}
+
+/// Creates the packed encoding of multiple values, appending the bytes of each value to a `Bytes` buffer:
+///
+/// ```ignore
+/// {
+///     let mut bytes = Bytes::new();
+///     bytes.append(Bytes::from(core::codec::encode(a)));
+///     bytes.append(b);
+///     // ...
+///     bytes
+/// }
+/// ```
+pub fn create_abi_encode_packed_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    parameters: Vec<sway::Expression>,
+) -> Result<sway::Expression, Error> {
+    // Ensure `std::bytes::Bytes` is imported
+    translated_definition.ensure_use_declared("std::bytes::Bytes");
+
+    // Generate a unique variable name
+    let variable_name = scope.borrow_mut().generate_unique_variable_name("bytes");
+
+    // Create the abi encoding block
+    let mut block = sway::Block {
+        statements: vec![
+            sway::Statement::from(sway::Let {
+                pattern: sway::LetPattern::from(sway::LetIdentifier {
+                    is_mutable: true,
+                    name: variable_name.clone(),
+                }),
+                type_name: None,
+                value: sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Bytes::new".into()),
+                    generic_parameters: None,
+                    parameters: vec![],
+                }),
+            }),
+        ],
+        final_expr: Some(sway::Expression::Identifier(variable_name.clone())),
+    };
+    
+    // Add the encoding statements to the block
+    for parameter in parameters {
+        let parameter = create_packed_bytes_expression(translated_definition, scope.clone(), parameter)?;
+
+        block.statements.push(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::from(sway::MemberAccess {
+                expression: sway::Expression::Identifier(variable_name.clone()),
+                member: "append".into(),
+            }),
+            generic_parameters: None,
+            parameters: vec![parameter],
+        })));
+    }
+
+    Ok(sway::Expression::from(block))
+}
+
+/// Creates `Bytes::from(core::codec::encode(x))`, encoding multiple values as a tuple.
+pub fn create_abi_encode_expression(translated_definition: &mut TranslatedDefinition, mut values: Vec<sway::Expression>) -> sway::Expression {
+    // Ensure `std::bytes::Bytes` is imported
+    translated_definition.ensure_use_declared("std::bytes::Bytes");
+
+    // (x) => x
+    let value = if values.len() == 1 {
+        values.pop().unwrap()
+    } else {
+        sway::Expression::Tuple(values)
+    };
+
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("Bytes::from".into()),
+        generic_parameters: None,
+        parameters: vec![
+            sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier("core::codec::encode".into()),
+                generic_parameters: None,
+                parameters: vec![value],
+            }),
+        ],
+    })
+}
+
+/// Creates the bytes of a value in the packed encoding of `abi.encodePacked`.
+///
+/// Dynamic values are appended without the length prefix of their regular encoding, while fixed-size values use their regular encoding.
+fn create_packed_bytes_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    value: sway::Expression,
+) -> Result<sway::Expression, Error> {
+    let type_name = translated_definition.get_expression_type(scope.clone(), &value)?;
+
+    match &type_name {
+        // x => x
+        sway::TypeName::Identifier { name, generic_parameters: None } if name == "Bytes" => Ok(value),
+
+        // x => x.as_bytes()
+        sway::TypeName::Identifier { name, generic_parameters: None } if name == "String" => Ok(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::from(sway::MemberAccess {
+                expression: value,
+                member: "as_bytes".into(),
+            }),
+            generic_parameters: None,
+            parameters: vec![],
+        })),
+
+        // x => String::from_ascii_str(x).as_bytes()
+        sway::TypeName::StringSlice => {
+            translated_definition.ensure_use_declared("std::string::String");
+
+            Ok(sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::from(sway::MemberAccess {
+                    expression: sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("String::from_ascii_str".into()),
+                        generic_parameters: None,
+                        parameters: vec![value],
+                    }),
+                    member: "as_bytes".into(),
+                }),
+                generic_parameters: None,
+                parameters: vec![],
+            }))
+        }
+
+        // x => Bytes::from(core::codec::encode(x))
+        _ => Ok(create_abi_encode_expression(translated_definition, vec![value])),
+    }
+}
+
+/// Creates a transfer of the base asset to an identity, converting the amount to the `u64` that Fuel asset amounts are limited to.
+fn create_native_transfer_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    to: sway::Expression,
+    amount: sway::Expression,
+) -> Result<sway::Expression, Error> {
+    let amount_type_name = translated_definition.get_expression_type(scope.clone(), &amount)?;
+
+    let amount = if is_untyped_integer_expression(&amount) {
+        amount
+    } else {
+        create_integer_width_conversion_expression(amount, &amount_type_name, 64)
+    };
+
+    // std::asset::transfer(to, AssetId::base(), amount)
+    Ok(sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("std::asset::transfer".into()),
+        generic_parameters: None,
+        parameters: vec![
+            to,
+            create_base_asset_id_expression(),
+            amount,
+        ],
+    }))
+}
+
+/// Creates an expression that gets the bytecode size of a contract identity, which is zero for non-contract addresses.
+pub fn create_code_size_expression(address: sway::Expression) -> sway::Expression {
+    // match x {
+    //     Identity::ContractId(x) => asm(r1, r2: x) { csiz r1 r2; r1: u64 }.as_u256(),
+    //     _ => 0,
+    // }
+    sway::Expression::from(sway::Match {
+        expression: address,
+        branches: vec![
+            sway::MatchBranch {
+                pattern: sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Identity::ContractId".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::Identifier("x".into()),
+                    ],
+                }),
+                value: create_integer_width_conversion_expression(
+                    sway::Expression::from(sway::AsmBlock {
+                        registers: vec![
+                            sway::AsmRegister {
+                                name: "r1".into(),
+                                value: None,
+                            },
+                            sway::AsmRegister {
+                                name: "r2".into(),
+                                value: Some(sway::Expression::Identifier("x".into())),
+                            },
+                        ],
+                        instructions: vec![
+                            sway::AsmInstruction {
+                                op_code: "csiz".into(),
+                                args: vec![
+                                    "r1".into(),
+                                    "r2".into(),
+                                ],
+                            },
+                        ],
+                        final_expression: Some(sway::AsmFinalExpression {
+                            register: "r1".into(),
+                            type_name: Some(sway::TypeName::Identifier {
+                                name: "u64".into(),
+                                generic_parameters: None,
+                            }),
+                        }),
+                    }),
+                    &sway::TypeName::Identifier {
+                        name: "u64".into(),
+                        generic_parameters: None,
+                    },
+                    256,
+                ),
+            },
+            sway::MatchBranch {
+                pattern: sway::Expression::Identifier("_".into()),
+                value: sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+            },
+        ],
+    })
+}
+
+/// Reports a warning for a global that has no Fuel equivalent, unless the `unsupported-global` lint is allowed.
+fn report_unsupported_global(
+    project: &mut Project,
+    translated_definition: &TranslatedDefinition,
+    loc: &solidity::Loc,
+    global_name: &str,
+    translation: &str,
+) {
+    project.report_warning(
+        Lint::UnsupportedGlobal,
+        &translated_definition.path,
+        Some(loc),
+        format!("`{global_name}` has no Fuel equivalent and {translation}"),
+    );
+}
+
+/// Creates a conversion of an unsigned integer value to the unsigned integer type with the supplied bit width.
+///
+/// Widening conversions are lossless, while narrowing conversions are checked. Returns the value unchanged if it does not need to be converted.
+#[inline]
+pub fn create_integer_width_conversion_expression(
+    value: sway::Expression,
+    value_type_name: &sway::TypeName,
+    bits: usize,
+) -> sway::Expression {
+    let Some(value_bits) = value_type_name.uint_bits() else {
+        return value;
+    };
+
+    if value_bits == bits {
+        return value;
+    }
+
+    let type_name = format!("u{bits}");
+
+    // x => x.as_uN()
+    if value_bits < bits {
+        // a + b => (a + b).as_uN()
+        let value = match value {
+            sway::Expression::BinaryExpression(_) => sway::Expression::Tuple(vec![value]),
+            value => value,
+        };
+
+        return sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::from(sway::MemberAccess {
+                expression: value,
+                member: format!("as_{type_name}"),
+            }),
+            generic_parameters: None,
+            parameters: vec![],
+        });
+    }
+
+    // (x) => x
+    let value = match value {
+        sway::Expression::Tuple(mut expressions) if expressions.len() == 1 => expressions.pop().unwrap(),
+        value => value,
+    };
+
+    // x => uN::try_from(x).unwrap()
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier(format!("{type_name}::try_from")),
+                generic_parameters: None,
+                parameters: vec![value],
+            }),
+            member: "unwrap".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![],
+    })
+}
+
+/// Checks if the expression is an integer literal, or an expression whose type is only determined by integer literals.
+///
+/// The types of these expressions are inferred by the Sway compiler, so they never need to be converted.
+pub fn is_untyped_integer_expression(expression: &sway::Expression) -> bool {
+    match expression {
+        sway::Expression::Literal(sway::Literal::DecInt(_) | sway::Literal::HexInt(_)) => true,
+        sway::Expression::Tuple(expressions) if expressions.len() == 1 => is_untyped_integer_expression(&expressions[0]),
+        sway::Expression::UnaryExpression(unary_expression) => is_untyped_integer_expression(&unary_expression.expression),
+
+        sway::Expression::BinaryExpression(binary_expression) => match binary_expression.operator.as_str() {
+            // The type of a shift is the type of the value being shifted
+            "<<" | ">>" => is_untyped_integer_expression(&binary_expression.lhs),
+            _ => is_untyped_integer_expression(&binary_expression.lhs) && is_untyped_integer_expression(&binary_expression.rhs),
+        },
+
+        _ => false,
+    }
+}
+
+/// Gets the storage key that a storage read is performed on, i.e: `storage.x.read()` => `storage.x`
+fn get_storage_read_key(expression: &sway::Expression) -> Option<&sway::Expression> {
+    let sway::Expression::FunctionCall(function_call) = expression else { return None };
+    let sway::Expression::MemberAccess(member_access) = &function_call.function else { return None };
+
+    if member_access.member != "read" || !function_call.parameters.is_empty() {
+        return None;
+    }
+
+    Some(&member_access.expression)
+}
+
+/// Creates an `Identity` for the supplied `b256` address, i.e: `Identity::Address(Address::from(x))`
+fn create_address_identity_expression(value: sway::Expression) -> sway::Expression {
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("Identity::Address".into()),
+        generic_parameters: None,
+        parameters: vec![
+            sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier("Address::from".into()),
+                generic_parameters: None,
+                parameters: vec![value],
+            }),
+        ],
+    })
+}
+
+/// Gets the contract id that an abi cast is performed on, i.e: `abi(I, x.into())` => `x`
+fn get_abi_cast_contract_id(expression: &sway::Expression) -> Option<&sway::Expression> {
+    let sway::Expression::FunctionCall(function_call) = expression else { return None };
+
+    if !matches!(&function_call.function, sway::Expression::Identifier(name) if name == "abi") || function_call.parameters.len() != 2 {
+        return None;
+    }
+
+    let sway::Expression::FunctionCall(into_call) = &function_call.parameters[1] else { return None };
+    let sway::Expression::MemberAccess(member_access) = &into_call.function else { return None };
+
+    if member_access.member != "into" || !into_call.parameters.is_empty() {
+        return None;
+    }
+
+    Some(&member_access.expression)
+}
+
+/// Splits an access to a struct field through a storage key that isn't a path within the storage block
+/// (i.e: `storage.x.get(k).a.b` or `s.a.b` where `s: StorageKey<S>`) into the storage key and its field names.
+///
+/// Sway only allows accessing the fields of a struct in storage through the storage block itself,
+/// so fields of a struct behind any other storage key have to be updated by reading and writing the whole struct.
+fn get_storage_key_field_access(expression: &sway::Expression) -> Option<(&sway::Expression, Vec<String>)> {
+    let mut field_names = vec![];
+    let mut storage_key = expression;
+
+    while let sway::Expression::MemberAccess(member_access) = storage_key {
+        if matches!(&member_access.expression, sway::Expression::Identifier(name) if name == "storage") {
+            return None;
+        }
+
+        field_names.insert(0, member_access.member.clone());
+        storage_key = &member_access.expression;
+    }
+
+    if field_names.is_empty() {
+        return None;
+    }
+
+    Some((storage_key, field_names))
+}
+
+/// Creates the natively checked arithmetic expression that a `SafeMath` function call is equivalent to.
+fn create_safe_math_expression(function_name: &str, lhs: sway::Expression, rhs: sway::Expression) -> Option<sway::Expression> {
+    let operator = match function_name {
+        "add" => "+",
+        "sub" => "-",
+        "mul" => "*",
+        "div" => "/",
+        "mod" => "%",
+        _ => return None,
+    };
+
+    // a.add(b).mul(c) => (a + b) * c
+    let parenthesize = |expression: sway::Expression| match expression {
+        sway::Expression::BinaryExpression(_) | sway::Expression::UnaryExpression(_) => sway::Expression::Tuple(vec![expression]),
+        _ => expression,
+    };
+
+    Some(sway::Expression::from(sway::BinaryExpression {
+        operator: operator.into(),
+        lhs: parenthesize(lhs),
+        rhs: parenthesize(rhs),
     }))
 }
 
@@ -3904,17 +4716,14 @@ pub fn translate_variable_access_expression(
                 return Err(Error::Wrapped(Box::new(std::io::Error::new(
                     std::io::ErrorKind::NotFound,
                     format!(
-                        "{}error: Variable not found in scope: \"{name}\"",
-                        match project.loc_to_line_and_column(&translated_definition.path, &expression.loc()) {
-                            Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                            None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                        }
+                        "{} - error: Variable not found in scope: \"{name}\"",
+                        project.loc_to_file_location_string(&translated_definition.path, &expression.loc())
                     ),
                 ))));
             };
 
             let variable_name = variable.borrow().new_name.clone();
-            let is_storage = variable.borrow().is_storage;
+            let is_storage = variable.borrow().is_storage && !variable.borrow().is_storage_pointer();
 
             Ok((
                 variable,
@@ -3930,21 +4739,66 @@ pub fn translate_variable_access_expression(
         }
 
         solidity::Expression::ArraySubscript(_, expression, Some(index)) => {
-            let index = translate_expression(project, translated_definition, scope.clone(), index.as_ref())?;
+            let mut index = translate_expression(project, translated_definition, scope.clone(), index.as_ref())?;
             let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), expression)?;
             let is_storage = variable.borrow().is_storage;
 
+            let storage_type_name = if is_storage {
+                match translated_definition.get_expression_type(scope.clone(), &expression)? {
+                    sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) } if name == "StorageKey" => {
+                        Some(generic_parameters.entries[0].type_name.clone())
+                    }
+
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+            let is_storage_vec = matches!(&storage_type_name, Some(sway::TypeName::Identifier { name, .. }) if name == "StorageVec");
+
+            // Arrays, vectors and storage vectors are indexed with a u64, storage maps are indexed with their key type
+            let index_bits = match &storage_type_name {
+                Some(sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) }) if name == "StorageMap" => {
+                    generic_parameters.entries[0].type_name.uint_bits()
+                }
+
+                _ if !is_storage || is_storage_vec => Some(64),
+                _ => None,
+            };
+
+            if let Some(bits) = index_bits {
+                if !is_untyped_integer_expression(&index) {
+                    let index_type_name = translated_definition.get_expression_type(scope.clone(), &index)?;
+                    index = create_integer_width_conversion_expression(index, &index_type_name, bits);
+                }
+            }
+
             Ok((
                 variable,
                 if is_storage {
-                    sway::Expression::from(sway::FunctionCall {
+                    let expression = sway::Expression::from(sway::FunctionCall {
                         function: sway::Expression::from(sway::MemberAccess {
                             expression,
                             member: "get".into(),
                         }),
                         generic_parameters: None,
                         parameters: vec![index],
-                    })
+                    });
+
+                    // x[i] => x.get(i).unwrap()
+                    if is_storage_vec {
+                        sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::from(sway::MemberAccess {
+                                expression,
+                                member: "unwrap".into(),
+                            }),
+                            generic_parameters: None,
+                            parameters: vec![],
+                        })
+                    } else {
+                        expression
+                    }
                 } else {
                     sway::Expression::from(sway::ArrayAccess {
                         expression,
@@ -3963,7 +4817,7 @@ pub fn translate_variable_access_expression(
         
             // Check if container is a struct
             if let Some(struct_definition) = translated_definition.structs.iter().find(|s| s.name == container_type_name_string) {
-                let field_name = crate::translate_naming_convention(member.name.as_str(), Case::Snake);
+                let field_name = project.translate_naming_convention(member.name.as_str(), Case::Snake);
         
                 if struct_definition.fields.iter().any(|f| f.name == field_name) {
                     return Ok((
@@ -3976,7 +4830,13 @@ pub fn translate_variable_access_expression(
                 }
             }
         
-            todo!("translate variable {container_type_name_string} member access expression: {expression} - {expression:#?}")
+            // i.e: `x.f()` where `f` is attached with a using directive
+            Err(Error::Wrapped(Box::new(
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("translate variable {container_type_name_string} member access expression: {expression} - {expression:#?}")
+                )
+            )))
         }
 
         solidity::Expression::FunctionCall(_, function, arguments) => {
@@ -4052,7 +4912,74 @@ pub fn create_assignment_expression(
 
     variable.mutation_count += 1;
 
+    // storage.x.get(k).a = v => { let mut _x = storage.x.get(k).read(); _x.a = v; storage.x.get(k).write(_x); }
     if variable.is_storage {
+        if let Some((storage_key, field_names)) = get_storage_key_field_access(expression) {
+            let value_name = format!("_{}", variable.new_name);
+
+            let mut lhs = sway::Expression::Identifier(value_name.clone());
+
+            for field_name in field_names {
+                lhs = sway::Expression::from(sway::MemberAccess {
+                    expression: lhs,
+                    member: field_name,
+                });
+            }
+
+            let assignment = match operator {
+                // Sway doesn't have these operators, so we have to implement them manually.
+                "%=" | "&=" | "|=" | "^=" => sway::Expression::from(sway::BinaryExpression {
+                    operator: "=".into(),
+                    lhs: lhs.clone(),
+                    rhs: sway::Expression::from(sway::BinaryExpression {
+                        operator: operator.trim_end_matches('=').into(),
+                        lhs,
+                        rhs: rhs.clone(),
+                    }),
+                }),
+
+                _ => sway::Expression::from(sway::BinaryExpression {
+                    operator: operator.into(),
+                    lhs,
+                    rhs: rhs.clone(),
+                }),
+            };
+
+            variable.read_count += 1;
+
+            return Ok(sway::Expression::from(sway::Block {
+                statements: vec![
+                    sway::Statement::from(sway::Let {
+                        pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+                            is_mutable: true,
+                            name: value_name.clone(),
+                        }),
+                        type_name: None,
+                        value: sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::from(sway::MemberAccess {
+                                expression: storage_key.clone(),
+                                member: "read".into(),
+                            }),
+                            generic_parameters: None,
+                            parameters: vec![],
+                        }),
+                    }),
+                    sway::Statement::from(assignment),
+                    sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::from(sway::MemberAccess {
+                            expression: storage_key.clone(),
+                            member: "write".into(),
+                        }),
+                        generic_parameters: None,
+                        parameters: vec![
+                            sway::Expression::Identifier(value_name),
+                        ],
+                    })),
+                ],
+                final_expr: None,
+            }));
+        }
+
         Ok(sway::Expression::from(sway::FunctionCall {
             function: sway::Expression::from(sway::MemberAccess {
                 expression: expression.clone(),
@@ -4111,10 +5038,9 @@ pub fn create_assignment_expression(
         }))
     } else {
         match &variable.type_name {
-            sway::TypeName::Identifier { name, .. } if name == "Vec" => {
-                let sway::Expression::ArrayAccess(array_access) = expression else {
-                    panic!("Expected array access expression, found {expression:#?}");
-                };
+            // v[i] = x => v.set(i, x)
+            sway::TypeName::Identifier { name, .. } if name == "Vec" && matches!(expression, sway::Expression::ArrayAccess(_)) => {
+                let sway::Expression::ArrayAccess(array_access) = expression else { unreachable!() };
 
                 Ok(sway::Expression::from(sway::FunctionCall {
                     function: sway::Expression::from(sway::MemberAccess {
@@ -4160,7 +5086,7 @@ pub fn create_assignment_expression(
             }
 
             _ => match operator {
-                "&=" | "|=" | "^=" => {
+                "%=" | "&=" | "|=" | "^=" => {
                     //
                     // NOTE:
                     // Sway doesn't have these operators, so we have to implement them manually.
@@ -4198,15 +5124,59 @@ pub fn translate_assignment_expression(
     lhs: &solidity::Expression,
     rhs: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
-    let rhs = match operator {
+    let rhs_loc = rhs.loc();
+
+    let mut rhs = match operator {
         "=" => translate_pre_or_post_operator_value_expression(project, translated_definition, scope.clone(), rhs)?,
         _ => translate_expression(project, translated_definition, scope.clone(), rhs)?,
     };
 
-    let rhs_type_name = translated_definition.get_expression_type(scope.clone(), &rhs)?;
+    let mut rhs_type_name = translated_definition.get_expression_type(scope.clone(), &rhs)?;
     
     let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), lhs)?;
 
+    // Convert values assigned between signed and unsigned integers
+    let variable_type_name = variable.borrow().type_name.clone();
+
+    if (rhs_type_name.is_int() && variable_type_name.is_uint()) || (rhs_type_name.is_uint() && variable_type_name.is_int()) {
+        rhs = create_integer_sign_conversion_expression(project, translated_definition, &rhs_loc, rhs, &rhs_type_name, &variable_type_name);
+        rhs_type_name = variable_type_name.clone();
+    }
+
+    // Convert the operands of compound assignments between unsigned integers of different widths
+    if operator != "=" && rhs_type_name.uint_bits().is_some() && !is_untyped_integer_expression(&rhs) {
+        let value_type_name = match variable_type_name.uint_bits() {
+            Some(_) => Some(variable_type_name.clone()),
+
+            // storage.x.get(k) += v => storage.x.get(k).write(storage.x.get(k).read() + v)
+            None if variable.borrow().is_storage => {
+                let value = sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::from(sway::MemberAccess {
+                        expression: expression.clone(),
+                        member: "read".into(),
+                    }),
+                    generic_parameters: None,
+                    parameters: vec![],
+                });
+
+                Some(translated_definition.get_expression_type(scope.clone(), &value)?)
+            }
+
+            None => None,
+        };
+
+        let bits = match operator {
+            // The shift amount is always a u64
+            "<<=" | ">>=" => Some(64),
+            _ => value_type_name.and_then(|t| t.uint_bits()),
+        };
+
+        if let Some(bits) = bits {
+            rhs = create_integer_width_conversion_expression(rhs, &rhs_type_name, bits);
+            rhs_type_name = translated_definition.get_expression_type(scope.clone(), &rhs)?;
+        }
+    }
+
     create_assignment_expression(project, translated_definition, operator, &expression, variable, &rhs, &rhs_type_name)
 }
 
@@ -4293,7 +5263,7 @@ pub fn translate_post_operator_expression(
 
     variable.read_count += 1;
 
-    let variable_name = if variable.is_storage {
+    let variable_name = if variable.is_storage && !variable.is_storage_pointer() {
         variable.new_name.clone()
     } else {
         format!("_{}", variable.new_name)
@@ -4326,6 +5296,251 @@ pub fn translate_post_operator_expression(
     }))
 }
 
+/// Ensures the toplevel function that stands in for `new Contract(...)` is declared, returning its name.
+///
+/// Fuel contracts cannot deploy other contracts, so the function takes the `ContractId` of a contract that was deployed
+/// ahead of time and forwards the constructor arguments (and coins, if the constructor is payable) to its `constructor` function.
+fn create_contract_deployment_function(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    contract_name: &str,
+) -> String {
+    let function_name = format!("new_{}", project.translate_naming_convention(contract_name, Case::Snake));
+
+    if translated_definition.functions.iter().any(|f| f.name == function_name) {
+        return function_name;
+    }
+
+    let abi = project.find_definition_with_abi(contract_name).unwrap().abi.clone().unwrap();
+
+    // Ensure the ABI is added to the current definition
+    if !translated_definition.abis.iter().any(|a| a.name == abi.name) {
+        translated_definition.abis.push(abi.clone());
+    }
+
+    let constructor = abi.functions.iter().find(|f| f.name == "constructor");
+    let is_payable = constructor.is_some_and(|f| f.attributes.iter().flat_map(|a| a.attributes.iter()).any(|a| a.name == "payable"));
+    let variable_name = project.translate_naming_convention(contract_name, Case::Snake);
+
+    let mut parameters = vec![
+        sway::Parameter {
+            name: "contract_id".into(),
+            type_name: Some(sway::TypeName::Identifier {
+                name: "ContractId".into(),
+                generic_parameters: None,
+            }),
+            ..Default::default()
+        },
+    ];
+
+    if is_payable {
+        parameters.push(sway::Parameter {
+            name: "coins".into(),
+            type_name: Some(sway::TypeName::Identifier {
+                name: "u64".into(),
+                generic_parameters: None,
+            }),
+            ..Default::default()
+        });
+    }
+
+    let constructor_parameters = constructor.map(|f| f.parameters.entries.clone()).unwrap_or_default();
+    parameters.extend(constructor_parameters.iter().cloned());
+
+    // let contract = abi(Contract, contract_id.into());
+    let mut statements = vec![
+        sway::Statement::from(sway::Let {
+            pattern: sway::LetPattern::from(sway::LetIdentifier {
+                is_mutable: false,
+                name: variable_name.clone(),
+            }),
+            type_name: None,
+            value: sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier("abi".into()),
+                generic_parameters: None,
+                parameters: vec![
+                    sway::Expression::Identifier(abi.name.clone()),
+                    sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::from(sway::MemberAccess {
+                            expression: sway::Expression::Identifier("contract_id".into()),
+                            member: "into".into(),
+                        }),
+                        generic_parameters: None,
+                        parameters: vec![],
+                    }),
+                ],
+            }),
+        }),
+    ];
+
+    // contract.constructor(...) or contract.constructor { coins: coins }(...)
+    if constructor.is_some() {
+        let function = sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::Identifier(variable_name.clone()),
+            member: "constructor".into(),
+        });
+
+        let parameters = constructor_parameters.iter().map(|p| sway::Expression::Identifier(p.name.clone())).collect();
+
+        statements.push(sway::Statement::from(if is_payable {
+            sway::Expression::from(sway::FunctionCallBlock {
+                function,
+                generic_parameters: None,
+                fields: vec![
+                    sway::ConstructorField {
+                        name: "coins".into(),
+                        value: sway::Expression::Identifier("coins".into()),
+                    },
+                ],
+                parameters,
+            })
+        } else {
+            sway::Expression::from(sway::FunctionCall {
+                function,
+                generic_parameters: None,
+                parameters,
+            })
+        }));
+    }
+
+    let return_type = sway::TypeName::Identifier {
+        name: abi.name.clone(),
+        generic_parameters: None,
+    };
+
+    let parameters = sway::ParameterList { entries: parameters };
+
+    translated_definition.functions.push(sway::Function {
+        comment: None,
+        attributes: None,
+        is_public: false,
+        name: function_name.clone(),
+        generic_parameters: None,
+        parameters: parameters.clone(),
+        return_type: Some(return_type.clone()),
+        body: Some(sway::Block {
+            statements,
+            final_expr: Some(sway::Expression::Identifier(variable_name)),
+        }),
+    });
+
+    translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(TranslatedFunction {
+        old_name: String::new(),
+        new_name: function_name.clone(),
+        parameters,
+        modifiers: vec![],
+        return_type: Some(return_type),
+    })));
+
+    function_name
+}
+
+/// Creates a `Vec` of the specified length filled with default values, i.e: `new uint[](length)`
+pub fn create_vec_with_length_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    type_name: &sway::TypeName,
+    length: sway::Expression,
+) -> Result<sway::Expression, Error> {
+    // {
+    //     let mut v = Vec::with_capacity(length);
+    //     let mut i = 0;
+    //     while i < length {
+    //         v.push(0);
+    //         i += 1;
+    //     }
+    //     v
+    // }
+
+    let sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) } = type_name else {
+        panic!("Expected Vec type name, found {type_name:#?}");
+    };
+
+    if name != "Vec" {
+        panic!("Expected Vec type name, found {type_name}");
+    }
+
+    let element_type_name = &generic_parameters.entries.first().unwrap().type_name;
+
+    // The capacity of a `Vec` is always a u64
+    let capacity = if is_untyped_integer_expression(&length) {
+        length.clone()
+    } else {
+        let length_type_name = translated_definition.get_expression_type(scope.clone(), &length)?;
+        create_integer_width_conversion_expression(length.clone(), &length_type_name, 64)
+    };
+
+    Ok(sway::Expression::from(sway::Block {
+        statements: vec![
+            // let mut v = Vec::with_capacity(length);
+            sway::Statement::from(sway::Let {
+                pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+                    is_mutable: true,
+                    name: "v".into(),
+                }),
+                type_name: Some(type_name.clone()),
+                value: sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Vec::with_capacity".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        capacity,
+                    ],
+                }),
+            }),
+
+            // let mut i = 0;
+            sway::Statement::from(sway::Let {
+                pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+                    is_mutable: true,
+                    name: "i".into(),
+                }),
+                type_name: None,
+                value: sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+            }),
+
+            // while i < length {
+            //     v.push(0);
+            //     i += 1;
+            // }
+            sway::Statement::from(sway::Expression::from(sway::While {
+                // i < length
+                condition: sway::Expression::from(sway::BinaryExpression {
+                    operator: "<".into(),
+                    lhs: sway::Expression::Identifier("i".into()),
+                    rhs: length.clone(),
+                }),
+
+                body: sway::Block {
+                    statements: vec![
+                        // v.push(0);
+                        sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::from(sway::MemberAccess {
+                                expression: sway::Expression::Identifier("v".into()),
+                                member: "push".into(),
+                            }),
+                            generic_parameters: None,
+                            parameters: vec![
+                                create_value_expression(translated_definition, scope.clone(), element_type_name, None),
+                            ],
+                        })),
+
+                        // i += 1;
+                        sway::Statement::from(sway::Expression::from(sway::BinaryExpression {
+                            operator: "+=".into(),
+                            lhs: sway::Expression::Identifier("i".into()),
+                            rhs: sway::Expression::from(sway::Literal::DecInt(BigUint::one())),
+                        })),
+                    ],
+                    final_expr: None,
+                }
+            }))
+        ],
+
+        // v
+        final_expr: Some(sway::Expression::Identifier("v".into())),
+    }))
+}
+
 #[inline]
 pub fn translate_new_expression(
     project: &mut Project,
@@ -4361,12 +5576,9 @@ pub fn translate_new_expression(
                         value,
                     }),
 
-                    arg => println!(
-                        "{}WARNING: unsupported function call block arg: {arg}",
-                        match project.loc_to_line_and_column(&translated_definition.path, &block_arg.loc()) {
-                            Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                            None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                        }
+                    arg => emitln!(project.emitter, 
+                        "{} - WARNING: unsupported function call block arg: {arg}",
+                        project.loc_to_file_location_string(&translated_definition.path, &block_arg.loc())
                     ),
                 }
             }
@@ -4393,34 +5605,68 @@ pub fn translate_new_expression(
     match expr.as_ref() {
         solidity::Expression::Variable(solidity::Identifier {name, ..}) => {
             if project.find_definition_with_abi(name).is_some() {
-                // new Contract(...) => /*unsupported: new Contract(...); using:*/ abi(Contract, Identity::ContractId(ContractId::from(ZERO_B256)))
+                // new Contract(...) => new_contract(/*unsupported: new Contract(...); using:*/ ContractId::from(ZERO_B256), ...)
+
+                let deployment = if block_fields.is_some() { expression.to_string() } else { format!("new {expression}") };
+
+                let function_name = create_contract_deployment_function(project, translated_definition, name);
+
+                project.report_warning(Lint::ContractCreation, &translated_definition.path, Some(&expression.loc()), format!("`{deployment}` cannot deploy a contract on Fuel, `{name}` must be deployed ahead of time and its `ContractId` supplied to `{function_name}`"));
 
                 translated_definition.ensure_use_declared("std::constants::ZERO_B256");
 
-                return Ok(sway::Expression::Commented(
-                    format!("unsupported: new {expression}; using:"),
-                    Box::new(sway::Expression::from(sway::FunctionCall {
-                        function: sway::Expression::Identifier("abi".into()),
-                        generic_parameters: None,
-                        parameters: vec![
-                            sway::Expression::Identifier(name.clone()),
-                            sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::Identifier("Identity::ContractId".into()),
-                                generic_parameters: None,
-                                parameters: vec![
-                                    sway::Expression::from(sway::FunctionCall {
-                                        function: sway::Expression::Identifier("ContractId::from".into()),
-                                        generic_parameters: None,
-                                        parameters: vec![
-                                            sway::Expression::Identifier("ZERO_B256".into()),
-                                        ],
-                                    }),
-                                ],
-                            }),
-                        ],
-                    }))
-                ));
+                let mut parameters = vec![
+                    sway::Expression::Commented(
+                        format!("unsupported: {deployment}; using:"),
+                        Box::new(sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("ContractId::from".into()),
+                            generic_parameters: None,
+                            parameters: vec![
+                                sway::Expression::Identifier("ZERO_B256".into()),
+                            ],
+                        })),
+                    ),
+                ];
+
+                // Forward the coins of the deployment to the constructor if it is payable
+                let function_has_coins = translated_definition.functions.iter()
+                    .find(|f| f.name == function_name)
+                    .is_some_and(|f| f.parameters.entries.iter().any(|p| p.name == "coins"));
+
+                if function_has_coins {
+                    parameters.push(
+                        block_fields.iter()
+                            .flatten()
+                            .find(|f| f.name == "coins")
+                            .map(|f| f.value.clone())
+                            .unwrap_or_else(|| sway::Expression::from(sway::Literal::DecInt(BigUint::zero())))
+                    );
+                }
+
+                parameters.extend(args);
+
+                *translated_definition.function_call_counts.entry(function_name.clone()).or_insert(0) += 1;
+
+                return Ok(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier(function_name),
+                    generic_parameters: None,
+                    parameters,
+                }));
+            }
+        }
+
+        // new T[](length) => { let mut v = Vec::with_capacity(length); ... v }
+        solidity::Expression::ArraySubscript(_, _, None) => {
+            if block_fields.is_some() {
+                panic!("Invalid new array expression: expected no block args, found {block_fields:#?}");
+            }
+
+            if args.len() != 1 {
+                panic!("Invalid new array expression: expected 1 argument, found {}", args.len());
             }
+
+            let type_name = translate_type_name(project, translated_definition, &expr, false, false)?;
+            return create_vec_with_length_expression(translated_definition, scope.clone(), &type_name, args[0].clone());
         }
 
         solidity::Expression::Type(_, type_name) => match &type_name {
@@ -4653,8 +5899,89 @@ pub fn translate_delete_expression(
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     let (variable, expr) = translate_variable_access_expression(project, translated_definition, scope.clone(), expression)?;
-    let type_name = variable.borrow().type_name.clone();
-    
-    let value = create_value_expression(translated_definition, scope.clone(), &type_name, None);
-    create_assignment_expression(project, translated_definition, "=", &expr, variable, &value, &type_name)
+
+    if !variable.borrow().is_storage {
+        let type_name = translated_definition.get_expression_type(scope.clone(), &expr)?;
+        let value = create_value_expression(translated_definition, scope.clone(), &type_name, None);
+
+        // v[i] => v.set(i, 0)
+        if let sway::Expression::ArrayAccess(_) = &expr {
+            return create_assignment_expression(project, translated_definition, "=", &expr, variable, &value, &type_name);
+        }
+
+        // x => x = 0
+        variable.borrow_mut().mutation_count += 1;
+
+        return Ok(sway::Expression::from(sway::BinaryExpression {
+            operator: "=".into(),
+            lhs: expr,
+            rhs: value,
+        }));
+    }
+
+    variable.borrow_mut().mutation_count += 1;
+
+    let create_member_call = |expression: sway::Expression, member: &str, parameters: Vec<sway::Expression>| {
+        sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::from(sway::MemberAccess {
+                expression,
+                member: member.into(),
+            }),
+            generic_parameters: None,
+            parameters,
+        })
+    };
+
+    // Get the storage type of the key, i.e: `StorageKey<T>` => `T`
+    let get_storage_type_name = |translated_definition: &mut TranslatedDefinition, expression: &sway::Expression| -> Result<Option<sway::TypeName>, Error> {
+        match translated_definition.get_expression_type(scope.clone(), expression)? {
+            sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) } if name == "StorageKey" => {
+                Ok(Some(generic_parameters.entries[0].type_name.clone()))
+            }
+
+            _ => Ok(None),
+        }
+    };
+
+    // Check for an element of a storage map or vector, i.e: `storage.x.get(k)`
+    if let sway::Expression::FunctionCall(function_call) = &expr {
+        if let sway::Expression::MemberAccess(member_access) = &function_call.function {
+            if member_access.member == "get" && function_call.parameters.len() == 1 {
+                let container = member_access.expression.clone();
+                let index = function_call.parameters[0].clone();
+
+                match get_storage_type_name(translated_definition, &container)? {
+                    // storage.x.get(k) => storage.x.remove(k)
+                    Some(sway::TypeName::Identifier { name, .. }) if name == "StorageMap" => {
+                        return Ok(create_member_call(container, "remove", vec![index]));
+                    }
+
+                    // storage.x.get(i) => storage.x.set(i, 0)
+                    Some(sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) }) if name == "StorageVec" => {
+                        let value = create_value_expression(translated_definition, scope.clone(), &generic_parameters.entries[0].type_name, None);
+                        return Ok(create_member_call(container, "set", vec![index, value]));
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let Some(type_name) = get_storage_type_name(translated_definition, &expr)? else {
+        panic!("Expected storage key expression, found {} - {expr:#?}", sway::TabbedDisplayer(&expr));
+    };
+
+    match &type_name {
+        // storage.x => storage.x.clear()
+        sway::TypeName::Identifier { name, .. } if matches!(name.as_str(), "StorageVec" | "StorageString" | "StorageBytes") => {
+            Ok(create_member_call(expr, "clear", vec![]))
+        }
+
+        // storage.x => storage.x.write(0)
+        _ => {
+            let value = create_value_expression(translated_definition, scope.clone(), &type_name, None);
+            Ok(create_member_call(expr, "write", vec![value]))
+        }
+    }
 }
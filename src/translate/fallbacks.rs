@@ -0,0 +1,102 @@
+use super::{ItemOriginKind, TranslatedDefinition};
+use crate::{project::{Lint, Project}, sway};
+
+/// Translates the `fallback` and `receive` functions of a contract to their closest Sway equivalents.
+///
+/// A `fallback()` function is moved out of the abi into a `#[fallback]` function, which Sway calls when no abi function matches the call.
+/// A `receive()` function is kept as an explicit `receive` abi function, since Fuel has no plain value transfers that call into a contract.
+pub fn translate_fallback_functions(project: &mut Project, translated_definition: &mut TranslatedDefinition) {
+    let fallback_function = translated_definition.find_contract_impl()
+        .and_then(|i| i.items.iter().find_map(|item| match item {
+            sway::ImplItem::Function(f) if f.name == "fallback" => Some(f.clone()),
+            _ => None,
+        }));
+
+    if let Some(function) = fallback_function {
+        let loc = translated_definition.get_item_origin(ItemOriginKind::AbiFunction, "fallback").map(|origin| origin.loc);
+
+        // `fallback(bytes calldata) returns (bytes memory)` has no Sway equivalent, so keep it as a regular abi function
+        if !function.parameters.entries.is_empty() || function.return_type.is_some() {
+            project.report_warning(
+                Lint::Fallback,
+                &translated_definition.path,
+                loc.as_ref(),
+                format!(
+                    "the `fallback` function of `{}` takes or returns its calldata, which `#[fallback]` functions cannot do; it was kept as a `fallback` abi function",
+                    translated_definition.name,
+                ),
+            );
+        } else {
+            remove_contract_function(translated_definition, "fallback");
+
+            let attributes = create_fallback_attributes(function.attributes.as_ref());
+
+            // Mark the toplevel implementation as the fallback function if the contract impl's function is a wrapper around it
+            if let Some(toplevel_function) = translated_definition.functions.iter_mut().find(|f| f.name == "fallback" && f.parameters.entries.is_empty()) {
+                toplevel_function.attributes = attributes;
+            } else {
+                translated_definition.functions.push(sway::Function {
+                    comment: None,
+                    attributes,
+                    is_public: false,
+                    name: "fallback".into(),
+                    generic_parameters: None,
+                    parameters: sway::ParameterList::default(),
+                    return_type: None,
+                    body: function.body,
+                });
+            }
+
+            project.report_warning(
+                Lint::Fallback,
+                &translated_definition.path,
+                loc.as_ref(),
+                format!(
+                    "the `fallback` function of `{}` was translated to a `#[fallback]` function, which is called when no abi function matches the call",
+                    translated_definition.name,
+                ),
+            );
+        }
+    }
+
+    if translated_definition.find_contract_impl().is_some_and(|i| i.items.iter().any(|item| matches!(item, sway::ImplItem::Function(f) if f.name == "receive"))) {
+        let loc = translated_definition.get_item_origin(ItemOriginKind::AbiFunction, "receive").map(|origin| origin.loc);
+
+        project.report_warning(
+            Lint::Fallback,
+            &translated_definition.path,
+            loc.as_ref(),
+            format!(
+                "the `receive` function of `{}` was translated to a payable `receive` abi function; coins transferred to the contract without calling it are not handled by it",
+                translated_definition.name,
+            ),
+        );
+    }
+}
+
+/// Removes a function from the abi and the contract impl.
+fn remove_contract_function(translated_definition: &mut TranslatedDefinition, name: &str) {
+    if let Some(contract_impl) = translated_definition.find_contract_impl_mut() {
+        contract_impl.items.retain(|item| !matches!(item, sway::ImplItem::Function(f) if f.name == name));
+    }
+
+    if let Some(abi) = translated_definition.abi.as_mut() {
+        abi.functions.retain(|f| f.name != name);
+    }
+}
+
+/// Creates the attributes of a `#[fallback]` function from the attributes of the translated `fallback` function.
+///
+/// A `#[fallback]` function always accepts forwarded coins, so it has no `payable` attribute.
+fn create_fallback_attributes(attributes: Option<&sway::AttributeList>) -> Option<sway::AttributeList> {
+    let mut attribute_list = attributes.cloned().unwrap_or_default();
+
+    attribute_list.attributes.retain(|a| a.name != "payable");
+
+    attribute_list.attributes.push(sway::Attribute {
+        name: "fallback".into(),
+        parameters: None,
+    });
+
+    Some(attribute_list)
+}
@@ -1,39 +1,37 @@
 use super::{
-    create_value_expression, finalize_block_translation, translate_block, translate_expression,
-    translate_return_type_name, translate_statement, translate_storage_name, translate_type_name,
-    TranslatedDefinition, TranslatedFunction, TranslatedModifier, TranslatedVariable,
-    TranslationScope,
+    create_value_expression, evaluate_before_statements, finalize_block_translation, get_rename_reason, join_comments, translate_block,
+    translate_expression, translate_natspec, translate_parameter_type_name, translate_return_type_name, translate_statement,
+    translate_storage_name, translate_type_name, ItemOriginKind, RenameReason, TranslatedDefinition, TranslatedFunction, TranslatedModifier,
+    TranslatedVariable, TranslationScope,
 };
-use crate::{project::Project, sway, Error};
+use crate::{emitter::emitln, project::{Lint, Project}, sway, Error};
 use convert_case::Case;
-use solang_parser::pt as solidity;
+use solang_parser::{helpers::CodeLocation, pt as solidity};
 use std::{cell::RefCell, rc::Rc};
 
 #[inline]
 pub fn translate_function_name(
-    _project: &mut Project,
+    project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
     function_definition: &solidity::FunctionDefinition,
 ) -> String {
-    // Generate the function signature
-    let mut signature = function_definition.name.as_ref().map(|i| i.name.clone()).unwrap_or_default();
-    
-    signature.push('(');
-    
-    for (i, (_, parameter)) in function_definition.params.iter().enumerate() {
-        signature = format!(
-            "{signature}{}{}",
-            if i > 0 { "," } else { "" },
-            parameter.as_ref().unwrap().ty,
-        );
-    }
-
-    signature.push(')');
+    let signature = get_function_name_signature(function_definition);
 
     // Add the translated function name to the function names mapping if we haven't already
     if !translated_definition.function_names.contains_key(&signature) {
         let old_name = function_definition.name.as_ref().map(|i| i.name.clone()).unwrap_or_default();
-        let mut new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
+        let mut new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
+        let is_public = is_public_function(function_definition);
+
+        if is_public {
+            if !translated_definition.public_function_names.contains(&new_name) {
+                translated_definition.public_function_names.push(new_name.clone());
+            }
+        }
+        // Prefix internal contract functions with an underscore when their name is already used by an abi function
+        else if matches!(translated_definition.kind, Some(solidity::ContractTy::Contract(_))) && translated_definition.public_function_names.contains(&new_name) {
+            new_name = format!("_{new_name}");
+        }
 
         // Increase the function name count
         let count = translated_definition.function_name_counts.entry(new_name.clone()).or_insert(0);
@@ -44,12 +42,51 @@ pub fn translate_function_name(
             new_name = format!("{new_name}_{}", *count);
         }
 
+        // Keep track of renamed functions, referring to functions that were not only translated to the naming convention by their signature
+        match get_rename_reason(project, old_name.as_str(), new_name.as_str(), Case::Snake) {
+            Some(RenameReason::NamingConvention) => {
+                translated_definition.add_renamed_identifier(old_name.as_str(), new_name.as_str(), RenameReason::NamingConvention);
+            }
+
+            // Colliding functions that share their name with a previous function are overloads
+            Some(RenameReason::Collision) if translated_definition.function_names.keys().any(|s| s.starts_with(format!("{old_name}(").as_str())) => {
+                translated_definition.add_renamed_identifier(signature.as_str(), new_name.as_str(), RenameReason::Overload);
+            }
+
+            Some(reason) => translated_definition.add_renamed_identifier(signature.as_str(), new_name.as_str(), reason),
+            None => {}
+        }
+
         translated_definition.function_names.insert(signature.clone(), new_name);
     }
 
     translated_definition.function_names.get(&signature).unwrap().clone()
 }
 
+/// Gets the signature that a function is keyed by in the function names of a translated definition, i.e: `transfer(address,uint256)`.
+pub fn get_function_name_signature(function_definition: &solidity::FunctionDefinition) -> String {
+    let mut signature = function_definition.name.as_ref().map(|i| i.name.clone()).unwrap_or_default();
+    
+    signature.push('(');
+    
+    for (i, (_, parameter)) in function_definition.params.iter().enumerate() {
+        signature = format!(
+            "{signature}{}{}",
+            if i > 0 { "," } else { "" },
+            parameter.as_ref().unwrap().ty,
+        );
+    }
+
+    signature.push(')');
+    signature
+}
+
+/// Checks if a function is part of a contract's abi, i.e: it is `public` or `external`, or it is a constructor, fallback or receive function.
+pub fn is_public_function(function_definition: &solidity::FunctionDefinition) -> bool {
+    matches!(function_definition.ty, solidity::FunctionTy::Constructor | solidity::FunctionTy::Fallback | solidity::FunctionTy::Receive)
+        || function_definition.attributes.iter().any(|x| matches!(x, solidity::FunctionAttribute::Visibility(solidity::Visibility::External(_) | solidity::Visibility::Public(_))))
+}
+
 #[inline]
 pub fn translate_function_declaration(
     project: &mut Project,
@@ -82,8 +119,8 @@ pub fn translate_function_declaration(
         let Some(parameter_identifier) = p.name.as_ref() else { continue };
         
         let old_name = parameter_identifier.name.clone();
-        let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let type_name = translate_type_name(project, translated_definition, &p.ty, false, true);
+        let new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
+        let type_name = translate_type_name(project, translated_definition, &p.ty, false, true)?;
 
         scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
             old_name,
@@ -93,34 +130,24 @@ pub fn translate_function_declaration(
         })));
     }
 
-    let mut constructor_calls = vec![];
     let mut modifiers = vec![];
     
-    // Translate the function's constructor/modifier invocations
+    // Translate the function's modifier invocations
     for attr in function_definition.attributes.iter() {
         let solidity::FunctionAttribute::BaseOrModifier(_, base) = attr else { continue };
 
         let old_name = base.name.identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".");
-        let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-
-        let parameters = base.args.as_ref()
-            .map(|args| args.iter().map(|a| translate_expression(project, translated_definition, scope.clone(), a)).collect::<Result<Vec<_>, _>>())
-            .unwrap_or_else(|| Ok(vec![]))?;
+        let new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
 
-        // Check to see if base is a constructor call
+        // Base constructor invocations are translated along with the constructors of the other base contracts by `translate_base_constructor_calls`
         if project.find_definition_with_abi(old_name.as_str()).is_some() {
-            let prefix = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-            let name = format!("{prefix}_constructor");
-            
-            constructor_calls.push(sway::FunctionCall {
-                function: sway::Expression::Identifier(name),
-                generic_parameters: None,
-                parameters,
-            });
-
             continue;
         }
 
+        let parameters = base.args.as_ref()
+            .map(|args| args.iter().map(|a| translate_expression(project, translated_definition, scope.clone(), a)).collect::<Result<Vec<_>, _>>())
+            .unwrap_or_else(|| Ok(vec![]))?;
+
         // Add the base to the modifiers list
         modifiers.push(sway::FunctionCall {
             function: sway::Expression::Identifier(new_name),
@@ -134,8 +161,8 @@ pub fn translate_function_declaration(
 
     for (_, parameter) in function_definition.params.iter() {
         let old_name = parameter.as_ref().unwrap().name.as_ref().map(|n| n.name.clone()).unwrap_or("_".into());
-        let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let mut type_name = translate_type_name(project, translated_definition, &parameter.as_ref().unwrap().ty, false, true);
+        let new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
+        let mut type_name = translate_parameter_type_name(project, translated_definition, parameter.as_ref().unwrap())?;
 
         // Check if the parameter's type is an ABI
         if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
@@ -160,20 +187,19 @@ pub fn translate_function_declaration(
         old_name,
         new_name,
         parameters,
-        constructor_calls,
         modifiers,
         return_type: if function_definition.returns.is_empty() {
             None
         } else {
             Some(if function_definition.returns.len() == 1 {
-                let type_name = translate_type_name(project, translated_definition, &function_definition.returns[0].1.as_ref().unwrap().ty, false, true);
+                let type_name = translate_type_name(project, translated_definition, &function_definition.returns[0].1.as_ref().unwrap().ty, false, true)?;
                 translate_return_type_name(project, translated_definition, type_name)
             } else {
                 sway::TypeName::Tuple {
                     type_names: function_definition.returns.iter().map(|(_, p)| {
-                        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true);
-                        translate_return_type_name(project, translated_definition, type_name)
-                    }).collect(),
+                        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true)?;
+                        Ok(translate_return_type_name(project, translated_definition, type_name))
+                    }).collect::<Result<Vec<_>, Error>>()?,
                 }
             })
         },
@@ -189,7 +215,7 @@ pub fn translate_modifier_definition(
     function_definition: &solidity::FunctionDefinition,
 ) -> Result<(), Error> {
     let old_name = function_definition.name.as_ref().unwrap().name.clone();
-    let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
+    let new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
 
     let mut modifier = TranslatedModifier {
         old_name,
@@ -198,6 +224,7 @@ pub fn translate_modifier_definition(
         has_underscore: false,
         pre_body: None,
         post_body: None,
+        captured_parameters: sway::ParameterList::default(),
     };
 
     let scope = Rc::new(RefCell::new(TranslationScope {
@@ -207,8 +234,8 @@ pub fn translate_modifier_definition(
 
     for (_, p) in function_definition.params.iter() {
         let old_name = p.as_ref().unwrap().name.as_ref().unwrap().name.clone();
-        let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true);
+        let new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
+        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true)?;
 
         modifier.parameters.entries.push(sway::Parameter {
             name: new_name.clone(),
@@ -230,6 +257,7 @@ pub fn translate_modifier_definition(
 
     let mut current_body: &mut Option<sway::Block> = &mut modifier.pre_body;
     let mut current_scope = Rc::new(RefCell::new(scope.borrow().clone()));
+    let mut pre_scope = None;
 
     let mut has_pre_storage_read = false;
     let mut has_pre_storage_write = false;
@@ -276,8 +304,14 @@ pub fn translate_modifier_definition(
                     finalize_block_translation(project, current_scope.clone(), block)?;
                 }
 
+                // Translate the post_body block in a child scope of the pre_body block so it can access the variables declared before the underscore statement
+                pre_scope = Some(current_scope.clone());
+
                 current_body = &mut modifier.post_body;
-                current_scope = Rc::new(RefCell::new(scope.borrow().clone()));
+                current_scope = Rc::new(RefCell::new(TranslationScope {
+                    parent: Some(current_scope.clone()),
+                    ..Default::default()
+                }));
 
                 has_storage_read = &mut has_post_storage_read;
                 has_storage_write = &mut has_post_storage_write;
@@ -305,7 +339,7 @@ pub fn translate_modifier_definition(
         // If the sway statement is a variable declaration, keep track of its statement index
         if let Some(sway::Statement::Let(sway_variable)) = block.statements.last() {
             let store_variable_statement_index = |id: &sway::LetIdentifier| {
-                let scope = scope.borrow_mut();
+                let scope = current_scope.borrow_mut();
                 let scope_entry = scope.variables.iter().rev().find(|v| v.borrow().new_name == id.name).unwrap();
                 scope_entry.borrow_mut().statement_index = Some(statement_index);
             };
@@ -363,18 +397,73 @@ pub fn translate_modifier_definition(
         panic!("Malformed modifier missing underscore statement: {}", modifier.old_name);
     }
 
+    // Capture the variables declared in the pre_body block that are used in the post_body block
+    if let (Some(pre_body), Some(post_body), Some(pre_scope)) = (modifier.pre_body.as_mut(), modifier.post_body.as_ref(), pre_scope.as_ref()) {
+        let mut declared_names = vec![];
+
+        for statement in pre_body.statements.iter() {
+            let sway::Statement::Let(sway::Let { pattern, .. }) = statement else { continue };
+
+            match pattern {
+                sway::LetPattern::Identifier(id) => declared_names.push(id.name.clone()),
+                sway::LetPattern::Tuple(ids) => declared_names.extend(ids.iter().map(|id| id.name.clone())),
+            }
+        }
+
+        let mut captured_names = vec![];
+
+        post_body.visit_expressions(&mut |expression| {
+            let sway::Expression::Identifier(name) = expression else { return };
+
+            if declared_names.contains(name) && !captured_names.contains(name) {
+                captured_names.push(name.clone());
+            }
+        });
+
+        // Keep the captured variables in declaration order
+        captured_names.sort_by_key(|name| declared_names.iter().rposition(|n| n == name));
+
+        for name in captured_names.iter() {
+            let Some(variable) = pre_scope.borrow().get_variable_from_new_name(name) else {
+                panic!("Failed to find captured modifier variable: {name}");
+            };
+
+            modifier.captured_parameters.entries.push(sway::Parameter {
+                name: name.clone(),
+                type_name: Some(variable.borrow().type_name.clone()),
+                ..Default::default()
+            });
+        }
+
+        // Return the captured variables from the pre_body block
+        if !captured_names.is_empty() {
+            pre_body.final_expr = Some(if captured_names.len() == 1 {
+                sway::Expression::Identifier(captured_names[0].clone())
+            } else {
+                sway::Expression::Tuple(captured_names.iter().map(|name| sway::Expression::Identifier(name.clone())).collect())
+            });
+        }
+    }
+
     // Generate toplevel modifier functions
     match (modifier.pre_body.as_ref(), modifier.post_body.as_ref()) {
         (Some(pre_body), Some(post_body)) => {
             let modifier_pre_function_name = format!("{}_pre", modifier.new_name);
 
             translated_definition.functions.push(sway::Function {
+                comment: None,
                 attributes: create_attributes(has_pre_storage_read, has_pre_storage_write),
                 is_public: false,
                 name: modifier_pre_function_name.clone(),
                 generic_parameters: None,
                 parameters: modifier.parameters.clone(),
-                return_type: None,
+                return_type: match modifier.captured_parameters.entries.len() {
+                    0 => None,
+                    1 => modifier.captured_parameters.entries[0].type_name.clone(),
+                    _ => Some(sway::TypeName::Tuple {
+                        type_names: modifier.captured_parameters.entries.iter().map(|p| p.type_name.clone().unwrap()).collect(),
+                    }),
+                },
                 body: Some(pre_body.clone()),
             });
 
@@ -383,11 +472,14 @@ pub fn translate_modifier_definition(
             let modifier_post_function_name = format!("{}_post", modifier.new_name);
 
             translated_definition.functions.push(sway::Function {
+                comment: None,
                 attributes: create_attributes(has_post_storage_read, has_post_storage_write),
                 is_public: false,
                 name: modifier_post_function_name.clone(),
                 generic_parameters: None,
-                parameters: modifier.parameters.clone(),
+                parameters: sway::ParameterList {
+                    entries: modifier.parameters.entries.iter().chain(modifier.captured_parameters.entries.iter()).cloned().collect(),
+                },
                 return_type: None,
                 body: Some(post_body.clone()),
             });
@@ -397,6 +489,7 @@ pub fn translate_modifier_definition(
 
         (Some(pre_body), None) => {
             translated_definition.functions.push(sway::Function {
+                comment: None,
                 attributes: create_attributes(has_pre_storage_read, has_pre_storage_write),
                 is_public: false,
                 name: modifier.new_name.clone(),
@@ -411,6 +504,7 @@ pub fn translate_modifier_definition(
 
         (None, Some(post_body)) => {
             translated_definition.functions.push(sway::Function {
+                comment: None,
                 attributes: create_attributes(has_post_storage_read, has_post_storage_write),
                 is_public: false,
                 name: modifier.new_name.clone(),
@@ -447,10 +541,14 @@ pub fn translate_function_definition(
 
     // Collect information about the function from its attributes
     let mut is_public = function_definition.attributes.iter().any(|x| matches!(x, solidity::FunctionAttribute::Visibility(solidity::Visibility::External(_) | solidity::Visibility::Public(_))));
-    let is_constant = function_definition.attributes.iter().any(|x| matches!(x, solidity::FunctionAttribute::Mutability(solidity::Mutability::Constant(_))));
-    let is_pure = function_definition.attributes.iter().any(|x| matches!(x, solidity::FunctionAttribute::Mutability(solidity::Mutability::Pure(_))));
-    let is_view = function_definition.attributes.iter().any(|x| matches!(x, solidity::FunctionAttribute::Mutability(solidity::Mutability::View(_))));
-    let is_payable = function_definition.attributes.iter().any(|x| matches!(x, solidity::FunctionAttribute::Mutability(solidity::Mutability::Payable(_))));
+    let mutability = function_definition.attributes.iter().find_map(|x| match x {
+        solidity::FunctionAttribute::Mutability(mutability) => Some(mutability.clone()),
+        _ => None,
+    });
+    let is_constant = matches!(mutability, Some(solidity::Mutability::Constant(_)));
+    let is_pure = matches!(mutability, Some(solidity::Mutability::Pure(_)));
+    let is_view = matches!(mutability, Some(solidity::Mutability::View(_)));
+    let is_payable = matches!(mutability, Some(solidity::Mutability::Payable(_)));
     let _is_virtual = function_definition.attributes.iter().any(|x| matches!(x, solidity::FunctionAttribute::Virtual(_)));
     let is_override = function_definition.attributes.iter().any(|x| matches!(x, solidity::FunctionAttribute::Override(_, _)));
 
@@ -458,6 +556,14 @@ pub fn translate_function_definition(
     if is_constructor {
         is_public = true;
     }
+
+    // Library functions are translated to module functions instead of abi functions, only exposing their public and external functions
+    let is_library = matches!(translated_definition.kind, Some(solidity::ContractTy::Library(_)));
+    let is_library_public = is_library && is_public;
+
+    if is_library {
+        is_public = false;
+    }
     
     let new_name_2 = if is_constructor {
         "constructor".to_string()
@@ -475,6 +581,28 @@ pub fn translate_function_definition(
        new_name = format!("{}_{}", crate::translate_naming_convention(&translated_definition.name, Case::Snake), new_name);
     }
 
+    // Keep track of the declared mutability of the function so it can be checked against its inferred storage attributes
+    if let Some(mutability) = mutability.as_ref() {
+        translated_definition.function_mutabilities.insert(new_name.clone(), mutability.clone());
+    }
+
+    // A non-payable fallback function rejects any value sent to it, but a Sway fallback function cannot reject forwarded coins
+    if is_fallback && !is_payable {
+        project.report_warning(Lint::NonPayableFallback, &translated_definition.path, Some(&function_definition.loc), "non-payable fallback function implicitly rejects `msg.value`, which has no Sway equivalent; forwarded coins will be accepted");
+    }
+
+    // An ERC-165 `supportsInterface` implementation is translated as is, but nothing on Fuel queries it
+    if function_definition.body.is_some()
+        && function_definition.params.len() == 1
+        && function_definition.name.as_ref().is_some_and(|n| n.name == "supportsInterface")
+    {
+        emitln!(project.emitter, 
+            "NOTE: {} - `{}` implements ERC-165 interface detection, which has no Fuel convention; its interface identifiers are translated to `[u8; 4]` constants of their Solidity selectors",
+            project.loc_to_file_location_string(&translated_definition.path, &function_definition.loc),
+            translated_definition.name,
+        );
+    }
+
     // println!(
     //     "Translating {}.{} {}",
     //     translated_definition.name,
@@ -490,8 +618,8 @@ pub fn translate_function_definition(
 
     for (_, parameter) in function_definition.params.iter() {
         let old_name = parameter.as_ref().unwrap().name.as_ref().map(|n| n.name.clone()).unwrap_or("_".into());
-        let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let mut type_name = translate_type_name(project, translated_definition, &parameter.as_ref().unwrap().ty, false, true);
+        let new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
+        let mut type_name = translate_parameter_type_name(project, translated_definition, parameter.as_ref().unwrap())?;
 
         // Check if the parameter's type is an ABI and make it an Identity
         if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
@@ -513,6 +641,7 @@ pub fn translate_function_definition(
 
     // Create the function declaration
     let mut sway_function = sway::Function {
+        comment: None,
         attributes: if is_constant || is_pure {
             None
         } else {
@@ -539,7 +668,7 @@ pub fn translate_function_definition(
             Some(sway::AttributeList { attributes })
         },
 
-        is_public: false,
+        is_public: is_library_public,
         name: new_name.clone(),
         generic_parameters: None,
 
@@ -549,14 +678,14 @@ pub fn translate_function_definition(
             None
         } else {
             Some(if function_definition.returns.len() == 1 {
-                let type_name = translate_type_name(project, translated_definition, &function_definition.returns[0].1.as_ref().unwrap().ty, false, true);
+                let type_name = translate_type_name(project, translated_definition, &function_definition.returns[0].1.as_ref().unwrap().ty, false, true)?;
                 translate_return_type_name(project, translated_definition, type_name)
             } else {
                 sway::TypeName::Tuple {
                     type_names: function_definition.returns.iter().map(|(_, p)| {
-                        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true);
-                        translate_return_type_name(project, translated_definition, type_name)
-                    }).collect(),
+                        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true)?;
+                        Ok(translate_return_type_name(project, translated_definition, type_name))
+                    }).collect::<Result<Vec<_>, Error>>()?,
                 }
             })
         },
@@ -564,12 +693,18 @@ pub fn translate_function_definition(
         body: None,
     };
 
+    // Document the abi function with its NatSpec, which is also kept on functions that are not part of the abi
+    let doc_comment = translate_natspec(project, translated_definition, &function_definition.loc);
+
     if is_public {
+        translated_definition.add_item_origin(ItemOriginKind::AbiFunction, &new_name_2, &function_definition.loc);
+
         sway_function.name = new_name_2.clone();
+        sway_function.comment = doc_comment.clone();
 
         if let Some(abi) = translated_definition.abi.as_mut() {
             // Only add the function to the abi if it doesn't already exist
-            if !abi.functions.contains(&sway_function) && !is_override {
+            if !abi.functions.iter().any(|f| f.is_equivalent(&sway_function)) && !is_override {
                 if is_constructor {
                     abi.functions.insert(0, sway_function.clone());
                 } else {
@@ -589,7 +724,25 @@ pub fn translate_function_definition(
     }
 
     // Convert the statements in the function's body (if any)
-    let Some(solidity::Statement::Block { statements, .. }) = function_definition.body.as_ref() else { return Ok(()) };
+    let Some(solidity::Statement::Block { loc: body_loc, statements, .. }) = function_definition.body.as_ref() else { return Ok(()) };
+
+    // Preserve the comments preceding the function
+    sway_function.comment = join_comments(
+        join_comments(
+            project.get_leading_comment(&translated_definition.path, &function_definition.loc),
+            project.get_source_location_comment(&translated_definition.path, &function_definition.loc),
+        ),
+        if is_public { None } else { doc_comment },
+    );
+
+    // Map the toplevel function to the whole Solidity function, including its body
+    let mut function_loc = function_definition.loc;
+
+    if let Some(body) = function_definition.body.as_ref() {
+        function_loc.use_end_from(&body.loc());
+    }
+
+    translated_definition.add_item_origin(ItemOriginKind::Function, &new_name, &function_loc);
 
     // Create the scope for the body of the toplevel function
     let scope = Rc::new(RefCell::new(TranslationScope {
@@ -602,10 +755,16 @@ pub fn translate_function_definition(
 
     for (_, p) in function_definition.params.iter() {
         let old_name = p.as_ref().unwrap().name.as_ref().map(|n| n.name.clone()).unwrap_or("_".into());
-        let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let mut type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true);
+        let new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
+        let storage_location = p.as_ref().unwrap().storage.clone();
+        let is_storage = matches!(storage_location, Some(solidity::StorageLocation::Storage(_)));
+        let mut type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, is_storage, !is_storage)?;
         let mut abi_type_name = None;
 
+        if let Some(reason) = get_rename_reason(project, old_name.as_str(), new_name.as_str(), Case::Snake) {
+            translated_definition.add_renamed_identifier(old_name.as_str(), new_name.as_str(), reason);
+        }
+
         // Check if the parameter's type is an ABI
         if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
             if project.find_definition_with_abi(name.as_str()).is_some() {
@@ -623,6 +782,8 @@ pub fn translate_function_definition(
             new_name,
             type_name,
             abi_type_name,
+            is_storage,
+            storage_location,
             ..Default::default()
         };
 
@@ -636,10 +797,14 @@ pub fn translate_function_definition(
     for (_, return_parameter) in function_definition.returns.iter() {
         let Some(return_parameter) = return_parameter else { continue };
         let Some(old_name) = return_parameter.name.as_ref().map(|n| n.name.clone()) else { continue };
-        let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let mut type_name = translate_type_name(project, translated_definition, &return_parameter.ty, false, true);
+        let new_name = project.translate_naming_convention(old_name.as_str(), Case::Snake);
+        let mut type_name = translate_type_name(project, translated_definition, &return_parameter.ty, false, true)?;
         let mut abi_type_name = None;
 
+        if let Some(reason) = get_rename_reason(project, old_name.as_str(), new_name.as_str(), Case::Snake) {
+            translated_definition.add_renamed_identifier(old_name.as_str(), new_name.as_str(), reason);
+        }
+
         // Check if the parameter's type is an ABI
         if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
             if project.find_definition_with_abi(name.as_str()).is_some() {
@@ -664,8 +829,64 @@ pub fn translate_function_definition(
         scope.borrow_mut().variables.push(Rc::new(RefCell::new(translated_variable)));
     }
 
+    // Track the value returned by the function's named return parameters, using default values for unnamed ones
+    if !return_parameters.is_empty() {
+        let mut return_values = vec![];
+
+        for (_, return_parameter) in function_definition.returns.iter() {
+            let return_parameter = return_parameter.as_ref().unwrap();
+
+            return_values.push(match return_parameter.name.as_ref() {
+                Some(name) => sway::Expression::Identifier(project.translate_naming_convention(name.name.as_str(), Case::Snake)),
+
+                None => {
+                    let type_name = translate_type_name(project, translated_definition, &return_parameter.ty, false, true)?;
+                    let type_name = translate_return_type_name(project, translated_definition, type_name);
+                    create_value_expression(translated_definition, scope.clone(), &type_name, None)
+                }
+            });
+        }
+
+        scope.borrow_mut().named_return_value = Some(if return_values.len() == 1 {
+            return_values.pop().unwrap()
+        } else {
+            sway::Expression::Tuple(return_values)
+        });
+    }
+
     // Translate the body for the toplevel function
-    let mut function_body = translate_block(project, translated_definition, scope.clone(), statements.as_slice())?;
+    let mut function_body = translate_block(project, translated_definition, scope.clone(), body_loc, statements.as_slice())?;
+
+    // Set aside the comments at the end of the body so they don't hide its final statement
+    let trailing_comment_count = function_body.statements.iter().rev().take_while(|s| matches!(s, sway::Statement::Comment(_))).count();
+    let trailing_comments = function_body.statements.split_off(function_body.statements.len() - trailing_comment_count);
+
+    // Payable functions can receive any asset, so only accept the base asset which `msg.value` refers to
+    if is_payable {
+        translated_definition.ensure_use_declared("std::call_frames::msg_asset_id");
+
+        // require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        function_body.statements.insert(0, sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier("require".into()),
+            generic_parameters: None,
+            parameters: vec![
+                sway::Expression::from(sway::BinaryExpression {
+                    operator: "==".into(),
+                    lhs: sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("msg_asset_id".into()),
+                        generic_parameters: None,
+                        parameters: vec![],
+                    }),
+                    rhs: sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("AssetId::base".into()),
+                        generic_parameters: None,
+                        parameters: vec![],
+                    }),
+                }),
+                sway::Expression::from(sway::Literal::String("Only the base asset can be sent".into())),
+            ],
+        })));
+    }
 
     if is_constructor {
         let prefix = crate::translate_naming_convention(translated_definition.name.as_str(), Case::Snake);
@@ -673,6 +894,7 @@ pub fn translate_function_definition(
         
         // Add the `constructor_called` field to the storage block
         translated_definition.get_storage().fields.push(sway::StorageField {
+            comment: None,
             name: constructor_called_variable_name.clone(),
             type_name: sway::TypeName::Identifier {
                 name: "bool".into(),
@@ -728,7 +950,8 @@ pub fn translate_function_definition(
             panic!("error: Variable not found in scope: \"{}\"", parameter.new_name);
         };
 
-        if variable.borrow().mutation_count > 0 {
+        // Writes to storage pointers are writes to storage, so they don't need a local copy
+        if variable.borrow().mutation_count > 0 && !parameter.is_storage_pointer() {
             function_body.statements.insert(0, sway::Statement::Let(sway::Let {
                 pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
                     is_mutable: true,
@@ -759,17 +982,9 @@ pub fn translate_function_definition(
 
     // If the function returns values but doesn't end in a return statement, propagate the return variables
     if !return_parameters.is_empty() && !matches!(function_body.statements.last(), Some(sway::Statement::Expression(sway::Expression::Return(_)))) {
-        function_body.statements.push(sway::Statement::from(sway::Expression::Return(Some(Box::new(
-            if return_parameters.len() == 1 {
-                sway::Expression::Identifier(
-                    return_parameters[0].new_name.clone()
-                )
-            } else {
-                sway::Expression::Tuple(
-                    return_parameters.iter().map(|p| sway::Expression::Identifier(p.new_name.clone())).collect()
-                )
-            }
-        )))));
+        function_body.statements.push(sway::Statement::from(sway::Expression::Return(
+            scope.borrow().get_named_return_value().map(Box::new)
+        )));
     }
 
     // Check if the final statement returns a value and change it to be the final expression of the block
@@ -778,16 +993,15 @@ pub fn translate_function_definition(
         function_body.final_expr = Some(*value);
     }
 
+    function_body.statements.extend(trailing_comments);
+
     // Get the function from the scope
     let function = match translated_definition.toplevel_scope.borrow().find_function(|f| f.borrow().new_name == new_name) {
         Some(function) => function,
         None => {
             panic!(
-                "{}error: Failed to find function `{}.{new_name}` in scope - {:#?}",
-                match project.loc_to_line_and_column(&translated_definition.path, &function_definition.loc) {
-                    Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                    None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                },
+                "{} - error: Failed to find function `{}.{new_name}` in scope - {:#?}",
+                project.loc_to_file_location_string(&translated_definition.path, &function_definition.loc),
                 translated_definition.name,
                 project.translated_definitions,
             )
@@ -796,11 +1010,6 @@ pub fn translate_function_definition(
 
     let function = function.borrow();
 
-    // Propagate constructor calls into the function's body
-    for constructor_call in function.constructor_calls.iter().rev() {
-        function_body.statements.insert(0, sway::Statement::from(sway::Expression::from(constructor_call.clone())));
-    }
-
     // Propagate modifier pre and post functions into the function's body
     let mut modifier_pre_calls = vec![];
     let mut modifier_post_calls = vec![];
@@ -815,43 +1024,117 @@ pub fn translate_function_definition(
         };
 
         if modifier.pre_body.is_some() && modifier.post_body.is_some() {
-            modifier_pre_calls.push(sway::FunctionCall {
+            // Prefix the captured variables with the modifier name so they don't collide with the function's variables
+            let captured_names = modifier.captured_parameters.entries.iter()
+                .map(|p| format!("{}_{}", modifier.new_name, p.name))
+                .collect::<Vec<_>>();
+
+            let pre_call = sway::Expression::from(sway::FunctionCall {
                 function: sway::Expression::Identifier(format!("{}_pre", modifier.new_name)),
                 generic_parameters: None,
                 parameters: modifier_invocation.parameters.clone(),
             });
 
-            modifier_post_calls.push(sway::FunctionCall {
+            modifier_pre_calls.push(match captured_names.len() {
+                0 => sway::Statement::from(pre_call),
+
+                // let x = modifier_pre(...);
+                1 => sway::Statement::Let(sway::Let {
+                    pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+                        is_mutable: false,
+                        name: captured_names[0].clone(),
+                    }),
+                    type_name: None,
+                    value: pre_call,
+                }),
+
+                // let (x, y) = modifier_pre(...);
+                _ => sway::Statement::Let(sway::Let {
+                    pattern: sway::LetPattern::Tuple(captured_names.iter().map(|name| sway::LetIdentifier {
+                        is_mutable: false,
+                        name: name.clone(),
+                    }).collect()),
+                    type_name: None,
+                    value: pre_call,
+                }),
+            });
+
+            modifier_post_calls.push(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
                 function: sway::Expression::Identifier(format!("{}_post", modifier.new_name)),
                 generic_parameters: None,
-                parameters: modifier_invocation.parameters.clone(),
-            });
+                parameters: modifier_invocation.parameters.iter().cloned()
+                    .chain(captured_names.iter().map(|name| sway::Expression::Identifier(name.clone())))
+                    .collect(),
+            })));
         } else if modifier.pre_body.is_some() {
-            modifier_pre_calls.push(sway::FunctionCall {
+            modifier_pre_calls.push(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
                 function: sway::Expression::Identifier(modifier.new_name.clone()),
                 generic_parameters: None,
                 parameters: modifier_invocation.parameters.clone(),
-            });
+            })));
         } else if modifier.post_body.is_some() {
-            modifier_post_calls.push(sway::FunctionCall {
+            modifier_post_calls.push(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
                 function: sway::Expression::Identifier(modifier.new_name.clone()),
                 generic_parameters: None,
                 parameters: modifier_invocation.parameters.clone(),
-            });
+            })));
         }
     }
 
-    for modifier_pre_call in modifier_pre_calls.iter().rev() {
-        function_body.statements.insert(0, sway::Statement::from(sway::Expression::from(modifier_pre_call.clone())));
+    if !modifier_post_calls.is_empty() {
+        // Early returns skip the code after the modifiers' underscore statements
+        let mut has_early_return = false;
+
+        function_body.visit_expressions(&mut |expression| {
+            if let sway::Expression::Return(_) = expression {
+                has_early_return = true;
+            }
+        });
+
+        if has_early_return {
+            project.report_warning(Lint::ModifierEarlyReturn, &translated_definition.path, Some(&function_definition.loc), format!("function `{}.{}` returns early, which skips the code following the underscore statement of its modifiers", translated_definition.name, new_name));
+        }
     }
 
-    for modifier_post_call in modifier_post_calls.iter().rev() {
-        function_body.statements.push(sway::Statement::from(sway::Expression::from(modifier_post_call.clone())));
+    for modifier_pre_call in modifier_pre_calls.into_iter().rev() {
+        function_body.statements.insert(0, modifier_pre_call);
+    }
+
+    // The code following the modifiers' underscore statements is unwound in reverse order
+    modifier_post_calls.reverse();
+
+    match function_body.final_expr.take() {
+        // Evaluate the return value before the code following the modifiers' underscore statements
+        // let result = x; modifier_post(); result
+        Some(final_expr) if !modifier_post_calls.is_empty() => {
+            let result_name = scope.borrow().generate_unique_variable_name("result");
+            let (statements, result) = evaluate_before_statements(&result_name, None, final_expr, modifier_post_calls);
+
+            function_body.statements.extend(statements);
+            function_body.final_expr = Some(result);
+        }
+
+        final_expr => {
+            function_body.statements.extend(modifier_post_calls);
+            function_body.final_expr = final_expr;
+        }
     }
 
     // Create the body for the toplevel function
     sway_function.body = Some(function_body);
 
+    // Replace any inherited implementation of the toplevel function, since the current definition is the most derived
+    let function_count = translated_definition.functions.len();
+
+    translated_definition.functions.retain(|f| f.name != sway_function.name || f.parameters != sway_function.parameters);
+
+    if translated_definition.functions.len() != function_count && !is_override {
+        project.report_warning(Lint::MissingOverride, &translated_definition.path, Some(&function_definition.loc), format!("function `{}` overrides an inherited function without being marked `override`", sway_function.name));
+    }
+
+    translated_definition.function_origins.insert(new_name.clone(), translated_definition.name.clone());
+    translated_definition.function_origins.insert(new_name_2.clone(), translated_definition.name.clone());
+
     // Add the toplevel function
     translated_definition.functions.push(sway_function.clone());
 
@@ -0,0 +1,41 @@
+use super::{TranslatedDefinition, TranslationScope};
+use crate::sway;
+use solang_parser::pt as solidity;
+use std::path::Path;
+
+/// Custom translation rules registered on a [Project](crate::project::Project) with [add_translation_hook](crate::project::Project::add_translation_hook).
+///
+/// Each callback returns `None` by default to use the built-in translation. Hooks are consulted in the order they were registered,
+/// and the first result returned is used. Callbacks can declare the uses and dependencies their results need on the supplied translated definition.
+pub trait TranslationHook {
+    /// Translates a Solidity type name, i.e: to map an in-house library type to a type from an existing Sway library.
+    fn translate_type_name(
+        &self,
+        _translated_definition: &mut TranslatedDefinition,
+        _type_name: &solidity::Expression,
+        _is_storage: bool,
+    ) -> Option<sway::TypeName> {
+        None
+    }
+
+    /// Translates a Solidity expression, i.e: to rewrite calls to an in-house library into calls to an existing Sway library.
+    fn translate_expression(
+        &self,
+        _translated_definition: &mut TranslatedDefinition,
+        _scope: &TranslationScope,
+        _expression: &solidity::Expression,
+    ) -> Option<sway::Expression> {
+        None
+    }
+
+    /// Recognizes a contract definition that should not be translated, returning the translated definition to use in its place.
+    ///
+    /// The returned definition is used to resolve the definitions that import or inherit the recognized contract.
+    fn recognize_contract(
+        &self,
+        _source_unit_path: &Path,
+        _contract_definition: &solidity::ContractDefinition,
+    ) -> Option<TranslatedDefinition> {
+        None
+    }
+}
@@ -0,0 +1,152 @@
+use super::{TranslatedDefinition, TranslatedFunction};
+use crate::sway;
+use std::{cell::RefCell, rc::Rc};
+
+/// Creates a call to the `to_identity` boundary helper, which wraps a contract id into an `Identity`.
+pub fn create_to_identity_call(translated_definition: &mut TranslatedDefinition, contract_id: sway::Expression) -> sway::Expression {
+    // to_identity(expect_contract(x)) => x
+    if let sway::Expression::FunctionCall(function_call) = &contract_id {
+        if matches!(&function_call.function, sway::Expression::Identifier(name) if name == "expect_contract") {
+            if let Some(function_call_count) = translated_definition.function_call_counts.get_mut("expect_contract") {
+                *function_call_count = function_call_count.saturating_sub(1);
+            }
+
+            return function_call.parameters[0].clone();
+        }
+    }
+
+    create_identity_helper_call(translated_definition, "to_identity", contract_id)
+}
+
+/// Creates a call to the `expect_address` boundary helper, which reverts if the `Identity` is not an address.
+pub fn create_expect_address_call(translated_definition: &mut TranslatedDefinition, identity: sway::Expression) -> sway::Expression {
+    create_identity_helper_call(translated_definition, "expect_address", identity)
+}
+
+/// Creates a call to the `expect_contract` boundary helper, which reverts if the `Identity` is not a contract id.
+pub fn create_expect_contract_call(translated_definition: &mut TranslatedDefinition, identity: sway::Expression) -> sway::Expression {
+    // expect_contract(to_identity(x)) => x
+    if let sway::Expression::FunctionCall(function_call) = &identity {
+        if matches!(&function_call.function, sway::Expression::Identifier(name) if name == "to_identity") {
+            if let Some(function_call_count) = translated_definition.function_call_counts.get_mut("to_identity") {
+                *function_call_count = function_call_count.saturating_sub(1);
+            }
+
+            return function_call.parameters[0].clone();
+        }
+    }
+
+    create_identity_helper_call(translated_definition, "expect_contract", identity)
+}
+
+fn create_identity_helper_call(translated_definition: &mut TranslatedDefinition, function_name: &str, argument: sway::Expression) -> sway::Expression {
+    ensure_identity_helper_declared(translated_definition, function_name);
+
+    *translated_definition.function_call_counts.entry(function_name.into()).or_insert(0) += 1;
+
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier(function_name.into()),
+        generic_parameters: None,
+        parameters: vec![argument],
+    })
+}
+
+/// Declares the `Identity` boundary helper function in the translated definition if it hasn't been declared yet:
+///
+/// ```ignore
+/// fn to_identity(contract_id: ContractId) -> Identity {
+///     Identity::ContractId(contract_id)
+/// }
+///
+/// fn expect_address(identity: Identity) -> Address {
+///     identity.as_address().unwrap()
+/// }
+///
+/// fn expect_contract(identity: Identity) -> ContractId {
+///     identity.as_contract_id().unwrap()
+/// }
+/// ```
+pub fn ensure_identity_helper_declared(translated_definition: &mut TranslatedDefinition, function_name: &str) {
+    if translated_definition.functions.iter().any(|f| f.name == function_name) {
+        return;
+    }
+
+    let (parameter_name, parameter_type_name, return_type_name) = match function_name {
+        "to_identity" => ("contract_id", "ContractId", "Identity"),
+        "expect_address" => ("identity", "Identity", "Address"),
+        "expect_contract" => ("identity", "Identity", "ContractId"),
+        _ => panic!("Unknown identity helper function: {function_name}"),
+    };
+
+    let parameter = sway::Expression::Identifier(parameter_name.into());
+
+    let body = match function_name {
+        "to_identity" => sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier("Identity::ContractId".into()),
+            generic_parameters: None,
+            parameters: vec![parameter],
+        }),
+        "expect_address" => create_unwrapped_member_call(parameter, "as_address"),
+        _ => create_unwrapped_member_call(parameter, "as_contract_id"),
+    };
+
+    let parameters = sway::ParameterList {
+        entries: vec![
+            sway::Parameter {
+                is_ref: false,
+                is_mut: false,
+                name: parameter_name.into(),
+                type_name: Some(sway::TypeName::Identifier {
+                    name: parameter_type_name.into(),
+                    generic_parameters: None,
+                }),
+            },
+        ],
+    };
+
+    let return_type = sway::TypeName::Identifier {
+        name: return_type_name.into(),
+        generic_parameters: None,
+    };
+
+    translated_definition.functions.push(sway::Function {
+        comment: None,
+        attributes: None,
+        is_public: false,
+        name: function_name.into(),
+        generic_parameters: None,
+        parameters: parameters.clone(),
+        return_type: Some(return_type.clone()),
+        body: Some(sway::Block {
+            statements: vec![],
+            final_expr: Some(body),
+        }),
+    });
+
+    translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(TranslatedFunction {
+        old_name: String::new(),
+        new_name: function_name.into(),
+        parameters,
+        modifiers: vec![],
+        return_type: Some(return_type),
+    })));
+}
+
+/// Creates `x.member().unwrap()`.
+fn create_unwrapped_member_call(expression: sway::Expression, member: &str) -> sway::Expression {
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::from(sway::MemberAccess {
+                    expression,
+                    member: member.into(),
+                }),
+                generic_parameters: None,
+                parameters: vec![],
+            }),
+            member: "unwrap".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![],
+    })
+}
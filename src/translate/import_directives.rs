@@ -1,12 +1,26 @@
-use super::TranslatedDefinition;
-use crate::{errors::Error, project::Project};
+use super::{translate_enum_definition, translate_struct_definition, translate_type_definition, TranslatedDefinition, TranslationScope};
+use crate::{errors::Error, project::{Project, UserTypeKind}, sway};
 use solang_parser::pt as solidity;
-use std::path::{Path, PathBuf};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
 pub fn resolve_import(
     project: &mut Project,
     definition_name: &String,
     source_unit_path: &Path,
+) -> Result<Option<TranslatedDefinition>, Error> {
+    resolve_import_from(project, definition_name, source_unit_path, &mut HashSet::new())
+}
+
+/// Resolves `definition_name` from the source unit at `source_unit_path` or its imports, skipping the source units in `visited`
+/// so that import cycles are only followed once.
+fn resolve_import_from(
+    project: &mut Project,
+    definition_name: &String,
+    source_unit_path: &Path,
+    visited: &mut HashSet<PathBuf>,
 ) -> Result<Option<TranslatedDefinition>, Error> {
     let mut source_unit_path = PathBuf::from(source_unit_path);
     let source_unit_directory = source_unit_path.parent().map(PathBuf::from).unwrap();
@@ -17,31 +31,35 @@ pub fn resolve_import(
         source_unit_path = source_unit_directory.join(source_unit_path);
     }
     
-    source_unit_path = crate::get_canonical_path(source_unit_path, false, false)
-        .map_err(|e| Error::Wrapped(Box::new(e))).unwrap();
+    source_unit_path = project.get_source_unit_path(source_unit_path)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
     
-    if !source_unit_path.exists() {
+    if !project.source_unit_exists(&source_unit_path) {
         return Err(Error::Wrapped(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, source_unit_path.to_string_lossy()))));
     }
 
-    let source_unit_path = crate::get_canonical_path(source_unit_path, false, false)
+    let source_unit_path = project.get_source_unit_path(source_unit_path)
         .map_err(|e| Error::Wrapped(Box::new(e)))?;
 
     if let Some(t) = project.translated_definitions.iter().find(|t| t.name == *definition_name && t.path == source_unit_path).cloned() {
         return Ok(Some(t));
     }
+
+    if !visited.insert(source_unit_path.clone()) {
+        return Ok(None);
+    }
     
     if let Some(import_directives) = project.import_directives.get(&source_unit_path).cloned() {
         for (path, definition_names) in import_directives.iter() {
             match definition_names.as_ref() {
                 Some(definition_names) => {
                     if definition_names.iter().any(|n| n == definition_name) {
-                        return resolve_import(project, definition_name, path);
+                        return resolve_import_from(project, definition_name, path, visited);
                     }
                 }
 
                 None => {
-                    if let Ok(Some(t)) = resolve_import(project, definition_name, path) {
+                    if let Ok(Some(t)) = resolve_import_from(project, definition_name, path, visited) {
                         return Ok(Some(t));
                     }
                 }
@@ -49,7 +67,7 @@ pub fn resolve_import(
         }
     }
 
-    project.translate(Some(definition_name), &source_unit_path)?;
+    project.translate_source_unit(Some(definition_name), &source_unit_path)?;
 
     if let Some(t) = project.translated_definitions.iter().find(|t| t.name == *definition_name && t.path == source_unit_path).cloned() {
         return Ok(Some(t));
@@ -64,7 +82,8 @@ pub fn translate_import_directives(
     translated_definition: &mut TranslatedDefinition,
     import_directives: &[solidity::Import],
 ) -> Result<(), Error> {
-    let source_unit_directory = translated_definition.path.parent().map(PathBuf::from).unwrap();
+    let source_unit_path = translated_definition.path.clone();
+    let source_unit_directory = source_unit_path.parent().map(PathBuf::from).unwrap();
 
     for import_directive in import_directives.iter() {
         let mut translate_import_directive = |definition_name: Option<&String>, filename: &solidity::StringLiteral| -> Result<(), Error> {
@@ -76,10 +95,10 @@ pub fn translate_import_directives(
                 import_path = source_unit_directory.join(import_path);
             }
             
-            import_path = crate::get_canonical_path(import_path, false, false)
-                .map_err(|e| Error::Wrapped(Box::new(e))).unwrap();
+            import_path = project.get_source_unit_path(import_path)
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
             
-            if !import_path.exists() {
+            if !project.source_unit_exists(&import_path) {
                 return Err(Error::Wrapped(Box::new(
                     std::io::Error::new(
                         std::io::ErrorKind::NotFound,
@@ -88,14 +107,21 @@ pub fn translate_import_directives(
                 )));
             }
 
-            let import_path = crate::get_canonical_path(import_path, false, false)
+            let import_path = project.get_source_unit_path(import_path)
                 .map_err(|e| Error::Wrapped(Box::new(e)))?;
 
+            // Leave the definitions of source units that import the current source unit to be translated when they are referred to,
+            // since translating them ahead of time can require the definitions of the current source unit that are still being translated
+            if let Some(import_cycle) = project.find_import_cycle(&source_unit_path, &import_path)? {
+                project.report_import_cycle(import_cycle);
+                return Ok(());
+            }
+
             if !project.translated_definitions.iter().any(|t| definition_name.map(|n| *n == t.name).unwrap_or(true) && t.path == import_path) {
                 if let Some(definition_name) = definition_name {
                     resolve_import(project, definition_name, &import_path)?;
                 } else {
-                    project.translate(definition_name, &import_path)?;
+                    project.translate_source_unit(definition_name, &import_path)?;
                 }
             }
 
@@ -103,7 +129,8 @@ pub fn translate_import_directives(
         };
 
         match import_directive {
-            solidity::Import::Plain(solidity::ImportPath::Filename(filename), _) => {
+            solidity::Import::Plain(solidity::ImportPath::Filename(filename), _)
+            | solidity::Import::GlobalSymbol(solidity::ImportPath::Filename(filename), _, _) => {
                 translate_import_directive(None, filename)?;
             }
 
@@ -119,3 +146,132 @@ pub fn translate_import_directives(
 
     Ok(())
 }
+
+/// Translates the struct, enum or user-defined value type `name` into `translated_definition` when it is declared at the top level of
+/// another source unit, i.e: a struct declared in an imported `Types.sol` file that has no contracts of its own.
+///
+/// Returns `false` if `name` is not a struct, enum or user-defined value type declared in another source unit.
+pub fn import_user_type(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    name: &str,
+) -> Result<bool, Error> {
+    let Some((source_unit_path, kind)) = project.find_user_type(&translated_definition.path.clone(), name)? else { return Ok(false) };
+
+    let is_type_name = |type_name: &sway::TypeName| matches!(type_name, sway::TypeName::Identifier { name: type_name, generic_parameters: None } if type_name == name);
+
+    let already_translated = match kind {
+        UserTypeKind::Struct => translated_definition.structs.iter().any(|s| s.name == name),
+        UserTypeKind::Enum => translated_definition.enums.iter().any(|e| is_type_name(&e.type_definition.name)),
+        UserTypeKind::TypeDefinition => translated_definition.type_definitions.iter().any(|t| is_type_name(&t.name)),
+        UserTypeKind::Contract => return Ok(false),
+    };
+
+    if already_translated {
+        return Ok(false);
+    }
+
+    let source_unit_id = project.solidity_source_units.get_id(&source_unit_path).unwrap();
+    let source_unit = project.solidity_source_units.get_shared(source_unit_id);
+
+    let Some(source_unit_part) = source_unit.0.iter().find(|source_unit_part| match (kind, source_unit_part) {
+        (UserTypeKind::Struct, solidity::SourceUnitPart::StructDefinition(struct_definition)) => struct_definition.name.as_ref().is_some_and(|n| n.name == name),
+        (UserTypeKind::Enum, solidity::SourceUnitPart::EnumDefinition(enum_definition)) => enum_definition.name.as_ref().is_some_and(|n| n.name == name),
+        (UserTypeKind::TypeDefinition, solidity::SourceUnitPart::TypeDefinition(type_definition)) => type_definition.name.name == name,
+        _ => false,
+    }) else {
+        return Ok(false);
+    };
+
+    // Add struct names ahead of time in case their fields refer to them
+    if kind == UserTypeKind::Struct && !translated_definition.struct_names.iter().any(|n| n == name) {
+        translated_definition.struct_names.push(name.into());
+    }
+
+    // Translate the type in the context of its source unit, so that the types it refers to are resolved from there
+    // and the locations of its warnings and item origins refer to the right file
+    let path = std::mem::replace(&mut translated_definition.path, source_unit_path);
+
+    let result = match source_unit_part {
+        solidity::SourceUnitPart::StructDefinition(struct_definition) => translate_struct_definition(project, translated_definition, struct_definition),
+        solidity::SourceUnitPart::EnumDefinition(enum_definition) => translate_enum_definition(project, translated_definition, enum_definition),
+        solidity::SourceUnitPart::TypeDefinition(type_definition) => translate_type_definition(project, translated_definition, type_definition),
+        _ => unreachable!(),
+    };
+
+    translated_definition.path = path;
+    result?;
+
+    Ok(true)
+}
+
+/// Rewrites the symbol at the head of `expression` to the name it was declared with when it was imported under an alias or through a namespace,
+/// i.e: `BaseToken(token)` of `import {ERC20 as BaseToken} from "...";` or `Base.ERC20(token)` of `import * as Base from "...";` become `ERC20(token)`.
+///
+/// Returns `None` if the head of `expression` doesn't refer to an aliased or namespaced symbol, or refers to a variable shadowing it.
+pub fn resolve_import_symbols(
+    project: &Project,
+    translated_definition: &TranslatedDefinition,
+    scope: Option<&TranslationScope>,
+    expression: &solidity::Expression,
+) -> Option<solidity::Expression> {
+    let is_shadowed = |name: &str| scope.is_some_and(|scope| scope.get_variable_from_old_name(name).is_some());
+
+    match expression {
+        solidity::Expression::Variable(identifier) => {
+            if is_shadowed(&identifier.name) {
+                return None;
+            }
+
+            let name = project.resolve_import_symbol(&translated_definition.path, &[&identifier.name])?;
+
+            Some(solidity::Expression::Variable(solidity::Identifier {
+                loc: identifier.loc,
+                name,
+            }))
+        }
+
+        solidity::Expression::MemberAccess(loc, container, member) => {
+            if let solidity::Expression::Variable(namespace) = container.as_ref() {
+                if !is_shadowed(&namespace.name) {
+                    if let Some(name) = project.resolve_import_symbol(&translated_definition.path, &[&namespace.name, &member.name]) {
+                        return Some(solidity::Expression::Variable(solidity::Identifier {
+                            loc: member.loc,
+                            name,
+                        }));
+                    }
+                }
+            }
+
+            let container = resolve_import_symbols(project, translated_definition, scope, container)?;
+            Some(solidity::Expression::MemberAccess(*loc, Box::new(container), member.clone()))
+        }
+
+        solidity::Expression::FunctionCall(loc, function, arguments) => {
+            let function = resolve_import_symbols(project, translated_definition, scope, function)?;
+            Some(solidity::Expression::FunctionCall(*loc, Box::new(function), arguments.clone()))
+        }
+
+        solidity::Expression::NamedFunctionCall(loc, function, arguments) => {
+            let function = resolve_import_symbols(project, translated_definition, scope, function)?;
+            Some(solidity::Expression::NamedFunctionCall(*loc, Box::new(function), arguments.clone()))
+        }
+
+        solidity::Expression::FunctionCallBlock(loc, function, block) => {
+            let function = resolve_import_symbols(project, translated_definition, scope, function)?;
+            Some(solidity::Expression::FunctionCallBlock(*loc, Box::new(function), block.clone()))
+        }
+
+        solidity::Expression::New(loc, expression) => {
+            let expression = resolve_import_symbols(project, translated_definition, scope, expression)?;
+            Some(solidity::Expression::New(*loc, Box::new(expression)))
+        }
+
+        solidity::Expression::ArraySubscript(loc, expression, index) => {
+            let expression = resolve_import_symbols(project, translated_definition, scope, expression)?;
+            Some(solidity::Expression::ArraySubscript(*loc, Box::new(expression), index.clone()))
+        }
+
+        _ => None,
+    }
+}
@@ -0,0 +1,139 @@
+use super::TranslatedDefinition;
+use crate::{
+    project::{Lint, Project},
+    sway,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The name of the generated function that asserts all of the invariants of a contract.
+pub const CHECK_INVARIANTS_FUNCTION_NAME: &str = "check_invariants";
+
+/// A `require` condition that only depends on contract state and is checked by multiple functions.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TranslatedInvariant {
+    pub condition: sway::Expression,
+    pub message: sway::Expression,
+    pub function_names: Vec<String>,
+}
+
+/// Collects the `require` conditions that only depend on storage and constants and are checked by more than one function,
+/// reporting them as invariants of the contract and optionally generating a function that asserts all of them.
+pub fn extract_invariants(project: &mut Project, translated_definition: &mut TranslatedDefinition) {
+    let mut local_names = HashSet::from(["storage".to_string()]);
+
+    for x in translated_definition.constants.iter() {
+        local_names.insert(x.name.clone());
+    }
+
+    let mut candidates: Vec<TranslatedInvariant> = vec![];
+
+    let functions = translated_definition.functions.iter()
+        .chain(translated_definition.impls.iter().flat_map(|i| i.items.iter()).filter_map(|item| match item {
+            sway::ImplItem::Function(f) => Some(f),
+            _ => None,
+        }));
+
+    for function in functions {
+        let Some(body) = function.body.as_ref() else { continue };
+
+        body.visit_expressions(&mut |expression| {
+            let sway::Expression::FunctionCall(function_call) = expression else { return };
+
+            if !matches!(&function_call.function, sway::Expression::Identifier(name) if name == "require") || function_call.parameters.len() != 2 {
+                return;
+            }
+
+            let condition = &function_call.parameters[0];
+
+            if !is_state_condition(condition, &local_names) {
+                return;
+            }
+
+            match candidates.iter_mut().find(|x| x.condition == *condition) {
+                Some(candidate) => {
+                    if !candidate.function_names.contains(&function.name) {
+                        candidate.function_names.push(function.name.clone());
+                    }
+                }
+
+                None => candidates.push(TranslatedInvariant {
+                    condition: condition.clone(),
+                    message: function_call.parameters[1].clone(),
+                    function_names: vec![function.name.clone()],
+                }),
+            }
+        });
+    }
+
+    candidates.retain(|x| x.function_names.len() > 1);
+
+    for invariant in candidates.iter() {
+        project.report_warning(
+            Lint::Invariant,
+            &translated_definition.path,
+            None,
+            format!(
+                "`{}` invariant `{}` is required by {}",
+                translated_definition.name,
+                sway::TabbedDisplayer(&invariant.condition),
+                invariant.function_names.iter().map(|f| format!("`{f}`")).collect::<Vec<_>>().join(", "),
+            ),
+        );
+    }
+
+    if project.invariant_helpers && !candidates.is_empty() && !translated_definition.functions.iter().any(|f| f.name == CHECK_INVARIANTS_FUNCTION_NAME) {
+        // fn check_invariants() {
+        //     require(a, "msg a");
+        //     require(b, "msg b");
+        // }
+        translated_definition.functions.push(sway::Function {
+            comment: None,
+            attributes: None,
+            is_public: false,
+            name: CHECK_INVARIANTS_FUNCTION_NAME.into(),
+            generic_parameters: None,
+            parameters: sway::ParameterList::default(),
+            return_type: None,
+            body: Some(sway::Block {
+                statements: candidates.iter()
+                    .map(|x| sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("require".into()),
+                        generic_parameters: None,
+                        parameters: vec![x.condition.clone(), x.message.clone()],
+                    })))
+                    .collect(),
+                final_expr: None,
+            }),
+        });
+    }
+
+    translated_definition.invariants = candidates;
+}
+
+/// Checks if the condition only reads storage, constants and literals.
+fn is_state_condition(condition: &sway::Expression, local_names: &HashSet<String>) -> bool {
+    let mut reads_storage = false;
+    let mut is_state_only = true;
+
+    condition.visit(&mut |expression| match expression {
+        sway::Expression::Identifier(name) => {
+            // Allow constants and enum variants, i.e: `MAX_SUPPLY` or `Status::Open`
+            let is_constant = local_names.contains(name) || (name.contains("::") && name.starts_with(char::is_uppercase));
+
+            if name == "storage" {
+                reads_storage = true;
+            } else if !is_constant {
+                is_state_only = false;
+            }
+        }
+
+        sway::Expression::Block(_) | sway::Expression::If(_) | sway::Expression::Match(_) | sway::Expression::While(_) | sway::Expression::AsmBlock(_) => {
+            is_state_only = false;
+        }
+
+        _ => {}
+    });
+
+    reads_storage && is_state_only
+}
@@ -1,18 +1,34 @@
+mod access_control;
 mod assembly;
+mod constants;
 mod contracts;
+mod conversions;
+mod debug_events;
+mod eip712;
 mod enums;
 mod expressions;
+mod fallbacks;
 mod functions;
+mod hooks;
+mod identities;
 mod import_directives;
+mod invariants;
+mod native_assets;
+mod natspec;
+mod reentrancy;
+mod sections;
+mod selectors;
 mod statements;
 mod storage;
 mod structs;
 mod type_definitions;
 mod type_names;
 
-pub use self::{assembly::*, contracts::*, enums::*, expressions::*, functions::*, import_directives::*, statements::*, storage::*, structs::*, type_definitions::*, type_names::*};
+pub use self::{access_control::*, assembly::*, constants::*, contracts::*, conversions::*, debug_events::*, eip712::*, enums::*, expressions::*, fallbacks::*, functions::*, hooks::*, identities::*, import_directives::*, invariants::*, native_assets::*, natspec::*, reentrancy::*, sections::*, selectors::*, statements::*, storage::*, structs::*, type_definitions::*, type_names::*};
 
-use crate::{errors::Error, sway};
+use crate::{errors::Error, project::{Project, Warning}, sway};
+use convert_case::Case;
+use serde::{Deserialize, Serialize};
 use solang_parser::pt as solidity;
 use std::{
     cell::RefCell,
@@ -22,20 +38,20 @@ use std::{
     rc::Rc
 };
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TranslatedUsingDirective {
     pub library_name: String,
     pub for_type: Option<sway::TypeName>,
     pub functions: Vec<TranslatedFunction>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TranslatedEnum {
     pub type_definition: sway::TypeDefinition,
     pub variants_impl: sway::Impl,
 }
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct TranslatedVariable {
     pub old_name: String,
     pub new_name: String,
@@ -44,22 +60,95 @@ pub struct TranslatedVariable {
     pub is_storage: bool,
     pub is_configurable: bool,
     pub is_constant: bool,
+    pub storage_location: Option<solidity::StorageLocation>,
     pub statement_index: Option<usize>,
     pub read_count: usize,
     pub mutation_count: usize,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+impl TranslatedVariable {
+    /// Checks if the variable is a `storage` pointer, which aliases a value in storage instead of being a storage field itself
+    #[inline]
+    pub fn is_storage_pointer(&self) -> bool {
+        self.is_storage && matches!(self.storage_location, Some(solidity::StorageLocation::Storage(_)))
+    }
+}
+
+/// The reason that an identifier was given a different name in the translated code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RenameReason {
+    /// The identifier was translated to the Sway naming convention, i.e: `totalSupply` => `total_supply`
+    NamingConvention,
+    /// The identifier is a Sway keyword and was escaped, i.e: `impl` => `impl_`
+    Keyword,
+    /// The identifier was disambiguated from another identifier with the same translated name, i.e: `myVar` => `my_var_2`
+    Collision,
+    /// The function was disambiguated from another function with the same name, i.e: `f(bool)` => `f_2`
+    Overload,
+    /// The identifier has an identifier mapping in the `charcoal.toml` file
+    IdentifierMapping,
+    /// The definition shares its name with a definition from another file and its output was qualified with its path, i.e: `Token` => `ATokenToken`
+    QualifiedName,
+    /// The contract definition has no name and was given a generated name, i.e: `Unnamed1`
+    Unnamed,
+}
+
+impl Display for RenameReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenameReason::NamingConvention => write!(f, "naming convention"),
+            RenameReason::Keyword => write!(f, "keyword"),
+            RenameReason::Collision => write!(f, "collision"),
+            RenameReason::Overload => write!(f, "overload"),
+            RenameReason::IdentifierMapping => write!(f, "identifier mapping"),
+            RenameReason::QualifiedName => write!(f, "qualified name"),
+            RenameReason::Unnamed => write!(f, "unnamed"),
+        }
+    }
+}
+
+/// Gets the reason that an identifier was given a different name in the translated code, or `None` if it was not renamed
+pub fn get_rename_reason(project: &Project, old_name: &str, new_name: &str, case: Case) -> Option<RenameReason> {
+    if old_name == new_name {
+        return None;
+    }
+
+    if project.identifier_mappings.get(old_name).is_some_and(|n| n == new_name) {
+        return Some(RenameReason::IdentifierMapping);
+    }
+
+    let translated_name = crate::translate_naming_convention(old_name, case);
+
+    if new_name != translated_name {
+        return Some(RenameReason::Collision);
+    }
+
+    // Postfix underscores are preserved, so an extra one means the name was escaped, i.e: `impl` => `impl_`
+    if translated_name.ends_with('_') && !old_name.ends_with('_') {
+        Some(RenameReason::Keyword)
+    } else {
+        Some(RenameReason::NamingConvention)
+    }
+}
+
+/// An identifier that was given a different name in the translated code, i.e: `f(bool)` => `f_2`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TranslatedIdentifier {
+    pub old: String,
+    pub new: String,
+    pub reason: RenameReason,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TranslatedFunction {
     pub old_name: String,
     pub new_name: String,
     pub parameters: sway::ParameterList,
-    pub constructor_calls: Vec<sway::FunctionCall>,
     pub modifiers: Vec<sway::FunctionCall>,
     pub return_type: Option<sway::TypeName>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TranslatedModifier {
     pub old_name: String,
     pub new_name: String,
@@ -67,13 +156,15 @@ pub struct TranslatedModifier {
     pub has_underscore: bool,
     pub pre_body: Option<sway::Block>,
     pub post_body: Option<sway::Block>,
+    pub captured_parameters: sway::ParameterList,
 }
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct TranslationScope {
     pub parent: Option<Rc<RefCell<TranslationScope>>>,
     pub variables: Vec<Rc<RefCell<TranslatedVariable>>>,
     pub functions: Vec<Rc<RefCell<TranslatedFunction>>>,
+    pub named_return_value: Option<sway::Expression>,
 }
 
 impl TranslationScope {
@@ -99,6 +190,15 @@ impl TranslationScope {
         }
     }
     
+    /// Gets the value returned by the function of the scope when it has named return variables, i.e: `(a, b)`
+    pub fn get_named_return_value(&self) -> Option<sway::Expression> {
+        if let Some(named_return_value) = self.named_return_value.as_ref() {
+            return Some(named_return_value.clone());
+        }
+
+        self.parent.as_ref().and_then(|parent| parent.borrow().get_named_return_value())
+    }
+
     #[inline]
     pub fn generate_unique_variable_name(&self, name: &str) -> String {
         let mut result = name.to_string();
@@ -208,7 +308,7 @@ impl TranslationScope {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DeferredInitialization {
     pub name: String,
     pub is_storage: bool,
@@ -217,23 +317,57 @@ pub struct DeferredInitialization {
     pub value: sway::Expression,
 }
 
-#[derive(Clone, Debug, Default)]
+/// Third-party code that a translated definition was derived from, which must be attributed in its generated project
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Attribution {
+    pub name: String,
+    pub url: String,
+    pub license: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ItemOriginKind {
+    /// A module function, or a function of the contract impl
+    Function,
+    AbiFunction,
+    StorageField,
+    Struct,
+    Constant,
+}
+
+/// The Solidity code that a generated Sway item was translated from
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ItemOrigin {
+    pub kind: ItemOriginKind,
+    pub name: String,
+    pub path: PathBuf,
+    pub loc: solidity::Loc,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct TranslatedDefinition {
     pub path: PathBuf,
     pub toplevel_scope: Rc<RefCell<TranslationScope>>,
     pub kind: Option<solidity::ContractTy>,
     pub dependencies: Vec<String>,
+    pub attributions: Vec<Attribution>,
     pub deferred_initializations: Vec<DeferredInitialization>,
+    pub base_constructor_calls: Vec<sway::Statement>,
+    pub submodules: Vec<(String, sway::Module)>,
 
     pub uses: Vec<sway::Use>,
+    pub comment: Option<String>,
     pub name: String,
     pub inherits: Vec<String>,
+    pub linearization: Vec<String>,
     pub using_directives: Vec<TranslatedUsingDirective>,
     pub type_definitions: Vec<sway::TypeDefinition>,
+    pub value_type_structs: Vec<String>,
     pub structs: Vec<sway::Struct>,
     pub enums: Vec<TranslatedEnum>,
     pub events_enums: Vec<(sway::Enum, sway::Impl)>,
     pub errors_enums: Vec<(sway::Enum, sway::Impl)>,
+    pub referenced_enum_names: Vec<String>,
     pub constants: Vec<sway::Constant>,
     pub abis: Vec<sway::Abi>,
     pub abi: Option<sway::Abi>,
@@ -249,9 +383,19 @@ pub struct TranslatedDefinition {
     pub function_name_counts: HashMap<String, usize>,
     pub function_names: HashMap<String, String>,
     pub function_call_counts: HashMap<String, usize>,
+    pub function_mutabilities: HashMap<String, solidity::Mutability>,
+    pub function_origins: HashMap<String, String>,
+    pub super_function_calls: Vec<String>,
+    pub public_function_names: Vec<String>,
+    pub renamed_identifiers: Vec<TranslatedIdentifier>,
+    /// The warnings reported while translating the definition, not counting the warnings of allowed lints
+    pub warnings: Vec<Warning>,
+    pub item_origins: Vec<ItemOrigin>,
 
     pub storage_fields_name_counts: HashMap<String, usize>,
     pub storage_fields_names: HashMap<String, String>,
+
+    pub invariants: Vec<TranslatedInvariant>,
 }
 
 impl Display for TranslatedDefinition {
@@ -263,6 +407,15 @@ impl Display for TranslatedDefinition {
             written += 1;
         }
 
+        if let Some(comment) = self.comment.as_ref() {
+            if written > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "{}", sway::TabbedDisplayer(&sway::ModuleItem::Comment(comment.clone())))?;
+            written += 1;
+        }
+
         for (i, x) in self.constants.iter().enumerate() {
             if i == 0 && written > 0 {
                 writeln!(f)?;
@@ -397,21 +550,33 @@ impl Display for TranslatedDefinition {
 
 impl Into<sway::Module> for TranslatedDefinition {
     fn into(self) -> sway::Module {
+        // Abstract contracts are translated to library modules, since their abi, storage and contract impl
+        // are only used by the contracts that inherit them when their inheritance is flattened
+        let is_abstract = matches!(self.kind.as_ref(), Some(solidity::ContractTy::Abstract(_)));
+
         let mut result = sway::Module {
             kind: match self.kind.as_ref().unwrap() {
-                solidity::ContractTy::Abstract(_)
-                | solidity::ContractTy::Contract(_)
+                solidity::ContractTy::Contract(_)
                 | solidity::ContractTy::Interface(_) => sway::ModuleKind::Contract,
 
-                solidity::ContractTy::Library(_) => sway::ModuleKind::Library,
+                solidity::ContractTy::Abstract(_)
+                | solidity::ContractTy::Library(_) => sway::ModuleKind::Library,
             },
             items: vec![],
         };
 
+        for (name, _) in self.submodules.iter() {
+            result.items.push(sway::ModuleItem::Submodule(name.clone()));
+        }
+
         for x in self.uses.iter() {
             result.items.push(sway::ModuleItem::Use(x.clone()));
         }
 
+        if let Some(comment) = self.comment.as_ref() {
+            result.items.push(sway::ModuleItem::Comment(comment.clone()));
+        }
+
         for x in self.constants.iter() {
             result.items.push(sway::ModuleItem::Constant(x.clone()));
         }
@@ -443,16 +608,18 @@ impl Into<sway::Module> for TranslatedDefinition {
             result.items.push(sway::ModuleItem::Abi(x.clone()));
         }
         
-        if let Some(x) = self.abi.as_ref() {
-            result.items.push(sway::ModuleItem::Abi(x.clone()));
-        }
-        
-        if let Some(x) = self.storage.as_ref() {
-            result.items.push(sway::ModuleItem::Storage(x.clone()));
-        }
-        
-        if let Some(x) = self.configurable.as_ref() {
-            result.items.push(sway::ModuleItem::Configurable(x.clone()));
+        if !is_abstract {
+            if let Some(x) = self.abi.as_ref() {
+                result.items.push(sway::ModuleItem::Abi(x.clone()));
+            }
+            
+            if let Some(x) = self.storage.as_ref() {
+                result.items.push(sway::ModuleItem::Storage(x.clone()));
+            }
+            
+            if let Some(x) = self.configurable.as_ref() {
+                result.items.push(sway::ModuleItem::Configurable(x.clone()));
+            }
         }
 
         for x in self.functions.iter() {
@@ -460,10 +627,25 @@ impl Into<sway::Module> for TranslatedDefinition {
                 continue;
             }
 
-            result.items.push(sway::ModuleItem::Function(x.clone()));
+            let mut x = x.clone();
+
+            // The functions of abstract contracts are exposed to the contracts that inherit them, and the
+            // public functions of libraries are exposed to their users, but contract modules don't export functions
+            x.is_public = match result.kind {
+                sway::ModuleKind::Library => is_abstract || x.is_public,
+                _ => false,
+            };
+
+            result.items.push(sway::ModuleItem::Function(x));
         }
         
+        let contract_impl = self.find_contract_impl();
+
         for x in self.impls.iter() {
+            if is_abstract && contract_impl == Some(x) {
+                continue;
+            }
+
             result.items.push(sway::ModuleItem::Impl(x.clone()));
         }
 
@@ -478,17 +660,24 @@ impl TranslatedDefinition {
             toplevel_scope: Rc::new(RefCell::new(TranslationScope::default())),
             kind: Some(kind),
             dependencies: vec![],
+            attributions: vec![],
             deferred_initializations: vec![],
+            base_constructor_calls: vec![],
+            submodules: vec![],
 
             uses: vec![],
+            comment: None,
             name: name.to_string(),
             inherits: inherits.iter().map(|i| i.to_string()).collect(),
+            linearization: vec![name.to_string()],
             using_directives: vec![],
             type_definitions: vec![],
+            value_type_structs: vec![],
             enums: vec![],
             structs: vec![],
             events_enums: vec![],
             errors_enums: vec![],
+            referenced_enum_names: vec![],
             constants: vec![],
             abis: vec![],
             abi: None,
@@ -504,9 +693,18 @@ impl TranslatedDefinition {
             function_name_counts: HashMap::new(),
             function_names: HashMap::new(),
             function_call_counts: HashMap::new(),
+            function_mutabilities: HashMap::new(),
+            function_origins: HashMap::new(),
+            super_function_calls: vec![],
+            public_function_names: vec![],
+            renamed_identifiers: vec![],
+            warnings: vec![],
+            item_origins: vec![],
 
             storage_fields_name_counts: HashMap::new(),
             storage_fields_names: HashMap::new(),
+
+            invariants: vec![],
         }
     }
 
@@ -520,6 +718,32 @@ impl TranslatedDefinition {
         }
     }
 
+    /// Declares a submodule of the generated project, which is written next to its `main.sw` file.
+    #[inline]
+    pub fn ensure_submodule_declared(&mut self, name: &str, create_module: impl FnOnce() -> sway::Module) {
+        if !self.submodules.iter().any(|(n, _)| n == name) {
+            self.submodules.push((name.into(), create_module()));
+        }
+    }
+
+    /// Records that code derived from a third-party source was included in the translated definition.
+    #[inline]
+    pub fn ensure_attribution_declared(&mut self, attribution: Attribution) {
+        if !self.attributions.contains(&attribution) {
+            self.attributions.push(attribution);
+            self.attributions.sort();
+        }
+    }
+
+    /// Records that code derived from the known third-party source with the supplied git url was included in the translated definition,
+    /// i.e: [crate::forc::SWAY_LIBS_GIT_URL]
+    #[inline]
+    pub fn ensure_known_attribution_declared(&mut self, git_url: &str) {
+        if let Some(attribution) = crate::forc::get_known_attribution(git_url) {
+            self.ensure_attribution_declared(attribution);
+        }
+    }
+
     #[inline]
     pub fn ensure_use_declared(&mut self, name: &str) {
         let mut tree: Option<sway::UseTree> = None;
@@ -551,6 +775,69 @@ impl TranslatedDefinition {
         }
     }
 
+    /// Removes the events and errors enums that are not referenced by an `emit` or `revert` statement
+    pub fn remove_unreferenced_events_and_errors_enums(&mut self) {
+        let referenced_enum_names = self.referenced_enum_names.clone();
+
+        self.events_enums.retain(|(e, _)| referenced_enum_names.contains(&e.name));
+        self.errors_enums.retain(|(e, _)| referenced_enum_names.contains(&e.name));
+
+        // Remove the `core::codec::AbiEncode` import if it is no longer used
+        if self.events_enums.is_empty() && self.errors_enums.is_empty() && !self.impls.iter().any(|i| matches!(&i.type_name, sway::TypeName::Identifier { name, .. } if name == "AbiEncode")) {
+            self.uses.retain(|u| u.tree.to_string() != "core::codec::AbiEncode");
+        }
+    }
+
+    /// Records the Solidity code that a generated item of the definition was translated from
+    pub fn add_item_origin(&mut self, kind: ItemOriginKind, name: &str, loc: &solidity::Loc) {
+        self.item_origins.push(ItemOrigin {
+            kind,
+            name: name.into(),
+            path: self.path.clone(),
+            loc: *loc,
+        });
+    }
+
+    /// Gets the Solidity code that a generated item of the definition was translated from, preferring the most derived implementation
+    pub fn get_item_origin(&self, kind: ItemOriginKind, name: &str) -> Option<&ItemOrigin> {
+        self.item_origins.iter().rfind(|origin| origin.kind == kind && origin.name == name)
+    }
+
+    /// Records an identifier that was given a different name in the translated code
+    pub fn add_renamed_identifier(&mut self, old: &str, new: &str, reason: RenameReason) {
+        if self.renamed_identifiers.iter().any(|i| i.old == old && i.new == new) {
+            return;
+        }
+
+        self.renamed_identifiers.push(TranslatedIdentifier {
+            old: old.into(),
+            new: new.into(),
+            reason,
+        });
+    }
+
+    /// Translates the name of a variable declared in the scope, disambiguating it from the other variables of the scope
+    /// whose names translate to the same name, i.e: `my_var` => `my_var_2` when `myVar` is already declared
+    pub fn translate_variable_name(&mut self, project: &Project, scope: Rc<RefCell<TranslationScope>>, old_name: &str) -> String {
+        let base_name = project.translate_naming_convention(old_name, Case::Snake);
+        let mut new_name = base_name.clone();
+        let mut count = 1;
+
+        while scope.borrow().find_variable(|v| {
+            let v = v.borrow();
+            !v.is_storage && v.new_name == new_name && v.old_name != old_name
+        }).is_some() {
+            count += 1;
+            new_name = format!("{base_name}_{count}");
+        }
+
+        if let Some(reason) = get_rename_reason(project, old_name, new_name.as_str(), Case::Snake) {
+            self.add_renamed_identifier(old_name, new_name.as_str(), reason);
+        }
+
+        new_name
+    }
+
     #[inline]
     pub fn import_enum(&mut self, translated_enum: &TranslatedEnum) {
         let sway::TypeName::Identifier { name, generic_parameters: None } = &translated_enum.type_definition.name else {
@@ -609,6 +896,17 @@ impl TranslatedDefinition {
         self.storage.as_mut().unwrap()
     }
 
+    /// Checks if the contract named `origin` comes before `existing_origin` in the translated definition's linearization,
+    /// meaning its implementation of a function overrides the implementation from `existing_origin`.
+    pub fn is_more_derived_origin(&self, origin: Option<&String>, existing_origin: Option<&String>) -> bool {
+        let (Some(origin), Some(existing_origin)) = (origin, existing_origin) else { return false };
+
+        let Some(index) = self.linearization.iter().position(|n| n == origin) else { return false };
+        let Some(existing_index) = self.linearization.iter().position(|n| n == existing_origin) else { return false };
+
+        index < existing_index
+    }
+
     #[inline]
     pub fn find_contract_impl(&self) -> Option<&sway::Impl> {
         self.impls.iter().find(|i| {
@@ -682,12 +980,12 @@ impl TranslatedDefinition {
                     name: "bool".into(),
                     generic_parameters: None,
                 }),
-                sway::Literal::DecInt(_) => Ok(sway::TypeName::Identifier {
-                    name: "u64".into(), // TODO: is this ok?
+                sway::Literal::DecInt(value) | sway::Literal::HexInt(value) => Ok(sway::TypeName::Identifier {
+                    name: if value.bits() > 64 { "u256".into() } else { "u64".into() }, // TODO: is this ok?
                     generic_parameters: None,
                 }),
-                sway::Literal::HexInt(_) => Ok(sway::TypeName::Identifier {
-                    name: "u64".into(), // TODO: is this ok?
+                sway::Literal::B256(_) => Ok(sway::TypeName::Identifier {
+                    name: "b256".into(),
                     generic_parameters: None,
                 }),
                 sway::Literal::String(_) => Ok(sway::TypeName::StringSlice),
@@ -728,6 +1026,21 @@ impl TranslatedDefinition {
         
                 let variable = variable.borrow();
 
+                // Storage pointers are references to values in storage
+                if variable.is_storage_pointer() {
+                    return Ok(sway::TypeName::Identifier {
+                        name: "StorageKey".into(),
+                        generic_parameters: Some(sway::GenericParameterList {
+                            entries: vec![
+                                sway::GenericParameter {
+                                    type_name: variable.type_name.clone(),
+                                    implements: None,
+                                },
+                            ],
+                        }),
+                    });
+                }
+
                 // Variable should not be a storage field
                 if variable.is_storage {
                     panic!("error: Variable not found in scope: \"{name}\"");
@@ -765,7 +1078,7 @@ impl TranslatedDefinition {
                             })
                         }
 
-                        "AssetId::default" => Ok(sway::TypeName::Identifier {
+                        "AssetId::base" | "AssetId::default" => Ok(sway::TypeName::Identifier {
                             name: "AssetId".into(),
                             generic_parameters: None,
                         }),
@@ -780,36 +1093,78 @@ impl TranslatedDefinition {
                             generic_parameters: None,
                         }),
     
-                        "I8::from" | "I8::max" | "I8::min" => Ok(sway::TypeName::Identifier {
+                        "core::codec::abi_decode" => {
+                            let sway::Expression::FunctionCall(function_call) = expression else {
+                                panic!("Malformed `core::codec::abi_decode` call, expected function call, found {expression:#?}");
+                            };
+
+                            let Some(generic_parameters) = function_call.generic_parameters.as_ref() else {
+                                panic!("Malformed `core::codec::abi_decode` call, expected generic parameter: {expression:#?}");
+                            };
+
+                            Ok(generic_parameters.entries[0].type_name.clone())
+                        }
+    
+                        "core::codec::encode" => Ok(sway::TypeName::Identifier {
+                            name: "raw_slice".into(),
+                            generic_parameters: None,
+                        }),
+    
+                        "ContractId::from" => Ok(sway::TypeName::Identifier {
+                            name: "ContractId".into(),
+                            generic_parameters: None,
+                        }),
+    
+                        "I8::from" | "I8::max" | "I8::min" | "I8::new" => Ok(sway::TypeName::Identifier {
                             name: "I8".into(),
                             generic_parameters: None,
                         }),
     
-                        "I16::from" | "I16::max" | "I16::min" => Ok(sway::TypeName::Identifier {
+                        "I16::from" | "I16::max" | "I16::min" | "I16::new" => Ok(sway::TypeName::Identifier {
                             name: "I16".into(),
                             generic_parameters: None,
                         }),
     
-                        "I32::from" | "I32::max" | "I32::min" => Ok(sway::TypeName::Identifier {
+                        "I32::from" | "I32::max" | "I32::min" | "I32::new" => Ok(sway::TypeName::Identifier {
                             name: "I32".into(),
                             generic_parameters: None,
                         }),
     
-                        "I64::from" | "I64::max" | "I64::min" => Ok(sway::TypeName::Identifier {
+                        "I64::from" | "I64::max" | "I64::min" | "I64::new" => Ok(sway::TypeName::Identifier {
                             name: "I64".into(),
                             generic_parameters: None,
                         }),
     
-                        "I128::from" | "I128::max" | "I128::min" => Ok(sway::TypeName::Identifier {
+                        "I128::from" | "I128::max" | "I128::min" | "I128::new" => Ok(sway::TypeName::Identifier {
                             name: "I128".into(),
                             generic_parameters: None,
                         }),
     
-                        "I256::from" | "I256::max" | "I256::min" => Ok(sway::TypeName::Identifier {
+                        "I256::from" | "I256::max" | "I256::min" | "I256::new" => Ok(sway::TypeName::Identifier {
                             name: "I256".into(),
                             generic_parameters: None,
                         }),
     
+                        "I8::try_from" | "I16::try_from" | "I32::try_from" | "I64::try_from" | "I128::try_from" | "I256::try_from" => Ok(sway::TypeName::Identifier {
+                            name: "Option".into(),
+                            generic_parameters: Some(sway::GenericParameterList {
+                                entries: vec![
+                                    sway::GenericParameter {
+                                        type_name: sway::TypeName::Identifier {
+                                            name: name.trim_end_matches("::try_from").into(),
+                                            generic_parameters: None,
+                                        },
+                                        implements: None,
+                                    },
+                                ],
+                            }),
+                        }),
+    
+                        "ContractId::this" => Ok(sway::TypeName::Identifier {
+                            name: "ContractId".into(),
+                            generic_parameters: None,
+                        }),
+    
                         "Identity::Address" | "Identity::ContractId" | "Identity::from" => Ok(sway::TypeName::Identifier {
                             name: "Identity".into(),
                             generic_parameters: None,
@@ -830,6 +1185,28 @@ impl TranslatedDefinition {
                             }),
                         }),
     
+                        "std::block::block_header_hash" => Ok(sway::TypeName::Identifier {
+                            name: "Result".into(),
+                            generic_parameters: Some(sway::GenericParameterList {
+                                entries: vec![
+                                    sway::GenericParameter {
+                                        type_name: sway::TypeName::Identifier {
+                                            name: "b256".into(),
+                                            generic_parameters: None,
+                                        },
+                                        implements: None,
+                                    },
+                                    sway::GenericParameter {
+                                        type_name: sway::TypeName::Identifier {
+                                            name: "BlockHashError".into(),
+                                            generic_parameters: None,
+                                        },
+                                        implements: None,
+                                    },
+                                ],
+                            }),
+                        }),
+
                         "std::block::height" => Ok(sway::TypeName::Identifier {
                             name: "u32".into(),
                             generic_parameters: None,
@@ -840,12 +1217,32 @@ impl TranslatedDefinition {
                             generic_parameters: None,
                         }),
 
+                        "std::registers::global_gas" => Ok(sway::TypeName::Identifier {
+                            name: "u64".into(),
+                            generic_parameters: None,
+                        }),
+
+                        "std::tx::tx_gas_price" => Ok(sway::TypeName::Identifier {
+                            name: "Option".into(),
+                            generic_parameters: Some(sway::GenericParameterList {
+                                entries: vec![
+                                    sway::GenericParameter {
+                                        type_name: sway::TypeName::Identifier {
+                                            name: "u64".into(),
+                                            generic_parameters: None,
+                                        },
+                                        implements: None,
+                                    },
+                                ],
+                            }),
+                        }),
+
                         "std::context::balance_of" => Ok(sway::TypeName::Identifier {
                             name: "u64".into(),
                             generic_parameters: None,
                         }),
                             
-                        "std::context::msg_amount" => Ok(sway::TypeName::Identifier {
+                        "msg_amount" | "std::context::msg_amount" => Ok(sway::TypeName::Identifier {
                             name: "u64".into(),
                             generic_parameters: None,
                         }),
@@ -854,11 +1251,29 @@ impl TranslatedDefinition {
                             name: "u64".into(),
                             generic_parameters: None,
                         }),
+
+                        "std::asset::transfer" => Ok(sway::TypeName::Tuple { type_names: vec![] }),
     
-                        "std::hash::keccak256" => Ok(sway::TypeName::Identifier {
+                        "std::hash::keccak256" | "std::hash::sha256" => Ok(sway::TypeName::Identifier {
                             name: "b256".into(),
                             generic_parameters: None,
                         }),
+
+                        "eip712::hash_typed_data" | "eip712::identity_to_b256" | "eip712::bool_to_b256" => Ok(sway::TypeName::Identifier {
+                            name: "b256".into(),
+                            generic_parameters: None,
+                        }),
+
+                        "std::storage::storage_api::read" => match expression {
+                            sway::Expression::FunctionCall(f) if f.generic_parameters.is_some() => Ok(sway::TypeName::Identifier {
+                                name: "Option".into(),
+                                generic_parameters: f.generic_parameters.clone(),
+                            }),
+
+                            _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                        }
+
+                        "std::storage::storage_api::write" => Ok(sway::TypeName::Tuple { type_names: vec![] }),
     
                         "u8::from" | "u8::max" | "u8::min" => Ok(sway::TypeName::Identifier {
                             name: "u8".into(),
@@ -1092,7 +1507,7 @@ impl TranslatedDefinition {
                             }
     
                             ("I8", None) => match member_access.member.as_str() {
-                                "neg" => return Ok(sway::TypeName::Identifier {
+                                "neg" | "pow" => return Ok(sway::TypeName::Identifier {
                                     name: "I8".into(),
                                     generic_parameters: None,
                                 }),
@@ -1101,7 +1516,7 @@ impl TranslatedDefinition {
                             }
 
                             ("I16", None) => match member_access.member.as_str() {
-                                "neg" => return Ok(sway::TypeName::Identifier {
+                                "neg" | "pow" => return Ok(sway::TypeName::Identifier {
                                     name: "I16".into(),
                                     generic_parameters: None,
                                 }),
@@ -1110,7 +1525,7 @@ impl TranslatedDefinition {
                             }
 
                             ("I32", None) => match member_access.member.as_str() {
-                                "neg" => return Ok(sway::TypeName::Identifier {
+                                "neg" | "pow" => return Ok(sway::TypeName::Identifier {
                                     name: "I32".into(),
                                     generic_parameters: None,
                                 }),
@@ -1119,7 +1534,7 @@ impl TranslatedDefinition {
                             }
 
                             ("I64", None) => match member_access.member.as_str() {
-                                "neg" => return Ok(sway::TypeName::Identifier {
+                                "neg" | "pow" => return Ok(sway::TypeName::Identifier {
                                     name: "I64".into(),
                                     generic_parameters: None,
                                 }),
@@ -1128,7 +1543,7 @@ impl TranslatedDefinition {
                             }
 
                             ("I128", None) => match member_access.member.as_str() {
-                                "neg" => return Ok(sway::TypeName::Identifier {
+                                "neg" | "pow" => return Ok(sway::TypeName::Identifier {
                                     name: "I128".into(),
                                     generic_parameters: None,
                                 }),
@@ -1137,7 +1552,7 @@ impl TranslatedDefinition {
                             }
 
                             ("I256", None) => match member_access.member.as_str() {
-                                "neg" => return Ok(sway::TypeName::Identifier {
+                                "neg" | "pow" => return Ok(sway::TypeName::Identifier {
                                     name: "I256".into(),
                                     generic_parameters: None,
                                 }),
@@ -1190,13 +1605,13 @@ impl TranslatedDefinition {
                             }
     
                             ("Option", Some(generic_parameters)) if generic_parameters.entries.len() == 1 => match member_access.member.as_str() {
-                                "unwrap" => Ok(generic_parameters.entries[0].type_name.clone()),
+                                "unwrap" | "unwrap_or" => Ok(generic_parameters.entries[0].type_name.clone()),
                                 
                                 _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
                             }
                             
                             ("Result", Some(generic_parameters)) if generic_parameters.entries.len() == 2 => match member_access.member.as_str() {
-                                "unwrap" => Ok(generic_parameters.entries[0].type_name.clone()),
+                                "unwrap" | "unwrap_or" => Ok(generic_parameters.entries[0].type_name.clone()),
                                 
                                 _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
                             }
@@ -1776,7 +2191,7 @@ impl TranslatedDefinition {
             sway::Expression::MemberAccess(member_access) => match &member_access.expression {
                 sway::Expression::Identifier(name) => match name.as_str() {
                     "storage" => {
-                        let Some(variable) = scope.borrow().find_variable(|v| v.borrow().is_storage && v.borrow().new_name == member_access.member) else {
+                        let Some(variable) = scope.borrow().find_variable(|v| v.borrow().is_storage && !v.borrow().is_storage_pointer() && v.borrow().new_name == member_access.member) else {
                             panic!("Failed to find storage variable in scope: `{}`", member_access.member);
                         };
 
@@ -1893,6 +2308,27 @@ impl TranslatedDefinition {
                         _ => {}
                     }
 
+                    // Check to see if container is a storage key of a struct, i.e: `storage.x.y` => `StorageKey<T>`
+                    if let sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) } = &type_name {
+                        if name == "StorageKey" {
+                            let struct_name = generic_parameters.entries[0].type_name.to_string();
+
+                            if let Some(field) = self.structs.iter().find(|s| s.name == struct_name).and_then(|s| s.fields.iter().find(|f| f.name == member_access.member)) {
+                                return Ok(sway::TypeName::Identifier {
+                                    name: "StorageKey".into(),
+                                    generic_parameters: Some(sway::GenericParameterList {
+                                        entries: vec![
+                                            sway::GenericParameter {
+                                                type_name: field.type_name.clone(),
+                                                implements: None,
+                                            },
+                                        ],
+                                    }),
+                                });
+                            }
+                        }
+                    }
+
                     // Check to see if container is a struct
                     if let Some(struct_definition) = self.structs.iter().find(|s| s.name == type_name_string) {
                         let Some(field) = struct_definition.fields.iter().find(|f| f.name == member_access.member) else {
@@ -1948,7 +2384,14 @@ impl TranslatedDefinition {
             sway::Expression::Continue => Ok(sway::TypeName::Tuple { type_names: vec![] }),
             sway::Expression::Break => Ok(sway::TypeName::Tuple { type_names: vec![] }),
             
-            sway::Expression::AsmBlock(_) => todo!("get type of asm block: {expression:#?}"),
+            sway::Expression::AsmBlock(asm_block) => match asm_block.final_expression.as_ref() {
+                Some(sway::AsmFinalExpression { type_name: Some(type_name), .. }) => Ok(type_name.clone()),
+                Some(_) => Ok(sway::TypeName::Identifier {
+                    name: "u64".into(),
+                    generic_parameters: None,
+                }),
+                None => Ok(sway::TypeName::Tuple { type_names: vec![] }),
+            }
             
             sway::Expression::Commented(_, x) => self.get_expression_type(scope.clone(), x),
         }
@@ -0,0 +1,1530 @@
+use super::{create_integer_width_conversion_expression, TranslatedDefinition};
+use crate::{forc::SWAY_STANDARDS_GIT_URL, project::{Lint, Project}, sway, Error};
+use convert_case::Case;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use solang_parser::pt as solidity;
+
+/// The Solidity names of the `address => uint` mappings that token contracts use to track balances.
+const BALANCE_MAPPING_NAMES: &[&str] = &["balanceOf", "_balanceOf", "balances", "_balances"];
+
+/// Rewrites the reads of the balance mapping of a token contract ported to a native asset into native balance queries,
+/// replacing the writes to the mapping with reverting stubs and dropping the mapping itself, since the balances are tracked by the blockchain instead.
+///
+/// The contract is left unchanged if the mapping is used in any other way.
+///
+/// Returns `false` if the project doesn't contain a contract with the supplied name.
+pub fn rewrite_native_asset_balances(project: &mut Project, definition_name: &str) -> Result<bool, Error> {
+    let Some(translated_definition) = project.translated_definitions.iter_mut().find(|d| {
+        d.name == definition_name && matches!(d.kind, Some(solidity::ContractTy::Contract(_)))
+    }) else {
+        return Ok(false);
+    };
+
+    let Some((field_name, value_type_name)) = find_balance_mapping(translated_definition) else {
+        project.report_definition_warning(Lint::NativeAsset, definition_name, None, format!("`{definition_name}` does not have a balance mapping to port to native balances"));
+        return Ok(true);
+    };
+
+    // Rewrite copies of the functions, so the contract is left unchanged if the mapping can't be dropped
+    let mut functions = translated_definition.functions.clone();
+    let mut impls = translated_definition.impls.clone();
+
+    let mut bodies = functions.iter_mut()
+        .filter_map(|f| f.body.as_mut())
+        .chain(impls.iter_mut().flat_map(|i| i.items.iter_mut()).filter_map(|item| match item {
+            sway::ImplItem::Function(f) => f.body.as_mut(),
+            _ => None,
+        }))
+        .collect::<Vec<_>>();
+
+    let mut replaced_write_count = 0;
+    let mut rewritten_read_count = 0;
+
+    for body in bodies.iter_mut() {
+        replaced_write_count += replace_balance_writes(body, &field_name);
+
+        body.visit_expressions_mut(&mut |expression| {
+            if let Some(identity) = get_balance_read_identity(expression, &field_name) {
+                *expression = create_native_balance_expression(identity.clone(), &value_type_name);
+                rewritten_read_count += 1;
+            }
+        });
+    }
+
+    let mut is_still_used = false;
+
+    for body in bodies.iter() {
+        body.visit_expressions(&mut |expression| {
+            if is_storage_field_access(expression, &field_name) {
+                is_still_used = true;
+            }
+        });
+    }
+
+    if is_still_used {
+        project.report_definition_warning(Lint::NativeAsset, definition_name, None, format!("the `{field_name}` balance mapping of `{definition_name}` is used in ways that can't be ported to native balances, leaving it unchanged"));
+        return Ok(true);
+    }
+
+    translated_definition.functions = functions;
+    translated_definition.impls = impls;
+
+    if let Some(storage) = translated_definition.storage.as_mut() {
+        storage.fields.retain(|f| f.name != field_name);
+
+        if storage.fields.is_empty() {
+            translated_definition.storage = None;
+        }
+    }
+
+    if rewritten_read_count > 0 {
+        project.report_definition_warning(Lint::NativeAsset, definition_name, None, format!("rewrote {rewritten_read_count} read(s) of the `{field_name}` balance mapping of `{definition_name}` to native balance queries, which can only be made for contract identities and revert for address identities"));
+    }
+
+    if replaced_write_count > 0 {
+        project.report_definition_warning(Lint::NativeAsset, definition_name, None, format!("replaced {replaced_write_count} write(s) to the `{field_name}` balance mapping of `{definition_name}` with reverting stubs, make sure the native asset is minted, burned and transferred in their place"));
+    }
+
+    Ok(true)
+}
+
+/// Finds the name of the storage field of the balance mapping of a token contract and the type of its values,
+/// i.e: `balance_of: StorageMap<Identity, u256>` => `("balance_of", u256)`
+fn find_balance_mapping(translated_definition: &TranslatedDefinition) -> Option<(String, sway::TypeName)> {
+    let storage = translated_definition.storage.as_ref()?;
+
+    // NOTE: Fields of inherited contracts are not recorded in `storage_fields_names`, so fall back to their default names
+    BALANCE_MAPPING_NAMES.iter()
+        .map(|name| {
+            translated_definition.storage_fields_names.get(*name).cloned()
+                .unwrap_or_else(|| crate::translate_naming_convention(name, Case::Snake))
+        })
+        .filter_map(|name| storage.fields.iter().find(|f| f.name == *name))
+        .find_map(|field| {
+            let sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) } = &field.type_name else { return None };
+
+            if name != "StorageMap" || generic_parameters.entries.len() != 2 {
+                return None;
+            }
+
+            let key_type_name = &generic_parameters.entries[0].type_name;
+            let value_type_name = &generic_parameters.entries[1].type_name;
+
+            if !matches!(key_type_name, sway::TypeName::Identifier { name, generic_parameters: None } if name == "Identity") || !value_type_name.is_uint() {
+                return None;
+            }
+
+            Some((field.name.clone(), value_type_name.clone()))
+        })
+}
+
+/// Replaces every statement of the block (and its nested blocks) that writes to the balance mapping with a reverting stub, returning how many were replaced.
+fn replace_balance_writes(block: &mut sway::Block, field_name: &str) -> usize {
+    let mut count = 0;
+
+    block.visit_blocks_mut(&mut |block| {
+        for statement in block.statements.iter_mut() {
+            let sway::Statement::Expression(expression) = statement else { continue };
+
+            if !is_balance_write(expression, field_name) {
+                continue;
+            }
+
+            // storage.x.insert(k, v) => /*TODO: ...*/ revert(0)
+            *expression = sway::Expression::Commented(
+                format!("TODO: mint, burn or transfer the native asset in place of writing to `storage.{field_name}`"),
+                Box::new(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("revert".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+                    ],
+                })),
+            );
+
+            count += 1;
+        }
+    });
+
+    count
+}
+
+/// Checks if the expression is `storage.x.get(k).write(v)` or `storage.x.insert(k, v)`.
+fn is_balance_write(expression: &sway::Expression, field_name: &str) -> bool {
+    let sway::Expression::FunctionCall(function_call) = expression else { return false };
+    let sway::Expression::MemberAccess(member_access) = &function_call.function else { return false };
+
+    match member_access.member.as_str() {
+        "write" => get_balance_entry_identity(&member_access.expression, field_name).is_some(),
+        "insert" => is_storage_field_access(&member_access.expression, field_name),
+        _ => false,
+    }
+}
+
+/// Gets `k` from `storage.x.get(k).read()`.
+fn get_balance_read_identity<'a>(expression: &'a sway::Expression, field_name: &str) -> Option<&'a sway::Expression> {
+    let sway::Expression::FunctionCall(function_call) = expression else { return None };
+    let sway::Expression::MemberAccess(member_access) = &function_call.function else { return None };
+
+    if member_access.member != "read" || !function_call.parameters.is_empty() {
+        return None;
+    }
+
+    get_balance_entry_identity(&member_access.expression, field_name)
+}
+
+/// Gets `k` from `storage.x.get(k)`.
+fn get_balance_entry_identity<'a>(expression: &'a sway::Expression, field_name: &str) -> Option<&'a sway::Expression> {
+    let sway::Expression::FunctionCall(function_call) = expression else { return None };
+    let sway::Expression::MemberAccess(member_access) = &function_call.function else { return None };
+
+    if member_access.member != "get" || function_call.parameters.len() != 1 || !is_storage_field_access(&member_access.expression, field_name) {
+        return None;
+    }
+
+    Some(&function_call.parameters[0])
+}
+
+/// Checks if the expression is `storage.x`.
+fn is_storage_field_access(expression: &sway::Expression, field_name: &str) -> bool {
+    matches!(
+        expression,
+        sway::Expression::MemberAccess(member_access)
+            if member_access.member == field_name
+            && matches!(&member_access.expression, sway::Expression::Identifier(x) if x == "storage")
+    )
+}
+
+/// Creates a query of the native balance of the identity for the default asset of the contract.
+///
+/// The native balances of addresses can't be queried from a contract, so the query reverts for address identities.
+fn create_native_balance_expression(identity: sway::Expression, value_type_name: &sway::TypeName) -> sway::Expression {
+    // match k {
+    //     Identity::ContractId(id) => std::context::balance_of(id, AssetId::default()),
+    //     Identity::Address(_) => /*TODO: ...*/ revert(0),
+    // }
+    let expression = sway::Expression::from(sway::Match {
+        expression: identity,
+        branches: vec![
+            sway::MatchBranch {
+                pattern: sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Identity::ContractId".into()),
+                    generic_parameters: None,
+                    parameters: vec![sway::Expression::Identifier("id".into())],
+                }),
+                value: sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("std::context::balance_of".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::Identifier("id".into()),
+                        sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("AssetId::default".into()),
+                            generic_parameters: None,
+                            parameters: vec![],
+                        }),
+                    ],
+                }),
+            },
+            sway::MatchBranch {
+                pattern: sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier("Identity::Address".into()),
+                    generic_parameters: None,
+                    parameters: vec![sway::Expression::Identifier("_".into())],
+                }),
+                value: sway::Expression::Commented(
+                    "TODO: the native balance of an address can't be queried from a contract".into(),
+                    Box::new(sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("revert".into()),
+                        generic_parameters: None,
+                        parameters: vec![
+                            sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+                        ],
+                    })),
+                ),
+            },
+        ],
+    });
+
+    // Native balances are always u64
+    create_integer_width_conversion_expression(expression, &sway::TypeName::Identifier {
+        name: "u64".into(),
+        generic_parameters: None,
+    }, value_type_name.uint_bits().unwrap_or(64))
+}
+
+/// Checks the type of a parameter or return value of a toplevel function.
+type TypeCheck = fn(&Option<sway::TypeName>) -> bool;
+
+fn is_identity_type(type_name: &Option<sway::TypeName>) -> bool {
+    matches!(type_name, Some(sway::TypeName::Identifier { name, generic_parameters: None }) if name == "Identity")
+}
+
+fn is_uint_type(type_name: &Option<sway::TypeName>) -> bool {
+    type_name.as_ref().is_some_and(|t| t.is_uint())
+}
+
+fn is_bool_type(type_name: &Option<sway::TypeName>) -> bool {
+    matches!(type_name, Some(sway::TypeName::Identifier { name, generic_parameters: None }) if name == "bool")
+}
+
+fn is_unit_type(type_name: &Option<sway::TypeName>) -> bool {
+    type_name.is_none()
+}
+
+fn is_bytes_type(type_name: &Option<sway::TypeName>) -> bool {
+    matches!(type_name, Some(sway::TypeName::Identifier { name, generic_parameters: None }) if name == "Bytes")
+}
+
+fn is_identity_vec_type(type_name: &Option<sway::TypeName>) -> bool {
+    is_identity_type(&get_vec_element_type_name(type_name))
+}
+
+fn is_uint_vec_type(type_name: &Option<sway::TypeName>) -> bool {
+    is_uint_type(&get_vec_element_type_name(type_name))
+}
+
+/// Gets `T` from `Vec<T>`.
+fn get_vec_element_type_name(type_name: &Option<sway::TypeName>) -> Option<sway::TypeName> {
+    let Some(sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) }) = type_name else { return None };
+
+    if name != "Vec" || generic_parameters.entries.len() != 1 {
+        return None;
+    }
+
+    Some(generic_parameters.entries[0].type_name.clone())
+}
+
+/// Finds the new names of the toplevel functions of the definition with the specified Solidity name, parameter types and return type.
+fn find_toplevel_functions(definition: &TranslatedDefinition, old_name: &str, parameter_checks: &[TypeCheck], return_check: TypeCheck) -> Vec<String> {
+    definition.toplevel_scope.borrow().functions.iter()
+        .filter_map(|f| {
+            let f = f.borrow();
+
+            let is_match = f.old_name == old_name
+                && f.parameters.entries.len() == parameter_checks.len()
+                && f.parameters.entries.iter().zip(parameter_checks.iter()).all(|(p, check)| check(&p.type_name))
+                && return_check(&f.return_type);
+
+            is_match.then(|| f.new_name.clone())
+        })
+        .collect()
+}
+
+/// Gets the parameters and return type of the toplevel function of the definition with the specified new name,
+/// even if its implementation was moved into the contract impl because it is not called internally.
+fn get_toplevel_function_signature(definition: &TranslatedDefinition, new_name: &str) -> Option<(sway::ParameterList, Option<sway::TypeName>)> {
+    definition.toplevel_scope.borrow().functions.iter()
+        .find(|f| f.borrow().new_name == new_name)
+        .map(|f| {
+            let f = f.borrow();
+            (f.parameters.clone(), f.return_type.clone())
+        })
+}
+
+/// Checks if the definition has the shape of an ERC-20 token contract (i.e: OpenZeppelin's or solmate's `ERC20`).
+pub fn is_erc20_contract(definition: &TranslatedDefinition) -> bool {
+    let has_function = |old_name: &str, parameter_checks: &[TypeCheck], return_check: TypeCheck| {
+        !find_toplevel_functions(definition, old_name, parameter_checks, return_check).is_empty()
+    };
+
+    // function totalSupply() external view returns (uint256)
+    has_function("totalSupply", &[], is_uint_type)
+        // function balanceOf(address account) external view returns (uint256)
+        && has_function("balanceOf", &[is_identity_type], is_uint_type)
+        // function transfer(address to, uint256 value) external returns (bool)
+        && has_function("transfer", &[is_identity_type, is_uint_type], is_bool_type)
+        // function allowance(address owner, address spender) external view returns (uint256)
+        && has_function("allowance", &[is_identity_type, is_identity_type], is_uint_type)
+        // function approve(address spender, uint256 value) external returns (bool)
+        && has_function("approve", &[is_identity_type, is_uint_type], is_bool_type)
+        // function transferFrom(address from, address to, uint256 value) external returns (bool)
+        && has_function("transferFrom", &[is_identity_type, is_identity_type, is_uint_type], is_bool_type)
+}
+
+/// Ports each ERC-20 token contract of the project to a native asset implementing the SRC-20 standard:
+/// * `_mint` and `_burn` mint and burn the default asset of the contract in place of writing to the balance mapping,
+///   including the writes of the functions they call (i.e: `_update` in OpenZeppelin's v5 `ERC20`).
+/// * `transfer` and `transferFrom` revert, since the native asset is transferred by transactions instead.
+/// * The balance mapping is rewritten to native balance queries, and its remaining writes to reverting stubs (see [rewrite_native_asset_balances]).
+/// * `name`, `symbol`, `decimals` and `totalSupply` are exposed through the `SRC20` abi instead of the contract abi.
+///
+/// Contracts listed in `excluded_definition_names` are skipped. Returns the names of the ported contracts.
+pub fn port_erc20_contracts_to_src20(project: &mut Project, excluded_definition_names: &[String]) -> Result<Vec<String>, Error> {
+    let definition_names = project.translated_definitions.iter()
+        .filter(|d| matches!(d.kind, Some(solidity::ContractTy::Contract(_))))
+        .filter(|d| !excluded_definition_names.contains(&d.name) && is_erc20_contract(d))
+        .map(|d| d.name.clone())
+        .collect::<Vec<_>>();
+
+    for definition_name in definition_names.iter() {
+        let translated_definition = project.translated_definitions.iter_mut().find(|d| d.name == *definition_name).unwrap();
+
+        revert_erc20_transfers(translated_definition);
+        let (burn_function_names, stale_callers) = add_erc20_supply_changes(translated_definition);
+        implement_erc20_src20_abi(translated_definition);
+
+        report_native_burns(project, definition_name, &burn_function_names);
+
+        for (caller_name, function_name) in stale_callers {
+            project.report_definition_warning(
+                Lint::NativeAsset,
+                definition_name,
+                None,
+                format!("`{definition_name}::{caller_name}` calls `{function_name}`, whose writes to the balance mapping were removed in favor of minting and burning the native asset, so it no longer moves balances"),
+            );
+        }
+
+        rewrite_native_asset_balances(project, definition_name)?;
+
+        project.report_definition_warning(
+            Lint::NativeAsset,
+            definition_name,
+            None,
+            format!("ported the ERC-20 token `{definition_name}` to an SRC-20 native asset, its `transfer` and `transferFrom` functions revert since native assets are transferred by transactions, allowances no longer authorize transfers, and amounts above `u64::MAX` can't be minted or burned"),
+        );
+    }
+
+    Ok(definition_names)
+}
+
+/// Mints and burns the default asset of the contract in its `_mint` and `_burn` functions, in place of their writes to the balance mapping
+/// and the writes of the functions they call:
+///
+/// ```ignore
+/// fn _mint(account: Identity, value: u256) {
+///     ...
+///     std::asset::mint_to(account, DEFAULT_SUB_ID, u64::try_from(value).unwrap());
+/// }
+///
+/// fn _burn(account: Identity, value: u256) {
+///     ...
+///     std::asset::burn(DEFAULT_SUB_ID, u64::try_from(value).unwrap());
+/// }
+/// ```
+///
+/// Returns the names of the burn functions, along with the name of each other function that calls a function whose writes were removed and the name of the called function.
+fn add_erc20_supply_changes(translated_definition: &mut TranslatedDefinition) -> (Vec<String>, Vec<(String, String)>) {
+    let mut supply_function_names = vec![];
+    let mut burn_function_names = vec![];
+
+    let balance_field_name = find_balance_mapping(translated_definition).map(|(field_name, _)| field_name);
+
+    for old_name in ["_mint", "_burn"] {
+        for function_name in find_toplevel_functions(translated_definition, old_name, &[is_identity_type, is_uint_type], is_unit_type) {
+            let Some(function) = translated_definition.functions.iter_mut().find(|f| f.name == function_name && f.body.is_some()) else { continue };
+
+            let account = sway::Expression::Identifier(function.parameters.entries[0].name.clone());
+            let value_type_name = function.parameters.entries[1].type_name.clone().unwrap();
+
+            // u64::try_from(value).unwrap()
+            let amount = create_integer_width_conversion_expression(
+                sway::Expression::Identifier(function.parameters.entries[1].name.clone()),
+                &value_type_name,
+                64,
+            );
+
+            let statement = sway::Statement::from(match old_name {
+                "_mint" => sway::Expression::create_function_call("std::asset::mint_to", vec![account, sway::Expression::Identifier("DEFAULT_SUB_ID".into()), amount]),
+                "_burn" => sway::Expression::create_function_call("std::asset::burn", vec![sway::Expression::Identifier("DEFAULT_SUB_ID".into()), amount]),
+                _ => unreachable!(),
+            });
+
+            let body = function.body.as_mut().unwrap();
+
+            if let Some(field_name) = balance_field_name.as_ref() {
+                body.retain_statements(&mut |s| !matches!(s, sway::Statement::Expression(x) if is_balance_write(x, field_name)));
+            }
+
+            body.statements.push(statement);
+
+            translated_definition.ensure_use_declared("std::constants::DEFAULT_SUB_ID");
+
+            if old_name == "_burn" {
+                burn_function_names.push(function_name.clone());
+            }
+
+            supply_function_names.push(function_name);
+        }
+    }
+
+    let Some(field_name) = balance_field_name else {
+        return (burn_function_names, vec![]);
+    };
+
+    // Remove the balance writes of the functions called by `_mint` and `_burn`, i.e: `_update(address(0), account, value)`
+    let mut changed_function_names = vec![];
+
+    for function_name in collect_called_functions(translated_definition, &supply_function_names) {
+        let Some(body) = translated_definition.functions.iter_mut().find(|f| f.name == function_name).and_then(|f| f.body.as_mut()) else { continue };
+
+        let mut has_balance_writes = false;
+
+        body.visit_expressions(&mut |expression| {
+            if is_balance_write(expression, &field_name) {
+                has_balance_writes = true;
+            }
+        });
+
+        if has_balance_writes {
+            body.retain_statements(&mut |s| !matches!(s, sway::Statement::Expression(x) if is_balance_write(x, &field_name)));
+            changed_function_names.push(function_name);
+        }
+    }
+
+    // Other callers of the changed functions no longer move balances, i.e: `_transfer(from, to, value)`
+    let mut stale_callers = vec![];
+
+    let bodies = translated_definition.functions.iter()
+        .map(|f| (f.name.as_str(), f.body.as_ref()))
+        .chain(translated_definition.impls.iter().flat_map(|i| i.items.iter()).filter_map(|item| match item {
+            sway::ImplItem::Function(f) => Some((f.name.as_str(), f.body.as_ref())),
+            _ => None,
+        }));
+
+    for (caller_name, body) in bodies {
+        let Some(body) = body else { continue };
+
+        if supply_function_names.iter().chain(changed_function_names.iter()).any(|name| name == caller_name) {
+            continue;
+        }
+
+        for function_name in changed_function_names.iter() {
+            if get_called_function_names(body).contains(function_name) {
+                stale_callers.push((caller_name.to_string(), function_name.clone()));
+            }
+        }
+    }
+
+    (burn_function_names, stale_callers)
+}
+
+/// Collects the names of the toplevel functions called by the functions with the supplied names, including the functions they call in turn.
+fn collect_called_functions(translated_definition: &TranslatedDefinition, function_names: &[String]) -> Vec<String> {
+    let mut result: Vec<String> = vec![];
+    let mut pending = function_names.to_vec();
+
+    while let Some(function_name) = pending.pop() {
+        let Some(body) = translated_definition.functions.iter().find(|f| f.name == function_name).and_then(|f| f.body.as_ref()) else { continue };
+
+        for called_function_name in get_called_function_names(body) {
+            if function_names.contains(&called_function_name) || result.contains(&called_function_name) {
+                continue;
+            }
+
+            if translated_definition.functions.iter().any(|f| f.name == called_function_name) {
+                result.push(called_function_name.clone());
+                pending.push(called_function_name);
+            }
+        }
+    }
+
+    result
+}
+
+/// Gets the names of the functions called by name within the block, i.e: `_update(from, to, value)` => `_update`
+fn get_called_function_names(block: &sway::Block) -> Vec<String> {
+    let mut result = vec![];
+
+    block.visit_expressions(&mut |expression| {
+        if let sway::Expression::FunctionCall(function_call) = expression {
+            if let sway::Expression::Identifier(name) = &function_call.function {
+                let name = name.trim_start_matches("::").to_string();
+
+                if !result.contains(&name) {
+                    result.push(name);
+                }
+            }
+        }
+    });
+
+    result
+}
+
+/// Makes the `transfer` and `transferFrom` functions of an ERC-20 token contract revert, since the native asset can only be transferred by its holder:
+///
+/// ```ignore
+/// fn transfer(to: Identity, value: u256) -> bool {
+///     /*TODO: ...*/ revert(0)
+/// }
+/// ```
+fn revert_erc20_transfers(translated_definition: &mut TranslatedDefinition) {
+    let Some(contract_impl) = translated_definition.find_contract_impl_mut() else { return };
+
+    for (old_name, parameter_count) in [("transfer", 2), ("transferFrom", 3)] {
+        let function_name = crate::translate_naming_convention(old_name, Case::Snake);
+
+        for item in contract_impl.items.iter_mut() {
+            let sway::ImplItem::Function(function) = item else { continue };
+
+            if function.name != function_name || function.parameters.entries.len() != parameter_count || function.body.is_none() {
+                continue;
+            }
+
+            function.body = Some(sway::Block {
+                statements: vec![],
+                final_expr: Some(sway::Expression::Commented(
+                    format!("TODO: `{old_name}` can't move the native asset on behalf of its holder, transfer it with a transaction instead"),
+                    Box::new(sway::Expression::create_function_call("revert", vec![sway::Expression::from(sway::Literal::DecInt(0u8.into()))])),
+                )),
+            });
+        }
+    }
+}
+
+/// Implements the `SRC20` abi for the default asset of the contract:
+///
+/// ```ignore
+/// impl SRC20 for Contract {
+///     #[storage(read)]
+///     fn total_assets() -> u64 {
+///         1
+///     }
+///
+///     #[storage(read)]
+///     fn total_supply(asset: AssetId) -> Option<u64> {
+///         if asset != AssetId::default() {
+///             return None;
+///         }
+///         Some(u64::try_from(src20_total_supply()).unwrap())
+///     }
+///     ...
+/// }
+/// ```
+fn implement_erc20_src20_abi(translated_definition: &mut TranslatedDefinition) {
+    let values = [("totalSupply", "u64"), ("name", "String"), ("symbol", "String"), ("decimals", "u8")].into_iter()
+        .map(|(old_name, value_type_name)| {
+            let value = move_contract_function(translated_definition, old_name, 0, "src20")
+                .and_then(|function| create_value_conversion(sway::Expression::create_function_call(&function.name, vec![]), function.return_type.as_ref()?, value_type_name));
+
+            (old_name, value_type_name, value)
+        })
+        .collect::<Vec<_>>();
+
+    // asset != AssetId::default()
+    let is_unknown_asset = sway::Expression::from(sway::BinaryExpression {
+        operator: "!=".into(),
+        lhs: sway::Expression::Identifier("asset".into()),
+        rhs: sway::Expression::create_function_call("AssetId::default", vec![]),
+    });
+
+    let total_assets = sway::Expression::from(sway::Literal::DecInt(1u8.into()));
+
+    implement_src20_abi(translated_definition, total_assets, is_unknown_asset, values);
+}
+
+/// Checks if the definition has the shape of an ERC-721 token contract (i.e: OpenZeppelin's or solmate's `ERC721`).
+pub fn is_erc721_contract(definition: &TranslatedDefinition) -> bool {
+    let has_function = |old_name: &str, parameter_checks: &[TypeCheck], return_check: TypeCheck| {
+        !find_toplevel_functions(definition, old_name, parameter_checks, return_check).is_empty()
+    };
+
+    // function balanceOf(address owner) external view returns (uint256)
+    has_function("balanceOf", &[is_identity_type], is_uint_type)
+        // function ownerOf(uint256 tokenId) external view returns (address)
+        && has_function("ownerOf", &[is_uint_type], is_identity_type)
+        // function approve(address to, uint256 tokenId) external
+        && has_function("approve", &[is_identity_type, is_uint_type], is_unit_type)
+        // function getApproved(uint256 tokenId) external view returns (address)
+        && has_function("getApproved", &[is_uint_type], is_identity_type)
+        // function setApprovalForAll(address operator, bool approved) external
+        && has_function("setApprovalForAll", &[is_identity_type, is_bool_type], is_unit_type)
+        // function isApprovedForAll(address owner, address operator) external view returns (bool)
+        && has_function("isApprovedForAll", &[is_identity_type, is_identity_type], is_bool_type)
+        // function transferFrom(address from, address to, uint256 tokenId) external
+        && has_function("transferFrom", &[is_identity_type, is_identity_type, is_uint_type], is_unit_type)
+}
+
+/// Ports each ERC-721 token contract of the project to native NFTs, minting a single coin of a separate asset for each token:
+/// * `_mint` and `_burn` mint and burn the asset of the token, whose sub id is the token id.
+/// * The token id of each minted asset is recorded in a `src20_token_ids` storage map.
+/// * `name` and `symbol` are exposed through the `SRC20` abi, and `tokenURI` through the `SRC7` abi under the `uri` key.
+///
+/// Returns the names of the ported contracts.
+pub fn port_erc721_contracts_to_nfts(project: &mut Project) -> Result<Vec<String>, Error> {
+    let definition_names = project.translated_definitions.iter()
+        .filter(|d| matches!(d.kind, Some(solidity::ContractTy::Contract(_))))
+        .filter(|d| is_erc721_contract(d))
+        .map(|d| d.name.clone())
+        .collect::<Vec<_>>();
+
+    for definition_name in definition_names.iter() {
+        let translated_definition = project.translated_definitions.iter_mut().find(|d| d.name == *definition_name).unwrap();
+
+        // The type of the token ids, i.e: `u256`
+        let Some(token_id_type_name) = find_toplevel_functions(translated_definition, "ownerOf", &[is_uint_type], is_identity_type).first()
+            .and_then(|function_name| get_toplevel_function_signature(translated_definition, function_name))
+            .and_then(|(parameters, _)| parameters.entries[0].type_name.clone())
+        else {
+            continue;
+        };
+
+        let stale_queries = find_erc721_ownership_queries(translated_definition);
+
+        declare_token_asset_storage(translated_definition, token_id_type_name, false);
+
+        let burn_function_names = add_erc721_supply_changes(translated_definition);
+        implement_erc721_src20_abi(translated_definition);
+        implement_token_uri_src7_abi(translated_definition, "tokenURI");
+
+        report_native_burns(project, definition_name, &burn_function_names);
+
+        for (function_name, mut field_names) in stale_queries {
+            let last_field_name = field_names.pop().unwrap();
+
+            let field_names = match field_names.is_empty() {
+                true => format!("`{last_field_name}`"),
+                false => format!("`{}` and `{last_field_name}`", field_names.join("`, `")),
+            };
+
+            project.report_definition_warning(
+                Lint::NativeAsset,
+                definition_name,
+                None,
+                format!("`{definition_name}::{function_name}` relies on the {field_names} ownership bookkeeping of the contract, which is not updated when the native NFTs are transferred by transactions, so it can act on stale owners and balances"),
+            );
+        }
+
+        project.report_definition_warning(
+            Lint::NativeAsset,
+            definition_name,
+            None,
+            format!("ported the ERC-721 token `{definition_name}` to native NFTs, its `transferFrom`, `approve` and `setApprovalForAll` functions and its ownership queries only reflect the bookkeeping of the contract since native assets are transferred by transactions"),
+        );
+    }
+
+    Ok(definition_names)
+}
+
+/// Mints and burns the asset of the token in the `_mint` and `_burn` functions of an ERC-721 token contract:
+///
+/// ```ignore
+/// fn _mint(to: Identity, token_id: u256) {
+///     ...
+///     storage.src20_token_ids.insert(AssetId::new(ContractId::this(), b256::from(token_id)), token_id);
+///     storage.src20_total_assets.write(storage.src20_total_assets.read() + 1);
+///     std::asset::mint_to(to, b256::from(token_id), 1);
+/// }
+///
+/// fn _burn(token_id: u256) {
+///     ...
+///     let _ = storage.src20_token_ids.remove(AssetId::new(ContractId::this(), b256::from(token_id)));
+///     storage.src20_total_assets.write(storage.src20_total_assets.read() - 1);
+///     std::asset::burn(b256::from(token_id), 1);
+/// }
+/// ```
+fn add_erc721_supply_changes(translated_definition: &mut TranslatedDefinition) -> Vec<String> {
+    let mint_function_names = find_toplevel_functions(translated_definition, "_mint", &[is_identity_type, is_uint_type], is_unit_type);
+    let burn_function_names = find_toplevel_functions(translated_definition, "_burn", &[is_uint_type], is_unit_type);
+
+    for function_name in mint_function_names.iter().chain(burn_function_names.iter()) {
+        let Some(function) = translated_definition.functions.iter_mut().find(|f| f.name == *function_name && f.body.is_some()) else { continue };
+
+        let token_id = sway::Expression::Identifier(function.parameters.entries.last().unwrap().name.clone());
+
+        // b256::from(token_id)
+        let sub_id = sway::Expression::create_function_call("b256::from", vec![token_id.clone()]);
+
+        // AssetId::new(ContractId::this(), b256::from(token_id))
+        let asset = sway::Expression::create_function_call("AssetId::new", vec![sway::Expression::create_function_call("ContractId::this", vec![]), sub_id.clone()]);
+
+        let statements = if mint_function_names.contains(function_name) {
+            let account = sway::Expression::Identifier(function.parameters.entries[0].name.clone());
+
+            vec![
+                sway::Statement::from(create_storage_method_call("src20_token_ids", "insert", vec![asset, token_id])),
+                sway::Statement::from(create_storage_method_call("src20_total_assets", "write", vec![
+                    sway::Expression::from(sway::BinaryExpression {
+                        operator: "+".into(),
+                        lhs: create_storage_method_call("src20_total_assets", "read", vec![]),
+                        rhs: sway::Expression::from(sway::Literal::DecInt(1u8.into())),
+                    }),
+                ])),
+                sway::Statement::from(sway::Expression::create_function_call("std::asset::mint_to", vec![account, sub_id, sway::Expression::from(sway::Literal::DecInt(1u8.into()))])),
+            ]
+        } else {
+            vec![
+                // `remove` returns whether the key was present, which has to be discarded explicitly
+                create_let_statement("_", false, create_storage_method_call("src20_token_ids", "remove", vec![asset])),
+                sway::Statement::from(create_storage_method_call("src20_total_assets", "write", vec![
+                    sway::Expression::from(sway::BinaryExpression {
+                        operator: "-".into(),
+                        lhs: create_storage_method_call("src20_total_assets", "read", vec![]),
+                        rhs: sway::Expression::from(sway::Literal::DecInt(1u8.into())),
+                    }),
+                ])),
+                sway::Statement::from(sway::Expression::create_function_call("std::asset::burn", vec![sub_id, sway::Expression::from(sway::Literal::DecInt(1u8.into()))])),
+            ]
+        };
+
+        function.body.as_mut().unwrap().statements.extend(statements);
+    }
+
+    burn_function_names
+}
+
+/// Finds the abi functions of an ERC-721 token contract that rely on the storage fields read by its `ownerOf` and `balanceOf` functions
+/// (i.e: `_owners` and `_balances`), returning their names along with the names of the fields they access.
+fn find_erc721_ownership_queries(translated_definition: &TranslatedDefinition) -> Vec<(String, Vec<String>)> {
+    let mut query_function_names = find_toplevel_functions(translated_definition, "ownerOf", &[is_uint_type], is_identity_type);
+    query_function_names.extend(find_toplevel_functions(translated_definition, "balanceOf", &[is_identity_type], is_uint_type));
+
+    // The implementations of the queries may have been moved into the contract impl if they are not called internally
+    let contract_functions = translated_definition.find_contract_impl()
+        .into_iter()
+        .flat_map(|i| i.items.iter())
+        .filter_map(|item| match item {
+            sway::ImplItem::Function(f) => Some(f),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Collects the storage fields accessed by a function body and the toplevel functions it calls
+    let get_accessed_field_names = |body: &sway::Block| {
+        let mut bodies = vec![body];
+
+        let called_function_names = get_called_function_names(body).into_iter()
+            .filter(|name| translated_definition.functions.iter().any(|f| f.name == *name))
+            .collect::<Vec<_>>();
+
+        for function_name in called_function_names.iter().chain(collect_called_functions(translated_definition, &called_function_names).iter()) {
+            bodies.extend(translated_definition.functions.iter().filter(|f| f.name == *function_name).filter_map(|f| f.body.as_ref()));
+        }
+
+        let mut field_names = vec![];
+
+        for body in bodies {
+            body.visit_expressions(&mut |expression| {
+                let sway::Expression::MemberAccess(member_access) = expression else { return };
+
+                if matches!(&member_access.expression, sway::Expression::Identifier(name) if name == "storage") && !field_names.contains(&member_access.member) {
+                    field_names.push(member_access.member.clone());
+                }
+            });
+        }
+
+        field_names
+    };
+
+    let ownership_field_names = query_function_names.iter()
+        .filter_map(|function_name| {
+            translated_definition.functions.iter().find(|f| f.name == *function_name)
+                .or_else(|| contract_functions.iter().copied().find(|f| f.name == *function_name))
+        })
+        .filter_map(|f| f.body.as_ref())
+        .flat_map(get_accessed_field_names)
+        .collect::<Vec<_>>();
+
+    let mut result = vec![];
+
+    for function in contract_functions.iter() {
+        let Some(body) = function.body.as_ref() else { continue };
+
+        let mut field_names = get_accessed_field_names(body).into_iter()
+            .filter(|field_name| ownership_field_names.contains(field_name))
+            .collect::<Vec<_>>();
+
+        if field_names.is_empty() {
+            continue;
+        }
+
+        field_names.sort();
+        result.push((function.name.clone(), field_names));
+    }
+
+    result
+}
+
+/// Implements the `SRC20` abi for the assets of the tokens minted by an ERC-721 token contract:
+///
+/// ```ignore
+/// impl SRC20 for Contract {
+///     #[storage(read)]
+///     fn total_assets() -> u64 {
+///         storage.src20_total_assets.read()
+///     }
+///
+///     #[storage(read)]
+///     fn total_supply(asset: AssetId) -> Option<u64> {
+///         if storage.src20_token_ids.get(asset).try_read().is_none() {
+///             return None;
+///         }
+///         Some(1)
+///     }
+///     ...
+/// }
+/// ```
+fn implement_erc721_src20_abi(translated_definition: &mut TranslatedDefinition) {
+    let mut values = vec![("totalSupply", "u64", Some(sway::Expression::from(sway::Literal::DecInt(1u8.into()))))];
+
+    for old_name in ["name", "symbol"] {
+        let value = move_contract_function(translated_definition, old_name, 0, "src20")
+            .and_then(|function| create_value_conversion(sway::Expression::create_function_call(&function.name, vec![]), function.return_type.as_ref()?, "String"));
+
+        values.push((old_name, "String", value));
+    }
+
+    values.push(("decimals", "u8", Some(sway::Expression::from(sway::Literal::DecInt(0u8.into())))));
+
+    let total_assets = create_storage_method_call("src20_total_assets", "read", vec![]);
+
+    implement_src20_abi(translated_definition, total_assets, create_unknown_token_asset_expression(), values);
+}
+
+/// Implements the `SRC7` abi for the assets of the tokens minted by an ERC-721 or ERC-1155 token contract,
+/// exposing its token URI function (i.e: `tokenURI` or `uri`) under the `uri` key:
+///
+/// ```ignore
+/// impl SRC7 for Contract {
+///     #[storage(read)]
+///     fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+///         if storage.src20_token_ids.get(asset).try_read().is_none() || key != String::from_ascii_str("uri") {
+///             return None;
+///         }
+///         Some(Metadata::String(src7_token_uri(storage.src20_token_ids.get(asset).read())))
+///     }
+/// }
+/// ```
+fn implement_token_uri_src7_abi(translated_definition: &mut TranslatedDefinition, old_name: &str) {
+    let Some(function) = move_contract_function(translated_definition, old_name, 1, "src7") else { return };
+
+    let token_id = sway::Expression::create_method_call(
+        create_storage_method_call("src20_token_ids", "get", vec![sway::Expression::Identifier("asset".into())]),
+        "read",
+        vec![],
+    );
+
+    let Some(uri) = function.return_type.as_ref()
+        .and_then(|return_type| create_value_conversion(sway::Expression::create_function_call(&function.name, vec![token_id]), return_type, "String"))
+    else {
+        return;
+    };
+
+    translated_definition.ensure_use_declared("standards::src7::SRC7");
+    translated_definition.ensure_use_declared("standards::src7::Metadata");
+
+    // storage.src20_token_ids.get(asset).try_read().is_none() || key != String::from_ascii_str("uri")
+    let is_unknown_metadata = sway::Expression::from(sway::BinaryExpression {
+        operator: "||".into(),
+        lhs: create_unknown_token_asset_expression(),
+        rhs: sway::Expression::from(sway::BinaryExpression {
+            operator: "!=".into(),
+            lhs: sway::Expression::Identifier("key".into()),
+            rhs: sway::Expression::create_function_call("String::from_ascii_str", vec![sway::Expression::from(sway::Literal::String("uri".into()))]),
+        }),
+    });
+
+    translated_definition.impls.push(sway::Impl {
+        generic_parameters: None,
+        type_name: sway::TypeName::create_identifier("SRC7"),
+        for_type_name: Some(sway::TypeName::create_identifier("Contract")),
+        items: vec![
+            create_src_abi_function(
+                "metadata",
+                vec![("asset", sway::TypeName::create_identifier("AssetId")), ("key", sway::TypeName::create_identifier("String"))],
+                create_option_type_name(sway::TypeName::create_identifier("Metadata")),
+                Some(is_unknown_metadata),
+                sway::Expression::create_function_call("Some", vec![sway::Expression::create_function_call("Metadata::String", vec![uri])]),
+            ),
+        ],
+    });
+}
+
+/// Checks if the definition has the shape of an ERC-1155 multi-token contract (i.e: OpenZeppelin's or solmate's `ERC1155`).
+pub fn is_erc1155_contract(definition: &TranslatedDefinition) -> bool {
+    let has_function = |old_name: &str, parameter_checks: &[TypeCheck], return_check: TypeCheck| {
+        !find_toplevel_functions(definition, old_name, parameter_checks, return_check).is_empty()
+    };
+
+    // function balanceOf(address account, uint256 id) external view returns (uint256)
+    has_function("balanceOf", &[is_identity_type, is_uint_type], is_uint_type)
+        // function balanceOfBatch(address[] calldata accounts, uint256[] calldata ids) external view returns (uint256[] memory)
+        && has_function("balanceOfBatch", &[is_identity_vec_type, is_uint_vec_type], is_uint_vec_type)
+        // function setApprovalForAll(address operator, bool approved) external
+        && has_function("setApprovalForAll", &[is_identity_type, is_bool_type], is_unit_type)
+        // function isApprovedForAll(address account, address operator) external view returns (bool)
+        && has_function("isApprovedForAll", &[is_identity_type, is_identity_type], is_bool_type)
+        // function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes calldata data) external
+        && has_function("safeTransferFrom", &[is_identity_type, is_identity_type, is_uint_type, is_uint_type, is_bytes_type], is_unit_type)
+        // function safeBatchTransferFrom(address from, address to, uint256[] calldata ids, uint256[] calldata values, bytes calldata data) external
+        && has_function("safeBatchTransferFrom", &[is_identity_type, is_identity_type, is_uint_vec_type, is_uint_vec_type, is_bytes_type], is_unit_type)
+}
+
+/// Ports each ERC-1155 multi-token contract of the project to Fuel's multi-asset model, minting a separate asset for each token id:
+/// * `_mint` and `_burn` mint and burn the asset of the token id through the `src20_mint` and `src20_burn` functions.
+/// * `_mintBatch` and `_burnBatch` call `src20_mint` and `src20_burn` in a loop over the token ids.
+/// * The token id and the total supply of each minted asset are recorded in the `src20_token_ids` and `src20_total_supplies` storage maps.
+/// * `name` and `symbol` (when declared) are exposed through the `SRC20` abi, and `uri` through the `SRC7` abi under the `uri` key.
+///
+/// Returns the names of the ported contracts.
+pub fn port_erc1155_contracts_to_multi_assets(project: &mut Project) -> Result<Vec<String>, Error> {
+    let definition_names = project.translated_definitions.iter()
+        .filter(|d| matches!(d.kind, Some(solidity::ContractTy::Contract(_))))
+        .filter(|d| is_erc1155_contract(d))
+        .map(|d| d.name.clone())
+        .collect::<Vec<_>>();
+
+    for definition_name in definition_names.iter() {
+        let translated_definition = project.translated_definitions.iter_mut().find(|d| d.name == *definition_name).unwrap();
+
+        // The types of the token ids and amounts, i.e: `u256`
+        let Some((token_id_type_name, value_type_name)) = find_toplevel_functions(translated_definition, "balanceOf", &[is_identity_type, is_uint_type], is_uint_type).first()
+            .and_then(|function_name| get_toplevel_function_signature(translated_definition, function_name))
+            .and_then(|(parameters, return_type)| Some((parameters.entries[1].type_name.clone()?, return_type?)))
+        else {
+            continue;
+        };
+
+        declare_token_asset_storage(translated_definition, token_id_type_name.clone(), true);
+
+        let burn_function_names = add_erc1155_supply_changes(translated_definition, &token_id_type_name, &value_type_name);
+        implement_erc1155_src20_abi(translated_definition);
+        implement_token_uri_src7_abi(translated_definition, "uri");
+
+        report_native_burns(project, definition_name, &burn_function_names);
+
+        project.report_definition_warning(
+            Lint::NativeAsset,
+            definition_name,
+            None,
+            format!("ported the ERC-1155 token `{definition_name}` to native assets, its `safeTransferFrom`, `safeBatchTransferFrom` and `setApprovalForAll` functions and its balance queries only reflect the bookkeeping of the contract since native assets are transferred by transactions, and amounts above `u64::MAX` can't be minted or burned"),
+        );
+    }
+
+    Ok(definition_names)
+}
+
+/// Mints and burns the assets of the token ids in the `_mint`, `_mintBatch`, `_burn` and `_burnBatch` functions of an ERC-1155 token contract:
+///
+/// ```ignore
+/// fn _mint(to: Identity, id: u256, value: u256, data: Bytes) {
+///     ...
+///     src20_mint(to, id, value);
+/// }
+///
+/// fn _mint_batch(to: Identity, ids: Vec<u256>, values: Vec<u256>, data: Bytes) {
+///     ...
+///     {
+///         let mut i = 0;
+///         while i < ids.len() {
+///             src20_mint(to, ids.get(i).unwrap(), values.get(i).unwrap());
+///             i += 1;
+///         }
+///     }
+/// }
+/// ```
+fn add_erc1155_supply_changes(translated_definition: &mut TranslatedDefinition, token_id_type_name: &sway::TypeName, value_type_name: &sway::TypeName) -> Vec<String> {
+    let mut burn_function_names = vec![];
+
+    let mint_functions = [
+        (find_toplevel_functions(translated_definition, "_mint", &[is_identity_type, is_uint_type, is_uint_type, is_bytes_type], is_unit_type), false),
+        (find_toplevel_functions(translated_definition, "_mintBatch", &[is_identity_type, is_uint_vec_type, is_uint_vec_type, is_bytes_type], is_unit_type), true),
+    ];
+
+    let burn_functions = [
+        (find_toplevel_functions(translated_definition, "_burn", &[is_identity_type, is_uint_type, is_uint_type], is_unit_type), false),
+        (find_toplevel_functions(translated_definition, "_burnBatch", &[is_identity_type, is_uint_vec_type, is_uint_vec_type], is_unit_type), true),
+    ];
+
+    for (is_mint, functions) in [(true, mint_functions), (false, burn_functions)] {
+        if functions.iter().all(|(function_names, _)| function_names.is_empty()) {
+            continue;
+        }
+
+        let helper_function_name = if is_mint { "src20_mint" } else { "src20_burn" };
+
+        for (function_names, is_batch) in functions {
+            for function_name in function_names {
+                let Some(function) = translated_definition.functions.iter_mut().find(|f| f.name == function_name && f.body.is_some()) else { continue };
+
+                let parameters = function.parameters.entries.iter()
+                    .map(|p| sway::Expression::Identifier(p.name.clone()))
+                    .collect::<Vec<_>>();
+
+                let (ids, values) = (parameters[1].clone(), parameters[2].clone());
+
+                // src20_mint(to, id, value) or src20_burn(id, value)
+                let create_helper_call = |id: sway::Expression, value: sway::Expression| {
+                    if is_mint {
+                        sway::Expression::create_function_call(helper_function_name, vec![parameters[0].clone(), id, value])
+                    } else {
+                        sway::Expression::create_function_call(helper_function_name, vec![id, value])
+                    }
+                };
+
+                let statement = if is_batch {
+                    // x.get(i).unwrap()
+                    let create_element_access = |x: sway::Expression| {
+                        sway::Expression::create_method_call(sway::Expression::create_method_call(x, "get", vec![sway::Expression::Identifier("i".into())]), "unwrap", vec![])
+                    };
+
+                    sway::Statement::from(sway::Expression::from(sway::Block {
+                        statements: vec![
+                            // let mut i = 0;
+                            create_let_statement("i", true, sway::Expression::from(sway::Literal::DecInt(0u8.into()))),
+
+                            // while i < ids.len() { ... }
+                            sway::Statement::from(sway::Expression::from(sway::While {
+                                condition: sway::Expression::from(sway::BinaryExpression {
+                                    operator: "<".into(),
+                                    lhs: sway::Expression::Identifier("i".into()),
+                                    rhs: sway::Expression::create_method_call(ids.clone(), "len", vec![]),
+                                }),
+
+                                body: sway::Block {
+                                    statements: vec![
+                                        sway::Statement::from(create_helper_call(create_element_access(ids), create_element_access(values))),
+
+                                        // i += 1;
+                                        sway::Statement::from(sway::Expression::from(sway::BinaryExpression {
+                                            operator: "+=".into(),
+                                            lhs: sway::Expression::Identifier("i".into()),
+                                            rhs: sway::Expression::from(sway::Literal::DecInt(1u8.into())),
+                                        })),
+                                    ],
+                                    final_expr: None,
+                                },
+                            })),
+                        ],
+                        final_expr: None,
+                    }))
+                } else {
+                    sway::Statement::from(create_helper_call(ids, values))
+                };
+
+                function.body.as_mut().unwrap().statements.push(statement);
+
+                *translated_definition.function_call_counts.entry(helper_function_name.into()).or_insert(0) += 1;
+
+                if !is_mint {
+                    burn_function_names.push(function_name);
+                }
+            }
+        }
+
+        let helper_function = create_erc1155_supply_change_function(is_mint, token_id_type_name, value_type_name);
+        translated_definition.functions.push(helper_function);
+    }
+
+    burn_function_names
+}
+
+/// Creates the `src20_mint` or `src20_burn` function of an ERC-1155 token contract:
+///
+/// ```ignore
+/// #[storage(read, write)]
+/// fn src20_mint(to: Identity, id: u256, value: u256) {
+///     let asset = AssetId::new(ContractId::this(), b256::from(id));
+///     let amount = u64::try_from(value).unwrap();
+///     if storage.src20_token_ids.get(asset).try_read().is_none() {
+///         storage.src20_token_ids.insert(asset, id);
+///         storage.src20_total_assets.write(storage.src20_total_assets.read() + 1);
+///     }
+///     storage.src20_total_supplies.insert(asset, storage.src20_total_supplies.get(asset).try_read().unwrap_or(0) + amount);
+///     std::asset::mint_to(to, b256::from(id), amount);
+/// }
+///
+/// #[storage(read, write)]
+/// fn src20_burn(id: u256, value: u256) {
+///     let asset = AssetId::new(ContractId::this(), b256::from(id));
+///     let amount = u64::try_from(value).unwrap();
+///     storage.src20_total_supplies.insert(asset, storage.src20_total_supplies.get(asset).read() - amount);
+///     std::asset::burn(b256::from(id), amount);
+/// }
+/// ```
+fn create_erc1155_supply_change_function(is_mint: bool, token_id_type_name: &sway::TypeName, value_type_name: &sway::TypeName) -> sway::Function {
+    let id = sway::Expression::Identifier("id".into());
+    let asset = sway::Expression::Identifier("asset".into());
+    let amount = sway::Expression::Identifier("amount".into());
+
+    // b256::from(id)
+    let sub_id = sway::Expression::create_function_call("b256::from", vec![id.clone()]);
+
+    let mut statements = vec![
+        // let asset = AssetId::new(ContractId::this(), b256::from(id));
+        create_let_statement("asset", false, sway::Expression::create_function_call("AssetId::new", vec![sway::Expression::create_function_call("ContractId::this", vec![]), sub_id.clone()])),
+
+        // let amount = u64::try_from(value).unwrap();
+        create_let_statement("amount", false, create_integer_width_conversion_expression(sway::Expression::Identifier("value".into()), value_type_name, 64)),
+    ];
+
+    let mut parameters = vec![("id", token_id_type_name.clone()), ("value", value_type_name.clone())];
+
+    if is_mint {
+        parameters.insert(0, ("to", sway::TypeName::create_identifier("Identity")));
+
+        statements.extend([
+            sway::Statement::from(sway::Expression::from(sway::If {
+                condition: Some(create_unknown_token_asset_expression()),
+                then_body: sway::Block {
+                    statements: vec![
+                        sway::Statement::from(create_storage_method_call("src20_token_ids", "insert", vec![asset.clone(), id])),
+                        sway::Statement::from(create_storage_method_call("src20_total_assets", "write", vec![
+                            sway::Expression::from(sway::BinaryExpression {
+                                operator: "+".into(),
+                                lhs: create_storage_method_call("src20_total_assets", "read", vec![]),
+                                rhs: sway::Expression::from(sway::Literal::DecInt(1u8.into())),
+                            }),
+                        ])),
+                    ],
+                    final_expr: None,
+                },
+                else_if: None,
+            })),
+
+            sway::Statement::from(create_storage_method_call("src20_total_supplies", "insert", vec![
+                asset.clone(),
+                sway::Expression::from(sway::BinaryExpression {
+                    operator: "+".into(),
+                    lhs: sway::Expression::create_method_call(
+                        sway::Expression::create_method_call(create_storage_method_call("src20_total_supplies", "get", vec![asset]), "try_read", vec![]),
+                        "unwrap_or",
+                        vec![sway::Expression::from(sway::Literal::DecInt(0u8.into()))],
+                    ),
+                    rhs: amount.clone(),
+                }),
+            ])),
+
+            sway::Statement::from(sway::Expression::create_function_call("std::asset::mint_to", vec![sway::Expression::Identifier("to".into()), sub_id, amount])),
+        ]);
+    } else {
+        statements.extend([
+            sway::Statement::from(create_storage_method_call("src20_total_supplies", "insert", vec![
+                asset.clone(),
+                sway::Expression::from(sway::BinaryExpression {
+                    operator: "-".into(),
+                    lhs: sway::Expression::create_method_call(create_storage_method_call("src20_total_supplies", "get", vec![asset]), "read", vec![]),
+                    rhs: amount.clone(),
+                }),
+            ])),
+
+            sway::Statement::from(sway::Expression::create_function_call("std::asset::burn", vec![sub_id, amount])),
+        ]);
+    }
+
+    sway::Function {
+        comment: None,
+        attributes: Some(sway::AttributeList {
+            attributes: vec![sway::Attribute {
+                name: "storage".into(),
+                parameters: Some(vec!["read".into(), "write".into()]),
+            }],
+        }),
+        is_public: false,
+        name: if is_mint { "src20_mint" } else { "src20_burn" }.into(),
+        generic_parameters: None,
+        parameters: sway::ParameterList {
+            entries: parameters.into_iter()
+                .map(|(name, type_name)| sway::Parameter {
+                    is_ref: false,
+                    is_mut: false,
+                    name: name.into(),
+                    type_name: Some(type_name),
+                })
+                .collect(),
+        },
+        return_type: None,
+        body: Some(sway::Block {
+            statements,
+            final_expr: None,
+        }),
+    }
+}
+
+/// Implements the `SRC20` abi for the assets of the token ids minted by an ERC-1155 token contract:
+///
+/// ```ignore
+/// impl SRC20 for Contract {
+///     #[storage(read)]
+///     fn total_assets() -> u64 {
+///         storage.src20_total_assets.read()
+///     }
+///
+///     #[storage(read)]
+///     fn total_supply(asset: AssetId) -> Option<u64> {
+///         if storage.src20_token_ids.get(asset).try_read().is_none() {
+///             return None;
+///         }
+///         Some(storage.src20_total_supplies.get(asset).read())
+///     }
+///     ...
+/// }
+/// ```
+fn implement_erc1155_src20_abi(translated_definition: &mut TranslatedDefinition) {
+    let total_supply = sway::Expression::create_method_call(
+        create_storage_method_call("src20_total_supplies", "get", vec![sway::Expression::Identifier("asset".into())]),
+        "read",
+        vec![],
+    );
+
+    let mut values = vec![("totalSupply", "u64", Some(total_supply))];
+
+    // ERC-1155 doesn't define a name or symbol, but most implementations declare them
+    for old_name in ["name", "symbol"] {
+        let value = move_contract_function(translated_definition, old_name, 0, "src20")
+            .and_then(|function| create_value_conversion(sway::Expression::create_function_call(&function.name, vec![]), function.return_type.as_ref()?, "String"));
+
+        values.push((old_name, "String", value));
+    }
+
+    values.push(("decimals", "u8", None));
+
+    let total_assets = create_storage_method_call("src20_total_assets", "read", vec![]);
+
+    implement_src20_abi(translated_definition, total_assets, create_unknown_token_asset_expression(), values);
+}
+
+/// Declares the storage fields that track the assets of the tokens minted by an ERC-721 or ERC-1155 token contract:
+///
+/// ```ignore
+/// storage {
+///     src20_token_ids: StorageMap<AssetId, u256> = StorageMap {},
+///     src20_total_assets: u64 = 0,
+///     src20_total_supplies: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+/// ```
+///
+/// The `src20_total_supplies` field is only declared when `track_supplies` is set, since each ERC-721 token has a supply of 1.
+fn declare_token_asset_storage(translated_definition: &mut TranslatedDefinition, token_id_type_name: sway::TypeName, track_supplies: bool) {
+    let create_storage_map_field = |name: &str, value_type_name: sway::TypeName| sway::StorageField {
+        comment: None,
+        name: name.into(),
+        type_name: sway::TypeName::Identifier {
+            name: "StorageMap".into(),
+            generic_parameters: Some(sway::GenericParameterList {
+                entries: vec![
+                    sway::GenericParameter { type_name: sway::TypeName::create_identifier("AssetId"), implements: None },
+                    sway::GenericParameter { type_name: value_type_name, implements: None },
+                ],
+            }),
+        },
+        value: sway::Expression::from(sway::Constructor {
+            type_name: sway::TypeName::create_identifier("StorageMap"),
+            fields: vec![],
+        }),
+    };
+
+    let storage = translated_definition.get_storage();
+
+    storage.fields.push(create_storage_map_field("src20_token_ids", token_id_type_name));
+
+    storage.fields.push(sway::StorageField {
+        comment: None,
+        name: "src20_total_assets".into(),
+        type_name: sway::TypeName::create_identifier("u64"),
+        value: sway::Expression::from(sway::Literal::DecInt(0u8.into())),
+    });
+
+    if track_supplies {
+        storage.fields.push(create_storage_map_field("src20_total_supplies", sway::TypeName::create_identifier("u64")));
+    }
+}
+
+/// Implements the `SRC20` abi, where the values of each function are only returned for known assets:
+///
+/// ```ignore
+/// impl SRC20 for Contract {
+///     #[storage(read)]
+///     fn total_assets() -> u64 {
+///         <total_assets>
+///     }
+///
+///     #[storage(read)]
+///     fn name(asset: AssetId) -> Option<String> {
+///         if <is_unknown_asset> {
+///             return None;
+///         }
+///         Some(<value>)
+///     }
+///     ...
+/// }
+/// ```
+fn implement_src20_abi(
+    translated_definition: &mut TranslatedDefinition,
+    total_assets: sway::Expression,
+    is_unknown_asset: sway::Expression,
+    values: Vec<(&str, &str, Option<sway::Expression>)>,
+) {
+    translated_definition.ensure_dependency_declared(format!("standards = {{ git = \"{SWAY_STANDARDS_GIT_URL}\", branch = \"master\" }}").as_str());
+    translated_definition.ensure_known_attribution_declared(SWAY_STANDARDS_GIT_URL);
+    translated_definition.ensure_use_declared("standards::src20::SRC20");
+    translated_definition.ensure_use_declared("std::string::String");
+
+    let mut items = vec![
+        create_src_abi_function("total_assets", vec![], sway::TypeName::create_identifier("u64"), None, total_assets),
+    ];
+
+    for (old_name, value_type_name, value) in values {
+        // Some(x) for known assets, or None
+        let (guard_condition, value) = match value {
+            Some(value) => (Some(is_unknown_asset.clone()), sway::Expression::create_function_call("Some", vec![value])),
+            None => (None, sway::Expression::Identifier("None".into())),
+        };
+
+        items.push(create_src_abi_function(
+            crate::translate_naming_convention(old_name, Case::Snake).as_str(),
+            vec![("asset", sway::TypeName::create_identifier("AssetId"))],
+            create_option_type_name(sway::TypeName::create_identifier(value_type_name)),
+            guard_condition,
+            value,
+        ));
+    }
+
+    translated_definition.impls.push(sway::Impl {
+        generic_parameters: None,
+        type_name: sway::TypeName::create_identifier("SRC20"),
+        for_type_name: Some(sway::TypeName::create_identifier("Contract")),
+        items,
+    });
+}
+
+/// Creates a `#[storage(read)]` function of a standard abi implementation, returning `None` early when the guard condition holds.
+fn create_src_abi_function(
+    name: &str,
+    parameters: Vec<(&str, sway::TypeName)>,
+    return_type: sway::TypeName,
+    guard_condition: Option<sway::Expression>,
+    value: sway::Expression,
+) -> sway::ImplItem {
+    let mut statements = vec![];
+
+    // if <guard_condition> { return None; }
+    if let Some(condition) = guard_condition {
+        statements.push(sway::Statement::from(sway::Expression::from(sway::If {
+            condition: Some(condition),
+            then_body: sway::Block {
+                statements: vec![
+                    sway::Statement::from(sway::Expression::Return(Some(Box::new(sway::Expression::Identifier("None".into()))))),
+                ],
+                final_expr: None,
+            },
+            else_if: None,
+        })));
+    }
+
+    sway::ImplItem::Function(sway::Function {
+        comment: None,
+        attributes: Some(sway::AttributeList {
+            attributes: vec![sway::Attribute {
+                name: "storage".into(),
+                parameters: Some(vec!["read".into()]),
+            }],
+        }),
+        is_public: false,
+        name: name.into(),
+        generic_parameters: None,
+        parameters: sway::ParameterList {
+            entries: parameters.into_iter()
+                .map(|(name, type_name)| sway::Parameter {
+                    is_ref: false,
+                    is_mut: false,
+                    name: name.into(),
+                    type_name: Some(type_name),
+                })
+                .collect(),
+        },
+        return_type: Some(return_type),
+        body: Some(sway::Block {
+            statements,
+            final_expr: Some(value),
+        }),
+    })
+}
+
+/// Moves a function out of the contract abi and its impl into a toplevel function, i.e: `fn name() -> String` => `fn src20_name() -> String`,
+/// so it doesn't collide with the functions of a standard abi. Returns the toplevel function.
+fn move_contract_function(translated_definition: &mut TranslatedDefinition, old_name: &str, parameter_count: usize, prefix: &str) -> Option<sway::Function> {
+    let function_name = crate::translate_naming_convention(old_name, Case::Snake);
+    let contract_impl = translated_definition.find_contract_impl_mut()?;
+
+    let index = contract_impl.items.iter().position(|item| {
+        matches!(item, sway::ImplItem::Function(f) if f.name == function_name && f.parameters.entries.len() == parameter_count)
+    })?;
+
+    let sway::ImplItem::Function(mut function) = contract_impl.items.remove(index) else { unreachable!() };
+
+    if let Some(abi) = translated_definition.abi.as_mut() {
+        abi.functions.retain(|f| f.name != function_name || f.parameters.entries.len() != parameter_count);
+    }
+
+    function.name = format!("{prefix}_{function_name}");
+    function.is_public = false;
+
+    *translated_definition.function_call_counts.entry(function.name.clone()).or_insert(0) += 1;
+    translated_definition.functions.push(function.clone());
+
+    Some(function)
+}
+
+/// Converts the value returned by a moved contract function to the value type of a standard abi function.
+fn create_value_conversion(value: sway::Expression, type_name: &sway::TypeName, value_type_name: &str) -> Option<sway::Expression> {
+    match (value_type_name, type_name.to_string().as_str()) {
+        ("String", "String") => Some(value),
+
+        // String::from_ascii_str(x)
+        ("String", "str") => Some(sway::Expression::create_function_call("String::from_ascii_str", vec![value])),
+
+        (_, _) if type_name.is_uint() => {
+            Some(create_integer_width_conversion_expression(value, type_name, sway::TypeName::create_identifier(value_type_name).uint_bits()?))
+        }
+
+        _ => None,
+    }
+}
+
+/// Creates `storage.src20_token_ids.get(asset).try_read().is_none()`.
+fn create_unknown_token_asset_expression() -> sway::Expression {
+    sway::Expression::create_method_call(
+        sway::Expression::create_method_call(
+            create_storage_method_call("src20_token_ids", "get", vec![sway::Expression::Identifier("asset".into())]),
+            "try_read",
+            vec![],
+        ),
+        "is_none",
+        vec![],
+    )
+}
+
+/// Creates `storage.field.method(parameters)`.
+fn create_storage_method_call(field_name: &str, method: &str, parameters: Vec<sway::Expression>) -> sway::Expression {
+    sway::Expression::create_method_call(
+        sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::Identifier("storage".into()),
+            member: field_name.into(),
+        }),
+        method,
+        parameters,
+    )
+}
+
+fn create_option_type_name(type_name: sway::TypeName) -> sway::TypeName {
+    sway::TypeName::Identifier {
+        name: "Option".into(),
+        generic_parameters: Some(sway::GenericParameterList {
+            entries: vec![sway::GenericParameter { type_name, implements: None }],
+        }),
+    }
+}
+
+/// Creates `let x = value;` or `let mut x = value;`.
+fn create_let_statement(name: &str, is_mutable: bool, value: sway::Expression) -> sway::Statement {
+    sway::Statement::from(sway::Let {
+        pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+            is_mutable,
+            name: name.into(),
+        }),
+        type_name: None,
+        value,
+    })
+}
+
+fn report_native_burns(project: &mut Project, definition_name: &str, function_names: &[String]) {
+    for function_name in function_names {
+        project.report_definition_warning(
+            Lint::NativeAsset,
+            definition_name,
+            None,
+            format!("`{definition_name}::{function_name}` burns native coins held by the contract, make sure the burned coins are forwarded to it"),
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use super::TranslatedDefinition;
+use crate::project::Project;
+use convert_case::Case;
+use solang_parser::pt as solidity;
+
+/// Translates the NatSpec doc comments preceding `loc` into a Sway doc comment, i.e:
+///
+/// ```text
+/// /// @notice Transfers tokens to a recipient
+/// /// @param to The recipient of the tokens
+/// /// @return Whether the transfer succeeded
+/// ```
+///
+/// becomes:
+///
+/// ```text
+/// /// Transfers tokens to a recipient
+/// ///
+/// /// # Parameters
+/// ///
+/// /// * `to` - The recipient of the tokens
+/// ///
+/// /// # Returns
+/// ///
+/// /// * Whether the transfer succeeded
+/// ```
+pub fn translate_natspec(project: &Project, translated_definition: &TranslatedDefinition, loc: &solidity::Loc) -> Option<String> {
+    let mut paragraphs = vec![];
+    let mut parameters = vec![];
+    let mut returns = vec![];
+
+    for tag in project.get_natspec_tags(&translated_definition.path, loc) {
+        let value = tag.value.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+
+        if value.is_empty() {
+            continue;
+        }
+
+        match tag.tag.as_str() {
+            "notice" | "dev" | "title" => paragraphs.push(value),
+            "author" => paragraphs.push(format!("Author: {value}")),
+            "inheritdoc" => paragraphs.push(format!("See the documentation of `{value}`.")),
+
+            "param" => {
+                // Refer to the parameter by its translated name
+                let (name, description) = value.split_once(char::is_whitespace).unwrap_or((value.as_str(), ""));
+                let name = project.translate_naming_convention(name, Case::Snake);
+
+                parameters.push(if description.trim().is_empty() {
+                    format!("* `{name}`")
+                } else {
+                    format!("* `{name}` - {}", description.trim())
+                });
+            }
+
+            "return" => returns.push(format!("* {value}")),
+
+            tag => paragraphs.push(format!("{}: {value}", tag.strip_prefix("custom:").unwrap_or(tag))),
+        }
+    }
+
+    if !parameters.is_empty() {
+        paragraphs.push(format!("# Parameters\n\n{}", parameters.join("\n")));
+    }
+
+    if !returns.is_empty() {
+        paragraphs.push(format!("# Returns\n\n{}", returns.join("\n")));
+    }
+
+    if paragraphs.is_empty() {
+        return None;
+    }
+
+    let mut lines = vec![];
+    let mut is_list_item = false;
+
+    for line in paragraphs.join("\n\n").lines() {
+        if line.is_empty() {
+            is_list_item = false;
+            lines.push("///".to_string());
+        } else if line.starts_with("* ") {
+            is_list_item = true;
+            lines.push(format!("/// {line}"));
+        } else if is_list_item {
+            // Indent the continuation lines of list items
+            lines.push(format!("///   {line}"));
+        } else {
+            lines.push(format!("/// {line}"));
+        }
+    }
+
+    Some(lines.join("\n"))
+}
+
+/// Combines the leading comment and the doc comment of an item, placing the doc comment directly before the item.
+pub fn join_comments(comment: Option<String>, doc_comment: Option<String>) -> Option<String> {
+    match (comment, doc_comment) {
+        (Some(comment), Some(doc_comment)) => Some(format!("{comment}\n{doc_comment}")),
+        (comment, doc_comment) => comment.or(doc_comment),
+    }
+}
@@ -0,0 +1,341 @@
+use super::{ItemOriginKind, TranslatedDefinition};
+use crate::{
+    emitter::emitln,
+    forc::SWAY_LIBS_GIT_URL,
+    project::{Lint, Project},
+    sway,
+};
+
+/// The member functions of the storage types that write to storage.
+const STORAGE_WRITE_FUNCTION_NAMES: &[&str] = &[
+    "write", "write_slice", "insert", "remove", "clear", "push", "pop", "swap_remove", "set", "store_vec",
+];
+
+/// The `sway_libs` function that reverts when the contract is reentered.
+const REENTRANCY_GUARD_FUNCTION_NAME: &str = "sway_libs::reentrancy::reentrancy_guard";
+
+/// Reports the contract functions that write to storage after transferring value or calling another contract.
+///
+/// Solidity contracts rely on the checks-effects-interactions pattern to stay safe from reentrancy, and a translated
+/// transfer or external call followed by a storage write breaks that pattern in the same way the original code did.
+pub fn report_reentrancy_patterns(project: &mut Project, translated_definition: &TranslatedDefinition) {
+    if project.is_lint_allowed(Lint::Reentrancy) {
+        return;
+    }
+
+    let Some(contract_impl) = translated_definition.find_contract_impl() else { return };
+
+    // Functions guarded against reentrancy are safe regardless of the order of their storage writes
+    let guard_function_names = translated_definition.functions.iter()
+        .filter(|f| f.body.as_ref().is_some_and(|body| calls_function(body, REENTRANCY_GUARD_FUNCTION_NAME)))
+        .map(|f| f.name.clone())
+        .collect::<Vec<_>>();
+
+    for item in contract_impl.items.iter() {
+        let sway::ImplItem::Function(function) = item else { continue };
+        let Some(body) = function.body.as_ref() else { continue };
+
+        if calls_function(body, REENTRANCY_GUARD_FUNCTION_NAME) || guard_function_names.iter().any(|name| calls_function(body, name)) {
+            continue;
+        }
+
+        let mut interaction = None;
+        let mut writes_after_interaction = false;
+
+        body.visit_expressions(&mut |expression| {
+            if interaction.is_some() {
+                writes_after_interaction |= is_storage_write(expression);
+            } else {
+                interaction = get_interaction_description(expression);
+            }
+        });
+
+        if let (Some(interaction), true) = (interaction, writes_after_interaction) {
+            project.report_warning(
+                Lint::Reentrancy,
+                &translated_definition.path,
+                translated_definition.get_item_origin(ItemOriginKind::Function, &function.name).map(|origin| &origin.loc),
+                format!("`{}::{}` writes to storage after {interaction}, which is open to reentrancy unless the storage is written first", translated_definition.name, function.name),
+            );
+        }
+    }
+}
+
+/// Gets a description of the interaction with another contract or identity that the expression performs, if any.
+fn get_interaction_description(expression: &sway::Expression) -> Option<&'static str> {
+    match expression {
+        // std::asset::transfer(to, asset_id, amount)
+        sway::Expression::FunctionCall(function_call) if matches!(&function_call.function, sway::Expression::Identifier(name) if name == "std::asset::transfer") => {
+            Some("transferring value")
+        }
+
+        // abi(A, x).f() or abi(A, x).f { coins: c }()
+        sway::Expression::FunctionCall(function_call) if is_abi_member_access(&function_call.function) => Some("calling another contract"),
+        sway::Expression::FunctionCallBlock(function_call_block) if is_abi_member_access(&function_call_block.function) => Some("calling another contract"),
+
+        // asm(...) { call r1 r2 r3 r4; ... }
+        sway::Expression::AsmBlock(asm_block) if asm_block.instructions.iter().any(|i| i.op_code == "call") => {
+            Some("calling another contract")
+        }
+
+        _ => None,
+    }
+}
+
+/// Checks if the expression is a member of an ABI cast, i.e: `abi(A, x).f`.
+fn is_abi_member_access(expression: &sway::Expression) -> bool {
+    let sway::Expression::MemberAccess(member_access) = expression else { return false };
+    let sway::Expression::FunctionCall(function_call) = &member_access.expression else { return false };
+    matches!(&function_call.function, sway::Expression::Identifier(name) if name == "abi")
+}
+
+/// Checks if the expression is a call to a member function that writes to a storage field, i.e: `storage.x.write(y)`.
+fn is_storage_write(expression: &sway::Expression) -> bool {
+    let sway::Expression::FunctionCall(function_call) = expression else { return false };
+    let sway::Expression::MemberAccess(member_access) = &function_call.function else { return false };
+
+    if !STORAGE_WRITE_FUNCTION_NAMES.contains(&member_access.member.as_str()) {
+        return false;
+    }
+
+    // storage.x.get(k).write(y) => storage
+    let mut container = &member_access.expression;
+
+    loop {
+        container = match container {
+            sway::Expression::MemberAccess(member_access) => &member_access.expression,
+            sway::Expression::FunctionCall(function_call) => &function_call.function,
+            sway::Expression::ArrayAccess(array_access) => &array_access.expression,
+            sway::Expression::Identifier(name) => return name == "storage",
+            _ => return false,
+        };
+    }
+}
+
+/// Gets the name of the storage field that the `nonReentrant` modifier of the definition juggles, i.e: `_status: u256`
+///
+/// Returns `None` if the definition doesn't have the shape of the `ReentrancyGuard` contract, whose `nonReentrant` modifier
+/// sets a status in storage before the function it modifies and resets it afterwards.
+fn get_reentrancy_status_field_name(definition: &TranslatedDefinition) -> Option<String> {
+    let modifier = definition.modifiers.iter().find(|m| {
+        m.old_name == "nonReentrant"
+            && m.parameters.entries.is_empty()
+            && m.captured_parameters.entries.is_empty()
+            && m.pre_body.is_some()
+            && m.post_body.is_some()
+    })?;
+
+    let (pre_function_names, field_names) = collect_guard_functions(definition, &modifier.new_name);
+
+    if pre_function_names.is_empty() || field_names.len() != 1 {
+        return None;
+    }
+
+    // The status is a single integer or boolean flag
+    let storage = definition.storage.as_ref()?;
+    let field = storage.fields.iter().find(|f| f.name == field_names[0])?;
+
+    if !field.type_name.is_uint() && field.type_name.to_string() != "bool" {
+        return None;
+    }
+
+    Some(field.name.clone())
+}
+
+/// Collects the names of the toplevel functions that implement the modifier (including the functions they call),
+/// along with the names of the storage fields they access.
+fn collect_guard_functions(definition: &TranslatedDefinition, modifier_name: &str) -> (Vec<String>, Vec<String>) {
+    let mut function_names = vec![format!("{modifier_name}_pre"), format!("{modifier_name}_post")];
+    let mut field_names: Vec<String> = vec![];
+    let mut i = 0;
+
+    while i < function_names.len() {
+        let Some(body) = definition.functions.iter().find(|f| f.name == function_names[i]).and_then(|f| f.body.as_ref()) else {
+            return (vec![], vec![]);
+        };
+
+        body.visit_expressions(&mut |expression| match expression {
+            sway::Expression::MemberAccess(member_access)
+                if matches!(&member_access.expression, sway::Expression::Identifier(name) if name == "storage") && !field_names.contains(&member_access.member) => {
+                field_names.push(member_access.member.clone());
+            }
+
+            sway::Expression::FunctionCall(function_call) => {
+                let sway::Expression::Identifier(name) = &function_call.function else { return };
+                let name = name.trim_start_matches("::");
+
+                if definition.functions.iter().any(|f| f.name == name) && !function_names.iter().any(|f| f == name) {
+                    function_names.push(name.into());
+                }
+            }
+
+            _ => {}
+        });
+
+        i += 1;
+    }
+
+    (function_names, field_names)
+}
+
+/// Maps the inherited `nonReentrant` modifier of a `ReentrancyGuard` contract to the reentrancy guard of `sway_libs`,
+/// which inspects the call frames instead of juggling a status in storage:
+///
+/// ```ignore
+/// fn non_reentrant_pre() {
+///     sway_libs::reentrancy::reentrancy_guard();
+/// }
+/// ```
+///
+/// Uncalled functions that read the status are dropped, i.e: `_reentrancyGuardEntered()`.
+/// The contract is left unchanged if the status is read in any other way.
+pub fn map_inherited_reentrancy_guard(project: &mut Project, translated_definition: &mut TranslatedDefinition) {
+    let Some(modifier) = translated_definition.modifiers.iter().find(|m| m.old_name == "nonReentrant") else { return };
+    let modifier_name = modifier.new_name.clone();
+
+    let Some(field_name) = get_reentrancy_status_field_name(translated_definition) else { return };
+    let (guard_function_names, _) = collect_guard_functions(translated_definition, &modifier_name);
+
+    let path = translated_definition.path.to_string_lossy().to_string();
+    let pre_function_name = format!("{modifier_name}_pre");
+    let post_function_name = format!("{modifier_name}_post");
+
+    // Rewrite copies of the functions, so the contract is left unchanged if the status can't be dropped
+    let mut functions = translated_definition.functions.clone();
+    let mut impls = translated_definition.impls.clone();
+
+    // fn non_reentrant_pre() { sway_libs::reentrancy::reentrancy_guard(); }
+    let pre_function = functions.iter_mut().find(|f| f.name == pre_function_name).unwrap();
+
+    pre_function.body = Some(sway::Block {
+        statements: vec![
+            sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier(REENTRANCY_GUARD_FUNCTION_NAME.into()),
+                generic_parameters: None,
+                parameters: vec![],
+            })),
+        ],
+        final_expr: None,
+    });
+
+    // Drop the post function and the helper functions that are no longer called
+    functions.retain(|f| f.name == pre_function_name || !guard_function_names.contains(&f.name));
+
+    // Drop the uncalled functions that read the status
+    let reading_function_names = functions.iter()
+        .filter(|f| f.body.as_ref().is_some_and(|body| accesses_storage_field(body, &field_name)))
+        .map(|f| f.name.clone())
+        .collect::<Vec<_>>();
+
+    let uncalled_function_names = reading_function_names.iter()
+        .filter(|name| {
+            !functions.iter().filter_map(|f| f.body.as_ref())
+                .chain(impls.iter().flat_map(|i| i.items.iter()).filter_map(|item| match item {
+                    sway::ImplItem::Function(f) => f.body.as_ref(),
+                    _ => None,
+                }))
+                .any(|body| calls_function(body, name))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    functions.retain(|f| !uncalled_function_names.contains(&f.name));
+
+    let mut bodies = functions.iter_mut()
+        .filter_map(|f| f.body.as_mut())
+        .chain(impls.iter_mut().flat_map(|i| i.items.iter_mut()).filter_map(|item| match item {
+            sway::ImplItem::Function(f) => f.body.as_mut(),
+            _ => None,
+        }))
+        .collect::<Vec<_>>();
+
+    // Remove the calls to the post function and the writes to the status, i.e: `storage._status.write(NOT_ENTERED);`
+    for body in bodies.iter_mut() {
+        body.retain_statements(&mut |statement| {
+            let sway::Statement::Expression(sway::Expression::FunctionCall(function_call)) = statement else { return true };
+
+            match &function_call.function {
+                sway::Expression::Identifier(name) => name.trim_start_matches("::") != post_function_name,
+
+                sway::Expression::MemberAccess(member_access) => {
+                    member_access.member != "write" || !matches!(
+                        &member_access.expression,
+                        sway::Expression::MemberAccess(member_access)
+                            if member_access.member == field_name
+                            && matches!(&member_access.expression, sway::Expression::Identifier(name) if name == "storage")
+                    )
+                }
+
+                _ => true,
+            }
+        });
+    }
+
+    if bodies.iter().any(|body| accesses_storage_field(body, &field_name)) {
+        emitln!(project.emitter, 
+            "NOTE: {path} - the `{field_name}` reentrancy status of `{}` is read outside of its `nonReentrant` modifier, keeping its ported implementation",
+            translated_definition.name,
+        );
+        return;
+    }
+
+    translated_definition.functions = functions;
+    translated_definition.impls = impls;
+
+    for function_name in guard_function_names.iter().chain(uncalled_function_names.iter()) {
+        if *function_name != pre_function_name && !translated_definition.functions.iter().any(|f| f.name == *function_name) {
+            translated_definition.function_call_counts.remove(function_name);
+        }
+    }
+
+    if let Some(storage) = translated_definition.storage.as_mut() {
+        storage.fields.retain(|f| f.name != field_name);
+
+        if storage.fields.is_empty() {
+            translated_definition.storage = None;
+        }
+    }
+
+    translated_definition.ensure_dependency_declared(format!("sway_libs = {{ git = \"{SWAY_LIBS_GIT_URL}\", branch = \"master\" }}").as_str());
+    translated_definition.ensure_known_attribution_declared(SWAY_LIBS_GIT_URL);
+
+    project.report_warning(
+        Lint::ReentrancyGuard,
+        &translated_definition.path,
+        translated_definition.get_item_origin(ItemOriginKind::StorageField, &field_name).map(|origin| &origin.loc),
+        format!(
+            "mapped the `nonReentrant` modifier of `{}` to `{REENTRANCY_GUARD_FUNCTION_NAME}`, dropping the `{field_name}` reentrancy status from storage",
+            translated_definition.name,
+        ),
+    );
+}
+
+/// Checks if the block (or any of its nested expressions) accesses the storage field with the supplied name, i.e: `storage.x`
+fn accesses_storage_field(block: &sway::Block, field_name: &str) -> bool {
+    let mut result = false;
+
+    block.visit_expressions(&mut |expression| {
+        if let sway::Expression::MemberAccess(member_access) = expression {
+            if member_access.member == field_name && matches!(&member_access.expression, sway::Expression::Identifier(name) if name == "storage") {
+                result = true;
+            }
+        }
+    });
+
+    result
+}
+
+/// Checks if the block (or any of its nested expressions) calls the function with the supplied name.
+fn calls_function(block: &sway::Block, function_name: &str) -> bool {
+    let mut result = false;
+
+    block.visit_expressions(&mut |expression| {
+        if let sway::Expression::FunctionCall(function_call) = expression {
+            if matches!(&function_call.function, sway::Expression::Identifier(name) if name.trim_start_matches("::") == function_name) {
+                result = true;
+            }
+        }
+    });
+
+    result
+}
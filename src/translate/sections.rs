@@ -0,0 +1,214 @@
+use super::TranslatedDefinition;
+use crate::{emitter::emitln, project::Project, sway};
+use convert_case::Case;
+use solang_parser::pt as solidity;
+use std::collections::HashSet;
+
+/// Extracts the helper functions of a translated contract whose Solidity names start with `prefix` into a separate library,
+/// which the contract declares as a dependency and imports. (Experimental)
+///
+/// The contract is not split into multiple contracts, so no cross-contract calls are generated. Splitting a contract into separate
+/// contracts that own a part of its storage is out of scope, since the remaining contract would have to be deployed with the ids of
+/// the section contracts and their access checks would no longer share its state. Only functions that can live outside of the contract
+/// are extracted: they must not access storage, must not be wrapped by the contract impl and must not reference the types, constants
+/// or other remaining functions of the contract. The reason each prefixed function was left in the contract is emitted as a note.
+///
+/// Returns `false` if the project doesn't contain a contract with the supplied name.
+pub fn extract_section_library(project: &mut Project, definition_name: &str, prefix: &str) -> bool {
+    let Some(index) = project.translated_definitions.iter().position(|d| {
+        d.name == definition_name && matches!(d.kind, Some(solidity::ContractTy::Contract(_)))
+    }) else {
+        return false;
+    };
+
+    let section_name = format!(
+        "{definition_name}{}",
+        crate::translate_naming_convention(prefix.trim_matches('_'), Case::Pascal),
+    );
+
+    // Don't split the same section twice (i.e: when the contract was imported by multiple source units)
+    if project.translated_definitions.iter().any(|d| d.name == section_name && d.path == project.translated_definitions[index].path) {
+        return true;
+    }
+
+    let translated_definition = &mut project.translated_definitions[index];
+    let (section_function_names, kept_functions) = collect_section_function_names(translated_definition, prefix);
+
+    for (function_name, reason) in kept_functions.iter() {
+        emitln!(project.emitter, 
+            "NOTE: {} - `{definition_name}::{function_name}` was left in the contract instead of the `{section_name}` library, since it {reason}",
+            translated_definition.path.to_string_lossy(),
+        );
+    }
+
+    if section_function_names.is_empty() {
+        emitln!(project.emitter, 
+            "WARNING: {} - no functions of `{definition_name}` starting with `{prefix}` can be extracted into a separate library",
+            translated_definition.path.to_string_lossy(),
+        );
+
+        return true;
+    }
+
+    let section_snake_name = crate::translate_naming_convention(section_name.as_str(), Case::Snake);
+
+    let mut section_definition = TranslatedDefinition::new(
+        translated_definition.path.clone(),
+        solidity::ContractTy::Library(solidity::Loc::Codegen),
+        section_name.clone(),
+        Vec::<String>::new(),
+    );
+
+    section_definition.uses = translated_definition.uses.clone();
+    section_definition.dependencies = translated_definition.dependencies.clone();
+    section_definition.attributions = translated_definition.attributions.clone();
+
+    // Move the section functions to the library, keeping their relative order
+    let mut functions = vec![];
+
+    for mut function in translated_definition.functions.drain(..) {
+        if !section_function_names.contains(&function.name) {
+            functions.push(function);
+            continue;
+        }
+
+        if let Some(function_call_count) = translated_definition.function_call_counts.get(&function.name) {
+            section_definition.function_call_counts.insert(function.name.clone(), *function_call_count);
+        }
+
+        function.is_public = true;
+        section_definition.functions.push(function);
+    }
+
+    translated_definition.functions = functions;
+
+    // The contract calls the section functions through a glob import of the library project
+    translated_definition.ensure_dependency_declared(format!("{section_snake_name} = {{ path = \"../{section_snake_name}\" }}").as_str());
+    translated_definition.ensure_use_declared(format!("{section_snake_name}::*").as_str());
+
+    project.translated_definitions.insert(index, section_definition);
+
+    true
+}
+
+/// Collects the names of the toplevel functions of the contract that belong to the section and can be moved to a separate library,
+/// along with the names of the section functions that have to stay in the contract and the reasons why, ordered by name.
+fn collect_section_function_names(translated_definition: &TranslatedDefinition, prefix: &str) -> (HashSet<String>, Vec<(String, String)>) {
+    let snake_prefix = crate::translate_naming_convention(prefix, Case::Snake);
+
+    let mut section_function_names = translated_definition.toplevel_scope.borrow().functions.iter()
+        .map(|f| f.borrow())
+        .filter(|f| f.old_name.starts_with(prefix) || f.new_name.starts_with(snake_prefix.as_str()))
+        .map(|f| f.new_name.clone())
+        .collect::<HashSet<_>>();
+
+    // Collect the names of the items the section functions can't reference once they are moved
+    let mut local_names = HashSet::from(["storage".to_string()]);
+
+    for x in translated_definition.constants.iter() {
+        local_names.insert(x.name.clone());
+    }
+
+    for x in translated_definition.type_definitions.iter() {
+        local_names.insert(x.name.to_string());
+    }
+
+    for x in translated_definition.enums.iter() {
+        local_names.insert(x.type_definition.name.to_string());
+    }
+
+    for x in translated_definition.structs.iter() {
+        local_names.insert(x.name.clone());
+    }
+
+    for (x, _) in translated_definition.events_enums.iter().chain(translated_definition.errors_enums.iter()) {
+        local_names.insert(x.name.clone());
+    }
+
+    for x in translated_definition.abis.iter() {
+        local_names.insert(x.name.clone());
+    }
+
+    // Collect the functions wrapped by the contract impl (i.e: `::f(x)`), which must stay in the contract
+    let mut impl_function_calls = HashSet::new();
+
+    for sway_impl in translated_definition.impls.iter() {
+        for item in sway_impl.items.iter() {
+            let sway::ImplItem::Function(function) = item else { continue };
+            let Some(body) = function.body.as_ref() else { continue };
+
+            body.visit_expressions(&mut |expression| {
+                if let sway::Expression::Identifier(name) = expression {
+                    if let Some(name) = name.strip_prefix("::") {
+                        impl_function_calls.insert(name.to_string());
+                    }
+                }
+            });
+        }
+    }
+
+    let mut kept_functions = vec![];
+
+    section_function_names.retain(|function_name| {
+        let Some(function) = translated_definition.functions.iter().find(|f| f.name == *function_name) else {
+            kept_functions.push((function_name.clone(), "is exposed by the contract abi".to_string()));
+            return false;
+        };
+
+        if impl_function_calls.contains(function_name) {
+            kept_functions.push((function_name.clone(), "is exposed by the contract abi".to_string()));
+            return false;
+        }
+
+        let code = sway::TabbedDisplayer(function).to_string();
+        let local_name = code.split(|c: char| !c.is_alphanumeric() && c != '_').find(|token| local_names.contains(*token));
+
+        match local_name {
+            Some("storage") => kept_functions.push((function_name.clone(), "accesses storage".to_string())),
+            Some(name) => kept_functions.push((function_name.clone(), format!("references `{name}`, which stays in the contract"))),
+            None => return true,
+        }
+
+        false
+    });
+
+    // Drop the functions that call contract functions which aren't part of the section, until none are left
+    loop {
+        let contract_function_names = translated_definition.functions.iter()
+            .map(|f| f.name.clone())
+            .filter(|name| !section_function_names.contains(name))
+            .collect::<HashSet<_>>();
+
+        let previous_count = section_function_names.len();
+
+        section_function_names.retain(|function_name| {
+            let function = translated_definition.functions.iter().find(|f| f.name == *function_name).unwrap();
+            let mut called_function_name = None;
+
+            if let Some(body) = function.body.as_ref() {
+                body.visit_expressions(&mut |expression| {
+                    if let sway::Expression::Identifier(name) = expression {
+                        let name = name.trim_start_matches("::");
+
+                        if called_function_name.is_none() && contract_function_names.contains(name) {
+                            called_function_name = Some(name.to_string());
+                        }
+                    }
+                });
+            }
+
+            let Some(called_function_name) = called_function_name else { return true };
+            kept_functions.push((function_name.clone(), format!("calls `{called_function_name}`, which stays in the contract")));
+
+            false
+        });
+
+        if section_function_names.len() == previous_count {
+            break;
+        }
+    }
+
+    kept_functions.sort();
+
+    (section_function_names, kept_functions)
+}
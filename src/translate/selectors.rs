@@ -0,0 +1,227 @@
+use crate::{project::Project, sway, translate::{TranslatedDefinition, TranslatedVariable}};
+use convert_case::Case;
+use num_bigint::BigUint;
+use sha3::{Digest, Keccak256};
+use solang_parser::pt as solidity;
+use std::{cell::RefCell, rc::Rc};
+
+/// Computes the 4-byte selector of a Solidity function signature, i.e: `transfer(address,uint256)` => `0xa9059cbb`.
+pub fn compute_function_selector(signature: &str) -> [u8; 4] {
+    let hash = Keccak256::digest(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Creates the `[u8; 4]` array that a `bytes4` selector is translated to, i.e: `[0xa9, 0x05, 0x9c, 0xbb]`.
+pub fn create_function_selector_expression(selector: [u8; 4]) -> sway::Expression {
+    sway::Expression::Array(sway::Array {
+        elements: selector.iter()
+            .map(|b| sway::Expression::from(sway::Literal::HexInt(BigUint::from(*b))))
+            .collect(),
+    })
+}
+
+/// Creates a reference to the `[u8; 4]` constant that `type(I).interfaceId` is translated to, i.e: `I_GREETER_INTERFACE_ID`.
+///
+/// The constant is declared in the translated definition the first time the interface identifier is referenced.
+pub fn create_interface_id_expression(translated_definition: &mut TranslatedDefinition, interface_name: &str, interface_id: [u8; 4]) -> sway::Expression {
+    let name = format!("{}_INTERFACE_ID", crate::translate_naming_convention(interface_name, Case::ScreamingSnake));
+
+    let type_name = sway::TypeName::Array {
+        type_name: Box::new(sway::TypeName::Identifier {
+            name: "u8".into(),
+            generic_parameters: None,
+        }),
+        length: 4,
+    };
+
+    if !translated_definition.constants.iter().any(|c| c.name == name) {
+        translated_definition.constants.push(sway::Constant {
+            comment: None,
+            is_public: false,
+            name: name.clone(),
+            type_name: type_name.clone(),
+            value: Some(create_function_selector_expression(interface_id)),
+        });
+
+        translated_definition.toplevel_scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+            old_name: String::new(),
+            new_name: name.clone(),
+            type_name,
+            is_constant: true,
+            ..Default::default()
+        })));
+    }
+
+    sway::Expression::Identifier(name)
+}
+
+/// Gets the canonical signature of a function of a Solidity contract or interface, i.e: `IERC20.transfer` => `transfer(address,uint256)`.
+///
+/// Overloaded functions are only resolved when the number of parameters is supplied and matches a single overload.
+pub fn get_function_signature(project: &Project, contract_name: &str, function_name: &str, parameter_count: Option<usize>) -> Option<String> {
+    let all_source_units = project.solidity_source_units.iter().map(|(_, source_unit)| source_unit).collect::<Vec<_>>();
+
+    let contract_definition = all_source_units.iter()
+        .flat_map(|source_unit| source_unit.0.iter())
+        .find_map(|source_unit_part| match source_unit_part {
+            solidity::SourceUnitPart::ContractDefinition(contract_definition) if contract_definition.name.as_ref().is_some_and(|n| n.name == contract_name) => Some(contract_definition),
+            _ => None,
+        })?;
+
+    let mut function_definitions = contract_definition.parts.iter()
+        .filter_map(|part| match part {
+            solidity::ContractPart::FunctionDefinition(function_definition) if function_definition.name.as_ref().is_some_and(|n| n.name == function_name) => Some(function_definition),
+            _ => None,
+        })
+        .filter(|f| parameter_count.is_none_or(|count| f.params.len() == count));
+
+    let function_definition = function_definitions.next()?;
+
+    if function_definitions.next().is_some() {
+        return None;
+    }
+
+    get_function_definition_signature(&all_source_units, contract_definition, function_definition)
+}
+
+/// Computes the ERC-165 interface identifier of a Solidity interface, i.e: the XOR of the selectors of all of its functions.
+///
+/// Functions of inherited interfaces are not included, matching the behavior of `type(I).interfaceId`.
+pub fn compute_interface_id(project: &Project, interface_name: &str) -> Option<[u8; 4]> {
+    let all_source_units = project.solidity_source_units.iter().map(|(_, source_unit)| source_unit).collect::<Vec<_>>();
+
+    let contract_definition = all_source_units.iter()
+        .flat_map(|source_unit| source_unit.0.iter())
+        .find_map(|source_unit_part| match source_unit_part {
+            solidity::SourceUnitPart::ContractDefinition(contract_definition) if contract_definition.name.as_ref().is_some_and(|n| n.name == interface_name) => Some(contract_definition),
+            _ => None,
+        })?;
+
+    if !matches!(contract_definition.ty, solidity::ContractTy::Interface(_)) {
+        return None;
+    }
+
+    let mut interface_id = [0u8; 4];
+
+    for part in contract_definition.parts.iter() {
+        let solidity::ContractPart::FunctionDefinition(function_definition) = part else { continue };
+
+        if !matches!(function_definition.ty, solidity::FunctionTy::Function) {
+            continue;
+        }
+
+        let signature = get_function_definition_signature(&all_source_units, contract_definition, function_definition)?;
+
+        for (x, y) in interface_id.iter_mut().zip(compute_function_selector(&signature)) {
+            *x ^= y;
+        }
+    }
+
+    Some(interface_id)
+}
+
+/// Gets the canonical signature of a Solidity function definition, i.e: `transfer(address,uint256)`.
+fn get_function_definition_signature(source_units: &[&solidity::SourceUnit], contract_definition: &solidity::ContractDefinition, function_definition: &solidity::FunctionDefinition) -> Option<String> {
+    let function_name = &function_definition.name.as_ref()?.name;
+
+    let parameter_types = function_definition.params.iter()
+        .map(|(_, p)| get_canonical_type_name(source_units, contract_definition, &p.as_ref()?.ty))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(format!("{function_name}({})", parameter_types.join(",")))
+}
+
+/// Gets the canonical name of a Solidity type as used in function signatures, i.e: `uint` => `uint256` or `IERC20` => `address`.
+fn get_canonical_type_name(source_units: &[&solidity::SourceUnit], contract_definition: &solidity::ContractDefinition, type_name: &solidity::Expression) -> Option<String> {
+    match type_name {
+        solidity::Expression::Type(_, ty) => match ty {
+            solidity::Type::Address | solidity::Type::AddressPayable | solidity::Type::Payable => Some("address".into()),
+            solidity::Type::Bool => Some("bool".into()),
+            solidity::Type::String => Some("string".into()),
+            solidity::Type::Int(bits) => Some(format!("int{bits}")),
+            solidity::Type::Uint(bits) => Some(format!("uint{bits}")),
+            solidity::Type::Bytes(length) => Some(format!("bytes{length}")),
+            solidity::Type::DynamicBytes => Some("bytes".into()),
+            _ => None,
+        },
+
+        // T[] => T[], T[N] => T[N]
+        solidity::Expression::ArraySubscript(_, element_type_name, length) => {
+            let element_type_name = get_canonical_type_name(source_units, contract_definition, element_type_name)?;
+
+            match length.as_deref() {
+                None => Some(format!("{element_type_name}[]")),
+                Some(solidity::Expression::NumberLiteral(_, length, _, _)) => Some(format!("{element_type_name}[{length}]")),
+                Some(_) => None,
+            }
+        }
+
+        // Library.Struct => Struct
+        solidity::Expression::MemberAccess(_, _, member) => get_user_defined_canonical_type_name(source_units, contract_definition, &member.name),
+
+        solidity::Expression::Variable(identifier) => get_user_defined_canonical_type_name(source_units, contract_definition, &identifier.name),
+
+        _ => None,
+    }
+}
+
+/// Gets the canonical name of a contract, enum, struct or user-defined value type, preferring the definitions of the supplied contract.
+fn get_user_defined_canonical_type_name(source_units: &[&solidity::SourceUnit], contract_definition: &solidity::ContractDefinition, name: &str) -> Option<String> {
+    let is_named = |identifier: &Option<solidity::Identifier>| identifier.as_ref().is_some_and(|i| i.name == name);
+
+    // Structs are encoded as tuples of their fields
+    let get_struct_canonical_type_name = |struct_definition: &solidity::StructDefinition| {
+        let field_type_names = struct_definition.fields.iter()
+            .map(|field| get_canonical_type_name(source_units, contract_definition, &field.ty))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(format!("({})", field_type_names.join(",")))
+    };
+
+    let other_contract_parts = source_units.iter()
+        .flat_map(|source_unit| source_unit.0.iter())
+        .filter_map(|part| match part {
+            solidity::SourceUnitPart::ContractDefinition(contract_definition) => Some(contract_definition.parts.iter()),
+            _ => None,
+        })
+        .flatten();
+
+    for part in contract_definition.parts.iter().chain(other_contract_parts) {
+        return match part {
+            // Enums are encoded as `uint8`
+            solidity::ContractPart::EnumDefinition(x) if is_named(&x.name) => Some("uint8".into()),
+            solidity::ContractPart::StructDefinition(x) if is_named(&x.name) => get_struct_canonical_type_name(x),
+
+            // User-defined value types are encoded as their underlying type
+            solidity::ContractPart::TypeDefinition(x) if x.name.name == name => get_canonical_type_name(source_units, contract_definition, &x.ty),
+
+            _ => continue,
+        };
+    }
+
+    for part in source_units.iter().flat_map(|source_unit| source_unit.0.iter()) {
+        return match part {
+            // Contracts and interfaces are encoded as addresses
+            solidity::SourceUnitPart::ContractDefinition(x) if is_named(&x.name) => Some("address".into()),
+
+            solidity::SourceUnitPart::EnumDefinition(x) if is_named(&x.name) => Some("uint8".into()),
+            solidity::SourceUnitPart::StructDefinition(x) if is_named(&x.name) => get_struct_canonical_type_name(x),
+            solidity::SourceUnitPart::TypeDefinition(x) if x.name.name == name => get_canonical_type_name(source_units, contract_definition, &x.ty),
+
+            _ => continue,
+        };
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_function_selector() {
+        assert_eq!(compute_function_selector("transfer(address,uint256)"), [0xa9, 0x05, 0x9c, 0xbb]);
+        assert_eq!(compute_function_selector("balanceOf(address)"), [0x70, 0xa0, 0x82, 0x31]);
+    }
+}
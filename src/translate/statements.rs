@@ -1,25 +1,41 @@
 use super::{
-    create_value_expression, translate_assembly_statement, translate_assignment_expression,
-    translate_expression, translate_pre_or_post_operator_value_expression, translate_type_name,
-    TranslatedDefinition, TranslatedVariable, TranslationScope,
+    create_assignment_expression, create_integer_sign_conversion_expression, create_value_expression, create_vec_with_length_expression, get_simple_expression_type,
+    translate_assembly_statement, translate_assignment_expression, translate_expression, translate_pre_or_post_operator_value_expression, translate_type_name,
+    translate_variable_access_expression, TranslatedDefinition, TranslatedVariable, TranslationScope,
 };
-use crate::{errors::Error, project::Project, sway};
-use convert_case::Case;
+use crate::{errors::Error, project::{Lint, Project}, sway};
 use num_bigint::BigUint;
-use num_traits::{One, Zero};
-use solang_parser::pt as solidity;
+use num_traits::Zero;
+use solang_parser::{helpers::CodeLocation, pt as solidity};
 use std::{cell::RefCell, rc::Rc};
 
 pub fn translate_block(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
     scope: Rc<RefCell<TranslationScope>>,
+    loc: &solidity::Loc,
     statements: &[solidity::Statement]
 ) -> Result<sway::Block, Error> {
     let mut block = sway::Block::default();
 
+    // Keep track of where the previous statement ended, so the comments between statements are preserved
+    let mut comment_offset = match loc {
+        solidity::Loc::File(_, start, _) => Some(*start),
+        _ => None,
+    };
+
     // Translate each of the statements in the block
     for statement in statements {
+        // Preserve the comments preceding the statement
+        if let (Some(start), solidity::Loc::File(_, statement_start, _)) = (comment_offset, statement.loc()) {
+            block.statements.extend(translate_comments(project, translated_definition, start, statement_start));
+
+            // Comments following the statement on the same line are kept in front of it, i.e: `x += 1; // trailing`
+            let (trailing_comments, trailing_end) = project.get_trailing_comments(&translated_definition.path, &statement.loc());
+            block.statements.extend(trailing_comments.into_iter().map(sway::Statement::Comment));
+            comment_offset = trailing_end;
+        }
+
         // Translate the statement
         let sway_statement = translate_statement(project, translated_definition, scope.clone(), statement)?;
 
@@ -51,11 +67,29 @@ pub fn translate_block(
         }
     }
 
+    // Preserve the comments following the last statement
+    if let (Some(start), solidity::Loc::File(_, _, end)) = (comment_offset, loc) {
+        block.statements.extend(translate_comments(project, translated_definition, start, *end));
+    }
+
     finalize_block_translation(project, scope.clone(), &mut block)?;
 
     Ok(block)
 }
 
+/// Translates the comments between the byte offsets `start` and `end` of the definition's source unit into comment statements.
+fn translate_comments(
+    project: &Project,
+    translated_definition: &TranslatedDefinition,
+    start: usize,
+    end: usize,
+) -> Vec<sway::Statement> {
+    project.get_comments_between(&translated_definition.path, start, end)
+        .into_iter()
+        .map(sway::Statement::Comment)
+        .collect()
+}
+
 pub fn finalize_block_translation(
     _project: &mut Project,
     scope: Rc<RefCell<TranslationScope>>,
@@ -66,8 +100,8 @@ pub fn finalize_block_translation(
         // Only check variables that are declared as statements
         let Some(statement_index) = variable.borrow().statement_index else { continue };
 
-        // If the variable has any mutations, mark it as mutable
-        if variable.borrow().mutation_count > 0 {
+        // If the variable has any mutations, mark it as mutable (unless they are writes to storage through a storage pointer)
+        if variable.borrow().mutation_count > 0 && !variable.borrow().is_storage_pointer() {
             let let_statement = match &mut block.statements[statement_index] {
                 sway::Statement::Let(let_statement) => let_statement,
                 statement => panic!("Expected let statement, found: {} - {statement:?}", sway::TabbedDisplayer(statement)),
@@ -142,6 +176,34 @@ pub fn finalize_block_translation(
     Ok(())
 }
 
+/// Evaluates `value` into a variable before executing `statements`, returning the resulting statements along with the variable to use in place of `value`:
+///
+/// ```ignore
+/// let result = x;
+/// <statements>
+/// ```
+pub fn evaluate_before_statements(
+    variable_name: &str,
+    type_name: Option<sway::TypeName>,
+    value: sway::Expression,
+    statements: impl IntoIterator<Item = sway::Statement>,
+) -> (Vec<sway::Statement>, sway::Expression) {
+    let mut result = vec![
+        sway::Statement::from(sway::Let {
+            pattern: sway::LetPattern::from(sway::LetIdentifier {
+                is_mutable: false,
+                name: variable_name.into(),
+            }),
+            type_name,
+            value,
+        }),
+    ];
+
+    result.extend(statements);
+
+    (result, sway::Expression::Identifier(variable_name.into()))
+}
+
 pub fn translate_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
@@ -149,7 +211,8 @@ pub fn translate_statement(
     statement: &solidity::Statement
 ) -> Result<sway::Statement, Error> {
     match statement {
-        solidity::Statement::Block { statements, .. } => translate_block_statement(project, translated_definition, scope.clone(), statements),
+        solidity::Statement::Block { loc, unchecked: true, statements } => translate_unchecked_block_statement(project, translated_definition, scope.clone(), loc, statements),
+        solidity::Statement::Block { loc, statements, .. } => translate_block_statement(project, translated_definition, scope.clone(), loc, statements),
         solidity::Statement::Assembly { dialect, flags, block, .. } => translate_assembly_statement(project, translated_definition, scope.clone(), dialect, flags, block),
         solidity::Statement::Args(_, named_arguments) => translate_args_statement(project, translated_definition, scope.clone(), named_arguments),
         solidity::Statement::If(_, condition, then_body, else_if) => translate_if_statement(project, translated_definition, scope.clone(), condition, then_body, else_if),
@@ -178,6 +241,7 @@ pub fn translate_block_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
     scope: Rc<RefCell<TranslationScope>>,
+    loc: &solidity::Loc,
     statements: &[solidity::Statement],
 ) -> Result<sway::Statement, Error> {
     let scope = Rc::new(RefCell::new(TranslationScope {
@@ -187,12 +251,156 @@ pub fn translate_block_statement(
 
     // Translate the block
     let translated_block = sway::Statement::from(sway::Expression::from(
-        translate_block(project, translated_definition, scope.clone(), statements)?
+        translate_block(project, translated_definition, scope.clone(), loc, statements)?
     ));
 
     Ok(translated_block)
 }
 
+#[inline]
+pub fn translate_unchecked_block_statement(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    loc: &solidity::Loc,
+    statements: &[solidity::Statement],
+) -> Result<sway::Statement, Error> {
+    let scope = Rc::new(RefCell::new(TranslationScope {
+        parent: Some(scope.clone()),
+        ..Default::default()
+    }));
+
+    let mut block = translate_block(project, translated_definition, scope.clone(), loc, statements)?;
+
+    // Keep checked arithmetic when strict overflow was requested
+    if project.strict_overflow {
+        project.report_warning(Lint::UncheckedArithmetic, &translated_definition.path, Some(loc), "`unchecked` block was translated with checked arithmetic, overflows will revert instead of wrapping");
+
+        return Ok(sway::Statement::from(sway::Expression::from(block)));
+    }
+
+    // Functions called from the block are executed with panicking on overflow disabled as well
+    let mut called_function_names = vec![];
+
+    block.visit_expressions(&mut |expression| {
+        let sway::Expression::FunctionCall(function_call) = expression else { return };
+        let sway::Expression::Identifier(name) = &function_call.function else { return };
+
+        if !called_function_names.contains(name) && translated_definition.toplevel_scope.borrow().find_function(|f| f.borrow().new_name == *name).is_some() {
+            called_function_names.push(name.clone());
+        }
+    });
+
+    if !called_function_names.is_empty() {
+        project.report_warning(
+            Lint::UncheckedArithmetic,
+            &translated_definition.path,
+            Some(loc),
+            format!(
+                "`unchecked` block calls {}, whose arithmetic will also wrap instead of reverting on overflow",
+                called_function_names.iter().map(|name| format!("`{name}`")).collect::<Vec<_>>().join(", "),
+            ),
+        );
+    }
+
+    // The flags are restored instead of enabling panicking on overflow, since the block can be executed from a function called within another `unchecked` block
+    let flags_name = scope.borrow().generate_unique_variable_name("flags");
+
+    // unchecked { x } => {
+    //     let flags = std::registers::flags();
+    //     std::flags::disable_panic_on_overflow();
+    //     x;
+    //     std::flags::set_flags(flags);
+    // }
+    let restore_flags = sway::Statement::from(sway::Expression::create_function_call("std::flags::set_flags", vec![
+        sway::Expression::Identifier(flags_name.clone()),
+    ]));
+
+    // Restore the flags before each statement that leaves the block early
+    let result_name = scope.borrow().generate_unique_variable_name("result");
+    insert_before_block_exits(&mut block, &restore_flags, &result_name, false);
+
+    block.statements.splice(0..0, [
+        sway::Statement::from(sway::Let {
+            pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+                is_mutable: false,
+                name: flags_name,
+            }),
+            type_name: None,
+            value: sway::Expression::create_function_call("std::registers::flags", vec![]),
+        }),
+        sway::Statement::from(sway::Expression::create_function_call("std::flags::disable_panic_on_overflow", vec![])),
+    ]);
+
+    if !matches!(block.statements.last(), Some(sway::Statement::Expression(sway::Expression::Return(_) | sway::Expression::Break | sway::Expression::Continue))) {
+        block.statements.push(restore_flags);
+    }
+
+    Ok(sway::Statement::from(sway::Expression::from(block)))
+}
+
+/// Inserts `statement` before each `return`, `break` and `continue` statement of the block (and its nested blocks) that leaves it.
+///
+/// The returned values are evaluated before `statement`, i.e: `return x;` => `let result = x; <statement>; return result;`
+fn insert_before_block_exits(block: &mut sway::Block, statement: &sway::Statement, result_name: &str, is_in_loop: bool) {
+    // Treat a trailing `return x`, `break` or `continue` expression as a statement
+    if let Some(sway::Expression::Return(_) | sway::Expression::Break | sway::Expression::Continue) = block.final_expr.as_ref() {
+        let final_expr = block.final_expr.take().unwrap();
+        block.statements.push(sway::Statement::from(final_expr));
+    }
+
+    let mut statements = vec![];
+
+    for mut s in block.statements.drain(..) {
+        match &mut s {
+            sway::Statement::Expression(sway::Expression::Return(value)) => {
+                if let Some(value) = value.take() {
+                    let (value_statements, result) = evaluate_before_statements(result_name, None, *value, [statement.clone()]);
+
+                    statements.extend(value_statements);
+                    statements.push(sway::Statement::from(sway::Expression::Return(Some(Box::new(result)))));
+                } else {
+                    statements.push(statement.clone());
+                    statements.push(s);
+                }
+
+                continue;
+            }
+
+            // `break` and `continue` statements of loops within the block don't leave it
+            sway::Statement::Expression(sway::Expression::Break | sway::Expression::Continue) if !is_in_loop => {
+                statements.push(statement.clone());
+            }
+
+            sway::Statement::Expression(sway::Expression::Block(x)) => insert_before_block_exits(x, statement, result_name, is_in_loop),
+            sway::Statement::Expression(sway::Expression::While(x)) => insert_before_block_exits(&mut x.body, statement, result_name, true),
+
+            sway::Statement::Expression(sway::Expression::If(x)) => {
+                let mut next = Some(x.as_mut());
+
+                while let Some(x) = next {
+                    insert_before_block_exits(&mut x.then_body, statement, result_name, is_in_loop);
+                    next = x.else_if.as_deref_mut();
+                }
+            }
+
+            sway::Statement::Expression(sway::Expression::Match(x)) => {
+                for branch in x.branches.iter_mut() {
+                    if let sway::Expression::Block(x) = &mut branch.value {
+                        insert_before_block_exits(x, statement, result_name, is_in_loop);
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        statements.push(s);
+    }
+
+    block.statements = statements;
+}
+
 #[inline]
 pub fn translate_args_statement(
     _project: &mut Project,
@@ -284,33 +492,46 @@ pub fn translate_expression_statement(
             if let solidity::Expression::List(_, parameters) = lhs.as_ref() {
                 // Check for a pure assignment without new variable declarations
                 if parameters.iter().all(|(_, p)| p.as_ref().map(|p| p.name.is_none()).unwrap_or(true)) {
-                    return Ok(sway::Statement::from(sway::Expression::from(sway::BinaryExpression {
-                        operator: "=".into(),
-                        lhs: sway::Expression::Tuple(
-                            parameters.iter()
-                                .map(|(_, p)| translate_expression(project, translated_definition, scope.clone(), &p.as_ref().unwrap().ty))
-                                .collect::<Result<Vec<_>, _>>()?
-                        ),
-                        rhs: translate_expression(project, translated_definition, scope.clone(), rhs)?,
-                    })));
+                    return translate_tuple_assignment_statement(project, translated_definition, scope.clone(), parameters, rhs);
                 }
 
-                // Collect variable translations for the scope
-                let mut variables = vec![];
+                // Add the variable translations to the scope
+                let mut variable_names = vec![];
 
                 for (_, p) in parameters.iter() {
                     let Some(p) = p.as_ref() else { continue };
                     let Some(name) = p.name.as_ref() else { continue };
 
-                    variables.push(Rc::new(RefCell::new(TranslatedVariable {
+                    let new_name = translated_definition.translate_variable_name(project, scope.clone(), name.name.as_str());
+                    variable_names.push(new_name.clone());
+
+                    let type_name = translate_type_name(project, translated_definition, &p.ty, false, false)?;
+
+                    scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
                         old_name: name.name.clone(),
-                        new_name: crate::translate_naming_convention(name.name.as_str(), Case::Snake),
-                        type_name: translate_type_name(project, translated_definition, &p.ty, false, false),
+                        new_name,
+                        type_name,
                         ..Default::default()
                     })));
                 }
 
-                scope.borrow_mut().variables.extend(variables);
+                let mut variable_names = variable_names.into_iter();
+
+                let value = translate_expression(project, translated_definition, scope.clone(), rhs.as_ref())?;
+
+                // Get the types of the skipped components from the value, i.e: `(uint a, , uint c) = f()`
+                let value_type_names = match translated_definition.get_expression_type(scope.clone(), &value)? {
+                    sway::TypeName::Tuple { type_names } if type_names.len() == parameters.len() => Some(type_names),
+                    _ => None,
+                };
+
+                let type_names = parameters.iter()
+                    .enumerate()
+                    .map(|(i, (_, p))| match p.as_ref() {
+                        Some(p) => translate_type_name(project, translated_definition, &p.ty, false, false).map(Some),
+                        None => Ok(value_type_names.as_ref().map(|t| t[i].clone())),
+                    })
+                    .collect::<Result<Option<Vec<_>>, _>>()?;
 
                 // Create the variable declaration statement
                 return Ok(sway::Statement::from(sway::Let {
@@ -318,35 +539,16 @@ pub fn translate_expression_statement(
                         parameters.iter()
                             .map(|(_, p)| sway::LetIdentifier {
                                 is_mutable: false,
-                                name: if let Some(p) = p.as_ref() {
-                                    if let Some(name) = p.name.as_ref() {
-                                        crate::translate_naming_convention(name.name.as_str(), Case::Snake)
-                                    } else {
-                                        "_".into()
-                                    }
-                                } else {
-                                    "_".into()
+                                name: match p.as_ref() {
+                                    Some(p) if p.name.is_some() => variable_names.next().unwrap(),
+                                    _ => "_".into(),
                                 },
                             })
                             .collect()
                     ),
 
-                    type_name: Some(sway::TypeName::Tuple {
-                        type_names: parameters.iter()
-                            .map(|(_, p)| {
-                                if let Some(p) = p.as_ref() {
-                                    translate_type_name(project, translated_definition, &p.ty, false, false)
-                                } else {
-                                    sway::TypeName::Identifier {
-                                        name: "_".into(),
-                                        generic_parameters: None,
-                                    }
-                                }
-                            })
-                            .collect(),
-                    }),
-                    
-                    value: translate_expression(project, translated_definition, scope.clone(), rhs.as_ref())?,
+                    type_name: type_names.map(|type_names| sway::TypeName::Tuple { type_names }),
+                    value,
                 }));
             }
         }
@@ -383,6 +585,71 @@ pub fn translate_expression_statement(
     ))
 }
 
+/// Translates an assignment to a list of existing variables, i.e: `(a, , c) = f();`
+///
+/// The value is stored in a temporary variable so that each component can be assigned on its own, which supports
+/// skipped components, storage variables and swaps such as `(a, b) = (b, a);`.
+#[inline]
+pub fn translate_tuple_assignment_statement(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Rc<RefCell<TranslationScope>>,
+    parameters: &[(solidity::Loc, Option<solidity::Parameter>)],
+    rhs: &solidity::Expression,
+) -> Result<sway::Statement, Error> {
+    let value = translate_expression(project, translated_definition, scope.clone(), rhs)?;
+
+    let sway::TypeName::Tuple { type_names } = translated_definition.get_expression_type(scope.clone(), &value)? else {
+        panic!("Expected tuple value in tuple assignment, found {} - {rhs:#?}", rhs);
+    };
+
+    if type_names.len() != parameters.len() {
+        panic!("Invalid tuple assignment: expected {} components, found {} - {rhs}", parameters.len(), type_names.len());
+    }
+
+    let variable_name = scope.borrow_mut().generate_unique_variable_name("value");
+
+    scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+        old_name: String::new(),
+        new_name: variable_name.clone(),
+        type_name: sway::TypeName::Tuple { type_names: type_names.clone() },
+        ..Default::default()
+    })));
+
+    // let value = f();
+    let mut block = sway::Block {
+        statements: vec![
+            sway::Statement::from(sway::Let {
+                pattern: sway::LetPattern::from(sway::LetIdentifier {
+                    is_mutable: false,
+                    name: variable_name.clone(),
+                }),
+                type_name: None,
+                value,
+            }),
+        ],
+        final_expr: None,
+    };
+
+    // a = value.0;
+    for (i, (_, p)) in parameters.iter().enumerate() {
+        let Some(p) = p.as_ref() else { continue };
+
+        let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), &p.ty)?;
+
+        let component = sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::Identifier(variable_name.clone()),
+            member: i.to_string(),
+        });
+
+        block.statements.push(sway::Statement::from(
+            create_assignment_expression(project, translated_definition, "=", &expression, variable, &component, &type_names[i])?
+        ));
+    }
+
+    Ok(sway::Statement::from(sway::Expression::from(block)))
+}
+
 #[inline]
 pub fn translate_variable_definition_statement(
     project: &mut Project,
@@ -392,8 +659,60 @@ pub fn translate_variable_definition_statement(
     initializer: &Option<solidity::Expression>,
 ) -> Result<sway::Statement, Error> {
     let old_name = variable_declaration.name.as_ref().unwrap().name.clone();
-    let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-    let type_name = translate_type_name(project, translated_definition, &variable_declaration.ty, false, false);
+    let new_name = translated_definition.translate_variable_name(project, scope.clone(), old_name.as_str());
+
+    // Storage pointers alias the value they point to, i.e: `S storage s = x[k];` => `let s: StorageKey<S> = storage.x.get(k);`
+    if matches!(variable_declaration.storage, Some(solidity::StorageLocation::Storage(_))) {
+        let storage_access = match initializer.as_ref() {
+            Some(x @ (solidity::Expression::Variable(_) | solidity::Expression::ArraySubscript(_, _, Some(_)) | solidity::Expression::MemberAccess(_, _, _))) => {
+                translate_variable_access_expression(project, translated_definition, scope.clone(), x)
+                    .ok()
+                    .filter(|(variable, _)| variable.borrow().is_storage)
+            }
+
+            _ => None,
+        };
+
+        if let Some((variable, value)) = storage_access {
+            variable.borrow_mut().read_count += 1;
+
+            let storage_type_name = translate_type_name(project, translated_definition, &variable_declaration.ty, true, false)?;
+
+            let statement = sway::Statement::from(sway::Let {
+                pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
+                    is_mutable: false,
+                    name: new_name.clone(),
+                }),
+                type_name: Some(sway::TypeName::Identifier {
+                    name: "StorageKey".into(),
+                    generic_parameters: Some(sway::GenericParameterList {
+                        entries: vec![
+                            sway::GenericParameter {
+                                type_name: storage_type_name.clone(),
+                                implements: None,
+                            },
+                        ],
+                    }),
+                }),
+                value,
+            });
+
+            scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+                old_name,
+                new_name,
+                type_name: storage_type_name,
+                is_storage: true,
+                storage_location: variable_declaration.storage.clone(),
+                ..Default::default()
+            })));
+
+            return Ok(statement);
+        }
+
+        project.report_warning(Lint::StorageAliasing, &translated_definition.path, Some(&variable_declaration.loc), format!("`{variable_declaration}` does not point to a storage field, using a copy of its value instead"));
+    }
+
+    let type_name = translate_type_name(project, translated_definition, &variable_declaration.ty, false, false)?;
     let mut value = None;
 
     if let Some(solidity::Expression::New(_, new_expression)) = initializer.as_ref() {
@@ -401,100 +720,20 @@ pub fn translate_variable_definition_statement(
             panic!("Unexpected new expression: {} - {new_expression:#?}", new_expression);
         };
 
-        let new_type_name = translate_type_name(project, translated_definition, ty, false, false);
+        let new_type_name = translate_type_name(project, translated_definition, ty, false, false)?;
 
         if type_name != new_type_name {
             panic!("Invalid new expression type name: expected `{type_name}`, found `{new_type_name}`");
         }
 
         match &type_name {
-            sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) } if name == "Vec" => {
-                // {
-                //     let mut v = Vec::with_capacity(length);
-                //     let mut i = 0;
-                //     while i < length {
-                //         v.push(0);
-                //         i += 1;
-                //     }
-                //     v
-                // }
-
+            sway::TypeName::Identifier { name, generic_parameters: Some(_) } if name == "Vec" => {
                 if args.len() != 1 {
                     panic!("Invalid new array expression: expected 1 argument, found {}", args.len());
                 }
 
-                let element_type_name = &generic_parameters.entries.first().unwrap().type_name;
                 let length = translate_expression(project, translated_definition, scope.clone(), &args[0])?;
-
-                value = Some(sway::Expression::from(sway::Block {
-                    statements: vec![
-                        // let mut v = Vec::with_capacity(length);
-                        sway::Statement::from(sway::Let {
-                            pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
-                                is_mutable: true,
-                                name: "v".into(),
-                            }),
-                            type_name: Some(type_name.clone()),
-                            value: sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::Identifier("Vec::with_capacity".into()),
-                                generic_parameters: None,
-                                parameters: vec![
-                                    length.clone(),
-                                ],
-                            }),
-                        }),
-
-                        // let mut i = 0;
-                        sway::Statement::from(sway::Let {
-                            pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
-                                is_mutable: true,
-                                name: "i".into(),
-                            }),
-                            type_name: None,
-                            value: sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
-                        }),
-
-                        // while i < length {
-                        //     v.push(0);
-                        //     i += 1;
-                        // }
-                        sway::Statement::from(sway::Expression::from(sway::While {
-                            // i < length
-                            condition: sway::Expression::from(sway::BinaryExpression {
-                                operator: "<".into(),
-                                lhs: sway::Expression::Identifier("i".into()),
-                                rhs: length.clone(),
-                            }),
-
-                            body: sway::Block {
-                                statements: vec![
-                                    // v.push(0);
-                                    sway::Statement::from(sway::Expression::from(sway::FunctionCall {
-                                        function: sway::Expression::from(sway::MemberAccess {
-                                            expression: sway::Expression::Identifier("v".into()),
-                                            member: "push".into(),
-                                        }),
-                                        generic_parameters: None,
-                                        parameters: vec![
-                                            create_value_expression(translated_definition, scope.clone(), element_type_name, None),
-                                        ],
-                                    })),
-
-                                    // i += 1;
-                                    sway::Statement::from(sway::Expression::from(sway::BinaryExpression {
-                                        operator: "+=".into(),
-                                        lhs: sway::Expression::Identifier("i".into()),
-                                        rhs: sway::Expression::from(sway::Literal::DecInt(BigUint::one())),
-                                    })),
-                                ],
-                                final_expr: None,
-                            }
-                        }))
-                    ],
-
-                    // v
-                    final_expr: Some(sway::Expression::Identifier("v".into())),
-                }));
+                value = Some(create_vec_with_length_expression(translated_definition, scope.clone(), &type_name, length)?);
             }
 
             _ => {}
@@ -512,7 +751,12 @@ pub fn translate_variable_definition_statement(
         value: if let Some(value) = value {
             value
         } else if let Some(x) = initializer.as_ref() {
-            translate_pre_or_post_operator_value_expression(project, translated_definition, scope.clone(), x)?
+            let value = translate_pre_or_post_operator_value_expression(project, translated_definition, scope.clone(), x)?;
+            // Convert values assigned between signed and unsigned integers
+            match get_simple_expression_type(translated_definition, scope.clone(), &value)? {
+                Some(value_type_name) => create_integer_sign_conversion_expression(project, translated_definition, &x.loc(), value, &value_type_name, &type_name),
+                None => value,
+            }
         } else {
             create_value_expression(translated_definition, scope.clone(), &type_name, None)
         },
@@ -704,7 +948,8 @@ pub fn translate_return_statement(
                 translate_expression(project, translated_definition, scope.clone(), x)?
             ))
         } else {
-            None
+            // return; => return (a, b);
+            scope.borrow().get_named_return_value().map(Box::new)
         }
     )))
 }
@@ -743,6 +988,11 @@ pub fn translate_revert_statement(
         }
 
         let (errors_enum, _) = errors_enum_and_impl;
+
+        // Keep track of the errors enum being referenced so it gets emitted
+        if !translated_definition.referenced_enum_names.contains(&errors_enum.name) {
+            translated_definition.referenced_enum_names.push(errors_enum.name.clone());
+        }
         
         return Ok(sway::Statement::from(sway::Expression::from(sway::Block {
             statements: vec![
@@ -846,6 +1096,13 @@ pub fn translate_emit_statement(
                 let Some((events_enum, _)) = translated_definition.events_enums.iter().find(|(e, _)| e.variants.iter().any(|v| v.name == *event_variant_name)) else {
                     panic!("Failed to find event variant \"{event_variant_name}\" in \"{}\": {:#?}", translated_definition.name, translated_definition.events_enums);
                 };
+
+                let events_enum_name = events_enum.name.clone();
+
+                // Keep track of the events enum being referenced so it gets emitted
+                if !translated_definition.referenced_enum_names.contains(&events_enum_name) {
+                    translated_definition.referenced_enum_names.push(events_enum_name.clone());
+                }
                 
                 return Ok(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
                     function: sway::Expression::Identifier("log".into()),
@@ -854,14 +1111,14 @@ pub fn translate_emit_statement(
                         if parameters.is_empty() {
                             sway::Expression::Identifier(format!(
                                 "{}::{}",
-                                events_enum.name,
+                                events_enum_name,
                                 event_variant_name,
                             ))
                         } else {
                             sway::Expression::from(sway::FunctionCall {
                                 function: sway::Expression::Identifier(format!(
                                     "{}::{}",
-                                    events_enum.name,
+                                    events_enum_name,
                                     event_variant_name,
                                 )),
                                 generic_parameters: None,
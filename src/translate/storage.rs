@@ -1,20 +1,20 @@
 use super::{
-    create_value_expression, translate_expression, translate_type_name, DeferredInitialization,
-    TranslatedDefinition, TranslatedFunction, TranslatedVariable, TranslationScope,
+    create_value_expression, evaluate_constant_expression, is_constant_value_expression, get_rename_reason, join_comments,
+    translate_expression, translate_natspec, translate_type_name, DeferredInitialization, ItemOriginKind, TranslatedDefinition, TranslatedFunction, TranslatedVariable, TranslationScope,
 };
-use crate::{project::Project, sway, Error};
+use crate::{project::{Lint, Project}, sway, Error};
 use convert_case::Case;
 use solang_parser::pt as solidity;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 #[inline]
 pub fn translate_storage_name(
-    _project: &mut Project,
+    project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
     name: &str,
 ) -> String {
     if !translated_definition.storage_fields_names.contains_key(name) {
-        let mut new_name = crate::translate_naming_convention(name, Case::Snake);
+        let mut new_name = project.translate_naming_convention(name, Case::Snake);
 
         let count = translated_definition.storage_fields_name_counts.entry(new_name.clone()).or_insert(0);
         *count += 1;
@@ -23,6 +23,10 @@ pub fn translate_storage_name(
             new_name = format!("{new_name}_{}", *count);
         }
 
+        if let Some(reason) = get_rename_reason(project, name, new_name.as_str(), Case::Snake) {
+            translated_definition.add_renamed_identifier(name, new_name.as_str(), reason);
+        }
+
         translated_definition.storage_fields_names.insert(name.into(), new_name);
     }
 
@@ -49,20 +53,26 @@ pub fn translate_state_variable(
     // Translate the variable's naming convention
     let old_name = variable_definition.name.as_ref().unwrap().name.clone();
     let new_name = if is_constant || is_immutable {
-        crate::translate_naming_convention(old_name.as_str(), Case::ScreamingSnake)
+        let new_name = project.translate_naming_convention(old_name.as_str(), Case::ScreamingSnake);
+
+        if let Some(reason) = get_rename_reason(project, old_name.as_str(), new_name.as_str(), Case::ScreamingSnake) {
+            translated_definition.add_renamed_identifier(old_name.as_str(), new_name.as_str(), reason);
+        }
+
+        new_name
     } else {
         translate_storage_name(project, translated_definition, old_name.as_str())
     };
 
     // Translate the variable's type name
-    let mut variable_type_name = translate_type_name(project, translated_definition, &variable_definition.ty, is_storage, false);
+    let mut variable_type_name = translate_type_name(project, translated_definition, &variable_definition.ty, is_storage, false)?;
     let mut abi_type_name = None;
 
     // Check if the variable's type is an ABI
     if let sway::TypeName::Identifier { name, generic_parameters: None } = &variable_type_name {
         // Check if type is a contract that hasn't been defined yet
         if project.find_definition_with_abi(name.as_str()).is_none() && translated_definition.contract_names.iter().any(|n| n == name) {
-            project.translate(Some(name), &translated_definition.path).unwrap();
+            project.translate_source_unit(Some(name), &translated_definition.path).unwrap();
         }
 
         if project.find_definition_with_abi(name.as_str()).is_some() {
@@ -85,74 +95,109 @@ pub fn translate_state_variable(
         ..Default::default()
     }));
 
-    let value = match &variable_type_name {
-        sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
-            // Create deferred initializations for types that can't be initialized with a value
-            ("StorageString", None) | ("StorageVec", Some(_)) => {
-                if let Some(x) = variable_definition.initializer.as_ref() {
-                    let value = translate_expression(project, translated_definition, value_scope.clone(), x)?;
-
-                    translated_definition.deferred_initializations.push(DeferredInitialization {
-                        name: new_name.clone(),
-                        is_storage,
-                        is_constant,
-                        is_configurable,
-                        value,
-                    });
-                }
+    // Attempt to evaluate the initial value ahead of time
+    let constant_value = variable_definition.initializer.as_ref()
+        .and_then(|x| evaluate_constant_expression(translated_definition, x))
+        .and_then(|x| x.to_expression(&variable_type_name));
 
-                sway::Expression::from(sway::Constructor {
-                    type_name: sway::TypeName::Identifier {
-                        name: name.clone(),
-                        generic_parameters: None,
-                    },
-                    fields: vec![],
-                })
-            }
+    let mut value = if let Some(value) = constant_value {
+        value
+    } else {
+        match &variable_type_name {
+            sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
+                // Create deferred initializations for types that can't be initialized with a value
+                ("StorageString", None) | ("StorageVec", Some(_)) => {
+                    if let Some(x) = variable_definition.initializer.as_ref() {
+                        let value = translate_expression(project, translated_definition, value_scope.clone(), x)?;
+
+                        translated_definition.deferred_initializations.push(DeferredInitialization {
+                            name: new_name.clone(),
+                            is_storage,
+                            is_constant,
+                            is_configurable,
+                            value,
+                        });
+                    }
+
+                    sway::Expression::from(sway::Constructor {
+                        type_name: sway::TypeName::Identifier {
+                            name: name.clone(),
+                            generic_parameters: None,
+                        },
+                        fields: vec![],
+                    })
+                }
 
-            // HACK: Check for Identity storage fields that have an abi cast for their initializer
-            ("Identity", None) => {
-                let initializer = variable_definition.initializer.as_ref()
-                    .map(|x| {
-                        let mut value = translate_expression(project, translated_definition, value_scope.clone(), x);
+                // HACK: Check for Identity storage fields that have an abi cast for their initializer
+                ("Identity", None) => {
+                    let initializer = variable_definition.initializer.as_ref()
+                        .map(|x| {
+                            let mut value = translate_expression(project, translated_definition, value_scope.clone(), x);
                         
-                        if let Ok(sway::Expression::Commented(comment, expression)) = &value {
-                            if let sway::Expression::FunctionCall(function_call) = expression.as_ref() {
-                                if let sway::Expression::Identifier(identifier) = &function_call.function {
-                                    if identifier == "abi" && function_call.parameters.len() == 2 {
-                                        value = Ok(sway::Expression::Commented(comment.clone(), Box::new(function_call.parameters[1].clone())));
+                            if let Ok(sway::Expression::Commented(comment, expression)) = &value {
+                                if let sway::Expression::FunctionCall(function_call) = expression.as_ref() {
+                                    if let sway::Expression::Identifier(identifier) = &function_call.function {
+                                        if identifier == "abi" && function_call.parameters.len() == 2 {
+                                            value = Ok(sway::Expression::Commented(comment.clone(), Box::new(function_call.parameters[1].clone())));
+                                        }
                                     }
                                 }
                             }
-                        }
 
-                        value
-                    })
-                    .transpose()?;
+                            value
+                        })
+                        .transpose()?;
                 
-                create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, initializer.as_ref())
-            }
+                    create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, initializer.as_ref())
+                }
 
-            _ => {
-                let initializer = variable_definition.initializer.as_ref()
-                    .map(|x| translate_expression(project, translated_definition, value_scope.clone(), x))
-                    .transpose()?;
+                _ => {
+                    let initializer = variable_definition.initializer.as_ref()
+                        .map(|x| translate_expression(project, translated_definition, value_scope.clone(), x))
+                        .transpose()?;
 
-                create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, initializer.as_ref())
+                    create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, initializer.as_ref())
+                }
             }
-        }
 
-        _ => if let Some(x) = variable_definition.initializer.as_ref() {
-            let value = translate_expression(project, translated_definition, value_scope.clone(), x)?;
-            create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, Some(&value))
-        } else {
-            create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, None)
-        },
+            _ => if let Some(x) = variable_definition.initializer.as_ref() {
+                let value = translate_expression(project, translated_definition, value_scope.clone(), x)?;
+                create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, Some(&value))
+            } else {
+                create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, None)
+            },
+        }
     };
 
+    // Defer initialization of storage fields whose initial value can't be evaluated at compile time
+    if is_storage && variable_definition.initializer.is_some() && !is_constant_value_expression(translated_definition, &value) {
+        translated_definition.deferred_initializations.push(DeferredInitialization {
+            name: new_name.clone(),
+            is_storage,
+            is_constant,
+            is_configurable,
+            value,
+        });
+
+        value = create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, None);
+    }
+
+    // Preserve the comments preceding the variable definition
+    let doc_comment = translate_natspec(project, translated_definition, &variable_definition.loc);
+    let comment = join_comments(
+        join_comments(
+            project.get_leading_comment(&translated_definition.path, &variable_definition.loc),
+            project.get_source_location_comment(&translated_definition.path, &variable_definition.loc),
+        ),
+        doc_comment.clone(),
+    );
+
     // Handle constant variable definitions
     if is_constant {
+        translated_definition.add_item_origin(ItemOriginKind::Constant, &new_name, &variable_definition.loc);
+
         translated_definition.constants.push(sway::Constant {
+            comment,
             is_public,
             name: new_name.clone(),
             type_name: variable_type_name.clone(),
@@ -173,7 +218,10 @@ pub fn translate_state_variable(
     }
     // Handle regular state variable definitions
     else {
+        translated_definition.add_item_origin(ItemOriginKind::StorageField, &new_name, &variable_definition.loc);
+
         translated_definition.get_storage().fields.push(sway::StorageField {
+            comment,
             name: new_name.clone(),
             type_name: variable_type_name.clone(),
             value,
@@ -220,6 +268,7 @@ pub fn translate_state_variable(
 
     // Create the function declaration for the abi
     let mut sway_function = sway::Function {
+        comment: None,
         attributes: if is_storage {
             Some(sway::AttributeList {
                 attributes: vec![
@@ -244,9 +293,15 @@ pub fn translate_state_variable(
         body: None,
     };
 
+    // Document the getter function with the NatSpec of the variable
+    sway_function.comment = doc_comment;
+
+    translated_definition.add_item_origin(ItemOriginKind::AbiFunction, &new_name, &variable_definition.loc);
+    translated_definition.add_item_origin(ItemOriginKind::Function, &new_name, &variable_definition.loc);
+
     if let Some(abi) = translated_definition.abi.as_mut() {
         // Only add the function to the abi if it doesn't already exist
-        if !abi.functions.contains(&sway_function) {
+        if !abi.functions.iter().any(|f| f.is_equivalent(&sway_function)) {
             abi.functions.push(sway_function.clone());
         }
     } else {
@@ -254,12 +309,14 @@ pub fn translate_state_variable(
         translated_definition.get_abi().functions.push(sway_function.clone());
     }
 
+    // Only the abi function is documented
+    sway_function.comment = project.get_source_location_comment(&translated_definition.path, &variable_definition.loc);
+
     // Add the toplevel function to the scope
     translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(TranslatedFunction {
         old_name: old_name.clone(),
         new_name: new_name.clone(),
         parameters: sway_function.parameters.clone(),
-        constructor_calls: vec![],
         modifiers: vec![],
         return_type: sway_function.return_type.clone(),
     })));
@@ -340,3 +397,291 @@ pub fn translate_state_variable(
 
     Ok(())
 }
+
+/// Storage methods which modify the storage they are called on
+const STORAGE_WRITE_METHODS: &[&str] = &[
+    "write", "write_slice", "insert", "remove", "push", "pop", "set", "clear",
+    "swap", "swap_remove", "store_vec", "fill", "resize", "reverse", "clear_existing",
+];
+
+/// Library functions which read the storage of the contract, i.e: `sway_libs::ownership::only_owner()`
+const STORAGE_READ_LIBRARY_FUNCTIONS: &[&str] = &[
+    "sway_libs::ownership::_owner", "sway_libs::ownership::only_owner",
+    "sway_libs::pausable::_is_paused", "sway_libs::pausable::require_paused", "sway_libs::pausable::require_not_paused",
+];
+
+/// Library functions which modify the storage of the contract, i.e: `sway_libs::ownership::transfer_ownership(x)`
+const STORAGE_WRITE_LIBRARY_FUNCTIONS: &[&str] = &[
+    "sway_libs::ownership::initialize_ownership", "sway_libs::ownership::transfer_ownership", "sway_libs::ownership::renounce_ownership",
+    "sway_libs::pausable::_pause", "sway_libs::pausable::_unpause",
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct StorageEffects {
+    reads: bool,
+    writes: bool,
+}
+
+/// Checks if the expression accesses a field within the storage block (i.e: `storage.x`, `storage.x.get(k)`),
+/// or a value in storage through one of the supplied storage keys (i.e: `s.x` where `s: StorageKey<S>`)
+fn is_storage_access_expression(expression: &sway::Expression, storage_key_names: &[String]) -> bool {
+    match expression {
+        sway::Expression::Identifier(name) => storage_key_names.contains(name),
+
+        sway::Expression::MemberAccess(member_access) => match &member_access.expression {
+            sway::Expression::Identifier(name) => name == "storage" || storage_key_names.contains(name),
+            x => is_storage_access_expression(x, storage_key_names),
+        },
+
+        sway::Expression::FunctionCall(function_call) => is_storage_access_expression(&function_call.function, storage_key_names),
+        sway::Expression::ArrayAccess(array_access) => is_storage_access_expression(&array_access.expression, storage_key_names),
+        
+        _ => false,
+    }
+}
+
+/// Collects the names of the storage keys that a function accesses storage through,
+/// i.e: `s: StorageKey<S>` or `let s: StorageKey<S> = storage.x.get(k);`
+fn collect_storage_key_names(function: &sway::Function) -> Vec<String> {
+    let is_storage_key = |type_name: Option<&sway::TypeName>| {
+        matches!(type_name, Some(sway::TypeName::Identifier { name, .. }) if name == "StorageKey")
+    };
+
+    let mut storage_key_names = function.parameters.entries.iter()
+        .filter(|p| is_storage_key(p.type_name.as_ref()))
+        .map(|p| p.name.clone())
+        .collect::<Vec<_>>();
+
+    let Some(body) = function.body.as_ref() else { return storage_key_names };
+
+    let mut collect_let_names = |block: &sway::Block| {
+        for statement in block.statements.iter() {
+            let sway::Statement::Let(sway::Let { pattern: sway::LetPattern::Identifier(id), type_name, .. }) = statement else { continue };
+
+            if is_storage_key(type_name.as_ref()) {
+                storage_key_names.push(id.name.clone());
+            }
+        }
+    };
+
+    body.visit_blocks(&mut collect_let_names);
+
+    storage_key_names
+}
+
+/// Collects the direct storage effects of a function, along with the names of the functions it calls
+fn collect_storage_effects(function: &sway::Function) -> (StorageEffects, Vec<String>) {
+    let mut effects = StorageEffects::default();
+    let mut function_calls = vec![];
+
+    let Some(body) = function.body.as_ref() else { return (effects, function_calls) };
+    let storage_key_names = collect_storage_key_names(function);
+
+    body.visit_expressions(&mut |expression| {
+        match expression {
+            sway::Expression::MemberAccess(member_access) => {
+                if matches!(&member_access.expression, sway::Expression::Identifier(name) if name == "storage" || storage_key_names.contains(name)) {
+                    effects.reads = true;
+                }
+            }
+
+            sway::Expression::FunctionCall(function_call) => match &function_call.function {
+                // Raw storage slot accesses, i.e: `std::storage::storage_api::read::<T>(slot, 0)`
+                sway::Expression::Identifier(name) if name == "std::storage::storage_api::read" => effects.reads = true,
+                sway::Expression::Identifier(name) if name == "std::storage::storage_api::write" => effects.writes = true,
+
+                // Library functions that access storage
+                sway::Expression::Identifier(name) if STORAGE_READ_LIBRARY_FUNCTIONS.contains(&name.as_str()) => effects.reads = true,
+                sway::Expression::Identifier(name) if STORAGE_WRITE_LIBRARY_FUNCTIONS.contains(&name.as_str()) => {
+                    effects.reads = true;
+                    effects.writes = true;
+                }
+
+                sway::Expression::Identifier(name) => {
+                    function_calls.push(name.trim_start_matches("::").to_string());
+                }
+
+                sway::Expression::MemberAccess(member_access) => {
+                    effects.writes |= STORAGE_WRITE_METHODS.contains(&member_access.member.as_str())
+                        && is_storage_access_expression(&member_access.expression, storage_key_names.as_slice());
+                }
+
+                _ => {}
+            },
+
+            _ => {}
+        }
+    });
+
+    (effects, function_calls)
+}
+
+/// Replaces the `#[storage(...)]` attribute in an attribute list with one matching the supplied storage effects
+fn apply_storage_attribute(attributes: &mut Option<sway::AttributeList>, effects: StorageEffects) {
+    let mut attribute_list = attributes.take().unwrap_or_default();
+
+    attribute_list.attributes.retain(|a| a.name != "storage");
+
+    if effects.reads || effects.writes {
+        attribute_list.attributes.insert(0, sway::Attribute {
+            name: "storage".into(),
+            parameters: Some(if effects.writes {
+                vec!["read".into(), "write".into()]
+            } else {
+                vec!["read".into()]
+            }),
+        });
+    }
+
+    if !attribute_list.attributes.is_empty() {
+        *attributes = Some(attribute_list);
+    }
+}
+
+/// Emits a warning if the inferred storage effects of a function exceed its declared `view` or `pure` mutability
+fn check_declared_mutability(project: &mut Project, translated_definition: &TranslatedDefinition, function_name: &str, effects: StorageEffects) {
+    let Some(mutability) = translated_definition.function_mutabilities.get(function_name) else { return };
+
+    let violation = match mutability {
+        solidity::Mutability::Pure(_) | solidity::Mutability::Constant(_) if effects.writes => "writes to",
+        solidity::Mutability::Pure(_) | solidity::Mutability::Constant(_) if effects.reads => "reads from",
+        solidity::Mutability::View(_) if effects.writes => "writes to",
+        _ => return,
+    };
+
+    project.report_warning(
+        Lint::Mutability,
+        &translated_definition.path,
+        translated_definition.get_item_origin(ItemOriginKind::Function, function_name).map(|origin| &origin.loc),
+        format!("`{}::{function_name}` is declared `{mutability}`, but its translation {violation} storage", translated_definition.name),
+    );
+}
+
+/// Propagates the storage effects of the toplevel functions of a translated definition through their call graph,
+/// so a function calling another function that accesses storage has the effects of both functions
+fn collect_toplevel_storage_effects(translated_definition: &TranslatedDefinition) -> HashMap<String, (StorageEffects, Vec<String>)> {
+    // Collect the direct effects of each toplevel function
+    let mut toplevel_effects = translated_definition.functions.iter()
+        .filter(|f| f.body.is_some())
+        .map(|f| (f.name.clone(), collect_storage_effects(f)))
+        .collect::<HashMap<_, _>>();
+
+    // Propagate the effects of called functions until nothing changes
+    loop {
+        let mut changed = false;
+        let names = toplevel_effects.keys().cloned().collect::<Vec<_>>();
+
+        for name in names {
+            let (mut effects, function_calls) = toplevel_effects.get(&name).cloned().unwrap();
+
+            for function_call in function_calls.iter() {
+                if let Some((callee_effects, _)) = toplevel_effects.get(function_call) {
+                    effects.reads |= callee_effects.reads;
+                    effects.writes |= callee_effects.writes;
+                }
+            }
+
+            if effects != toplevel_effects.get(&name).unwrap().0 {
+                toplevel_effects.get_mut(&name).unwrap().0 = effects;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    toplevel_effects
+}
+
+/// Infers the storage attributes of all functions of a translated definition from their translated bodies,
+/// reporting the functions whose storage effects exceed their declared mutability
+pub fn infer_storage_attributes(project: &mut Project, translated_definition: &mut TranslatedDefinition) {
+    let toplevel_effects = collect_toplevel_storage_effects(translated_definition);
+
+    for function in translated_definition.functions.iter() {
+        if let Some((effects, _)) = toplevel_effects.get(&function.name) {
+            check_declared_mutability(project, translated_definition, &function.name, *effects);
+        }
+    }
+
+    let abi_effects = apply_storage_attributes(translated_definition, &toplevel_effects);
+
+    for (function_name, effects) in abi_effects.iter() {
+        check_declared_mutability(project, translated_definition, function_name, *effects);
+    }
+}
+
+/// Infers the storage attributes of all functions of a translated definition again after their bodies have been rewritten
+/// (i.e: by porting balances to native assets), without reporting the declared mutability of the functions a second time
+pub fn refresh_storage_attributes(translated_definition: &mut TranslatedDefinition) {
+    let toplevel_effects = collect_toplevel_storage_effects(translated_definition);
+    apply_storage_attributes(translated_definition, &toplevel_effects);
+}
+
+/// Applies the storage effects of the toplevel functions to their attributes, and to the attributes of the functions in impl blocks calling them.
+/// Returns the effects of the functions in the contract impl block.
+fn apply_storage_attributes(
+    translated_definition: &mut TranslatedDefinition,
+    toplevel_effects: &HashMap<String, (StorageEffects, Vec<String>)>,
+) -> Vec<(String, StorageEffects)> {
+    let get_effects = |function: &sway::Function| -> StorageEffects {
+        let (mut effects, function_calls) = collect_storage_effects(function);
+
+        for function_call in function_calls.iter() {
+            if let Some((callee_effects, _)) = toplevel_effects.get(function_call) {
+                effects.reads |= callee_effects.reads;
+                effects.writes |= callee_effects.writes;
+            }
+        }
+
+        effects
+    };
+
+    // Apply the effects to the toplevel functions
+    for function in translated_definition.functions.iter_mut() {
+        if let Some((effects, _)) = toplevel_effects.get(&function.name) {
+            apply_storage_attribute(&mut function.attributes, *effects);
+        }
+    }
+
+    // Apply the effects to the functions in impl blocks, and their abi declarations
+    let definition_name = translated_definition.name.clone();
+    let mut abi_effects = vec![];
+
+    for sway_impl in translated_definition.impls.iter_mut() {
+        let is_for_contract = matches!(&sway_impl.for_type_name, Some(sway::TypeName::Identifier { name, .. }) if name == "Contract");
+        let is_contract_impl = is_for_contract && matches!(&sway_impl.type_name, sway::TypeName::Identifier { name, .. } if *name == definition_name);
+
+        // The storage attributes of the implementations of other abis (i.e: `impl SRC20 for Contract`) must match their abi declarations
+        if is_for_contract && !is_contract_impl {
+            continue;
+        }
+
+        for item in sway_impl.items.iter_mut() {
+            let sway::ImplItem::Function(function) = item else { continue };
+
+            if function.body.is_none() {
+                continue;
+            }
+
+            let effects = get_effects(function);
+            apply_storage_attribute(&mut function.attributes, effects);
+
+            if is_contract_impl {
+                abi_effects.push((function.name.clone(), effects));
+            }
+        }
+    }
+
+    if let Some(abi) = translated_definition.abi.as_mut() {
+        for function in abi.functions.iter_mut() {
+            if let Some((_, effects)) = abi_effects.iter().find(|(name, _)| *name == function.name) {
+                apply_storage_attribute(&mut function.attributes, *effects);
+            }
+        }
+    }
+
+    abi_effects
+}
@@ -1,4 +1,4 @@
-use super::{translate_type_name, TranslatedDefinition};
+use super::{get_rename_reason, join_comments, translate_natspec, translate_type_name, ItemOriginKind, TranslatedDefinition};
 use crate::{project::Project, sway, Error};
 use convert_case::Case;
 use solang_parser::pt as solidity;
@@ -9,18 +9,36 @@ pub fn translate_struct_definition(
     translated_definition: &mut TranslatedDefinition,
     struct_definition: &solidity::StructDefinition,
 ) -> Result<(), Error> {
+    let struct_name = struct_definition.name.as_ref().unwrap().name.clone();
+    let mut fields = vec![];
+
+    for f in struct_definition.fields.iter() {
+        let old_name = f.name.as_ref().unwrap().name.as_str();
+        let new_name = project.translate_naming_convention(old_name, Case::Snake); // TODO: keep track of original name
+
+        if let Some(reason) = get_rename_reason(project, old_name, new_name.as_str(), Case::Snake) {
+            translated_definition.add_renamed_identifier(format!("{struct_name}.{old_name}").as_str(), format!("{struct_name}.{new_name}").as_str(), reason);
+        }
+
+        fields.push(sway::StructField {
+            is_public: false,
+            name: new_name,
+            type_name: translate_type_name(project, translated_definition, &f.ty, false, false)?,
+        });
+    }
+
+    translated_definition.add_item_origin(ItemOriginKind::Struct, &struct_name, &struct_definition.loc);
+
     let struct_definition = sway::Struct {
+        comment: join_comments(
+            project.get_leading_comment(&translated_definition.path, &struct_definition.loc),
+            translate_natspec(project, translated_definition, &struct_definition.loc),
+        ),
         attributes: None,
         is_public: false,
-        name: struct_definition.name.as_ref().unwrap().name.clone(),
+        name: struct_name,
         generic_parameters: None,
-        fields: struct_definition.fields.iter().map(|f| {
-            sway::StructField {
-                is_public: false,
-                name: crate::translate_naming_convention(f.name.as_ref().unwrap().name.as_str(), Case::Snake), // TODO: keep track of original name
-                type_name: translate_type_name(project, translated_definition, &f.ty, false, false),
-            }
-        }).collect(),
+        fields,
     };
 
     translated_definition.structs.push(struct_definition);
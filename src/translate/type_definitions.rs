@@ -1,5 +1,5 @@
 use super::{translate_type_name, TranslatedDefinition};
-use crate::{project::Project, sway, Error};
+use crate::{project::{Lint, Project}, sway, Error};
 use solang_parser::pt as solidity;
 
 #[inline]
@@ -8,7 +8,7 @@ pub fn translate_type_definition(
     translated_definition: &mut TranslatedDefinition,
     type_definition: &solidity::TypeDefinition,
 ) -> Result<(), Error> {
-    let underlying_type = translate_type_name(project, translated_definition, &type_definition.ty, false, false);
+    let underlying_type = translate_type_name(project, translated_definition, &type_definition.ty, false, false)?;
 
     translated_definition.type_definitions.push(sway::TypeDefinition {
         is_public: true,
@@ -21,3 +21,191 @@ pub fn translate_type_definition(
 
     Ok(())
 }
+
+/// Translates the user-defined operators of a `using {add as +} for T global;` directive into operator trait implementations for `T`.
+///
+/// Sway type aliases are not distinct from their underlying types, so a user-defined value type with operators is translated
+/// into a struct wrapping its underlying value, allowing `impl core::ops::Add for T` to coexist with the underlying type's implementation.
+#[inline]
+pub fn translate_user_defined_operators(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    using_directive: &solidity::Using,
+) -> Result<(), Error> {
+    let solidity::UsingList::Functions(using_functions) = &using_directive.list else { return Ok(()) };
+
+    if !using_functions.iter().any(|f| f.oper.is_some()) {
+        return Ok(());
+    }
+
+    let Some(for_type) = using_directive.ty.as_ref() else {
+        panic!("User-defined operators require a type: {using_directive}")
+    };
+
+    let type_name = translate_type_name(project, translated_definition, for_type, false, false)?;
+
+    let sway::TypeName::Identifier { name: type_name_string, generic_parameters: None } = &type_name else {
+        panic!("User-defined operators are only supported for user-defined value types: {using_directive}")
+    };
+
+    // Translate the user-defined value type to a struct wrapping its underlying value
+    if let Some(index) = translated_definition.type_definitions.iter().position(|t| t.name == type_name) {
+        let type_definition = translated_definition.type_definitions.remove(index);
+
+        translated_definition.structs.push(sway::Struct {
+            comment: None,
+            attributes: None,
+            is_public: true,
+            name: type_name_string.clone(),
+            generic_parameters: None,
+            fields: vec![
+                sway::StructField {
+                    is_public: true,
+                    name: "value".into(),
+                    type_name: type_definition.underlying_type.unwrap(),
+                },
+            ],
+        });
+
+        translated_definition.struct_names.push(type_name_string.clone());
+        translated_definition.value_type_structs.push(type_name_string.clone());
+    }
+
+    let self_type_name = sway::TypeName::Identifier {
+        name: "Self".into(),
+        generic_parameters: None,
+    };
+
+    let bool_type_name = sway::TypeName::Identifier {
+        name: "bool".into(),
+        generic_parameters: None,
+    };
+
+    for using_function in using_functions.iter() {
+        let Some(operator) = using_function.oper else { continue };
+
+        // Get the operator trait and function for the operator
+        let (trait_name, function_name, return_type) = match operator {
+            solidity::UserDefinedOperator::Add => ("Add", "add", &self_type_name),
+            solidity::UserDefinedOperator::Subtract => ("Subtract", "subtract", &self_type_name),
+            solidity::UserDefinedOperator::Multiply => ("Multiply", "multiply", &self_type_name),
+            solidity::UserDefinedOperator::Divide => ("Divide", "divide", &self_type_name),
+            solidity::UserDefinedOperator::Modulo => ("Mod", "modulo", &self_type_name),
+            solidity::UserDefinedOperator::BitwiseAnd => ("BitwiseAnd", "binary_and", &self_type_name),
+            solidity::UserDefinedOperator::BitwiseOr => ("BitwiseOr", "binary_or", &self_type_name),
+            solidity::UserDefinedOperator::BitwiseXor => ("BitwiseXor", "binary_xor", &self_type_name),
+            solidity::UserDefinedOperator::BitwiseNot => ("Not", "not", &self_type_name),
+            solidity::UserDefinedOperator::Equal => ("Eq", "eq", &bool_type_name),
+            solidity::UserDefinedOperator::Less => ("Ord", "lt", &bool_type_name),
+            solidity::UserDefinedOperator::More => ("Ord", "gt", &bool_type_name),
+
+            // `!=`, `<=` and `>=` are derived from `==`, `<` and `>` by the `Eq` and `OrdEq` traits, and Sway has no negation trait
+            _ => {
+                project.report_warning(Lint::UserDefinedOperator, &translated_definition.path, Some(&using_function.loc), format!("user-defined operator `{operator}` has no Sway operator trait equivalent and will not be translated"));
+
+                continue;
+            }
+        };
+
+        // Find the function bound to the operator
+        let old_name = using_function.path.identifiers.last().unwrap().name.clone();
+
+        let Some(function) = translated_definition.toplevel_scope.borrow().find_function(|f| {
+            let f = f.borrow();
+            f.old_name == old_name && f.parameters.entries.len() == operator.args() && f.parameters.entries.iter().all(|p| p.type_name.as_ref() == Some(&type_name))
+        }) else {
+            panic!("Failed to find function for user-defined operator `{operator}`: \"{old_name}\"")
+        };
+
+        let function_name_2 = function.borrow().new_name.clone();
+
+        let mut parameters = sway::ParameterList {
+            entries: vec![
+                sway::Parameter {
+                    name: "self".into(),
+                    type_name: None,
+                    ..Default::default()
+                },
+            ],
+        };
+
+        if operator.is_binary() {
+            parameters.entries.push(sway::Parameter {
+                name: "other".into(),
+                type_name: Some(self_type_name.clone()),
+                ..Default::default()
+            });
+        }
+
+        // impl core::ops::Add for T { fn add(self, other: Self) -> Self { ::add(self, other) } }
+        let function = sway::Function {
+            comment: None,
+            attributes: None,
+            is_public: false,
+            name: function_name.into(),
+            generic_parameters: None,
+            parameters: parameters.clone(),
+            return_type: Some(return_type.clone()),
+            body: Some(sway::Block {
+                statements: vec![],
+                final_expr: Some(sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier(format!("::{function_name_2}")),
+                    generic_parameters: None,
+                    parameters: parameters.entries.iter().map(|p| sway::Expression::Identifier(p.name.clone())).collect(),
+                })),
+            }),
+        };
+
+        get_operator_impl(translated_definition, trait_name, &type_name).items.push(sway::ImplItem::Function(function));
+    }
+
+    // `Ord` requires both `lt` and `gt`, so derive a missing function by swapping the operands of the other
+    if let Some(ord_impl) = translated_definition.impls.iter_mut().find(|i| is_operator_impl(i, "Ord", &type_name)) {
+        for (function_name, other_function_name) in [("lt", "gt"), ("gt", "lt")] {
+            if ord_impl.items.iter().any(|i| matches!(i, sway::ImplItem::Function(f) if f.name == function_name)) {
+                continue;
+            }
+
+            let Some(sway::ImplItem::Function(other_function)) = ord_impl.items.iter().find(|i| matches!(i, sway::ImplItem::Function(f) if f.name == other_function_name)).cloned() else {
+                continue;
+            };
+
+            let mut function = other_function;
+            function.name = function_name.into();
+
+            // a < b => b > a
+            if let Some(sway::Expression::FunctionCall(function_call)) = function.body.as_mut().and_then(|b| b.final_expr.as_mut()) {
+                function_call.parameters.reverse();
+            }
+
+            ord_impl.items.push(sway::ImplItem::Function(function));
+        }
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn is_operator_impl(sway_impl: &sway::Impl, trait_name: &str, type_name: &sway::TypeName) -> bool {
+    let sway::TypeName::Identifier { name, .. } = &sway_impl.type_name else { return false };
+    *name == format!("core::ops::{trait_name}") && sway_impl.for_type_name.as_ref() == Some(type_name)
+}
+
+/// Gets the implementation of the `core::ops` trait for the type. If it doesn't exist, it gets created.
+fn get_operator_impl<'a>(translated_definition: &'a mut TranslatedDefinition, trait_name: &str, type_name: &sway::TypeName) -> &'a mut sway::Impl {
+    if let Some(index) = translated_definition.impls.iter().position(|i| is_operator_impl(i, trait_name, type_name)) {
+        return &mut translated_definition.impls[index];
+    }
+
+    translated_definition.impls.push(sway::Impl {
+        generic_parameters: None,
+        type_name: sway::TypeName::Identifier {
+            name: format!("core::ops::{trait_name}"),
+            generic_parameters: None,
+        },
+        for_type_name: Some(type_name.clone()),
+        items: vec![],
+    });
+
+    translated_definition.impls.last_mut().unwrap()
+}
@@ -1,6 +1,6 @@
-use super::{translate_expression, TranslatedDefinition, TranslationScope};
-use crate::{project::Project, sway};
-use solang_parser::pt as solidity;
+use super::{import_user_type, resolve_import, resolve_import_symbols, translate_expression, TranslatedDefinition, TranslationScope};
+use crate::{errors::Error, project::{Lint, Project}, sway};
+use solang_parser::{helpers::CodeLocation, pt as solidity};
 use std::{cell::RefCell, rc::Rc};
 
 #[inline]
@@ -36,14 +36,66 @@ pub fn translate_return_type_name(
     }
 }
 
+/// Translates the type name of a function parameter, passing `storage` pointers by their storage key, i.e: `S storage s` => `s: StorageKey<S>`
+pub fn translate_parameter_type_name(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    parameter: &solidity::Parameter,
+) -> Result<sway::TypeName, Error> {
+    if !matches!(parameter.storage, Some(solidity::StorageLocation::Storage(_))) {
+        return translate_type_name(project, translated_definition, &parameter.ty, false, true);
+    }
+
+    Ok(sway::TypeName::Identifier {
+        name: "StorageKey".into(),
+        generic_parameters: Some(sway::GenericParameterList {
+            entries: vec![
+                sway::GenericParameter {
+                    type_name: translate_type_name(project, translated_definition, &parameter.ty, true, false)?,
+                    implements: None,
+                },
+            ],
+        }),
+    })
+}
+
 pub fn translate_type_name(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
     type_name: &solidity::Expression,
     is_storage: bool,
     is_parameter: bool,
-) -> sway::TypeName {
-    match type_name {
+) -> Result<sway::TypeName, Error> {
+    // Refer to types imported under an alias or through a namespace by their declared names
+    if let Some(type_name) = resolve_import_symbols(project, translated_definition, None, type_name) {
+        return translate_type_name(project, translated_definition, &type_name, is_storage, is_parameter);
+    }
+
+    // Check for user-supplied type overrides
+    if let Some(type_name) = project.type_overrides.get(&type_name.to_string()) {
+        // Import overriding types that are referenced by path, i.e: `src20::SRC20` => `use src20::SRC20;` and `SRC20`
+        if let sway::TypeName::Identifier { name, generic_parameters } = type_name {
+            if let Some((_, type_name)) = name.rsplit_once("::").filter(|_| name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == ':')) {
+                translated_definition.ensure_use_declared(name);
+
+                return Ok(sway::TypeName::Identifier {
+                    name: type_name.into(),
+                    generic_parameters: generic_parameters.clone(),
+                });
+            }
+        }
+
+        return Ok(type_name.clone());
+    }
+
+    // Check for type names translated by translation hooks
+    for translation_hook in project.translation_hooks.clone() {
+        if let Some(type_name) = translation_hook.translate_type_name(translated_definition, type_name, is_storage) {
+            return Ok(type_name);
+        }
+    }
+
+    Ok(match type_name {
         solidity::Expression::Type(_, type_expression) => match type_expression {
             solidity::Type::Address => sway::TypeName::Identifier {
                 name: "Identity".into(),
@@ -84,42 +136,42 @@ pub fn translate_type_name(
                     name: match *bits {
                         0..=8 => {
                             if *bits != 8 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I8`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported signed integer type `int{bits}`, using `I8`"));
                             }
                             translated_definition.ensure_use_declared("signed_integers::i8::*");
                             "I8".into()
                         }
                         9..=16 => {
                             if *bits != 16 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I16`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported signed integer type `int{bits}`, using `I16`"));
                             }
                             translated_definition.ensure_use_declared("signed_integers::i16::*");
                             "I16".into()
                         }
                         17..=32 => {
                             if *bits != 32 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I32`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported signed integer type `int{bits}`, using `I32`"));
                             }
                             translated_definition.ensure_use_declared("signed_integers::i32::*");
                             "I32".into()
                         }
                         33..=64 => {
                             if *bits != 64 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I64`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported signed integer type `int{bits}`, using `I64`"));
                             }
                             translated_definition.ensure_use_declared("signed_integers::i64::*");
                             "I64".into()
                         }
                         65..=128 => {
                             if *bits != 128 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I128`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported signed integer type `int{bits}`, using `I128`"));
                             }
                             translated_definition.ensure_use_declared("signed_integers::i128::*");
                             "I128".into()
                         }
                         129..=256 => {
                             if *bits != 256 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I256`...");
+                                project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported signed integer type `int{bits}`, using `I256`"));
                             }
                             translated_definition.ensure_use_declared("signed_integers::i256::*");
                             "I256".into()
@@ -134,31 +186,31 @@ pub fn translate_type_name(
                 name: match *bits {
                     0..=8 => {
                         if *bits != 8 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u8`...");
+                            project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u8`"));
                         }
                         "u8".into()
                     }
                     9..=16 => {
                         if *bits != 16 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u16`...");
+                            project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u16`"));
                         }
                         "u16".into()
                     }
                     17..=32 => {
                         if *bits != 32 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u32`...");
+                            project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u32`"));
                         }
                         "u32".into()
                     }
                     33..=64 => {
                         if *bits != 64 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u64`...");
+                            project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u64`"));
                         }
                         "u64".into()
                     }
                     65..=256 => {
                         if *bits != 256 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u256`...");
+                            project.report_warning(Lint::NarrowInt, &translated_definition.path, Some(&type_name.loc()), format!("unsupported unsigned integer type `uint{bits}`, using `u256`"));
                         }
                         "u256".into()
                     }
@@ -209,11 +261,11 @@ pub fn translate_type_name(
                                         generic_parameters: Some(sway::GenericParameterList {
                                             entries: vec![
                                                 sway::GenericParameter {
-                                                    type_name: translate_type_name(project, translated_definition, key.as_ref(), is_storage, is_parameter),
+                                                    type_name: translate_type_name(project, translated_definition, key.as_ref(), is_storage, is_parameter)?,
                                                     implements: None,
                                                 },
                                                 sway::GenericParameter {
-                                                    type_name: translate_type_name(project, translated_definition, value.as_ref(), is_storage, is_parameter),
+                                                    type_name: translate_type_name(project, translated_definition, value.as_ref(), is_storage, is_parameter)?,
                                                     implements: None,
                                                 },
                                             ],
@@ -230,11 +282,11 @@ pub fn translate_type_name(
                         generic_parameters: Some(sway::GenericParameterList {
                             entries: vec![
                                 sway::GenericParameter {
-                                    type_name: translate_type_name(project, translated_definition, key.as_ref(), is_storage, is_parameter),
+                                    type_name: translate_type_name(project, translated_definition, key.as_ref(), is_storage, is_parameter)?,
                                     implements: None,
                                 },
                                 sway::GenericParameter {
-                                    type_name: translate_type_name(project, translated_definition, value.as_ref(), is_storage, is_parameter),
+                                    type_name: translate_type_name(project, translated_definition, value.as_ref(), is_storage, is_parameter)?,
                                     implements: None,
                                 },
                             ],
@@ -252,18 +304,18 @@ pub fn translate_type_name(
                 sway::TypeName::Identifier { name: type_name, generic_parameters: None } if type_name == name => true,
                 _ => false,
             }) {
-                return sway::TypeName::Identifier {
+                return Ok(sway::TypeName::Identifier {
                     name: name.clone(),
                     generic_parameters: None,
-                };
+                });
             }
             
             // Check if type is a struct
             if translated_definition.struct_names.iter().any(|n| n == name) {
-                return sway::TypeName::Identifier {
+                return Ok(sway::TypeName::Identifier {
                     name: name.clone(),
                     generic_parameters: None,
-                };
+                });
             }
             
             // Check if type is an enum
@@ -271,15 +323,25 @@ pub fn translate_type_name(
                 sway::TypeName::Identifier { name: type_name, generic_parameters: None } => type_name == name,
                 _ => false,
             }) {
-                return sway::TypeName::Identifier {
+                return Ok(sway::TypeName::Identifier {
                     name: name.clone(),
                     generic_parameters: None,
-                };
+                });
             }
             
+            // Check if type is a struct, enum or user-defined value type declared in another source unit
+            if import_user_type(project, translated_definition, name)? {
+                return translate_type_name(project, translated_definition, type_name, is_storage, is_parameter);
+            }
+
             // Check if type is a contract that hasn't been defined yet
-            if project.find_definition_with_abi(name.as_str()).is_none() && translated_definition.contract_names.iter().any(|n| n == name) {
-                project.translate(Some(name), &translated_definition.path).unwrap();
+            if project.find_definition_with_abi(name.as_str()).is_none() {
+                if translated_definition.contract_names.iter().any(|n| n == name) {
+                    project.translate_source_unit(Some(name), &translated_definition.path)?;
+                } else {
+                    // The contract may be imported from a source unit that was not translated ahead of time (i.e: due to an import cycle)
+                    resolve_import(project, name, &translated_definition.path)?;
+                }
             }
 
             // Check if type is an ABI
@@ -289,10 +351,26 @@ pub fn translate_type_name(
                     translated_definition.abis.push(external_definition.abi.as_ref().unwrap().clone());
                 }
 
-                return sway::TypeName::Identifier {
+                return Ok(sway::TypeName::Identifier {
                     name: external_definition.name.clone(),
                     generic_parameters: None,
-                };
+                });
+            }
+
+            // Check if type is a contract that is still being translated (i.e: when definitions refer to each other through an import cycle)
+            // NOTE: its ABI is not available yet, so it is referred to by the `Identity` that ABI types are passed around as
+            if project.translation_stack.iter().any(|(_, definition_name)| definition_name == name) {
+                project.report_warning(
+                    Lint::ImportCycle,
+                    &translated_definition.path,
+                    Some(&type_name.loc()),
+                    format!("`{name}` is still being translated through an import cycle, so it was translated to `Identity` instead of its abi"),
+                );
+
+                return Ok(sway::TypeName::Identifier {
+                    name: "Identity".into(),
+                    generic_parameters: None,
+                });
             }
 
             todo!("translate variable type expression: {} - {type_name:#?}", type_name.to_string())
@@ -300,7 +378,7 @@ pub fn translate_type_name(
 
         solidity::Expression::ArraySubscript(_, type_name, length) => match length.as_ref() {
             Some(length) => sway::TypeName::Array {
-                type_name: Box::new(translate_type_name(project, translated_definition, type_name, is_storage, is_parameter)),
+                type_name: Box::new(translate_type_name(project, translated_definition, type_name, is_storage, is_parameter)?),
                 length: {
                     // Create an empty scope to translate the array length expression
                     let scope = Rc::new(RefCell::new(TranslationScope {
@@ -328,7 +406,7 @@ pub fn translate_type_name(
                 generic_parameters: Some(sway::GenericParameterList {
                     entries: vec![
                         sway::GenericParameter {
-                            type_name: translate_type_name(project, translated_definition, type_name, is_storage, is_parameter),
+                            type_name: translate_type_name(project, translated_definition, type_name, is_storage, is_parameter)?,
                             implements: None,
                         },
                     ],
@@ -365,7 +443,7 @@ pub fn translate_type_name(
                         translated_definition.import_enum(translated_enum);
                     }
 
-                    return type_name;
+                    return Ok(type_name);
                 }
 
                 todo!("member access type name expression: {type_name:#?}")
@@ -375,5 +453,5 @@ pub fn translate_type_name(
         }
 
         _ => unimplemented!("type name expression: {type_name:#?}"),
-    }
+    })
 }
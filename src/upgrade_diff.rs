@@ -0,0 +1,129 @@
+use crate::sway;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ItemChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl Display for ItemChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemChangeKind::Added => write!(f, "added"),
+            ItemChangeKind::Removed => write!(f, "removed"),
+            ItemChangeKind::Changed => write!(f, "changed"),
+        }
+    }
+}
+
+/// A generated Sway item that differs between two translations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemChange {
+    pub kind: ItemChangeKind,
+    pub item_kind: String,
+    pub item_name: String,
+}
+
+impl Display for ItemChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} `{}`", self.kind, self.item_kind, self.item_name)
+    }
+}
+
+/// Collects the reviewable items of a generated module as `(item kind, item name, generated code)` entries.
+pub fn collect_module_items(module: &sway::Module) -> Vec<(&'static str, String, String)> {
+    let mut items = vec![];
+
+    for item in module.items.iter() {
+        match item {
+            // Imports and submodule declarations are derived from the rest of the module and comments aren't code, so they aren't worth reviewing on their own
+            sway::ModuleItem::Comment(_) | sway::ModuleItem::Submodule(_) | sway::ModuleItem::Use(_) => {}
+
+            sway::ModuleItem::TypeDefinition(x) => items.push(("type definition", x.name.to_string(), sway::TabbedDisplayer(x).to_string())),
+            sway::ModuleItem::Constant(x) => items.push(("constant", x.name.clone(), sway::TabbedDisplayer(x).to_string())),
+            sway::ModuleItem::Struct(x) => items.push(("struct", x.name.clone(), sway::TabbedDisplayer(x).to_string())),
+            sway::ModuleItem::Enum(x) => items.push(("enum", x.name.clone(), sway::TabbedDisplayer(x).to_string())),
+            sway::ModuleItem::Trait(x) => items.push(("trait", x.name.clone(), sway::TabbedDisplayer(x).to_string())),
+            sway::ModuleItem::Function(x) => items.push(("function", x.name.clone(), sway::TabbedDisplayer(x).to_string())),
+
+            sway::ModuleItem::Abi(x) => {
+                for function in x.functions.iter() {
+                    items.push(("abi function", format!("{}::{}", x.name, function.name), sway::TabbedDisplayer(function).to_string()));
+                }
+            }
+
+            sway::ModuleItem::Storage(x) => {
+                for field in x.fields.iter() {
+                    items.push(("storage field", field.name.clone(), sway::TabbedDisplayer(field).to_string()));
+                }
+            }
+
+            sway::ModuleItem::Configurable(x) => {
+                for field in x.fields.iter() {
+                    items.push(("configurable field", field.name.clone(), sway::TabbedDisplayer(field).to_string()));
+                }
+            }
+
+            sway::ModuleItem::Impl(x) => {
+                let type_name = x.for_type_name.as_ref().unwrap_or(&x.type_name);
+
+                for impl_item in x.items.iter() {
+                    let (item_kind, item_name) = match impl_item {
+                        sway::ImplItem::Constant(c) => ("impl constant", c.name.clone()),
+                        sway::ImplItem::TypeDefinition(t) => ("impl type definition", t.name.to_string()),
+                        sway::ImplItem::Function(f) => ("impl function", f.name.clone()),
+                    };
+
+                    items.push((item_kind, format!("{type_name}::{item_name}"), sway::TabbedDisplayer(impl_item).to_string()));
+                }
+            }
+        }
+    }
+
+    items
+}
+
+/// Compares the items of two generated modules, returning every item that was added, removed or changed.
+pub fn diff_modules(old_module: Option<&sway::Module>, new_module: Option<&sway::Module>) -> Vec<ItemChange> {
+    let old_items = old_module.map(collect_module_items).unwrap_or_default();
+    let new_items = new_module.map(collect_module_items).unwrap_or_default();
+
+    diff_items(old_items.as_slice(), new_items.as_slice())
+}
+
+/// Compares two lists of `(item kind, item name, item contents)` entries, returning every item that was added, removed or changed.
+pub fn diff_items<K: AsRef<str>>(old_items: &[(K, String, String)], new_items: &[(K, String, String)]) -> Vec<ItemChange> {
+    let mut changes = vec![];
+
+    for (item_kind, item_name, old_code) in old_items.iter() {
+        match new_items.iter().find(|(k, n, _)| k.as_ref() == item_kind.as_ref() && n == item_name) {
+            Some((_, _, new_code)) if new_code != old_code => changes.push(ItemChange {
+                kind: ItemChangeKind::Changed,
+                item_kind: item_kind.as_ref().into(),
+                item_name: item_name.clone(),
+            }),
+
+            Some(_) => {}
+
+            None => changes.push(ItemChange {
+                kind: ItemChangeKind::Removed,
+                item_kind: item_kind.as_ref().into(),
+                item_name: item_name.clone(),
+            }),
+        }
+    }
+
+    for (item_kind, item_name, _) in new_items.iter() {
+        if !old_items.iter().any(|(k, n, _)| k.as_ref() == item_kind.as_ref() && n == item_name) {
+            changes.push(ItemChange {
+                kind: ItemChangeKind::Added,
+                item_kind: item_kind.as_ref().into(),
+                item_name: item_name.clone(),
+            });
+        }
+    }
+
+    changes
+}
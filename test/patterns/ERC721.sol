@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+// An ERC-721 token shaped like OpenZeppelin's v5 `ERC721`, whose ownership queries read the bookkeeping of the contract
+contract ERC721 {
+    mapping(uint256 tokenId => address) private _owners;
+    mapping(address owner => uint256) private _balances;
+    mapping(uint256 tokenId => address) private _tokenApprovals;
+    mapping(address owner => mapping(address operator => bool)) private _operatorApprovals;
+    string private _name;
+    string private _symbol;
+
+    event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+    event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId);
+    event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+
+    error ERC721NonexistentToken(uint256 tokenId);
+    error ERC721InvalidReceiver(address receiver);
+    error ERC721InsufficientApproval(address operator, uint256 tokenId);
+
+    constructor(string memory name_, string memory symbol_) {
+        _name = name_;
+        _symbol = symbol_;
+    }
+
+    function name() public view returns (string memory) {
+        return _name;
+    }
+
+    function symbol() public view returns (string memory) {
+        return _symbol;
+    }
+
+    function tokenURI(uint256 tokenId) public view returns (string memory) {
+        _requireOwned(tokenId);
+        return "";
+    }
+
+    function balanceOf(address owner) public view returns (uint256) {
+        return _balances[owner];
+    }
+
+    function ownerOf(uint256 tokenId) public view returns (address) {
+        return _requireOwned(tokenId);
+    }
+
+    function approve(address to, uint256 tokenId) public {
+        address owner = _requireOwned(tokenId);
+        _tokenApprovals[tokenId] = to;
+        emit Approval(owner, to, tokenId);
+    }
+
+    function getApproved(uint256 tokenId) public view returns (address) {
+        return _tokenApprovals[tokenId];
+    }
+
+    function setApprovalForAll(address operator, bool approved) public {
+        _operatorApprovals[msg.sender][operator] = approved;
+        emit ApprovalForAll(msg.sender, operator, approved);
+    }
+
+    function isApprovedForAll(address owner, address operator) public view returns (bool) {
+        return _operatorApprovals[owner][operator];
+    }
+
+    function transferFrom(address from, address to, uint256 tokenId) public {
+        if (to == address(0)) {
+            revert ERC721InvalidReceiver(address(0));
+        }
+        address owner = _requireOwned(tokenId);
+        if (owner != msg.sender && _tokenApprovals[tokenId] != msg.sender && !_operatorApprovals[owner][msg.sender]) {
+            revert ERC721InsufficientApproval(msg.sender, tokenId);
+        }
+        _update(to, tokenId);
+        emit Transfer(from, to, tokenId);
+    }
+
+    function _requireOwned(uint256 tokenId) internal view returns (address) {
+        address owner = _owners[tokenId];
+        if (owner == address(0)) {
+            revert ERC721NonexistentToken(tokenId);
+        }
+        return owner;
+    }
+
+    function _update(address to, uint256 tokenId) internal returns (address) {
+        address from = _owners[tokenId];
+        if (from != address(0)) {
+            _balances[from] -= 1;
+        }
+        if (to != address(0)) {
+            _balances[to] += 1;
+        }
+        _owners[tokenId] = to;
+        return from;
+    }
+
+    function _mint(address to, uint256 tokenId) internal {
+        if (to == address(0)) {
+            revert ERC721InvalidReceiver(address(0));
+        }
+        _update(to, tokenId);
+        emit Transfer(address(0), to, tokenId);
+    }
+
+    function _burn(uint256 tokenId) internal {
+        address from = _update(address(0), tokenId);
+        emit Transfer(from, address(0), tokenId);
+    }
+}
+
+contract Token is ERC721 {
+    uint256 private _nextTokenId;
+
+    constructor() ERC721("Token", "TKN") {}
+
+    function mint() public returns (uint256) {
+        uint256 tokenId = _nextTokenId++;
+        _mint(msg.sender, tokenId);
+        return tokenId;
+    }
+
+    function burn(uint256 tokenId) public {
+        _burn(tokenId);
+    }
+}
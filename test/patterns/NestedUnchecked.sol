@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+// An `unchecked` block calling a function with its own `unchecked` block, which must leave overflows unchecked when it returns
+contract NestedUnchecked {
+    function inc(uint256 a) internal pure returns (uint256) {
+        unchecked {
+            return a + 1;
+        }
+    }
+
+    function add(uint256 a, uint256 b) public pure returns (uint256) {
+        unchecked {
+            uint256 c = inc(a);
+            return c + b;
+        }
+    }
+
+    function sum(uint256[] memory values) public pure returns (uint256 total) {
+        for (uint256 i = 0; i < values.length; i++) {
+            unchecked {
+                if (values[i] == 0) {
+                    break;
+                }
+                total += inc(values[i]);
+            }
+        }
+    }
+}
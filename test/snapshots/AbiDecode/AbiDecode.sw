@@ -0,0 +1,38 @@
+contract;
+
+use std::bytes::Bytes;
+use std::constants::ZERO_B256;
+
+struct MyStruct {
+    name: str,
+    nums: [u256; 2],
+}
+
+abi AbiDecode {
+    fn encode(x: u256, addr: Identity, arr: Vec<u256>, my_struct: MyStruct) -> Bytes;
+
+    fn decode(data: Bytes) -> (u256, Identity, Vec<u256>, MyStruct);
+}
+
+impl AbiDecode for Contract {
+    fn encode(x: u256, addr: Identity, arr: Vec<u256>, my_struct: MyStruct) -> Bytes {
+        Bytes::from(core::codec::encode((x, addr, arr, my_struct)))
+    }
+
+    fn decode(data: Bytes) -> (u256, Identity, Vec<u256>, MyStruct) {
+        let mut x: u256 = 0;
+        let mut addr: Identity = Identity::Address(Address::from(ZERO_B256));
+        let mut arr: Vec<u256> = Vec::new();
+        let mut my_struct: MyStruct = MyStruct {
+            name: "",
+            nums: [0, 0],
+        };
+        // (uint x, address addr, uint[] memory arr, MyStruct myStruct) = ...
+        let value = core::codec::abi_decode::<(u256, Identity, Vec<u256>, MyStruct)>(data.as_raw_slice());
+        x = value.0;
+        addr = value.1;
+        arr = value.2;
+        my_struct = value.3;
+        (x, addr, arr, my_struct)
+    }
+}
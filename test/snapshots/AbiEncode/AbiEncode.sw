@@ -0,0 +1,35 @@
+contract;
+
+use std::bytes::Bytes;
+
+abi AbiEncode {
+    fn test(_contract: Identity, data: Bytes);
+
+    fn encode_with_signature(to: Identity, amount: u256) -> Bytes;
+
+    fn encode_with_selector(to: Identity, amount: u256) -> Bytes;
+
+    fn encode_call(to: Identity, amount: u256) -> Bytes;
+}
+
+impl AbiEncode for Contract {
+    fn test(_contract: Identity, data: Bytes) {
+        let (ok, _) = /* raw call data has no Fuel equivalent */ todo!("_contract.call(data)");
+        require(ok, "call failed");
+    }
+
+    fn encode_with_signature(to: Identity, amount: u256) -> Bytes {
+        // Typo is not checked - "transfer(address, uint)"
+        Bytes::from(core::codec::encode(([0xA9, 0x5, 0x9C, 0xBB], to, amount)))
+    }
+
+    fn encode_with_selector(to: Identity, amount: u256) -> Bytes {
+        // Type is not checked - (IERC20.transfer.selector, true, amount)
+        Bytes::from(core::codec::encode(([0xA9, 0x5, 0x9C, 0xBB], to, amount)))
+    }
+
+    fn encode_call(to: Identity, amount: u256) -> Bytes {
+        // Typo and type errors will not compile
+        Bytes::from(core::codec::encode(([0xA9, 0x5, 0x9C, 0xBB], to, amount)))
+    }
+}
@@ -0,0 +1,6 @@
+contract;
+
+abi IERC20 {
+    #[storage(read, write)]
+    fn transfer(_: Identity, _: u256);
+}
@@ -0,0 +1,10 @@
+contract;
+
+abi Token {
+    fn transfer(_: Identity, _: u256);
+}
+
+impl Token for Contract {
+    fn transfer(_: Identity, _: u256) {
+    }
+}
@@ -0,0 +1,125 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+abi Array {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn arr(a: u64) -> u256;
+
+    #[storage(read)]
+    fn arr_2(a: u64) -> u256;
+
+    #[storage(read)]
+    fn my_fixed_size_arr() -> [u256; 10];
+
+    #[storage(read)]
+    fn get(i: u256) -> u256;
+
+    #[storage(read)]
+    fn get_arr() -> Vec<u256>;
+
+    #[storage(read, write)]
+    fn push(i: u256);
+
+    #[storage(read, write)]
+    fn pop();
+
+    #[storage(read)]
+    fn get_length() -> u256;
+
+    #[storage(read, write)]
+    fn remove(index: u256);
+
+    fn examples();
+}
+
+storage {
+    // Several ways to initialize an array
+    arr: StorageVec<u256> = StorageVec {},
+    arr_2: StorageVec<u256> = StorageVec {},
+    // Fixed sized array, all elements initialize to 0
+    my_fixed_size_arr: [u256; 10] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    array_constructor_called: bool = false,
+}
+
+impl Array for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.array_constructor_called.read(), "The Array constructor has already been called");
+        storage.arr_2.push(1);
+        storage.arr_2.push(2);
+        storage.arr_2.push(3);
+        storage.array_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn arr(a: u64) -> u256 {
+        storage.arr.get(a).unwrap().read()
+    }
+
+    #[storage(read)]
+    fn arr_2(a: u64) -> u256 {
+        storage.arr_2.get(a).unwrap().read()
+    }
+
+    #[storage(read)]
+    fn my_fixed_size_arr() -> [u256; 10] {
+        storage.my_fixed_size_arr.read()
+    }
+
+    #[storage(read)]
+    fn get(i: u256) -> u256 {
+        storage.arr.get(u64::try_from(i).unwrap()).unwrap().read()
+    }
+
+    // Solidity can return the entire array.
+    // But this function should be avoided for
+    // arrays that can grow indefinitely in length.
+    #[storage(read)]
+    fn get_arr() -> Vec<u256> {
+        storage.arr.read()
+    }
+
+    #[storage(read, write)]
+    fn push(i: u256) {
+        // Append to array
+        // This will increase the array length by 1.
+        storage.arr.push(i);
+    }
+
+    #[storage(read, write)]
+    fn pop() {
+        // Remove last element from array
+        // This will decrease the array length by 1
+        storage.arr.pop();
+    }
+
+    #[storage(read)]
+    fn get_length() -> u256 {
+        storage.arr.len()
+    }
+
+    #[storage(read, write)]
+    fn remove(index: u256) {
+        // Delete does not change the array length.
+        // It resets the value at index to it's default value,
+        // in this case 0
+        storage.arr.get(u64::try_from(index).unwrap()).unwrap().write(0);
+    }
+
+    fn examples() {
+        // create array in memory, only fixed size can be created
+        let a = {
+            let mut v: Vec<u256> = Vec::with_capacity(5);
+            let mut i = 0;
+            while i < 5 {
+                v.push(0);
+                i += 1;
+            }
+            v
+        };
+    }
+}
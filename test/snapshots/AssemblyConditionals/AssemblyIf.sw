@@ -0,0 +1,30 @@
+contract;
+
+abi AssemblyIf {
+    fn yul_if(x: u256) -> u256;
+
+    fn yul_switch(x: u256) -> u256;
+}
+
+impl AssemblyIf for Contract {
+    fn yul_if(x: u256) -> u256 {
+        let mut z: u256 = 0;
+        if x < 10 {
+            z = 99;
+        }
+        z
+    }
+
+    fn yul_switch(x: u256) -> u256 {
+        let mut z: u256 = 0;
+        match x {
+            1 => {
+                z = 10;
+            },
+            2 => {
+                z = 20;
+            },
+        }
+        z
+    }
+}
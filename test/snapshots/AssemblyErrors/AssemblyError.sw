@@ -0,0 +1,13 @@
+contract;
+
+abi AssemblyError {
+    fn yul_revert(x: u256);
+}
+
+impl AssemblyError for Contract {
+    fn yul_revert(x: u256) {
+        if x > 10 {
+            todo!("revert(0, 0)");
+        }
+    }
+}
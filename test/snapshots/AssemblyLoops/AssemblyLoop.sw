@@ -0,0 +1,29 @@
+contract;
+
+abi AssemblyLoop {
+    fn yul_for_loop() -> u256;
+
+    fn yul_while_loop() -> u256;
+}
+
+impl AssemblyLoop for Contract {
+    fn yul_for_loop() -> u256 {
+        let mut z: u256 = 0;
+        let mut i = 0;
+        while i < 10 {
+            z = z + 1;
+            i = i + 1;
+        }
+        z
+    }
+
+    fn yul_while_loop() -> u256 {
+        let mut z: u256 = 0;
+        let mut i = 0;
+        while i < 5 {
+            i = i + 1;
+            z = z + 1;
+        }
+        z
+    }
+}
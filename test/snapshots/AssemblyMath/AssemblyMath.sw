@@ -0,0 +1,39 @@
+contract;
+
+abi AssemblyMath {
+    fn yul_add(x: u256, y: u256) -> u256;
+
+    fn yul_mul(x: u256, y: u256) -> u256;
+
+    fn yul_fixed_point_round(x: u256, b: u256) -> u256;
+}
+
+impl AssemblyMath for Contract {
+    fn yul_add(x: u256, y: u256) -> u256 {
+        let mut z: u256 = 0;
+        z = x + y;
+        if z < x {
+            todo!("revert(0, 0)");
+        }
+        z
+    }
+
+    fn yul_mul(x: u256, y: u256) -> u256 {
+        let mut z: u256 = 0;
+        match x {
+            0 => {
+                z = 0;
+            },
+        }
+        z
+    }
+
+    // Round to nearest multiple of b
+    fn yul_fixed_point_round(x: u256, b: u256) -> u256 {
+        let mut z: u256 = 0;
+        let half = b / 2;
+        z = x + half;
+        z = z / b * b;
+        z
+    }
+}
@@ -0,0 +1,14 @@
+contract;
+
+abi AssemblyVariable {
+    fn yul_let() -> u256;
+}
+
+impl AssemblyVariable for Contract {
+    fn yul_let() -> u256 {
+        let mut z: u256 = 0;
+        let x = 123;
+        z = 456;
+        z
+    }
+}
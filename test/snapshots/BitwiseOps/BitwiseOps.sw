@@ -0,0 +1,109 @@
+contract;
+
+abi BitwiseOps {
+    fn and(x: u256, y: u256) -> u256;
+
+    fn or(x: u256, y: u256) -> u256;
+
+    fn xor(x: u256, y: u256) -> u256;
+
+    fn not(x: u8) -> u8;
+
+    fn shift_left(x: u256, bits: u256) -> u256;
+
+    fn shift_right(x: u256, bits: u256) -> u256;
+
+    fn get_last_n_bits(x: u256, n: u256) -> u256;
+
+    fn get_last_n_bits_using_mod(x: u256, n: u256) -> u256;
+
+    fn most_significant_bit(x: u256) -> u256;
+
+    fn get_first_n_bits(x: u256, n: u256, len: u256) -> u256;
+}
+
+impl BitwiseOps for Contract {
+    // x     = 1110 = 8 + 4 + 2 + 0 = 14
+    // y     = 1011 = 8 + 0 + 2 + 1 = 11
+    // x & y = 1010 = 8 + 0 + 2 + 0 = 10
+    fn and(x: u256, y: u256) -> u256 {
+        x & y
+    }
+
+    // x     = 1100 = 8 + 4 + 0 + 0 = 12
+    // y     = 1001 = 8 + 0 + 0 + 1 = 9
+    // x | y = 1101 = 8 + 4 + 0 + 1 = 13
+    fn or(x: u256, y: u256) -> u256 {
+        x | y
+    }
+
+    // x     = 1100 = 8 + 4 + 0 + 0 = 12
+    // y     = 0101 = 0 + 4 + 0 + 1 = 5
+    // x ^ y = 1001 = 8 + 0 + 0 + 1 = 9
+    fn xor(x: u256, y: u256) -> u256 {
+        x ^ y
+    }
+
+    // x  = 00001100 =   0 +  0 +  0 +  0 + 8 + 4 + 0 + 0 = 12
+    // ~x = 11110011 = 128 + 64 + 32 + 16 + 0 + 0 + 2 + 1 = 243
+    fn not(x: u8) -> u8 {
+        !x
+    }
+
+    // 1 << 0 = 0001 --> 0001 = 1
+    // 1 << 1 = 0001 --> 0010 = 2
+    // 1 << 2 = 0001 --> 0100 = 4
+    // 1 << 3 = 0001 --> 1000 = 8
+    // 3 << 2 = 0011 --> 1100 = 12
+    fn shift_left(x: u256, bits: u256) -> u256 {
+        x << u64::try_from(bits).unwrap()
+    }
+
+    // 8  >> 0 = 1000 --> 1000 = 8
+    // 8  >> 1 = 1000 --> 0100 = 4
+    // 8  >> 2 = 1000 --> 0010 = 2
+    // 8  >> 3 = 1000 --> 0001 = 1
+    // 8  >> 4 = 1000 --> 0000 = 0
+    // 12 >> 1 = 1100 --> 0110 = 6
+    fn shift_right(x: u256, bits: u256) -> u256 {
+        x >> u64::try_from(bits).unwrap()
+    }
+
+    // Get last n bits from x
+    fn get_last_n_bits(x: u256, n: u256) -> u256 {
+        // Example, last 3 bits
+        // x        = 1101 = 13
+        // mask     = 0111 = 7
+        // x & mask = 0101 = 5
+        let mask = (1 << u64::try_from(n).unwrap()) - 1;
+        x & mask
+    }
+
+    // Get last n bits from x using mod operator
+    fn get_last_n_bits_using_mod(x: u256, n: u256) -> u256 {
+        // 1 << n = 2 ** n
+        x % (1 << u64::try_from(n).unwrap())
+    }
+
+    // Get position of most significant bit
+    // x = 1100 = 10, most significant bit = 1000, so this function will return 3
+    fn most_significant_bit(x: u256) -> u256 {
+        let mut x: u256 = x;
+        let mut i = 0;
+        while (x >>= 1) > 0 {
+            i += 1;
+        }
+        i
+    }
+
+    // Get first n bits from x
+    // len = length of bits in x = position of most significant bit of x, + 1
+    fn get_first_n_bits(x: u256, n: u256, len: u256) -> u256 {
+        // Example
+        // x        = 1110 = 14, n = 2, len = 4
+        // mask     = 1100 = 12
+        // x & mask = 1100 = 12
+        let mask = ((1 << u64::try_from(n).unwrap()) - 1) << u64::try_from(len - n).unwrap();
+        x & mask
+    }
+}
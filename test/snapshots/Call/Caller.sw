@@ -0,0 +1,66 @@
+contract;
+
+use std::bytes::Bytes;
+use core::codec::AbiEncode;
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+
+enum CallerEvent {
+    Response: (bool, Bytes),
+}
+
+impl AbiEncode for CallerEvent {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            CallerEvent::Response((a, b)) => {
+                "Response".abi_encode(buffer);
+                a.abi_encode(buffer);
+                b.abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi Receiver {
+    #[payable]
+    fn foo(_message: str, _x: u256) -> u256;
+}
+
+abi Caller {
+    #[payable]
+    fn test_call_foo(_addr: Identity);
+
+    #[payable]
+    fn test_call_does_not_exist(_addr: Identity);
+}
+
+fn expect_contract(identity: Identity) -> ContractId {
+    identity.as_contract_id().unwrap()
+}
+
+impl Caller for Contract {
+    // Let's imagine that contract Caller does not have the source code for the
+    // contract Receiver, but we do know the address of contract Receiver and the function to call.
+    #[payable]
+    fn test_call_foo(_addr: Identity) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        // You can send ether and specify a custom gas amount
+        let (success, data): (bool, Bytes) = {
+            let result = abi(Receiver, expect_contract(_addr).into()).foo {
+                coins: msg_amount(),
+                gas: 5000,
+            }
+            ("call foo", 123);
+            (true, Bytes::from(core::codec::encode(result)))
+        };
+        log(CallerEvent::Response((success, data)));
+    }
+
+    // Calling a function that does not exist triggers the fallback function.
+    #[payable]
+    fn test_call_does_not_exist(_addr: Identity) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        let (success, data): (bool, Bytes) = /* raw call data has no Fuel equivalent */ todo!("_addr.call{value: msg.value}(abi.encodeWithSignature(\"doesNotExist()\"))");
+        log(CallerEvent::Response((success, data)));
+    }
+}
@@ -0,0 +1,46 @@
+contract;
+
+use core::codec::AbiEncode;
+use std::auth::msg_sender;
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+
+enum ReceiverEvent {
+    Received: (Identity, u256, str),
+}
+
+impl AbiEncode for ReceiverEvent {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            ReceiverEvent::Received((a, b, c)) => {
+                "Received".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                b.abi_encode(buffer);
+                __to_str_array(c).abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi Receiver {
+    #[payable]
+    fn foo(_message: str, _x: u256) -> u256;
+}
+
+#[fallback]
+fn fallback() {
+    require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+    log(ReceiverEvent::Received((msg_sender().unwrap(), msg_amount(), "Fallback was called")));
+}
+
+impl Receiver for Contract {
+    #[payable]
+    fn foo(_message: str, _x: u256) -> u256 {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        log(ReceiverEvent::Received((msg_sender().unwrap(), msg_amount(), _message)));
+        _x + 1
+    }
+}
@@ -0,0 +1,49 @@
+contract;
+
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+
+abi Callee {
+    #[storage(read)]
+    fn x() -> u256;
+
+    #[storage(read)]
+    fn value() -> u256;
+
+    #[storage(read, write)]
+    fn set_x(_x: u256) -> u256;
+
+    #[storage(read, write), payable]
+    fn set_xand_send_ether(_x: u256) -> (u256, u256);
+}
+
+storage {
+    x: u256 = 0,
+    value: u256 = 0,
+}
+
+impl Callee for Contract {
+    #[storage(read)]
+    fn x() -> u256 {
+        storage.x.read()
+    }
+
+    #[storage(read)]
+    fn value() -> u256 {
+        storage.value.read()
+    }
+
+    #[storage(read, write)]
+    fn set_x(_x: u256) -> u256 {
+        storage.x.write(_x);
+        storage.x.read()
+    }
+
+    #[storage(read, write), payable]
+    fn set_xand_send_ether(_x: u256) -> (u256, u256) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        storage.x.write(_x);
+        storage.value.write(msg_amount());
+        (storage.x.read(), storage.value.read())
+    }
+}
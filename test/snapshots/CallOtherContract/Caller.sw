@@ -0,0 +1,55 @@
+contract;
+
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+
+abi Callee {
+    #[storage(read)]
+    fn x() -> u256;
+
+    #[storage(read)]
+    fn value() -> u256;
+
+    #[storage(read, write)]
+    fn set_x(_x: u256) -> u256;
+
+    #[storage(read, write), payable]
+    fn set_xand_send_ether(_x: u256) -> (u256, u256);
+}
+
+abi Caller {
+    fn set_x(_callee: Identity, _x: u256);
+
+    fn set_x_from_address(_addr: Identity, _x: u256);
+
+    #[payable]
+    fn set_xand_send_ether(_callee: Identity, _x: u256);
+}
+
+fn expect_contract(identity: Identity) -> ContractId {
+    identity.as_contract_id().unwrap()
+}
+
+fn set_x(_callee: Identity, _x: u256) {
+    let x = abi(Callee, expect_contract(_callee).into()).set_x(_x);
+}
+
+impl Caller for Contract {
+    fn set_x(_callee: Identity, _x: u256) {
+        ::set_x(_callee, _x)
+    }
+
+    fn set_x_from_address(_addr: Identity, _x: u256) {
+        let callee = abi(Callee, expect_contract(_addr).into());
+        callee.set_x(_x);
+    }
+
+    #[payable]
+    fn set_xand_send_ether(_callee: Identity, _x: u256) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        let (x, value): (u256, u256) = abi(Callee, expect_contract(_callee).into()).set_xand_send_ether {
+            coins: msg_amount(),
+        }
+        (_x);
+    }
+}
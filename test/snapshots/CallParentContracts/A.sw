@@ -0,0 +1,42 @@
+contract;
+
+use core::codec::AbiEncode;
+
+/* Inheritance tree
+   A
+ /  \
+B   C
+ \ /
+  D
+ */
+
+enum AEvent {
+    Log: str,
+}
+
+impl AbiEncode for AEvent {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            AEvent::Log(a) => {
+                "Log".abi_encode(buffer);
+                __to_str_array(a).abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi A {
+    fn foo();
+
+    fn bar();
+}
+
+impl A for Contract {
+    fn foo() {
+        log(AEvent::Log("A.foo called"));
+    }
+
+    fn bar() {
+        log(AEvent::Log("A.bar called"));
+    }
+}
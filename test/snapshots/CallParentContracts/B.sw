@@ -0,0 +1,44 @@
+contract;
+
+use core::codec::AbiEncode;
+
+enum AEvent {
+    Log: str,
+}
+
+impl AbiEncode for AEvent {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            AEvent::Log(a) => {
+                "Log".abi_encode(buffer);
+                __to_str_array(a).abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi B {
+    fn foo();
+
+    fn bar();
+}
+
+fn a_foo() {
+    log(AEvent::Log("A.foo called"));
+}
+
+fn a_bar() {
+    log(AEvent::Log("A.bar called"));
+}
+
+impl B for Contract {
+    fn foo() {
+        log(AEvent::Log("B.foo called"));
+        a_foo();
+    }
+
+    fn bar() {
+        log(AEvent::Log("B.bar called"));
+        a_bar();
+    }
+}
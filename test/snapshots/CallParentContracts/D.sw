@@ -0,0 +1,57 @@
+contract;
+
+use core::codec::AbiEncode;
+
+enum AEvent {
+    Log: str,
+}
+
+impl AbiEncode for AEvent {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            AEvent::Log(a) => {
+                "Log".abi_encode(buffer);
+                __to_str_array(a).abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi D {
+    fn foo();
+
+    fn bar();
+}
+
+fn a_foo() {
+    log(AEvent::Log("A.foo called"));
+}
+
+fn a_bar() {
+    log(AEvent::Log("A.bar called"));
+}
+
+fn c_foo() {
+    log(AEvent::Log("C.foo called"));
+    a_foo();
+}
+
+fn b_bar() {
+    log(AEvent::Log("B.bar called"));
+    a_bar();
+}
+
+fn c_bar() {
+    log(AEvent::Log("C.bar called"));
+    b_bar();
+}
+
+impl D for Contract {
+    fn foo() {
+        c_foo();
+    }
+
+    fn bar() {
+        c_bar();
+    }
+}
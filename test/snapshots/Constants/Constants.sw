@@ -0,0 +1,21 @@
+contract;
+
+// coding convention to uppercase constant variables
+pub const MY_ADDRESS: Identity = 0x777788889999AAAABBBBCCCCDDDDEEEEFFFFCCCCu256;
+pub const MY_UINT: u256 = 123;
+
+abi Constants {
+    fn MY_ADDRESS() -> Identity;
+
+    fn MY_UINT() -> u256;
+}
+
+impl Constants for Contract {
+    fn MY_ADDRESS() -> Identity {
+        MY_ADDRESS
+    }
+
+    fn MY_UINT() -> u256 {
+        MY_UINT
+    }
+}
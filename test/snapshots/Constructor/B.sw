@@ -0,0 +1,60 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+// There are 2 ways to initialize parent contract with parameters.
+// Pass the parameters here in the inheritance list.
+
+abi B {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn name() -> StorageString;
+
+    #[storage(read)]
+    fn text() -> StorageString;
+}
+
+storage {
+    name: StorageString = StorageString {},
+    x_constructor_called: bool = false,
+    text: StorageString = StorageString {},
+    y_constructor_called: bool = false,
+    b_constructor_called: bool = false,
+}
+
+#[storage(read, write)]
+fn x_constructor(_name: str) {
+    require(!storage.x_constructor_called.read(), "The X constructor has already been called");
+    storage.name.write_slice(String::from_ascii_str(_name));
+    storage.x_constructor_called.write(true);
+}
+
+#[storage(read, write)]
+fn y_constructor(_text: str) {
+    require(!storage.y_constructor_called.read(), "The Y constructor has already been called");
+    storage.text.write_slice(String::from_ascii_str(_text));
+    storage.y_constructor_called.write(true);
+}
+
+impl B for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.b_constructor_called.read(), "The B constructor has already been called");
+        x_constructor("Input to X");
+        y_constructor("Input to Y");
+        storage.b_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn name() -> StorageString {
+        storage.name.read()
+    }
+
+    #[storage(read)]
+    fn text() -> StorageString {
+        storage.text.read()
+    }
+}
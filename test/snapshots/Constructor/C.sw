@@ -0,0 +1,59 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+abi C {
+    #[storage(read, write)]
+    fn constructor(_name: str, _text: str);
+
+    #[storage(read)]
+    fn name() -> StorageString;
+
+    #[storage(read)]
+    fn text() -> StorageString;
+}
+
+storage {
+    name: StorageString = StorageString {},
+    x_constructor_called: bool = false,
+    text: StorageString = StorageString {},
+    y_constructor_called: bool = false,
+    c_constructor_called: bool = false,
+}
+
+#[storage(read, write)]
+fn x_constructor(_name: str) {
+    require(!storage.x_constructor_called.read(), "The X constructor has already been called");
+    storage.name.write_slice(String::from_ascii_str(_name));
+    storage.x_constructor_called.write(true);
+}
+
+#[storage(read, write)]
+fn y_constructor(_text: str) {
+    require(!storage.y_constructor_called.read(), "The Y constructor has already been called");
+    storage.text.write_slice(String::from_ascii_str(_text));
+    storage.y_constructor_called.write(true);
+}
+
+impl C for Contract {
+    // Pass the parameters here in the constructor,
+    // similar to function modifiers.
+    #[storage(read, write)]
+    fn constructor(_name: str, _text: str) {
+        require(!storage.c_constructor_called.read(), "The C constructor has already been called");
+        x_constructor(_name);
+        y_constructor(_text);
+        storage.c_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn name() -> StorageString {
+        storage.name.read()
+    }
+
+    #[storage(read)]
+    fn text() -> StorageString {
+        storage.text.read()
+    }
+}
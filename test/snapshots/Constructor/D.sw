@@ -0,0 +1,65 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+// Parent constructors are always called in the order of inheritance
+// regardless of the order of parent contracts listed in the
+// constructor of the child contract.
+// Order of constructors called:
+// 1. X
+// 2. Y
+// 3. D
+
+abi D {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn name() -> StorageString;
+
+    #[storage(read)]
+    fn text() -> StorageString;
+}
+
+storage {
+    name: StorageString = StorageString {},
+    x_constructor_called: bool = false,
+    text: StorageString = StorageString {},
+    y_constructor_called: bool = false,
+    d_constructor_called: bool = false,
+}
+
+#[storage(read, write)]
+fn x_constructor(_name: str) {
+    require(!storage.x_constructor_called.read(), "The X constructor has already been called");
+    storage.name.write_slice(String::from_ascii_str(_name));
+    storage.x_constructor_called.write(true);
+}
+
+#[storage(read, write)]
+fn y_constructor(_text: str) {
+    require(!storage.y_constructor_called.read(), "The Y constructor has already been called");
+    storage.text.write_slice(String::from_ascii_str(_text));
+    storage.y_constructor_called.write(true);
+}
+
+impl D for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.d_constructor_called.read(), "The D constructor has already been called");
+        x_constructor("X was called");
+        y_constructor("Y was called");
+        storage.d_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn name() -> StorageString {
+        storage.name.read()
+    }
+
+    #[storage(read)]
+    fn text() -> StorageString {
+        storage.text.read()
+    }
+}
@@ -0,0 +1,62 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+// Order of constructors called:
+// 1. X
+// 2. Y
+// 3. E
+
+abi E {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn name() -> StorageString;
+
+    #[storage(read)]
+    fn text() -> StorageString;
+}
+
+storage {
+    name: StorageString = StorageString {},
+    x_constructor_called: bool = false,
+    text: StorageString = StorageString {},
+    y_constructor_called: bool = false,
+    e_constructor_called: bool = false,
+}
+
+#[storage(read, write)]
+fn x_constructor(_name: str) {
+    require(!storage.x_constructor_called.read(), "The X constructor has already been called");
+    storage.name.write_slice(String::from_ascii_str(_name));
+    storage.x_constructor_called.write(true);
+}
+
+#[storage(read, write)]
+fn y_constructor(_text: str) {
+    require(!storage.y_constructor_called.read(), "The Y constructor has already been called");
+    storage.text.write_slice(String::from_ascii_str(_text));
+    storage.y_constructor_called.write(true);
+}
+
+impl E for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.e_constructor_called.read(), "The E constructor has already been called");
+        x_constructor("X was called");
+        y_constructor("Y was called");
+        storage.e_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn name() -> StorageString {
+        storage.name.read()
+    }
+
+    #[storage(read)]
+    fn text() -> StorageString {
+        storage.text.read()
+    }
+}
@@ -0,0 +1,33 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+// Base contract X
+
+abi X {
+    #[storage(read, write)]
+    fn constructor(_name: str);
+
+    #[storage(read)]
+    fn name() -> StorageString;
+}
+
+storage {
+    name: StorageString = StorageString {},
+    x_constructor_called: bool = false,
+}
+
+impl X for Contract {
+    #[storage(read, write)]
+    fn constructor(_name: str) {
+        require(!storage.x_constructor_called.read(), "The X constructor has already been called");
+        storage.name.write_slice(String::from_ascii_str(_name));
+        storage.x_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn name() -> StorageString {
+        storage.name.read()
+    }
+}
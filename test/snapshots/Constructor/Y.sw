@@ -0,0 +1,33 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+// Base contract Y
+
+abi Y {
+    #[storage(read, write)]
+    fn constructor(_text: str);
+
+    #[storage(read)]
+    fn text() -> StorageString;
+}
+
+storage {
+    text: StorageString = StorageString {},
+    y_constructor_called: bool = false,
+}
+
+impl Y for Contract {
+    #[storage(read, write)]
+    fn constructor(_text: str) {
+        require(!storage.y_constructor_called.read(), "The Y constructor has already been called");
+        storage.text.write_slice(String::from_ascii_str(_text));
+        storage.y_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn text() -> StorageString {
+        storage.text.read()
+    }
+}
@@ -0,0 +1,45 @@
+contract;
+
+abi Counter {
+    #[storage(read)]
+    fn count() -> u256;
+
+    #[storage(read)]
+    fn get() -> u256;
+
+    #[storage(read, write)]
+    fn inc();
+
+    #[storage(read, write)]
+    fn dec();
+}
+
+storage {
+    count: u256 = 0,
+}
+
+impl Counter for Contract {
+    #[storage(read)]
+    fn count() -> u256 {
+        storage.count.read()
+    }
+
+    // Function to get the current count
+    #[storage(read)]
+    fn get() -> u256 {
+        storage.count.read()
+    }
+
+    // Function to increment count by 1
+    #[storage(read, write)]
+    fn inc() {
+        storage.count.write(storage.count.read() + 1);
+    }
+
+    // Function to decrement count by 1
+    #[storage(read, write)]
+    fn dec() {
+        // This function will fail if count = 0
+        storage.count.write(storage.count.read() - 1);
+    }
+}
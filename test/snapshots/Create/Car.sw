@@ -0,0 +1,58 @@
+contract;
+
+use std::constants::ZERO_B256;
+use std::storage::storage_string::*;
+use std::string::*;
+use std::call_frames::msg_asset_id;
+
+abi Car {
+    #[storage(read, write), payable]
+    fn constructor(_owner: Identity, _model: str);
+
+    #[storage(read)]
+    fn owner() -> Identity;
+
+    #[storage(read)]
+    fn model() -> StorageString;
+
+    #[storage(read)]
+    fn car_addr() -> Identity;
+}
+
+storage {
+    owner: Identity = Identity::Address(Address::from(ZERO_B256)),
+    model: StorageString = StorageString {},
+    car_addr: Identity = Identity::Address(Address::from(ZERO_B256)),
+    car_constructor_called: bool = false,
+}
+
+fn to_identity(contract_id: ContractId) -> Identity {
+    Identity::ContractId(contract_id)
+}
+
+impl Car for Contract {
+    #[storage(read, write), payable]
+    fn constructor(_owner: Identity, _model: str) {
+        require(!storage.car_constructor_called.read(), "The Car constructor has already been called");
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        storage.owner.write(_owner);
+        storage.model.write_slice(String::from_ascii_str(_model));
+        storage.car_addr.write(to_identity(ContractId::this()));
+        storage.car_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn owner() -> Identity {
+        storage.owner.read()
+    }
+
+    #[storage(read)]
+    fn model() -> StorageString {
+        storage.model.read()
+    }
+
+    #[storage(read)]
+    fn car_addr() -> Identity {
+        storage.car_addr.read()
+    }
+}
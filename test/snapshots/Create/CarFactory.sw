@@ -0,0 +1,97 @@
+contract;
+
+use std::storage::storage_vec::*;
+use std::string::*;
+use std::constants::ZERO_B256;
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+
+abi Car {
+    #[storage(read, write), payable]
+    fn constructor(_owner: Identity, _model: str);
+
+    #[storage(read)]
+    fn owner() -> Identity;
+
+    #[storage(read)]
+    fn model() -> StorageString;
+
+    #[storage(read)]
+    fn car_addr() -> Identity;
+}
+
+abi CarFactory {
+    #[storage(read)]
+    fn cars(a: u64) -> Car;
+
+    #[storage(read, write)]
+    fn create(_owner: Identity, _model: str);
+
+    #[storage(read, write), payable]
+    fn create_and_send_ether(_owner: Identity, _model: str);
+
+    #[storage(read, write)]
+    fn create_2(_owner: Identity, _model: str, _salt: b256);
+
+    #[storage(read, write), payable]
+    fn create_2_and_send_ether(_owner: Identity, _model: str, _salt: b256);
+
+    #[storage(read)]
+    fn get_car(_index: u256) -> (Identity, String, Identity, u256);
+}
+
+storage {
+    cars: StorageVec<Car> = StorageVec {},
+}
+
+fn new_car(contract_id: ContractId, coins: u64, _owner: Identity, _model: str) -> Car {
+    let car = abi(Car, contract_id.into());
+    car.constructor {
+        coins: coins,
+    }
+    (_owner, _model);
+    car
+}
+
+impl CarFactory for Contract {
+    #[storage(read)]
+    fn cars(a: u64) -> Car {
+        storage.cars.get(a).unwrap().read()
+    }
+
+    #[storage(read, write)]
+    fn create(_owner: Identity, _model: str) {
+        let car = new_car(/*unsupported: new Car(_owner, _model); using:*/ ContractId::from(ZERO_B256), 0, _owner, _model);
+        storage.cars.push(car);
+    }
+
+    #[storage(read, write), payable]
+    fn create_and_send_ether(_owner: Identity, _model: str) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        let car = new_car(/*unsupported: (new Car){value: msg.value}(_owner, _model); using:*/ ContractId::from(ZERO_B256), msg_amount(), _owner, _model);
+        storage.cars.push(car);
+    }
+
+    #[storage(read, write)]
+    fn create_2(_owner: Identity, _model: str, _salt: b256) {
+        let car = new_car(/*unsupported: (new Car){salt: _salt}(_owner, _model); using:*/ ContractId::from(ZERO_B256), 0, _owner, _model);
+        storage.cars.push(car);
+    }
+
+    #[storage(read, write), payable]
+    fn create_2_and_send_ether(_owner: Identity, _model: str, _salt: b256) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        let car = new_car(/*unsupported: (new Car){value: msg.value, salt: _salt}(_owner, _model); using:*/ ContractId::from(ZERO_B256), msg_amount(), _owner, _model);
+        storage.cars.push(car);
+    }
+
+    #[storage(read)]
+    fn get_car(_index: u256) -> (Identity, String, Identity, u256) {
+        let mut owner: Identity = Identity::Address(Address::from(ZERO_B256));
+        let mut model: str = "";
+        let mut car_addr: Identity = Identity::Address(Address::from(ZERO_B256));
+        let mut balance: u256 = 0;
+        let car = storage.cars.get(u64::try_from(_index).unwrap()).unwrap().read();
+        (car.owner(), car.model(), car.car_addr(), todo!("address(car)"))
+    }
+}
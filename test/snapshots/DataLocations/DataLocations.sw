@@ -0,0 +1,58 @@
+contract;
+
+use std::storage::storage_vec::*;
+use std::hash::Hash;
+
+struct MyStruct {
+    foo: u256,
+}
+
+abi DataLocations {
+    #[storage(read)]
+    fn arr(a: u64) -> u256;
+
+    #[storage(read)]
+    fn f();
+
+    fn g(_arr: Vec<u256>) -> Vec<u256>;
+
+    fn h(_arr: Vec<u256>);
+}
+
+storage {
+    arr: StorageVec<u256> = StorageVec {},
+    map: StorageMap<u256, Identity> = StorageMap {},
+    my_structs: StorageMap<u256, MyStruct> = StorageMap {},
+}
+
+fn _f(_arr: StorageKey<StorageVec<u256>>, _map: StorageKey<StorageMap<u256, Identity>>, _my_struct: StorageKey<MyStruct>) {
+    // do something with storage variables
+}
+
+impl DataLocations for Contract {
+    #[storage(read)]
+    fn arr(a: u64) -> u256 {
+        storage.arr.get(a).unwrap().read()
+    }
+
+    #[storage(read)]
+    fn f() {
+        // call _f with state variables
+        _f(storage.arr, storage.map, storage.my_structs.get(1));
+        // get a struct from a mapping
+        let my_struct: StorageKey<MyStruct> = storage.my_structs.get(1);
+        // create a struct in memory
+        let my_mem_struct = MyStruct {
+            foo: 0,
+        };
+    }
+
+    // You can return memory variables
+    fn g(_arr: Vec<u256>) -> Vec<u256> {
+        // do something with memory array
+    }
+
+    fn h(_arr: Vec<u256>) {
+        // do something with calldata array
+    }
+}
@@ -0,0 +1,49 @@
+contract;
+
+use std::constants::ZERO_B256;
+use std::bytes::Bytes;
+use std::call_frames::msg_asset_id;
+
+abi A {
+    #[storage(read)]
+    fn num() -> u256;
+
+    #[storage(read)]
+    fn sender() -> Identity;
+
+    #[storage(read)]
+    fn value() -> u256;
+
+    #[payable]
+    fn set_vars(_contract: Identity, _num: u256);
+}
+
+storage {
+    num: u256 = 0,
+    sender: Identity = Identity::Address(Address::from(ZERO_B256)),
+    value: u256 = 0,
+}
+
+impl A for Contract {
+    #[storage(read)]
+    fn num() -> u256 {
+        storage.num.read()
+    }
+
+    #[storage(read)]
+    fn sender() -> Identity {
+        storage.sender.read()
+    }
+
+    #[storage(read)]
+    fn value() -> u256 {
+        storage.value.read()
+    }
+
+    #[payable]
+    fn set_vars(_contract: Identity, _num: u256) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        // A's storage is set, B is not modified.
+        let (success, data): (bool, Bytes) = /* delegatecall has no Fuel equivalent */ todo!("_contract.delegatecall(abi.encodeWithSignature(\"setVars(uint256)\", _num))");
+    }
+}
@@ -0,0 +1,54 @@
+contract;
+
+use std::constants::ZERO_B256;
+use std::auth::msg_sender;
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+
+// NOTE: Deploy this contract first
+
+abi B {
+    #[storage(read)]
+    fn num() -> u256;
+
+    #[storage(read)]
+    fn sender() -> Identity;
+
+    #[storage(read)]
+    fn value() -> u256;
+
+    #[storage(read, write), payable]
+    fn set_vars(_num: u256);
+}
+
+storage {
+    // NOTE: storage layout must be the same as contract A
+    num: u256 = 0,
+    sender: Identity = Identity::Address(Address::from(ZERO_B256)),
+    value: u256 = 0,
+}
+
+impl B for Contract {
+    #[storage(read)]
+    fn num() -> u256 {
+        storage.num.read()
+    }
+
+    #[storage(read)]
+    fn sender() -> Identity {
+        storage.sender.read()
+    }
+
+    #[storage(read)]
+    fn value() -> u256 {
+        storage.value.read()
+    }
+
+    #[storage(read, write), payable]
+    fn set_vars(_num: u256) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        storage.num.write(_num);
+        storage.sender.write(msg_sender().unwrap());
+        storage.value.write(msg_amount());
+    }
+}
@@ -0,0 +1,70 @@
+contract;
+
+type Status = u8;
+
+impl Status {
+    const PENDING: Status = 0;
+    const SHIPPED: Status = 1;
+    const ACCEPTED: Status = 2;
+    const REJECTED: Status = 3;
+    const CANCELED: Status = 4;
+}
+
+abi Enum {
+    #[storage(read)]
+    fn status() -> Status;
+
+    #[storage(read)]
+    fn get() -> Status;
+
+    #[storage(read, write)]
+    fn set(_status: Status);
+
+    #[storage(read, write)]
+    fn cancel();
+
+    #[storage(read, write)]
+    fn reset();
+}
+
+storage {
+    // Default value is the first element listed in
+    // definition of the type, in this case "Pending"
+    status: Status = Status::PENDING,
+}
+
+impl Enum for Contract {
+    #[storage(read)]
+    fn status() -> Status {
+        storage.status.read()
+    }
+
+    // Returns uint
+    // Pending  - 0
+    // Shipped  - 1
+    // Accepted - 2
+    // Rejected - 3
+    // Canceled - 4
+    #[storage(read)]
+    fn get() -> Status {
+        storage.status.read()
+    }
+
+    // Update status by passing uint into input
+    #[storage(read, write)]
+    fn set(_status: Status) {
+        storage.status.write(_status);
+    }
+
+    // You can update to a specific enum like this
+    #[storage(read, write)]
+    fn cancel() {
+        storage.status.write(Status::CANCELED);
+    }
+
+    // delete resets the enum to its first value, 0
+    #[storage(read, write)]
+    fn reset() {
+        storage.status.write(Status::PENDING);
+    }
+}
@@ -0,0 +1,78 @@
+contract;
+
+use core::codec::AbiEncode;
+
+enum ErrorError {
+    InsufficientBalance: (u256, u256),
+}
+
+impl AbiEncode for ErrorError {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            ErrorError::InsufficientBalance((a, b)) => {
+                "InsufficientBalance".abi_encode(buffer);
+                a.abi_encode(buffer);
+                b.abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi Error {
+    #[storage(read)]
+    fn num() -> u256;
+
+    fn test_require(_i: u256);
+
+    fn test_revert(_i: u256);
+
+    #[storage(read)]
+    fn test_assert();
+
+    fn test_custom_error(_withdraw_amount: u256);
+}
+
+storage {
+    num: u256 = 0,
+}
+
+impl Error for Contract {
+    #[storage(read)]
+    fn num() -> u256 {
+        storage.num.read()
+    }
+
+    fn test_require(_i: u256) {
+        // Require should be used to validate conditions such as:
+        // - inputs
+        // - conditions before execution
+        // - return values from calls to other functions
+        require(_i > 10, "Input must be greater than 10");
+    }
+
+    fn test_revert(_i: u256) {
+        // Revert is useful when the condition to check is complex.
+        // This code does the exact same thing as the example above
+        if _i <= 10 {
+            log("Input must be greater than 10");
+            revert(0);
+        }
+    }
+
+    #[storage(read)]
+    fn test_assert() {
+        // Assert should only be used to test for internal errors,
+        // and to check invariants.
+        // Here we assert that num is always equal to 0
+        // since it is impossible to update the value of num
+        assert(storage.num.read() == 0);
+    }
+
+    fn test_custom_error(_withdraw_amount: u256) {
+        let bal = std::context::this_balance(AssetId::base()).as_u256();
+        if bal < _withdraw_amount {
+            log(ErrorError::InsufficientBalance((bal, _withdraw_amount)));
+            revert(0);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+contract;
+
+abi EtherUnits {
+    #[storage(read)]
+    fn one_wei() -> u256;
+
+    #[storage(read)]
+    fn is_one_wei() -> bool;
+
+    #[storage(read)]
+    fn one_ether() -> u256;
+
+    #[storage(read)]
+    fn is_one_ether() -> bool;
+}
+
+storage {
+    one_wei: u256 = 1,
+    // 1 wei is equal to 1
+    is_one_wei: bool = true,
+    one_ether: u256 = 1000000000000000000,
+    // 1 ether is equal to 10^18 wei
+    is_one_ether: bool = true,
+}
+
+impl EtherUnits for Contract {
+    #[storage(read)]
+    fn one_wei() -> u256 {
+        storage.one_wei.read()
+    }
+
+    #[storage(read)]
+    fn is_one_wei() -> bool {
+        storage.is_one_wei.read()
+    }
+
+    #[storage(read)]
+    fn one_ether() -> u256 {
+        storage.one_ether.read()
+    }
+
+    #[storage(read)]
+    fn is_one_ether() -> bool {
+        storage.is_one_ether.read()
+    }
+}
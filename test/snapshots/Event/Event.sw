@@ -0,0 +1,39 @@
+contract;
+
+use core::codec::AbiEncode;
+use std::auth::msg_sender;
+
+enum EventEvent {
+    Log: (Identity, str),
+    AnotherLog: (),
+}
+
+impl AbiEncode for EventEvent {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            EventEvent::Log((a, b)) => {
+                "Log".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                __to_str_array(b).abi_encode(buffer);
+            },
+            EventEvent::AnotherLog => {
+                "AnotherLog".abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi Event {
+    fn test();
+}
+
+impl Event for Contract {
+    fn test() {
+        log(EventEvent::Log((msg_sender().unwrap(), "Hello World!")));
+        log(EventEvent::Log((msg_sender().unwrap(), "Hello EVM!")));
+        log(EventEvent::AnotherLog);
+    }
+}
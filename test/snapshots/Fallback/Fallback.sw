@@ -0,0 +1,49 @@
+contract;
+
+use core::codec::AbiEncode;
+use std::call_frames::msg_asset_id;
+
+enum FallbackEvent {
+    Log: (str, u256),
+}
+
+impl AbiEncode for FallbackEvent {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            FallbackEvent::Log((a, b)) => {
+                "Log".abi_encode(buffer);
+                __to_str_array(a).abi_encode(buffer);
+                b.abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi Fallback {
+    #[payable]
+    fn receive();
+
+    fn get_balance() -> u256;
+}
+
+#[fallback]
+fn fallback() {
+    require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+    // send / transfer (forwards 2300 gas to this fallback function)
+    // call (forwards all of the gas)
+    log(FallbackEvent::Log(("fallback", std::registers::global_gas().as_u256())));
+}
+
+impl Fallback for Contract {
+    // Receive is a variant of fallback that is triggered when msg.data is empty
+    #[payable]
+    fn receive() {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        log(FallbackEvent::Log(("receive", std::registers::global_gas().as_u256())));
+    }
+
+    // Helper function to check the balance of this contract
+    fn get_balance() -> u256 {
+        std::context::this_balance(AssetId::base()).as_u256()
+    }
+}
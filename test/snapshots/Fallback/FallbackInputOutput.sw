@@ -0,0 +1,41 @@
+contract;
+
+use std::constants::ZERO_B256;
+use std::bytes::Bytes;
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+
+// TestFallbackInputOutput -> FallbackInputOutput -> Counter
+
+abi FallbackInputOutput {
+    #[storage(read, write)]
+    fn constructor(_target: Identity);
+
+    #[payable]
+    fn fallback(data: Bytes) -> Bytes;
+}
+
+storage {
+    fallback_input_output_constructor_called: bool = false,
+}
+
+configurable {
+    TARGET: Identity = Identity::Address(Address::from(ZERO_B256)),
+}
+
+impl FallbackInputOutput for Contract {
+    #[storage(read, write)]
+    fn constructor(_target: Identity) {
+        require(!storage.fallback_input_output_constructor_called.read(), "The FallbackInputOutput constructor has already been called");
+        TARGET = _target;
+        storage.fallback_input_output_constructor_called.write(true);
+    }
+
+    #[payable]
+    fn fallback(data: Bytes) -> Bytes {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        let (ok, res): (bool, Bytes) = /* raw call data has no Fuel equivalent */ todo!("target.call{value: msg.value}(data)");
+        require(ok, "call failed");
+        res
+    }
+}
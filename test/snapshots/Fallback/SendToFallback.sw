@@ -0,0 +1,31 @@
+contract;
+
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+use std::bytes::Bytes;
+
+abi SendToFallback {
+    #[payable]
+    fn transfer_to_fallback(_to: Identity);
+
+    #[payable]
+    fn call_fallback(_to: Identity);
+}
+
+impl SendToFallback for Contract {
+    #[payable]
+    fn transfer_to_fallback(_to: Identity) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        std::asset::transfer(_to, AssetId::base(), msg_amount());
+    }
+
+    #[payable]
+    fn call_fallback(_to: Identity) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        let (sent, _): (bool, Bytes) = {
+            std::asset::transfer(_to, AssetId::base(), msg_amount());
+            (true, Bytes::new())
+        };
+        require(sent, "Failed to send Ether");
+    }
+}
@@ -0,0 +1,37 @@
+contract;
+
+use std::bytes::Bytes;
+use core::codec::AbiEncode;
+
+enum TestFallbackInputOutputEvent {
+    Log: Bytes,
+}
+
+impl AbiEncode for TestFallbackInputOutputEvent {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            TestFallbackInputOutputEvent::Log(a) => {
+                "Log".abi_encode(buffer);
+                a.abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi TestFallbackInputOutput {
+    fn test(_fallback: Identity, data: Bytes);
+
+    fn get_test_data() -> (Bytes, Bytes);
+}
+
+impl TestFallbackInputOutput for Contract {
+    fn test(_fallback: Identity, data: Bytes) {
+        let (ok, res): (bool, Bytes) = /* raw call data has no Fuel equivalent */ todo!("_fallback.call(data)");
+        require(ok, "call failed");
+        log(TestFallbackInputOutputEvent::Log(res));
+    }
+
+    fn get_test_data() -> (Bytes, Bytes) {
+        (Bytes::from(core::codec::encode([0x6D, 0x4C, 0xE6, 0x3C])), Bytes::from(core::codec::encode([0x37, 0x13, 0x3, 0xC0])))
+    }
+}
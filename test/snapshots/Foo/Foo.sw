@@ -0,0 +1,40 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+struct Point {
+    x: u256,
+    y: u256,
+}
+
+abi Foo {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn name() -> StorageString;
+}
+
+storage {
+    name: StorageString = StorageString {},
+    foo_constructor_called: bool = false,
+}
+
+fn add(x: u256, y: u256) -> u256 {
+    x + y
+}
+
+impl Foo for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.foo_constructor_called.read(), "The Foo constructor has already been called");
+        storage.name.write_slice(String::from_ascii_str("Foo"));
+        storage.foo_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn name() -> StorageString {
+        storage.name.read()
+    }
+}
@@ -0,0 +1,82 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+abi Function {
+    #[storage(read)]
+    fn arr(a: u64) -> u256;
+
+    fn return_many() -> (u256, bool, u256);
+
+    fn named() -> (u256, bool, u256);
+
+    fn assigned() -> (u256, bool, u256);
+
+    fn destructuring_assignments() -> (u256, bool, u256, u256, u256);
+
+    fn array_input(_arr: Vec<u256>);
+
+    #[storage(read)]
+    fn array_output() -> Vec<u256>;
+}
+
+storage {
+    // Can use array for output
+    arr: StorageVec<u256> = StorageVec {},
+}
+
+// Functions can return multiple values.
+fn return_many() -> (u256, bool, u256) {
+    (1, true, 2)
+}
+
+impl Function for Contract {
+    #[storage(read)]
+    fn arr(a: u64) -> u256 {
+        storage.arr.get(a).unwrap().read()
+    }
+
+    // Functions can return multiple values.
+    fn return_many() -> (u256, bool, u256) {
+        ::return_many()
+    }
+
+    // Return values can be named.
+    fn named() -> (u256, bool, u256) {
+        let mut x: u256 = 0;
+        let mut b: bool = false;
+        let mut y: u256 = 0;
+        (1, true, 2)
+    }
+
+    // Return values can be assigned to their name.
+    // In this case the return statement can be omitted.
+    fn assigned() -> (u256, bool, u256) {
+        let mut x: u256 = 0;
+        let mut b: bool = false;
+        let mut y: u256 = 0;
+        x = 1;
+        b = true;
+        y = 2;
+        (x, b, y)
+    }
+
+    // Use destructuring assignment when calling another
+    // function that returns multiple values.
+    fn destructuring_assignments() -> (u256, bool, u256, u256, u256) {
+        let (i, b, j): (u256, bool, u256) = return_many();
+        // Values can be left out.
+        let (x, _, y): (u256, u64, u256) = (4, 5, 6);
+        (i, b, j, x, y)
+    }
+
+    // Cannot use map for either input or output
+    // Can use array for input
+    fn array_input(_arr: Vec<u256>) {
+    }
+
+    #[storage(read)]
+    fn array_output() -> Vec<u256> {
+        storage.arr.read()
+    }
+}
@@ -0,0 +1,30 @@
+contract;
+
+use std::constants::ZERO_B256;
+
+// Call function with key-value inputs
+
+abi XYZ {
+    fn some_func_with_many_inputs(x: u256, y: u256, z: u256, a: Identity, b: bool, c: str) -> u256;
+
+    fn call_func() -> u256;
+
+    fn call_func_with_key_value() -> u256;
+}
+
+fn some_func_with_many_inputs(x: u256, y: u256, z: u256, a: Identity, b: bool, c: str) -> u256 {
+}
+
+impl XYZ for Contract {
+    fn some_func_with_many_inputs(x: u256, y: u256, z: u256, a: Identity, b: bool, c: str) -> u256 {
+        ::some_func_with_many_inputs(x, y, z, a, b, c)
+    }
+
+    fn call_func() -> u256 {
+        some_func_with_many_inputs(1, 2, 3, Identity::Address(Address::from(ZERO_B256)), true, "c")
+    }
+
+    fn call_func_with_key_value() -> u256 {
+        some_func_with_many_inputs(1, 2, 3, Identity::Address(Address::from(ZERO_B256)), true, "c")
+    }
+}
@@ -0,0 +1,100 @@
+contract;
+
+use std::constants::ZERO_B256;
+use std::auth::msg_sender;
+
+abi FunctionModifier {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn owner() -> Identity;
+
+    #[storage(read)]
+    fn x() -> u256;
+
+    #[storage(read)]
+    fn locked() -> bool;
+
+    #[storage(read, write)]
+    fn change_owner(_new_owner: Identity);
+
+    #[storage(read, write)]
+    fn decrement(i: u256);
+}
+
+storage {
+    // We will use these variables to demonstrate how to use
+    // modifiers.
+    owner: Identity = Identity::Address(Address::from(ZERO_B256)),
+    x: u256 = 10,
+    locked: bool = false,
+    function_modifier_constructor_called: bool = false,
+}
+
+#[storage(read)]
+fn only_owner() {
+    require(msg_sender().unwrap() == storage.owner.read(), "Not owner");
+}
+
+fn valid_address(_addr: Identity) {
+    require(_addr != Identity::Address(Address::from(ZERO_B256)), "Not valid address");
+}
+
+#[storage(read, write)]
+fn no_reentrancy_pre() {
+    require(!storage.locked.read(), "No reentrancy");
+    storage.locked.write(true);
+}
+
+#[storage(read, write)]
+fn no_reentrancy_post() {
+    storage.locked.write(false);
+}
+
+#[storage(read, write)]
+fn decrement(i: u256) {
+    no_reentrancy_pre();
+    storage.x.write(storage.x.read() - i);
+    if i > 1 {
+        decrement(i - 1);
+    }
+    no_reentrancy_post();
+}
+
+impl FunctionModifier for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.function_modifier_constructor_called.read(), "The FunctionModifier constructor has already been called");
+        // Set the transaction sender as the owner of the contract.
+        storage.owner.write(msg_sender().unwrap());
+        storage.function_modifier_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn owner() -> Identity {
+        storage.owner.read()
+    }
+
+    #[storage(read)]
+    fn x() -> u256 {
+        storage.x.read()
+    }
+
+    #[storage(read)]
+    fn locked() -> bool {
+        storage.locked.read()
+    }
+
+    #[storage(read, write)]
+    fn change_owner(_new_owner: Identity) {
+        only_owner();
+        valid_address(_new_owner);
+        storage.owner.write(_new_owner);
+    }
+
+    #[storage(read, write)]
+    fn decrement(i: u256) {
+        ::decrement(i)
+    }
+}
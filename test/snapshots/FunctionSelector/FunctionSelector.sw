@@ -0,0 +1,24 @@
+contract;
+
+use std::bytes::Bytes;
+
+abi FunctionSelector {
+    fn get_selector(_func: str) -> [u8; 4];
+}
+
+fn b256_to_bytes4(value: b256) -> [u8; 4] {
+    let bytes = Bytes::from(value);
+    [bytes.get(0).unwrap(), bytes.get(1).unwrap(), bytes.get(2).unwrap(), bytes.get(3).unwrap()]
+}
+
+impl FunctionSelector for Contract {
+    /*
+    "transfer(address,uint256)"
+    0xa9059cbb
+    "transferFrom(address,address,uint256)"
+    0x23b872dd
+     */
+    fn get_selector(_func: str) -> [u8; 4] {
+        b256_to_bytes4(std::hash::keccak256(Bytes::from(raw_slice::from_parts::<u8>(_func.as_ptr(), _func.len()))))
+    }
+}
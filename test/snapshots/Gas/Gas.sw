@@ -0,0 +1,32 @@
+contract;
+
+abi Gas {
+    #[storage(read)]
+    fn i() -> u256;
+
+    #[storage(read, write)]
+    fn forever();
+}
+
+storage {
+    i: u256 = 0,
+}
+
+impl Gas for Contract {
+    #[storage(read)]
+    fn i() -> u256 {
+        storage.i.read()
+    }
+
+    // Using up all of the gas that you send causes your transaction to fail.
+    // State changes are undone.
+    // Gas spent are not refunded.
+    #[storage(read, write)]
+    fn forever() {
+        // Here we run a loop until all of the gas are spent
+        // and the transaction fails
+        while true {
+            storage.i.write(storage.i.read() + 1);
+        }
+    }
+}
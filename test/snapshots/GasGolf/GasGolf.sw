@@ -0,0 +1,60 @@
+contract;
+
+// gas golf
+
+abi GasGolf {
+    #[storage(read)]
+    fn total() -> u256;
+
+    #[storage(read, write)]
+    fn sum_if_even_and_less_than_99(nums: Vec<u256>);
+}
+
+storage {
+    // start - 50908 gas
+    // use calldata - 49163 gas
+    // load state variables to memory - 48952 gas
+    // short circuit - 48634 gas
+    // loop increments - 48244 gas
+    // cache array length - 48209 gas
+    // load array elements to memory - 48047 gas
+    // uncheck i overflow/underflow - 47309 gas
+    total: u256 = 0,
+}
+
+impl GasGolf for Contract {
+    #[storage(read)]
+    fn total() -> u256 {
+        storage.total.read()
+    }
+
+    // start - not gas optimized
+    // function sumIfEvenAndLessThan99(uint[] memory nums) external {
+    //     for (uint i = 0; i < nums.length; i += 1) {
+    //         bool isEven = nums[i] % 2 == 0;
+    //         bool isLessThan99 = nums[i] < 99;
+    //         if (isEven && isLessThan99) {
+    //             total += nums[i];
+    //         }
+    //     }
+    // }
+    // gas optimized
+    // [1, 2, 3, 4, 5, 100]
+    #[storage(read, write)]
+    fn sum_if_even_and_less_than_99(nums: Vec<u256>) {
+        let mut _total = storage.total.read();
+        let len = nums.len();
+        let mut i = 0;
+        while i < len {
+            let num = nums.get(u64::try_from(i).unwrap()).unwrap();
+            if num % 2 == 0 && num < 99 {
+                _total += num;
+            }
+            let flags = std::registers::flags();
+            std::flags::disable_panic_on_overflow();
+            i += 1;
+            std::flags::set_flags(flags);
+        }
+        storage.total.write(_total);
+    }
+}
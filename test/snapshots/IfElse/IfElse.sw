@@ -0,0 +1,33 @@
+contract;
+
+abi IfElse {
+    fn foo(x: u256) -> u256;
+
+    fn ternary(_x: u256) -> u256;
+}
+
+impl IfElse for Contract {
+    fn foo(x: u256) -> u256 {
+        if x < 10 {
+            return 0;
+        } else if x < 20 {
+            return 1;
+        } else {
+            return 2;
+        }
+    }
+
+    fn ternary(_x: u256) -> u256 {
+        // if (_x < 10) {
+        //     return 1;
+        // }
+        // return 2;
+        // shorthand way to write if / else statement
+        // the "?" operator is called the ternary operator
+        if _x < 10 {
+            1
+        } else {
+            2
+        }
+    }
+}
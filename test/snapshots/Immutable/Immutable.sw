@@ -0,0 +1,40 @@
+contract;
+
+use std::constants::ZERO_B256;
+use std::auth::msg_sender;
+
+abi Immutable {
+    #[storage(read, write)]
+    fn constructor(_my_uint: u256);
+
+    fn MY_ADDRESS() -> Identity;
+
+    fn MY_UINT() -> u256;
+}
+
+storage {
+    immutable_constructor_called: bool = false,
+}
+
+configurable {
+    MY_ADDRESS: Identity = Identity::Address(Address::from(ZERO_B256)),
+    MY_UINT: u256 = 0,
+}
+
+impl Immutable for Contract {
+    #[storage(read, write)]
+    fn constructor(_my_uint: u256) {
+        require(!storage.immutable_constructor_called.read(), "The Immutable constructor has already been called");
+        MY_ADDRESS = msg_sender().unwrap();
+        MY_UINT = _my_uint;
+        storage.immutable_constructor_called.write(true);
+    }
+
+    fn MY_ADDRESS() -> Identity {
+        MY_ADDRESS
+    }
+
+    fn MY_UINT() -> u256 {
+        MY_UINT
+    }
+}
@@ -0,0 +1,59 @@
+contract;
+
+use std::constants::ZERO_B256;
+use std::string::*;
+
+abi Foo {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn name() -> StorageString;
+}
+
+abi Import {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn foo() -> Identity;
+
+    #[storage(read)]
+    fn get_foo_name() -> String;
+}
+
+storage {
+    // Initialize Foo.sol
+    foo: Identity = Identity::Address(Address::from(ZERO_B256)),
+    import_constructor_called: bool = false,
+}
+
+fn new_foo(contract_id: ContractId) -> Foo {
+    let foo = abi(Foo, contract_id.into());
+    foo.constructor();
+    foo
+}
+
+fn expect_contract(identity: Identity) -> ContractId {
+    identity.as_contract_id().unwrap()
+}
+
+impl Import for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.import_constructor_called.read(), "The Import constructor has already been called");
+        storage.foo.write(new_foo(/*unsupported: new Foo(); using:*/ ContractId::from(ZERO_B256)));
+        storage.import_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn foo() -> Identity {
+        storage.foo.read()
+    }
+
+    // Test Foo.sol by getting it's name.
+    #[storage(read)]
+    fn get_foo_name() -> String {
+        abi(Foo, expect_contract(storage.foo.read()).into()).name()
+    }
+}
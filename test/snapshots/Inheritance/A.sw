@@ -0,0 +1,22 @@
+contract;
+
+use std::string::*;
+
+/* Graph of inheritance
+    A
+   / \
+  B   C
+ / \ /
+F  D,E
+
+ */
+
+abi A {
+    fn foo() -> String;
+}
+
+impl A for Contract {
+    fn foo() -> String {
+        "A"
+    }
+}
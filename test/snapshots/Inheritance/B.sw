@@ -0,0 +1,15 @@
+contract;
+
+use std::string::*;
+
+// Contracts inherit other contracts by using the keyword 'is'.
+
+abi B {
+    fn foo() -> String;
+}
+
+impl B for Contract {
+    fn foo() -> String {
+        "B"
+    }
+}
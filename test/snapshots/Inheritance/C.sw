@@ -0,0 +1,13 @@
+contract;
+
+use std::string::*;
+
+abi C {
+    fn foo() -> String;
+}
+
+impl C for Contract {
+    fn foo() -> String {
+        "C"
+    }
+}
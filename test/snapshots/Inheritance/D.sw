@@ -0,0 +1,22 @@
+contract;
+
+use std::string::*;
+
+// Contracts can inherit from multiple parent contracts.
+// When a function is called that is defined multiple times in
+// different contracts, parent contracts are searched from
+// right to left, and in depth-first manner.
+
+abi D {
+    fn foo() -> String;
+}
+
+fn c_foo() -> String {
+    "C"
+}
+
+impl D for Contract {
+    fn foo() -> String {
+        c_foo()
+    }
+}
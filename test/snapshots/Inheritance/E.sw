@@ -0,0 +1,17 @@
+contract;
+
+use std::string::*;
+
+abi E {
+    fn foo() -> String;
+}
+
+fn b_foo() -> String {
+    "B"
+}
+
+impl E for Contract {
+    fn foo() -> String {
+        b_foo()
+    }
+}
@@ -0,0 +1,20 @@
+contract;
+
+use std::string::*;
+
+// Inheritance must be ordered from “most base-like” to “most derived”.
+// Swapping the order of A and B will throw a compilation error.
+
+abi F {
+    fn foo() -> String;
+}
+
+fn b_foo() -> String {
+    "B"
+}
+
+impl F for Contract {
+    fn foo() -> String {
+        b_foo()
+    }
+}
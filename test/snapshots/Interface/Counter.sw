@@ -0,0 +1,25 @@
+contract;
+
+abi Counter {
+    #[storage(read)]
+    fn count() -> u256;
+
+    #[storage(read, write)]
+    fn increment();
+}
+
+storage {
+    count: u256 = 0,
+}
+
+impl Counter for Contract {
+    #[storage(read)]
+    fn count() -> u256 {
+        storage.count.read()
+    }
+
+    #[storage(read, write)]
+    fn increment() {
+        storage.count.write(storage.count.read() + 1);
+    }
+}
@@ -0,0 +1,9 @@
+contract;
+
+abi ICounter {
+    #[storage(read)]
+    fn count() -> u256;
+
+    #[storage(read, write)]
+    fn increment();
+}
@@ -0,0 +1,29 @@
+contract;
+
+abi ICounter {
+    #[storage(read)]
+    fn count() -> u256;
+
+    #[storage(read, write)]
+    fn increment();
+}
+
+abi MyContract {
+    fn increment_counter(_counter: Identity);
+
+    fn get_count(_counter: Identity) -> u256;
+}
+
+fn expect_contract(identity: Identity) -> ContractId {
+    identity.as_contract_id().unwrap()
+}
+
+impl MyContract for Contract {
+    fn increment_counter(_counter: Identity) {
+        abi(ICounter, expect_contract(_counter).into()).increment();
+    }
+
+    fn get_count(_counter: Identity) -> u256 {
+        abi(ICounter, expect_contract(_counter).into()).count()
+    }
+}
@@ -0,0 +1,35 @@
+contract;
+
+abi UniswapV2Factory {
+    #[storage(read)]
+    fn get_pair(token_a: Identity, token_b: Identity) -> Identity;
+}
+
+abi UniswapV2Pair {
+    #[storage(read)]
+    fn get_reserves() -> (u256, u256, u32);
+}
+
+abi UniswapExample {
+    #[storage(read)]
+    fn get_token_reserves() -> (u256, u256);
+}
+
+storage {
+    factory: Identity = 0x5C69BEE701EF814A2B6A3EDD4B1652CB9CC5AA6Fu256,
+    dai: Identity = 0x6B175474E89094C44DA98B954EEDEAC495271D0Fu256,
+    weth: Identity = 0xC02AAA39B223FE8D0A0E5C4F27EAD9083C756CC2u256,
+}
+
+fn expect_contract(identity: Identity) -> ContractId {
+    identity.as_contract_id().unwrap()
+}
+
+impl UniswapExample for Contract {
+    #[storage(read)]
+    fn get_token_reserves() -> (u256, u256) {
+        let pair = abi(UniswapV2Factory, expect_contract(storage.factory.read()).into()).get_pair(storage.dai.read(), storage.weth.read());
+        let (reserve_0, reserve_1, _): (u256, u256, u32) = abi(UniswapV2Pair, expect_contract(pair).into()).get_reserves();
+        (reserve_0, reserve_1)
+    }
+}
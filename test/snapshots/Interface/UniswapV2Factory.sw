@@ -0,0 +1,8 @@
+contract;
+
+// Uniswap example
+
+abi UniswapV2Factory {
+    #[storage(read)]
+    fn get_pair(token_a: Identity, token_b: Identity) -> Identity;
+}
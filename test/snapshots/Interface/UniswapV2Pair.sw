@@ -0,0 +1,6 @@
+contract;
+
+abi UniswapV2Pair {
+    #[storage(read)]
+    fn get_reserves() -> (u256, u256, u32);
+}
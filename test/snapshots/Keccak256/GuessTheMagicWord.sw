@@ -0,0 +1,33 @@
+contract;
+
+use std::bytes::Bytes;
+use std::string::String;
+
+abi GuessTheMagicWord {
+    #[storage(read)]
+    fn answer() -> b256;
+
+    #[storage(read)]
+    fn guess(_word: str) -> bool;
+}
+
+storage {
+    answer: b256 = 0x60298F78CC0B47170BA79C10AA3851D7648BD96F2F8E46A19DBC777C36FB0C00,
+}
+
+impl GuessTheMagicWord for Contract {
+    #[storage(read)]
+    fn answer() -> b256 {
+        storage.answer.read()
+    }
+
+    // Magic word is "Solidity"
+    #[storage(read)]
+    fn guess(_word: str) -> bool {
+        std::hash::keccak256({
+            let mut bytes = Bytes::new();
+            bytes.append(String::from_ascii_str(_word).as_bytes());
+            bytes
+        }) == storage.answer.read()
+    }
+}
@@ -0,0 +1,36 @@
+contract;
+
+use std::bytes::Bytes;
+use std::string::String;
+
+abi HashFunction {
+    fn hash(_text: str, _num: u256, _addr: Identity) -> b256;
+
+    fn collision(_text: str, _another_text: str) -> b256;
+}
+
+impl HashFunction for Contract {
+    fn hash(_text: str, _num: u256, _addr: Identity) -> b256 {
+        std::hash::keccak256({
+            let mut bytes = Bytes::new();
+            bytes.append(String::from_ascii_str(_text).as_bytes());
+            bytes.append(Bytes::from(core::codec::encode(_num)));
+            bytes.append(Bytes::from(core::codec::encode(_addr)));
+            bytes
+        })
+    }
+
+    // Example of hash collision
+    // Hash collision can occur when you pass more than one dynamic data type
+    // to abi.encodePacked. In such case, you should use abi.encode instead.
+    fn collision(_text: str, _another_text: str) -> b256 {
+        // encodePacked(AAA, BBB) -> AAABBB
+        // encodePacked(AA, ABBB) -> AAABBB
+        std::hash::keccak256({
+            let mut bytes = Bytes::new();
+            bytes.append(String::from_ascii_str(_text).as_bytes());
+            bytes.append(String::from_ascii_str(_another_text).as_bytes());
+            bytes
+        })
+    }
+}
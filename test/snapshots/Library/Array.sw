@@ -0,0 +1,14 @@
+library;
+
+use std::storage::storage_vec::*;
+
+// Array function to delete element at index and re-organize the array
+// so that there are no gaps between the elements.
+
+#[storage(read, write)]
+pub fn array_remove(arr: StorageKey<StorageVec<u256>>, index: u256) {
+    // Move the last element into the place to delete
+    require(arr.len() > 0, "Can't remove from empty array");
+    arr.get(u64::try_from(index).unwrap()).unwrap().write(arr.get(arr.len() - 1).unwrap().read());
+    arr.pop();
+}
@@ -0,0 +1,35 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+abi TestArray {
+    #[storage(read)]
+    fn arr(a: u64) -> u256;
+
+    #[storage(read, write)]
+    fn test_array_remove();
+}
+
+storage {
+    arr: StorageVec<u256> = StorageVec {},
+}
+
+impl TestArray for Contract {
+    #[storage(read)]
+    fn arr(a: u64) -> u256 {
+        storage.arr.get(a).unwrap().read()
+    }
+
+    #[storage(read, write)]
+    fn test_array_remove() {
+        let mut i = 0;
+        while i < 3 {
+            storage.arr.push(i);
+            i += 1;
+        }
+        storage.arr.remove(1);
+        assert(storage.arr.len() == 2);
+        assert(storage.arr.get(0).unwrap().read() == 0);
+        assert(storage.arr.get(1).unwrap().read() == 2);
+    }
+}
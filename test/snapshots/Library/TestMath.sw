@@ -0,0 +1,27 @@
+contract;
+
+abi TestMath {
+    fn test_square_root(x: u256) -> u256;
+}
+
+fn math_sqrt(y: u256) -> u256 {
+    let mut z: u256 = 0;
+    if y > 3 {
+        z = y;
+        let mut x = y / 2 + 1;
+        while x < z {
+            z = x;
+            x = (y / x + x) / 2;
+        }
+    } else if y != 0 {
+        z = 1;
+    }
+    // else z = 0 (default value)
+    z
+}
+
+impl TestMath for Contract {
+    fn test_square_root(x: u256) -> u256 {
+        math_sqrt(x)
+    }
+}
@@ -0,0 +1,28 @@
+contract;
+
+abi Loop {
+    fn loop();
+}
+
+impl Loop for Contract {
+    fn loop() {
+        // for loop
+        let mut i = 0;
+        while i < 10 {
+            if i == 3 {
+                // Skip to next iteration with continue
+                continue;
+            }
+            if i == 5 {
+                // Exit loop with break
+                break;
+            }
+            i += 1;
+        }
+        // while loop
+        let mut j = 0;
+        while j < 10 {
+            j += 1;
+        }
+    }
+}
@@ -0,0 +1,48 @@
+contract;
+
+use std::hash::Hash;
+
+abi Mapping {
+    #[storage(read)]
+    fn my_map(a: Identity) -> u256;
+
+    #[storage(read)]
+    fn get(_addr: Identity) -> u256;
+
+    #[storage(read, write)]
+    fn set(_addr: Identity, _i: u256);
+
+    #[storage(read, write)]
+    fn remove(_addr: Identity);
+}
+
+storage {
+    // Mapping from address to uint
+    my_map: StorageMap<Identity, u256> = StorageMap {},
+}
+
+impl Mapping for Contract {
+    #[storage(read)]
+    fn my_map(a: Identity) -> u256 {
+        storage.my_map.get(a).read()
+    }
+
+    #[storage(read)]
+    fn get(_addr: Identity) -> u256 {
+        // Mapping always returns a value.
+        // If the value was never set, it will return the default value.
+        storage.my_map.get(_addr).read()
+    }
+
+    #[storage(read, write)]
+    fn set(_addr: Identity, _i: u256) {
+        // Update the value at this address
+        storage.my_map.get(_addr).write(_i);
+    }
+
+    #[storage(read, write)]
+    fn remove(_addr: Identity) {
+        // Reset the value to the default value.
+        storage.my_map.remove(_addr);
+    }
+}
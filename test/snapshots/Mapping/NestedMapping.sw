@@ -0,0 +1,46 @@
+contract;
+
+use std::hash::Hash;
+
+abi NestedMapping {
+    #[storage(read)]
+    fn nested(a: Identity, b: u256) -> bool;
+
+    #[storage(read)]
+    fn get(_addr_1: Identity, _i: u256) -> bool;
+
+    #[storage(read, write)]
+    fn set(_addr_1: Identity, _i: u256, _boo: bool);
+
+    #[storage(read, write)]
+    fn remove(_addr_1: Identity, _i: u256);
+}
+
+storage {
+    // Nested mapping (mapping from address to another mapping)
+    nested: StorageMap<Identity, StorageMap<u256, bool>> = StorageMap {},
+}
+
+impl NestedMapping for Contract {
+    #[storage(read)]
+    fn nested(a: Identity, b: u256) -> bool {
+        storage.nested.get(a).get(b).read()
+    }
+
+    #[storage(read)]
+    fn get(_addr_1: Identity, _i: u256) -> bool {
+        // You can get values from a nested mapping
+        // even when it is not initialized
+        storage.nested.get(_addr_1).get(_i).read()
+    }
+
+    #[storage(read, write)]
+    fn set(_addr_1: Identity, _i: u256, _boo: bool) {
+        storage.nested.get(_addr_1).get(_i).write(_boo);
+    }
+
+    #[storage(read, write)]
+    fn remove(_addr_1: Identity, _i: u256) {
+        storage.nested.get(_addr_1).remove(_i);
+    }
+}
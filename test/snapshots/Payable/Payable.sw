@@ -0,0 +1,82 @@
+contract;
+
+use std::constants::ZERO_B256;
+use std::auth::msg_sender;
+use std::call_frames::msg_asset_id;
+use std::bytes::Bytes;
+
+abi Payable {
+    #[storage(read, write), payable]
+    fn constructor();
+
+    #[storage(read)]
+    fn owner() -> Identity;
+
+    #[payable]
+    fn deposit();
+
+    fn not_payable();
+
+    #[storage(read)]
+    fn withdraw();
+
+    fn transfer(_to: Identity, _amount: u256);
+}
+
+storage {
+    // Payable address can send Ether via transfer or send
+    owner: Identity = Identity::Address(Address::from(ZERO_B256)),
+    payable_constructor_called: bool = false,
+}
+
+impl Payable for Contract {
+    // Payable constructor can receive Ether
+    #[storage(read, write), payable]
+    fn constructor() {
+        require(!storage.payable_constructor_called.read(), "The Payable constructor has already been called");
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        storage.owner.write(msg_sender().unwrap());
+        storage.payable_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn owner() -> Identity {
+        storage.owner.read()
+    }
+
+    // Function to deposit Ether into this contract.
+    // Call this function along with some Ether.
+    // The balance of this contract will be automatically updated.
+    #[payable]
+    fn deposit() {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+    }
+
+    // Call this function along with some Ether.
+    // The function will throw an error since this function is not payable.
+    fn not_payable() {
+    }
+
+    // Function to withdraw all Ether from this contract.
+    #[storage(read)]
+    fn withdraw() {
+        // get the amount of Ether stored in this contract
+        let amount = std::context::this_balance(AssetId::base()).as_u256();
+        // send all Ether to owner
+        let (success, _): (bool, Bytes) = {
+            std::asset::transfer(storage.owner.read(), AssetId::base(), u64::try_from(amount).unwrap());
+            (true, Bytes::new())
+        };
+        require(success, "Failed to send Ether");
+    }
+
+    // Function to transfer Ether from this contract to address from input
+    fn transfer(_to: Identity, _amount: u256) {
+        // Note that "to" is declared as payable
+        let (success, _): (bool, Bytes) = {
+            std::asset::transfer(_to, AssetId::base(), u64::try_from(_amount).unwrap());
+            (true, Bytes::new())
+        };
+        require(success, "Failed to send Ether");
+    }
+}
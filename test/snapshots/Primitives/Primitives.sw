@@ -0,0 +1,189 @@
+contract;
+
+use signed_integers::i8::*;
+use signed_integers::i64::*;
+use signed_integers::i256::*;
+use std::constants::ZERO_B256;
+
+abi Primitives {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn boo() -> bool;
+
+    #[storage(read)]
+    fn u_8() -> u8;
+
+    #[storage(read)]
+    fn u_256() -> u256;
+
+    #[storage(read)]
+    fn u() -> u256;
+
+    #[storage(read)]
+    fn i_8() -> I8;
+
+    #[storage(read)]
+    fn i_256() -> I256;
+
+    #[storage(read)]
+    fn i() -> I256;
+
+    #[storage(read)]
+    fn min_int() -> I256;
+
+    #[storage(read)]
+    fn max_int() -> I256;
+
+    #[storage(read)]
+    fn addr() -> Identity;
+
+    #[storage(read)]
+    fn default_boo() -> bool;
+
+    #[storage(read)]
+    fn default_uint() -> u256;
+
+    #[storage(read)]
+    fn default_int() -> I256;
+
+    #[storage(read)]
+    fn default_addr() -> Identity;
+}
+
+storage {
+    boo: bool = true,
+    /*
+    uint stands for unsigned integer, meaning non negative integers
+    different sizes are available
+        uint8   ranges from 0 to 2 ** 8 - 1
+        uint16  ranges from 0 to 2 ** 16 - 1
+        ...
+        uint256 ranges from 0 to 2 ** 256 - 1
+     */
+    u_8: u8 = 1,
+    u_256: u256 = 456,
+    // uint is an alias for uint256
+    u: u256 = 123,
+    /*
+    Negative numbers are allowed for int types.
+    Like uint, different ranges are available from int8 to int256
+
+    int256 ranges from -2 ** 255 to 2 ** 255 - 1
+    int128 ranges from -2 ** 127 to 2 ** 127 - 1
+     */
+    i_8: I8 = I8::new(),
+    i_256: I256 = 456,
+    // int is same as int256
+    i: I256 = I256::new(),
+    // minimum and maximum of int
+    min_int: I256 = I256::min(),
+    max_int: I256 = I256::max(),
+    addr: Identity = 0xCA35B7D915458EF540ADE6068DFE2F44E8FA733Cu256,
+    /*
+    In Solidity, the data type byte represent a sequence of bytes.
+    Solidity presents two type of bytes types :
+
+     - fixed-sized byte arrays
+     - dynamically-sized byte arrays.
+
+     The term bytes in Solidity represents a dynamic array of bytes.
+     It’s a shorthand for byte[] .
+     */
+    //  [10110101]
+    a: [u8; 1] = [0xB5],
+    //  [01010110]
+    b: [u8; 1] = [0x56],
+    // Default values
+    // Unassigned variables have a default value
+    // false
+    default_boo: bool = false,
+    // 0
+    default_uint: u256 = 0,
+    // 0
+    default_int: I256 = I256::new(),
+    // 0x0000000000000000000000000000000000000000
+    default_addr: Identity = Identity::Address(Address::from(ZERO_B256)),
+    primitives_constructor_called: bool = false,
+}
+
+impl Primitives for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.primitives_constructor_called.read(), "The Primitives constructor has already been called");
+        storage.i.write(123.neg());
+        storage.i_8.write(1.neg());
+        storage.primitives_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn boo() -> bool {
+        storage.boo.read()
+    }
+
+    #[storage(read)]
+    fn u_8() -> u8 {
+        storage.u_8.read()
+    }
+
+    #[storage(read)]
+    fn u_256() -> u256 {
+        storage.u_256.read()
+    }
+
+    #[storage(read)]
+    fn u() -> u256 {
+        storage.u.read()
+    }
+
+    #[storage(read)]
+    fn i_8() -> I8 {
+        storage.i_8.read()
+    }
+
+    #[storage(read)]
+    fn i_256() -> I256 {
+        storage.i_256.read()
+    }
+
+    #[storage(read)]
+    fn i() -> I256 {
+        storage.i.read()
+    }
+
+    #[storage(read)]
+    fn min_int() -> I256 {
+        storage.min_int.read()
+    }
+
+    #[storage(read)]
+    fn max_int() -> I256 {
+        storage.max_int.read()
+    }
+
+    #[storage(read)]
+    fn addr() -> Identity {
+        storage.addr.read()
+    }
+
+    #[storage(read)]
+    fn default_boo() -> bool {
+        storage.default_boo.read()
+    }
+
+    #[storage(read)]
+    fn default_uint() -> u256 {
+        storage.default_uint.read()
+    }
+
+    #[storage(read)]
+    fn default_int() -> I256 {
+        storage.default_int.read()
+    }
+
+    #[storage(read)]
+    fn default_addr() -> Identity {
+        storage.default_addr.read()
+    }
+}
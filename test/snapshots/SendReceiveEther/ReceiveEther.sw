@@ -0,0 +1,42 @@
+contract;
+
+use std::call_frames::msg_asset_id;
+
+abi ReceiveEther {
+    #[payable]
+    fn receive();
+
+    fn get_balance() -> u256;
+}
+
+#[fallback]
+fn fallback() {
+    require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+}
+
+impl ReceiveEther for Contract {
+    /*
+    Which function is called, fallback() or receive()?
+
+           send Ether
+               |
+         msg.data is empty?
+              / \
+            yes  no
+            /     \
+    receive() exists?  fallback()
+         /   \
+        yes   no
+        /      \
+    receive()   fallback()
+     */
+    // Function to receive Ether. msg.data must be empty
+    #[payable]
+    fn receive() {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+    }
+
+    fn get_balance() -> u256 {
+        std::context::this_balance(AssetId::base()).as_u256()
+    }
+}
@@ -0,0 +1,49 @@
+contract;
+
+use std::context::msg_amount;
+use std::call_frames::msg_asset_id;
+use std::bytes::Bytes;
+
+abi SendEther {
+    #[payable]
+    fn send_via_transfer(_to: Identity);
+
+    #[payable]
+    fn send_via_send(_to: Identity);
+
+    #[payable]
+    fn send_via_call(_to: Identity);
+}
+
+impl SendEther for Contract {
+    #[payable]
+    fn send_via_transfer(_to: Identity) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        // This function is no longer recommended for sending Ether.
+        std::asset::transfer(_to, AssetId::base(), msg_amount());
+    }
+
+    #[payable]
+    fn send_via_send(_to: Identity) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        // Send returns a boolean value indicating success or failure.
+        // This function is not recommended for sending Ether.
+        let sent = {
+            std::asset::transfer(_to, AssetId::base(), msg_amount());
+            true
+        };
+        require(sent, "Failed to send Ether");
+    }
+
+    #[payable]
+    fn send_via_call(_to: Identity) {
+        require(msg_asset_id() == AssetId::base(), "Only the base asset can be sent");
+        // Call returns a boolean value indicating success or failure.
+        // This is the current recommended method to use.
+        let (sent, data): (bool, Bytes) = {
+            std::asset::transfer(_to, AssetId::base(), msg_amount());
+            (true, Bytes::new())
+        };
+        require(sent, "Failed to send Ether");
+    }
+}
@@ -0,0 +1,39 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+abi A {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn name() -> StorageString;
+
+    #[storage(read)]
+    fn get_name() -> String;
+}
+
+storage {
+    name: StorageString = StorageString {},
+    a_constructor_called: bool = false,
+}
+
+impl A for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.a_constructor_called.read(), "The A constructor has already been called");
+        storage.name.write_slice(String::from_ascii_str("Contract A"));
+        storage.a_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn name() -> StorageString {
+        storage.name.read()
+    }
+
+    #[storage(read)]
+    fn get_name() -> String {
+        storage.name.read_slice().unwrap()
+    }
+}
@@ -0,0 +1,55 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+// Shadowing is disallowed in Solidity 0.6
+// This will not compile
+// contract B is A {
+//     string public name = "Contract B";
+// }
+
+abi C {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn name() -> StorageString;
+
+    #[storage(read)]
+    fn get_name() -> String;
+}
+
+storage {
+    name: StorageString = StorageString {},
+    a_constructor_called: bool = false,
+    c_constructor_called: bool = false,
+}
+
+#[storage(read, write)]
+fn a_constructor() {
+    require(!storage.a_constructor_called.read(), "The A constructor has already been called");
+    storage.name.write_slice(String::from_ascii_str("Contract A"));
+    storage.a_constructor_called.write(true);
+}
+
+impl C for Contract {
+    // This is the correct way to override inherited state variables.
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.c_constructor_called.read(), "The C constructor has already been called");
+        a_constructor();
+        storage.name.write_slice(String::from_ascii_str("Contract C"));
+        storage.c_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn name() -> StorageString {
+        storage.name.read()
+    }
+
+    #[storage(read)]
+    fn get_name() -> String {
+        storage.name.read_slice().unwrap()
+    }
+}
@@ -0,0 +1,36 @@
+contract;
+
+abi SimpleStorage {
+    #[storage(read)]
+    fn num() -> u256;
+
+    #[storage(read, write)]
+    fn set(_num: u256);
+
+    #[storage(read)]
+    fn get() -> u256;
+}
+
+storage {
+    // State variable to store a number
+    num: u256 = 0,
+}
+
+impl SimpleStorage for Contract {
+    #[storage(read)]
+    fn num() -> u256 {
+        storage.num.read()
+    }
+
+    // You need to send a transaction to write to a state variable.
+    #[storage(read, write)]
+    fn set(_num: u256) {
+        storage.num.write(_num);
+    }
+
+    // You can read from a state variable without sending a transaction.
+    #[storage(read)]
+    fn get() -> u256 {
+        storage.num.read()
+    }
+}
@@ -0,0 +1,89 @@
+contract;
+
+use std::storage::storage_vec::*;
+use std::string::*;
+
+struct Todo {
+    text: str,
+    completed: bool,
+}
+
+abi Todos {
+    #[storage(read)]
+    fn todos(a: u64) -> Todo;
+
+    #[storage(read, write)]
+    fn create(_text: str);
+
+    #[storage(read)]
+    fn get(_index: u256) -> (String, bool);
+
+    #[storage(read, write)]
+    fn update_text(_index: u256, _text: str);
+
+    #[storage(read, write)]
+    fn toggle_completed(_index: u256);
+}
+
+storage {
+    // An array of 'Todo' structs
+    todos: StorageVec<Todo> = StorageVec {},
+}
+
+impl Todos for Contract {
+    #[storage(read)]
+    fn todos(a: u64) -> Todo {
+        storage.todos.get(a).unwrap().read()
+    }
+
+    #[storage(read, write)]
+    fn create(_text: str) {
+        // 3 ways to initialize a struct
+        // - calling it like a function
+        storage.todos.push(Todo {
+            text: _text,
+            completed: false,
+        });
+        // key value mapping
+        storage.todos.push(Todo {
+            text: _text,
+            completed: false,
+        });
+        // initialize an empty struct and then update it
+        let mut todo = Todo {
+            text: "",
+            completed: false,
+        };
+        todo.text = _text;
+        // todo.completed initialized to false
+        storage.todos.push(todo);
+    }
+
+    // Solidity automatically created a getter for 'todos' so
+    // you don't actually need this function.
+    #[storage(read)]
+    fn get(_index: u256) -> (String, bool) {
+        let mut text: str = "";
+        let mut completed: bool = false;
+        let todo: StorageKey<Todo> = storage.todos.get(u64::try_from(_index).unwrap()).unwrap();
+        (todo.read().text, todo.read().completed)
+    }
+
+    // update text
+    #[storage(read, write)]
+    fn update_text(_index: u256, _text: str) {
+        let todo: StorageKey<Todo> = storage.todos.get(u64::try_from(_index).unwrap()).unwrap();
+        let mut _todo = todo.read();
+        _todo.text = _text;
+        todo.write(_todo);
+    }
+
+    // update completed
+    #[storage(read, write)]
+    fn toggle_completed(_index: u256) {
+        let todo: StorageKey<Todo> = storage.todos.get(u64::try_from(_index).unwrap()).unwrap();
+        let mut _todo = todo.read();
+        _todo.completed = !todo.read().completed;
+        todo.write(_todo);
+    }
+}
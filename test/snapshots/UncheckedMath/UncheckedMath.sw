@@ -0,0 +1,44 @@
+contract;
+
+abi UncheckedMath {
+    fn add(x: u256, y: u256) -> u256;
+
+    fn sub(x: u256, y: u256) -> u256;
+
+    fn sum_of_cubes(x: u256, y: u256) -> u256;
+}
+
+impl UncheckedMath for Contract {
+    fn add(x: u256, y: u256) -> u256 {
+        // 22291 gas
+        // return x + y;
+        // 22103 gas
+        let flags = std::registers::flags();
+        std::flags::disable_panic_on_overflow();
+        let result = x + y;
+        std::flags::set_flags(flags);
+        result
+    }
+
+    fn sub(x: u256, y: u256) -> u256 {
+        // 22329 gas
+        // return x - y;
+        // 22147 gas
+        let flags = std::registers::flags();
+        std::flags::disable_panic_on_overflow();
+        let result = x - y;
+        std::flags::set_flags(flags);
+        result
+    }
+
+    fn sum_of_cubes(x: u256, y: u256) -> u256 {
+        // Wrap complex math logic inside unchecked
+        let flags = std::registers::flags();
+        std::flags::disable_panic_on_overflow();
+        let x_3 = x * x * x;
+        let y_3 = y * y * y;
+        let result = x_3 + y_3;
+        std::flags::set_flags(flags);
+        result
+    }
+}
@@ -0,0 +1,54 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::auth::msg_sender;
+use std::string::*;
+
+abi Variables {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn text() -> StorageString;
+
+    #[storage(read)]
+    fn num() -> u256;
+
+    fn do_something();
+}
+
+storage {
+    // State variables are stored on the blockchain.
+    text: StorageString = StorageString {},
+    num: u256 = 123,
+    variables_constructor_called: bool = false,
+}
+
+impl Variables for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.variables_constructor_called.read(), "The Variables constructor has already been called");
+        storage.text.write_slice(String::from_ascii_str("Hello"));
+        storage.variables_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn text() -> StorageString {
+        storage.text.read()
+    }
+
+    #[storage(read)]
+    fn num() -> u256 {
+        storage.num.read()
+    }
+
+    fn do_something() {
+        // Local variables are not saved to the blockchain.
+        let i = 456;
+        // Here are some global variables
+        // Current block timestamp
+        let timestamp = std::block::timestamp().as_u256();
+        // address of the caller
+        let sender = msg_sender().unwrap();
+    }
+}
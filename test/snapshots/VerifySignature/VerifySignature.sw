@@ -0,0 +1,164 @@
+contract;
+
+use std::bytes::Bytes;
+use std::string::String;
+use std::b512::B512;
+use std::constants::ZERO_B256;
+
+/* Signature Verification
+
+How to Sign and Verify
+# Signing
+1. Create message to sign
+2. Hash the message
+3. Sign the hash (off chain, keep your private key secret)
+
+# Verify
+1. Recreate hash from the original message
+2. Recover signer from signature and hash
+3. Compare recovered signer to claimed signer
+ */
+
+abi VerifySignature {
+    fn get_message_hash(_to: Identity, _amount: u256, _message: str, _nonce: u256) -> b256;
+
+    fn get_eth_signed_message_hash(_message_hash: b256) -> b256;
+
+    fn verify(_signer: Identity, _to: Identity, _amount: u256, _message: str, _nonce: u256, signature: Bytes) -> bool;
+
+    fn recover_signer(_eth_signed_message_hash: b256, _signature: Bytes) -> Identity;
+
+    fn split_signature(sig: Bytes) -> (b256, b256, u8);
+}
+
+/* 1. Unlock MetaMask account
+ethereum.enable()
+ */
+/* 2. Get message hash to sign
+getMessageHash(
+    0x14723A09ACff6D2A60DcdF7aA4AFf308FDDC160C,
+    123,
+    "coffee and donuts",
+    1
+)
+
+hash = "0xcf36ac4f97dc10d91fc2cbb20d718e94a8cbfe0f82eaedc6a4aa38946fb797cd"
+ */
+fn get_message_hash(_to: Identity, _amount: u256, _message: str, _nonce: u256) -> b256 {
+    std::hash::keccak256({
+        let mut bytes = Bytes::new();
+        bytes.append(Bytes::from(core::codec::encode(_to)));
+        bytes.append(Bytes::from(core::codec::encode(_amount)));
+        bytes.append(String::from_ascii_str(_message).as_bytes());
+        bytes.append(Bytes::from(core::codec::encode(_nonce)));
+        bytes
+    })
+}
+
+/* 3. Sign message hash
+# using browser
+account = "copy paste account of signer here"
+ethereum.request({ method: "personal_sign", params: [account, hash]}).then(console.log)
+
+# using web3
+web3.personal.sign(hash, web3.eth.defaultAccount, console.log)
+
+Signature will be different for different accounts
+0x993dab3dd91f5c6dc28e17439be475478f5635c92a56e17e82349d3fb2f166196f466c0b4e0c146f285204f0dcb13e5ae67bc33f4b888ec32dfe0a063e8f3f781b
+ */
+fn get_eth_signed_message_hash(_message_hash: b256) -> b256 {
+    /*
+    Signature is produced by signing a keccak256 hash with the following format:
+    "\x19Ethereum Signed Message\n" + len(msg) + msg
+     */
+    std::hash::keccak256({
+        let mut bytes = Bytes::new();
+        bytes.append(String::from_ascii_str("\x19Ethereum Signed Message:\n32").as_bytes());
+        bytes.append(Bytes::from(core::codec::encode(_message_hash)));
+        bytes
+    })
+}
+
+fn recover_signer(_eth_signed_message_hash: b256, _signature: Bytes) -> Identity {
+    let (r, s, v): (b256, b256, u8) = split_signature(_signature);
+    {
+        let y_parity_and_s = if v == 28 {
+            s | 0x8000000000000000000000000000000000000000000000000000000000000000
+        } else {
+            s
+        };
+        match std::vm::evm::ecr::ec_recover_evm_address(B512::from((r, y_parity_and_s)), _eth_signed_message_hash) {
+            Ok(address) => Identity::Address(Address::from(b256::from(address))),
+            Err(_) => Identity::Address(Address::from(ZERO_B256)),
+        }
+    }
+}
+
+fn split_signature(sig: Bytes) -> (b256, b256, u8) {
+    let mut r: b256 = ZERO_B256;
+    let mut s: b256 = ZERO_B256;
+    let mut v: u8 = 0;
+    require(sig.len() == 65, "invalid signature length");
+    r = todo!("mload(add(sig, 32))");
+    s = todo!("mload(add(sig, 64))");
+    v = todo!("byte(0, mload(add(sig, 96)))");
+    // implicitly return (r, s, v)
+    (r, s, v)
+}
+
+impl VerifySignature for Contract {
+    /* 1. Unlock MetaMask account
+    ethereum.enable()
+     */
+    /* 2. Get message hash to sign
+    getMessageHash(
+        0x14723A09ACff6D2A60DcdF7aA4AFf308FDDC160C,
+        123,
+        "coffee and donuts",
+        1
+    )
+
+    hash = "0xcf36ac4f97dc10d91fc2cbb20d718e94a8cbfe0f82eaedc6a4aa38946fb797cd"
+     */
+    fn get_message_hash(_to: Identity, _amount: u256, _message: str, _nonce: u256) -> b256 {
+        ::get_message_hash(_to, _amount, _message, _nonce)
+    }
+
+    /* 3. Sign message hash
+    # using browser
+    account = "copy paste account of signer here"
+    ethereum.request({ method: "personal_sign", params: [account, hash]}).then(console.log)
+
+    # using web3
+    web3.personal.sign(hash, web3.eth.defaultAccount, console.log)
+
+    Signature will be different for different accounts
+    0x993dab3dd91f5c6dc28e17439be475478f5635c92a56e17e82349d3fb2f166196f466c0b4e0c146f285204f0dcb13e5ae67bc33f4b888ec32dfe0a063e8f3f781b
+     */
+    fn get_eth_signed_message_hash(_message_hash: b256) -> b256 {
+        ::get_eth_signed_message_hash(_message_hash)
+    }
+
+    /* 4. Verify signature
+    signer = 0xB273216C05A8c0D4F0a4Dd0d7Bae1D2EfFE636dd
+    to = 0x14723A09ACff6D2A60DcdF7aA4AFf308FDDC160C
+    amount = 123
+    message = "coffee and donuts"
+    nonce = 1
+    signature =
+        0x993dab3dd91f5c6dc28e17439be475478f5635c92a56e17e82349d3fb2f166196f466c0b4e0c146f285204f0dcb13e5ae67bc33f4b888ec32dfe0a063e8f3f781b
+     */
+    fn verify(_signer: Identity, _to: Identity, _amount: u256, _message: str, _nonce: u256, signature: Bytes) -> bool {
+        let message_hash = get_message_hash(_to, _amount, _message, _nonce);
+        let eth_signed_message_hash = get_eth_signed_message_hash(message_hash);
+        recover_signer(eth_signed_message_hash, signature) == _signer
+    }
+
+    fn recover_signer(_eth_signed_message_hash: b256, _signature: Bytes) -> Identity {
+        ::recover_signer(_eth_signed_message_hash, _signature)
+    }
+
+    fn split_signature(sig: Bytes) -> (b256, b256, u8) {
+        ::split_signature(sig)
+    }
+}
@@ -0,0 +1,33 @@
+contract;
+
+abi ViewAndPure {
+    #[storage(read)]
+    fn x() -> u256;
+
+    #[storage(read)]
+    fn add_to_x(y: u256) -> u256;
+
+    fn add(i: u256, j: u256) -> u256;
+}
+
+storage {
+    x: u256 = 1,
+}
+
+impl ViewAndPure for Contract {
+    #[storage(read)]
+    fn x() -> u256 {
+        storage.x.read()
+    }
+
+    // Promise not to modify the state.
+    #[storage(read)]
+    fn add_to_x(y: u256) -> u256 {
+        storage.x.read() + y
+    }
+
+    // Promise not to modify or read from the state.
+    fn add(i: u256, j: u256) -> u256 {
+        i + j
+    }
+}
@@ -0,0 +1,85 @@
+contract;
+
+use std::storage::storage_string::*;
+use std::string::*;
+
+abi Base {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read)]
+    fn public_var() -> StorageString;
+
+    fn test_private_func() -> String;
+
+    fn test_internal_func() -> String;
+
+    fn public_func() -> String;
+
+    fn external_func() -> String;
+}
+
+storage {
+    // This function will not compile since we're trying to call
+    // an external function here.
+    // function testExternalFunc() public pure returns (string memory) {
+    //     return externalFunc();
+    // }
+    // State variables
+    private_var: StorageString = StorageString {},
+    internal_var: StorageString = StorageString {},
+    public_var: StorageString = StorageString {},
+    base_constructor_called: bool = false,
+}
+
+// Private function can only be called
+// - inside this contract
+// Contracts that inherit this contract cannot call this function.
+fn private_func() -> String {
+    "private function called"
+}
+
+// Internal function can be called
+// - inside this contract
+// - inside contracts that inherit this contract
+fn internal_func() -> String {
+    "internal function called"
+}
+
+impl Base for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.base_constructor_called.read(), "The Base constructor has already been called");
+        storage.public_var.write_slice(String::from_ascii_str("my public variable"));
+        storage.internal_var.write_slice(String::from_ascii_str("my internal variable"));
+        storage.private_var.write_slice(String::from_ascii_str("my private variable"));
+        storage.base_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn public_var() -> StorageString {
+        storage.public_var.read()
+    }
+
+    fn test_private_func() -> String {
+        private_func()
+    }
+
+    fn test_internal_func() -> String {
+        internal_func()
+    }
+
+    // Public functions can be called
+    // - inside this contract
+    // - inside contracts that inherit this contract
+    // - by other contracts and accounts
+    fn public_func() -> String {
+        "public function called"
+    }
+
+    // External functions can only be called
+    // - by other contracts and accounts
+    fn external_func() -> String {
+        "external function called"
+    }
+}
@@ -0,0 +1,293 @@
+contract;
+
+use core::codec::AbiEncode;
+use std::hash::Hash;
+use std::storage::storage_string::*;
+use std::string::*;
+use std::auth::msg_sender;
+use std::constants::ZERO_B256;
+use std::constants::DEFAULT_SUB_ID;
+use standards::src20::SRC20;
+use std::string::String;
+
+enum ERC20Event {
+    Transfer: (Identity, Identity, u256),
+    Approval: (Identity, Identity, u256),
+}
+
+impl AbiEncode for ERC20Event {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            ERC20Event::Transfer((a, b, c)) => {
+                "Transfer".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                match b {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                c.abi_encode(buffer);
+            },
+            ERC20Event::Approval((a, b, c)) => {
+                "Approval".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                match b {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                c.abi_encode(buffer);
+            },
+        }
+    }
+}
+
+enum ERC20Error {
+    ERC20InsufficientBalance: (Identity, u256, u256),
+    ERC20InvalidSender: Identity,
+    ERC20InvalidReceiver: Identity,
+    ERC20InsufficientAllowance: (Identity, u256, u256),
+}
+
+impl AbiEncode for ERC20Error {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            ERC20Error::ERC20InsufficientBalance((a, b, c)) => {
+                "ERC20InsufficientBalance".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                b.abi_encode(buffer);
+                c.abi_encode(buffer);
+            },
+            ERC20Error::ERC20InvalidSender(a) => {
+                "ERC20InvalidSender".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+            },
+            ERC20Error::ERC20InvalidReceiver(a) => {
+                "ERC20InvalidReceiver".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+            },
+            ERC20Error::ERC20InsufficientAllowance((a, b, c)) => {
+                "ERC20InsufficientAllowance".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                b.abi_encode(buffer);
+                c.abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi Token {
+    #[storage(read, write)]
+    fn constructor();
+
+    fn balance_of(account: Identity) -> u256;
+
+    fn transfer(to: Identity, value: u256) -> bool;
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity) -> u256;
+
+    #[storage(read, write)]
+    fn approve(spender: Identity, value: u256) -> bool;
+
+    fn transfer_from(from: Identity, to: Identity, value: u256) -> bool;
+
+    #[storage(read, write)]
+    fn burn(value: u256);
+}
+
+storage {
+    _allowances: StorageMap<Identity, StorageMap<Identity, u256>> = StorageMap {},
+    _total_supply: u256 = 0,
+    _name: StorageString = StorageString {},
+    _symbol: StorageString = StorageString {},
+    erc_20_constructor_called: bool = false,
+    token_constructor_called: bool = false,
+}
+
+#[storage(read, write)]
+fn _transfer(from: Identity, to: Identity, value: u256) {
+    if from == Identity::Address(Address::from(ZERO_B256)) {
+        log(ERC20Error::ERC20InvalidSender(Identity::Address(Address::from(ZERO_B256))));
+        revert(0);
+    }
+    if to == Identity::Address(Address::from(ZERO_B256)) {
+        log(ERC20Error::ERC20InvalidReceiver(Identity::Address(Address::from(ZERO_B256))));
+        revert(0);
+    }
+    _update(from, to, value);
+}
+
+#[storage(read, write)]
+fn _update(from: Identity, to: Identity, value: u256) {
+    if from == Identity::Address(Address::from(ZERO_B256)) {
+        storage._total_supply.write(storage._total_supply.read() + value);
+    } else {
+        let from_balance = match from {
+            Identity::ContractId(id) => std::context::balance_of(id, AssetId::default()),
+            Identity::Address(_) => /*TODO: the native balance of an address can't be queried from a contract*/ revert(0),
+        }.as_u256();
+        if from_balance < value {
+            log(ERC20Error::ERC20InsufficientBalance((from, from_balance, value)));
+            revert(0);
+        }
+        let flags = std::registers::flags();
+        std::flags::disable_panic_on_overflow();
+        std::flags::set_flags(flags);
+    }
+    if to == Identity::Address(Address::from(ZERO_B256)) {
+        let flags = std::registers::flags();
+        std::flags::disable_panic_on_overflow();
+        storage._total_supply.write(storage._total_supply.read() - value);
+        std::flags::set_flags(flags);
+    } else {
+        let flags = std::registers::flags();
+        std::flags::disable_panic_on_overflow();
+        std::flags::set_flags(flags);
+    }
+    log(ERC20Event::Transfer((from, to, value)));
+}
+
+#[storage(read, write)]
+fn _mint(account: Identity, value: u256) {
+    if account == Identity::Address(Address::from(ZERO_B256)) {
+        log(ERC20Error::ERC20InvalidReceiver(Identity::Address(Address::from(ZERO_B256))));
+        revert(0);
+    }
+    _update(Identity::Address(Address::from(ZERO_B256)), account, value);
+    std::asset::mint_to(account, DEFAULT_SUB_ID, u64::try_from(value).unwrap());
+}
+
+#[storage(read, write)]
+fn _burn(account: Identity, value: u256) {
+    if account == Identity::Address(Address::from(ZERO_B256)) {
+        log(ERC20Error::ERC20InvalidSender(Identity::Address(Address::from(ZERO_B256))));
+        revert(0);
+    }
+    _update(account, Identity::Address(Address::from(ZERO_B256)), value);
+    std::asset::burn(DEFAULT_SUB_ID, u64::try_from(value).unwrap());
+}
+
+#[storage(read, write)]
+fn erc_20_constructor(name_: str, symbol_: str) {
+    require(!storage.erc_20_constructor_called.read(), "The ERC20 constructor has already been called");
+    storage._name.write_slice(String::from_ascii_str(name_));
+    storage._symbol.write_slice(String::from_ascii_str(symbol_));
+    storage.erc_20_constructor_called.write(true);
+}
+
+#[storage(read)]
+fn src20_total_supply() -> u256 {
+    storage._total_supply.read()
+}
+
+#[storage(read)]
+fn src20_name() -> String {
+    storage._name.read_slice().unwrap()
+}
+
+#[storage(read)]
+fn src20_symbol() -> String {
+    storage._symbol.read_slice().unwrap()
+}
+
+fn src20_decimals() -> u8 {
+    18
+}
+
+impl Token for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        require(!storage.token_constructor_called.read(), "The Token constructor has already been called");
+        erc_20_constructor("Token", "TKN");
+        _mint(msg_sender().unwrap(), 1000000);
+        storage.token_constructor_called.write(true);
+    }
+
+    fn balance_of(account: Identity) -> u256 {
+        match account {
+            Identity::ContractId(id) => std::context::balance_of(id, AssetId::default()),
+            Identity::Address(_) => /*TODO: the native balance of an address can't be queried from a contract*/ revert(0),
+        }.as_u256()
+    }
+
+    fn transfer(to: Identity, value: u256) -> bool {
+        /*TODO: `transfer` can't move the native asset on behalf of its holder, transfer it with a transaction instead*/ revert(0)
+    }
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity) -> u256 {
+        storage._allowances.get(owner).get(spender).read()
+    }
+
+    #[storage(read, write)]
+    fn approve(spender: Identity, value: u256) -> bool {
+        storage._allowances.get(msg_sender().unwrap()).get(spender).write(value);
+        log(ERC20Event::Approval((msg_sender().unwrap(), spender, value)));
+        true
+    }
+
+    fn transfer_from(from: Identity, to: Identity, value: u256) -> bool {
+        /*TODO: `transferFrom` can't move the native asset on behalf of its holder, transfer it with a transaction instead*/ revert(0)
+    }
+
+    #[storage(read, write)]
+    fn burn(value: u256) {
+        _burn(msg_sender().unwrap(), value);
+    }
+}
+
+impl SRC20 for Contract {
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        1
+    }
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        if asset != AssetId::default() {
+            return None;
+        }
+        Some(u64::try_from(src20_total_supply()).unwrap())
+    }
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        if asset != AssetId::default() {
+            return None;
+        }
+        Some(src20_name())
+    }
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        if asset != AssetId::default() {
+            return None;
+        }
+        Some(src20_symbol())
+    }
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        if asset != AssetId::default() {
+            return None;
+        }
+        Some(src20_decimals())
+    }
+}
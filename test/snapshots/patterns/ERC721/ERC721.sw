@@ -0,0 +1,301 @@
+contract;
+
+use core::codec::AbiEncode;
+use std::hash::Hash;
+use std::storage::storage_string::*;
+use std::string::*;
+use std::auth::msg_sender;
+use std::constants::ZERO_B256;
+use standards::src20::SRC20;
+use std::string::String;
+use standards::src7::SRC7;
+use standards::src7::Metadata;
+
+// An ERC-721 token shaped like OpenZeppelin's v5 `ERC721`, whose ownership queries read the bookkeeping of the contract
+
+enum ERC721Event {
+    Transfer: (Identity, Identity, u256),
+    Approval: (Identity, Identity, u256),
+    ApprovalForAll: (Identity, Identity, bool),
+}
+
+impl AbiEncode for ERC721Event {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            ERC721Event::Transfer((a, b, c)) => {
+                "Transfer".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                match b {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                c.abi_encode(buffer);
+            },
+            ERC721Event::Approval((a, b, c)) => {
+                "Approval".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                match b {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                c.abi_encode(buffer);
+            },
+            ERC721Event::ApprovalForAll((a, b, c)) => {
+                "ApprovalForAll".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                match b {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                c.abi_encode(buffer);
+            },
+        }
+    }
+}
+
+enum ERC721Error {
+    ERC721NonexistentToken: u256,
+    ERC721InvalidReceiver: Identity,
+    ERC721InsufficientApproval: (Identity, u256),
+}
+
+impl AbiEncode for ERC721Error {
+    fn abi_encode(self, ref mut buffer: core::codec::Buffer) {
+        match self {
+            ERC721Error::ERC721NonexistentToken(a) => {
+                "ERC721NonexistentToken".abi_encode(buffer);
+                a.abi_encode(buffer);
+            },
+            ERC721Error::ERC721InvalidReceiver(a) => {
+                "ERC721InvalidReceiver".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+            },
+            ERC721Error::ERC721InsufficientApproval((a, b)) => {
+                "ERC721InsufficientApproval".abi_encode(buffer);
+                match a {
+                    Identity::Address(x) => x.abi_encode(buffer),
+                    Identity::ContractId(x) => x.abi_encode(buffer),
+                }
+                b.abi_encode(buffer);
+            },
+        }
+    }
+}
+
+abi ERC721 {
+    #[storage(read, write)]
+    fn constructor(name_: str, symbol_: str);
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u256;
+
+    #[storage(read)]
+    fn owner_of(token_id: u256) -> Identity;
+
+    #[storage(read, write)]
+    fn approve(to: Identity, token_id: u256);
+
+    #[storage(read)]
+    fn get_approved(token_id: u256) -> Identity;
+
+    #[storage(read, write)]
+    fn set_approval_for_all(operator: Identity, approved: bool);
+
+    #[storage(read)]
+    fn is_approved_for_all(owner: Identity, operator: Identity) -> bool;
+
+    #[storage(read, write)]
+    fn transfer_from(from: Identity, to: Identity, token_id: u256);
+}
+
+storage {
+    _owners: StorageMap<u256, Identity> = StorageMap {},
+    _balances: StorageMap<Identity, u256> = StorageMap {},
+    _token_approvals: StorageMap<u256, Identity> = StorageMap {},
+    _operator_approvals: StorageMap<Identity, StorageMap<Identity, bool>> = StorageMap {},
+    _name: StorageString = StorageString {},
+    _symbol: StorageString = StorageString {},
+    erc_721_constructor_called: bool = false,
+    src20_token_ids: StorageMap<AssetId, u256> = StorageMap {},
+    src20_total_assets: u64 = 0,
+}
+
+#[storage(read)]
+fn _require_owned(token_id: u256) -> Identity {
+    let owner = storage._owners.get(token_id).read();
+    if owner == Identity::Address(Address::from(ZERO_B256)) {
+        log(ERC721Error::ERC721NonexistentToken(token_id));
+        revert(0);
+    }
+    owner
+}
+
+#[storage(read, write)]
+fn _update(to: Identity, token_id: u256) -> Identity {
+    let from = storage._owners.get(token_id).read();
+    if from != Identity::Address(Address::from(ZERO_B256)) {
+        storage._balances.get(from).write(storage._balances.get(from).read() - 1);
+    }
+    if to != Identity::Address(Address::from(ZERO_B256)) {
+        storage._balances.get(to).write(storage._balances.get(to).read() + 1);
+    }
+    storage._owners.get(token_id).write(to);
+    from
+}
+
+#[storage(read, write)]
+fn _mint(to: Identity, token_id: u256) {
+    if to == Identity::Address(Address::from(ZERO_B256)) {
+        log(ERC721Error::ERC721InvalidReceiver(Identity::Address(Address::from(ZERO_B256))));
+        revert(0);
+    }
+    _update(to, token_id);
+    log(ERC721Event::Transfer((Identity::Address(Address::from(ZERO_B256)), to, token_id)));
+    storage.src20_token_ids.insert(AssetId::new(ContractId::this(), b256::from(token_id)), token_id);
+    storage.src20_total_assets.write(storage.src20_total_assets.read() + 1);
+    std::asset::mint_to(to, b256::from(token_id), 1);
+}
+
+#[storage(read, write)]
+fn _burn(token_id: u256) {
+    let from = _update(Identity::Address(Address::from(ZERO_B256)), token_id);
+    log(ERC721Event::Transfer((from, Identity::Address(Address::from(ZERO_B256)), token_id)));
+    let _ = storage.src20_token_ids.remove(AssetId::new(ContractId::this(), b256::from(token_id)));
+    storage.src20_total_assets.write(storage.src20_total_assets.read() - 1);
+    std::asset::burn(b256::from(token_id), 1);
+}
+
+#[storage(read)]
+fn src20_name() -> String {
+    storage._name.read_slice().unwrap()
+}
+
+#[storage(read)]
+fn src20_symbol() -> String {
+    storage._symbol.read_slice().unwrap()
+}
+
+#[storage(read)]
+fn src7_token_uri(token_id: u256) -> String {
+    _require_owned(token_id);
+    ""
+}
+
+impl ERC721 for Contract {
+    #[storage(read, write)]
+    fn constructor(name_: str, symbol_: str) {
+        require(!storage.erc_721_constructor_called.read(), "The ERC721 constructor has already been called");
+        storage._name.write_slice(String::from_ascii_str(name_));
+        storage._symbol.write_slice(String::from_ascii_str(symbol_));
+        storage.erc_721_constructor_called.write(true);
+    }
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u256 {
+        storage._balances.get(owner).read()
+    }
+
+    #[storage(read)]
+    fn owner_of(token_id: u256) -> Identity {
+        _require_owned(token_id)
+    }
+
+    #[storage(read, write)]
+    fn approve(to: Identity, token_id: u256) {
+        let owner = _require_owned(token_id);
+        storage._token_approvals.get(token_id).write(to);
+        log(ERC721Event::Approval((owner, to, token_id)));
+    }
+
+    #[storage(read)]
+    fn get_approved(token_id: u256) -> Identity {
+        storage._token_approvals.get(token_id).read()
+    }
+
+    #[storage(read, write)]
+    fn set_approval_for_all(operator: Identity, approved: bool) {
+        storage._operator_approvals.get(msg_sender().unwrap()).get(operator).write(approved);
+        log(ERC721Event::ApprovalForAll((msg_sender().unwrap(), operator, approved)));
+    }
+
+    #[storage(read)]
+    fn is_approved_for_all(owner: Identity, operator: Identity) -> bool {
+        storage._operator_approvals.get(owner).get(operator).read()
+    }
+
+    #[storage(read, write)]
+    fn transfer_from(from: Identity, to: Identity, token_id: u256) {
+        if to == Identity::Address(Address::from(ZERO_B256)) {
+            log(ERC721Error::ERC721InvalidReceiver(Identity::Address(Address::from(ZERO_B256))));
+            revert(0);
+        }
+        let owner = _require_owned(token_id);
+        if owner != msg_sender().unwrap() && storage._token_approvals.get(token_id).read() != msg_sender().unwrap() && !storage._operator_approvals.get(owner).get(msg_sender().unwrap()).read() {
+            log(ERC721Error::ERC721InsufficientApproval((msg_sender().unwrap(), token_id)));
+            revert(0);
+        }
+        _update(to, token_id);
+        log(ERC721Event::Transfer((from, to, token_id)));
+    }
+}
+
+impl SRC20 for Contract {
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        storage.src20_total_assets.read()
+    }
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        if storage.src20_token_ids.get(asset).try_read().is_none() {
+            return None;
+        }
+        Some(1)
+    }
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        if storage.src20_token_ids.get(asset).try_read().is_none() {
+            return None;
+        }
+        Some(src20_name())
+    }
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        if storage.src20_token_ids.get(asset).try_read().is_none() {
+            return None;
+        }
+        Some(src20_symbol())
+    }
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        if storage.src20_token_ids.get(asset).try_read().is_none() {
+            return None;
+        }
+        Some(0)
+    }
+}
+
+impl SRC7 for Contract {
+    #[storage(read)]
+    fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+        if storage.src20_token_ids.get(asset).try_read().is_none() || key != String::from_ascii_str("uri") {
+            return None;
+        }
+        Some(Metadata::String(src7_token_uri(storage.src20_token_ids.get(asset).read())))
+    }
+}
@@ -0,0 +1,45 @@
+contract;
+
+// An `unchecked` block calling a function with its own `unchecked` block, which must leave overflows unchecked when it returns
+
+abi NestedUnchecked {
+    fn add(a: u256, b: u256) -> u256;
+
+    fn sum(values: Vec<u256>) -> u256;
+}
+
+fn inc(a: u256) -> u256 {
+    let flags = std::registers::flags();
+    std::flags::disable_panic_on_overflow();
+    let result = a + 1;
+    std::flags::set_flags(flags);
+    result
+}
+
+impl NestedUnchecked for Contract {
+    fn add(a: u256, b: u256) -> u256 {
+        let flags = std::registers::flags();
+        std::flags::disable_panic_on_overflow();
+        let c = inc(a);
+        let result = c + b;
+        std::flags::set_flags(flags);
+        result
+    }
+
+    fn sum(values: Vec<u256>) -> u256 {
+        let mut total: u256 = 0;
+        let mut i = 0;
+        while i < values.len().as_u256() {
+            let flags = std::registers::flags();
+            std::flags::disable_panic_on_overflow();
+            if values.get(u64::try_from(i).unwrap()).unwrap() == 0 {
+                std::flags::set_flags(flags);
+                break;
+            }
+            total += inc(values.get(u64::try_from(i).unwrap()).unwrap());
+            std::flags::set_flags(flags);
+            i += 1;
+        }
+        total
+    }
+}
@@ -0,0 +1,242 @@
+//! Translates the pinned corpus of open-source contracts listed in `test/corpus.toml` and checks the coverage of each translation.
+//!
+//! The corpus is checked out into cargo's temporary target directory on first use, so running it requires `git` and network access:
+//! `cargo test --features corpus --test corpus`
+//!
+//! Each contract is checked out at its pinned `commit` and fails if it generates more TODOs than its `max_todo_count`.
+//! After moving a contract to another `revision` or an intended change to the coverage, the pins are regenerated by running:
+//! `UPDATE_CORPUS=1 cargo test --features corpus --test corpus`
+
+use convert_case::{Case, Casing};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// A contract of the corpus, along with the coverage expected from its translation.
+struct CorpusContract {
+    name: String,
+    repository: String,
+    revision: String,
+    commit: Option<String>,
+    target: PathBuf,
+    definition: String,
+    max_todo_count: Option<usize>,
+    forc_build: bool,
+}
+
+fn read_corpus() -> Vec<CorpusContract> {
+    let corpus_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("test").join("corpus.toml");
+
+    let table: toml::Table = std::fs::read_to_string(&corpus_path)
+        .unwrap_or_else(|e| panic!("Failed to read \"{}\": {e}", corpus_path.to_string_lossy()))
+        .parse()
+        .unwrap_or_else(|e| panic!("Failed to parse \"{}\": {e}", corpus_path.to_string_lossy()));
+
+    let get_str = |contract: &toml::Table, key: &str| contract.get(key)
+        .and_then(toml::Value::as_str)
+        .map(String::from)
+        .unwrap_or_else(|| panic!("Expected `{key}` in each corpus contract"));
+
+    table.get("contracts")
+        .and_then(toml::Value::as_array)
+        .expect("Expected a `contracts` array in the corpus")
+        .iter()
+        .map(|contract| {
+            let contract = contract.as_table().expect("Expected a table for each corpus contract");
+
+            CorpusContract {
+                name: get_str(contract, "name"),
+                repository: get_str(contract, "repository"),
+                revision: get_str(contract, "revision"),
+                commit: contract.get("commit").and_then(toml::Value::as_str).map(String::from),
+                target: PathBuf::from(get_str(contract, "target")),
+                definition: get_str(contract, "definition"),
+                max_todo_count: contract.get("max_todo_count").and_then(toml::Value::as_integer).map(|x| x as usize),
+                forc_build: contract.get("forc_build").and_then(toml::Value::as_bool).unwrap_or(false),
+            }
+        })
+        .collect()
+}
+
+/// Runs a command, returning its standard output, or its combined output as an error if it fails.
+fn run(command: &mut Command) -> Result<String, String> {
+    let output = command.output().map_err(|e| format!("failed to run {command:?}: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{command:?} failed with {}:\n{}{}",
+            output.status,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Checks out the repository of the contract at its pinned commit, unless it has already been checked out, returning the checkout directory and the commit.
+///
+/// When `update` is set, the repository is checked out at its `revision` instead, so that its commit can be pinned.
+fn checkout(contract: &CorpusContract, corpus_directory: &Path, update: bool) -> Result<(PathBuf, String), String> {
+    let repository_name = contract.repository.trim_end_matches('/').rsplit('/').next().unwrap();
+
+    if update {
+        let checkout_directory = corpus_directory.join("sources").join(format!("{repository_name}-{}", contract.revision));
+
+        // Check out the revision again, since tags and branches can be moved
+        if checkout_directory.exists() {
+            std::fs::remove_dir_all(&checkout_directory).map_err(|e| e.to_string())?;
+        }
+
+        run(Command::new("git")
+            .args(["clone", "--quiet", "--depth", "1", "--branch", contract.revision.as_str(), contract.repository.as_str()])
+            .arg(&checkout_directory))?;
+
+        let commit = run(Command::new("git").arg("-C").arg(&checkout_directory).args(["rev-parse", "HEAD"]))?;
+
+        return Ok((checkout_directory, commit));
+    }
+
+    let Some(commit) = contract.commit.as_ref() else {
+        return Err("missing a pinned `commit`, run with `UPDATE_CORPUS=1` to pin it".into());
+    };
+
+    let checkout_directory = corpus_directory.join("sources").join(format!("{repository_name}-{commit}"));
+
+    if !checkout_directory.exists() {
+        std::fs::create_dir_all(&checkout_directory).map_err(|e| e.to_string())?;
+
+        let result = run(Command::new("git").arg("-C").arg(&checkout_directory).args(["init", "--quiet"]))
+            .and_then(|_| run(Command::new("git").arg("-C").arg(&checkout_directory).args(["fetch", "--quiet", "--depth", "1", contract.repository.as_str(), commit.as_str()])))
+            .and_then(|_| run(Command::new("git").arg("-C").arg(&checkout_directory).args(["checkout", "--quiet", "FETCH_HEAD"])));
+
+        // Don't leave a partial checkout behind to be reused by the next run
+        if let Err(e) = result {
+            let _ = std::fs::remove_dir_all(&checkout_directory);
+            return Err(e);
+        }
+    }
+
+    let head = run(Command::new("git").arg("-C").arg(&checkout_directory).args(["rev-parse", "HEAD"]))?;
+
+    if head != *commit {
+        return Err(format!("checked out {head}, expected the pinned commit {commit}"));
+    }
+
+    Ok((checkout_directory, commit.clone()))
+}
+
+/// Translates the contract and checks its coverage, returning the commit it was translated at and the number of TODOs generated for it.
+///
+/// When `update` is set, the coverage is not checked, so that the number of TODOs can be pinned.
+fn check_contract(contract: &CorpusContract, corpus_directory: &Path, update: bool) -> Result<(String, usize), String> {
+    let (checkout_directory, commit) = checkout(contract, corpus_directory, update)?;
+    let output_directory = corpus_directory.join("output").join(contract.name.as_str());
+
+    if output_directory.exists() {
+        std::fs::remove_dir_all(&output_directory).map_err(|e| e.to_string())?;
+    }
+
+    run(Command::new(env!("CARGO_BIN_EXE_charcoal"))
+        .arg("--target").arg(checkout_directory.join(&contract.target))
+        .arg("--definition-name").arg(contract.definition.as_str())
+        .arg("--output-directory").arg(&output_directory))?;
+
+    // Get the number of TODOs from the manifest of the run
+    let manifest: toml::Table = std::fs::read_to_string(output_directory.join("charcoal_manifest.toml"))
+        .map_err(|e| format!("failed to read the manifest: {e}"))?
+        .parse()
+        .map_err(|e| format!("failed to parse the manifest: {e}"))?;
+
+    let todo_count = manifest.get("definitions")
+        .and_then(toml::Value::as_array)
+        .map(|definitions| definitions.iter().filter_map(|d| d.get("todo_count").and_then(toml::Value::as_integer)).sum::<i64>() as usize)
+        .unwrap_or(0);
+
+    if !update {
+        let Some(max_todo_count) = contract.max_todo_count else {
+            return Err(format!("generated {todo_count} TODO(s), but is missing a pinned `max_todo_count`, run with `UPDATE_CORPUS=1` to pin it"));
+        };
+
+        if todo_count > max_todo_count {
+            return Err(format!("generated {todo_count} TODO(s), expected at most {max_todo_count}"));
+        }
+    }
+
+    if contract.forc_build {
+        if Command::new("forc").arg("--version").output().is_err() {
+            eprintln!("NOTE: `forc` was not found, skipping the build of `{}`", contract.name);
+        } else {
+            let project_directory = output_directory.join(contract.definition.to_case(Case::Snake));
+            run(Command::new("forc").arg("build").arg("--path").arg(project_directory))?;
+        }
+    }
+
+    Ok((commit, todo_count))
+}
+
+/// Writes the pinned `commit` and `max_todo_count` of each of the supplied contracts to the corpus, keeping the rest of it unchanged.
+fn write_corpus_pins(pins: &[(String, String, usize)]) {
+    let corpus_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("test").join("corpus.toml");
+
+    let source = std::fs::read_to_string(&corpus_path)
+        .unwrap_or_else(|e| panic!("Failed to read \"{}\": {e}", corpus_path.to_string_lossy()));
+
+    let mut lines = vec![];
+    let mut pin = None;
+
+    for line in source.lines() {
+        if line.starts_with("[[contracts]]") {
+            pin = None;
+        } else if let Some(name) = line.strip_prefix("name = ") {
+            pin = pins.iter().find(|(x, _, _)| format!("{x:?}") == name.trim());
+        }
+
+        match pin {
+            // Drop the previous pins of the contract
+            Some(_) if line.starts_with("commit = ") || line.starts_with("max_todo_count = ") => continue,
+
+            Some((_, commit, _)) if line.starts_with("revision = ") => {
+                lines.push(line.to_string());
+                lines.push(format!("commit = {commit:?}"));
+            }
+
+            Some((_, _, todo_count)) if line.starts_with("definition = ") => {
+                lines.push(line.to_string());
+                lines.push(format!("max_todo_count = {todo_count}"));
+            }
+
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    std::fs::write(&corpus_path, format!("{}\n", lines.join("\n")))
+        .unwrap_or_else(|e| panic!("Failed to write \"{}\": {e}", corpus_path.to_string_lossy()));
+}
+
+#[test]
+fn golden_corpus() {
+    let corpus_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("corpus");
+    let update = std::env::var("UPDATE_CORPUS").is_ok_and(|x| x == "1");
+
+    let mut failures = vec![];
+    let mut pins = vec![];
+
+    for contract in read_corpus() {
+        match check_contract(&contract, &corpus_directory, update) {
+            Ok((commit, todo_count)) => {
+                eprintln!("{}: translated `{}` at {commit} with {todo_count} TODO(s)", contract.name, contract.definition);
+                pins.push((contract.name, commit, todo_count));
+            }
+
+            Err(e) => failures.push(format!("{}: {e}", contract.name)),
+        }
+    }
+
+    if update {
+        write_corpus_pins(&pins);
+    }
+
+    assert!(failures.is_empty(), "{} corpus contract(s) failed:\n{}", failures.len(), failures.join("\n"));
+}
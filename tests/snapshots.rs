@@ -0,0 +1,250 @@
+//! Translates the fixture contracts in `test/solidity-by-example/contracts` and compares the Sway generated for each definition
+//! against its snapshot in `test/snapshots`.
+//!
+//! The fixtures in `test/patterns` are translated with the options of the passes they cover (see `PATTERN_FIXTURES`),
+//! and compared against their snapshots in `test/snapshots/patterns`.
+//!
+//! After an intended change to the generated code, the snapshots are regenerated by running:
+//! `UPDATE_SNAPSHOTS=1 cargo test --test snapshots`
+//!
+//! Each fixture is also translated repeatedly to check that the generated code, the written project files and the messages are identical between runs.
+//!
+//! When the `syntax-check` feature is enabled, the generated code of each fixture is also parsed with the Sway parser.
+
+use charcoal::{builder::ProjectBuilder, emitter::Emitter, sway};
+use std::path::{Path, PathBuf};
+
+/// The fixtures that can not be translated yet.
+const UNSUPPORTED_FIXTURES: &[&str] = &[
+    // try statements are not supported
+    "TryCatch.sol",
+];
+
+/// Enables the options of the passes covered by a pattern fixture.
+type ConfigureBuilder = fn(ProjectBuilder) -> ProjectBuilder;
+
+/// The fixtures in `test/patterns` and the options they are translated with.
+const PATTERN_FIXTURES: &[(&str, ConfigureBuilder)] = &[
+    // `_mint` and `_burn` write to the balances themselves
+    ("ERC20V4.sol", |builder| builder.erc20_as_src20(true)),
+    // `_mint` and `_burn` write to the balances through `_update`
+    ("ERC20V5.sol", |builder| builder.erc20_as_src20(true)),
+    // the ownership queries read the bookkeeping of the contract, which native transfers don't update
+    ("ERC721.sol", |builder| builder.erc721_as_nft(true)),
+    // an `unchecked` block calling a function with its own `unchecked` block
+    ("NestedUnchecked.sol", |builder| builder),
+];
+
+fn collect_fixtures(fixtures_directory: &Path) -> Vec<PathBuf> {
+    let mut fixtures = std::fs::read_dir(fixtures_directory)
+        .unwrap_or_else(|e| panic!("Failed to read \"{}\": {e}", fixtures_directory.to_string_lossy()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|x| x == "sol"))
+        .filter(|path| !UNSUPPORTED_FIXTURES.iter().any(|x| path.file_name().is_some_and(|n| n == *x)))
+        .collect::<Vec<_>>();
+
+    fixtures.sort();
+    fixtures
+}
+
+/// Describes the first line that differs between the snapshot and the generated code.
+fn describe_mismatch(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(x), Some(y)) if x == y => line += 1,
+            (x, y) => {
+                return format!(
+                    "line {line} differs:\n    expected: {}\n    actual:   {}",
+                    x.unwrap_or("<end of file>"),
+                    y.unwrap_or("<end of file>"),
+                );
+            }
+        }
+    }
+}
+
+/// Translates the fixture with the supplied builder and compares the generated code of each of its definitions against its snapshot,
+/// or writes the snapshots when updating.
+fn check_fixture(builder: ProjectBuilder, fixture_path: &Path, snapshots_directory: &Path, update: bool) -> Result<(), Vec<String>> {
+    let translated_definitions = builder
+        .source(fixture_path)
+        .emitter(Emitter::Silent)
+        .build()
+        .map_err(|e| vec![e.to_string()])?;
+
+    let snapshot_directory = snapshots_directory.join(fixture_path.file_stem().unwrap());
+
+    if update {
+        if snapshot_directory.exists() {
+            std::fs::remove_dir_all(&snapshot_directory).map_err(|e| vec![e.to_string()])?;
+        }
+
+        std::fs::create_dir_all(&snapshot_directory).map_err(|e| vec![e.to_string()])?;
+    }
+
+    let mut failures = vec![];
+    let mut snapshot_paths = vec![];
+
+    for translated_definition in translated_definitions {
+        let snapshot_path = snapshot_directory.join(format!("{}.sw", translated_definition.name));
+        let module: sway::Module = translated_definition.into();
+        let actual = sway::TabbedDisplayer(&module).to_string();
+
+        if update {
+            std::fs::write(&snapshot_path, actual).map_err(|e| vec![e.to_string()])?;
+        } else {
+            match std::fs::read_to_string(&snapshot_path) {
+                Ok(expected) if expected == actual => {}
+                Ok(expected) => failures.push(format!("{}: {}", snapshot_path.to_string_lossy(), describe_mismatch(&expected, &actual))),
+                Err(_) => failures.push(format!("{}: missing snapshot", snapshot_path.to_string_lossy())),
+            }
+        }
+
+        snapshot_paths.push(snapshot_path);
+    }
+
+    // Check for snapshots of definitions that are no longer generated
+    if let Ok(entries) = std::fs::read_dir(&snapshot_directory) {
+        for path in entries.map(|entry| entry.unwrap().path()) {
+            if !snapshot_paths.contains(&path) {
+                failures.push(format!("{}: stale snapshot", path.to_string_lossy()));
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
+
+#[test]
+fn snapshots() {
+    let test_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("test");
+    let fixtures_directory = test_directory.join("solidity-by-example").join("contracts");
+    let snapshots_directory = test_directory.join("snapshots");
+    let update = std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|x| x == "1");
+
+    let mut failures = vec![];
+
+    for fixture_path in collect_fixtures(&fixtures_directory) {
+        if let Err(e) = check_fixture(ProjectBuilder::new(), &fixture_path, &snapshots_directory, update) {
+            failures.extend(e);
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} snapshot(s) failed, run with `UPDATE_SNAPSHOTS=1` if the changes are intended:\n{}",
+        failures.len(),
+        failures.join("\n"),
+    );
+}
+
+#[test]
+fn pattern_snapshots() {
+    let test_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("test");
+    let fixtures_directory = test_directory.join("patterns");
+    let snapshots_directory = test_directory.join("snapshots").join("patterns");
+    let update = std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|x| x == "1");
+
+    let mut failures = vec![];
+
+    for (fixture_name, configure) in PATTERN_FIXTURES {
+        if let Err(e) = check_fixture(configure(ProjectBuilder::new()), &fixtures_directory.join(fixture_name), &snapshots_directory, update) {
+            failures.extend(e);
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} snapshot(s) failed, run with `UPDATE_SNAPSHOTS=1` if the changes are intended:\n{}",
+        failures.len(),
+        failures.join("\n"),
+    );
+}
+
+/// Collects the paths and contents of the files within the directory, in a stable order.
+fn collect_written_files(directory: &Path, result: &mut Vec<String>) -> Result<(), String> {
+    let mut paths = std::fs::read_dir(directory)
+        .map_err(|e| e.to_string())?
+        .map(|entry| entry.map(|entry| entry.path()).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            collect_written_files(&path, result)?;
+        } else {
+            let contents = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+            result.push(format!("{}\n{contents}", path.strip_prefix(directory).unwrap().to_string_lossy()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Translates the fixture into a project written to `output_directory`, returning the rendered Sway of each translated definition,
+/// followed by the paths and contents of the written files and the emitted messages.
+fn translate_fixture(fixture_path: &Path, output_directory: &Path) -> Result<Vec<String>, String> {
+    let emitter = Emitter::buffer();
+
+    if output_directory.exists() {
+        std::fs::remove_dir_all(output_directory).map_err(|e| e.to_string())?;
+    }
+
+    let translated_definitions = ProjectBuilder::new()
+        .source(fixture_path)
+        .output(output_directory)
+        .emitter(emitter.clone())
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let mut result = translated_definitions.into_iter()
+        .map(|translated_definition| {
+            let module: sway::Module = translated_definition.into();
+            sway::TabbedDisplayer(&module).to_string()
+        })
+        .collect::<Vec<_>>();
+
+    collect_written_files(output_directory, &mut result)?;
+
+    result.push(emitter.buffer_contents().unwrap());
+
+    Ok(result)
+}
+
+#[test]
+fn repeated_runs_are_identical() {
+    const RUN_COUNT: usize = 3;
+
+    let fixtures_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("test").join("solidity-by-example").join("contracts");
+    let output_directory = std::env::temp_dir().join(format!("charcoal-repeated-runs-{}", std::process::id()));
+    let mut failures = vec![];
+
+    for fixture_path in collect_fixtures(&fixtures_directory) {
+        let first_result = translate_fixture(&fixture_path, &output_directory);
+
+        for _ in 1..RUN_COUNT {
+            if translate_fixture(&fixture_path, &output_directory) != first_result {
+                failures.push(fixture_path.to_string_lossy().to_string());
+                break;
+            }
+        }
+    }
+
+    let _ = std::fs::remove_dir_all(&output_directory);
+
+    assert!(
+        failures.is_empty(),
+        "{} fixture(s) were translated differently between runs:\n{}",
+        failures.len(),
+        failures.join("\n"),
+    );
+}